@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use anise::math::angles::{AngleConvention, LongitudeDirection, LongitudeRange};
+use anise::math::units::AngleUnit;
 use clap::{Args, Parser, Subcommand};
 use hifitime::Epoch;
 
@@ -8,6 +10,8 @@ use hifitime::Epoch;
 pub struct CliArgs {
     #[clap(subcommand)]
     pub action: Actions,
+    #[clap(flatten)]
+    pub angles: AngleArgs,
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Subcommand)]
@@ -40,6 +44,16 @@ pub enum Actions {
         /// Output ANISE binary file
         outfile: PathBuf,
     },
+    /// Convert the provided Instrument Kernel into an ANISE instrument dataset
+    ConvertIk {
+        /// Path to the IK (e.g. cas_iss_v10.ti)
+        ikfile: PathBuf,
+        /// Output ANISE binary file
+        outfile: PathBuf,
+        /// Path to the FK defining the FOV frames of the instruments
+        #[clap(long)]
+        fkfile: Option<PathBuf>,
+    },
     /// Truncate the segment of the provided ID of the input NAIF DAF file to the provided start and end epochs
     /// Limitation: this may not work correctly if there are several segments with the same ID.
     /// Only works with Chebyshev Type 2 data types (i.e. planetary ephemerides).
@@ -49,6 +63,42 @@ pub enum Actions {
     RmDAFById(RmById),
 }
 
+/// Output convention of the angles printed by any action
+#[derive(Debug, PartialEq, Eq, PartialOrd, Args)]
+pub(crate) struct AngleArgs {
+    /// Print the angles in radians instead of degrees
+    #[clap(long, global = true)]
+    pub radians: bool,
+    /// Print the longitudes between 0 and 360 degrees instead of between -180 and +180 degrees
+    #[clap(long, global = true)]
+    pub longitude_0_360: bool,
+    /// Print the longitudes positive towards the West instead of the East
+    #[clap(long, global = true)]
+    pub west_positive: bool,
+}
+
+impl AngleArgs {
+    pub fn convention(&self) -> AngleConvention {
+        AngleConvention {
+            unit: if self.radians {
+                AngleUnit::Radian
+            } else {
+                AngleUnit::Degree
+            },
+            longitude_range: if self.longitude_0_360 {
+                LongitudeRange::ZeroTo360
+            } else {
+                LongitudeRange::PlusMinus180
+            },
+            longitude_direction: if self.west_positive {
+                LongitudeDirection::WestPositive
+            } else {
+                LongitudeDirection::EastPositive
+            },
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Args)]
 pub(crate) struct RmById {
     /// Input DAF file, SPK or BPC
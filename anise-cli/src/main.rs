@@ -18,12 +18,13 @@ use zerocopy::FromBytes;
 
 use anise::file2heap;
 use anise::naif::daf::{file_record::FileRecordError, DAFError, FileRecord, NAIFRecord};
-use anise::naif::kpl::parser::{convert_fk, convert_tpc};
+use anise::naif::kpl::parser::{convert_fk, convert_ik, convert_tpc};
 use anise::prelude::*;
 use anise::structure::dataset::{DataSetError, DataSetType};
 use anise::structure::metadata::Metadata;
 use anise::structure::{
-    EulerParameterDataSet, LocationDataSet, PlanetaryDataSet, SpacecraftDataSet,
+    ArticulationDataSet, EulerParameterDataSet, InstrumentDataSet, LocationDataSet,
+    PlanetaryDataSet, SpacecraftDataSet, TraverseDataSet,
 };
 
 mod args;
@@ -78,6 +79,7 @@ fn main() -> Result<(), CliErrors> {
     }
 
     let cli = CliArgs::parse();
+    let angles = cli.angles.convention();
     match cli.action {
         Actions::Check {
             file,
@@ -115,6 +117,32 @@ fn main() -> Result<(), CliErrors> {
                         let dataset =
                             LocationDataSet::try_from_bytes(bytes).context(CliDataSetSnafu)?;
                         println!("{dataset}");
+                        // Each location is printed once, whether it is referenced by ID, by name, or both.
+                        for (index, (opt_id, opt_name)) in dataset.lut.entries() {
+                            if let Some(location) = dataset.data.get(index as usize) {
+                                let id = opt_id.map_or("Unset".to_string(), |id| id.to_string());
+                                let name = opt_name.unwrap_or("Unset".to_string());
+                                println!("{name} ({id}): {}", location.describe(angles));
+                            }
+                        }
+                        Ok(())
+                    }
+                    DataSetType::InstrumentData => {
+                        let dataset =
+                            InstrumentDataSet::try_from_bytes(bytes).context(CliDataSetSnafu)?;
+                        println!("{dataset}");
+                        Ok(())
+                    }
+                    DataSetType::ArticulationData => {
+                        let dataset =
+                            ArticulationDataSet::try_from_bytes(bytes).context(CliDataSetSnafu)?;
+                        println!("{dataset}");
+                        Ok(())
+                    }
+                    DataSetType::TraverseData => {
+                        let dataset =
+                            TraverseDataSet::try_from_bytes(bytes).context(CliDataSetSnafu)?;
+                        println!("{dataset}");
                         Ok(())
                     }
                 }
@@ -167,6 +195,21 @@ fn main() -> Result<(), CliErrors> {
 
             Ok(())
         }
+        Actions::ConvertIk {
+            ikfile,
+            fkfile,
+            outfile,
+        } => {
+            let fk = match fkfile {
+                Some(fkfile) => Some(convert_fk(fkfile, false).context(CliDataSetSnafu)?),
+                None => None,
+            };
+            let dataset = convert_ik(ikfile, fk.as_ref(), false).context(CliDataSetSnafu)?;
+
+            dataset.save_as(&outfile, false).context(CliDataSetSnafu)?;
+
+            Ok(())
+        }
         Actions::TruncDAFById(action) => {
             ensure!(
                 action.start.is_some() || action.end.is_some(),
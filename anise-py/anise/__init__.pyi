@@ -193,6 +193,29 @@ class Almanac:
 
         Original code from GMAT, <https://github.com/ChristopherRabotin/GMAT/blob/GMAT-R2022a/src/gmatutil/util/CalculationUtilities.cpp#L209-L219>"""
 
+    def body_vector_in_frame(
+        self,
+        sc_id: int,
+        body_vector: list,
+        target_frame: astro.Frame,
+        epoch: time.Epoch,
+    ) -> numpy.ndarray:
+        """Returns the provided vector, expressed in the body frame of a spacecraft (e.g. an instrument boresight), in the target frame.
+
+        The body frame is the orientation ID of the spacecraft attitude, which must be loaded as Euler parameter or BPC data, and
+        the rotation from the body frame to the target frame is computed through the orientation tree, like [Almanac::rotate]."""
+
+    def bpc_comments(self, alias: str) -> typing.Optional[str]:
+        """Returns the decoded text of the comment area of the BPC loaded with the provided alias, if that BPC has any comments.
+
+        Mission kernels typically document their provenance and usage constraints in the comment area."""
+
+    def bpc_coverage(self, id: int) -> typing.List:
+        """Returns the time windows during which the provided frame ID has orientation data in any of the loaded BPCs, i.e. the union
+        of the windows of all of its segments, sorted chronologically. This is equivalent to SPICE's `ckcov`/`pckcov` across all loaded files.
+
+        Unlike `bpc_domain`, this reports any gaps between segments, e.g. when an ITRF93 prediction BPC does not follow the historical one."""
+
     def bpc_domain(self, id: int) -> typing.Tuple:
         """Returns the applicable domain of the request id, i.e. start and end epoch that the provided id has loaded data."""
 
@@ -220,6 +243,20 @@ class Almanac:
         """Unloads (in-place) the BPC with the provided alias.
         **WARNING:** This causes the order of the loaded files to be perturbed, which may be an issue if several SPKs with the same IDs are loaded."""
 
+    def bpc_unload_index(self, index: int) -> None:
+        """Unloads (in-place) the BPC at the provided index in the loading order, i.e. zero is the first loaded BPC.
+        The loading order of the remaining BPCs is preserved."""
+
+    def clamp_to_coverage(
+        self, target_frame: astro.Frame, observer_frame: astro.Frame, epoch: time.Epoch
+    ) -> typing.Tuple:
+        """Returns the epoch nearest to the provided epoch at which the state of the target frame as seen from the observer frame can
+        be computed, and the clamping distance from the provided epoch to that epoch (positive if later), cf. [Self::frame_coverage].
+
+        This is meant for user interfaces which snap a requested epoch into the loaded data instead of reporting an error: an epoch
+        in a gap of the coverage is clamped to the nearest end of the surrounding windows, and an epoch within the coverage is
+        returned as is with a zero distance."""
+
     def describe(
         self,
         spk: typing.Optional[bool] = None,
@@ -234,6 +271,47 @@ class Almanac:
         """Pretty prints the description of this Almanac, showing everything by default. Default time scale is TDB.
         If any parameter is set to true, then nothing other than that will be printed."""
 
+    def find_event_arcs(
+        self,
+        function: typing.Callable[[time.Epoch], float],
+        condition: analysis.Condition,
+        epoch_precision: time.Duration,
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+    ) -> typing.List[typing.Tuple[time.Epoch, time.Epoch]]:
+        """Searches for the time intervals when the provided scalar function of the epoch is within the range of the condition, between
+        the start and end epochs, cf. `find_events`. Intervals in progress at the start or the end epoch are clipped to these epochs.
+
+        This method may only be used for less than, greater than, and between conditions. For individual events, use `find_events`."""
+
+    def find_events(
+        self,
+        function: typing.Callable[[time.Epoch], float],
+        condition: analysis.Condition,
+        epoch_precision: time.Duration,
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+    ) -> typing.List[time.Epoch]:
+        """Searches for the epochs when the provided scalar function of the epoch equals a value, or reaches a local minimum or maximum,
+        between the start and end epochs. This is the equivalent of the SPICE `gfuds` routine.
+
+        Unlike `report_events`, the function may be any computation derived from Almanac queries (e.g. a separation angle,
+        a distance, an elevation): it is called with the epoch and must return a float. The same adaptive step scanner and
+        Brent solver are used, so the epoch precision drives both the convergence and the scanning step. The function is assumed
+        to be continuous: for angles which wrap around, return a continuous function of the angle instead (e.g. its sine).
+
+        This method may only be used for equality, minimum, and maximum conditions. For spanned conditions, use `find_event_arcs`."""
+
+    def frame_coverage(
+        self, target_frame: astro.Frame, observer_frame: astro.Frame
+    ) -> typing.List:
+        """Returns the time windows during which the loaded data allows computing the state of the target frame as seen from the
+        observer frame, sorted chronologically.
+
+        This is the intersection of the SPK coverage of all of the ephemerides between both frames and their common node, and of
+        the BPC coverage of the orientations of both frames, if any. If neither restricts the epochs (e.g. the same frame), this is
+        a single window spanning all of the representable epochs. An empty list means that both frames are never simultaneously covered."""
+
     def frame_info(self, uid: astro.Frame) -> astro.Frame:
         """Returns the frame information (gravitational param, shape) as defined in this Almanac from an empty frame"""
 
@@ -241,6 +319,22 @@ class Almanac:
     def from_ccsds_oem_file(path: str, naif_id: int) -> Almanac:
         """Initializes a new Almanac from a file path to CCSDS OEM file, after converting to to SPICE SPK/BSP"""
 
+    def line_of_sight(
+        self,
+        observer: astro.Frame,
+        target: astro.Frame,
+        epoch: time.Epoch,
+        occulting_bodies: typing.List[astro.Frame],
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> typing.Optional[astro.Frame]:
+        """Returns the first of the occulting bodies whose ellipsoid blocks the line of sight from the origin of the observer frame to
+        the origin of the target frame at the provided epoch, or None if the line of sight is clear.
+
+        Each body is modeled by the ellipsoid of its frame, fetched from the loaded planetary data if the frame does not define it.
+        The axes of the ellipsoid are those of the frame, so use body fixed frames (e.g. IAU Earth) to account for the orientation
+        of oblate bodies. Only the segment between the observer and the target is checked, so a body behind the target does not
+        block the line of sight, and a body whose center is the observer or the target is ignored."""
+
     def line_of_sight_obstructed(
         self,
         observer: astro.Orbit,
@@ -277,6 +371,23 @@ class Almanac:
         - `tau` is a parameter that determines the intersection point along the line of sight.
         - The condition `(1.0 - tau) * r1sq + r1dotr2 * tau <= ob_mean_eq_radius_km^2` checks if the line of sight is within the obstructing body's radius, indicating an obstruction."""
 
+    def line_of_sight_windows(
+        self,
+        observer: astro.Frame,
+        target: astro.Frame,
+        occulting_bodies: typing.List[astro.Frame],
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+        step: time.Duration,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> typing.List[typing.Tuple[time.Epoch, time.Epoch]]:
+        """Searches for the time intervals when the line of sight from the origin of the observer frame to the origin of the target
+        frame is not blocked by any of the occulting bodies, cf. `line_of_sight`, between the start and end epochs.
+
+        The time span is sampled at the provided step, which must be shorter than the shortest window to find, and the bounds of
+        each window are then refined by bisection to ten milliseconds. Windows in progress at the start or the end epoch are
+        clipped to these epochs."""
+
     def list_kernels(
         self,
         spk: typing.Optional[bool] = None,
@@ -301,12 +412,56 @@ class Almanac:
     def load_stk_e_file(self, path: str, naif_id: int) -> Almanac:
         """Converts the provided Ansys STK .e file to SPICE SPK/BSP and loads it in the Almanac."""
 
+    def local_frame_dcm_to_inertial(
+        self,
+        target_frame: astro.Frame,
+        observer_frame: astro.Frame,
+        local_frame: astro.LocalFrame,
+        epoch: time.Epoch,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> rotation.DCM:
+        """Returns the DCM from the local frame of the trajectory of the target, as seen from the observer, to the observer frame,
+        evaluated at any epoch covered by the ephemeris of the target. The observer frame must be inertial.
+
+        This is a dynamic frame: unlike `Orbit.dcm_to_inertial`, which assumes two body dynamics, the time derivative of the DCM
+        is computed from the ephemeris of the target one millisecond before and after the epoch, so it accounts for all of the
+        dynamics of the trajectory. The time derivative is not set if either of these states cannot be computed."""
+
     def location_from_id(self, id: int) -> astro.Location:
         """Returns the Location from its ID, searching through all loaded location datasets in reverse order."""
 
     def location_from_name(self, name: str) -> astro.Location:
         """Returns the Location from its name, searching through all loaded location datasets in reverse order."""
 
+    def nadir_yaw_steering_dcm(
+        self,
+        target_frame: astro.Frame,
+        observer_frame: astro.Frame,
+        body_frame_id: int,
+        epoch: time.Epoch,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> rotation.DCM:
+        """Returns the DCM from the observer frame to the body frame of the target for the nadir pointing attitude law with yaw steering.
+
+        The body Z axis points to the nadir, i.e. the center of the observer frame. The body Y axis is perpendicular to the direction of
+        the Sun, which is in the body X-Z plane on the +X side: solar arrays rotating about the body Y axis can always face the Sun.
+        When the Sun is along the nadir direction, the yaw is undefined and the body Y axis is the negative orbit normal, like in LVLH.
+
+        The observer frame must be inertial, and the time derivative of the DCM is not set."""
+
+    def nadir_yaw_steering_profile(
+        self,
+        target_frame: astro.Frame,
+        observer_frame: astro.Frame,
+        body_frame_id: int,
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+        step: time.Duration,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> typing.List[typing.Tuple[time.Epoch, rotation.Quaternion]]:
+        """Samples the nadir pointing attitude law with yaw steering from the start to the end epoch, both included, at the provided step,
+        cf. `nadir_yaw_steering_dcm`. Each quaternion rotates from the observer frame to the body frame."""
+
     def occultation(
         self,
         back_frame: astro.Frame,
@@ -321,6 +476,66 @@ class Almanac:
         A value in between means that the back object is partially hidden from the observser (i.e. _penumbra_ if the back object is the Sun).
         Refer to the [MathSpec](https://nyxspace.com/nyxspace/MathSpec/celestial/eclipse/) for modeling details."""
 
+    def occultation_kind(
+        self,
+        back_frame: astro.Frame,
+        front_frame: astro.Frame,
+        observer: astro.Orbit,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> astro.OccultationKind:
+        """Classifies the occultation of the `back_frame` object by the `front_frame` object as seen from the observer, modeling both objects
+        as spheres of their mean equatorial radius. This is the equivalent of the SPICE `occult` routine.
+
+        The back object is visible if the front object is further from the observer than the back object, even if their disks overlap."""
+
+    def eclipse_windows(
+        self,
+        observer: astro.Frame,
+        eclipsing_frame: astro.Frame,
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+        step: time.Duration,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> typing.List[astro.EclipseWindow]:
+        """Searches for the eclipses of the Sun by the eclipsing body as seen from the origin of the observer frame, e.g. a spacecraft
+        ephemeris, between the start and end epochs, returning the penumbra and umbra entry and exit epochs of each eclipse.
+
+        This is the occultation search of the Sun by the eclipsing body, where the penumbra is any kind of occultation and the umbra is
+        a total occultation. Eclipses in progress at the start or the end epoch are clipped to these epochs."""
+
+    def occultation_windows(
+        self,
+        back_frame: astro.Frame,
+        front_frame: astro.Frame,
+        observer: astro.Frame,
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+        step: time.Duration,
+        kind: typing.Optional[astro.OccultationKind] = None,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> typing.List[astro.OccultationWindow]:
+        """Searches for the time intervals when the `back_frame` object is occulted by the `front_frame` object as seen from the origin of
+        the observer frame, between the start and end epochs. This is the equivalent of the SPICE `gfoclt` routine.
+
+        If a kind is provided, only the windows of that exact kind are returned, otherwise all of the windows where the back object
+        is not fully visible are returned. The time span is sampled at the provided step, which must be shorter than the shortest
+        window to find, and the bounds of each window are then refined by bisection to ten milliseconds.
+        Windows in progress at the start or the end epoch are clipped to these epochs."""
+
+    def radec(
+        self,
+        target_frame: astro.Frame,
+        observer_frame: astro.Frame,
+        epoch: time.Epoch,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> astro.RaDec:
+        """Returns the right ascension, declination, and range of the target as seen from the observer, with their rates of change, in
+        the orientation of the observer frame at the desired epoch.
+
+        # SPICE Compatibility
+        This is the equivalent of calling `recrad` on the position returned by `spkezr`: use a light time and stellar aberration
+        correction (e.g. Aberration("CN+S")) for the apparent right ascension and declination of the target."""
+
     def report_event_arcs(
         self,
         state_spec: analysis.StateSpec,
@@ -355,6 +570,27 @@ class Almanac:
         of the event as a multiplicative factor of that precision is used to scan the trajectory linearly. Alternatively, you may export the scalars at
         a fixed interval using the report_scalars or report_scalars_flat function and manually analyze the results of the scalar expression."""
 
+    def report_passes(
+        self,
+        state_spec: analysis.StateSpec,
+        location_id: int,
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+        sample_rate: time.Duration,
+        obstructing_body: typing.Optional[astro.Frame] = None,
+        sun_exclusion_deg: float = 5.0,
+        elevation_mask_deg: float = 0.0,
+    ) -> list:
+        """Report the pass summaries (AOS, LOS, culmination, and Sun interference) of the object over the desired location ID.
+
+        A pass is a visibility arc where the elevation of the object above the terrain mask of the location (or above the horizon
+        if the terrain mask is not set) is greater than the provided elevation mask, e.g. zero to report every visibility arc.
+        Passes in progress at the start or the end epoch are clipped to these epochs.
+
+        The AER data is sampled at the provided sample rate, which drives the accuracy of the culmination and Sun separation.
+        Sun interference is flagged if the angular separation between the Sun and the object, as seen from the location,
+        is less than the provided exclusion angle at any sample of the pass."""
+
     def report_scalars(
         self, report: analysis.ReportScalars, time_series: time.TimeSeries
     ) -> dict:
@@ -387,6 +623,23 @@ class Almanac:
 
         **WARNING:** This function only performs the translation and no rotation _whatsoever_. Use the `transform_to` function instead to include rotations."""
 
+    def set_kernel_priority(self, alias: str, priority: int) -> None:
+        """Sets (in-place) the priority tag of the SPK or BPC loaded with the provided alias, used by the `HighestPriorityTag` precedence."""
+
+    def shadow_factor(
+        self,
+        eclipsing_frame: astro.Frame,
+        observer: astro.Orbit,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> float:
+        """Computes the fraction of the solar disk visible from the observer despite the eclipsing body, which is modeled by its ellipsoid:
+        zero in umbra, one in full sunlight, and continuous in penumbra. This is the illumination factor used in power and solar
+        radiation pressure models.
+
+        The eclipsing body is modeled as a disk whose radius is the extent of its ellipsoid in the direction of the Sun, as seen
+        from the observer. The axes of the ellipsoid are those of the eclipsing frame, so use the body fixed frame of the
+        eclipsing body (e.g. IAU Earth) to account for its oblateness, which requires the orientation data of that frame."""
+
     def solar_eclipsing(
         self,
         eclipsing_frame: astro.Frame,
@@ -411,6 +664,17 @@ class Almanac:
 
         Refer to [solar_eclipsing] for details."""
 
+    def spk_comments(self, alias: str) -> typing.Optional[str]:
+        """Returns the decoded text of the comment area of the SPK loaded with the provided alias, if that SPK has any comments.
+
+        Mission kernels typically document their provenance and usage constraints in the comment area."""
+
+    def spk_coverage(self, id: int) -> typing.List:
+        """Returns the time windows during which the provided ID has ephemeris data in any of the loaded SPKs, i.e. the union
+        of the windows of all of its segments, sorted chronologically. This is equivalent to SPICE's `spkcov` across all loaded files.
+
+        Unlike `spk_domain`, this reports any gaps between segments."""
+
     def spk_domain(self, id: int) -> typing.Tuple:
         """Returns the applicable domain of the request id, i.e. start and end epoch that the provided id has loaded data."""
 
@@ -430,6 +694,16 @@ class Almanac:
     ) -> astro.Orbit:
         """Alias fo SPICE's `spkezr` where the inputs must be the NAIF IDs of the objects and frames with the caveat that the aberration is moved to the last positional argument."""
 
+    def spk_gaps(
+        self, id: int, start: time.Epoch, end: time.Epoch
+    ) -> typing.List:
+        """Returns the time windows within `[start, end]` during which none of the loaded SPKs provide ephemeris data for the provided ID,
+        i.e. the gaps between its segments, between the files, and before or after its loaded data. An empty list means that the whole
+        analysis window is covered.
+
+        # Note
+        This only checks the segments of the provided ID: querying its state may also require the data of its centers up to the common ephemeris root."""
+
     def spk_summaries(self, id: int) -> typing.List:
         """Returns a vector of the summaries whose ID matches the desired `id`, in the order in which they will be used, i.e. in reverse loading order.
 
@@ -449,6 +723,10 @@ class Almanac:
         """Unloads (in-place) the SPK with the provided alias.
         **WARNING:** This causes the order of the loaded files to be perturbed, which may be an issue if several SPKs with the same IDs are loaded."""
 
+    def spk_unload_index(self, index: int) -> None:
+        """Unloads (in-place) the SPK at the provided index in the loading order, i.e. zero is the first loaded SPK.
+        The loading order of the remaining SPKs is preserved."""
+
     def state_of(
         self,
         object_id: int,
@@ -517,6 +795,40 @@ Obs. -- Target
     ) -> float:
         """Convenience function that calls `sun_angle_deg` with the provided frames instead of the ephemeris ID."""
 
+    def sun_pointing_dcm(
+        self,
+        target_frame: astro.Frame,
+        observer_frame: astro.Frame,
+        body_frame_id: int,
+        roll_constraint: typing.List[float],
+        epoch: time.Epoch,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> rotation.DCM:
+        """Returns the DCM from the observer frame to the body frame of the target for the Sun pointing attitude law with a roll constraint.
+
+        The body Z axis points to the Sun, and the body X axis is as close as possible to the roll constraint, which is a direction
+        in the observer frame (e.g. the ecliptic pole, or the orbit normal). When the roll constraint is along the direction of the
+        Sun, the roll is undefined and an arbitrary perpendicular direction is used instead.
+
+        The observer frame must be inertial, and the time derivative of the DCM is not set."""
+
+    def sun_pointing_profile(
+        self,
+        target_frame: astro.Frame,
+        observer_frame: astro.Frame,
+        body_frame_id: int,
+        roll_constraint: typing.List[float],
+        start_epoch: time.Epoch,
+        end_epoch: time.Epoch,
+        step: time.Duration,
+        ab_corr: typing.Optional[Aberration] = None,
+    ) -> typing.List[typing.Tuple[time.Epoch, rotation.Quaternion]]:
+        """Samples the Sun pointing attitude law with a roll constraint from the start to the end epoch, both included, at the provided step,
+        cf. `sun_pointing_dcm`. Each quaternion rotates from the observer frame to the body frame."""
+
+    def tdb_minus_ut1(self, epoch: time.Epoch) -> typing.Optional[time.Duration]:
+        """Returns the TDB - UT1 difference at the provided epoch, if this Almanac has a UT1 provider which covers it."""
+
     def to_metaalmanac(self) -> MetaAlmanac:
         """Saves the current configuration to a MetaAlmanac for future reloading from the local file system.
 
@@ -524,6 +836,10 @@ Obs. -- Target
         The alias used for each data type is expected to be a path. Further, all paths are ASSUMED to be loaded from the same directory.
         The Almanac does not resolve directories for you."""
 
+    def topocentric_dcm(self, topocentric_frame: Frame, to_frame: Frame, epoch: time.Epoch) -> rotation.DCM:
+        """Returns the DCM from the axes of the registered topocentric frame to the orientation of the provided frame at the provided epoch,
+        including its time derivative from the rotation of the body fixed frame of the site."""
+
     def transform(
         self,
         target_frame: astro.Frame,
@@ -544,6 +860,15 @@ Obs. -- Target
         # Note
         The units will be those of the underlying ephemeris data (typically km and km/s)"""
 
+    def transform_covariance_to(
+        self, covariance: astro.FrameCovariance, new_frame: astro.Frame
+    ) -> astro.FrameCovariance:
+        """Returns the provided covariance expressed in the axes of the new frame, at the epoch of that covariance.
+
+        The covariance is rotated with the 6x6 state DCM between both frames, P' = M P M^T, which includes the time derivative of the
+        rotation: when transforming to or from a rotating frame (e.g. a body fixed frame), the velocity covariance is coupled to the
+        position covariance by the rotation rate of that frame."""
+
     def transform_many(
         self,
         target_frame: astro.Frame,
@@ -599,6 +924,12 @@ Obs. -- Target
         # Note
         This function performs a recursion of no more than twice the [MAX_TREE_DEPTH]."""
 
+    def translate_default(
+        self, target_frame: astro.Frame, observer_frame: astro.Frame, epoch: time.Epoch
+    ) -> astro.Orbit:
+        """Returns the Cartesian state of the target frame as seen from the observer frame at the provided epoch, corrected with the
+default aberration correction of the settings of this Almanac. Use `translate` to override that correction."""
+
     def translate_geometric(
         self, target_frame: astro.Frame, observer_frame: astro.Frame, epoch: time.Epoch
     ) -> astro.Orbit:
@@ -619,12 +950,79 @@ Obs. -- Target
     ) -> astro.Orbit:
         """Performs the GEOMETRIC translation to the parent. Use translate_from_to for aberration."""
 
+    def unload(self, alias: str) -> None:
+        """Unloads (in-place) the kernel loaded with the provided alias, which is the path of the file if it was loaded with `load`, regardless of its type.
+
+        Unlike `spk_unload` and `bpc_unload`, this preserves the loading order of the remaining kernels, and therefore their precedence."""
+
+    def with_extrapolation(self, extrapolation: Extrapolation) -> Almanac:
+        """Returns a copy of this Almanac with the provided extrapolation policy."""
+
+    def with_segment_precedence(self, precedence: SegmentPrecedence) -> Almanac:
+        """Returns a copy of this Almanac with the provided segment precedence policy."""
+
+    def with_settings(self, settings: AlmanacSettings) -> Almanac:
+        """Returns a copy of this Almanac with the provided settings."""
+
+    def with_strict_overlap(self, strict: bool) -> Almanac:
+        """Returns a copy of this Almanac where loading an SPK or a BPC fails if any of its segments covers the same ID over
+an overlapping time span as a segment of an already loaded kernel."""
+
+    def with_topocentric_frame(self, id: int, latitude_deg: float, longitude_deg: float, height_km: float, body_frame: Frame, axes: TopocentricAxes) -> Almanac:
+        """Returns a copy of this Almanac where a topocentric frame anchored at the provided geodetic site of the body fixed frame is registered,
+such that `Frame(id, id)` may then be used as the target or observer of `transform` and `transform_to`."""
+
+    def with_ut1_provider(self, provider: time.Ut1Provider) -> Almanac:
+        """Returns a copy of this Almanac which uses the provided UT1 provider for the rotation of the IAU Earth frame."""
+
     def __repr__(self) -> str:
         """Return repr(self)."""
 
     def __str__(self) -> str:
         """Return str(self)."""
 
+@typing.final
+class AlmanacSettings:
+    """Settings of an Almanac, applied to all of its queries unless a query explicitly overrides them."""
+
+    aberration: Aberration | None
+    extrapolation: Extrapolation
+    segment_precedence: SegmentPrecedence
+    strict_overlap: bool
+
+    def __init__(self) -> None:
+        """Settings of an Almanac, applied to all of its queries unless a query explicitly overrides them."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+@typing.final
+class Extrapolation:
+    """Defines what happens when the ephemeris of an object is queried outside of the time span covered by the loaded SPKs."""
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    Clamp: Extrapolation = ...
+    Error: Extrapolation = ...
+
 @typing.final
 class LocationDataSet:
     """A wrapper around a location dataset kernel (PyO3 does not handle type aliases).
@@ -851,6 +1249,53 @@ class PyReportScalars:
     def to_s_expr(self) -> str:
         """Converts this report builder to its S-Expression"""
 
+@typing.final
+class SegmentPrecedence:
+    """Defines which loaded kernel answers a query when several loaded SPKs (or BPCs) provide data for the same object at the same epoch.
+
+    The default is SPICE's rule: the last loaded kernel wins."""
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __new__(cls) -> SegmentPrecedence: ...
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    FirstLoaded: SegmentPrecedence = ...
+    HighestPriorityTag: SegmentPrecedence = ...
+    LastLoaded: SegmentPrecedence = ...
+
+@typing.final
+class TopocentricAxes:
+    """Axes of a topocentric frame."""
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    ENU: TopocentricAxes = ...
+    NED: TopocentricAxes = ...
+    SEZ: TopocentricAxes = ...
+
 def exec_gui(): ...
 
 __author__: str = "Christopher Rabotin <christopher.rabotin@gmail.com>"
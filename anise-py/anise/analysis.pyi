@@ -325,6 +325,37 @@ class OrthogonalFrame:
     XZ: type = ...
     YZ: type = ...
 
+@typing.final
+class PassSummary:
+    """Summary of a pass of an object over a location, typically used for scheduling."""
+
+    aos_azimuth_deg: float
+    aos_epoch: time.Epoch
+    culmination_epoch: time.Epoch
+    duration: time.Duration
+    location_ref: str
+    los_azimuth_deg: float
+    los_epoch: time.Epoch
+    max_elevation_deg: float
+    min_sun_separation_deg: float
+    sun_interference: bool
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __new__(cls): ...
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
+
 @typing.final
 class Plane:
     """Plane selector, sets the missing component to zero.
@@ -513,6 +544,10 @@ class VisibilityArc:
         """Initialize self.  See help(type(self)) for accurate signature."""
 
     def __new__(cls): ...
+    def culmination(self) -> typing.Tuple:
+        """Returns the culmination of this pass, i.e. the epoch and value of the maximum elevation in degrees, if any AER data is available.
+        The maximum is refined by fitting a parabola through the highest AER sample and its two neighbors."""
+
     def duration(self) -> time.Duration: ...
     def end_epoch(self) -> time.Epoch: ...
     def start_epoch(self) -> time.Epoch: ...
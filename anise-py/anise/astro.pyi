@@ -155,6 +155,34 @@ class DragData:
     def __str__(self) -> str:
         """Return str(self)."""
 
+@typing.final
+class EclipseWindow:
+    """Eclipse of the Sun by an eclipsing body as seen from an observer, as returned by the eclipse search of the Almanac.
+
+    The penumbra bounds the whole eclipse, i.e. when any part of the solar disk is hidden, and the umbra is the part of the
+    eclipse where the solar disk is fully hidden, if any."""
+
+    eclipsing_frame: Frame
+    penumbra_entry_epoch: time.Epoch
+    penumbra_exit_epoch: time.Epoch
+    umbra_entry_epoch: typing.Optional[time.Epoch]
+    umbra_exit_epoch: typing.Optional[time.Epoch]
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def duration(self) -> time.Duration:
+        """Returns the duration of this eclipse, from penumbra entry to penumbra exit"""
+
+    def umbra_duration(self) -> typing.Optional[time.Duration]:
+        """Returns the duration of the umbra of this eclipse, if any"""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
+
 @typing.final
 class Ellipsoid:
     """Only the tri-axial Ellipsoid shape model is currently supported by ANISE.
@@ -192,6 +220,9 @@ class Ellipsoid:
 
         BODY399_RADII     = ( 6378.1366   6378.1366   6356.7519 )"""
 
+    def eccentricity(self) -> float:
+        """Returns the first eccentricity of the meridian ellipse, computed from the flattening ratio as `sqrt(2f - f^2)`"""
+
     def flattening(self) -> float:
         """Returns the flattening ratio, computed from the mean equatorial radius and the polar radius"""
 
@@ -429,6 +460,9 @@ class Frame:
     def from_asn1(data: bytes) -> Frame:
         """Decodes an ASN.1 DER encoded byte array into a Frame."""
 
+    def hill_radius_km(self, distance_km: float, central_frame: Frame) -> float:
+        """Returns the radius of the Hill sphere of this frame's body in km, given its distance to the central body and the frame of that central body."""
+
     def is_celestial(self) -> bool:
         """Returns whether this is a celestial frame"""
 
@@ -453,6 +487,10 @@ class Frame:
     def semi_major_radius_km(self) -> float:
         """Returns the semi major radius of the tri-axial ellipoid shape of this frame, if defined"""
 
+    def soi_radius_km(self, distance_km: float, central_frame: Frame) -> float:
+        """Returns the radius of the Laplace sphere of influence of this frame's body in km, given its distance to the central body
+        (typically the semi-major axis of its orbit) and the frame of that central body."""
+
     def strip(self) -> None:
         """Removes the graviational parameter and the shape information from this frame.
         Use this to prevent astrodynamical computations."""
@@ -496,6 +534,37 @@ class Frame:
     def __str__(self) -> str:
         """Return str(self)."""
 
+@typing.final
+class FrameCovariance:
+    """A position and velocity covariance expressed in the axes of a frame at an epoch, e.g. the covariance of an orbit determination
+    solution, which may be transformed into the axes of any other frame with `Almanac::transform_covariance_to`.
+
+    Unlike [Covariance], which is expressed in a local frame of an orbit, this covariance does not require the state it describes."""
+
+    epoch: time.Epoch
+    frame: Frame
+    matrix: numpy.ndarray
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __new__(
+        cls, covar: numpy.ndarray, frame: Frame, epoch: time.Epoch
+    ) -> FrameCovariance:
+        """A position and velocity covariance expressed in the axes of a frame at an epoch, e.g. the covariance of an orbit determination
+        solution, which may be transformed into the axes of any other frame with `Almanac::transform_covariance_to`.
+
+        Unlike [Covariance], which is expressed in a local frame of an orbit, this covariance does not require the state it describes."""
+
+    def sigmas(self) -> numpy.ndarray:
+        """Returns the standard deviations of the position (km) and velocity (km/s) components, i.e. the square root of the diagonal."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
+
 @typing.final
 class FrameUid:
     """A unique frame reference that only contains enough information to build the actual Frame object.
@@ -520,6 +589,7 @@ class LocalFrame:
     def __repr__(self) -> str:
         """Return repr(self)."""
     Inertial: LocalFrame = ...
+    LVLH: LocalFrame = ...
     RCN: LocalFrame = ...
     RIC: LocalFrame = ...
     VNC: LocalFrame = ...
@@ -647,6 +717,53 @@ class Occultation:
     def __str__(self) -> str:
         """Return str(self)."""
 
+@typing.final
+class OccultationKind:
+    """Classification of an occultation of a back object by a front object as seen from an observer, from the least to the most occulted.
+
+This matches the SPICE `occult` classification, where `Visible` is SPICE's "no occultation"."""
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    Annular: OccultationKind = ...
+    Partial: OccultationKind = ...
+    Total: OccultationKind = ...
+    Visible: OccultationKind = ...
+
+@typing.final
+class OccultationWindow:
+    """Time interval during which the back object is occulted by the front object, as returned by the occultation search of the Almanac."""
+
+    back_frame: Frame
+    end_epoch: time.Epoch
+    front_frame: Frame
+    kind: OccultationKind
+    start_epoch: time.Epoch
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def duration(self) -> time.Duration:
+        """Returns the duration of this occultation window"""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
+
 @typing.final
 class Orbit:
     """Defines a Cartesian state in a given frame at a given epoch in a given time scale. Radius data is expressed in kilometers. Velocity data is expressed in kilometers per second.
@@ -728,6 +845,10 @@ class Orbit:
     def apoapsis_km(self) -> float:
         """Returns the radius of apoapsis (or apogee around Earth), in kilometers."""
 
+    def asymptote_out_ra_dec_deg(self) -> typing.Tuple[float, float]:
+        """Returns the right ascension and declination of the outgoing asymptote in degrees, in the frame of this orbit, e.g. the
+        RLA and DLA of a launch or departure hyperbola, or an error if the orbit is not hyperbolic"""
+
     def at_epoch(self, new_epoch: time.Epoch) -> Orbit:
         """Adjusts the true anomaly of this orbit using the mean anomaly.
 
@@ -743,6 +864,19 @@ class Orbit:
 
         Note that the time is **not** returned in the vector."""
 
+    def dcm3x3_from_lvlh_to_inertial(self) -> rotation.DCM:
+        """Builds the rotation matrix that rotates from this state's inertial frame to this state's LVLH frame (local vertical, local horizontal),
+        the reference of the common nadir pointing and yaw steering attitude modes.
+
+        # Frame warning
+        If the state is NOT in an inertial frame, then this computation is INVALID.
+
+        # Algorithm
+        1. Build the Z vector as the nadir direction, i.e. -\\hat{r}
+        2. Build the Y vector as the negative orbit normal, i.e. the opposite of the orbital momentum unit vector -\\hat{h}
+        3. Build the X vector as the cross product of Y and Z, which is along the velocity for circular orbits
+        4. Return the DCM structure **without** accounting for the transport theorem."""
+
     def dcm3x3_from_rcn_to_inertial(self) -> rotation.DCM:
         """Builds the rotation matrix that rotates from this state's inertial frame to this state's RCN frame (radial, cross, normal)
 
@@ -807,6 +941,21 @@ class Orbit:
         If the pre or post states cannot be computed, then the time derivative of the DCM will _not_ be set.
         Further note that most astrodynamics tools do *not* account for the time derivative in the RIC frame."""
 
+    def dcm_from_lvlh_to_inertial(self) -> rotation.DCM:
+        """Builds the rotation matrix that rotates from this state's inertial frame to this state's LVLH frame (local vertical, local horizontal),
+        the reference of the common nadir pointing and yaw steering attitude modes.
+
+        # Frame warning
+        If the state is NOT in an inertial frame, then this computation is INVALID.
+
+        # Algorithm
+        1. Build the LVLH DCM of this state, cf. `dcm3x3_from_lvlh_to_inertial`
+        2. Compute the difference between the DCMs of the pre and post states (+/- 1 ms), to build the DCM time derivative
+        3. Return the DCM structure with a 6x6 DCM with the time derivative of the LVLH frame set.
+
+        # Note on the time derivative
+        If the pre or post states cannot be computed, then the time derivative of the DCM will _not_ be set."""
+
     def dcm_from_ric_to_inertial(self) -> rotation.DCM:
         """Builds the rotation matrix that rotates from this state's inertial frame to this state's RIC frame
 
@@ -1029,7 +1178,23 @@ class Orbit:
         # Implementation notes
         This function starts by converting the mean anomaly to true anomaly, and then it initializes the orbit
         using the keplerian(..) method.
-        The conversion is from GMAT's MeanToTrueAnomaly function, transliterated originally by Claude and GPT4 with human adjustments."""
+        The conversion solves Kepler's equation for the eccentric anomaly, which is then converted to the true anomaly."""
+
+    @staticmethod
+    def from_keplerian_periapsis(
+        periapsis_km: float,
+        ecc: float,
+        inc_deg: float,
+        raan_deg: float,
+        aop_deg: float,
+        ta_deg: float,
+        epoch: time.Epoch,
+        frame: Frame,
+    ) -> Orbit:
+        """Creates a new Orbit from the Keplerian orbital elements using the radius of periapsis instead of the semi major axis,
+        which also supports parabolic orbits.
+
+        **Units:** km, none, degrees, degrees, degrees, degrees"""
 
     @staticmethod
     def from_latlongalt(
@@ -1086,6 +1251,17 @@ class Orbit:
     def hz(self) -> float:
         """Returns the orbital momentum value on the Z axis"""
 
+    def in_frame_of(self, other: Orbit, local_frame: LocalFrame) -> Orbit:
+        """Returns this state relative to the other state, expressed in the provided local frame of the other state, in position and velocity (with transport theorem).
+        For example, `chaser.in_frame_of(&target, LocalFrame::RIC)` is the relative motion of the chaser in the RIC frame of the target, and
+        is the same as `target.ric_difference(&chaser)`.
+
+        # Algorithm
+        1. Compute the difference between `self` and `other`
+        2. Compute the DCM from the local frame of `other` to the inertial frame
+        3. Rotate the difference into the local frame of `other`
+        4. Strip the astrodynamical information from the frame, enabling only computations from `CartesianState`"""
+
     def inc_brouwer_short_deg(self) -> float:
         """Returns the Brouwer-short mean inclination in degrees."""
 
@@ -1157,6 +1333,12 @@ class Orbit:
     def raan_deg(self) -> float:
         """Returns the right ascension of the ascending node in degrees"""
 
+    def radec(self) -> RaDec:
+        """Returns the right ascension, declination, and range of this state with their rates of change, in the frame of this state.
+
+        The angles and their rates are zero for a zero radius, and the right ascension and its rate are zero along the Z axis, where
+        they are undefined."""
+
     def radius_km(self) -> numpy.ndarray:
         """radius vector in km"""
 
@@ -1212,12 +1394,18 @@ class Orbit:
     def set_aop_deg(self, new_aop_deg: float) -> None:
         """Mutates this orbit to change the AOP"""
 
+    def set_ea_deg(self, new_ea_deg: float) -> None:
+        """Mutates this orbit to change the eccentric anomaly, or the hyperbolic anomaly for hyperbolic orbits"""
+
     def set_ecc(self, new_ecc: float) -> None:
         """Mutates this orbit to change the ECC"""
 
     def set_inc_deg(self, new_inc_deg: float) -> None:
         """Mutates this orbit to change the INC"""
 
+    def set_ma_deg(self, new_ma_deg: float) -> None:
+        """Mutates this orbit to change the mean anomaly, solving Kepler's equation for the new eccentric (or hyperbolic) anomaly"""
+
     def set_raan_deg(self, new_raan_deg: float) -> None:
         """Mutates this orbit to change the RAAN"""
 
@@ -1261,6 +1449,9 @@ class Orbit:
     def velocity_km_s(self) -> numpy.ndarray:
         """velocity vector in km/s"""
 
+    def vinf_km_s(self) -> float:
+        """Returns the hyperbolic excess velocity in km/s, i.e. the square root of the $C_3$, or an error if the orbit is not hyperbolic"""
+
     def vinf_periapsis_km(self, turn_angle_degrees: float) -> float:
         """Returns the radius of periapse in kilometers for the provided turn angle of this hyperbolic orbit.
         Returns an error if the orbit is not hyperbolic."""
@@ -1288,12 +1479,18 @@ class Orbit:
     def with_apoapsis_periapsis_km(self, new_ra_km: float, new_rp_km: float) -> Orbit:
         """Returns a copy of this state with the provided apoasis and periapsis"""
 
+    def with_ea_deg(self, new_ea_deg: float) -> Orbit:
+        """Returns a copy of the state with a new eccentric anomaly, or a new hyperbolic anomaly for hyperbolic orbits"""
+
     def with_ecc(self, new_ecc: float) -> Orbit:
         """Returns a copy of the state with a new ECC"""
 
     def with_inc_deg(self, new_inc_deg: float) -> Orbit:
         """Returns a copy of the state with a new INC"""
 
+    def with_ma_deg(self, new_ma_deg: float) -> Orbit:
+        """Returns a copy of the state with a new mean anomaly"""
+
     def with_raan_deg(self, new_raan_deg: float) -> Orbit:
         """Returns a copy of the state with a new RAAN"""
 
@@ -1328,6 +1525,31 @@ class Orbit:
     def __str__(self) -> str:
         """Return str(self)."""
 
+@typing.final
+class RaDec:
+    """Right ascension, declination, and range of a state with their rates of change, as returned by [Orbit::radec].
+
+    The angles are measured in the XY plane and from the XY plane of the frame of the state, e.g. the equator of the J2000 frame.
+    This is the equivalent of the SPICE `recrad` routine, along with the time derivatives of the angles."""
+
+    dec_deg: float
+    dec_rate_deg_s: float
+    epoch: time.Epoch
+    frame: Frame
+    ra_deg: float
+    ra_rate_deg_s: float
+    range_km: float
+    range_rate_km_s: float
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
+
 @typing.final
 class SRPData:
     area_m2: float
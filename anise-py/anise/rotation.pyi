@@ -255,6 +255,19 @@ Importantly, ANISE prevents the composition of two Euler Parameters if the frame
     def as_vector(self) -> numpy.ndarray:
         """Returns the data of this EP as a vector."""
 
+    @staticmethod
+    def from_array(components: typing.List, order: QuaternionOrder, sense: QuaternionSense, from_id: int, to_id: int) -> Quaternion:
+        """Builds the quaternion transforming coordinates from the `from_id` frame to the `to_id` frame, given its components in the
+        provided order and sense, e.g. as exchanged with other software."""
+
+    @staticmethod
+    def from_hamilton(w: float, x: float, y: float, z: float, from_id: int, to_id: int) -> Quaternion:
+        """Builds this quaternion from a scalar first, active, Hamilton quaternion, as used by SPICE (e.g. `m2q` and C-kernels)."""
+
+    @staticmethod
+    def from_jpl(x: float, y: float, z: float, w: float, from_id: int, to_id: int) -> Quaternion:
+        """Builds this quaternion from a scalar last, passive, JPL (or Shuster) quaternion, as used by many flight software and star trackers."""
+
     def b_matrix(self) -> numpy.ndarray:
         """Returns the 4x3 matrix which relates the body angular velocity vector w to the derivative of this Euler Parameter.
         dQ/dt = 1/2 [B(Q)] w"""
@@ -284,9 +297,18 @@ Importantly, ANISE prevents the composition of two Euler Parameters if the frame
     def short(self) -> Quaternion:
         """Returns the short way rotation of this quaternion"""
 
+    def to_array(self, order: QuaternionOrder, sense: QuaternionSense) -> typing.List:
+        """Returns the components of this quaternion in the provided order and sense, e.g. to exchange it with other software."""
+
     def to_dcm(self) -> DCM:
         """Convert this quaterion to a DCM"""
 
+    def to_hamilton(self) -> typing.List:
+        """Returns the components of this quaternion as a scalar first, active, Hamilton quaternion, as used by SPICE."""
+
+    def to_jpl(self) -> typing.List:
+        """Returns the components of this quaternion as a scalar last, passive, JPL (or Shuster) quaternion."""
+
     def uvec_angle_rad(self) -> tuple:
         """Returns the principal line of rotation (a unit vector) and the angle of rotation in radians"""
 
@@ -319,3 +341,48 @@ Importantly, ANISE prevents the composition of two Euler Parameters if the frame
 
     def __str__(self) -> str:
         """Return str(self)."""
+
+@typing.final
+class QuaternionOrder:
+    """Order of the components of a quaternion when exchanged as an array."""
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    ScalarFirst: QuaternionOrder = ...
+    ScalarLast: QuaternionOrder = ...
+
+@typing.final
+class QuaternionSense:
+    """Sense of a quaternion exchanged with other software, i.e. how it transforms the coordinates of a vector `v`
+from its `from` frame to its `to` frame. Both senses use the Hamilton product.
+
+For the same frames, the components of the active quaternion are those of the conjugate of the passive quaternion."""
+
+    def __init__(self, *args: typing.Any, **kwargs: typing.Any) -> None:
+        """Initialize self.  See help(type(self)) for accurate signature."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    Active: QuaternionSense = ...
+    Passive: QuaternionSense = ...
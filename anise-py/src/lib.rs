@@ -9,10 +9,10 @@
  */
 
 use anise::almanac::metaload::{MetaAlmanac, MetaFile};
-use anise::almanac::Almanac;
+use anise::almanac::{Almanac, AlmanacSettings, Extrapolation, SegmentPrecedence, TopocentricAxes};
 use anise::analysis::prelude::{
     find_arc_intersections, Condition, Event, EventArc, EventDetails, EventEdge, OrbitalElement,
-    Plane, VisibilityArc,
+    PassSummary, Plane, VisibilityArc,
 };
 use anise::analysis::python::{
     PyFrameSpec, PyOrthogonalFrame, PyScalarExpr, PyStateSpec, PyVectorExpr,
@@ -20,11 +20,16 @@ use anise::analysis::python::{
 use anise::analysis::report::PyReportScalars;
 use anise::astro::orbit::Orbit;
 use anise::astro::Aberration;
-use anise::astro::{AzElRange, Location, Occultation, TerrainMask};
-use anise::ephemerides::ephemeris::{Covariance, Ephemeris, EphemerisRecord, LocalFrame};
+use anise::astro::{
+    AzElRange, EclipseWindow, Location, Occultation, OccultationKind, OccultationWindow, RaDec,
+    TerrainMask,
+};
+use anise::ephemerides::ephemeris::{
+    Covariance, Ephemeris, EphemerisRecord, FrameCovariance, LocalFrame,
+};
 use anise::frames::Frame;
 use anise::frames::FrameUid;
-use anise::math::rotation::{Quaternion, DCM};
+use anise::math::rotation::{Quaternion, QuaternionOrder, QuaternionSense, DCM};
 use anise::naif::daf::DafDataType;
 use anise::structure::dataset::location_dhall::PyLocationDataSet;
 use anise::structure::dataset::location_dhall::{LocationDhallSet, LocationDhallSetEntry};
@@ -58,6 +63,10 @@ fn pyanise(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<Almanac>()?;
     m.add_class::<Aberration>()?;
+    m.add_class::<SegmentPrecedence>()?;
+    m.add_class::<AlmanacSettings>()?;
+    m.add_class::<Extrapolation>()?;
+    m.add_class::<TopocentricAxes>()?;
     m.add_class::<MetaAlmanac>()?;
     m.add_class::<MetaFile>()?;
     m.add_class::<LocationDhallSet>()?;
@@ -79,11 +88,16 @@ pub(crate) fn astro(_py: Python, sm: &Bound<'_, PyModule>) -> PyResult<()> {
     sm.add_class::<Orbit>()?;
     sm.add_class::<AzElRange>()?;
     sm.add_class::<Occultation>()?;
+    sm.add_class::<OccultationKind>()?;
+    sm.add_class::<OccultationWindow>()?;
+    sm.add_class::<EclipseWindow>()?;
+    sm.add_class::<RaDec>()?;
     sm.add_class::<Location>()?;
     sm.add_class::<TerrainMask>()?;
     sm.add_class::<Ephemeris>()?;
     sm.add_class::<EphemerisRecord>()?;
     sm.add_class::<Covariance>()?;
+    sm.add_class::<FrameCovariance>()?;
     sm.add_class::<LocalFrame>()?;
     sm.add_class::<DafDataType>()?;
     sm.add_class::<Mass>()?;
@@ -100,6 +114,8 @@ pub(crate) fn astro(_py: Python, sm: &Bound<'_, PyModule>) -> PyResult<()> {
 pub(crate) fn rotation(_py: Python, sm: &Bound<PyModule>) -> PyResult<()> {
     sm.add_class::<DCM>()?;
     sm.add_class::<Quaternion>()?;
+    sm.add_class::<QuaternionOrder>()?;
+    sm.add_class::<QuaternionSense>()?;
 
     Ok(())
 }
@@ -119,6 +135,7 @@ fn analysis(_py: Python, sm: &Bound<PyModule>) -> PyResult<()> {
     sm.add_class::<EventEdge>()?;
     sm.add_class::<EventArc>()?;
     sm.add_class::<VisibilityArc>()?;
+    sm.add_class::<PassSummary>()?;
     sm.add_class::<PyReportScalars>()?;
     sm.add_wrapped(wrap_pyfunction!(find_arc_intersections))?;
     Ok(())
@@ -0,0 +1,11 @@
+#![no_main]
+use anise::almanac::Almanac;
+use bytes::BytesMut;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // create default almanac to serve as test env
+    let almanac = Almanac::default();
+    // best effort loading must never panic, even on damaged data
+    let _ = almanac.load_from_bytes_best_effort(BytesMut::from(data), "fuzz");
+});
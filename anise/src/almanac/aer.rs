@@ -9,7 +9,7 @@
  */
 
 use crate::{
-    astro::{Aberration, AzElRange},
+    astro::{Aberration, AzElRange, RefractionModel},
     constants::SPEED_OF_LIGHT_KM_S,
     ephemerides::{EphemerisError, EphemerisPhysicsSnafu},
     errors::{AlmanacError, EphemerisSnafu, PhysicsError},
@@ -241,6 +241,22 @@ impl Almanac {
             }),
         }
     }
+
+    /// Computes the azimuth (in degrees), apparent elevation (in degrees), range (in kilometers), and range-rate (in km/s) of the
+    /// receiver state (`rx`) seen from the provided location, where the geometric elevation is corrected for the atmospheric
+    /// refraction per the provided model, e.g. to predict the pointing of an antenna or a telescope.
+    /// Refer to [azimuth_elevation_range_sez_from_location] for details: the terrain mask is compared to the apparent elevation.
+    pub fn apparent_azimuth_elevation_range_sez_from_location(
+        &self,
+        rx: Orbit,
+        location: Location,
+        refraction: RefractionModel,
+        obstructing_body: Option<Frame>,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<AzElRange> {
+        self.azimuth_elevation_range_sez_from_location(rx, location, obstructing_body, ab_corr)
+            .map(|aer| aer.with_refraction(refraction))
+    }
 }
 
 #[cfg(test)]
@@ -251,7 +267,7 @@ mod ut_aer {
     use hifitime::Unit;
 
     use crate::astro::orbit::Orbit;
-    use crate::astro::AzElRange;
+    use crate::astro::{AzElRange, RefractionModel};
     use crate::constants::frames::{EARTH_ITRF93, EARTH_J2000, IAU_EARTH_FRAME};
     use crate::math::cartesian::CartesianState;
     use crate::prelude::{Almanac, Epoch};
@@ -524,6 +540,45 @@ mod ut_aer {
         }
     }
 
+    #[test]
+    fn apparent_aer_from_location() {
+        let almanac = Almanac::new("../data/pck08.pca").unwrap();
+        let iau_earth = almanac.frame_info(IAU_EARTH_FRAME).unwrap();
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 1, 14);
+
+        let station = Location {
+            latitude_deg: 19.82,
+            longitude_deg: -155.47,
+            height_km: 4.2,
+            frame: IAU_EARTH_FRAME.into(),
+            terrain_mask: vec![],
+            terrain_mask_ignored: true,
+        };
+        // An object a few degrees above the horizon of the station
+        let rx = Orbit::try_latlongalt(25.0, -150.0, 500.0, epoch, iau_earth).unwrap();
+
+        let geometric = almanac
+            .azimuth_elevation_range_sez_from_location(rx, station.clone(), None, None)
+            .unwrap();
+        let model = RefractionModel::BennettMeteo {
+            pressure_hpa: 615.0,
+            temperature_deg_c: 0.0,
+        };
+        let apparent = almanac
+            .apparent_azimuth_elevation_range_sez_from_location(rx, station, model, None, None)
+            .unwrap();
+        println!("{geometric}\n{apparent}");
+
+        assert!(geometric.elevation_deg > 0.0);
+        assert_eq!(
+            apparent.elevation_deg,
+            model.apparent_elevation_deg(geometric.elevation_deg)
+        );
+        assert!(apparent.elevation_deg > geometric.elevation_deg);
+        assert_eq!(apparent.azimuth_deg, geometric.azimuth_deg);
+        assert_eq!(apparent.range_km, geometric.range_km);
+    }
+
     /// Rebuild the GMAT Verif test using a location data type directly.
     ///
     /// For reference, the `gmat_verif` test below returns these values
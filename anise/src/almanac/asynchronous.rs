@@ -0,0 +1,202 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Asynchronous variants of the loading and batch operations, usable from any async runtime.
+//!
+//! Each operation runs on its own dedicated thread and wakes the awaiting task when it completes, so the executor threads are never
+//! blocked, and neither the runtime's blocking thread pool nor a rayon pool is used.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use hifitime::Epoch;
+
+use super::report::AlmanacReport;
+use super::Almanac;
+use crate::ephemerides::EphemerisError;
+use crate::errors::AlmanacResult;
+use crate::naif::daf::DafDataType;
+use crate::naif::SPK;
+use crate::orientations::OrientationError;
+use crate::NaifId;
+
+#[cfg(feature = "metaload")]
+use super::metaload::MetaAlmanac;
+
+/// Time domain of each loaded ID, as returned by `spk_domains` and `bpc_domains`.
+pub type Domains = HashMap<NaifId, (Epoch, Epoch)>;
+
+/// A future resolving to the output of a blocking operation running on its own thread.
+///
+/// If the operation panics, the panic is resumed when this future is polled.
+pub struct BlockingTask<T> {
+    shared: Arc<Mutex<TaskState<T>>>,
+}
+
+struct TaskState<T> {
+    output: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> BlockingTask<T> {
+    /// Runs the provided operation on a new thread.
+    pub fn spawn<F>(operation: F) -> Self
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(TaskState {
+            output: None,
+            waker: None,
+        }));
+
+        let thread_shared = shared.clone();
+        thread::spawn(move || {
+            let output = panic::catch_unwind(AssertUnwindSafe(operation));
+            let waker = {
+                let mut state = thread_shared.lock().unwrap_or_else(|e| e.into_inner());
+                state.output = Some(output);
+                state.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Self { shared }
+    }
+}
+
+impl<T> Future for BlockingTask<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        match state.output.take() {
+            Some(Ok(output)) => Poll::Ready(output),
+            Some(Err(payload)) => panic::resume_unwind(payload),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Almanac {
+    /// Asynchronous variant of `load`.
+    pub fn load_async(self, path: String) -> BlockingTask<AlmanacResult<Self>> {
+        BlockingTask::spawn(move || self.load(&path))
+    }
+
+    /// Asynchronous variant of `report`.
+    pub fn report_async(self: Arc<Self>) -> BlockingTask<AlmanacResult<AlmanacReport>> {
+        BlockingTask::spawn(move || self.report())
+    }
+
+    /// Asynchronous variant of `spk_domains`.
+    pub fn spk_domains_async(self: Arc<Self>) -> BlockingTask<Result<Domains, EphemerisError>> {
+        BlockingTask::spawn(move || self.spk_domains())
+    }
+
+    /// Asynchronous variant of `bpc_domains`.
+    pub fn bpc_domains_async(self: Arc<Self>) -> BlockingTask<Result<Domains, OrientationError>> {
+        BlockingTask::spawn(move || self.bpc_domains())
+    }
+}
+
+#[cfg(feature = "metaload")]
+impl MetaAlmanac {
+    /// Asynchronous variant of `process`, which downloads the files if needed. The processed MetaAlmanac is returned with the Almanac.
+    pub fn process_async(
+        mut self,
+        autodelete: bool,
+    ) -> BlockingTask<(Self, AlmanacResult<Almanac>)> {
+        BlockingTask::spawn(move || {
+            let almanac = self.process(autodelete);
+            (self, almanac)
+        })
+    }
+}
+
+impl SPK {
+    /// Asynchronous variant of `resample_nth_segment`, returning the resampled SPK.
+    pub fn resample_nth_segment_async(
+        mut self,
+        idx: usize,
+        data_type: DafDataType,
+        degree: usize,
+        tolerance_km: f64,
+    ) -> BlockingTask<Result<Self, EphemerisError>> {
+        BlockingTask::spawn(move || {
+            self.resample_nth_segment(idx, data_type, degree, tolerance_km)?;
+            Ok(self)
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_asynchronous {
+    use super::BlockingTask;
+    use crate::prelude::Almanac;
+    use core::future::Future;
+    use core::task::{Context, Poll, Waker};
+    use std::sync::Arc;
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Minimal executor, which parks the current thread until the future is woken up.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn blocking_task() {
+        let task = BlockingTask::spawn(|| (0..1000_u64).sum::<u64>());
+        assert_eq!(block_on(task), 499500);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation failed")]
+    fn blocking_task_panic() {
+        block_on(BlockingTask::spawn(|| panic!("operation failed")))
+    }
+
+    #[test]
+    fn almanac_async() {
+        let almanac = block_on(Almanac::default().load_async("not/a/file.bsp".to_string()));
+        assert!(almanac.is_err());
+
+        let almanac = Arc::new(Almanac::default());
+        let report = block_on(almanac.clone().report_async()).unwrap();
+        assert!(report.spk.is_empty());
+        assert!(block_on(almanac.spk_domains_async()).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::{Duration, Epoch, TimeSeries};
+
+use super::Almanac;
+use crate::astro::Aberration;
+use crate::constants::frames::SUN_J2000;
+use crate::errors::AlmanacResult;
+use crate::math::rotation::{Quaternion, DCM};
+use crate::math::{Matrix3, Vector3};
+use crate::prelude::Frame;
+use crate::NaifId;
+
+impl Almanac {
+    /// Returns the DCM from the observer frame to the body frame of the target for the nadir pointing attitude law with yaw steering.
+    ///
+    /// The body Z axis points to the nadir, i.e. the center of the observer frame. The body Y axis is perpendicular to the direction of
+    /// the Sun, which is in the body X-Z plane on the +X side: solar arrays rotating about the body Y axis can always face the Sun.
+    /// When the Sun is along the nadir direction, the yaw is undefined and the body Y axis is the negative orbit normal, like in LVLH.
+    ///
+    /// The observer frame must be inertial, and the time derivative of the DCM is not set.
+    pub fn nadir_yaw_steering_dcm(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<DCM> {
+        let state = self.transform(target_frame, observer_frame, epoch, ab_corr)?;
+        let to_sun = self.sun_direction(state.radius_km, observer_frame, epoch, ab_corr)?;
+
+        let z = -state.r_hat();
+        let mut y = z.cross(&to_sun);
+        if y.norm() < 1e-9 {
+            y = -state.h_hat().unwrap_or_else(|_| any_perpendicular(&z));
+        }
+        let y = y.normalize();
+        let x = y.cross(&z);
+
+        Ok(body_dcm(x, y, z, observer_frame, body_frame_id))
+    }
+
+    /// Returns the DCM from the observer frame to the body frame of the target for the Sun pointing attitude law with a roll constraint.
+    ///
+    /// The body Z axis points to the Sun, and the body X axis is as close as possible to the roll constraint, which is a direction
+    /// in the observer frame (e.g. the ecliptic pole, or the orbit normal). When the roll constraint is along the direction of the
+    /// Sun, the roll is undefined and an arbitrary perpendicular direction is used instead.
+    ///
+    /// The observer frame must be inertial, and the time derivative of the DCM is not set.
+    pub fn sun_pointing_dcm(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        roll_constraint: Vector3,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<DCM> {
+        let state = self.transform(target_frame, observer_frame, epoch, ab_corr)?;
+        let z = self.sun_direction(state.radius_km, observer_frame, epoch, ab_corr)?;
+
+        let mut x = roll_constraint - roll_constraint.dot(&z) * z;
+        if x.norm() <= 1e-9 * roll_constraint.norm() {
+            x = any_perpendicular(&z);
+        }
+        let x = x.normalize();
+        let y = z.cross(&x);
+
+        Ok(body_dcm(x, y, z, observer_frame, body_frame_id))
+    }
+
+    /// Samples the nadir pointing attitude law with yaw steering from the start to the end epoch, both included, at the provided step,
+    /// cf. [Almanac::nadir_yaw_steering_dcm]. Each quaternion rotates from the observer frame to the body frame.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nadir_yaw_steering_profile(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Quaternion)>> {
+        TimeSeries::inclusive(start_epoch, end_epoch, step)
+            .map(|epoch| {
+                let dcm = self.nadir_yaw_steering_dcm(
+                    target_frame,
+                    observer_frame,
+                    body_frame_id,
+                    epoch,
+                    ab_corr,
+                )?;
+                Ok((epoch, Quaternion::from(dcm)))
+            })
+            .collect()
+    }
+
+    /// Samples the Sun pointing attitude law with a roll constraint from the start to the end epoch, both included, at the provided step,
+    /// cf. [Almanac::sun_pointing_dcm]. Each quaternion rotates from the observer frame to the body frame.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sun_pointing_profile(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        roll_constraint: Vector3,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Quaternion)>> {
+        TimeSeries::inclusive(start_epoch, end_epoch, step)
+            .map(|epoch| {
+                let dcm = self.sun_pointing_dcm(
+                    target_frame,
+                    observer_frame,
+                    body_frame_id,
+                    roll_constraint,
+                    epoch,
+                    ab_corr,
+                )?;
+                Ok((epoch, Quaternion::from(dcm)))
+            })
+            .collect()
+    }
+
+    /// Returns the unit vector from the provided position, in km in the observer frame, to the Sun.
+    fn sun_direction(
+        &self,
+        position_km: Vector3,
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vector3> {
+        let sun_km = self
+            .transform(
+                SUN_J2000.with_orient(observer_frame.orientation_id),
+                observer_frame,
+                epoch,
+                ab_corr,
+            )?
+            .radius_km;
+        Ok((sun_km - position_km).normalize())
+    }
+}
+
+/// Builds the DCM whose rows are the provided body axes, expressed in the observer frame.
+fn body_dcm(
+    x: Vector3,
+    y: Vector3,
+    z: Vector3,
+    observer_frame: Frame,
+    body_frame_id: NaifId,
+) -> DCM {
+    DCM {
+        rot_mat: Matrix3::from_rows(&[x.transpose(), y.transpose(), z.transpose()]),
+        rot_mat_dt: None,
+        from: observer_frame.orientation_id,
+        to: body_frame_id,
+    }
+}
+
+/// Returns a unit vector perpendicular to the provided unit vector.
+fn any_perpendicular(v: &Vector3) -> Vector3 {
+    let candidate = if v.x.abs() < 0.9 {
+        Vector3::x()
+    } else {
+        Vector3::y()
+    };
+    v.cross(&candidate).normalize()
+}
+
+#[cfg(test)]
+mod ut_attitude {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::rotation::{EulerSequence, DCM};
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn attitude_laws() {
+        // The Sun is along +X of the Earth, and the spacecraft is above +Y moving along +Z.
+        let spk = test_spk(&[
+            linear_segment(10, 0, [0.0; 3], [0.0; 3]),
+            linear_segment(399, 0, [-1.5e8, 0.0, 0.0], [0.0; 3]),
+            linear_segment(-85, 399, [0.0, 7000.0, 0.0], [0.0, 0.0, 7.5]),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        let sc = Frame::from_ephem_j2000(-85);
+        let noon = Epoch::from_et_seconds(43_200.0);
+
+        let dcm = almanac
+            .nadir_yaw_steering_dcm(sc, EARTH_J2000, -85000, noon, None)
+            .unwrap();
+        assert_eq!((dcm.from, dcm.to), (1, -85000));
+        assert!(
+            (dcm.rot_mat * dcm.rot_mat.transpose() - DCM::identity(1, 1).rot_mat).norm() < 1e-12
+        );
+        // Z is nadir, and the Sun is in the X-Z plane on the +X side
+        let nadir = dcm.rot_mat * Vector3::new(0.0, -1.0, 0.0);
+        assert!((nadir - Vector3::z()).norm() < 1e-12);
+        let sun = (dcm.rot_mat * Vector3::new(1.5e8, -7000.0, 0.0)).normalize();
+        assert!(sun.y.abs() < 1e-12 && sun.x > 0.0);
+
+        let dcm = almanac
+            .sun_pointing_dcm(sc, EARTH_J2000, -85000, Vector3::z(), noon, None)
+            .unwrap();
+        let sun = (dcm.rot_mat * Vector3::new(1.5e8, -7000.0, 0.0)).normalize();
+        assert!((sun - Vector3::z()).norm() < 1e-12);
+        // The X axis is the roll constraint, which is perpendicular to the direction of the Sun
+        assert!((dcm.rot_mat.transpose() * Vector3::x() - Vector3::z()).norm() < 1e-12);
+        // A degenerate roll constraint still yields a valid rotation
+        let dcm = almanac
+            .sun_pointing_dcm(sc, EARTH_J2000, -85000, Vector3::x(), noon, None)
+            .unwrap();
+        assert!((dcm.rot_mat.determinant() - 1.0).abs() < 1e-12);
+
+        // The profiles are sampled at each step, and the yaw rotates as the spacecraft moves
+        let profile = almanac
+            .nadir_yaw_steering_profile(
+                sc,
+                EARTH_J2000,
+                -85000,
+                noon,
+                noon + Unit::Minute * 10,
+                Unit::Minute * 1,
+                None,
+            )
+            .unwrap();
+        assert_eq!(profile.len(), 11);
+        assert_eq!(profile[0].0, noon);
+        let first = DCM::from(profile[0].1);
+        let last = DCM::from(profile[10].1);
+        assert!(
+            (first.euler_angles_deg(EulerSequence::ZYX)
+                - last.euler_angles_deg(EulerSequence::ZYX))
+            .norm()
+                > 1.0
+        );
+        let profile = almanac
+            .sun_pointing_profile(
+                sc,
+                EARTH_J2000,
+                -85000,
+                Vector3::z(),
+                noon,
+                noon + Unit::Minute * 10,
+                Unit::Minute * 5,
+                None,
+            )
+            .unwrap();
+        assert_eq!(profile.len(), 3);
+    }
+}
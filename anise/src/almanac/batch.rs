@@ -0,0 +1,212 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::constants::orientations::J2000;
+use crate::errors::{AlmanacResult, EphemerisSnafu, OrientationSnafu};
+use crate::math::{cartesian::CartesianState, rotation::DCM};
+use crate::orientations::OrientationPhysicsSnafu;
+use crate::prelude::Frame;
+
+/// Frame chain evaluation from one frame to another at one epoch, shared by all of the states of a batch with that frame pair and epoch.
+struct SharedTransform {
+    /// Rotation into J2000, if the frames do not share their orientation
+    to_j2000: Option<DCM>,
+    /// State of the origin of the input frame with respect to the origin of the observer frame
+    frame_state: CartesianState,
+    /// Observer frame, with its planetary data if loaded
+    observer_frame: Frame,
+    to_observer: DCM,
+}
+
+impl SharedTransform {
+    /// Evaluates the chain exactly as [Almanac::transform_to] does without aberration correction.
+    fn new(
+        almanac: &Almanac,
+        from: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<Self> {
+        let action = "batch transform";
+        let (to_j2000, mid_frame) = if from.orient_origin_match(observer_frame) {
+            (None, from)
+        } else {
+            let mid_frame = from.with_orient(J2000);
+            let dcm = almanac
+                .rotate(from, mid_frame, epoch)
+                .context(OrientationSnafu { action })?;
+            (Some(dcm), mid_frame)
+        };
+
+        let frame_state = almanac
+            .translate(mid_frame, observer_frame, epoch, None)
+            .context(EphemerisSnafu { action })?;
+
+        let resolved_frame = almanac.frame_info(observer_frame).unwrap_or(observer_frame);
+        let to_observer = almanac
+            .rotate(
+                resolved_frame.with_orient(mid_frame.orientation_id),
+                observer_frame,
+                epoch,
+            )
+            .context(OrientationSnafu { action })?;
+
+        Ok(Self {
+            to_j2000,
+            frame_state,
+            observer_frame: resolved_frame,
+            to_observer,
+        })
+    }
+
+    fn apply(&self, mut state: CartesianState) -> AlmanacResult<CartesianState> {
+        let action = "batch transform";
+        if let Some(dcm) = self.to_j2000 {
+            state = (dcm * state)
+                .context(OrientationPhysicsSnafu {})
+                .context(OrientationSnafu { action })?;
+        }
+        let mut state = state.add_unchecked(&self.frame_state);
+        state.frame = self.observer_frame.with_orient(self.to_observer.from);
+
+        (self.to_observer * state)
+            .context(OrientationPhysicsSnafu {})
+            .context(OrientationSnafu { action })
+    }
+}
+
+impl Almanac {
+    /// Returns each of the provided states as seen from its paired observer frame (e.g. to normalize telemetry records in mixed frames
+    /// to J2000), without aberration correction, in the same order as the input. Each result is that of [Almanac::transform_to].
+    ///
+    /// The states are grouped by frame pair and processed chronologically within each group, such that the evaluation of the frame chain
+    /// (the translation between the origins of both frames and their rotations) is computed once for all of the states at the same epoch,
+    /// and consecutive evaluations use nearby ephemeris and orientation records. States in or observed from topocentric frames or traverses
+    /// are transformed individually.
+    pub fn transform_many(
+        &self,
+        states: &[(CartesianState, Frame)],
+    ) -> Vec<AlmanacResult<CartesianState>> {
+        let key = |(state, observer_frame): &(CartesianState, Frame)| {
+            (
+                state.frame.ephemeris_id,
+                state.frame.orientation_id,
+                observer_frame.ephemeris_id,
+                observer_frame.orientation_id,
+                state.epoch,
+            )
+        };
+
+        let mut order = (0..states.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| key(&states[i]));
+
+        let mut results = states.iter().map(|_| None).collect::<Vec<_>>();
+        for group in order.chunk_by(|&i, &j| key(&states[i]) == key(&states[j])) {
+            let (first, observer_frame) = states[group[0]];
+            let shared = if self.topocentric_frame(first.frame).is_some()
+                || self.topocentric_frame(observer_frame).is_some()
+                || self.traverse_from_id(first.frame.ephemeris_id).is_some()
+                || self.traverse_from_id(observer_frame.ephemeris_id).is_some()
+            {
+                None
+            } else {
+                SharedTransform::new(self, first.frame, observer_frame, first.epoch).ok()
+            };
+
+            for &i in group {
+                let (state, observer_frame) = states[i];
+                results[i] = Some(match &shared {
+                    Some(shared) => shared.apply(state),
+                    // Reports the error of each state, or handles the topocentric frames and the traverses.
+                    None => self.transform_to(state, observer_frame, None),
+                });
+            }
+        }
+
+        // Every index belongs to exactly one group.
+        results.into_iter().flatten().collect()
+    }
+}
+
+#[cfg(test)]
+mod ut_batch {
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME, IAU_MOON_FRAME, MOON_J2000};
+    use crate::naif::daf::test_utils::{linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use hifitime::Epoch;
+
+    #[test]
+    fn transform_many() {
+        let spk = test_spk(&[
+            linear_segment(399, 0, [1.5e8, 0.0, 0.0], [0.0, 30.0, 0.0]),
+            linear_segment(301, 399, [0.0, 384_400.0, 0.0], [-1.0, 0.0, 0.1]),
+        ]);
+        let almanac = Almanac::default()
+            .load("../data/pck08.pca")
+            .unwrap()
+            .with_spk(spk);
+
+        // Telemetry records in mixed frames, with several records per epoch, to be normalized to Earth J2000 or Moon J2000.
+        let frames = [EARTH_J2000, IAU_EARTH_FRAME, MOON_J2000, IAU_MOON_FRAME];
+        let mut records = Vec::new();
+        for k in 0..40 {
+            let epoch = Epoch::from_et_seconds(1_000.0 + 600.0 * (k % 7) as f64);
+            let state = Orbit::new(
+                7000.0 + k as f64,
+                -100.0 * k as f64,
+                50.0,
+                0.1,
+                7.5,
+                0.01 * k as f64,
+                epoch,
+                frames[k % frames.len()],
+            );
+            let observer = if k % 3 == 0 { MOON_J2000 } else { EARTH_J2000 };
+            records.push((state, observer));
+        }
+        // Unknown frames only fail their own records.
+        let unknown = Frame::from_ephem_j2000(-85);
+        records.insert(
+            5,
+            (
+                Orbit::from_position(7000.0, 0.0, 0.0, records[0].0.epoch, unknown),
+                EARTH_J2000,
+            ),
+        );
+
+        let results = almanac.transform_many(&records);
+        assert_eq!(results.len(), records.len());
+        for (i, ((state, observer), result)) in records.iter().zip(&results).enumerate() {
+            let expected = almanac.transform_to(*state, *observer, None);
+            if i == 5 {
+                assert!(result.is_err() && expected.is_err());
+                continue;
+            }
+            let (result, expected) = (result.as_ref().unwrap(), expected.unwrap());
+            assert_eq!(result.frame, expected.frame);
+            assert_eq!(result.epoch, state.epoch);
+            assert!(
+                (result.radius_km - expected.radius_km).norm() < 1e-6,
+                "{i}: {result} != {expected}"
+            );
+            assert!((result.velocity_km_s - expected.velocity_km_s).norm() < 1e-9);
+        }
+
+        // Same frames are unchanged
+        let mut state = records[0].0;
+        state.frame = EARTH_J2000;
+        let same = almanac.transform_many(&[(state, EARTH_J2000)]);
+        assert!((same[0].as_ref().unwrap().radius_km - state.radius_km).norm() < 1e-9);
+        assert!(almanac.transform_many(&[]).is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use bytes::BytesMut;
+use core::fmt;
+use log::warn;
+
+use super::Almanac;
+use crate::errors::{AlmanacError, AlmanacResult, InputOutputError};
+use crate::structure::dataset::{DataSet, DataSetError, DataSetT};
+
+/// Report of a best effort load, listing what was loaded, what was skipped within the loaded files, and what could not be loaded at all.
+#[derive(Debug, Default, PartialEq)]
+pub struct LoadReport {
+    /// Paths (or aliases) of the files that were loaded, possibly partially
+    pub loaded: Vec<String>,
+    /// Path (or alias) of each file and description of each segment or data item that was skipped in it
+    pub warnings: Vec<(String, String)>,
+    /// Path (or alias) of each file that could not be loaded at all, with the error
+    pub failures: Vec<(String, AlmanacError)>,
+}
+
+impl LoadReport {
+    /// Returns true if all of the files were loaded in full.
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty() && self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "loaded {} file(s) with {} warning(s), {} file(s) failed",
+            self.loaded.len(),
+            self.warnings.len(),
+            self.failures.len()
+        )?;
+        for (path, warning) in &self.warnings {
+            write!(f, "\n\twarning with {path}: {warning}")?;
+        }
+        for (path, err) in &self.failures {
+            write!(f, "\n\tfailed to load {path}: {err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    /// Loads all of the provided paths in best effort, guessing at the file type of each, and returns the report of the load.
+    ///
+    /// Unlike [Self::load], a damaged file does not prevent loading the other files:
+    /// + the segments of SPK and BPC files whose summary is invalid (unsupported data type, data out of the file, invalid time span) are skipped;
+    /// + the data items of ANISE datasets that cannot be decoded are skipped, and a checksum mismatch is only reported;
+    /// + files that cannot be read or decoded at all, or that fail the strict overlap check or the memory budget, are not loaded.
+    ///
+    /// Every skipped segment, data item, and file is listed in the report, and logged as a warning.
+    pub fn load_best_effort(mut self, paths: &[&str]) -> (Self, LoadReport) {
+        let mut report = LoadReport::default();
+
+        for path in paths {
+            let loaded = match std::fs::read(path) {
+                Err(e) => Err(AlmanacError::Loading {
+                    path: path.to_string(),
+                    source: InputOutputError::IOError { kind: e.kind() },
+                }),
+                Ok(bytes) => self.decode_best_effort(BytesMut::from(&bytes[..]), path, &mut report),
+            };
+
+            match loaded {
+                Ok(kernel) => {
+                    self = self.with_kernel_as(kernel, Some(path.to_string()));
+                    report.loaded.push(path.to_string());
+                }
+                Err(e) => {
+                    warn!("skipping {path}: {e}");
+                    report.failures.push((path.to_string(), e));
+                }
+            }
+        }
+
+        (self, report)
+    }
+
+    /// Loads the provided bytes in best effort under the provided alias, cf. [Self::load_best_effort].
+    ///
+    /// Returns an error only if the bytes cannot be loaded at all, and otherwise returns the description of the skipped segments or data items.
+    pub fn load_from_bytes_best_effort(
+        self,
+        bytes: BytesMut,
+        alias: &str,
+    ) -> AlmanacResult<(Self, Vec<String>)> {
+        let mut report = LoadReport::default();
+        let kernel = self.decode_best_effort(bytes, alias, &mut report)?;
+        let warnings = report.warnings.into_iter().map(|(_, w)| w).collect();
+        Ok((
+            self.with_kernel_as(kernel, Some(alias.to_string())),
+            warnings,
+        ))
+    }
+
+    fn decode_best_effort(
+        &self,
+        bytes: BytesMut,
+        alias: &str,
+        report: &mut LoadReport,
+    ) -> AlmanacResult<super::DecodedKernel> {
+        let mut warnings = Vec::new();
+        let kernel = self.decode_kernel(bytes, Some(alias), Some(&mut warnings))?;
+        self.check_memory_budget(&kernel, Some(alias))?;
+        for warning in warnings {
+            warn!("with {alias}: {warning}");
+            report.warnings.push((alias.to_string(), warning));
+        }
+        Ok(kernel)
+    }
+}
+
+/// Decodes a dataset, in best effort if warnings are provided.
+pub(super) fn decode_dataset<T: DataSetT>(
+    bytes: BytesMut,
+    warnings: Option<&mut Vec<String>>,
+) -> Result<DataSet<T>, DataSetError> {
+    match warnings {
+        Some(warnings) => {
+            let (dataset, skipped) = DataSet::try_from_bytes_best_effort(bytes)?;
+            warnings.extend(skipped);
+            Ok(dataset)
+        }
+        None => DataSet::try_from_bytes(bytes),
+    }
+}
+
+#[cfg(test)]
+mod ut_best_effort {
+    use super::Almanac;
+    use crate::file2heap;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::naif::daf::NAIFSummaryRecord;
+    use crate::naif::spk::summary::SPKSummaryRecord;
+    use bytes::BytesMut;
+
+    /// Builds the bytes of an SPK with two fixed segments of the Moon, the second one pointing beyond the end of the file.
+    fn damaged_spk_bytes() -> BytesMut {
+        let mut spk = test_spk(&[
+            fixed_segment(301, 399, [1.0, 0.0, 0.0]),
+            fixed_segment(301, 399, [1.0, 0.0, 0.0]),
+        ]);
+        let damaged = SPKSummaryRecord {
+            end_idx: 1_000_000,
+            ..spk.data_summaries(None).unwrap()[1]
+        };
+        spk.set_nth_summary(1, damaged).unwrap();
+        spk.bytes
+    }
+
+    #[test]
+    fn damaged_spk_segment() {
+        let (almanac, warnings) = Almanac::default()
+            .load_from_bytes_best_effort(damaged_spk_bytes(), "damaged")
+            .unwrap();
+
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].contains("segment #1 of ID 301"));
+        assert_eq!(almanac.num_loaded_spk(), 1);
+
+        let summaries = almanac.spk_data["damaged"].data_summaries(None).unwrap();
+        assert!(!summaries[0].is_empty());
+        // The damaged segment is skipped.
+        assert!(summaries[1].is_empty());
+    }
+
+    #[test]
+    fn damaged_dataset() {
+        let mut bytes = BytesMut::from(&file2heap!("../data/pck08.pca").unwrap()[..]);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x01;
+
+        assert!(Almanac::default().load_from_bytes(bytes.clone()).is_err());
+
+        let (almanac, warnings) = Almanac::default()
+            .load_from_bytes_best_effort(bytes, "damaged")
+            .unwrap();
+        assert!(!warnings.is_empty());
+        assert_eq!(almanac.planetary_data.len(), 1);
+        // The checksum is recomputed on load so that scrubbing checks against the loaded data.
+        assert!(almanac.planetary_data["damaged"].scrub().is_ok());
+    }
+
+    #[test]
+    fn multi_file() {
+        let (almanac, report) = Almanac::default().load_best_effort(&[
+            "../data/pck08.pca",
+            "../data/does-not-exist.bsp",
+            "../data/pck11.pca",
+        ]);
+
+        assert_eq!(almanac.planetary_data.len(), 2);
+        assert_eq!(report.loaded.len(), 2);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].0, "../data/does-not-exist.bsp");
+        assert!(!report.is_clean());
+    }
+}
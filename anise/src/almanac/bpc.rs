@@ -23,6 +23,7 @@ use crate::orientations::{NoOrientationsLoadedSnafu, OrientationError};
 use crate::{naif::daf::DAFError, NaifId};
 use log::{error, warn};
 
+use super::spk::union_windows;
 use super::Almanac;
 
 impl Almanac {
@@ -46,21 +47,41 @@ impl Almanac {
         if self.bpc_data.insert(alias, bpc).is_some() {
             warn!("{msg}");
         }
+        self.refresh_priority_order();
         self
     }
 
     /// Unloads the BPC with the provided alias.
     /// **WARNING:** This causes the order of the loaded files to be perturbed, which may be an issue if several SPKs with the same IDs are loaded.
     pub fn bpc_unload(&mut self, alias: &str) -> Result<(), OrientationError> {
+        self.kernel_priority.remove(alias);
         if self.bpc_data.swap_remove(alias).is_none() {
             Err(OrientationError::AliasNotFound {
                 alias: alias.to_string(),
                 action: "unload BPC",
             })
         } else {
+            self.refresh_priority_order();
             Ok(())
         }
     }
+
+    /// Unloads the BPC at the provided index in the loading order, i.e. zero is the first loaded BPC.
+    /// The loading order of the remaining BPCs is preserved.
+    pub fn bpc_unload_index(&mut self, index: usize) -> Result<(), OrientationError> {
+        match self.bpc_data.shift_remove_index(index) {
+            Some((alias, _)) => {
+                self.kernel_priority.remove(&alias);
+                self.refresh_priority_order();
+                Ok(())
+            }
+            None => Err(OrientationError::AliasNotFound {
+                alias: format!("BPC #{index}"),
+                action: "unload BPC",
+            }),
+        }
+    }
+
     pub fn num_loaded_bpc(&self) -> usize {
         self.bpc_data.len()
     }
@@ -71,10 +92,11 @@ impl Almanac {
         name: &str,
         epoch: Epoch,
     ) -> Result<(&BPCSummaryRecord, usize, Option<usize>, usize), OrientationError> {
-        for (no, bpc) in self.bpc_data.values().rev().enumerate() {
+        for no in self.bpc_precedence() {
+            let bpc = &self.bpc_data[no];
             if let Ok((summary, daf_idx, idx_in_bpc)) = bpc.summary_from_name_at_epoch(name, epoch)
             {
-                return Ok((summary, self.num_loaded_bpc() - no - 1, daf_idx, idx_in_bpc));
+                return Ok((summary, no, daf_idx, idx_in_bpc));
             }
         }
 
@@ -95,10 +117,10 @@ impl Almanac {
         id: i32,
         epoch: Epoch,
     ) -> Result<(&BPCSummaryRecord, usize, Option<usize>, usize), OrientationError> {
-        for (no, bpc) in self.bpc_data.values().rev().enumerate() {
+        for no in self.bpc_precedence() {
+            let bpc = &self.bpc_data[no];
             if let Ok((summary, daf_idx, idx_in_bpc)) = bpc.summary_from_id_at_epoch(id, epoch) {
-                // NOTE: We're iterating backward, so the correct BPC number is "total loaded" minus "current iteration".
-                return Ok((summary, self.num_loaded_bpc() - no - 1, daf_idx, idx_in_bpc));
+                return Ok((summary, no, daf_idx, idx_in_bpc));
             }
         }
 
@@ -123,9 +145,10 @@ impl Almanac {
         &self,
         name: &str,
     ) -> Result<(&BPCSummaryRecord, usize, Option<usize>, usize), OrientationError> {
-        for (no, bpc) in self.bpc_data.values().rev().enumerate() {
+        for no in self.bpc_precedence() {
+            let bpc = &self.bpc_data[no];
             if let Ok((summary, daf_idx, idx_in_bpc)) = bpc.summary_from_name(name) {
-                return Ok((summary, self.num_loaded_bpc() - no - 1, daf_idx, idx_in_bpc));
+                return Ok((summary, no, daf_idx, idx_in_bpc));
             }
         }
 
@@ -144,10 +167,10 @@ impl Almanac {
         &self,
         id: i32,
     ) -> Result<(&BPCSummaryRecord, usize, Option<usize>, usize), OrientationError> {
-        for (no, bpc) in self.bpc_data.values().rev().enumerate() {
+        for no in self.bpc_precedence() {
+            let bpc = &self.bpc_data[no];
             if let Ok((summary, daf_idx, idx_in_bpc)) = bpc.summary_from_id(id) {
-                // NOTE: We're iterating backward, so the correct BPC number is "total loaded" minus "current iteration".
-                return Ok((summary, self.num_loaded_bpc() - no - 1, daf_idx, idx_in_bpc));
+                return Ok((summary, no, daf_idx, idx_in_bpc));
             }
         }
 
@@ -171,7 +194,8 @@ impl Almanac {
     pub fn bpc_summaries(&self, id: NaifId) -> Result<Vec<BPCSummaryRecord>, OrientationError> {
         let mut summaries = vec![];
 
-        for bpc in self.bpc_data.values().rev() {
+        for no in self.bpc_precedence() {
+            let bpc = &self.bpc_data[no];
             for these_summaries in bpc.iter_summary_blocks().flatten() {
                 for summary in these_summaries {
                     if summary.id() == id {
@@ -215,6 +239,24 @@ impl Almanac {
         Ok((start, end))
     }
 
+    /// Returns the time windows during which the provided frame ID has orientation data in any of the loaded BPCs, i.e. the union
+    /// of the windows of all of its segments, sorted chronologically. This is equivalent to SPICE's `ckcov`/`pckcov` across all loaded files.
+    ///
+    /// Unlike `bpc_domain`, this reports any gaps between segments, e.g. when an ITRF93 prediction BPC does not follow the historical one.
+    ///
+    /// :type id: int
+    /// :rtype: typing.List
+    pub fn bpc_coverage(&self, id: NaifId) -> Result<Vec<(Epoch, Epoch)>, OrientationError> {
+        let summaries = self.bpc_summaries(id)?;
+
+        Ok(union_windows(
+            summaries
+                .iter()
+                .map(|summary| (summary.start_epoch(), summary.end_epoch()))
+                .collect(),
+        ))
+    }
+
     /// Returns a map of each loaded BPC ID to its domain validity.
     ///
     /// # Warning
@@ -248,6 +290,27 @@ impl Almanac {
 
         Ok(domains)
     }
+
+    /// Returns the decoded text of the comment area of the BPC loaded with the provided alias, if that BPC has any comments.
+    ///
+    /// Mission kernels typically document their provenance and usage constraints in the comment area.
+    ///
+    /// :type alias: str
+    /// :rtype: typing.Optional[str]
+    pub fn bpc_comments(&self, alias: &str) -> Result<Option<String>, OrientationError> {
+        let bpc = self
+            .bpc_data
+            .get(alias)
+            .ok_or_else(|| OrientationError::AliasNotFound {
+                alias: alias.to_string(),
+                action: "read BPC comments",
+            })?;
+
+        bpc.comments().map_err(|source| OrientationError::BPC {
+            action: "reading BPC comments",
+            source,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -278,5 +341,13 @@ mod ut_almanac_bpc {
                 .is_err(),
             "empty Almanac should report an error"
         );
+        assert!(
+            almanac.bpc_comments("invalid alias").is_err(),
+            "empty Almanac should report an error"
+        );
+        assert!(
+            almanac.bpc_coverage(3000).is_err(),
+            "empty Almanac should report an error"
+        );
     }
 }
@@ -0,0 +1,171 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Helpers for downstream build scripts, to check at compile time that the frames used by a crate exist in its data package.
+//!
+//! ```ignore
+//! // build.rs
+//! use anise::almanac::build_checks::require_frames;
+//! use anise::constants::frames::{EARTH_J2000, MOON_J2000};
+//!
+//! fn main() {
+//!     require_frames(&["data/de440s.bsp", "data/pck08.pca"], &[EARTH_J2000, MOON_J2000]);
+//! }
+//! ```
+
+use core::fmt;
+
+use super::Almanac;
+use crate::constants::orientations::{ECLIPJ2000, J2000};
+use crate::errors::AlmanacResult;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+/// A frame whose ephemeris or orientation is not provided by the loaded data.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MissingFrame {
+    pub frame: Frame,
+    /// Set if no SPK provides the ephemeris of this frame
+    pub ephemeris: bool,
+    /// Set if neither a BPC, the planetary data, nor the Euler parameters provide the orientation of this frame
+    pub orientation: bool,
+}
+
+impl fmt::Display for MissingFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match (self.ephemeris, self.orientation) {
+            (true, true) => "ephemeris and orientation",
+            (true, false) => "ephemeris",
+            _ => "orientation",
+        };
+        write!(f, "{}: no {what} data", self.frame)
+    }
+}
+
+impl Almanac {
+    /// Returns whether the loaded SPKs provide the ephemeris of the provided ID, either as the target of a segment or as the root.
+    pub fn has_ephemeris(&self, id: NaifId) -> bool {
+        self.spk_summaries(id).is_ok()
+            || self.try_find_ephemeris_root().is_ok_and(|root| root == id)
+    }
+
+    /// Returns whether the loaded data provides the orientation of the provided ID, including the built-in inertial frames.
+    pub fn has_orientation(&self, id: NaifId) -> bool {
+        id == J2000
+            || id == ECLIPJ2000
+            || self.bpc_summaries(id).is_ok()
+            || self.get_planetary_data_from_id(id).is_ok()
+            || self
+                .euler_param_data
+                .values()
+                .any(|data| data.get_by_id(id).is_ok())
+    }
+
+    /// Returns the frames of the provided list whose ephemeris or orientation is not provided by the loaded data.
+    pub fn missing_frames(&self, frames: &[Frame]) -> Vec<MissingFrame> {
+        frames
+            .iter()
+            .filter_map(|frame| {
+                let missing = MissingFrame {
+                    frame: *frame,
+                    ephemeris: !self.has_ephemeris(frame.ephemeris_id),
+                    orientation: !self.has_orientation(frame.orientation_id),
+                };
+                (missing.ephemeris || missing.orientation).then_some(missing)
+            })
+            .collect()
+    }
+}
+
+/// Loads the provided data files and returns the frames of the provided list which they do not fully define.
+///
+/// Each path is printed as a `cargo:rerun-if-changed` directive, so the build script is rerun when the data package changes.
+pub fn check_frames<P: AsRef<str>>(
+    paths: &[P],
+    frames: &[Frame],
+) -> AlmanacResult<Vec<MissingFrame>> {
+    let mut almanac = Almanac::default();
+    for path in paths {
+        let path = path.as_ref();
+        println!("cargo:rerun-if-changed={path}");
+        almanac = almanac.load(path)?;
+    }
+    Ok(almanac.missing_frames(frames))
+}
+
+/// Same as `check_frames` but panics, and therefore fails the compilation when called from a build script, if any data file
+/// cannot be loaded or any frame is missing.
+pub fn require_frames<P: AsRef<str>>(paths: &[P], frames: &[Frame]) {
+    let missing = check_frames(paths, frames).unwrap_or_else(|e| panic!("{e}"));
+    if !missing.is_empty() {
+        let list = missing
+            .iter()
+            .map(|m| format!("  - {m}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!(
+            "{} frame(s) not defined by the data package:\n{list}",
+            missing.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod ut_build_checks {
+    use super::{check_frames, require_frames};
+    use crate::constants::frames::{EARTH_J2000, EME2000, IAU_EARTH_FRAME, MOON_J2000, SSB_J2000};
+    use crate::constants::orientations::ECLIPJ2000;
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::naif::SPK;
+    use crate::prelude::Almanac;
+
+    /// Builds an SPK with a single Earth segment centered on the SSB.
+    fn earth_spk() -> SPK {
+        test_spk(&[chebyshev_segment(
+            399,
+            0,
+            0.0,
+            86_400.0,
+            &[[[1000.0, 1.0], [0.0; 2], [0.0; 2]]],
+        )])
+    }
+
+    #[test]
+    fn missing_frames() {
+        let almanac = Almanac::default().with_spk(earth_spk());
+
+        assert!(almanac.has_ephemeris(399));
+        assert!(almanac.has_ephemeris(0));
+        assert!(!almanac.has_ephemeris(301));
+        assert!(almanac.has_orientation(ECLIPJ2000));
+
+        assert!(almanac
+            .missing_frames(&[EARTH_J2000, SSB_J2000, EME2000])
+            .is_empty());
+
+        let missing = almanac.missing_frames(&[MOON_J2000, IAU_EARTH_FRAME]);
+        assert_eq!(missing.len(), 2);
+        assert!(missing[0].ephemeris && !missing[0].orientation);
+        assert!(!missing[1].ephemeris && missing[1].orientation);
+        assert!(format!("{}", missing[0]).ends_with("no ephemeris data"));
+    }
+
+    #[test]
+    fn unloadable_package() {
+        assert!(check_frames(&["not/a/file.bsp"], &[EARTH_J2000]).is_err());
+        assert!(check_frames::<&str>(&[], &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "1 frame(s) not defined by the data package")]
+    fn require_frames_panics() {
+        require_frames::<&str>(&[], &[EARTH_J2000]);
+    }
+}
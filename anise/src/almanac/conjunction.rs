@@ -0,0 +1,212 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch, TimeSeries};
+
+use super::Almanac;
+use crate::errors::{AlmanacError, AlmanacResult};
+use crate::math::{cartesian::CartesianState, Vector3};
+use crate::prelude::Frame;
+
+/// Precision of the epoch of a closest approach.
+const CA_EPOCH_PRECISION: Duration = Duration::from_parts(0, 1_000);
+
+/// A local minimum of the distance between two objects, as found by [Almanac::close_approaches].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CloseApproach {
+    /// Time of closest approach
+    pub epoch: Epoch,
+    /// Position of the target with respect to the observer at the time of closest approach, in the observer frame
+    pub relative_position_km: Vector3,
+    /// Velocity of the target with respect to the observer at the time of closest approach, in the observer frame
+    pub relative_velocity_km_s: Vector3,
+}
+
+impl CloseApproach {
+    /// Returns the distance between both objects at the time of closest approach.
+    pub fn miss_distance_km(&self) -> f64 {
+        self.relative_position_km.norm()
+    }
+
+    /// Returns the relative speed of both objects at the time of closest approach.
+    pub fn relative_speed_km_s(&self) -> f64 {
+        self.relative_velocity_km_s.norm()
+    }
+}
+
+impl fmt::Display for CloseApproach {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TCA {}: miss distance {:.6} km at {:.6} km/s",
+            self.epoch,
+            self.miss_distance_km(),
+            self.relative_speed_km_s()
+        )
+    }
+}
+
+/// Returns the rate of change of the distance of the relative state.
+fn range_rate_km_s(state: &CartesianState) -> f64 {
+    state.radius_km.dot(&state.velocity_km_s) / state.rmag_km()
+}
+
+impl Almanac {
+    /// Finds the closest approaches of the target to the observer (e.g. two spacecraft, or a spacecraft and a body) between the start and
+    /// end epochs, sorted chronologically, keeping only those closer than the maximum miss distance, if provided.
+    ///
+    /// # Algorithm
+    /// The range-rate between both objects is sampled at the provided step (the coarse grid), and each change of its sign from negative
+    /// to positive brackets a local minimum of the distance, whose epoch is then refined by bisection to within a microsecond. The step
+    /// must be small compared to the time between successive approaches (e.g. a fraction of the orbital period), or some approaches may
+    /// be missed. Minima of the distance at the bounds of the window are not approaches, and are not reported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_approaches(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        max_miss_distance_km: Option<f64>,
+    ) -> AlmanacResult<Vec<CloseApproach>> {
+        if end_epoch <= start_epoch || step <= Duration::ZERO {
+            return Err(AlmanacError::GenericError {
+                err: format!(
+                    "close approach search requires ordered epochs ({start_epoch} to {end_epoch}) and a positive step ({step})"
+                ),
+            });
+        }
+
+        let relative = |epoch: Epoch| self.transform(target_frame, observer_frame, epoch, None);
+
+        let mut approaches = Vec::new();
+        let mut prev: Option<(Epoch, f64)> = None;
+        for epoch in
+            TimeSeries::inclusive(start_epoch, end_epoch, step).chain(core::iter::once(end_epoch))
+        {
+            if prev.is_some_and(|(prev_epoch, _)| prev_epoch == epoch) {
+                // The end epoch is already in the time series.
+                continue;
+            }
+            let rate = range_rate_km_s(&relative(epoch)?);
+
+            if let Some((prev_epoch, prev_rate)) = prev {
+                if prev_rate < 0.0 && rate >= 0.0 {
+                    // Bisection of the range-rate, which is negative before and positive after the closest approach.
+                    let (mut low, mut high) = (prev_epoch, epoch);
+                    while high - low > CA_EPOCH_PRECISION {
+                        let mid = low + (high - low) * 0.5;
+                        if range_rate_km_s(&relative(mid)?) < 0.0 {
+                            low = mid;
+                        } else {
+                            high = mid;
+                        }
+                    }
+
+                    let state = relative(low + (high - low) * 0.5)?;
+                    if max_miss_distance_km.is_none_or(|max_km| state.rmag_km() <= max_km) {
+                        approaches.push(CloseApproach {
+                            epoch: state.epoch,
+                            relative_position_km: state.radius_km,
+                            relative_velocity_km_s: state.velocity_km_s,
+                        });
+                    }
+                }
+            }
+
+            prev = Some((epoch, rate));
+        }
+
+        Ok(approaches)
+    }
+}
+
+#[cfg(test)]
+mod ut_conjunction {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn close_approaches() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        // Two circular orbits of the same radius in different planes cross each other twice per orbit, every half period.
+        let primary = Orbit::try_keplerian(7000.0, 0.0, 51.6, 0.0, 0.0, 0.0, epoch, frame).unwrap();
+        let secondary =
+            Orbit::try_keplerian(7000.0, 0.0, 98.0, 0.0, 0.0, 0.5, epoch, frame).unwrap();
+
+        let mut almanac = Almanac::default();
+        for (naif_id, orbit) in [(-10, primary), (-11, secondary)] {
+            let mut ephem = Ephemeris::new(format!("{naif_id}"));
+            for minute in 0..=300 {
+                ephem.insert_orbit(
+                    orbit
+                        .propagate_to_epoch(epoch + Unit::Minute * minute)
+                        .unwrap(),
+                );
+            }
+            almanac = almanac.with_spk(
+                ephem
+                    .to_spice_bsp(naif_id, Some(DafDataType::Type13HermiteUnequalStep))
+                    .unwrap(),
+            );
+        }
+
+        let (target, observer) = (Frame::from_ephem_j2000(-11), Frame::from_ephem_j2000(-10));
+        let end = epoch + Unit::Hour * 5;
+        let approaches = almanac
+            .close_approaches(target, observer, epoch, end, Unit::Minute * 5, None)
+            .unwrap();
+        for ca in &approaches {
+            println!("{ca}");
+        }
+
+        // The secondary leads by half a degree, so the closest approaches straddle the crossings of the orbits, every half period.
+        let half_period = primary.period().unwrap() * 0.5;
+        let lead = primary.period().unwrap() * (0.25 / 360.0);
+        assert_eq!(approaches.len(), 6);
+        for (k, ca) in approaches.iter().enumerate() {
+            let expected = epoch + half_period * (k + 1) as f64 - lead;
+            assert!((ca.epoch - expected).abs() < Unit::Second * 1, "{ca}");
+            assert!((50.0..61.0).contains(&ca.miss_distance_km()), "{ca}");
+            // Local minimum of the distance, where the separation is perpendicular to the relative velocity
+            let range_rate_km_s =
+                ca.relative_position_km.dot(&ca.relative_velocity_km_s) / ca.miss_distance_km();
+            assert!(range_rate_km_s.abs() < 1e-5, "{range_rate_km_s}");
+            for offset in [Unit::Second * -1, Unit::Second * 1] {
+                let state = almanac
+                    .transform(target, observer, ca.epoch + offset, None)
+                    .unwrap();
+                assert!(state.rmag_km() > ca.miss_distance_km());
+            }
+        }
+
+        // Screening threshold
+        assert!(almanac
+            .close_approaches(target, observer, epoch, end, Unit::Minute * 5, Some(40.0))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            almanac
+                .close_approaches(target, observer, epoch, end, Unit::Minute * 5, Some(100.0))
+                .unwrap()
+                .len(),
+            6
+        );
+        assert!(almanac
+            .close_approaches(target, observer, end, epoch, Unit::Minute * 5, None)
+            .is_err());
+    }
+}
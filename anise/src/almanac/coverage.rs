@@ -0,0 +1,238 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::{Duration, Epoch};
+use snafu::ResultExt;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::spk::intersect_windows;
+use super::Almanac;
+use crate::ephemerides::{paths::MAX_TREE_DEPTH, EphemerisError};
+use crate::errors::{AlmanacError, AlmanacResult, EphemerisSnafu};
+use crate::naif::daf::DAFError;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+impl Almanac {
+    /// Returns the IDs from the provided ID up to (excluding) the ephemeris root, following the centers of the segments used first.
+    fn ephemeris_chain(&self, id: NaifId, root: NaifId) -> Result<Vec<NaifId>, EphemerisError> {
+        let mut chain = Vec::new();
+        let mut id = id;
+        for _ in 0..MAX_TREE_DEPTH {
+            if id == root {
+                return Ok(chain);
+            }
+            chain.push(id);
+            // The summaries are never empty, or this would have returned an error.
+            id = self.spk_summaries(id)?[0].center_id;
+        }
+
+        Err(EphemerisError::SPK {
+            action: "computing path to ephemeris root",
+            source: DAFError::MaxRecursionDepth,
+        })
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Almanac {
+    /// Returns the time windows during which the loaded data allows computing the state of the target frame as seen from the
+    /// observer frame, sorted chronologically.
+    ///
+    /// This is the intersection of the SPK coverage of all of the ephemerides between both frames and their common node, and of
+    /// the BPC coverage of the orientations of both frames, if any. If neither restricts the epochs (e.g. the same frame), this is
+    /// a single window spanning all of the representable epochs. An empty list means that both frames are never simultaneously covered.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :rtype: typing.List
+    pub fn frame_coverage(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+    ) -> AlmanacResult<Vec<(Epoch, Epoch)>> {
+        let mut coverage = vec![(
+            Epoch::from_tai_duration(Duration::MIN),
+            Epoch::from_tai_duration(Duration::MAX),
+        )];
+
+        if target_frame.ephemeris_id != observer_frame.ephemeris_id {
+            let action = "computing frame coverage";
+            let root = self
+                .try_find_ephemeris_root()
+                .context(EphemerisSnafu { action })?;
+            let target_chain = self
+                .ephemeris_chain(target_frame.ephemeris_id, root)
+                .context(EphemerisSnafu { action })?;
+            let observer_chain = self
+                .ephemeris_chain(observer_frame.ephemeris_id, root)
+                .context(EphemerisSnafu { action })?;
+
+            // The ephemerides above the common node are not needed.
+            for id in target_chain
+                .iter()
+                .filter(|id| !observer_chain.contains(id))
+                .chain(
+                    observer_chain
+                        .iter()
+                        .filter(|id| !target_chain.contains(id)),
+                )
+            {
+                let id_coverage = self.spk_coverage(*id).context(EphemerisSnafu { action })?;
+                coverage = intersect_windows(&coverage, &id_coverage);
+            }
+        }
+
+        if target_frame.orientation_id != observer_frame.orientation_id {
+            for id in [target_frame.orientation_id, observer_frame.orientation_id] {
+                // Orientations which are not in a BPC are computed from the planetary data at any epoch.
+                if let Ok(id_coverage) = self.bpc_coverage(id) {
+                    coverage = intersect_windows(&coverage, &id_coverage);
+                }
+            }
+        }
+
+        Ok(coverage)
+    }
+
+    /// Returns the epoch nearest to the provided epoch at which the state of the target frame as seen from the observer frame can
+    /// be computed, and the clamping distance from the provided epoch to that epoch (positive if later), cf. [Self::frame_coverage].
+    ///
+    /// This is meant for user interfaces which snap a requested epoch into the loaded data instead of reporting an error: an epoch
+    /// in a gap of the coverage is clamped to the nearest end of the surrounding windows, and an epoch within the coverage is
+    /// returned as is with a zero distance.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type epoch: Epoch
+    /// :rtype: typing.Tuple
+    pub fn clamp_to_coverage(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<(Epoch, Duration)> {
+        let coverage = self.frame_coverage(target_frame, observer_frame)?;
+
+        let clamped = coverage
+            .iter()
+            .map(|&(start, end)| {
+                if epoch < start {
+                    start
+                } else if epoch > end {
+                    end
+                } else {
+                    epoch
+                }
+            })
+            .min_by_key(|clamped| (*clamped - epoch).abs())
+            .ok_or_else(|| AlmanacError::GenericError {
+                err: format!(
+                    "{target_frame:x} and {observer_frame:x} have no common coverage in the loaded data"
+                ),
+            })?;
+
+        Ok((clamped, clamped - epoch))
+    }
+}
+
+#[cfg(test)]
+mod ut_coverage {
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME, MOON_J2000, SSB_J2000};
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Duration, Epoch, Unit};
+
+    #[test]
+    fn clamp_to_coverage() {
+        // Fixed position with respect to the center, between the provided TDB seconds past J2000
+        let fixed = |target_id, center_id, start_s: f64, end_s: f64| {
+            chebyshev_segment(
+                target_id,
+                center_id,
+                start_s,
+                end_s - start_s,
+                &[[[1.0e4], [0.0], [0.0]]],
+            )
+        };
+        let spk = test_spk(&[
+            fixed(399, 0, 0.0, 86_400.0),
+            fixed(301, 399, 10_000.0, 50_000.0),
+            fixed(-85, 399, 0.0, 30_000.0),
+            fixed(-85, 399, 40_000.0, 86_400.0),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        let sc = Frame::from_ephem_j2000(-85);
+        let et = Epoch::from_et_seconds;
+        // The epochs of the summaries are within a nanosecond of their TDB seconds.
+        let assert_windows = |windows: Vec<(Epoch, Epoch)>, expected: &[(f64, f64)]| {
+            assert_eq!(windows.len(), expected.len(), "{windows:?}");
+            for ((start, end), (start_s, end_s)) in windows.into_iter().zip(expected) {
+                assert!((start - et(*start_s)).abs() < Unit::Microsecond * 1);
+                assert!((end - et(*end_s)).abs() < Unit::Microsecond * 1);
+            }
+        };
+
+        // Only the ephemerides below the common node restrict the coverage.
+        assert_windows(
+            almanac.frame_coverage(sc, EARTH_J2000).unwrap(),
+            &[(0.0, 30_000.0), (40_000.0, 86_400.0)],
+        );
+        assert_eq!(
+            almanac.frame_coverage(sc, SSB_J2000).unwrap(),
+            almanac.frame_coverage(sc, EARTH_J2000).unwrap()
+        );
+        assert_windows(
+            almanac.frame_coverage(sc, MOON_J2000).unwrap(),
+            &[(10_000.0, 30_000.0), (40_000.0, 50_000.0)],
+        );
+
+        // Epochs in the coverage are unchanged, and others snap to the nearest covered epoch.
+        for (epoch_s, clamped_s) in [
+            (20_000.0, 20_000.0),
+            (5_000.0, 10_000.0),
+            (33_000.0, 30_000.0),
+            (36_000.0, 40_000.0),
+            (70_000.0, 50_000.0),
+        ] {
+            let (clamped, distance) = almanac
+                .clamp_to_coverage(sc, MOON_J2000, et(epoch_s))
+                .unwrap();
+            assert!((clamped - et(clamped_s)).abs() < Unit::Microsecond * 1);
+            assert_eq!(clamped, et(epoch_s) + distance);
+            assert!(
+                (distance.to_seconds() - (clamped_s - epoch_s)).abs() < 1e-6,
+                "{distance}"
+            );
+            // The clamped epoch can be queried
+            almanac.translate(sc, MOON_J2000, clamped, None).unwrap();
+        }
+
+        // The coverage of a frame with itself is not restricted, even in a body fixed frame.
+        let epoch = et(1.0e9);
+        assert_eq!(
+            almanac
+                .clamp_to_coverage(EARTH_J2000, IAU_EARTH_FRAME, epoch)
+                .unwrap(),
+            (epoch, Duration::ZERO)
+        );
+
+        // Unknown frames are errors, and so are frames never covered simultaneously.
+        assert!(almanac
+            .clamp_to_coverage(sc, Frame::from_ephem_j2000(599), epoch)
+            .is_err());
+        let spk = test_spk(&[fixed(399, 0, 0.0, 100.0), fixed(-85, 0, 200.0, 300.0)]);
+        let disjoint = Almanac::default().with_spk(spk);
+        assert!(disjoint.frame_coverage(sc, EARTH_J2000).unwrap().is_empty());
+        assert!(disjoint.clamp_to_coverage(sc, EARTH_J2000, epoch).is_err());
+    }
+}
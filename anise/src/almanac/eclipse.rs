@@ -8,15 +8,16 @@
  * Documentation: https://nyxspace.com/
  */
 
-use hifitime::{Duration, Unit};
+use hifitime::{Duration, Epoch, TimeSeries, Unit};
 use log::error;
 
 use crate::{
-    astro::{Aberration, Occultation},
+    astro::{Aberration, EclipseWindow, Occultation, OccultationKind, OccultationWindow},
     constants::{frames::SUN_J2000, orientations::J2000},
     ephemerides::EphemerisPhysicsSnafu,
-    errors::{AlmanacError, EphemerisSnafu, OrientationSnafu},
+    errors::{AlmanacError, AlmanacPhysicsSnafu, EphemerisSnafu, OrientationSnafu, PhysicsError},
     frames::Frame,
+    math::Vector3,
     prelude::Orbit,
 };
 
@@ -105,6 +106,99 @@ impl Almanac {
         }
     }
 
+    /// Returns the first of the occulting bodies whose ellipsoid blocks the line of sight from the origin of the observer frame to
+    /// the origin of the target frame at the provided epoch, or None if the line of sight is clear.
+    ///
+    /// Each body is modeled by the ellipsoid of its frame, fetched from the loaded planetary data if the frame does not define it.
+    /// The axes of the ellipsoid are those of the frame, so use body fixed frames (e.g. IAU Earth) to account for the orientation
+    /// of oblate bodies. Only the segment between the observer and the target is checked, so a body behind the target does not
+    /// block the line of sight, and a body whose center is the observer or the target is ignored.
+    pub fn line_of_sight(
+        &self,
+        observer: Frame,
+        target: Frame,
+        epoch: Epoch,
+        occulting_bodies: &[Frame],
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Option<Frame>> {
+        for body in occulting_bodies.iter().copied() {
+            if body.ephem_origin_match(observer) || body.ephem_origin_match(target) {
+                continue;
+            }
+
+            let body = if body.shape.is_some() {
+                body
+            } else {
+                self.frame_info(body)
+                    .map_err(|e| AlmanacError::GenericError {
+                        err: format!("{e} when fetching {body:e} frame data"),
+                    })?
+            };
+            let shape = body
+                .shape
+                .ok_or(PhysicsError::MissingFrameData {
+                    action: "computing line of sight",
+                    data: "shape",
+                    frame: body.into(),
+                })
+                .context(AlmanacPhysicsSnafu {
+                    action: "computing line of sight",
+                })?;
+
+            // Scale the positions by the semi axes of the ellipsoid, which becomes the unit sphere.
+            let axes = Vector3::new(
+                shape.semi_major_equatorial_radius_km,
+                shape.semi_minor_equatorial_radius_km,
+                shape.polar_radius_km,
+            );
+            let start = self
+                .transform(observer, body, epoch, ab_corr)?
+                .radius_km
+                .component_div(&axes);
+            let end = self
+                .transform(target, body, epoch, ab_corr)?
+                .radius_km
+                .component_div(&axes);
+
+            // Closest point of the segment to the center of the unit sphere
+            let delta = end - start;
+            let t = if delta.norm_squared() > 0.0 {
+                (-start.dot(&delta) / delta.norm_squared()).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            if (start + t * delta).norm_squared() < 1.0 {
+                return Ok(Some(body));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Searches for the time intervals when the line of sight from the origin of the observer frame to the origin of the target
+    /// frame is not blocked by any of the occulting bodies, cf. [Almanac::line_of_sight], between the start and end epochs.
+    ///
+    /// The time span is sampled at the provided step, which must be shorter than the shortest window to find, and the bounds of
+    /// each window are then refined by bisection to ten milliseconds. Windows in progress at the start or the end epoch are
+    /// clipped to these epochs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn line_of_sight_windows(
+        &self,
+        observer: Frame,
+        target: Frame,
+        occulting_bodies: &[Frame],
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Epoch)>> {
+        find_windows(start_epoch, end_epoch, step, |epoch| {
+            Ok(self
+                .line_of_sight(observer, target, epoch, occulting_bodies, ab_corr)?
+                .is_none())
+        })
+    }
+
     /// Computes the occultation percentage of the `back_frame` object by the `front_frame` object as seen from the observer, when according for the provided aberration correction.
     ///
     /// A zero percent occultation means that the back object is fully visible from the observer.
@@ -115,7 +209,7 @@ impl Almanac {
         &self,
         mut back_frame: Frame,
         mut front_frame: Frame,
-        mut observer: Orbit,
+        observer: Orbit,
         ab_corr: Option<Aberration>,
     ) -> AlmanacResult<Occultation> {
         if back_frame.mean_equatorial_radius_km().is_err() {
@@ -162,58 +256,231 @@ impl Almanac {
             });
         }
 
-        // All of the computations happen with the observer as the center.
-        // `eb` stands for front object; `ls` stands for back object.
-        // Get the radius vector of the spacecraft to the front object
+        let disks = self.apparent_disks(back_frame, front_frame, observer, ab_corr)?;
 
-        // Ensure that the observer is in the J2000 frame.
-        observer = self
-            .rotate_to(observer, observer.frame.with_orient(J2000))
-            .context(OrientationSnafu {
-                action: "computing eclipse state",
-            })?;
-        let r_eb = self
-            .transform_to(observer, front_frame.with_orient(J2000), ab_corr)?
-            .radius_km;
+        let percentage = compute_occultation_percentage(
+            disks.separation_rad,
+            disks.back_radius_rad,
+            disks.front_radius_rad,
+        )?;
 
-        // Get the radius vector of the back object to the spacecraft
-        let r_ls = -self
-            .transform_to(observer, back_frame.with_orient(J2000), ab_corr)?
-            .radius_km;
+        Ok(Occultation {
+            epoch,
+            percentage,
+            back_frame,
+            front_frame,
+        })
+    }
 
-        // Compute the apparent radii of the back object and front object (preventing any NaN)
-        let r_ls_prime = if bobj_mean_eq_radius_km >= r_ls.norm() {
-            core::f64::consts::FRAC_PI_2
+    /// Classifies the occultation of the `back_frame` object by the `front_frame` object as seen from the observer, modeling both objects
+    /// as spheres of their mean equatorial radius. This is the equivalent of the SPICE `occult` routine.
+    ///
+    /// The back object is visible if the front object is further from the observer than the back object, even if their disks overlap.
+    ///
+    /// :type back_frame: Frame
+    /// :type front_frame: Frame
+    /// :type observer: Orbit
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: OccultationKind
+    pub fn occultation_kind(
+        &self,
+        mut back_frame: Frame,
+        mut front_frame: Frame,
+        observer: Orbit,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<OccultationKind> {
+        if back_frame.mean_equatorial_radius_km().is_err() {
+            back_frame = self
+                .frame_info(back_frame)
+                .map_err(|e| AlmanacError::GenericError {
+                    err: format!("{e} when fetching {back_frame:e} frame data"),
+                })?;
+        }
+
+        if front_frame.mean_equatorial_radius_km().is_err() {
+            front_frame = self
+                .frame_info(front_frame)
+                .map_err(|e| AlmanacError::GenericError {
+                    err: format!("{e} when fetching {front_frame:e} frame data"),
+                })?;
+        }
+
+        let disks = self.apparent_disks(back_frame, front_frame, observer, ab_corr)?;
+
+        let kind = if disks.front_distance_km > disks.back_distance_km
+            || disks.separation_rad >= disks.back_radius_rad + disks.front_radius_rad
+        {
+            OccultationKind::Visible
+        } else if disks.front_radius_rad >= disks.separation_rad + disks.back_radius_rad {
+            OccultationKind::Total
+        } else if disks.back_radius_rad >= disks.separation_rad + disks.front_radius_rad {
+            OccultationKind::Annular
         } else {
-            (bobj_mean_eq_radius_km / r_ls.norm()).asin()
+            OccultationKind::Partial
         };
 
-        let fobj_mean_eq_radius_km = front_frame
-            .mean_equatorial_radius_km()
-            .context(EphemerisPhysicsSnafu {
-                action: "fetching mean equatorial radius of front object",
-            })
-            .context(EphemerisSnafu {
-                action: "computing eclipse state",
-            })?;
+        Ok(kind)
+    }
 
-        let r_fobj_prime = if fobj_mean_eq_radius_km >= r_eb.norm() {
-            core::f64::consts::FRAC_PI_2
-        } else {
-            (fobj_mean_eq_radius_km / r_eb.norm()).asin()
+    /// Searches for the time intervals when the `back_frame` object is occulted by the `front_frame` object as seen from the origin of
+    /// the observer frame, between the start and end epochs. This is the equivalent of the SPICE `gfoclt` routine.
+    ///
+    /// If a kind is provided, only the windows of that exact kind are returned, otherwise all of the windows where the back object
+    /// is not fully visible are returned. The time span is sampled at the provided step, which must be shorter than the shortest
+    /// window to find, and the bounds of each window are then refined by bisection to ten milliseconds.
+    /// Windows in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// :type back_frame: Frame
+    /// :type front_frame: Frame
+    /// :type observer: Frame
+    /// :type kind: OccultationKind, optional
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List
+    #[allow(clippy::too_many_arguments)]
+    pub fn occultation_windows(
+        &self,
+        back_frame: Frame,
+        front_frame: Frame,
+        observer: Frame,
+        kind: Option<OccultationKind>,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<OccultationWindow>> {
+        let kind_at = |epoch: Epoch| {
+            self.occultation_kind(
+                back_frame,
+                front_frame,
+                Orbit::zero_at_epoch(epoch, observer),
+                ab_corr,
+            )
+        };
+        let matches = |this_kind: OccultationKind| match kind {
+            Some(kind) => this_kind == kind,
+            None => this_kind != OccultationKind::Visible,
+        };
+        // Returns the last epoch matching the state of `before` and the first epoch matching the state of `after`.
+        let bisect = |mut before: Epoch, mut after: Epoch| -> AlmanacResult<(Epoch, Epoch)> {
+            let matched = matches(kind_at(before)?);
+            while after - before > Unit::Millisecond * 10 {
+                let mid = before + (after - before) * 0.5;
+                if matches(kind_at(mid)?) == matched {
+                    before = mid;
+                } else {
+                    after = mid;
+                }
+            }
+            Ok((before, after))
         };
 
-        // Compute the apparent separation of both circles
-        let d_prime = (-(r_ls.dot(&r_eb)) / (r_eb.norm() * r_ls.norm())).acos();
+        let mut windows = Vec::new();
+        let mut current: Option<OccultationWindow> = None;
+        let mut prev_epoch = start_epoch;
+
+        for epoch in search_epochs(start_epoch, end_epoch, step)? {
+            if epoch < prev_epoch || (epoch == prev_epoch && epoch != start_epoch) {
+                continue;
+            }
+
+            let this_kind = kind_at(epoch)?;
+            match current.as_mut() {
+                None if matches(this_kind) => {
+                    let start_epoch = if epoch == start_epoch {
+                        epoch
+                    } else {
+                        bisect(prev_epoch, epoch)?.1
+                    };
+                    current = Some(OccultationWindow {
+                        start_epoch,
+                        end_epoch: epoch,
+                        kind: this_kind,
+                        back_frame,
+                        front_frame,
+                    });
+                }
+                None => {}
+                Some(window) if matches(this_kind) => {
+                    window.end_epoch = epoch;
+                    window.kind = window.kind.max(this_kind);
+                }
+                Some(window) => {
+                    window.end_epoch = bisect(prev_epoch, epoch)?.0;
+                    windows.push(*window);
+                    current = None;
+                }
+            }
+
+            prev_epoch = epoch;
+        }
 
-        let percentage = compute_occultation_percentage(d_prime, r_ls_prime, r_fobj_prime)?;
+        if let Some(window) = current {
+            windows.push(window);
+        }
 
-        Ok(Occultation {
-            epoch,
-            percentage,
-            back_frame,
-            front_frame,
-        })
+        Ok(windows)
+    }
+
+    /// Searches for the eclipses of the Sun by the eclipsing body as seen from the origin of the observer frame, e.g. a spacecraft
+    /// ephemeris, between the start and end epochs, returning the penumbra and umbra entry and exit epochs of each eclipse.
+    ///
+    /// This is the occultation search of the Sun by the eclipsing body, where the penumbra is any kind of occultation and the umbra is
+    /// a total occultation: refer to [Almanac::occultation_windows] for the sampling step and the precision of the epochs.
+    /// Eclipses in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// :type observer: Frame
+    /// :type eclipsing_frame: Frame
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List
+    pub fn eclipse_windows(
+        &self,
+        observer: Frame,
+        eclipsing_frame: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<EclipseWindow>> {
+        let windows = |kind: Option<OccultationKind>| {
+            self.occultation_windows(
+                SUN_J2000,
+                eclipsing_frame,
+                observer,
+                kind,
+                start_epoch,
+                end_epoch,
+                step,
+                ab_corr,
+            )
+        };
+
+        let umbras = windows(Some(OccultationKind::Total))?;
+
+        Ok(windows(None)?
+            .into_iter()
+            .map(|penumbra| {
+                let mut within = umbras.iter().filter(|umbra| {
+                    umbra.start_epoch >= penumbra.start_epoch
+                        && umbra.end_epoch <= penumbra.end_epoch
+                });
+                let first = within.next();
+                let last = within.next_back().or(first);
+
+                EclipseWindow {
+                    penumbra_entry_epoch: penumbra.start_epoch,
+                    penumbra_exit_epoch: penumbra.end_epoch,
+                    umbra_entry_epoch: first.map(|umbra| umbra.start_epoch),
+                    umbra_exit_epoch: last.map(|umbra| umbra.end_epoch),
+                    eclipsing_frame,
+                }
+            })
+            .collect())
     }
 
     /// Computes the solar eclipsing of the observer due to the eclipsing_frame.
@@ -234,6 +501,108 @@ impl Almanac {
         self.occultation(SUN_J2000, eclipsing_frame, observer, ab_corr)
     }
 
+    /// Computes the fraction of the solar disk visible from the observer despite the eclipsing body, which is modeled by its ellipsoid:
+    /// zero in umbra, one in full sunlight, and continuous in penumbra. This is the illumination factor used in power and solar
+    /// radiation pressure models.
+    ///
+    /// The eclipsing body is modeled as a disk whose radius is the extent of its ellipsoid in the direction of the Sun, as seen
+    /// from the observer. The axes of the ellipsoid are those of the eclipsing frame, so use the body fixed frame of the
+    /// eclipsing body (e.g. IAU Earth) to account for its oblateness, which requires the orientation data of that frame.
+    ///
+    /// :type eclipsing_frame: Frame
+    /// :type observer: Orbit
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: float
+    pub fn shadow_factor(
+        &self,
+        mut eclipsing_frame: Frame,
+        observer: Orbit,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<f64> {
+        if eclipsing_frame.shape.is_none() {
+            eclipsing_frame =
+                self.frame_info(eclipsing_frame)
+                    .map_err(|e| AlmanacError::GenericError {
+                        err: format!("{e} when fetching {eclipsing_frame:e} frame data"),
+                    })?;
+        }
+
+        let shape = eclipsing_frame
+            .shape
+            .ok_or(PhysicsError::MissingFrameData {
+                action: "computing shadow factor",
+                data: "shape",
+                frame: eclipsing_frame.into(),
+            })
+            .context(AlmanacPhysicsSnafu {
+                action: "computing shadow factor",
+            })?;
+
+        let sun_radius_km = self
+            .frame_info(SUN_J2000)
+            .map_err(|e| AlmanacError::GenericError {
+                err: format!("{e} when fetching {SUN_J2000:e} frame data"),
+            })?
+            .mean_equatorial_radius_km()
+            .context(EphemerisPhysicsSnafu {
+                action: "fetching mean equatorial radius of the Sun",
+            })
+            .context(EphemerisSnafu {
+                action: "computing shadow factor",
+            })?;
+
+        // Vectors from the eclipsing body to the observer, and from the observer to the Sun, in the eclipsing frame.
+        let r_eb = self
+            .transform_to(observer, eclipsing_frame, ab_corr)?
+            .radius_km;
+        let r_ls = -self
+            .transform_to(
+                observer,
+                SUN_J2000.with_orient(eclipsing_frame.orientation_id),
+                ab_corr,
+            )?
+            .radius_km;
+
+        // Direction from the center of the eclipsing body to the Sun, in the plane of the sky of the observer.
+        let u_los = -r_eb.normalize();
+        let mut u_sky = r_ls - r_ls.dot(&u_los) * u_los;
+        if u_sky.norm() < f64::EPSILON * r_ls.norm() {
+            // The Sun is behind the center of the body: any direction in the plane of the sky will do.
+            u_sky = u_los.cross(&Vector3::x());
+            if u_sky.norm() < 1e-6 {
+                u_sky = u_los.cross(&Vector3::y());
+            }
+        }
+        let u_sky = u_sky.normalize();
+
+        let eclipsing_radius_km = Vector3::new(
+            u_sky.x * shape.semi_major_equatorial_radius_km,
+            u_sky.y * shape.semi_minor_equatorial_radius_km,
+            u_sky.z * shape.polar_radius_km,
+        )
+        .norm();
+
+        let apparent_radius_rad = |radius_km: f64, distance_km: f64| {
+            if radius_km >= distance_km {
+                core::f64::consts::FRAC_PI_2
+            } else {
+                (radius_km / distance_km).asin()
+            }
+        };
+
+        let separation_rad = (-(r_ls.dot(&r_eb)) / (r_eb.norm() * r_ls.norm()))
+            .clamp(-1.0, 1.0)
+            .acos();
+
+        let percentage = compute_occultation_percentage(
+            separation_rad,
+            apparent_radius_rad(sun_radius_km, r_ls.norm()),
+            apparent_radius_rad(eclipsing_radius_km, r_eb.norm()),
+        )?;
+
+        Ok((1.0 - percentage / 100.0).clamp(0.0, 1.0))
+    }
+
     /// Computes the Beta angle (β) for a given orbital state, in degrees. A Beta angle of 0° indicates that the orbit plane is edge-on to the Sun, leading to maximum eclipse time. Conversely, a Beta angle of +90° or -90° means the orbit plane is face-on to the Sun, resulting in continuous sunlight exposure and no eclipses.
     ///
     /// The Beta angle (β) is defined as the angle between the orbit plane of a spacecraft and the vector from the central body (e.g., Earth) to the Sun. In simpler terms, it measures how much of the time a satellite in orbit is exposed to direct sunlight.
@@ -309,6 +678,87 @@ impl Almanac {
     }
 }
 
+/// Apparent disks of the back and front objects of an occultation, as seen from the observer.
+struct ApparentDisks {
+    back_radius_rad: f64,
+    front_radius_rad: f64,
+    separation_rad: f64,
+    back_distance_km: f64,
+    front_distance_km: f64,
+}
+
+impl Almanac {
+    /// Computes the apparent disks of the back and front frames, which must include their shape, as seen from the observer.
+    fn apparent_disks(
+        &self,
+        back_frame: Frame,
+        front_frame: Frame,
+        mut observer: Orbit,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<ApparentDisks> {
+        let bobj_mean_eq_radius_km = back_frame
+            .mean_equatorial_radius_km()
+            .context(EphemerisPhysicsSnafu {
+                action: "fetching mean equatorial radius of back frame",
+            })
+            .context(EphemerisSnafu {
+                action: "computing occultation state",
+            })?;
+
+        // All of the computations happen with the observer as the center.
+        // `eb` stands for front object; `ls` stands for back object.
+        // Get the radius vector of the spacecraft to the front object
+
+        // Ensure that the observer is in the J2000 frame.
+        observer = self
+            .rotate_to(observer, observer.frame.with_orient(J2000))
+            .context(OrientationSnafu {
+                action: "computing eclipse state",
+            })?;
+        let r_eb = self
+            .transform_to(observer, front_frame.with_orient(J2000), ab_corr)?
+            .radius_km;
+
+        // Get the radius vector of the back object to the spacecraft
+        let r_ls = -self
+            .transform_to(observer, back_frame.with_orient(J2000), ab_corr)?
+            .radius_km;
+
+        // Compute the apparent radii of the back object and front object (preventing any NaN)
+        let r_ls_prime = if bobj_mean_eq_radius_km >= r_ls.norm() {
+            core::f64::consts::FRAC_PI_2
+        } else {
+            (bobj_mean_eq_radius_km / r_ls.norm()).asin()
+        };
+
+        let fobj_mean_eq_radius_km = front_frame
+            .mean_equatorial_radius_km()
+            .context(EphemerisPhysicsSnafu {
+                action: "fetching mean equatorial radius of front object",
+            })
+            .context(EphemerisSnafu {
+                action: "computing eclipse state",
+            })?;
+
+        let r_fobj_prime = if fobj_mean_eq_radius_km >= r_eb.norm() {
+            core::f64::consts::FRAC_PI_2
+        } else {
+            (fobj_mean_eq_radius_km / r_eb.norm()).asin()
+        };
+
+        // Compute the apparent separation of both circles
+        let d_prime = (-(r_ls.dot(&r_eb)) / (r_eb.norm() * r_ls.norm())).acos();
+
+        Ok(ApparentDisks {
+            back_radius_rad: r_ls_prime,
+            front_radius_rad: r_fobj_prime,
+            separation_rad: d_prime,
+            back_distance_km: r_ls.norm(),
+            front_distance_km: r_eb.norm(),
+        })
+    }
+}
+
 /// Compute the occultation percentage
 fn compute_occultation_percentage(
     d_prime: f64,
@@ -353,6 +803,84 @@ fn compute_occultation_percentage(
     }
 }
 
+/// Returns the time intervals between the start and end epochs when the condition holds, sampled at the provided step and
+/// refined by bisection to ten milliseconds.
+///
+/// This does not rely on the event finder of the `analysis` module since the latter is an optional feature, and it finds the roots
+/// of continuous scalar functions with Brent's method, whereas these conditions are booleans (e.g. whether a body is in the FOV of
+/// an instrument) whose changes can only be bracketed by sampling and then bisected.
+pub(super) fn find_windows<F>(
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    step: Duration,
+    condition: F,
+) -> AlmanacResult<Vec<(Epoch, Epoch)>>
+where
+    F: Fn(Epoch) -> AlmanacResult<bool>,
+{
+    // Returns the last epoch matching the state of `before` and the first epoch matching the state of `after`.
+    let bisect = |mut before: Epoch, mut after: Epoch| -> AlmanacResult<(Epoch, Epoch)> {
+        let matched = condition(before)?;
+        while after - before > Unit::Millisecond * 10 {
+            let mid = before + (after - before) * 0.5;
+            if condition(mid)? == matched {
+                before = mid;
+            } else {
+                after = mid;
+            }
+        }
+        Ok((before, after))
+    };
+
+    let mut windows = Vec::new();
+    let mut current: Option<Epoch> = None;
+    let mut prev_epoch = start_epoch;
+
+    for epoch in search_epochs(start_epoch, end_epoch, step)? {
+        if epoch < prev_epoch || (epoch == prev_epoch && epoch != start_epoch) {
+            continue;
+        }
+
+        match (current, condition(epoch)?) {
+            (None, true) => {
+                current = Some(if epoch == start_epoch {
+                    epoch
+                } else {
+                    bisect(prev_epoch, epoch)?.1
+                });
+            }
+            (Some(start), false) => {
+                windows.push((start, bisect(prev_epoch, epoch)?.0));
+                current = None;
+            }
+            _ => {}
+        }
+
+        prev_epoch = epoch;
+    }
+
+    if let Some(start) = current {
+        windows.push((start, prev_epoch));
+    }
+
+    Ok(windows)
+}
+
+/// Returns the epochs sampled by the window searches, from the start epoch to the end epoch included, or an error if the step is
+/// not positive.
+fn search_epochs(
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    step: Duration,
+) -> AlmanacResult<impl Iterator<Item = Epoch>> {
+    if step <= Duration::ZERO {
+        return Err(AlmanacError::GenericError {
+            err: format!("window search requires a positive step ({step})"),
+        });
+    }
+    Ok(TimeSeries::inclusive(start_epoch, end_epoch, step).chain(core::iter::once(end_epoch)))
+}
+
 /// Compute the area of the circular segment of radius r and chord length d
 fn circ_seg_area(r: f64, d: f64) -> f64 {
     r.powi(2) * (d / r).acos() - d * (r.powi(2) - d.powi(2)).sqrt()
@@ -600,3 +1128,425 @@ mod ut_los {
         assert!(pct_inside >= 99.999);
     }
 }
+
+#[cfg(test)]
+mod ut_occultation {
+    use crate::astro::OccultationKind;
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000, SUN_J2000};
+    use crate::naif::daf::test_utils::{fixed_segment, linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use crate::structure::planetocentric::PlanetaryData;
+    use crate::structure::PlanetaryDataSet;
+    use hifitime::{Duration, Epoch, Unit};
+
+    const AU_KM: f64 = 1.5e8;
+
+    #[test]
+    fn occultation_windows() {
+        let mut dataset = PlanetaryDataSet::default();
+        for (id, radius_km) in [(10, 695_700.0), (399, 6378.0), (301, 1737.4)] {
+            let data = PlanetaryData {
+                object_id: id,
+                shape: Some(Ellipsoid::from_sphere(radius_km)),
+                ..Default::default()
+            };
+            dataset.push(data, Some(id), None).unwrap();
+        }
+
+        // The observer is one "AU" from the Sun, the Moon crosses the solar disk at noon and is slightly smaller than the Sun,
+        // and the Earth is close to the observer, fully hiding the Sun.
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(-85, 0, [AU_KM, 0.0, 0.0]),
+            linear_segment(
+                301,
+                0,
+                [AU_KM - 384_400.0, 0.0, 0.0],
+                [0.0, 10_000.0 / 43_200.0, 0.0],
+            ),
+            fixed_segment(399, 0, [AU_KM - 100_000.0, 0.0, 0.0]),
+        ]);
+        let almanac = Almanac::default()
+            .with_spk(spk)
+            .with_planetary_data(dataset);
+
+        let observer = Frame::from_ephem_j2000(-85);
+        let at = |seconds: f64| Epoch::from_et_seconds(seconds);
+        let kind_at = |back: Frame, front: Frame, epoch: Epoch| {
+            almanac
+                .occultation_kind(back, front, Orbit::zero_at_epoch(epoch, observer), None)
+                .unwrap()
+        };
+
+        assert_eq!(
+            kind_at(SUN_J2000, MOON_J2000, at(43_200.0)),
+            OccultationKind::Annular
+        );
+        assert_eq!(
+            kind_at(SUN_J2000, MOON_J2000, at(36_000.0)),
+            OccultationKind::Partial
+        );
+        assert_eq!(
+            kind_at(SUN_J2000, MOON_J2000, at(0.0)),
+            OccultationKind::Visible
+        );
+        assert_eq!(
+            kind_at(SUN_J2000, EARTH_J2000, at(0.0)),
+            OccultationKind::Total
+        );
+        // The Sun is behind the Earth, so it does not occult it.
+        assert_eq!(
+            kind_at(EARTH_J2000, SUN_J2000, at(0.0)),
+            OccultationKind::Visible
+        );
+
+        let windows = |kind: Option<OccultationKind>| {
+            almanac
+                .occultation_windows(
+                    SUN_J2000,
+                    MOON_J2000,
+                    observer,
+                    kind,
+                    at(0.0),
+                    at(86_400.0),
+                    Unit::Minute * 10,
+                    None,
+                )
+                .unwrap()
+        };
+
+        let any = windows(None);
+        assert_eq!(any.len(), 1);
+        assert_eq!(any[0].kind, OccultationKind::Annular);
+        let annular = windows(Some(OccultationKind::Annular));
+        assert_eq!(annular.len(), 1);
+        let partial = windows(Some(OccultationKind::Partial));
+        assert_eq!(partial.len(), 2);
+        assert!(windows(Some(OccultationKind::Total)).is_empty());
+
+        for window in any.iter().chain(&annular) {
+            // The crossing is symmetric about noon
+            let midpoint = window.start_epoch + window.duration() * 0.5;
+            assert!((midpoint - at(43_200.0)).abs() < Unit::Millisecond * 20);
+            // The bounds are refined to the search precision
+            let inside = kind_at(SUN_J2000, MOON_J2000, window.start_epoch);
+            let before = kind_at(
+                SUN_J2000,
+                MOON_J2000,
+                window.start_epoch - Unit::Millisecond * 10,
+            );
+            assert_ne!(inside, before);
+            assert!(inside >= window.kind.min(OccultationKind::Partial));
+        }
+        assert!(annular[0].duration() < any[0].duration());
+        assert_eq!(partial[0].start_epoch, any[0].start_epoch);
+        assert_eq!(partial[1].end_epoch, any[0].end_epoch);
+
+        // Windows in progress are clipped to the search span
+        let total = almanac
+            .occultation_windows(
+                SUN_J2000,
+                EARTH_J2000,
+                observer,
+                Some(OccultationKind::Total),
+                at(0.0),
+                at(86_400.0),
+                Unit::Hour * 1,
+                None,
+            )
+            .unwrap();
+        assert_eq!(total.len(), 1);
+        assert_eq!(total[0].duration(), Unit::Day * 1);
+
+        // The step must be positive
+        assert!(almanac
+            .occultation_windows(
+                SUN_J2000,
+                EARTH_J2000,
+                observer,
+                None,
+                at(0.0),
+                at(86_400.0),
+                Unit::Hour * -1,
+                None,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn eclipse_windows() {
+        let mut dataset = PlanetaryDataSet::default();
+        for (id, radius_km) in [(10, 695_700.0), (399, 6378.0)] {
+            let data = PlanetaryData {
+                object_id: id,
+                shape: Some(Ellipsoid::from_sphere(radius_km)),
+                ..Default::default()
+            };
+            dataset.push(data, Some(id), None).unwrap();
+        }
+
+        // The Earth crosses the line of sight from the observer to the Sun at noon, and is large enough to fully hide it.
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(-85, 0, [AU_KM, 0.0, 0.0]),
+            linear_segment(
+                399,
+                0,
+                [AU_KM - 100_000.0, 0.0, 0.0],
+                [0.0, 50_000.0 / 43_200.0, 0.0],
+            ),
+        ]);
+        let almanac = Almanac::default()
+            .with_spk(spk)
+            .with_planetary_data(dataset);
+
+        let observer = Frame::from_ephem_j2000(-85);
+        let at = |seconds: f64| Epoch::from_et_seconds(seconds);
+        let kind_at = |epoch: Epoch| {
+            almanac
+                .occultation_kind(
+                    SUN_J2000,
+                    EARTH_J2000,
+                    Orbit::zero_at_epoch(epoch, observer),
+                    None,
+                )
+                .unwrap()
+        };
+
+        let eclipses = almanac
+            .eclipse_windows(
+                observer,
+                EARTH_J2000,
+                at(0.0),
+                at(86_400.0),
+                Unit::Minute * 1,
+                None,
+            )
+            .unwrap();
+        assert_eq!(eclipses.len(), 1);
+        let eclipse = eclipses[0];
+        println!("{eclipse}");
+
+        let umbra_entry = eclipse.umbra_entry_epoch.unwrap();
+        let umbra_exit = eclipse.umbra_exit_epoch.unwrap();
+        assert!(eclipse.penumbra_entry_epoch < umbra_entry);
+        assert!(umbra_exit < eclipse.penumbra_exit_epoch);
+        assert!(eclipse.umbra_duration().unwrap() < eclipse.duration());
+
+        // The eclipse is symmetric about noon
+        for (entry, exit) in [
+            (eclipse.penumbra_entry_epoch, eclipse.penumbra_exit_epoch),
+            (umbra_entry, umbra_exit),
+        ] {
+            let midpoint = entry + (exit - entry) * 0.5;
+            assert!((midpoint - at(43_200.0)).abs() < Unit::Millisecond * 20);
+        }
+
+        // The bounds are refined to the search precision
+        let before = eclipse.penumbra_entry_epoch - Unit::Millisecond * 10;
+        assert_eq!(kind_at(before), OccultationKind::Visible);
+        assert_eq!(
+            kind_at(eclipse.penumbra_entry_epoch),
+            OccultationKind::Partial
+        );
+        assert_eq!(
+            kind_at(umbra_entry - Unit::Millisecond * 10),
+            OccultationKind::Partial
+        );
+        assert_eq!(kind_at(umbra_entry), OccultationKind::Total);
+
+        // A search ending within the umbra clips the eclipse
+        let clipped = almanac
+            .eclipse_windows(
+                observer,
+                EARTH_J2000,
+                at(0.0),
+                at(43_200.0),
+                Unit::Minute * 1,
+                None,
+            )
+            .unwrap();
+        assert_eq!(clipped.len(), 1);
+        assert_eq!(
+            clipped[0].penumbra_entry_epoch,
+            eclipse.penumbra_entry_epoch
+        );
+        assert_eq!(clipped[0].umbra_exit_epoch, Some(at(43_200.0)));
+        assert_eq!(clipped[0].penumbra_exit_epoch, at(43_200.0));
+    }
+
+    #[test]
+    fn shadow_factor() {
+        let mut dataset = PlanetaryDataSet::default();
+        let sun = PlanetaryData {
+            object_id: 10,
+            shape: Some(Ellipsoid::from_sphere(695_700.0)),
+            ..Default::default()
+        };
+        dataset.push(sun, Some(10), None).unwrap();
+
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(399, 0, [AU_KM, 0.0, 0.0]),
+        ]);
+        let almanac = Almanac::default()
+            .with_spk(spk)
+            .with_planetary_data(dataset);
+
+        // A very oblate Earth, whose axes are those of the J2000 frame.
+        let earth = EARTH_J2000.with_ellipsoid(Ellipsoid {
+            semi_major_equatorial_radius_km: 6378.0,
+            semi_minor_equatorial_radius_km: 6378.0,
+            polar_radius_km: 5000.0,
+        });
+        let epoch = Epoch::from_et_seconds(43_200.0);
+        let factor = |y_km: f64, z_km: f64| {
+            almanac
+                .shadow_factor(
+                    earth,
+                    Orbit::from_position(10_000.0, y_km, z_km, epoch, EARTH_J2000),
+                    None,
+                )
+                .unwrap()
+        };
+
+        assert_eq!(factor(0.0, 0.0), 0.0);
+        assert_eq!(factor(0.0, 20_000.0), 1.0);
+        // The shadow is continuous across the penumbra
+        let mut prev = 0.0;
+        for offset_km in 5000..=5600 {
+            let this = factor(0.0, offset_km as f64);
+            assert!(this >= prev && this - prev < 0.05, "{offset_km}: {this}");
+            prev = this;
+        }
+        assert_eq!(prev, 1.0);
+        // The polar radius is smaller, so the shadow is narrower along Z than along Y
+        assert!(factor(0.0, 5500.0) > 0.99);
+        assert_eq!(factor(5500.0, 0.0), 0.0);
+
+        // The shape is fetched from the planetary data, and required
+        assert!(almanac
+            .shadow_factor(EARTH_J2000, Orbit::zero_at_epoch(epoch, EARTH_J2000), None)
+            .is_err());
+    }
+
+    #[test]
+    fn line_of_sight() {
+        let mut dataset = PlanetaryDataSet::default();
+        for (id, radius_km) in [(10, 695_700.0), (399, 6378.0)] {
+            let data = PlanetaryData {
+                object_id: id,
+                shape: Some(Ellipsoid::from_sphere(radius_km)),
+                ..Default::default()
+            };
+            dataset.push(data, Some(id), None).unwrap();
+        }
+
+        // The target crosses behind the Earth as seen from the observer, which is on the other side of the Earth.
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(399, 0, [AU_KM, 0.0, 0.0]),
+            fixed_segment(-85, 0, [AU_KM + 10_000.0, 0.0, 0.0]),
+            linear_segment(
+                -86,
+                0,
+                [AU_KM - 10_000.0, 0.0, 0.0],
+                [0.0, 20_000.0 / 43_200.0, 0.0],
+            ),
+        ]);
+        let almanac = Almanac::default()
+            .with_spk(spk)
+            .with_planetary_data(dataset);
+
+        let observer = Frame::from_ephem_j2000(-85);
+        let target = Frame::from_ephem_j2000(-86);
+        let bodies = [SUN_J2000, EARTH_J2000];
+        let noon = Epoch::from_et_seconds(43_200.0);
+
+        let blocking = almanac
+            .line_of_sight(observer, target, noon, &bodies, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(blocking.ephemeris_id, 399);
+        assert!(almanac
+            .line_of_sight(observer, target, Epoch::from_et_seconds(0.0), &bodies, None)
+            .unwrap()
+            .is_none());
+        // The Earth does not block the line of sight to itself
+        assert!(almanac
+            .line_of_sight(observer, EARTH_J2000, noon, &bodies, None)
+            .unwrap()
+            .is_none());
+
+        // The line of sight grazes the Earth when the target is 16,562 km off the X axis, i.e. 35,775 seconds from noon.
+        let graze_s = 43_200.0 * 6378.0 / (10_000.0_f64.powi(2) - 6378.0_f64.powi(2)).sqrt();
+        let windows = almanac
+            .line_of_sight_windows(
+                observer,
+                target,
+                &bodies,
+                Epoch::from_et_seconds(0.0),
+                Epoch::from_et_seconds(86_400.0),
+                Unit::Minute * 10,
+                None,
+            )
+            .unwrap();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].0, Epoch::from_et_seconds(0.0));
+        assert!((windows[0].1 - (noon - Unit::Second * graze_s)).abs() < Unit::Millisecond * 10);
+        assert!((windows[1].0 - (noon + Unit::Second * graze_s)).abs() < Unit::Millisecond * 10);
+        assert_eq!(windows[1].1, Epoch::from_et_seconds(86_400.0));
+        // The step must be positive
+        assert!(almanac
+            .line_of_sight_windows(
+                observer,
+                target,
+                &bodies,
+                Epoch::from_et_seconds(0.0),
+                Epoch::from_et_seconds(86_400.0),
+                Duration::ZERO,
+                None,
+            )
+            .is_err());
+
+        // The shape of each body is required
+        assert!(almanac
+            .line_of_sight(observer, target, noon, &[MOON_J2000], None)
+            .is_err());
+    }
+
+    #[test]
+    fn beta_angle() {
+        // The Sun is along -X as seen from the Earth
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(399, 0, [AU_KM, 0.0, 0.0]),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        let epoch = Epoch::from_et_seconds(43_200.0);
+        let beta = |r_km: [f64; 3], v_km_s: [f64; 3]| {
+            let orbit = Orbit::new(
+                r_km[0],
+                r_km[1],
+                r_km[2],
+                v_km_s[0],
+                v_km_s[1],
+                v_km_s[2],
+                epoch,
+                EARTH_J2000,
+            );
+            almanac.beta_angle_deg(orbit, None).unwrap()
+        };
+
+        // Equatorial orbit: the Sun is in the orbit plane
+        assert!(beta([7000.0, 0.0, 0.0], [0.0, 7.5, 0.0]).abs() < 1e-9);
+        // The orbit normal points away from the Sun
+        assert!((beta([0.0, 7000.0, 0.0], [0.0, 0.0, 7.5]) + 90.0).abs() < 1e-6);
+        // The orbit normal is 30 degrees off the anti-Sun direction
+        let (s, c) = 30.0_f64.to_radians().sin_cos();
+        assert!((beta([0.0, 7000.0, 0.0], [-7.5 * s, 0.0, 7.5 * c]) + 60.0).abs() < 1e-9);
+        // Reversing the motion flips the sign
+        assert!((beta([0.0, 7000.0, 0.0], [7.5 * s, 0.0, -7.5 * c]) - 60.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,408 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch, Unit};
+use log::warn;
+use std::fs;
+use std::path::Path;
+
+use super::{Almanac, Ut1Source};
+use crate::errors::{AlmanacError, AlmanacResult};
+
+/// Earth orientation parameters at one epoch, as published by the IERS.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EarthOrientation {
+    pub epoch: Epoch,
+    pub ut1_minus_utc: Duration,
+    /// X coordinate of the celestial intermediate pole with respect to the terrestrial reference frame, in arcseconds
+    pub x_pole_arcsec: f64,
+    /// Y coordinate of the celestial intermediate pole with respect to the terrestrial reference frame, in arcseconds
+    pub y_pole_arcsec: f64,
+    /// Offset dX of the celestial pole with respect to the IAU 2006/2000A precession-nutation model, in milliarcseconds
+    pub dx_mas: f64,
+    /// Offset dY of the celestial pole with respect to the IAU 2006/2000A precession-nutation model, in milliarcseconds
+    pub dy_mas: f64,
+}
+
+impl EarthOrientation {
+    /// Returns TAI - UT1, which is continuous across leap seconds, unlike UT1 - UTC.
+    fn tai_minus_ut1(&self) -> Duration {
+        tai_minus_utc(self.epoch) - self.ut1_minus_utc
+    }
+}
+
+impl fmt::Display for EarthOrientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: UT1 - UTC = {}, xp = {:.6} arcsec, yp = {:.6} arcsec, dX = {:.3} mas, dY = {:.3} mas",
+            self.epoch,
+            self.ut1_minus_utc,
+            self.x_pole_arcsec,
+            self.y_pole_arcsec,
+            self.dx_mas,
+            self.dy_mas
+        )
+    }
+}
+
+/// Earth orientation parameters (EOP) loaded from the files published by the IERS, sorted chronologically.
+///
+/// The parameters are linearly interpolated between the tabulated epochs, typically daily, where UT1 is interpolated as TAI - UT1
+/// such that leap seconds do not introduce a jump. Once loaded in an Almanac with [Almanac::with_eop_data], they are available
+/// with [Almanac::earth_orientation]. This also implements [Ut1Source], so it may drive the rotation of the IAU Earth frame with
+/// [Almanac::with_ut1_provider].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EopData {
+    pub records: Vec<EarthOrientation>,
+}
+
+impl EopData {
+    /// Loads the EOP from an IERS `finals2000A` file (e.g. `finals2000A.all` or `finals2000A.daily`), cf. [Self::from_finals2000a].
+    pub fn from_finals2000a_file<P: AsRef<Path>>(path: P) -> AlmanacResult<Self> {
+        let content = fs::read_to_string(&path).map_err(|e| AlmanacError::EopParsing {
+            lno: 0,
+            details: format!("could not read {}: {e}", path.as_ref().display()),
+        })?;
+        Self::from_finals2000a(&content)
+    }
+
+    /// Parses the fixed width columns of the IERS `finals2000A` format, using the values of the Bulletin A (final or predicted).
+    ///
+    /// Lines without UT1 - UTC, e.g. past the end of the predictions, are skipped. The celestial pole offsets are set to zero
+    /// when they are not published, as is the case for the later predictions.
+    pub fn from_finals2000a(content: &str) -> AlmanacResult<Self> {
+        let mut records = Vec::new();
+        for (lno, line) in content.lines().enumerate() {
+            let lno = lno + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some(mjd) = fixed_column(line, lno, 8, 15)? else {
+                return Err(AlmanacError::EopParsing {
+                    lno,
+                    details: "missing MJD".to_string(),
+                });
+            };
+            let Some(ut1_minus_utc_s) = fixed_column(line, lno, 59, 68)? else {
+                continue;
+            };
+
+            records.push(EarthOrientation {
+                epoch: Epoch::from_mjd_utc(mjd),
+                ut1_minus_utc: Unit::Second * ut1_minus_utc_s,
+                x_pole_arcsec: fixed_column(line, lno, 19, 27)?.unwrap_or_default(),
+                y_pole_arcsec: fixed_column(line, lno, 38, 46)?.unwrap_or_default(),
+                dx_mas: fixed_column(line, lno, 98, 106)?.unwrap_or_default(),
+                dy_mas: fixed_column(line, lno, 117, 125)?.unwrap_or_default(),
+            });
+        }
+
+        Self::new(records)
+    }
+
+    /// Loads the EOP from an IERS CSV file (e.g. `finals2000A.all.csv`), cf. [Self::from_csv].
+    pub fn from_csv_file<P: AsRef<Path>>(path: P) -> AlmanacResult<Self> {
+        let content = fs::read_to_string(&path).map_err(|e| AlmanacError::EopParsing {
+            lno: 0,
+            details: format!("could not read {}: {e}", path.as_ref().display()),
+        })?;
+        Self::from_csv(&content)
+    }
+
+    /// Parses the CSV format of the IERS, delimited by semicolons (or commas), whose header names the `MJD`, `x_pole`, `y_pole`,
+    /// `UT1-UTC`, and optionally the `dX` and `dY` columns. Other columns are ignored.
+    ///
+    /// Rows without UT1 - UTC are skipped, and the celestial pole offsets are set to zero when they are not published.
+    pub fn from_csv(content: &str) -> AlmanacResult<Self> {
+        let mut lines = content
+            .lines()
+            .enumerate()
+            .map(|(lno, line)| (lno + 1, line))
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let Some((header_lno, header)) = lines.next() else {
+            return Self::new(Vec::new());
+        };
+        let delimiter = if header.contains(';') { ';' } else { ',' };
+        let names = header.split(delimiter).map(str::trim).collect::<Vec<_>>();
+        let index_of = |name: &str| names.iter().position(|col| *col == name);
+        let required = |name: &str| {
+            index_of(name).ok_or_else(|| AlmanacError::EopParsing {
+                lno: header_lno,
+                details: format!("missing `{name}` column in header"),
+            })
+        };
+
+        let mjd_col = required("MJD")?;
+        let x_pole_col = required("x_pole")?;
+        let y_pole_col = required("y_pole")?;
+        let ut1_col = required("UT1-UTC")?;
+        let dx_col = index_of("dX");
+        let dy_col = index_of("dY");
+
+        let mut records = Vec::new();
+        for (lno, line) in lines {
+            let fields = line.split(delimiter).map(str::trim).collect::<Vec<_>>();
+            let value = |col: Option<usize>| -> AlmanacResult<Option<f64>> {
+                match col.and_then(|col| fields.get(col)) {
+                    Some(field) if !field.is_empty() => {
+                        field
+                            .parse()
+                            .map(Some)
+                            .map_err(|e| AlmanacError::EopParsing {
+                                lno,
+                                details: format!("could not parse `{field}`: {e}"),
+                            })
+                    }
+                    _ => Ok(None),
+                }
+            };
+
+            let Some(mjd) = value(Some(mjd_col))? else {
+                return Err(AlmanacError::EopParsing {
+                    lno,
+                    details: "missing MJD".to_string(),
+                });
+            };
+            let Some(ut1_minus_utc_s) = value(Some(ut1_col))? else {
+                continue;
+            };
+
+            records.push(EarthOrientation {
+                epoch: Epoch::from_mjd_utc(mjd),
+                ut1_minus_utc: Unit::Second * ut1_minus_utc_s,
+                x_pole_arcsec: value(Some(x_pole_col))?.unwrap_or_default(),
+                y_pole_arcsec: value(Some(y_pole_col))?.unwrap_or_default(),
+                dx_mas: value(dx_col)?.unwrap_or_default(),
+                dy_mas: value(dy_col)?.unwrap_or_default(),
+            });
+        }
+
+        Self::new(records)
+    }
+
+    /// Builds the EOP data from the provided records, which must be in strictly increasing chronological order.
+    pub fn new(records: Vec<EarthOrientation>) -> AlmanacResult<Self> {
+        if let Some(pos) = records
+            .windows(2)
+            .position(|pair| pair[0].epoch >= pair[1].epoch)
+        {
+            return Err(AlmanacError::EopParsing {
+                lno: 0,
+                details: format!(
+                    "records are not in increasing chronological order at {}",
+                    records[pos + 1].epoch
+                ),
+            });
+        }
+        Ok(Self { records })
+    }
+
+    /// Returns the number of tabulated epochs.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if there are no tabulated epochs.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the first and last tabulated epochs, if any.
+    pub fn domain(&self) -> Option<(Epoch, Epoch)> {
+        Some((self.records.first()?.epoch, self.records.last()?.epoch))
+    }
+
+    /// Returns the Earth orientation parameters linearly interpolated at the provided epoch, or None outside of the tabulated epochs.
+    pub fn at(&self, epoch: Epoch) -> Option<EarthOrientation> {
+        let idx = self.records.partition_point(|record| record.epoch <= epoch);
+        if idx == 0 {
+            return None;
+        } else if idx == self.records.len() {
+            let last = self.records.last()?;
+            return (last.epoch == epoch).then_some(*last);
+        }
+
+        let (prev, next) = (&self.records[idx - 1], &self.records[idx]);
+        let f = (epoch - prev.epoch).to_seconds() / (next.epoch - prev.epoch).to_seconds();
+        let lerp = |a: f64, b: f64| a + f * (b - a);
+
+        let prev_tai_minus_ut1_s = prev.tai_minus_ut1().to_seconds();
+        let tai_minus_ut1_s = lerp(prev_tai_minus_ut1_s, next.tai_minus_ut1().to_seconds());
+
+        Some(EarthOrientation {
+            epoch,
+            ut1_minus_utc: tai_minus_utc(epoch) - Unit::Second * tai_minus_ut1_s,
+            x_pole_arcsec: lerp(prev.x_pole_arcsec, next.x_pole_arcsec),
+            y_pole_arcsec: lerp(prev.y_pole_arcsec, next.y_pole_arcsec),
+            dx_mas: lerp(prev.dx_mas, next.dx_mas),
+            dy_mas: lerp(prev.dy_mas, next.dy_mas),
+        })
+    }
+}
+
+impl Ut1Source for EopData {
+    fn tai_minus_ut1(&self, epoch: Epoch) -> Option<Duration> {
+        Some(self.at(epoch)?.tai_minus_ut1())
+    }
+}
+
+/// Returns TAI - UTC at the provided epoch, i.e. the number of leap seconds.
+fn tai_minus_utc(epoch: Epoch) -> Duration {
+    epoch.to_tai_duration() - epoch.to_utc_duration()
+}
+
+/// Parses the value between the provided (one-indexed and inclusive) columns of a fixed width line, or None if it is blank.
+fn fixed_column(line: &str, lno: usize, first: usize, last: usize) -> AlmanacResult<Option<f64>> {
+    let Some(field) = line.get(first - 1..last.min(line.len())) else {
+        return Ok(None);
+    };
+    let field = field.trim();
+    if field.is_empty() {
+        Ok(None)
+    } else {
+        field
+            .parse()
+            .map(Some)
+            .map_err(|e| AlmanacError::EopParsing {
+                lno,
+                details: format!("could not parse `{field}` in columns {first}-{last}: {e}"),
+            })
+    }
+}
+
+impl Almanac {
+    /// Loads the provided Earth orientation parameters.
+    pub fn with_eop_data(self, data: EopData) -> Self {
+        self.with_eop_data_as(data, None)
+    }
+
+    /// Loads the provided Earth orientation parameters.
+    pub fn with_eop_data_as(mut self, data: EopData, alias: Option<String>) -> Self {
+        let alias = alias.unwrap_or(Epoch::now().unwrap_or_default().to_string());
+        let msg = format!("unloading EOP data `{alias}`");
+        if self.eop_data.insert(alias, data).is_some() {
+            warn!("{msg}");
+        }
+        self
+    }
+
+    /// Returns the Earth orientation parameters interpolated at the provided epoch, searching through all loaded EOP data in
+    /// reverse loading order, such that the most recently loaded data covering this epoch is used.
+    pub fn earth_orientation(&self, epoch: Epoch) -> AlmanacResult<EarthOrientation> {
+        self.eop_data
+            .values()
+            .rev()
+            .find_map(|data| data.at(epoch))
+            .ok_or(AlmanacError::MissingEop { epoch })
+    }
+}
+
+#[cfg(test)]
+mod ut_eop {
+    use super::EopData;
+    use crate::errors::AlmanacError;
+    use crate::prelude::Almanac;
+    use hifitime::{Epoch, Unit};
+
+    // Excerpt of finals2000A.all around the leap second of 2016-12-31, the third line without the celestial pole offsets.
+    const FINALS_2000A: &str = "\
+161230 57752.00 I  0.048406 0.000025  0.265880 0.000030  I-0.4118744 0.0000075  1.6264 0.0052  I     0.111    0.300    -0.049    0.300
+161231 57753.00 I  0.046957 0.000025  0.266425 0.000030  I-0.4134943 0.0000072  1.6172 0.0053  I     0.108    0.300    -0.045    0.300
+17 1 1 57754.00 I  0.045519 0.000025  0.266975 0.000030  I 0.5849223 0.0000064  1.5889 0.0052
+17 1 2 57755.00
+";
+
+    #[test]
+    fn finals2000a() {
+        let data = EopData::from_finals2000a(FINALS_2000A).unwrap();
+        assert_eq!(data.len(), 3);
+        let (start, end) = data.domain().unwrap();
+        assert_eq!(start, Epoch::from_gregorian_utc_at_midnight(2016, 12, 30));
+        assert_eq!(end, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1));
+
+        let first = data.records[0];
+        assert!((first.ut1_minus_utc.to_seconds() + 0.4118744).abs() < 1e-9);
+        assert_eq!(first.x_pole_arcsec, 0.048406);
+        assert_eq!(first.y_pole_arcsec, 0.265880);
+        assert_eq!(first.dx_mas, 0.111);
+        assert_eq!(first.dy_mas, -0.049);
+        assert_eq!(data.records[2].dx_mas, 0.0);
+
+        // Tabulated epochs are returned as is.
+        assert_eq!(data.at(start).unwrap(), first);
+        assert_eq!(data.at(end).unwrap(), data.records[2]);
+        assert!(data.at(start - Unit::Second * 1).is_none());
+        assert!(data.at(end + Unit::Second * 1).is_none());
+
+        // Halfway between two days, the parameters are the average.
+        let noon = data.at(start + Unit::Hour * 12).unwrap();
+        println!("{noon}");
+        assert!((noon.x_pole_arcsec - (0.048406 + 0.046957) / 2.0).abs() < 1e-12);
+        assert!((noon.dy_mas - (-0.049 - 0.045) / 2.0).abs() < 1e-12);
+        assert!((noon.ut1_minus_utc.to_seconds() + (0.4118744 + 0.4134943) / 2.0).abs() < 1e-9);
+
+        // UT1 - UTC jumps by the leap second, but UT1 itself is continuous.
+        let before_leap = data
+            .at(Epoch::from_gregorian_utc_hms(2016, 12, 31, 12, 0, 0))
+            .unwrap();
+        assert!(
+            (before_leap.ut1_minus_utc.to_seconds() - (-0.4134943 + 0.5849223 - 1.0) / 2.0).abs()
+                < 1e-6
+        );
+        let last_hour = data
+            .at(Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 0, 0))
+            .unwrap();
+        assert!((last_hour.ut1_minus_utc.to_seconds() + 0.415).abs() < 1e-3);
+    }
+
+    #[test]
+    fn csv_and_almanac() {
+        let csv = "\
+MJD;Year;Month;Day;Type;x_pole;sigma_x_pole;y_pole;sigma_y_pole;Type;UT1-UTC;sigma_UT1-UTC;LOD;sigma_LOD;Type;dX;sigma_dX;dY;sigma_dY
+60676;2025;01;01;final;0.117800;0.000091;0.304862;0.000091;final;0.0446093;0.0000174;0.5066;0.0121;final;0.288;0.300;-0.035;0.300
+60677;2025;01;02;final;0.116451;0.000091;0.305918;0.000091;final;0.0441203;0.0000174;0.4571;0.0121;final;0.296;0.300;-0.041;0.300
+60678;2025;01;03;prediction;0.115102;;0.306972;;prediction;;;;;;;;;
+";
+        let data = EopData::from_csv(csv).unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.records[1].x_pole_arcsec, 0.116451);
+        assert_eq!(data.records[1].dx_mas, 0.296);
+
+        // Missing columns are reported.
+        assert!(matches!(
+            EopData::from_csv("MJD;x_pole;y_pole\n60676;0.1;0.3\n"),
+            Err(AlmanacError::EopParsing { lno: 1, .. })
+        ));
+
+        let epoch = Epoch::from_gregorian_utc_hms(2025, 1, 1, 6, 0, 0);
+        let almanac = Almanac::default();
+        assert_eq!(
+            almanac.earth_orientation(epoch),
+            Err(AlmanacError::MissingEop { epoch })
+        );
+
+        let almanac = almanac.with_eop_data(data.clone());
+        let eop = almanac.earth_orientation(epoch).unwrap();
+        assert!(
+            (eop.ut1_minus_utc.to_seconds() - (0.75 * 0.0446093 + 0.25 * 0.0441203)).abs() < 1e-9
+        );
+
+        // As a UT1 source, TAI - UT1 is the 37 leap seconds minus UT1 - UTC.
+        let with_ut1 = almanac.with_ut1_provider(data);
+        let tdb_minus_ut1 = with_ut1.tdb_minus_ut1(epoch).unwrap();
+        assert!(
+            (tdb_minus_ut1.to_seconds() - (69.184 - eop.ut1_minus_utc.to_seconds())).abs() < 2e-3
+        );
+    }
+}
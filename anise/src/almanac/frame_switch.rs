@@ -0,0 +1,217 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+
+use super::Almanac;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+/// An ID alias which resolves to a different underlying ID before and after the switch epoch, e.g. to switch from a predicted
+/// attitude to a reconstructed attitude once the latter is available.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrameSwitch {
+    /// ID used up to the switch epoch (excluded)
+    pub before: NaifId,
+    /// ID used from the switch epoch (included)
+    pub after: NaifId,
+    pub switch_epoch: Epoch,
+}
+
+impl FrameSwitch {
+    /// Returns the underlying ID at the provided epoch.
+    pub fn resolve(&self, epoch: Epoch) -> NaifId {
+        if epoch < self.switch_epoch {
+            self.before
+        } else {
+            self.after
+        }
+    }
+}
+
+impl fmt::Display for FrameSwitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} before {}, {} after",
+            self.before, self.switch_epoch, self.after
+        )
+    }
+}
+
+impl Almanac {
+    /// Registers an alias of the provided frame, which resolves to the `before` frame prior to the switch epoch and to the `after`
+    /// frame from the switch epoch onward. The ephemeris ID and the orientation ID of the alias are switched independently, and only
+    /// if they differ from those of one of the underlying frames.
+    ///
+    /// The alias is resolved by the translations and rotations at the epoch of each of their evaluations, including light time
+    /// corrected epochs, and the returned states and rotations are expressed in the alias frame, so it may be used in any query.
+    ///
+    /// # Notes
+    /// + The IDs of the alias should not be used by any loaded SPK or BPC, as the alias takes precedence.
+    /// + Aliases are resolved exactly once: if an underlying frame is itself an alias, it is not resolved again and its IDs must be
+    ///   found in the loaded SPK or BPC. Chained or cyclic switches are therefore not supported, but cannot recurse indefinitely.
+    pub fn with_frame_switch(
+        mut self,
+        alias: Frame,
+        before: Frame,
+        after: Frame,
+        switch_epoch: Epoch,
+    ) -> Self {
+        if alias.ephemeris_id != before.ephemeris_id || alias.ephemeris_id != after.ephemeris_id {
+            self.ephemeris_switches.insert(
+                alias.ephemeris_id,
+                FrameSwitch {
+                    before: before.ephemeris_id,
+                    after: after.ephemeris_id,
+                    switch_epoch,
+                },
+            );
+        }
+        if alias.orientation_id != before.orientation_id
+            || alias.orientation_id != after.orientation_id
+        {
+            self.orientation_switches.insert(
+                alias.orientation_id,
+                FrameSwitch {
+                    before: before.orientation_id,
+                    after: after.orientation_id,
+                    switch_epoch,
+                },
+            );
+        }
+        self
+    }
+
+    /// Returns the underlying frame of the provided frame at the provided epoch, which is the frame itself if it is not an alias.
+    pub fn resolve_frame_switch(&self, frame: Frame, epoch: Epoch) -> Frame {
+        let mut resolved = frame;
+        if let Some(switch) = self.ephemeris_switches.get(&frame.ephemeris_id) {
+            resolved.ephemeris_id = switch.resolve(epoch);
+        }
+        if let Some(switch) = self.orientation_switches.get(&frame.orientation_id) {
+            resolved.orientation_id = switch.resolve(epoch);
+        }
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod ut_frame_switch {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::constants::orientations::J2000;
+    use crate::math::rotation::EulerParameter;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::prelude::{Aberration, Almanac, Frame};
+    use crate::structure::EulerParameterDataSet;
+    use core::f64::consts::FRAC_PI_2;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn switch_ephemeris_and_attitude() {
+        let spk = test_spk(&[
+            fixed_segment(399, 0, [0.0; 3]),
+            fixed_segment(-85001, 0, [7000.0, 0.0, 0.0]),
+            fixed_segment(-85002, 0, [7000.0, 1.0, 0.0]),
+        ]);
+
+        let mut attitude = EulerParameterDataSet::default();
+        for (id, q) in [
+            (-85100, EulerParameter::identity(-85100, J2000)),
+            (-85200, EulerParameter::about_z(FRAC_PI_2, -85200, J2000)),
+        ] {
+            attitude.push(q, Some(id), None).unwrap();
+        }
+
+        let switch_epoch = Epoch::from_et_seconds(43_200.0);
+        let alias = Frame::new(-85000, -85000);
+        let almanac = Almanac::default()
+            .with_spk(spk)
+            .with_euler_parameters(attitude)
+            .with_frame_switch(
+                alias,
+                Frame::new(-85001, -85100),
+                Frame::new(-85002, -85200),
+                switch_epoch,
+            );
+
+        let before = switch_epoch - Unit::Hour * 1;
+        let after = switch_epoch + Unit::Hour * 1;
+        assert_eq!(
+            almanac.resolve_frame_switch(alias, before),
+            Frame::new(-85001, -85100)
+        );
+        assert_eq!(
+            almanac.resolve_frame_switch(alias, switch_epoch),
+            Frame::new(-85002, -85200)
+        );
+        assert_eq!(
+            almanac.resolve_frame_switch(EARTH_J2000, before),
+            EARTH_J2000
+        );
+
+        // Translations
+        let alias_j2k = alias.with_orient(J2000);
+        let state = almanac
+            .translate(alias_j2k, EARTH_J2000, before, None)
+            .unwrap();
+        assert_eq!(state.radius_km, Vector3::new(7000.0, 0.0, 0.0));
+        let state = almanac
+            .translate(alias_j2k, EARTH_J2000, after, None)
+            .unwrap();
+        assert_eq!(state.radius_km, Vector3::new(7000.0, 1.0, 0.0));
+        assert_eq!(state.frame, EARTH_J2000);
+        let state = almanac
+            .translate(EARTH_J2000, alias_j2k, after, Aberration::LT)
+            .unwrap();
+        assert!((state.radius_km + Vector3::new(7000.0, 1.0, 0.0)).norm() < 1e-9);
+        assert_eq!(state.frame.ephemeris_id, -85000);
+
+        // Rotations: the Earth as seen from the spacecraft body frame
+        let dcm = almanac.rotate(EARTH_J2000, alias, before).unwrap();
+        assert_eq!((dcm.from, dcm.to), (J2000, -85000));
+        let state = almanac.transform(EARTH_J2000, alias, before, None).unwrap();
+        assert!((state.radius_km - Vector3::new(-7000.0, 0.0, 0.0)).norm() < 1e-9);
+        assert_eq!(state.frame, alias);
+
+        let state = almanac.transform(EARTH_J2000, alias, after, None).unwrap();
+        let expected = almanac
+            .transform(EARTH_J2000, Frame::new(-85002, -85200), after, None)
+            .unwrap()
+            .radius_km;
+        assert!((expected - Vector3::new(-7000.0, -1.0, 0.0)).norm() > 1.0);
+        assert!((state.radius_km - expected).norm() < 1e-9, "{state}");
+
+        // The Earth as seen from the spacecraft, transformed back to the Earth
+        let back = almanac.transform_to(state, EARTH_J2000, None).unwrap();
+        assert!(back.radius_km.norm() < 1e-9);
+    }
+
+    #[test]
+    fn cyclic_switches_do_not_recurse() {
+        let spk = test_spk(&[fixed_segment(399, 0, [0.0; 3])]);
+
+        let switch_epoch = Epoch::from_et_seconds(43_200.0);
+        let (a, b) = (Frame::new(-85001, -85001), Frame::new(-85002, -85002));
+        let almanac = Almanac::default()
+            .with_spk(spk)
+            .with_frame_switch(a, b, b, switch_epoch)
+            .with_frame_switch(b, a, a, switch_epoch);
+
+        // Each alias resolves once to the other one, which is not loaded.
+        assert_eq!(almanac.resolve_frame_switch(a, switch_epoch), b);
+        assert!(almanac
+            .translate(a.with_orient(J2000), EARTH_J2000, switch_epoch, None)
+            .is_err());
+        assert!(almanac.rotate(a, EARTH_J2000, switch_epoch).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::astro::Aberration;
+use crate::constants::frames::SUN_J2000;
+use crate::errors::{AlmanacPhysicsSnafu, AlmanacResult, PhysicsError};
+use crate::math::Vector3;
+use crate::prelude::Frame;
+
+/// Illumination angles at a point on the surface of a body, as computed by [Almanac::illumination_angles].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IlluminationAngles {
+    pub epoch: Epoch,
+    /// Angle between the directions from the surface point to the Sun and to the observer
+    pub phase_angle_deg: f64,
+    /// Angle between the surface normal and the direction from the surface point to the Sun, above 90 degrees at night
+    pub solar_incidence_angle_deg: f64,
+    /// Angle between the surface normal and the direction from the surface point to the observer, above 90 degrees if the point faces away
+    pub emission_angle_deg: f64,
+}
+
+impl IlluminationAngles {
+    /// Returns true if the Sun is above the local horizon of the surface point.
+    pub fn is_lit(&self) -> bool {
+        self.solar_incidence_angle_deg < 90.0
+    }
+
+    /// Returns true if the observer is above the local horizon of the surface point.
+    pub fn is_visible(&self) -> bool {
+        self.emission_angle_deg < 90.0
+    }
+}
+
+impl fmt::Display for IlluminationAngles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: phase = {:.6} deg, incidence = {:.6} deg, emission = {:.6} deg",
+            self.epoch,
+            self.phase_angle_deg,
+            self.solar_incidence_angle_deg,
+            self.emission_angle_deg
+        )
+    }
+}
+
+impl Almanac {
+    /// Computes the phase, solar incidence, and emission angles at the provided surface point, in km in the body fixed target frame,
+    /// as seen from the observer. This is the equivalent of the SPICE `ilumin` routine.
+    ///
+    /// The surface normal is that of the ellipsoid of the target frame, fetched from the loaded planetary data if the frame does not define it.
+    ///
+    /// # Notes
+    /// The aberration corrections are computed for the center of the target body, and not for the surface point itself.
+    pub fn illumination_angles(
+        &self,
+        surface_point_km: Vector3,
+        target_frame: Frame,
+        observer: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<IlluminationAngles> {
+        let target_frame = if target_frame.shape.is_some() {
+            target_frame
+        } else {
+            self.frame_info(target_frame).unwrap_or(target_frame)
+        };
+        let shape = target_frame
+            .shape
+            .ok_or(PhysicsError::MissingFrameData {
+                action: "computing illumination angles",
+                data: "shape",
+                frame: target_frame.into(),
+            })
+            .context(AlmanacPhysicsSnafu {
+                action: "computing illumination angles",
+            })?;
+
+        let observer_km = self
+            .transform(observer, target_frame, epoch, ab_corr)?
+            .radius_km;
+        let sun_km = self
+            .transform(SUN_J2000, target_frame, epoch, ab_corr)?
+            .radius_km;
+
+        let to_observer = (observer_km - surface_point_km).normalize();
+        let to_sun = (sun_km - surface_point_km).normalize();
+
+        Ok(IlluminationAngles {
+            epoch,
+            phase_angle_deg: to_sun
+                .dot(&to_observer)
+                .clamp(-1.0, 1.0)
+                .acos()
+                .to_degrees(),
+            solar_incidence_angle_deg: shape.solar_incidence_angle_deg(surface_point_km, sun_km),
+            emission_angle_deg: shape.emission_angle_deg(surface_point_km, observer_km),
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_illumination {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use hifitime::Epoch;
+
+    #[test]
+    fn illumination_angles() {
+        let au_km = 1.5e8;
+        // The subsolar point of the Earth, seen 45 degrees off its zenith
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(399, 0, [au_km, 0.0, 0.0]),
+            fixed_segment(-85, 0, [au_km - 6378.0 - 1000.0, 1000.0, 0.0]),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        // A spherical Earth whose "body fixed" frame is J2000
+        let earth = EARTH_J2000.with_ellipsoid(Ellipsoid::from_sphere(6378.0));
+        let epoch = Epoch::from_et_seconds(43_200.0);
+
+        let angles = almanac
+            .illumination_angles(
+                Vector3::new(-6378.0, 0.0, 0.0),
+                earth,
+                Frame::from_ephem_j2000(-85),
+                epoch,
+                None,
+            )
+            .unwrap();
+        assert!(angles.solar_incidence_angle_deg.abs() < 1e-6);
+        assert!((angles.emission_angle_deg - 45.0).abs() < 1e-9);
+        assert!((angles.phase_angle_deg - 45.0).abs() < 1e-9);
+        assert!(angles.is_lit() && angles.is_visible());
+
+        // The antisolar point is at night and hidden from the spacecraft
+        let night = almanac
+            .illumination_angles(
+                Vector3::new(6378.0, 0.0, 0.0),
+                earth,
+                Frame::from_ephem_j2000(-85),
+                epoch,
+                None,
+            )
+            .unwrap();
+        assert!((night.solar_incidence_angle_deg - 180.0).abs() < 1e-6);
+        assert!(!night.is_lit() && !night.is_visible());
+
+        // The shape is required
+        assert!(almanac
+            .illumination_angles(
+                Vector3::new(-6378.0, 0.0, 0.0),
+                EARTH_J2000,
+                Frame::from_ephem_j2000(-85),
+                epoch,
+                None,
+            )
+            .is_err());
+    }
+}
@@ -8,8 +8,14 @@
  * Documentation: https://nyxspace.com/
  */
 
+use super::eclipse::find_windows;
 use super::Almanac;
-use crate::errors::{AlmanacPhysicsSnafu, AlmanacResult};
+use crate::astro::Aberration;
+use crate::constants::orientations::J2000;
+use crate::errors::{AlmanacPhysicsSnafu, AlmanacResult, OrientationSnafu};
+use crate::math::rotation::EulerParameter;
+use crate::math::Vector3;
+use crate::prelude::Frame;
 use crate::{
     errors::AlmanacError,
     math::rotation::Quaternion,
@@ -17,8 +23,23 @@ use crate::{
     structure::{dataset::DataSetError, instrument::Instrument, lookuptable::LutError},
 };
 
+use core::f64::consts::TAU;
+use hifitime::{Duration, Epoch};
 use snafu::ResultExt;
 
+/// Number of points of the apparent limb of an ellipsoid checked against the field of view of an instrument.
+const FOV_LIMB_POINTS: usize = 72;
+
+/// Shape of a target body checked against the field of view of an instrument.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FovTargetShape {
+    /// The target is its center only
+    #[default]
+    Point,
+    /// The target is the ellipsoid of its body fixed frame, which is in the FOV if any part of it is
+    Ellipsoid,
+}
+
 impl Almanac {
     /// Returns the Instrument from its ID, searching through all loaded instrument datasets in reverse order.
     pub fn instrument_from_id(&self, id: i32) -> AlmanacResult<Instrument> {
@@ -71,4 +92,362 @@ impl Almanac {
                 action: "instrument FOV",
             })
     }
+
+    /// Returns the instrument and the rotation from the spacecraft frame to the body frame on which the instrument is mounted,
+    /// i.e. the "from" frame of the mounting rotation of the instrument, whose attitude is fetched from the loaded CK/BPC or Euler parameter data.
+    fn instrument_attitude(
+        &self,
+        instrument_id: i32,
+        sc_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<(Instrument, EulerParameter)> {
+        let instrument = self.instrument_from_id(instrument_id)?;
+        let sc_to_body = self
+            .rotate(
+                sc_frame,
+                sc_frame.with_orient(instrument.q_to_i.from),
+                epoch,
+            )
+            .context(OrientationSnafu {
+                action: "fetching the attitude of the instrument",
+            })?;
+
+        Ok((instrument, EulerParameter::from(sc_to_body)))
+    }
+
+    /// Returns the angular margin in degrees of the provided direction, in the spacecraft frame, to the FOV boundary of the instrument.
+    /// The direction is considered from the origin of the instrument, i.e. the mounting offset is ignored.
+    fn direction_fov_margin_deg(
+        instrument: &Instrument,
+        sc_q_to_b: EulerParameter,
+        direction: Vector3,
+        sc_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<f64> {
+        let instrument = Instrument {
+            offset_i: Vector3::zeros(),
+            ..*instrument
+        };
+        let sc_state = Orbit::zero_at_epoch(epoch, sc_frame);
+        let mut target_state = sc_state;
+        target_state.radius_km = direction;
+
+        instrument
+            .fov_margin_deg(sc_q_to_b, sc_state, target_state)
+            .context(AlmanacPhysicsSnafu {
+                action: "instrument FOV",
+            })
+    }
+
+    /// Returns the angular margin in degrees of the center of the target to the FOV boundary of the instrument of the spacecraft,
+    /// cf. [Instrument::fov_margin_deg], using the attitude of the spacecraft body from the loaded data.
+    ///
+    /// The spacecraft frame sets the ephemeris ID of the spacecraft and the orientation in which the geometry is computed, e.g. J2000.
+    /// The body frame of the spacecraft is the "from" frame of the mounting rotation of the instrument, and its attitude is fetched from the
+    /// loaded CK/BPC or Euler parameter data.
+    pub fn fov_margin_to_body_deg(
+        &self,
+        instrument_id: i32,
+        sc_frame: Frame,
+        target_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<f64> {
+        let (instrument, sc_q_to_b) = self.instrument_attitude(instrument_id, sc_frame, epoch)?;
+
+        let sc_state = Orbit::zero_at_epoch(epoch, sc_frame);
+        let mut target_state = sc_state;
+        target_state.radius_km = self
+            .transform(target_frame, sc_frame, epoch, ab_corr)?
+            .radius_km;
+
+        instrument
+            .fov_margin_deg(sc_q_to_b, sc_state, target_state)
+            .context(AlmanacPhysicsSnafu {
+                action: "instrument FOV",
+            })
+    }
+
+    /// Returns whether the target body is in the FOV of the instrument of the spacecraft at the provided epoch, like the SPICE `fovtrg` routine.
+    ///
+    /// With the [FovTargetShape::Point] shape, only the center of the target is checked, cf. [Self::fov_margin_to_body_deg].
+    /// With the [FovTargetShape::Ellipsoid] shape, the target is in the FOV if its center, any of 72 points of its apparent limb,
+    /// or the intersection of the boresight with its ellipsoid is in the FOV: the ellipsoid must be defined in the planetary data of the
+    /// body fixed target frame. A target that contains the spacecraft is always in the FOV.
+    pub fn is_body_in_fov(
+        &self,
+        instrument_id: i32,
+        sc_frame: Frame,
+        target_frame: Frame,
+        shape: FovTargetShape,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<bool> {
+        if self.fov_margin_to_body_deg(instrument_id, sc_frame, target_frame, epoch, ab_corr)?
+            >= 0.0
+        {
+            return Ok(true);
+        } else if shape == FovTargetShape::Point {
+            return Ok(false);
+        }
+
+        let action = "checking the ellipsoid in the instrument FOV";
+        let (instrument, sc_q_to_b) = self.instrument_attitude(instrument_id, sc_frame, epoch)?;
+        let geometry = self.body_fixed_geometry(target_frame, sc_frame, epoch, ab_corr, action)?;
+
+        let Some((center_km, semi_major_km, semi_minor_km)) =
+            geometry.shape.limb(geometry.observer_km)
+        else {
+            // The spacecraft is on or inside the ellipsoid.
+            return Ok(true);
+        };
+
+        // Rotation from the body fixed target frame into the spacecraft frame.
+        let body_to_sc = self
+            .rotate(sc_frame.with_orient(J2000), sc_frame, epoch)
+            .context(OrientationSnafu { action })?
+            .rot_mat
+            * geometry.inertial_to_body.rot_mat.transpose();
+
+        for i in 0..FOV_LIMB_POINTS {
+            let (sin_a, cos_a) = (i as f64 * TAU / FOV_LIMB_POINTS as f64).sin_cos();
+            let limb_point_km = center_km + semi_major_km * cos_a + semi_minor_km * sin_a;
+            let direction = body_to_sc * (limb_point_km - geometry.observer_km);
+            if Self::direction_fov_margin_deg(&instrument, sc_q_to_b, direction, sc_frame, epoch)?
+                >= 0.0
+            {
+                return Ok(true);
+            }
+        }
+
+        // The FOV may also be entirely within the apparent disk of the target.
+        let q_sc_to_i = (instrument.q_to_i * sc_q_to_b).context(AlmanacPhysicsSnafu { action })?;
+        let boresight_body = body_to_sc.transpose() * (q_sc_to_i.conjugate() * Vector3::z());
+
+        Ok(geometry
+            .shape
+            .intersect(geometry.observer_km, boresight_body)
+            .is_some())
+    }
+
+    /// Returns whether the ray, i.e. the provided direction expressed in the ray frame, is in the FOV of the instrument of the spacecraft
+    /// at the provided epoch, like the SPICE `fovray` routine. Refer to [Self::fov_margin_to_body_deg] for the spacecraft frame.
+    pub fn is_ray_in_fov(
+        &self,
+        instrument_id: i32,
+        sc_frame: Frame,
+        ray: Vector3,
+        ray_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<bool> {
+        let (instrument, sc_q_to_b) = self.instrument_attitude(instrument_id, sc_frame, epoch)?;
+        let direction = self
+            .rotate(ray_frame, sc_frame, epoch)
+            .context(OrientationSnafu {
+                action: "rotating the ray into the spacecraft frame",
+            })?
+            * ray;
+
+        Ok(
+            Self::direction_fov_margin_deg(&instrument, sc_q_to_b, direction, sc_frame, epoch)?
+                >= 0.0,
+        )
+    }
+
+    /// Searches for the time intervals when the target body is in the FOV of the instrument of the spacecraft, cf. [Self::is_body_in_fov],
+    /// between the start and end epochs, like the SPICE `gftfov` routine.
+    ///
+    /// The time span is sampled at the provided step, which must be shorter than the shortest window to find, and the bounds of
+    /// each window are then refined by bisection to ten milliseconds. Windows in progress at the start or the end epoch are
+    /// clipped to these epochs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn body_in_fov_windows(
+        &self,
+        instrument_id: i32,
+        sc_frame: Frame,
+        target_frame: Frame,
+        shape: FovTargetShape,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Epoch)>> {
+        find_windows(start_epoch, end_epoch, step, |epoch| {
+            self.is_body_in_fov(instrument_id, sc_frame, target_frame, shape, epoch, ab_corr)
+        })
+    }
+
+    /// Searches for the time intervals when the ray is in the FOV of the instrument of the spacecraft, cf. [Self::is_ray_in_fov],
+    /// between the start and end epochs, like the SPICE `gfrfov` routine. Refer to [Self::body_in_fov_windows] for the search.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ray_in_fov_windows(
+        &self,
+        instrument_id: i32,
+        sc_frame: Frame,
+        ray: Vector3,
+        ray_frame: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+    ) -> AlmanacResult<Vec<(Epoch, Epoch)>> {
+        find_windows(start_epoch, end_epoch, step, |epoch| {
+            self.is_ray_in_fov(instrument_id, sc_frame, ray, ray_frame, epoch)
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_instrument_fov {
+    use super::FovTargetShape;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::constants::orientations::J2000;
+    use crate::math::rotation::EulerParameter;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{fixed_segment, linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::instrument::{FovShape, Instrument};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use crate::structure::{EulerParameterDataSet, InstrumentDataSet};
+    use core::f64::consts::FRAC_PI_2;
+    use hifitime::{Epoch, Unit};
+
+    /// Spacecraft 1000 km above the Earth along -X, moving along +Y, with its body frame aligned with J2000, and instruments whose
+    /// boresight are +X (10 deg), +Y (10 and 35 deg), and 20 deg from +X towards +Y (1 deg).
+    fn almanac() -> Almanac {
+        let spk = test_spk(&[
+            fixed_segment(399, 0, [0.0; 3]),
+            linear_segment(-85, 0, [-7378.0, 0.0, 0.0], [0.0, 10_000.0 / 43_200.0, 0.0]),
+        ]);
+
+        let mut attitude = EulerParameterDataSet::default();
+        attitude
+            .push(
+                EulerParameter::identity(-85000, J2000),
+                Some(-85000),
+                Some("SC_BODY"),
+            )
+            .unwrap();
+
+        let to_x = EulerParameter::about_y(FRAC_PI_2, -85000, -85100);
+        let to_y = EulerParameter::about_x(-FRAC_PI_2, -85000, -85100);
+        let tilted =
+            (EulerParameter::about_x(-20.0_f64.to_radians(), -85100, -85100) * to_x).unwrap();
+
+        let mut instruments = InstrumentDataSet::default();
+        for (id, q_to_i, half_angle_deg) in [
+            (-85101, to_x, 10.0),
+            (-85102, to_y, 10.0),
+            (-85103, to_y, 35.0),
+            (-85104, tilted, 1.0),
+        ] {
+            let instrument = Instrument {
+                q_to_i,
+                offset_i: Vector3::zeros(),
+                fov: FovShape::Conical { half_angle_deg },
+            };
+            instruments.push(instrument, Some(id), None).unwrap();
+        }
+
+        Almanac::default()
+            .with_spk(spk)
+            .with_euler_parameters(attitude)
+            .with_instrument_data(instruments)
+    }
+
+    #[test]
+    fn body_and_ray_in_fov() {
+        let almanac = almanac();
+        let sc = Frame::from_ephem_j2000(-85);
+        let earth = EARTH_J2000.with_ellipsoid(Ellipsoid::from_sphere(6378.0));
+        let epoch = Epoch::from_et_seconds(43_200.0);
+
+        // Boresight towards the Earth center
+        let margin = almanac
+            .fov_margin_to_body_deg(-85101, sc, earth, epoch, None)
+            .unwrap();
+        assert!((margin - 10.0).abs() < 1e-9, "{margin}");
+        for shape in [FovTargetShape::Point, FovTargetShape::Ellipsoid] {
+            assert!(almanac
+                .is_body_in_fov(-85101, sc, earth, shape, epoch, None)
+                .unwrap());
+        }
+
+        // Boresight 90 deg from the Earth center, whose angular radius is 59.8 deg: the limb is 30.2 deg off boresight.
+        for shape in [FovTargetShape::Point, FovTargetShape::Ellipsoid] {
+            assert!(!almanac
+                .is_body_in_fov(-85102, sc, earth, shape, epoch, None)
+                .unwrap());
+        }
+        assert!(!almanac
+            .is_body_in_fov(-85103, sc, earth, FovTargetShape::Point, epoch, None)
+            .unwrap());
+        assert!(almanac
+            .is_body_in_fov(-85103, sc, earth, FovTargetShape::Ellipsoid, epoch, None)
+            .unwrap());
+
+        // Narrow FOV entirely within the apparent disk of the Earth
+        assert!(!almanac
+            .is_body_in_fov(-85104, sc, earth, FovTargetShape::Point, epoch, None)
+            .unwrap());
+        assert!(almanac
+            .is_body_in_fov(-85104, sc, earth, FovTargetShape::Ellipsoid, epoch, None)
+            .unwrap());
+
+        // Rays
+        assert!(almanac
+            .is_ray_in_fov(-85101, sc, Vector3::x(), EARTH_J2000, epoch)
+            .unwrap());
+        assert!(!almanac
+            .is_ray_in_fov(-85101, sc, Vector3::y(), EARTH_J2000, epoch)
+            .unwrap());
+        assert!(almanac
+            .is_ray_in_fov(-85102, sc, Vector3::new(0.1, 1.0, 0.0), EARTH_J2000, epoch)
+            .unwrap());
+
+        // Unknown instrument
+        assert!(almanac
+            .is_ray_in_fov(-85199, sc, Vector3::x(), EARTH_J2000, epoch)
+            .is_err());
+    }
+
+    #[test]
+    fn fov_windows() {
+        let almanac = almanac();
+        let sc = Frame::from_ephem_j2000(-85);
+        let start = Epoch::from_et_seconds(0.0);
+        let end = Epoch::from_et_seconds(86_400.0);
+
+        // The spacecraft moves along +Y at 10000 km per half day, so the Earth center is within 10 deg of the +X boresight
+        // while the spacecraft is within 7378 tan(10 deg) km of the X axis.
+        let half_window_s = 7378.0 * 10.0_f64.to_radians().tan() / 10_000.0 * 43_200.0;
+        let windows = almanac
+            .body_in_fov_windows(
+                -85101,
+                sc,
+                EARTH_J2000,
+                FovTargetShape::Point,
+                start,
+                end,
+                Unit::Minute * 10,
+                None,
+            )
+            .unwrap();
+        assert_eq!(windows.len(), 1);
+        assert!(((windows[0].0 - start).to_seconds() - (43_200.0 - half_window_s)).abs() < 0.1);
+        assert!(((windows[0].1 - start).to_seconds() - (43_200.0 + half_window_s)).abs() < 0.1);
+
+        // A fixed ray is always in the FOV since the attitude is fixed.
+        let windows = almanac
+            .ray_in_fov_windows(
+                -85101,
+                sc,
+                Vector3::x(),
+                EARTH_J2000,
+                start,
+                end,
+                Unit::Hour * 1,
+            )
+            .unwrap();
+        assert_eq!(windows, vec![(start, end)]);
+    }
 }
@@ -0,0 +1,289 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Epoch, Unit};
+use snafu::ResultExt;
+
+use super::{Almanac, IlluminationAngles};
+use crate::astro::Aberration;
+use crate::constants::orientations::J2000;
+use crate::constants::SPEED_OF_LIGHT_KM_S;
+use crate::errors::{AlmanacPhysicsSnafu, AlmanacResult, OrientationSnafu, PhysicsError};
+use crate::math::rotation::DCM;
+use crate::math::Vector3;
+use crate::prelude::Frame;
+use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+
+/// Intersection of a ray from an observer with the surface of a target body, as computed by [Almanac::surface_intercept].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SurfaceIntercept {
+    /// Epoch of the target at which the intercept is computed, i.e. the observation epoch corrected for the light time, if any
+    pub target_epoch: Epoch,
+    /// Intercept point on the ellipsoid, in km in the body fixed target frame
+    pub point_km: Vector3,
+    /// Vector from the observer to the intercept point, in km in the body fixed target frame
+    pub observer_to_point_km: Vector3,
+    /// Illumination angles at the intercept point, as seen from the observer
+    pub illumination: IlluminationAngles,
+}
+
+impl SurfaceIntercept {
+    /// Returns the distance from the observer to the intercept point in km.
+    pub fn range_km(&self) -> f64 {
+        self.observer_to_point_km.norm()
+    }
+}
+
+impl fmt::Display for SurfaceIntercept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "intercept at [{:.6}, {:.6}, {:.6}] km ({} at target, range {:.6} km): {}",
+            self.point_km.x,
+            self.point_km.y,
+            self.point_km.z,
+            self.target_epoch,
+            self.range_km(),
+            self.illumination
+        )
+    }
+}
+
+impl Almanac {
+    /// Computes the intersection of the ray from the observer along the provided direction, expressed in the direction frame,
+    /// with the ellipsoid of the body fixed target frame. This is the equivalent of the SPICE `sincpt` routine for ellipsoids.
+    ///
+    /// Returns None if the ray does not intersect the ellipsoid. The ellipsoid is fetched from the loaded planetary data if the
+    /// target frame does not define it.
+    ///
+    /// # Notes
+    /// With aberration corrections, the orientation of the target is evaluated at the epoch corrected for the light time to the
+    /// center of the target body, and not to the intercept point itself, like [Almanac::illumination_angles].
+    pub fn surface_intercept(
+        &self,
+        target_frame: Frame,
+        observer: Frame,
+        direction: Vector3,
+        direction_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Option<SurfaceIntercept>> {
+        let geometry = self.body_fixed_geometry(
+            target_frame,
+            observer,
+            epoch,
+            ab_corr,
+            "computing surface intercept",
+        )?;
+        let direction_body = geometry.direction_in_body(
+            self,
+            direction,
+            direction_frame,
+            epoch,
+            "rotating the surface intercept direction",
+        )?;
+
+        let Some(point_km) = geometry
+            .shape
+            .intersect(geometry.observer_km, direction_body)
+        else {
+            return Ok(None);
+        };
+
+        let illumination =
+            self.illumination_angles(point_km, geometry.target_frame, observer, epoch, ab_corr)?;
+
+        Ok(Some(SurfaceIntercept {
+            target_epoch: geometry.target_epoch,
+            point_km,
+            observer_to_point_km: point_km - geometry.observer_km,
+            illumination,
+        }))
+    }
+
+    /// Returns the geometry of the observer in the body fixed target frame, corrected for the light time to the center of the
+    /// target if requested. The ellipsoid is fetched from the loaded planetary data if the target frame does not define it.
+    pub(crate) fn body_fixed_geometry(
+        &self,
+        target_frame: Frame,
+        observer: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+        action: &'static str,
+    ) -> AlmanacResult<BodyFixedGeometry> {
+        let target_frame = if target_frame.shape.is_some() {
+            target_frame
+        } else {
+            self.frame_info(target_frame).unwrap_or(target_frame)
+        };
+        let shape = target_frame
+            .shape
+            .ok_or(PhysicsError::MissingFrameData {
+                action,
+                data: "shape",
+                frame: target_frame.into(),
+            })
+            .context(AlmanacPhysicsSnafu { action })?;
+
+        // Position of the target center seen from the observer, in the inertial frame.
+        let target_km = self
+            .transform(
+                target_frame.with_orient(J2000),
+                observer.with_orient(J2000),
+                epoch,
+                ab_corr,
+            )?
+            .radius_km;
+
+        let target_epoch = match ab_corr {
+            Some(ab_corr) => {
+                let light_time = Unit::Second * (target_km.norm() / SPEED_OF_LIGHT_KM_S);
+                if ab_corr.transmit_mode {
+                    epoch + light_time
+                } else {
+                    epoch - light_time
+                }
+            }
+            None => epoch,
+        };
+
+        // The target is oriented at the target epoch.
+        let inertial_to_body = self
+            .rotate(target_frame.with_orient(J2000), target_frame, target_epoch)
+            .context(OrientationSnafu { action })?;
+
+        Ok(BodyFixedGeometry {
+            target_frame,
+            shape,
+            target_epoch,
+            inertial_to_body,
+            observer_km: inertial_to_body * -target_km,
+        })
+    }
+}
+
+/// Geometry of an observer relative to the ellipsoid of a body fixed target frame, shared by the surface queries.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct BodyFixedGeometry {
+    /// Target frame, including its shape
+    pub target_frame: Frame,
+    /// Ellipsoid of the target
+    pub shape: Ellipsoid,
+    /// Epoch of the target, i.e. the observation epoch corrected for the light time, if any
+    pub target_epoch: Epoch,
+    /// Rotation from the inertial frame into the body fixed target frame, at the target epoch
+    pub inertial_to_body: DCM,
+    /// Position of the observer, in km in the body fixed target frame
+    pub observer_km: Vector3,
+}
+
+impl BodyFixedGeometry {
+    /// Returns the provided direction, pointed at the observation epoch in the direction frame, in the body fixed target frame.
+    pub(crate) fn direction_in_body(
+        &self,
+        almanac: &Almanac,
+        direction: Vector3,
+        direction_frame: Frame,
+        epoch: Epoch,
+        action: &'static str,
+    ) -> AlmanacResult<Vector3> {
+        let direction_inertial = almanac
+            .rotate(direction_frame, direction_frame.with_orient(J2000), epoch)
+            .context(OrientationSnafu { action })?
+            * direction;
+
+        Ok(self.inertial_to_body * direction_inertial)
+    }
+}
+
+#[cfg(test)]
+mod ut_intercept {
+    use crate::astro::Aberration;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::constants::SPEED_OF_LIGHT_KM_S;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn surface_intercept() {
+        let au_km = 1.5e8;
+        // The spacecraft is 1000 km above the subsolar point of the Earth
+        let spk = test_spk(&[
+            fixed_segment(10, 0, [0.0; 3]),
+            fixed_segment(399, 0, [au_km, 0.0, 0.0]),
+            fixed_segment(-85, 0, [au_km - 7378.0, 0.0, 0.0]),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        // A spherical Earth whose "body fixed" frame is J2000
+        let earth = EARTH_J2000.with_ellipsoid(Ellipsoid::from_sphere(6378.0));
+        let sc = Frame::from_ephem_j2000(-85);
+        let epoch = Epoch::from_et_seconds(43_200.0);
+
+        // Nadir pointing hits the subsolar point
+        let intercept = almanac
+            .surface_intercept(earth, sc, Vector3::x(), EARTH_J2000, epoch, None)
+            .unwrap()
+            .unwrap();
+        assert!((intercept.point_km - Vector3::new(-6378.0, 0.0, 0.0)).norm() < 1e-9);
+        assert!((intercept.range_km() - 1000.0).abs() < 1e-9);
+        assert_eq!(intercept.target_epoch, epoch);
+        assert!(intercept.illumination.solar_incidence_angle_deg.abs() < 1e-6);
+        assert!(intercept.illumination.emission_angle_deg.abs() < 1e-6);
+
+        // Off nadir pointing hits the sphere further away with a larger emission angle
+        let off_nadir = almanac
+            .surface_intercept(
+                earth,
+                sc,
+                Vector3::new(1.0, 0.3, 0.0),
+                EARTH_J2000,
+                epoch,
+                None,
+            )
+            .unwrap()
+            .unwrap();
+        assert!((off_nadir.point_km.norm() - 6378.0).abs() < 1e-9);
+        assert!(off_nadir.range_km() > 1000.0);
+        assert!(off_nadir.illumination.emission_angle_deg > 10.0);
+        // The intercept is along the ray
+        assert!(
+            (off_nadir.observer_to_point_km.normalize() - Vector3::new(1.0, 0.3, 0.0).normalize())
+                .norm()
+                < 1e-12
+        );
+
+        // Pointing away or along the horizon misses
+        assert!(almanac
+            .surface_intercept(earth, sc, -Vector3::x(), EARTH_J2000, epoch, None)
+            .unwrap()
+            .is_none());
+        assert!(almanac
+            .surface_intercept(earth, sc, Vector3::y(), EARTH_J2000, epoch, None)
+            .unwrap()
+            .is_none());
+
+        // With light time, the target epoch precedes the observation
+        let corrected = almanac
+            .surface_intercept(earth, sc, Vector3::x(), EARTH_J2000, epoch, Aberration::LT)
+            .unwrap()
+            .unwrap();
+        let light_time = Unit::Second * (7378.0 / SPEED_OF_LIGHT_KM_S);
+        assert!((epoch - corrected.target_epoch - light_time).abs() < Unit::Microsecond * 1);
+
+        // The shape is required
+        assert!(almanac
+            .surface_intercept(EARTH_J2000, sc, Vector3::x(), EARTH_J2000, epoch, None)
+            .is_err());
+    }
+}
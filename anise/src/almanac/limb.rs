@@ -0,0 +1,302 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+
+use super::Almanac;
+use crate::astro::Aberration;
+use crate::errors::AlmanacResult;
+use crate::math::Vector3;
+use crate::prelude::Frame;
+
+/// Point of a ray from an observer nearest to the ellipsoid of a target body, as computed by [Almanac::tangent_point].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TangentPoint {
+    /// Epoch of the target at which the tangent point is computed, i.e. the observation epoch corrected for the light time, if any
+    pub target_epoch: Epoch,
+    /// Point of the ray nearest to the ellipsoid, in km in the body fixed target frame
+    pub tangent_point_km: Vector3,
+    /// Point of the ellipsoid nearest to the tangent point, in km in the body fixed target frame
+    pub surface_point_km: Vector3,
+    /// Vector from the observer to the tangent point, in km in the body fixed target frame
+    pub observer_to_tangent_km: Vector3,
+}
+
+impl TangentPoint {
+    /// Returns the altitude of the tangent point above the ellipsoid in km, which is zero if the ray intersects the ellipsoid.
+    pub fn altitude_km(&self) -> f64 {
+        (self.tangent_point_km - self.surface_point_km).norm()
+    }
+
+    /// Returns the distance from the observer to the tangent point in km.
+    pub fn range_km(&self) -> f64 {
+        self.observer_to_tangent_km.norm()
+    }
+}
+
+impl fmt::Display for TangentPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tangent point at [{:.6}, {:.6}, {:.6}] km ({} at target, range {:.6} km, altitude {:.6} km)",
+            self.tangent_point_km.x,
+            self.tangent_point_km.y,
+            self.tangent_point_km.z,
+            self.target_epoch,
+            self.range_km(),
+            self.altitude_km()
+        )
+    }
+}
+
+/// Apparent limb of the ellipsoid of a target body as seen from an observer, as computed by [Almanac::limb].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Limb {
+    /// Epoch of the target at which the limb is computed, i.e. the observation epoch corrected for the light time, if any
+    pub target_epoch: Epoch,
+    /// Center of the limb ellipse, in km in the body fixed target frame
+    pub center_km: Vector3,
+    /// Semi major axis vector of the limb ellipse, in km in the body fixed target frame
+    pub semi_major_km: Vector3,
+    /// Semi minor axis vector of the limb ellipse, in km in the body fixed target frame
+    pub semi_minor_km: Vector3,
+    /// Position of the observer, in km in the body fixed target frame
+    pub observer_km: Vector3,
+}
+
+impl Limb {
+    /// Returns the point of the limb at the provided angle from the semi major axis, in km in the body fixed target frame.
+    pub fn point_km(&self, angle_deg: f64) -> Vector3 {
+        let (sin_a, cos_a) = angle_deg.to_radians().sin_cos();
+        self.center_km + self.semi_major_km * cos_a + self.semi_minor_km * sin_a
+    }
+
+    /// Returns the angular radius in degrees of the semi major axis of the limb, as seen from the observer.
+    pub fn angular_semi_major_deg(&self) -> f64 {
+        (self.center_km + self.semi_major_km - self.observer_km)
+            .angle(&(self.center_km - self.observer_km))
+            .to_degrees()
+    }
+
+    /// Returns the angular radius in degrees of the semi minor axis of the limb, as seen from the observer.
+    pub fn angular_semi_minor_deg(&self) -> f64 {
+        (self.center_km + self.semi_minor_km - self.observer_km)
+            .angle(&(self.center_km - self.observer_km))
+            .to_degrees()
+    }
+}
+
+impl fmt::Display for Limb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "limb centered at [{:.6}, {:.6}, {:.6}] km ({} at target) with semi axes of {:.6} km and {:.6} km",
+            self.center_km.x,
+            self.center_km.y,
+            self.center_km.z,
+            self.target_epoch,
+            self.semi_major_km.norm(),
+            self.semi_minor_km.norm()
+        )
+    }
+}
+
+impl Almanac {
+    /// Computes the tangent point of the ray from the observer along the provided direction, expressed in the direction frame,
+    /// with the ellipsoid of the body fixed target frame, i.e. the point of the ray nearest to the ellipsoid, and the point of the
+    /// ellipsoid nearest to it. This is the equivalent of the SPICE `tangpt` routine, and is used for radio and stellar occultation
+    /// planning, where the altitude of the tangent point is the grazing altitude of the ray.
+    ///
+    /// If the ray intersects the ellipsoid, the tangent point is the first intersection and its altitude is zero. If the ray points
+    /// away from the ellipsoid, the tangent point is the observer itself.
+    ///
+    /// # Notes
+    /// With aberration corrections, the orientation of the target is evaluated at the epoch corrected for the light time to the
+    /// center of the target body, like [Almanac::surface_intercept].
+    pub fn tangent_point(
+        &self,
+        target_frame: Frame,
+        observer: Frame,
+        direction: Vector3,
+        direction_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<TangentPoint> {
+        let geometry = self.body_fixed_geometry(
+            target_frame,
+            observer,
+            epoch,
+            ab_corr,
+            "computing tangent point",
+        )?;
+        let direction_body = geometry.direction_in_body(
+            self,
+            direction,
+            direction_frame,
+            epoch,
+            "rotating the tangent point direction",
+        )?;
+
+        let (tangent_point_km, surface_point_km) = geometry
+            .shape
+            .tangent_point(geometry.observer_km, direction_body);
+
+        Ok(TangentPoint {
+            target_epoch: geometry.target_epoch,
+            tangent_point_km,
+            surface_point_km,
+            observer_to_tangent_km: tangent_point_km - geometry.observer_km,
+        })
+    }
+
+    /// Computes the apparent limb of the ellipsoid of the body fixed target frame as seen from the observer, i.e. the ellipse of
+    /// the surface points where the line of sight from the observer grazes the ellipsoid. This is the equivalent of the SPICE
+    /// `edlimb` routine.
+    ///
+    /// Returns None if the observer is on or inside the ellipsoid.
+    pub fn limb(
+        &self,
+        target_frame: Frame,
+        observer: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Option<Limb>> {
+        let geometry =
+            self.body_fixed_geometry(target_frame, observer, epoch, ab_corr, "computing limb")?;
+
+        Ok(geometry.shape.limb(geometry.observer_km).map(
+            |(center_km, semi_major_km, semi_minor_km)| Limb {
+                target_epoch: geometry.target_epoch,
+                center_km,
+                semi_major_km,
+                semi_minor_km,
+                observer_km: geometry.observer_km,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod ut_limb {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use hifitime::Epoch;
+
+    #[test]
+    fn tangent_point_and_limb() {
+        // The spacecraft is 1000 km above the Earth along -X
+        let spk = test_spk(&[
+            fixed_segment(399, 0, [0.0; 3]),
+            fixed_segment(-85, 0, [-7378.0, 0.0, 0.0]),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        let earth = EARTH_J2000.with_ellipsoid(Ellipsoid::from_sphere(6378.0));
+        let sc = Frame::from_ephem_j2000(-85);
+        let epoch = Epoch::from_et_seconds(43_200.0);
+
+        // A ray along +Y grazes the sphere at the sub spacecraft point with an altitude of 1000 km
+        let grazing = almanac
+            .tangent_point(earth, sc, Vector3::y(), EARTH_J2000, epoch, None)
+            .unwrap();
+        assert!((grazing.tangent_point_km - Vector3::new(-7378.0, 0.0, 0.0)).norm() < 1e-9);
+        assert!((grazing.surface_point_km - Vector3::new(-6378.0, 0.0, 0.0)).norm() < 1e-9);
+        assert!((grazing.altitude_km() - 1000.0).abs() < 1e-9);
+        assert!(grazing.range_km() < 1e-9);
+
+        // Tilted towards the Earth, the ray grazes lower, ahead of the spacecraft
+        let lower = almanac
+            .tangent_point(
+                earth,
+                sc,
+                Vector3::new(0.1, 1.0, 0.0),
+                EARTH_J2000,
+                epoch,
+                None,
+            )
+            .unwrap();
+        assert!(lower.altitude_km() < 1000.0 && lower.altitude_km() > 0.0);
+        assert!(lower.range_km() > 0.0);
+        assert!((lower.surface_point_km.norm() - 6378.0).abs() < 1e-9);
+
+        // Nadir pointing intersects the surface
+        let nadir = almanac
+            .tangent_point(earth, sc, Vector3::x(), EARTH_J2000, epoch, None)
+            .unwrap();
+        assert!(nadir.altitude_km() < 1e-9);
+        assert!((nadir.range_km() - 1000.0).abs() < 1e-9);
+
+        // The limb of a sphere is a circle, and the line of sight to each of its points is tangent to the sphere.
+        let limb = almanac.limb(earth, sc, epoch, None).unwrap().unwrap();
+        let expected_radius = 6378.0 * (1.0 - (6378.0_f64 / 7378.0).powi(2)).sqrt();
+        assert!((limb.semi_major_km.norm() - expected_radius).abs() < 1e-9);
+        assert!((limb.semi_minor_km.norm() - expected_radius).abs() < 1e-9);
+        assert!(
+            (limb.center_km - Vector3::new(-6378.0_f64.powi(2) / 7378.0, 0.0, 0.0)).norm() < 1e-9
+        );
+        let half_angle_deg = (6378.0_f64 / 7378.0).asin().to_degrees();
+        assert!((limb.angular_semi_major_deg() - half_angle_deg).abs() < 1e-9);
+        for angle_deg in [0.0, 45.0, 200.0] {
+            let point = limb.point_km(angle_deg);
+            assert!((point.norm() - 6378.0).abs() < 1e-9);
+            assert!(point.dot(&(point - limb.observer_km)).abs() < 1e-3);
+        }
+
+        // An observer inside the ellipsoid has no limb
+        let inside = EARTH_J2000.with_ellipsoid(Ellipsoid::from_sphere(8000.0));
+        assert!(almanac.limb(inside, sc, epoch, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn ellipsoid_limb_and_nearest_point() {
+        let shape = Ellipsoid {
+            semi_major_equatorial_radius_km: 3.0,
+            semi_minor_equatorial_radius_km: 2.0,
+            polar_radius_km: 1.0,
+        };
+
+        // The nearest point of an axis is on that axis, and nearest points are on the surface along the normal.
+        assert!((shape.nearest_point(Vector3::new(0.0, 0.0, 5.0)) - Vector3::z()).norm() < 1e-12);
+        for point in [
+            Vector3::new(4.0, 1.0, 2.0),
+            Vector3::new(-1.0, 3.0, -0.5),
+            Vector3::new(0.5, 0.2, 0.1),
+        ] {
+            let nearest = shape.nearest_point(point);
+            let level = (nearest.x / 3.0).powi(2) + (nearest.y / 2.0).powi(2) + nearest.z.powi(2);
+            assert!((level - 1.0).abs() < 1e-12);
+            let offset = point - nearest;
+            assert!(
+                offset.cross(&shape.surface_normal(nearest)).norm() < 1e-9 * offset.norm().max(1.0)
+            );
+        }
+
+        // Seen from far along +Z, the limb is the equator.
+        let (center, major, minor) = shape.limb(Vector3::new(0.0, 0.0, 1e9)).unwrap();
+        assert!(center.norm() < 1e-6);
+        assert!((major.norm() - 3.0).abs() < 1e-6);
+        assert!((minor.norm() - 2.0).abs() < 1e-6);
+
+        // The limb points of a triaxial ellipsoid are on its surface, and tangent to the line of sight.
+        let observer = Vector3::new(5.0, -4.0, 3.0);
+        let (center, major, minor) = shape.limb(observer).unwrap();
+        assert!(major.norm() >= minor.norm());
+        assert!(major.dot(&minor).abs() < 1e-12);
+        for angle in [0.0_f64, 1.0, 2.5, 4.0] {
+            let point = center + major * angle.cos() + minor * angle.sin();
+            let level = (point.x / 3.0).powi(2) + (point.y / 2.0).powi(2) + point.z.powi(2);
+            assert!((level - 1.0).abs() < 1e-12);
+            assert!(shape.surface_normal(point).dot(&(observer - point)).abs() < 1e-12);
+        }
+    }
+}
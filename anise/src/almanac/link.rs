@@ -0,0 +1,402 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch, TimeSeries, Unit};
+
+use super::Almanac;
+use crate::constants::frames::SSB_J2000;
+use crate::constants::SPEED_OF_LIGHT_KM_S;
+use crate::errors::AlmanacResult;
+use crate::math::Vector3;
+use crate::prelude::{Frame, Orbit};
+
+/// Maximum number of iterations of the light time solution of a link.
+const MAX_LIGHT_TIME_ITER: usize = 10;
+
+/// One-way radio link from a transmitter to a receiver at one reception epoch, as computed by [Almanac::link_history].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LinkSample {
+    /// Epoch at which the signal is received
+    pub receive_epoch: Epoch,
+    /// Epoch at which the received signal was transmitted
+    pub transmit_epoch: Epoch,
+    /// Distance between the transmitter at transmission and the receiver at reception
+    pub range_km: f64,
+    /// Rate of change of the range with respect to the reception epoch, positive when the objects move apart
+    pub range_rate_km_s: f64,
+    pub transmit_frequency_hz: f64,
+    pub received_frequency_hz: f64,
+}
+
+impl LinkSample {
+    /// Returns the one-way light time of the signal.
+    pub fn light_time(&self) -> Duration {
+        self.receive_epoch - self.transmit_epoch
+    }
+
+    /// Returns the Doppler shift of the received frequency, negative when the objects move apart.
+    pub fn doppler_shift_hz(&self) -> f64 {
+        self.received_frequency_hz - self.transmit_frequency_hz
+    }
+}
+
+impl fmt::Display for LinkSample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: range {:.6} km, range-rate {:.6} km/s, light time {}, received {:.3} Hz (Doppler {:.3} Hz)",
+            self.receive_epoch,
+            self.range_km,
+            self.range_rate_km_s,
+            self.light_time(),
+            self.received_frequency_hz,
+            self.doppler_shift_hz()
+        )
+    }
+}
+
+/// Direction of the signal of a light time computation, with respect to the observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LightTimeDirection {
+    /// The observer receives the signal at the epoch, e.g. the downlink from a spacecraft to a ground station
+    Reception,
+    /// The observer transmits the signal at the epoch, e.g. the uplink from a ground station to a spacecraft
+    Transmission,
+}
+
+/// Model of the Doppler effect between a transmitter and a receiver.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DopplerModel {
+    /// Classical Doppler effect from the motion of both objects along the line of sight, as used by [Almanac::link_history]
+    #[default]
+    Classical,
+    /// Special relativistic Doppler effect, which also accounts for the time dilation of both objects (i.e. the transverse Doppler effect)
+    Relativistic,
+}
+
+impl Almanac {
+    /// Returns the converged one-way light time between the observer and the target at the provided epoch: the time taken by a
+    /// signal received by the observer at that epoch since it left the target, or by a signal transmitted by the observer at that
+    /// epoch until it reaches the target.
+    ///
+    /// The light time is solved iteratively from the positions of both objects with respect to the solar system barycenter.
+    pub fn light_time(
+        &self,
+        observer_frame: Frame,
+        target_frame: Frame,
+        epoch: Epoch,
+        direction: LightTimeDirection,
+    ) -> AlmanacResult<Duration> {
+        let (observer, target) =
+            self.light_time_states(observer_frame, target_frame, epoch, direction)?;
+        Ok((target.epoch - observer.epoch).abs())
+    }
+
+    /// Returns the converged two-way (round trip) light time of a signal between the observer and the target, which bounces
+    /// off or is turned around by the target without delay.
+    ///
+    /// In reception, the epoch is that at which the observer receives the returned signal, and in transmission, it is that at
+    /// which the observer transmits the signal.
+    pub fn two_way_light_time(
+        &self,
+        observer_frame: Frame,
+        target_frame: Frame,
+        epoch: Epoch,
+        direction: LightTimeDirection,
+    ) -> AlmanacResult<Duration> {
+        let first_leg = self.light_time(observer_frame, target_frame, epoch, direction)?;
+        let bounce_epoch = match direction {
+            LightTimeDirection::Reception => epoch - first_leg,
+            LightTimeDirection::Transmission => epoch + first_leg,
+        };
+        let second_leg = self.light_time(target_frame, observer_frame, bounce_epoch, direction)?;
+        Ok(first_leg + second_leg)
+    }
+
+    /// Returns the one-way link from the transmitter to the receiver of a signal of the provided frequency received at the
+    /// provided epoch, cf. [Almanac::link_history].
+    pub fn link_at(
+        &self,
+        transmitter_frame: Frame,
+        receiver_frame: Frame,
+        transmit_frequency_hz: f64,
+        receive_epoch: Epoch,
+    ) -> AlmanacResult<LinkSample> {
+        let (receiver, transmitter) = self.light_time_states(
+            receiver_frame,
+            transmitter_frame,
+            receive_epoch,
+            LightTimeDirection::Reception,
+        )?;
+
+        let rho_km = receiver.radius_km - transmitter.radius_km;
+        let range_km = rho_km.norm();
+        let unit_rho = if range_km > f64::EPSILON {
+            rho_km / range_km
+        } else {
+            Vector3::zeros()
+        };
+
+        // The transmission epoch depends on the reception epoch through the light time, hence the correction of the range-rate
+        // by the motion of the transmitter along the line of sight.
+        let tx_radial_rate_c = unit_rho.dot(&transmitter.velocity_km_s) / SPEED_OF_LIGHT_KM_S;
+        let range_rate_km_s = unit_rho.dot(&(receiver.velocity_km_s - transmitter.velocity_km_s))
+            / (1.0 - tx_radial_rate_c);
+
+        Ok(LinkSample {
+            receive_epoch,
+            transmit_epoch: transmitter.epoch,
+            range_km,
+            range_rate_km_s,
+            transmit_frequency_hz,
+            // The received frequency is the transmitted one scaled by the rate of the transmission epoch with respect to the
+            // reception epoch.
+            received_frequency_hz: transmit_frequency_hz
+                * (1.0 - range_rate_km_s / SPEED_OF_LIGHT_KM_S),
+        })
+    }
+
+    /// Returns the Doppler factor of a signal received by the receiver at the provided epoch from the transmitter, i.e. the ratio
+    /// of the received frequency to the transmitted frequency, which is below one when the objects move apart.
+    ///
+    /// The transmission epoch is light time corrected, and the velocities of both objects are taken with respect to the solar
+    /// system barycenter, as in [Almanac::link_at]. The gravitational redshift is not modeled.
+    pub fn doppler_factor(
+        &self,
+        transmitter_frame: Frame,
+        receiver_frame: Frame,
+        receive_epoch: Epoch,
+        model: DopplerModel,
+    ) -> AlmanacResult<f64> {
+        let link = self.link_at(transmitter_frame, receiver_frame, 1.0, receive_epoch)?;
+        let classical = link.received_frequency_hz / link.transmit_frequency_hz;
+
+        Ok(match model {
+            DopplerModel::Classical => classical,
+            // The ratio of the Lorentz factors of the receiver and the transmitter is the ratio of their proper time rates.
+            DopplerModel::Relativistic => {
+                let transmitter =
+                    self.transform(transmitter_frame, SSB_J2000, link.transmit_epoch, None)?;
+                let receiver = self.transform(receiver_frame, SSB_J2000, receive_epoch, None)?;
+                let beta_tx_sq = (transmitter.vmag_km_s() / SPEED_OF_LIGHT_KM_S).powi(2);
+                let beta_rx_sq = (receiver.vmag_km_s() / SPEED_OF_LIGHT_KM_S).powi(2);
+                classical * (1.0 - beta_tx_sq).sqrt() / (1.0 - beta_rx_sq).sqrt()
+            }
+        })
+    }
+
+    /// Returns the Doppler shift, in Hz, of a carrier of the provided frequency received by the receiver at the provided epoch
+    /// from the transmitter, negative when the objects move apart, cf. [Almanac::doppler_factor].
+    pub fn doppler_shift_hz(
+        &self,
+        transmitter_frame: Frame,
+        receiver_frame: Frame,
+        carrier_frequency_hz: f64,
+        receive_epoch: Epoch,
+        model: DopplerModel,
+    ) -> AlmanacResult<f64> {
+        let factor =
+            self.doppler_factor(transmitter_frame, receiver_frame, receive_epoch, model)?;
+        Ok(carrier_frequency_hz * (factor - 1.0))
+    }
+
+    /// Returns the one-way link from the transmitter to the receiver of a signal of the provided frequency at each reception epoch
+    /// of the time series, e.g. the Doppler profile of a pass of a spacecraft over a ground station (whose frame may be a
+    /// topocentric frame or a traverse) for radio planning.
+    ///
+    /// The geometry is light time corrected: the transmitter is placed at its transmission epoch, found iteratively, and the
+    /// receiver at the reception epoch, both with respect to the solar system barycenter. The received frequency includes the
+    /// classical Doppler effect from the motion of both objects, but neither the relativistic time dilation nor the gravitational
+    /// redshift.
+    pub fn link_history(
+        &self,
+        transmitter_frame: Frame,
+        receiver_frame: Frame,
+        transmit_frequency_hz: f64,
+        time_series: TimeSeries,
+    ) -> AlmanacResult<Vec<LinkSample>> {
+        time_series
+            .map(|epoch| {
+                self.link_at(
+                    transmitter_frame,
+                    receiver_frame,
+                    transmit_frequency_hz,
+                    epoch,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the states, with respect to the solar system barycenter, of the observer at the provided epoch and of the target at
+    /// the epoch at which the signal received by the observer left it (reception) or the signal of the observer reaches it (transmission).
+    fn light_time_states(
+        &self,
+        observer_frame: Frame,
+        target_frame: Frame,
+        epoch: Epoch,
+        direction: LightTimeDirection,
+    ) -> AlmanacResult<(Orbit, Orbit)> {
+        let observer = self.transform(observer_frame, SSB_J2000, epoch, None)?;
+
+        // Solve for the epoch of the target such that the signal travels between both objects at the speed of light.
+        let mut light_time_s = 0.0;
+        let mut target = self.transform(target_frame, SSB_J2000, epoch, None)?;
+        for _ in 0..MAX_LIGHT_TIME_ITER {
+            let prev_light_time_s = light_time_s;
+            light_time_s = (observer.radius_km - target.radius_km).norm() / SPEED_OF_LIGHT_KM_S;
+            let target_epoch = match direction {
+                LightTimeDirection::Reception => epoch - Unit::Second * light_time_s,
+                LightTimeDirection::Transmission => epoch + Unit::Second * light_time_s,
+            };
+            target = self.transform(target_frame, SSB_J2000, target_epoch, None)?;
+            if (light_time_s - prev_light_time_s).abs() < 1e-12 {
+                break;
+            }
+        }
+
+        Ok((observer, target))
+    }
+}
+
+#[cfg(test)]
+mod ut_link {
+    use super::{DopplerModel, LightTimeDirection};
+    use crate::constants::SPEED_OF_LIGHT_KM_S;
+    use crate::naif::daf::test_utils::{fixed_segment, linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Duration, Epoch, TimeSeries, Unit};
+
+    const SPEED_KM_S: f64 = 10.0;
+    const X0_KM: f64 = 1.0e6;
+
+    /// Returns an almanac with a beacon (-10) at rest, and a probe (-11) moving away from it along the X axis, over the first day past J2000 TDB.
+    fn beacon_and_probe() -> Almanac {
+        Almanac::default().with_spk(test_spk(&[
+            fixed_segment(-10, 0, [0.0; 3]),
+            linear_segment(-11, 0, [X0_KM, 0.0, 0.0], [SPEED_KM_S, 0.0, 0.0]),
+        ]))
+    }
+
+    #[test]
+    fn link_history() {
+        let epoch = Epoch::from_et_seconds(43_200.0);
+        let almanac = beacon_and_probe();
+        let beacon = Frame::from_ephem_j2000(-10);
+        let probe = Frame::from_ephem_j2000(-11);
+        let freq_hz = 8.4e9;
+
+        // Downlink: only the transmitter moves, away from the receiver, so the range is its distance at transmission.
+        let samples = almanac
+            .link_history(
+                probe,
+                beacon,
+                freq_hz,
+                TimeSeries::inclusive(epoch, epoch + Unit::Minute * 30, Unit::Minute * 10),
+            )
+            .unwrap();
+        assert_eq!(samples.len(), 4);
+        for sample in &samples {
+            println!("{sample}");
+            let dt_s = (sample.transmit_epoch - epoch).to_seconds();
+            assert!((sample.range_km - (X0_KM + SPEED_KM_S * dt_s)).abs() < 1e-6);
+            assert!(
+                (sample.light_time().to_seconds() - sample.range_km / SPEED_OF_LIGHT_KM_S).abs()
+                    < 1e-6
+            );
+            let expected_hz = freq_hz / (1.0 + SPEED_KM_S / SPEED_OF_LIGHT_KM_S);
+            assert!((sample.received_frequency_hz - expected_hz).abs() < 1e-3);
+            assert!(sample.doppler_shift_hz() < 0.0);
+        }
+
+        // Uplink: only the receiver moves, away from the transmitter.
+        let sample = almanac.link_at(beacon, probe, freq_hz, epoch).unwrap();
+        println!("{sample}");
+        assert!((sample.range_km - X0_KM).abs() < 1e-6);
+        assert!((sample.range_rate_km_s - SPEED_KM_S).abs() < 1e-9);
+        let expected_hz = freq_hz * (1.0 - SPEED_KM_S / SPEED_OF_LIGHT_KM_S);
+        assert!((sample.received_frequency_hz - expected_hz).abs() < 1e-3);
+    }
+
+    #[test]
+    fn light_time() {
+        let epoch = Epoch::from_et_seconds(43_200.0);
+        let almanac = beacon_and_probe();
+        let beacon = Frame::from_ephem_j2000(-10);
+        let probe = Frame::from_ephem_j2000(-11);
+        let c = SPEED_OF_LIGHT_KM_S;
+
+        let assert_close = |light_time: Duration, expected_s: f64| {
+            assert!(
+                (light_time.to_seconds() - expected_s).abs() < 1e-8,
+                "{light_time} != {expected_s} s"
+            );
+        };
+
+        // The signal received by the beacon left the probe when it was closer, and the signal transmitted by the beacon
+        // catches up with the probe further away.
+        let reception = almanac
+            .light_time(beacon, probe, epoch, LightTimeDirection::Reception)
+            .unwrap();
+        assert_close(reception, X0_KM / (c + SPEED_KM_S));
+        let transmission = almanac
+            .light_time(beacon, probe, epoch, LightTimeDirection::Transmission)
+            .unwrap();
+        assert_close(transmission, X0_KM / (c - SPEED_KM_S));
+
+        // Two-way: the beacon is at rest, so the other leg only depends on the position of the probe at the bounce.
+        let down_s = X0_KM / (c + SPEED_KM_S);
+        let two_way = almanac
+            .two_way_light_time(beacon, probe, epoch, LightTimeDirection::Reception)
+            .unwrap();
+        assert_close(two_way, down_s + (X0_KM - SPEED_KM_S * down_s) / c);
+
+        let up_s = X0_KM / (c - SPEED_KM_S);
+        let two_way = almanac
+            .two_way_light_time(beacon, probe, epoch, LightTimeDirection::Transmission)
+            .unwrap();
+        assert_close(two_way, up_s + (X0_KM + SPEED_KM_S * up_s) / c);
+    }
+
+    #[test]
+    fn doppler_factor() {
+        let epoch = Epoch::from_et_seconds(43_200.0);
+        let almanac = beacon_and_probe();
+        let beacon = Frame::from_ephem_j2000(-10);
+        let probe = Frame::from_ephem_j2000(-11);
+        let beta = SPEED_KM_S / SPEED_OF_LIGHT_KM_S;
+        let freq_hz = 8.4e9;
+
+        // Downlink from the receding probe, whose time dilation further lowers the received frequency.
+        let classical = almanac
+            .doppler_factor(probe, beacon, epoch, DopplerModel::Classical)
+            .unwrap();
+        assert!((classical - 1.0 / (1.0 + beta)).abs() < 1e-15);
+        let relativistic = almanac
+            .doppler_factor(probe, beacon, epoch, DopplerModel::Relativistic)
+            .unwrap();
+        assert!((relativistic - ((1.0 - beta) / (1.0 + beta)).sqrt()).abs() < 1e-15);
+        assert!(relativistic < classical);
+
+        // The classical shift matches that of the link.
+        let sample = almanac.link_at(probe, beacon, freq_hz, epoch).unwrap();
+        let shift_hz = almanac
+            .doppler_shift_hz(probe, beacon, freq_hz, epoch, DopplerModel::Classical)
+            .unwrap();
+        assert!((shift_hz - sample.doppler_shift_hz()).abs() < 1e-6);
+
+        // Uplink to the receding probe: its time dilation raises the received frequency above the classical one, to the same factor.
+        let relativistic = almanac
+            .doppler_factor(beacon, probe, epoch, DopplerModel::Relativistic)
+            .unwrap();
+        assert!((relativistic - ((1.0 - beta) / (1.0 + beta)).sqrt()).abs() < 1e-15);
+        assert!(relativistic > 1.0 - beta);
+    }
+}
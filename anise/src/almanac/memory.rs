@@ -0,0 +1,285 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use core::mem::size_of;
+
+use indexmap::IndexMap;
+
+use super::{Almanac, DecodedKernel, EarthOrientation, EopData};
+use crate::errors::{AlmanacError, AlmanacResult};
+use crate::naif::daf::{NAIFSummaryRecord, DAF};
+use crate::structure::dataset::{DataSet, DataSetT};
+use crate::structure::lookuptable::LookUpTable;
+use crate::NaifId;
+
+/// Memory held by one kernel or dataset loaded in an Almanac.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Kind of data: "SPK", "BPC", "planetary data", "spacecraft data", "Euler parameters", "location data", "instrument data",
+    /// "articulation data", "traverse data", or "EOP data"
+    pub kind: &'static str,
+    /// Alias of the kernel or dataset, typically its path
+    pub alias: String,
+    pub bytes: usize,
+}
+
+/// Memory held by the kernels and datasets loaded in an Almanac, in the order in which they are searched within each kind.
+///
+/// The size of a DAF kernel (SPK or BPC) is the size of its bytes. The size of a dataset is that of its items and of its look up
+/// table, excluding the data that the items may hold on the heap (e.g. the vertices of a polygonal field of view). The spare capacity
+/// of the allocations is not included, so that the report does not depend on how the data was loaded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+    pub usage: Vec<MemoryUsage>,
+}
+
+impl MemoryReport {
+    /// Returns the total number of bytes held by the loaded kernels and datasets.
+    pub fn total_bytes(&self) -> usize {
+        self.usage.iter().map(|usage| usage.bytes).sum()
+    }
+
+    /// Returns the number of bytes held by the kernels or datasets of the provided kind.
+    pub fn bytes_of_kind(&self, kind: &str) -> usize {
+        self.usage
+            .iter()
+            .filter(|usage| usage.kind == kind)
+            .map(|usage| usage.bytes)
+            .sum()
+    }
+}
+
+impl fmt::Display for MemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for usage in &self.usage {
+            writeln!(
+                f,
+                "{:<18} {:>12} B  {}",
+                usage.kind, usage.bytes, usage.alias
+            )?;
+        }
+        write!(f, "{:<18} {:>12} B", "total", self.total_bytes())
+    }
+}
+
+fn daf_bytes<R: NAIFSummaryRecord>(daf: &DAF<R>) -> usize {
+    daf.bytes.len()
+}
+
+fn lut_bytes(lut: &LookUpTable) -> usize {
+    // Each entry of an index map holds its key, its value, and its hash, plus an index in the hash table.
+    let by_id = lut.by_id.len() * (size_of::<NaifId>() + size_of::<u32>() + 2 * size_of::<u64>());
+    let by_name = lut.by_name.len()
+        * (size_of::<String>() + size_of::<u32>() + 2 * size_of::<u64>())
+        + lut.by_name.keys().map(|name| name.len()).sum::<usize>();
+    by_id + by_name
+}
+
+fn dataset_bytes<T: DataSetT>(dataset: &DataSet<T>) -> usize {
+    size_of::<DataSet<T>>()
+        + dataset.metadata.originator.len()
+        + lut_bytes(&dataset.lut)
+        + dataset.data.len() * size_of::<T>()
+}
+
+fn push_usage<V>(
+    report: &mut MemoryReport,
+    kind: &'static str,
+    data: &IndexMap<String, V>,
+    bytes: impl Fn(&V) -> usize,
+) {
+    for (alias, item) in data {
+        report.usage.push(MemoryUsage {
+            kind,
+            alias: alias.clone(),
+            bytes: bytes(item),
+        });
+    }
+}
+
+impl DecodedKernel {
+    fn memory_usage(&self) -> (&'static str, usize) {
+        match self {
+            Self::Spk(spk) => ("SPK", daf_bytes(spk)),
+            Self::Bpc(bpc) => ("BPC", daf_bytes(bpc)),
+            Self::Planetary(dataset) => ("planetary data", dataset_bytes(dataset)),
+            Self::Spacecraft(dataset) => ("spacecraft data", dataset_bytes(dataset)),
+            Self::EulerParameter(dataset) => ("Euler parameters", dataset_bytes(dataset)),
+            Self::Location(dataset) => ("location data", dataset_bytes(dataset)),
+            Self::Instrument(dataset) => ("instrument data", dataset_bytes(dataset)),
+            Self::Articulation(dataset) => ("articulation data", dataset_bytes(dataset)),
+            Self::Traverse(dataset) => ("traverse data", dataset_bytes(dataset)),
+        }
+    }
+}
+
+impl Almanac {
+    /// Returns the memory held by each of the loaded kernels and datasets.
+    pub fn memory_report(&self) -> MemoryReport {
+        let mut report = MemoryReport::default();
+        push_usage(&mut report, "SPK", &self.spk_data, daf_bytes);
+        push_usage(&mut report, "BPC", &self.bpc_data, daf_bytes);
+        push_usage(
+            &mut report,
+            "planetary data",
+            &self.planetary_data,
+            dataset_bytes,
+        );
+        push_usage(
+            &mut report,
+            "spacecraft data",
+            &self.spacecraft_data,
+            dataset_bytes,
+        );
+        push_usage(
+            &mut report,
+            "Euler parameters",
+            &self.euler_param_data,
+            dataset_bytes,
+        );
+        push_usage(
+            &mut report,
+            "location data",
+            &self.location_data,
+            dataset_bytes,
+        );
+        push_usage(
+            &mut report,
+            "instrument data",
+            &self.instrument_data,
+            dataset_bytes,
+        );
+        push_usage(
+            &mut report,
+            "articulation data",
+            &self.articulation_data,
+            dataset_bytes,
+        );
+        push_usage(
+            &mut report,
+            "traverse data",
+            &self.traverse_data,
+            dataset_bytes,
+        );
+        push_usage(&mut report, "EOP data", &self.eop_data, |data| {
+            size_of::<EopData>() + data.len() * size_of::<EarthOrientation>()
+        });
+        report
+    }
+
+    /// Returns a copy of this Almanac where loading a kernel or a dataset (with `load`, `load_from_bytes`, or the best effort loading)
+    /// fails if the memory held by all of the loaded kernels and datasets would then exceed the provided number of bytes, as
+    /// computed by [Self::memory_report]. There is no budget if None.
+    ///
+    /// Data added with the `with_*` functions is not checked, but it counts towards the budget of the next loads.
+    pub fn with_memory_budget(mut self, budget_bytes: Option<usize>) -> Self {
+        self.settings.memory_budget_bytes = budget_bytes;
+        self
+    }
+
+    /// Returns an error if adding the provided kernel under the provided alias would exceed the memory budget.
+    pub(super) fn check_memory_budget(
+        &self,
+        kernel: &DecodedKernel,
+        alias: Option<&str>,
+    ) -> AlmanacResult<()> {
+        let Some(budget_bytes) = self.settings.memory_budget_bytes else {
+            return Ok(());
+        };
+
+        let (kind, needed_bytes) = kernel.memory_usage();
+        // A kernel loaded with the same alias would be replaced.
+        let used_bytes = self
+            .memory_report()
+            .usage
+            .iter()
+            .filter(|usage| !(usage.kind == kind && Some(usage.alias.as_str()) == alias))
+            .map(|usage| usage.bytes)
+            .sum::<usize>();
+
+        if used_bytes + needed_bytes > budget_bytes {
+            Err(AlmanacError::MemoryBudget {
+                kind,
+                alias: alias.unwrap_or("bytes").to_string(),
+                needed_bytes,
+                used_bytes,
+                budget_bytes,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_memory {
+    use crate::errors::AlmanacError;
+    use crate::naif::daf::test_utils::test_spk;
+    use crate::prelude::Almanac;
+    use crate::structure::PlanetaryDataSet;
+    use bytes::BytesMut;
+
+    #[test]
+    fn report_and_budget() {
+        let almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        let report = almanac.memory_report();
+        assert_eq!(report.usage.len(), 1);
+        assert_eq!(report.usage[0].kind, "planetary data");
+        assert_eq!(report.usage[0].alias, "../data/pck08.pca");
+        let pca_bytes = report.total_bytes();
+        assert!(pca_bytes > 0);
+        assert_eq!(report.bytes_of_kind("planetary data"), pca_bytes);
+        assert_eq!(report.bytes_of_kind("SPK"), 0);
+        println!("{report}");
+
+        let spk = test_spk(&[]);
+        let spk_bytes = BytesMut::from(&spk.bytes[..]);
+
+        // Reloading the same alias replaces it, so it fits in a budget of its own size.
+        let almanac = almanac.with_memory_budget(Some(pca_bytes));
+        let almanac = almanac.load("../data/pck08.pca").unwrap();
+        assert_eq!(almanac.memory_report().total_bytes(), pca_bytes);
+
+        match almanac.clone().load_from_bytes(spk_bytes.clone()) {
+            Err(AlmanacError::MemoryBudget {
+                kind,
+                needed_bytes,
+                used_bytes,
+                budget_bytes,
+                ..
+            }) => {
+                assert_eq!(kind, "SPK");
+                assert_eq!(needed_bytes, spk_bytes.len());
+                assert_eq!(used_bytes, pca_bytes);
+                assert_eq!(budget_bytes, pca_bytes);
+            }
+            other => panic!("expected a memory budget error, got {:?}", other.err()),
+        }
+        assert!(almanac
+            .clone()
+            .load_from_bytes_best_effort(spk_bytes.clone(), "spk")
+            .is_err());
+
+        let almanac = almanac
+            .with_memory_budget(Some(pca_bytes + spk_bytes.len()))
+            .load_from_bytes(spk_bytes)
+            .unwrap();
+        assert_eq!(
+            almanac.memory_report().total_bytes(),
+            almanac.settings.memory_budget_bytes.unwrap()
+        );
+
+        // Data added directly is not checked
+        let almanac = almanac.with_planetary_data(PlanetaryDataSet::default());
+        assert_eq!(almanac.memory_report().usage.len(), 3);
+        assert!(almanac.memory_report().total_bytes() > pca_bytes + 1024);
+    }
+}
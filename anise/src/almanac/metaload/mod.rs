@@ -10,9 +10,11 @@
 
 mod metaalmanac;
 mod metafile;
+mod sync;
 
 pub use metaalmanac::MetaAlmanac;
 pub use metafile::MetaFile;
+pub use sync::{KernelSync, SyncFile, SyncStatus, NAIF_GENERIC_KERNELS};
 
 use super::Almanac;
 
@@ -54,6 +56,12 @@ pub enum MetaAlmanacError {
         "download to {desired} blocked while lock file `{desired}.lock` exists, please delete lock file"
     ))]
     PersistentLock { desired: String },
+    #[snafu(display("{uri} has a CRC32 of 0x{computed:x} but 0x{expected:x} was expected"))]
+    ChecksumMismatch {
+        uri: String,
+        expected: u32,
+        computed: u32,
+    },
 }
 
 impl Almanac {
@@ -0,0 +1,434 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use log::{debug, info};
+use platform_dirs::AppDirs;
+use std::fs::{self, create_dir_all, remove_file, rename, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::errors::{AlmanacResult, MetaSnafu};
+use crate::prelude::InputOutputError;
+use snafu::ResultExt;
+
+use super::{Almanac, MetaAlmanacError, MetaFile};
+
+/// NAIF's generic kernels directory, a typical base URL for a [KernelSync].
+pub const NAIF_GENERIC_KERNELS: &str = "https://naif.jpl.nasa.gov/pub/naif/generic_kernels/";
+
+/// Extension of the file storing the validators and checksum of each synchronized file.
+const SYNC_EXT: &str = "sync";
+/// Extension of a partially downloaded file, resumed with an HTTP range request.
+const PART_EXT: &str = "part";
+
+/// A file mirrored by a [KernelSync].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SyncFile {
+    /// Path of this file relative to the base URL, also used as its path relative to the local directory
+    pub path: String,
+    /// Optionally specify the CRC32 of this file: the local copy is downloaded again if it does not match
+    pub crc32: Option<u32>,
+}
+
+impl SyncFile {
+    pub fn new(path: &str, crc32: Option<u32>) -> Self {
+        Self {
+            path: path.to_string(),
+            crc32,
+        }
+    }
+}
+
+/// Outcome of the synchronization of a single file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SyncStatus {
+    /// The local copy is up to date, nothing was downloaded
+    Unchanged,
+    /// The file was downloaded in full
+    Downloaded,
+    /// A partial download was completed with an HTTP range request
+    Resumed,
+}
+
+impl fmt::Display for SyncStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Mirrors a subset of a remote kernel directory (e.g. NAIF's generic kernels) into a local directory, only fetching the files which changed.
+///
+/// For each file, the `Last-Modified` and `ETag` validators of the last download are stored next to the local copy with its CRC32,
+/// and sent as `If-Modified-Since` and `If-None-Match` on the next synchronization, so unchanged files are not downloaded again.
+/// A local copy whose CRC32 does not match the recorded one (or the expected one) is downloaded again. Interrupted downloads are
+/// resumed with an HTTP range request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KernelSync {
+    /// URL of the remote directory
+    pub base_url: String,
+    /// Files to mirror
+    pub files: Vec<SyncFile>,
+    /// Local directory where the files are mirrored
+    pub local_dir: PathBuf,
+    /// Timeout of the download of each file
+    pub timeout: Duration,
+}
+
+impl KernelSync {
+    /// Initializes a new synchronization of the provided files into the ANISE data directory (like [MetaFile]).
+    pub fn new(base_url: &str, files: Vec<SyncFile>) -> Result<Self, MetaAlmanacError> {
+        let app_dir =
+            AppDirs::new(Some("nyx-space/anise"), true).ok_or(MetaAlmanacError::AppDirError)?;
+
+        Ok(Self {
+            base_url: base_url.to_string(),
+            files,
+            local_dir: app_dir.data_dir,
+            timeout: Duration::from_secs(300),
+        })
+    }
+
+    /// Returns a copy of this synchronization into the provided local directory.
+    pub fn with_local_dir<P: AsRef<Path>>(mut self, local_dir: P) -> Self {
+        self.local_dir = local_dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Returns the local path of the provided file.
+    pub fn local_path(&self, file: &SyncFile) -> PathBuf {
+        self.local_dir.join(file.path.trim_start_matches('/'))
+    }
+
+    /// Synchronizes all of the files, returning the status of each of them in the same order.
+    pub fn sync(&self) -> Result<Vec<SyncStatus>, MetaAlmanacError> {
+        let client = self.agent();
+        self.files
+            .iter()
+            .map(|file| self.sync_file(&client, file))
+            .collect()
+    }
+
+    fn agent(&self) -> ureq::Agent {
+        ureq::Agent::config_builder()
+            .timeout_global(Some(self.timeout))
+            .http_status_as_error(false)
+            .build()
+            .into()
+    }
+
+    fn sync_file(
+        &self,
+        client: &ureq::Agent,
+        file: &SyncFile,
+    ) -> Result<SyncStatus, MetaAlmanacError> {
+        let uri = format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            file.path.trim_start_matches('/')
+        );
+        let dest_path = self.local_path(file);
+        let sync_path = with_added_extension(&dest_path, SYNC_EXT);
+        let part_path = with_added_extension(&dest_path, PART_EXT);
+
+        if let Some(parent) = dest_path.parent() {
+            create_dir_all(parent).map_err(|e| io_error(parent, "creating directories", e))?;
+        }
+
+        // Only trust the recorded validators if the local copy is intact.
+        let record = SyncRecord::read(&sync_path).filter(|record| {
+            file.crc32.is_none_or(|crc32| crc32 == record.crc32)
+                && fs::read(&dest_path).is_ok_and(|bytes| crc32fast::hash(&bytes) == record.crc32)
+        });
+
+        let resume_from = fs::metadata(&part_path).map(|meta| meta.len()).unwrap_or(0);
+
+        let mut request = client.get(&uri);
+        if let Some(record) = &record {
+            if let Some(last_modified) = &record.last_modified {
+                request = request.header("If-Modified-Since", last_modified);
+            }
+            if let Some(etag) = &record.etag {
+                request = request.header("If-None-Match", etag);
+            }
+        } else if resume_from > 0 {
+            request = request.header("Range", format!("bytes={resume_from}-"));
+        }
+
+        let mut resp = request.call().map_err(|e| MetaAlmanacError::CnxError {
+            uri: uri.clone(),
+            error: format!("{e}"),
+        })?;
+
+        let status = match resp.status().as_u16() {
+            304 if record.is_some() => {
+                debug!("{uri} not modified");
+                return Ok(SyncStatus::Unchanged);
+            }
+            206 => SyncStatus::Resumed,
+            200 => SyncStatus::Downloaded,
+            code => {
+                return Err(MetaAlmanacError::FetchError {
+                    error: format!("status = {code}"),
+                    uri,
+                })
+            }
+        };
+
+        let header = |name: &str| {
+            resp.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string())
+        };
+        let last_modified = header("Last-Modified");
+        let etag = header("ETag");
+
+        // Stream the body into the partial file, appending to it only if the server honored the range request.
+        let mut part_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(status == SyncStatus::Resumed)
+            .truncate(status != SyncStatus::Resumed)
+            .open(&part_path)
+            .map_err(|e| io_error(&part_path, "creating file for storage", e))?;
+        io::copy(&mut resp.body_mut().as_reader(), &mut part_file).map_err(|e| {
+            MetaAlmanacError::FetchError {
+                error: format!("{e}"),
+                uri: uri.clone(),
+            }
+        })?;
+        drop(part_file);
+
+        let bytes = fs::read(&part_path).map_err(|e| io_error(&part_path, "reading file", e))?;
+        let crc32 = crc32fast::hash(&bytes);
+        if let Some(expected) = file.crc32 {
+            if expected != crc32 {
+                // The partial file is corrupted, so it must not be resumed.
+                let _ = remove_file(&part_path);
+                return Err(MetaAlmanacError::ChecksumMismatch {
+                    uri,
+                    expected,
+                    computed: crc32,
+                });
+            }
+        }
+
+        rename(&part_path, &dest_path).map_err(|e| io_error(&dest_path, "storing file", e))?;
+        SyncRecord {
+            last_modified,
+            etag,
+            crc32,
+        }
+        .write(&sync_path)
+        .map_err(|e| io_error(&sync_path, "storing validators", e))?;
+
+        info!(
+            "Synchronized {uri} to {} (CRC32 = 0x{crc32:x})",
+            dest_path.display()
+        );
+
+        Ok(status)
+    }
+}
+
+impl Almanac {
+    /// Synchronizes the files of the provided kernel synchronization, and loads all of them into a copy of this Almanac.
+    pub fn load_synced(self, sync: &KernelSync) -> AlmanacResult<Self> {
+        let client = sync.agent();
+        let mut almanac = self;
+        for (fno, file) in sync.files.iter().enumerate() {
+            let meta = MetaFile {
+                uri: sync.local_path(file).to_string_lossy().to_string(),
+                crc32: file.crc32,
+            };
+            sync.sync_file(&client, file).context(MetaSnafu {
+                fno,
+                file: meta.clone(),
+            })?;
+            almanac = almanac.load(&meta.uri)?;
+        }
+        Ok(almanac)
+    }
+}
+
+/// Validators and checksum of the last download of a synchronized file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct SyncRecord {
+    last_modified: Option<String>,
+    etag: Option<String>,
+    crc32: u32,
+}
+
+impl SyncRecord {
+    fn read(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let mut record = Self::default();
+        let mut crc32 = None;
+        for line in contents.lines() {
+            match line.split_once(": ") {
+                Some(("last-modified", value)) => record.last_modified = Some(value.to_string()),
+                Some(("etag", value)) => record.etag = Some(value.to_string()),
+                Some(("crc32", value)) => crc32 = u32::from_str_radix(value, 16).ok(),
+                _ => {}
+            }
+        }
+        record.crc32 = crc32?;
+        Some(record)
+    }
+
+    fn write(&self, path: &Path) -> io::Result<()> {
+        let mut contents = format!("crc32: {:08x}\n", self.crc32);
+        if let Some(last_modified) = &self.last_modified {
+            contents += &format!("last-modified: {last_modified}\n");
+        }
+        if let Some(etag) = &self.etag {
+            contents += &format!("etag: {etag}\n");
+        }
+        fs::write(path, contents)
+    }
+}
+
+fn with_added_extension(path: &Path, ext: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(ext);
+    PathBuf::from(path)
+}
+
+fn io_error(path: &Path, what: &'static str, e: io::Error) -> MetaAlmanacError {
+    MetaAlmanacError::MetaIO {
+        path: path.to_string_lossy().to_string(),
+        what,
+        source: InputOutputError::IOError { kind: e.kind() },
+    }
+}
+
+#[cfg(test)]
+mod ut_sync {
+    use super::{KernelSync, SyncFile, SyncStatus};
+    use crate::almanac::metaload::MetaAlmanacError;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread;
+
+    /// Serves one response per request, as returned by `respond` given the lowercase request headers, and returns the requests it received.
+    fn serve<F>(num_requests: usize, respond: F) -> (String, thread::JoinHandle<Vec<String>>)
+    where
+        F: Fn(usize, &str) -> String + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/kernels/", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for n in 0..num_requests {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request += &line.to_lowercase();
+                }
+                stream.write_all(respond(n, &request).as_bytes()).unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn response(status: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nLast-Modified: Tue, 01 Oct 2024 00:00:00 GMT\r\nETag: \"v1\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn local_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("anise-sync-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn incremental_sync() {
+        let (base_url, server) = serve(4, |n, request| match n {
+            0 | 2 => response("200 OK", "hello kernel"),
+            1 if request.contains("if-none-match: \"v1\"") => response("304 Not Modified", ""),
+            3 if request.contains("range: bytes=6-") => response("206 Partial Content", "kernel"),
+            _ => response("500 Internal Server Error", ""),
+        });
+
+        let dir = local_dir("incremental");
+        let file = SyncFile::new("pck/kernel.tpc", None);
+        let sync = KernelSync::new(&base_url, vec![file.clone()])
+            .unwrap()
+            .with_local_dir(&dir);
+        let path = sync.local_path(&file);
+
+        // First download
+        assert_eq!(sync.sync().unwrap(), vec![SyncStatus::Downloaded]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello kernel");
+
+        // Unchanged on the server
+        assert_eq!(sync.sync().unwrap(), vec![SyncStatus::Unchanged]);
+
+        // Corrupted local copy, downloaded again without validators
+        fs::write(&path, "hello kernal").unwrap();
+        assert_eq!(sync.sync().unwrap(), vec![SyncStatus::Downloaded]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello kernel");
+
+        // Interrupted download, resumed with a range request
+        fs::remove_file(&path).unwrap();
+        fs::write(dir.join("pck/kernel.tpc.part"), "hello ").unwrap();
+        assert_eq!(sync.sync().unwrap(), vec![SyncStatus::Resumed]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello kernel");
+        assert!(!dir.join("pck/kernel.tpc.part").exists());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("get /kernels/pck/kernel.tpc"));
+        assert!(requests[1].contains("if-modified-since: tue, 01 oct 2024 00:00:00 gmt"));
+        assert!(!requests[2].contains("if-modified-since"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let (base_url, server) = serve(1, |_, _| response("200 OK", "hello kernel"));
+
+        let dir = local_dir("checksum");
+        let file = SyncFile::new("kernel.bsp", Some(0xdeadbeef));
+        let sync = KernelSync::new(&base_url, vec![file.clone()])
+            .unwrap()
+            .with_local_dir(&dir);
+
+        match sync.sync() {
+            Err(MetaAlmanacError::ChecksumMismatch {
+                expected, computed, ..
+            }) => {
+                assert_eq!(expected, 0xdeadbeef);
+                assert_eq!(computed, crc32fast::hash(b"hello kernel"));
+            }
+            other => panic!("expected a checksum mismatch, got {other:?}"),
+        }
+        assert!(!sync.local_path(&file).exists());
+
+        server.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
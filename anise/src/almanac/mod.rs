@@ -25,26 +25,91 @@ use crate::naif::daf::{FileRecord, NAIFRecord};
 use crate::naif::pretty_print::NAIFPrettyPrint;
 use crate::naif::{BPC, SPK};
 use crate::orientations::{BPCSnafu, OrientationError};
+use crate::structure::articulation::Articulation;
 use crate::structure::dataset::{DataSetError, DataSetType};
 use crate::structure::lookuptable::LutError;
 use crate::structure::metadata::Metadata;
+use crate::structure::traverse::Traverse;
 use crate::structure::{
-    EulerParameterDataSet, InstrumentDataSet, LocationDataSet, PlanetaryDataSet, SpacecraftDataSet,
+    ArticulationDataSet, EulerParameterDataSet, InstrumentDataSet, LocationDataSet,
+    PlanetaryDataSet, SpacecraftDataSet, TraverseDataSet,
 };
 use crate::NaifId;
+use best_effort::decode_dataset;
 use core::fmt;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub use best_effort::LoadReport;
+pub use conjunction::CloseApproach;
+pub use eop::{EarthOrientation, EopData};
+pub use frame_switch::FrameSwitch;
+pub use illumination::IlluminationAngles;
+pub use instrument::FovTargetShape;
+pub use intercept::SurfaceIntercept;
+pub use limb::{Limb, TangentPoint};
+pub use link::{DopplerModel, LightTimeDirection, LinkSample};
+pub use memory::{MemoryReport, MemoryUsage};
+pub use overrides::{ConstantOverride, PlanetaryConstant};
+use precedence::PriorityOrder;
+pub use precedence::SegmentPrecedence;
+pub use realtime::RealTimeTable;
+pub use relative::RelativeState;
+pub use settings::{AlmanacSettings, Extrapolation};
+pub use shadowing::ComponentObscuration;
+pub use sky::{AvoidanceCircle, SkyAnnotations, SkyPoint, SkySnapshot};
+pub use topocentric::{TopocentricAxes, TopocentricFrame};
+pub use tracking::RangeObservable;
+pub use transfer::TransferWindow;
+pub use trim::PhaseKernels;
+pub use ut1::Ut1Source;
+pub use warm_up::WarmUpReport;
+pub use watchlist::{TargetSummary, Watchlist, WatchlistSummary};
 
 pub mod aer;
+pub mod asynchronous;
+pub mod attitude;
+pub mod batch;
+pub mod best_effort;
 pub mod bpc;
+pub mod conjunction;
+pub mod coverage;
 pub mod eclipse;
+pub mod eop;
+pub mod frame_switch;
+pub mod illumination;
 pub mod instrument;
+pub mod intercept;
+pub mod limb;
+pub mod link;
+pub mod memory;
+pub mod overrides;
 pub mod planetary;
+pub mod precedence;
+pub mod provenance;
+pub mod realtime;
+pub mod relative;
+pub mod report;
+pub mod settings;
+pub mod shadowing;
+pub mod sky;
 pub mod solar;
 pub mod spk;
+pub mod topocentric;
+pub mod tracking;
+pub mod transfer;
 pub mod transform;
+pub mod trim;
+pub mod ut1;
+pub mod warm_up;
+pub mod watchlist;
 
+#[cfg(feature = "build_checks")]
+pub mod build_checks;
 #[cfg(feature = "metaload")]
 pub mod metaload;
+#[cfg(feature = "analysis")]
+pub mod reparent;
 
 #[cfg(feature = "python")]
 mod python;
@@ -78,6 +143,41 @@ pub struct Almanac {
     pub location_data: IndexMap<String, LocationDataSet>,
     /// Dataset of instruments
     pub instrument_data: IndexMap<String, InstrumentDataSet>,
+    /// Dataset of articulations of appendages
+    pub articulation_data: IndexMap<String, ArticulationDataSet>,
+    /// Dataset of traverses of surface assets
+    pub traverse_data: IndexMap<String, TraverseDataSet>,
+    /// Priority tag of the SPKs and BPCs by alias, used by the `HighestPriorityTag` precedence, only set with `set_kernel_priority`
+    pub(crate) kernel_priority: HashMap<String, i32>,
+    /// Settings applied to all of the queries of this Almanac
+    pub settings: AlmanacSettings,
+    /// Topocentric frames registered by their ID
+    pub topocentric_frames: HashMap<NaifId, TopocentricFrame>,
+    /// Ephemeris IDs which switch to another ID at an epoch, by alias ID
+    pub ephemeris_switches: HashMap<NaifId, FrameSwitch>,
+    /// Orientation IDs which switch to another ID at an epoch, by alias ID
+    pub orientation_switches: HashMap<NaifId, FrameSwitch>,
+    /// Source of UT1 driving the rotation of the IAU Earth frame, if any
+    pub ut1_provider: Option<Arc<dyn Ut1Source>>,
+    /// Earth orientation parameters, e.g. loaded from the IERS finals files
+    pub eop_data: IndexMap<String, EopData>,
+    /// Planetary constants which take precedence over the loaded planetary data, in the order in which they were set
+    pub constant_overrides: Vec<ConstantOverride>,
+    /// Search order of the SPKs and BPCs under the `HighestPriorityTag` precedence
+    pub(crate) priority_order: PriorityOrder,
+}
+
+/// A kernel decoded from bytes, before it is added to an Almanac.
+enum DecodedKernel {
+    Spk(SPK),
+    Bpc(BPC),
+    Planetary(PlanetaryDataSet),
+    Spacecraft(SpacecraftDataSet),
+    EulerParameter(EulerParameterDataSet),
+    Location(LocationDataSet),
+    Instrument(InstrumentDataSet),
+    Articulation(ArticulationDataSet),
+    Traverse(TraverseDataSet),
 }
 
 impl fmt::Display for Almanac {
@@ -194,12 +294,85 @@ impl Almanac {
         self
     }
 
+    /// Loads the provided articulation data.
+    pub fn with_articulation_data(self, dataset: ArticulationDataSet) -> Self {
+        self.with_articulation_data_as(dataset, None)
+    }
+
+    /// Loads the provided articulation data.
+    pub fn with_articulation_data_as(
+        mut self,
+        dataset: ArticulationDataSet,
+        alias: Option<String>,
+    ) -> Self {
+        let alias = alias.unwrap_or(Epoch::now().unwrap_or_default().to_string());
+        let msg = format!("unloading articulation data `{alias}`");
+        if self.articulation_data.insert(alias, dataset).is_some() {
+            warn!("{msg}");
+        }
+        self
+    }
+
+    /// Loads the provided traverse data.
+    pub fn with_traverse_data(self, dataset: TraverseDataSet) -> Self {
+        self.with_traverse_data_as(dataset, None)
+    }
+
+    /// Loads the provided traverse data.
+    pub fn with_traverse_data_as(
+        mut self,
+        dataset: TraverseDataSet,
+        alias: Option<String>,
+    ) -> Self {
+        let alias = alias.unwrap_or(Epoch::now().unwrap_or_default().to_string());
+        let msg = format!("unloading traverse data `{alias}`");
+        if self.traverse_data.insert(alias, dataset).is_some() {
+            warn!("{msg}");
+        }
+        self
+    }
+
     /// Loads the provides bytes as one of the data types supported in ANISE.
     pub fn load_from_bytes(self, bytes: BytesMut) -> AlmanacResult<Self> {
-        self._load_from_bytes(bytes, None)
+        self._load_from_bytes(bytes, None, None)
+    }
+
+    fn _load_from_bytes(
+        self,
+        bytes: BytesMut,
+        path: Option<&str>,
+        warnings: Option<&mut Vec<String>>,
+    ) -> AlmanacResult<Self> {
+        let kernel = self.decode_kernel(bytes, path, warnings)?;
+        self.check_memory_budget(&kernel, path)?;
+        Ok(self.with_kernel_as(kernel, path.map(|p| p.to_string())))
     }
 
-    fn _load_from_bytes(self, bytes: BytesMut, path: Option<&str>) -> AlmanacResult<Self> {
+    /// Adds the provided decoded kernel to this Almanac.
+    fn with_kernel_as(self, kernel: DecodedKernel, alias: Option<String>) -> Self {
+        match kernel {
+            DecodedKernel::Spk(spk) => self.with_spk_as(spk, alias),
+            DecodedKernel::Bpc(bpc) => self.with_bpc_as(bpc, alias),
+            DecodedKernel::Planetary(dataset) => self.with_planetary_data_as(dataset, alias),
+            DecodedKernel::Spacecraft(dataset) => self.with_spacecraft_data_as(dataset, alias),
+            DecodedKernel::EulerParameter(dataset) => self.with_euler_parameters_as(dataset, alias),
+            DecodedKernel::Location(dataset) => self.with_location_data_as(dataset, alias),
+            DecodedKernel::Instrument(dataset) => self.with_instrument_data_as(dataset, alias),
+            DecodedKernel::Articulation(dataset) => self.with_articulation_data_as(dataset, alias),
+            DecodedKernel::Traverse(dataset) => self.with_traverse_data_as(dataset, alias),
+        }
+    }
+
+    /// Decodes the provided bytes as one of the data types supported in ANISE, without modifying this Almanac.
+    ///
+    /// If warnings are provided, the bytes are decoded in best effort, cf. [Self::load_best_effort], and the description of
+    /// the segments and data items that were skipped is appended to the warnings.
+    fn decode_kernel(
+        &self,
+        bytes: BytesMut,
+        path: Option<&str>,
+        warnings: Option<&mut Vec<String>>,
+    ) -> AlmanacResult<DecodedKernel> {
         // Check if they forgot to run git lfs
         if let Some(lfs_header) = bytes.get(..8) {
             if lfs_header == "version".as_bytes() {
@@ -209,8 +382,6 @@ impl Almanac {
             }
         }
 
-        let path_str = path.map_or_else(|| None, |p| Some(p.to_string()));
-
         // Load the header only
         if let Some(file_record_bytes) = bytes.get(..FileRecord::SIZE) {
             let file_record = FileRecord::read_from_bytes(file_record_bytes).unwrap();
@@ -218,25 +389,53 @@ impl Almanac {
                 return match fileid {
                     "PCK" => {
                         info!("Loading {} as DAF/PCK", path.unwrap_or("bytes"));
-                        let bpc = BPC::parse(bytes)
+                        let mut bpc = BPC::parse(bytes)
                             .context(BPCSnafu {
                                 action: "parsing bytes",
                             })
                             .context(OrientationSnafu {
                                 action: "from generic loading",
                             })?;
-                        Ok(self.with_bpc_as(bpc, path_str))
+                        if let Some(warnings) = warnings {
+                            warnings.extend(
+                                bpc.clear_invalid_summaries()
+                                    .context(BPCSnafu {
+                                        action: "clearing invalid summaries",
+                                    })
+                                    .context(OrientationSnafu {
+                                        action: "from best effort loading",
+                                    })?,
+                            );
+                        }
+                        if self.settings.strict_overlap {
+                            self.check_bpc_overlap(&bpc, path)?;
+                        }
+                        Ok(DecodedKernel::Bpc(bpc))
                     }
                     "SPK" => {
                         info!("Loading {} as DAF/SPK", path.unwrap_or("bytes"));
-                        let spk = SPK::parse(bytes)
+                        let mut spk = SPK::parse(bytes)
                             .context(SPKSnafu {
                                 action: "parsing bytes",
                             })
                             .context(EphemerisSnafu {
                                 action: "from generic loading",
                             })?;
-                        Ok(self.with_spk_as(spk, path_str))
+                        if let Some(warnings) = warnings {
+                            warnings.extend(
+                                spk.clear_invalid_summaries()
+                                    .context(SPKSnafu {
+                                        action: "clearing invalid summaries",
+                                    })
+                                    .context(EphemerisSnafu {
+                                        action: "from best effort loading",
+                                    })?,
+                            );
+                        }
+                        if self.settings.strict_overlap {
+                            self.check_spk_overlap(&spk, path)?;
+                        }
+                        Ok(DecodedKernel::Spk(spk))
                     }
                     fileid => Err(AlmanacError::GenericError {
                         err: format!("DAF/{fileid} is not yet supported"),
@@ -265,7 +464,7 @@ impl Almanac {
                 }
                 DataSetType::SpacecraftData => {
                     // Decode as spacecraft data
-                    let dataset = SpacecraftDataSet::try_from_bytes(bytes).context({
+                    let dataset: SpacecraftDataSet = decode_dataset(bytes, warnings).context({
                         TLDataSetSnafu {
                             action: "loading as spacecraft data",
                         }
@@ -274,36 +473,71 @@ impl Almanac {
                         "Loading {} as ANISE spacecraft data",
                         path.unwrap_or("bytes")
                     );
-                    Ok(self.with_spacecraft_data_as(dataset, path_str))
+                    Ok(DecodedKernel::Spacecraft(dataset))
                 }
                 DataSetType::PlanetaryData => {
                     // Decode as planetary data
-                    let dataset = PlanetaryDataSet::try_from_bytes(bytes).context({
+                    let dataset: PlanetaryDataSet = decode_dataset(bytes, warnings).context({
                         TLDataSetSnafu {
                             action: "loading as planetary data",
                         }
                     })?;
                     info!("Loading {} as ANISE/PCA", path.unwrap_or("bytes"));
-                    Ok(self.with_planetary_data_as(dataset, path_str))
+                    Ok(DecodedKernel::Planetary(dataset))
                 }
                 DataSetType::EulerParameterData => {
                     // Decode as euler parameter data
-                    let dataset = EulerParameterDataSet::try_from_bytes(bytes).context({
-                        TLDataSetSnafu {
-                            action: "loading Euler parameters",
-                        }
-                    })?;
+                    let dataset: EulerParameterDataSet =
+                        decode_dataset(bytes, warnings).context({
+                            TLDataSetSnafu {
+                                action: "loading Euler parameters",
+                            }
+                        })?;
                     info!("Loading {} as ANISE/EPA", path.unwrap_or("bytes"));
-                    Ok(self.with_euler_parameters_as(dataset, path_str))
+                    Ok(DecodedKernel::EulerParameter(dataset))
                 }
                 DataSetType::LocationData => {
-                    let dataset = LocationDataSet::try_from_bytes(bytes).context({
+                    let dataset: LocationDataSet = decode_dataset(bytes, warnings).context({
                         TLDataSetSnafu {
                             action: "loading location data",
                         }
                     })?;
                     info!("Loading {} as ANISE/LDA", path.unwrap_or("bytes"));
-                    Ok(self.with_location_data_as(dataset, path_str))
+                    Ok(DecodedKernel::Location(dataset))
+                }
+                DataSetType::InstrumentData => {
+                    let dataset: InstrumentDataSet = decode_dataset(bytes, warnings).context({
+                        TLDataSetSnafu {
+                            action: "loading instrument data",
+                        }
+                    })?;
+                    info!(
+                        "Loading {} as ANISE instrument data",
+                        path.unwrap_or("bytes")
+                    );
+                    Ok(DecodedKernel::Instrument(dataset))
+                }
+                DataSetType::ArticulationData => {
+                    let dataset: ArticulationDataSet =
+                        decode_dataset(bytes, warnings).context({
+                            TLDataSetSnafu {
+                                action: "loading articulation data",
+                            }
+                        })?;
+                    info!(
+                        "Loading {} as ANISE articulation data",
+                        path.unwrap_or("bytes")
+                    );
+                    Ok(DecodedKernel::Articulation(dataset))
+                }
+                DataSetType::TraverseData => {
+                    let dataset: TraverseDataSet = decode_dataset(bytes, warnings).context({
+                        TLDataSetSnafu {
+                            action: "loading traverse data",
+                        }
+                    })?;
+                    info!("Loading {} as ANISE traverse data", path.unwrap_or("bytes"));
+                    Ok(DecodedKernel::Traverse(dataset))
                 }
             }
         } else {
@@ -326,7 +560,7 @@ impl Almanac {
             Ok(bytes) => BytesMut::from(&bytes[..]),
         };
 
-        self._load_from_bytes(bytes, Some(path))
+        self._load_from_bytes(bytes, Some(path), None)
             .map_err(|e| match e {
                 AlmanacError::GenericError { err } => {
                     // Add the path to the error
@@ -457,6 +691,31 @@ impl Almanac {
 
         kernels
     }
+    /// Unloads (in-place) the kernel loaded with the provided alias, which is the path of the file if it was loaded with `load`, regardless of its type.
+    ///
+    /// Unlike `spk_unload` and `bpc_unload`, this preserves the loading order of the remaining kernels, and therefore their precedence.
+    pub fn unload(&mut self, alias: &str) -> AlmanacResult<()> {
+        self.kernel_priority.remove(alias);
+        if self.spk_data.shift_remove(alias).is_some()
+            || self.bpc_data.shift_remove(alias).is_some()
+            || self.planetary_data.shift_remove(alias).is_some()
+            || self.spacecraft_data.shift_remove(alias).is_some()
+            || self.euler_param_data.shift_remove(alias).is_some()
+            || self.location_data.shift_remove(alias).is_some()
+            || self.instrument_data.shift_remove(alias).is_some()
+            || self.articulation_data.shift_remove(alias).is_some()
+            || self.traverse_data.shift_remove(alias).is_some()
+            || self.eop_data.shift_remove(alias).is_some()
+        {
+            self.refresh_priority_order();
+            Ok(())
+        } else {
+            Err(AlmanacError::GenericError {
+                err: format!("cannot unload `{alias}`: no kernel loaded with this alias"),
+            })
+        }
+    }
+
     /// Set the CRC32 of all loaded DAF files
     pub fn set_crc32(&mut self) {
         for spk in self.spk_data.values_mut() {
@@ -514,6 +773,7 @@ impl Almanac {
             if let Some(entry) = self.spk_data.shift_remove(alias) {
                 self.spk_data.insert(new_alias, entry);
             }
+            self.refresh_priority_order();
         }
 
         Ok(())
@@ -566,6 +826,7 @@ impl Almanac {
             if let Some(entry) = self.bpc_data.shift_remove(alias) {
                 self.bpc_data.insert(new_alias, entry);
             }
+            self.refresh_priority_order();
         }
 
         Ok(())
@@ -589,4 +850,31 @@ impl Almanac {
             },
         })
     }
+
+    /// Returns the articulation from its ID, searching through all loaded articulation datasets in reverse order.
+    pub(crate) fn articulation_from_id(
+        &self,
+        id: NaifId,
+    ) -> Result<Articulation, OrientationError> {
+        for data in self.articulation_data.values().rev() {
+            if let Ok(datum) = data.get_by_id(id) {
+                return Ok(datum);
+            }
+        }
+
+        Err(OrientationError::OrientationDataSet {
+            source: DataSetError::DataSetLut {
+                action: "fetching by ID",
+                source: LutError::UnknownId { id },
+            },
+        })
+    }
+
+    /// Returns the traverse from its ID, searching through all loaded traverse datasets in reverse order, if any.
+    pub(crate) fn traverse_from_id(&self, id: NaifId) -> Option<Traverse> {
+        self.traverse_data
+            .values()
+            .rev()
+            .find_map(|data| data.get_by_id(id).ok())
+    }
 }
@@ -0,0 +1,293 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use core::mem::discriminant;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::Almanac;
+use crate::structure::planetocentric::{
+    ellipsoid::Ellipsoid, phaseangle::PhaseAngle, PlanetaryData, MAX_NUT_PREC_ANGLES,
+};
+use crate::NaifId;
+
+/// Value of a planetary constant which takes precedence over that of the loaded planetary data.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PlanetaryConstant {
+    /// Gravitational parameter in km^3/s^2
+    MuKm3S2(f64),
+    /// Tri-axial ellipsoid of the body
+    Shape(Ellipsoid),
+    /// Right ascension of the pole, used for the rotation of the IAU frame of the body
+    PoleRightAscension(PhaseAngle<MAX_NUT_PREC_ANGLES>),
+    /// Declination of the pole, used for the rotation of the IAU frame of the body
+    PoleDeclination(PhaseAngle<MAX_NUT_PREC_ANGLES>),
+    /// Prime meridian, used for the rotation of the IAU frame of the body
+    PrimeMeridian(PhaseAngle<MAX_NUT_PREC_ANGLES>),
+}
+
+impl PlanetaryConstant {
+    /// Returns the name of this constant.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MuKm3S2(_) => "gravitational parameter",
+            Self::Shape(_) => "shape",
+            Self::PoleRightAscension(_) => "pole right ascension",
+            Self::PoleDeclination(_) => "pole declination",
+            Self::PrimeMeridian(_) => "prime meridian",
+        }
+    }
+
+    /// Returns the value of this same constant in the provided planetary data, if it defines it.
+    pub fn loaded_in(&self, data: &PlanetaryData) -> Option<Self> {
+        match self {
+            Self::MuKm3S2(_) => Some(Self::MuKm3S2(data.mu_km3_s2)),
+            Self::Shape(_) => data.shape.map(Self::Shape),
+            Self::PoleRightAscension(_) => data.pole_right_ascension.map(Self::PoleRightAscension),
+            Self::PoleDeclination(_) => data.pole_declination.map(Self::PoleDeclination),
+            Self::PrimeMeridian(_) => data.prime_meridian.map(Self::PrimeMeridian),
+        }
+    }
+
+    /// Sets this constant in the provided planetary data.
+    pub fn apply_to(&self, data: &mut PlanetaryData) {
+        match *self {
+            Self::MuKm3S2(mu_km3_s2) => data.mu_km3_s2 = mu_km3_s2,
+            Self::Shape(shape) => data.shape = Some(shape),
+            Self::PoleRightAscension(angle) => data.pole_right_ascension = Some(angle),
+            Self::PoleDeclination(angle) => data.pole_declination = Some(angle),
+            Self::PrimeMeridian(angle) => data.prime_meridian = Some(angle),
+        }
+    }
+}
+
+impl fmt::Display for PlanetaryConstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MuKm3S2(mu_km3_s2) => write!(f, "{mu_km3_s2} km^3/s^2"),
+            Self::Shape(shape) => write!(f, "{shape}"),
+            Self::PoleRightAscension(angle)
+            | Self::PoleDeclination(angle)
+            | Self::PrimeMeridian(angle) => write!(f, "{angle}"),
+        }
+    }
+}
+
+/// A planetary constant of an object which overrides the loaded planetary data, with a provenance tag (e.g. the mission document it comes from).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstantOverride {
+    /// NAIF ID of the object, as in the planetary data
+    pub id: NaifId,
+    pub constant: PlanetaryConstant,
+    pub provenance: String,
+}
+
+impl fmt::Display for ConstantOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} = {} ({})",
+            self.constant.name(),
+            self.id,
+            self.constant,
+            self.provenance
+        )
+    }
+}
+
+#[derive(Tabled)]
+struct OverrideRow {
+    #[tabled(rename = "ID")]
+    id: NaifId,
+    #[tabled(rename = "Constant")]
+    name: &'static str,
+    #[tabled(rename = "Loaded value")]
+    loaded: String,
+    #[tabled(rename = "Override")]
+    value: String,
+    #[tabled(rename = "Provenance")]
+    provenance: String,
+}
+
+impl Almanac {
+    /// Returns a copy of this Almanac where the provided constant of the object of the provided ID overrides that of the loaded
+    /// planetary data, replacing any previous override of the same constant of this object.
+    ///
+    /// Overrides apply to all of the computations which use planetary data: the frame information (gravitational parameter and shape),
+    /// and the rotation of the IAU frames (pole and prime meridian), regardless of the order in which the planetary data is loaded.
+    /// Overriding the gravitational parameter or the shape of an object without planetary data makes its frame information available.
+    pub fn with_constant_override(
+        mut self,
+        id: NaifId,
+        constant: PlanetaryConstant,
+        provenance: impl Into<String>,
+    ) -> Self {
+        self.set_constant_override(id, constant, provenance);
+        self
+    }
+
+    /// Overrides the provided constant of the object of the provided ID, cf. [Self::with_constant_override].
+    pub fn set_constant_override(
+        &mut self,
+        id: NaifId,
+        constant: PlanetaryConstant,
+        provenance: impl Into<String>,
+    ) {
+        self.constant_overrides.retain(|other| {
+            other.id != id || discriminant(&other.constant) != discriminant(&constant)
+        });
+        self.constant_overrides.push(ConstantOverride {
+            id,
+            constant,
+            provenance: provenance.into(),
+        });
+    }
+
+    /// Removes all of the constant overrides of the object of the provided ID, returning them.
+    pub fn remove_constant_overrides(&mut self, id: NaifId) -> Vec<ConstantOverride> {
+        let (removed, kept) = self
+            .constant_overrides
+            .drain(..)
+            .partition(|other| other.id == id);
+        self.constant_overrides = kept;
+        removed
+    }
+
+    /// Returns the provenance of the override of the constant of the same kind as the provided one, for the object of the provided ID, if any.
+    pub fn constant_provenance(&self, id: NaifId, constant: PlanetaryConstant) -> Option<&str> {
+        self.constant_overrides
+            .iter()
+            .find(|other| {
+                other.id == id && discriminant(&other.constant) == discriminant(&constant)
+            })
+            .map(|other| other.provenance.as_str())
+    }
+
+    /// Returns the provided planetary data with the constant overrides of its object applied.
+    pub(crate) fn with_overrides(&self, mut data: PlanetaryData) -> PlanetaryData {
+        let id = data.object_id;
+        for other in self
+            .constant_overrides
+            .iter()
+            .filter(|other| other.id == id)
+        {
+            other.constant.apply_to(&mut data);
+        }
+        data
+    }
+
+    /// Returns a table of the constant overrides, with the loaded value each replaces and its provenance, to audit the constants used by this Almanac.
+    pub fn describe_constant_overrides(&self) -> String {
+        let rows = self
+            .constant_overrides
+            .iter()
+            .map(|other| OverrideRow {
+                id: other.id,
+                name: other.constant.name(),
+                loaded: self
+                    .planetary_data
+                    .values()
+                    .rev()
+                    .find_map(|data| data.get_by_id(other.id).ok())
+                    .and_then(|data| other.constant.loaded_in(&data))
+                    .map_or("Unset".to_string(), |value| format!("{value}")),
+                value: format!("{}", other.constant),
+                provenance: other.provenance.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut tbl = Table::new(rows);
+        tbl.with(Style::modern());
+        format!("{tbl}")
+    }
+}
+
+#[cfg(test)]
+mod ut_overrides {
+    use super::PlanetaryConstant;
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME, MOON_J2000};
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use crate::structure::planetocentric::phaseangle::PhaseAngle;
+    use hifitime::Epoch;
+
+    #[test]
+    fn constant_overrides() {
+        let almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 6, 1);
+        let loaded = almanac.frame_info(EARTH_J2000).unwrap();
+
+        let overridden = almanac
+            .clone()
+            .with_constant_override(399, PlanetaryConstant::MuKm3S2(398_600.0), "draft")
+            .with_constant_override(
+                399,
+                PlanetaryConstant::MuKm3S2(398_600.441_8),
+                "ICD-123 rev B",
+            )
+            .with_constant_override(
+                399,
+                PlanetaryConstant::Shape(Ellipsoid::WGS84),
+                "ICD-123 rev B",
+            )
+            .with_constant_override(
+                399,
+                PlanetaryConstant::PrimeMeridian(
+                    PhaseAngle::maybe_new(&[280.0, 360.985_612_288_8, 0.0]).unwrap(),
+                ),
+                "ERA",
+            );
+        println!("{}", overridden.describe_constant_overrides());
+
+        // The latest override of a constant replaces the previous one.
+        assert_eq!(overridden.constant_overrides.len(), 3);
+        let frame = overridden.frame_info(EARTH_J2000).unwrap();
+        assert_eq!(frame.mu_km3_s2, Some(398_600.441_8));
+        assert_eq!(frame.shape, Some(Ellipsoid::WGS84));
+        assert_ne!(loaded.mu_km3_s2, frame.mu_km3_s2);
+        assert_eq!(
+            overridden.constant_provenance(399, PlanetaryConstant::MuKm3S2(0.0)),
+            Some("ICD-123 rev B")
+        );
+        assert_eq!(
+            overridden.constant_provenance(301, PlanetaryConstant::MuKm3S2(0.0)),
+            None
+        );
+        // Other objects are unaffected
+        assert_eq!(
+            overridden.frame_info(MOON_J2000).unwrap(),
+            almanac.frame_info(MOON_J2000).unwrap()
+        );
+
+        // The rotation uses the overridden prime meridian, and the loaded pole.
+        let (ra_deg, dec_deg, pm_deg) = overridden.pole_angles_deg(IAU_EARTH_FRAME, epoch).unwrap();
+        let (loaded_ra_deg, loaded_dec_deg, loaded_pm_deg) =
+            almanac.pole_angles_deg(IAU_EARTH_FRAME, epoch).unwrap();
+        assert_eq!((ra_deg, dec_deg), (loaded_ra_deg, loaded_dec_deg));
+        assert_ne!(pm_deg, loaded_pm_deg);
+        let dcm = overridden
+            .rotate(IAU_EARTH_FRAME, EARTH_J2000, epoch)
+            .unwrap();
+        let loaded_dcm = almanac.rotate(IAU_EARTH_FRAME, EARTH_J2000, epoch).unwrap();
+        assert!((dcm.rot_mat - loaded_dcm.rot_mat).norm() > 1e-3);
+
+        // Objects without planetary data get frame information from their overrides.
+        let sc_frame = Frame::from_ephem_j2000(-85);
+        assert!(almanac.frame_info(sc_frame).is_err());
+        let mut with_sc = overridden.clone();
+        with_sc.set_constant_override(-85, PlanetaryConstant::MuKm3S2(1e-9), "mass budget");
+        assert_eq!(with_sc.frame_info(sc_frame).unwrap().mu_km3_s2, Some(1e-9));
+
+        // Removing the overrides restores the loaded values.
+        assert_eq!(with_sc.remove_constant_overrides(399).len(), 3);
+        assert_eq!(with_sc.frame_info(EARTH_J2000).unwrap(), loaded);
+        assert_eq!(with_sc.constant_overrides.len(), 1);
+    }
+}
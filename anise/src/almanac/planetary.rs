@@ -7,11 +7,16 @@
  *
  * Documentation: https://nyxspace.com/
  */
-use super::Almanac;
+use super::{Almanac, PlanetaryConstant};
 use crate::{
+    astro::Aberration,
+    errors::{AlmanacError, AlmanacPhysicsSnafu, AlmanacResult, EphemerisSnafu, GenericSnafu},
+    math::cartesian::CartesianState,
     prelude::{Frame, FrameUid},
     structure::{
-        dataset::DataSetError, lookuptable::LutError, planetocentric::PlanetaryData,
+        dataset::DataSetError,
+        lookuptable::LutError,
+        planetocentric::{PlanetaryData, PoleAnglesDeg},
         PlanetaryDataSet,
     },
     NaifId,
@@ -44,10 +49,27 @@ impl Almanac {
         let uid = uid.into();
         for data in self.planetary_data.values().rev() {
             if let Ok(datum) = data.get_by_id(uid.ephemeris_id) {
-                return Ok(datum.to_frame(uid));
+                return Ok(self.with_overrides(datum).to_frame(uid));
             }
         }
 
+        // Objects without planetary data may still have their constants overridden.
+        let mut frame = Frame::from(uid);
+        for other in self
+            .constant_overrides
+            .iter()
+            .filter(|other| other.id == uid.ephemeris_id)
+        {
+            match other.constant {
+                PlanetaryConstant::MuKm3S2(mu_km3_s2) => frame.mu_km3_s2 = Some(mu_km3_s2),
+                PlanetaryConstant::Shape(shape) => frame.shape = Some(shape),
+                _ => {}
+            }
+        }
+        if frame.mu_km3_s2.is_some() || frame.shape.is_some() {
+            return Ok(frame);
+        }
+
         Err(PlanetaryDataError::PlanetaryDataSet {
             action: "fetching frame by its UID via ephemeris_id",
             source: DataSetError::DataSetLut {
@@ -66,7 +88,7 @@ impl Almanac {
     ) -> Result<PlanetaryData, PlanetaryDataError> {
         for data in self.planetary_data.values().rev() {
             if let Ok(datum) = data.get_by_id(id) {
-                return Ok(datum);
+                return Ok(self.with_overrides(datum));
             }
         }
 
@@ -79,6 +101,53 @@ impl Almanac {
         })
     }
 
+    /// Returns the planetary data of the provided orientation ID and that of its planetary system (i.e. its parent, or itself
+    /// if the parent is not loaded), searching through all loaded planetary datasets in reverse order.
+    fn planetary_data_and_system(
+        &self,
+        id: NaifId,
+    ) -> Result<(PlanetaryData, PlanetaryData), PlanetaryDataError> {
+        for data in self.planetary_data.values().rev() {
+            if let Ok(datum) = data.get_by_id(id) {
+                let system = data.get_by_id(datum.parent_id).unwrap_or(datum);
+                return Ok((self.with_overrides(datum), self.with_overrides(system)));
+            }
+        }
+
+        Err(PlanetaryDataError::PlanetaryDataSet {
+            action: "fetching planetary data and system via its id",
+            source: DataSetError::DataSetLut {
+                action: "fetching by ID",
+                source: LutError::UnknownId { id },
+            },
+        })
+    }
+
+    /// Evaluates the nutation and precession angles of the planetary system of the provided frame at the provided epoch.
+    ///
+    /// Returns a vector of tuples of each angle in degrees and its rate in degrees per Julian century, in the order in which they are defined in the PCK.
+    pub fn nut_prec_angles_deg(
+        &self,
+        frame: Frame,
+        epoch: Epoch,
+    ) -> Result<Vec<(f64, f64)>, PlanetaryDataError> {
+        let (_, system) = self.planetary_data_and_system(frame.orientation_id)?;
+        Ok(system.nut_prec_angles_deg(epoch))
+    }
+
+    /// Evaluates the pole right ascension, the pole declination, and the prime meridian angles in degrees of the provided frame
+    /// at the provided epoch, including the nutation and precession terms. These are the angles used to build the rotation of the IAU frames.
+    ///
+    /// Each angle is `None` if the planetary data of this frame does not define it.
+    pub fn pole_angles_deg(
+        &self,
+        frame: Frame,
+        epoch: Epoch,
+    ) -> Result<PoleAnglesDeg, PlanetaryDataError> {
+        let (datum, system) = self.planetary_data_and_system(frame.orientation_id)?;
+        Ok(datum.pole_angles_deg(epoch, &system))
+    }
+
     /// Returns the plantary from its ID, searching through all loaded planetary datasets in reverse order.
     pub fn set_planetary_data_from_id(
         &mut self,
@@ -123,6 +192,126 @@ impl Almanac {
     }
 }
 
+impl Almanac {
+    /// Returns the radius of the Laplace sphere of influence of the body of the provided frame with respect to the central body, in km,
+    /// using the osculating semi-major axis of the body about the central body at the provided epoch.
+    ///
+    /// Both frames must have their gravitational parameter defined in the loaded planetary data.
+    pub fn soi_radius_km(
+        &self,
+        frame: Frame,
+        central_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<f64> {
+        let (frame, central_frame) = self.frame_pair_info(frame, central_frame)?;
+
+        let state = self
+            .translate(frame, central_frame, epoch, None)
+            .context(EphemerisSnafu {
+                action: "computing sphere of influence",
+            })?;
+
+        let sma_km = state.sma_km().context(AlmanacPhysicsSnafu {
+            action: "computing sphere of influence",
+        })?;
+
+        frame
+            .soi_radius_km(sma_km, central_frame)
+            .context(AlmanacPhysicsSnafu {
+                action: "computing sphere of influence",
+            })
+    }
+
+    /// Returns the radius of the Hill sphere of the body of the provided frame with respect to the central body, in km,
+    /// using the distance between both bodies at the provided epoch from the loaded ephemerides.
+    ///
+    /// Both frames must have their gravitational parameter defined in the loaded planetary data.
+    pub fn hill_radius_km(
+        &self,
+        frame: Frame,
+        central_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<f64> {
+        let (frame, central_frame) = self.frame_pair_info(frame, central_frame)?;
+
+        let state = self
+            .translate(frame, central_frame, epoch, None)
+            .context(EphemerisSnafu {
+                action: "computing Hill sphere",
+            })?;
+
+        frame
+            .hill_radius_km(state.rmag_km(), central_frame)
+            .context(AlmanacPhysicsSnafu {
+                action: "computing Hill sphere",
+            })
+    }
+
+    /// Returns the instantaneous state of the barycenter of the provided bodies, as seen from the observer frame,
+    /// using the gravitational parameters of the loaded planetary data as the mass weights.
+    ///
+    /// This is useful to build a custom integration center, e.g. the barycenter of a subset of the Jovian system.
+    ///
+    /// # Errors
+    /// + No bodies are provided;
+    /// + Any of the bodies does not have its gravitational parameter defined in the loaded planetary data;
+    /// + Any of the bodies cannot be transformed into the observer frame at this epoch.
+    pub fn barycenter(
+        &self,
+        bodies: &[Frame],
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<CartesianState> {
+        ensure!(
+            !bodies.is_empty(),
+            GenericSnafu {
+                err: "barycenter requires at least one body"
+            }
+        );
+
+        let mut total_mu_km3_s2 = 0.0;
+        let mut weighted = CartesianState::zero_at_epoch(epoch, observer_frame);
+        for body in bodies {
+            let mu_km3_s2 = self
+                .frame_info(*body)
+                .map_err(|e| AlmanacError::GenericError {
+                    err: format!("{e} when fetching {body} frame data"),
+                })?
+                .mu_km3_s2()
+                .context(AlmanacPhysicsSnafu {
+                    action: "computing barycenter",
+                })?;
+
+            let state = self.transform(*body, observer_frame, epoch, ab_corr)?;
+
+            weighted.radius_km += mu_km3_s2 * state.radius_km;
+            weighted.velocity_km_s += mu_km3_s2 * state.velocity_km_s;
+            total_mu_km3_s2 += mu_km3_s2;
+        }
+
+        weighted.radius_km /= total_mu_km3_s2;
+        weighted.velocity_km_s /= total_mu_km3_s2;
+
+        Ok(weighted)
+    }
+
+    /// Fetches the frame information of both frames, for the SOI and Hill sphere computations.
+    fn frame_pair_info(&self, frame: Frame, central_frame: Frame) -> AlmanacResult<(Frame, Frame)> {
+        let frame = self
+            .frame_info(frame)
+            .map_err(|e| AlmanacError::GenericError {
+                err: format!("{e} when fetching {frame} frame data"),
+            })?;
+        let central_frame =
+            self.frame_info(central_frame)
+                .map_err(|e| AlmanacError::GenericError {
+                    err: format!("{e} when fetching {central_frame} frame data"),
+                })?;
+        Ok((frame, central_frame))
+    }
+}
+
 #[derive(Tabled, Default)]
 struct PlanetaryRow {
     #[tabled(rename = "Name")]
@@ -0,0 +1,338 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use core::iter::{Copied, Rev};
+use core::ops::Range;
+use core::slice;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use indexmap::IndexMap;
+
+use super::Almanac;
+use crate::errors::{AlmanacError, AlmanacResult};
+use crate::naif::daf::{NAIFSummaryRecord, DAF};
+use crate::naif::{BPC, SPK};
+
+/// Defines which loaded kernel answers a query when several loaded SPKs (or BPCs) provide data for the same object at the same epoch.
+///
+/// The default is SPICE's rule: the last loaded kernel wins.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int, module = "anise"))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SegmentPrecedence {
+    /// The most recently loaded kernel takes precedence (SPICE behavior)
+    #[default]
+    LastLoaded,
+    /// The first loaded kernel takes precedence
+    FirstLoaded,
+    /// The kernel with the highest priority tag takes precedence, where kernels without a tag have a priority of zero.
+    /// Kernels with the same priority follow the last loaded rule.
+    HighestPriorityTag,
+}
+
+impl fmt::Display for SegmentPrecedence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Search order of the loaded SPKs and BPCs under the `HighestPriorityTag` precedence, refreshed by the methods which load,
+/// unload, or rename kernels, or set their priority, so that the kernels are not sorted on every query.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PriorityOrder {
+    spk: KernelOrder,
+    bpc: KernelOrder,
+}
+
+/// Search order of one kind of kernels, along with the aliases it was computed from.
+#[derive(Clone, Debug, Default)]
+struct KernelOrder {
+    aliases: Vec<String>,
+    order: Vec<usize>,
+}
+
+impl KernelOrder {
+    fn new<'a, I>(aliases: I, order: Vec<usize>) -> Self
+    where
+        I: Iterator<Item = &'a String>,
+    {
+        Self {
+            aliases: aliases.cloned().collect(),
+            order,
+        }
+    }
+}
+
+/// Iterator over the indexes of the loaded kernels, in the order in which they are searched.
+pub(crate) enum PrecedenceIter<'a> {
+    Reversed(Rev<Range<usize>>),
+    Forward(Range<usize>),
+    Cached(Copied<slice::Iter<'a, usize>>),
+    Sorted(std::vec::IntoIter<usize>),
+}
+
+impl Iterator for PrecedenceIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Reversed(iter) => iter.next(),
+            Self::Forward(iter) => iter.next(),
+            Self::Cached(iter) => iter.next(),
+            Self::Sorted(iter) => iter.next(),
+        }
+    }
+}
+
+impl Almanac {
+    /// Returns a copy of this Almanac with the provided segment precedence policy.
+    pub fn with_segment_precedence(mut self, precedence: SegmentPrecedence) -> Self {
+        self.settings.segment_precedence = precedence;
+        self
+    }
+
+    /// Returns the priority tag of the SPK or BPC loaded with the provided alias, if one was set.
+    pub fn kernel_priority(&self, alias: &str) -> Option<i32> {
+        self.kernel_priority.get(alias).copied()
+    }
+
+    /// Sets (in-place) the priority tag of the SPK or BPC loaded with the provided alias, used by the `HighestPriorityTag` precedence.
+    pub fn set_kernel_priority(&mut self, alias: &str, priority: i32) -> AlmanacResult<()> {
+        if self.spk_data.contains_key(alias) || self.bpc_data.contains_key(alias) {
+            self.kernel_priority.insert(alias.to_string(), priority);
+            self.refresh_priority_order();
+            Ok(())
+        } else {
+            Err(AlmanacError::GenericError {
+                err: format!(
+                    "cannot set priority of `{alias}`: no SPK nor BPC loaded with this alias"
+                ),
+            })
+        }
+    }
+
+    /// Returns a copy of this Almanac where loading an SPK or a BPC (with `load` or `load_from_bytes`) fails if any of its segments
+    /// covers the same ID over an overlapping time span as a segment of an already loaded kernel.
+    ///
+    /// Kernels added with `with_spk` or `with_bpc` are not checked: use `check_spk_overlap` or `check_bpc_overlap` before adding them.
+    pub fn with_strict_overlap(mut self, strict: bool) -> Self {
+        self.settings.strict_overlap = strict;
+        self
+    }
+
+    /// Returns an error if any segment of the provided SPK covers the same target over an overlapping time span as a segment of one
+    /// of the loaded SPKs. The loaded SPK with the provided alias, if any, is ignored since it would be replaced.
+    pub fn check_spk_overlap(&self, spk: &SPK, alias: Option<&str>) -> AlmanacResult<()> {
+        check_overlap("SPK", spk, alias, &self.spk_data)
+    }
+
+    /// Returns an error if any segment of the provided BPC covers the same frame over an overlapping time span as a segment of one
+    /// of the loaded BPCs. The loaded BPC with the provided alias, if any, is ignored since it would be replaced.
+    pub fn check_bpc_overlap(&self, bpc: &BPC, alias: Option<&str>) -> AlmanacResult<()> {
+        check_overlap("BPC", bpc, alias, &self.bpc_data)
+    }
+
+    /// Returns the indexes of the loaded SPKs in the order in which they are searched.
+    pub(crate) fn spk_precedence(&self) -> PrecedenceIter<'_> {
+        self.precedence(self.spk_data.keys(), &self.priority_order.spk)
+    }
+
+    /// Returns the indexes of the loaded BPCs in the order in which they are searched.
+    pub(crate) fn bpc_precedence(&self) -> PrecedenceIter<'_> {
+        self.precedence(self.bpc_data.keys(), &self.priority_order.bpc)
+    }
+
+    /// Sorts the loaded SPKs and BPCs by priority again, which must be called whenever they or their priorities change.
+    pub(crate) fn refresh_priority_order(&mut self) {
+        self.priority_order = PriorityOrder {
+            spk: KernelOrder::new(
+                self.spk_data.keys(),
+                self.priority_sorted(self.spk_data.keys()),
+            ),
+            bpc: KernelOrder::new(
+                self.bpc_data.keys(),
+                self.priority_sorted(self.bpc_data.keys()),
+            ),
+        };
+    }
+
+    fn precedence<'a, 'b, I>(&self, aliases: I, cached: &'b KernelOrder) -> PrecedenceIter<'b>
+    where
+        I: ExactSizeIterator<Item = &'a String> + Clone,
+    {
+        let num = aliases.len();
+        match self.settings.segment_precedence {
+            SegmentPrecedence::LastLoaded => PrecedenceIter::Reversed((0..num).rev()),
+            SegmentPrecedence::FirstLoaded => PrecedenceIter::Forward(0..num),
+            SegmentPrecedence::HighestPriorityTag => {
+                if cached.aliases.iter().eq(aliases.clone()) {
+                    PrecedenceIter::Cached(cached.order.iter().copied())
+                } else {
+                    // The kernels were modified without refreshing the order, e.g. by editing the public fields directly.
+                    PrecedenceIter::Sorted(self.priority_sorted(aliases).into_iter())
+                }
+            }
+        }
+    }
+
+    fn priority_sorted<'a, I>(&self, aliases: I) -> Vec<usize>
+    where
+        I: ExactSizeIterator<Item = &'a String>,
+    {
+        let num = aliases.len();
+        let priorities = aliases
+            .map(|alias| self.kernel_priority.get(alias).copied().unwrap_or(0))
+            .collect::<Vec<i32>>();
+        let mut order = (0..num).rev().collect::<Vec<usize>>();
+        // Stable sort, so ties remain in the last loaded order.
+        order.sort_by_key(|idx| core::cmp::Reverse(priorities[*idx]));
+        order
+    }
+}
+
+fn check_overlap<R: NAIFSummaryRecord>(
+    kind: &'static str,
+    daf: &DAF<R>,
+    alias: Option<&str>,
+    loaded: &IndexMap<String, DAF<R>>,
+) -> AlmanacResult<()> {
+    for summary in daf
+        .iter_summary_blocks()
+        .flatten()
+        .flatten()
+        .filter(|summary| !summary.is_empty())
+    {
+        for (other_alias, other) in loaded {
+            if Some(other_alias.as_str()) == alias {
+                continue;
+            }
+            for other_summary in
+                other
+                    .iter_summary_blocks()
+                    .flatten()
+                    .flatten()
+                    .filter(|other_summary| {
+                        other_summary.id() == summary.id() && !other_summary.is_empty()
+                    })
+            {
+                let start = summary.start_epoch().max(other_summary.start_epoch());
+                let end = summary.end_epoch().min(other_summary.end_epoch());
+                if start < end {
+                    return Err(AlmanacError::KernelOverlap {
+                        kind,
+                        alias: alias.unwrap_or("bytes").to_string(),
+                        other: other_alias.clone(),
+                        id: summary.id(),
+                        start,
+                        end,
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod ut_precedence {
+    use super::SegmentPrecedence;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::naif::SPK;
+    use crate::prelude::Almanac;
+    use hifitime::Epoch;
+
+    /// Builds an SPK with a single Moon segment at the Earth.
+    fn moon_spk() -> SPK {
+        test_spk(&[fixed_segment(301, 399, [0.0; 3])])
+    }
+
+    #[test]
+    fn segment_precedence() {
+        let epoch = Epoch::from_et_seconds(3600.0);
+        let mut almanac = Almanac::default()
+            .with_spk_as(moon_spk(), Some("a".to_string()))
+            .with_spk_as(moon_spk(), Some("b".to_string()))
+            .with_spk_as(moon_spk(), Some("c".to_string()));
+
+        // Default is SPICE's last loaded rule
+        assert_eq!(
+            almanac.settings.segment_precedence,
+            SegmentPrecedence::LastLoaded
+        );
+        assert_eq!(almanac.spk_summary_at_epoch(301, epoch).unwrap().1, 2);
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![2, 1, 0]);
+
+        almanac = almanac.with_segment_precedence(SegmentPrecedence::FirstLoaded);
+        assert_eq!(almanac.spk_summary_at_epoch(301, epoch).unwrap().1, 0);
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        almanac = almanac.with_segment_precedence(SegmentPrecedence::HighestPriorityTag);
+        // Without any tag, this is the last loaded rule
+        assert_eq!(almanac.spk_summary_at_epoch(301, epoch).unwrap().1, 2);
+        almanac.set_kernel_priority("b", 10).unwrap();
+        almanac.set_kernel_priority("a", -1).unwrap();
+        assert_eq!(almanac.spk_summary_at_epoch(301, epoch).unwrap().1, 1);
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![1, 2, 0]);
+        // Summaries are reported in the order in which they are used
+        assert_eq!(almanac.spk_summaries(301).unwrap().len(), 3);
+
+        assert!(almanac.set_kernel_priority("unknown", 1).is_err());
+
+        // Changing a priority reorders the same kernels
+        almanac.set_kernel_priority("a", 20).unwrap();
+        assert_eq!(almanac.kernel_priority("a"), Some(20));
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(almanac.spk_summary_at_epoch(301, epoch).unwrap().1, 0);
+        almanac.set_kernel_priority("a", -1).unwrap();
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![1, 2, 0]);
+
+        // Unloading removes the priority
+        almanac.unload("b").unwrap();
+        assert_eq!(almanac.kernel_priority("b"), None);
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![1, 0]);
+
+        almanac.set_kernel_priority("a", 5).unwrap();
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![0, 1]);
+
+        // The kernels are sorted again if they were replaced without the Almanac methods, even if their number is unchanged.
+        almanac.spk_data.shift_remove("a");
+        almanac.spk_data.insert("d".to_string(), moon_spk());
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![1, 0]);
+        almanac.spk_data.shift_remove("c");
+        assert_eq!(almanac.spk_precedence().collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn strict_overlap() {
+        use crate::errors::AlmanacError;
+        use bytes::BytesMut;
+
+        let almanac = Almanac::default().with_spk_as(moon_spk(), Some("a".to_string()));
+        // Replacing the same alias is not an overlap
+        assert!(almanac.check_spk_overlap(&moon_spk(), Some("a")).is_ok());
+        assert!(matches!(
+            almanac.check_spk_overlap(&moon_spk(), Some("b")),
+            Err(AlmanacError::KernelOverlap { id: 301, .. })
+        ));
+        assert!(Almanac::default()
+            .check_spk_overlap(&moon_spk(), None)
+            .is_ok());
+
+        // Loading is only checked in strict mode
+        let bytes = BytesMut::from(&moon_spk().bytes[..]);
+        assert!(almanac.clone().load_from_bytes(bytes.clone()).is_ok());
+        assert!(almanac
+            .with_strict_overlap(true)
+            .load_from_bytes(bytes)
+            .is_err());
+    }
+}
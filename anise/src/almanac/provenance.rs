@@ -0,0 +1,393 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Epoch, Unit as TimeUnit};
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::astro::Aberration;
+use crate::constants::frames::SSB_J2000;
+use crate::constants::orientations::{ECLIPJ2000, J2000};
+use crate::constants::SPEED_OF_LIGHT_KM_S;
+use crate::ephemerides::paths::MAX_TREE_DEPTH;
+use crate::ephemerides::EphemerisError;
+use crate::errors::{AlmanacResult, EphemerisSnafu, OrientationSnafu};
+use crate::math::cartesian::CartesianState;
+use crate::naif::daf::datatypes::{Type2ChebyshevSet, Type3ChebyshevSet};
+use crate::naif::daf::{DafDataType, NAIFSummaryRecord, DAF};
+use crate::orientations::OrientationError;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+/// Describes which loaded data answered one link (from a child to its parent) of the ephemeris or orientation tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkProvenance {
+    /// Kind of data used for this link: "SPK", "BPC", "planetary data", "Euler parameters", or "built-in"
+    pub kind: &'static str,
+    /// Alias of the kernel or dataset, typically its path, or None for built-in rotations
+    pub alias: Option<String>,
+    /// Ephemeris or orientation ID of the child of this link
+    pub from_id: NaifId,
+    /// Ephemeris or orientation ID of the parent of this link
+    pub to_id: NaifId,
+    /// Epoch at which this link was evaluated, which differs from the query epoch for the target of an aberration corrected query
+    pub epoch: Epoch,
+    /// Name of the DAF segment, as stored in the name record
+    pub segment_name: Option<String>,
+    /// Index of the summary record of the DAF segment (None for the first one) and index of the segment in that summary record
+    pub segment_index: Option<(Option<usize>, usize)>,
+    pub data_type: Option<DafDataType>,
+    /// Index of the interpolation record used in the DAF segment, only available for Chebyshev segments
+    pub record_index: Option<usize>,
+}
+
+impl LinkProvenance {
+    fn dataset(
+        kind: &'static str,
+        alias: Option<&String>,
+        from_id: NaifId,
+        to_id: NaifId,
+        epoch: Epoch,
+    ) -> Self {
+        Self {
+            kind,
+            alias: alias.cloned(),
+            from_id,
+            to_id,
+            epoch,
+            segment_name: None,
+            segment_index: None,
+            data_type: None,
+            record_index: None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn segment<R: NAIFSummaryRecord>(
+        kind: &'static str,
+        alias: &str,
+        daf: &DAF<R>,
+        summary: &R,
+        daf_idx: Option<usize>,
+        idx: usize,
+        from_id: NaifId,
+        to_id: NaifId,
+        epoch: Epoch,
+    ) -> Self {
+        let segment_name = daf.file_record().ok().and_then(|file_rcrd| {
+            daf.name_record(daf_idx).ok().map(|name_rcrd| {
+                name_rcrd
+                    .nth_name(idx, file_rcrd.summary_size())
+                    .to_string()
+            })
+        });
+
+        let data_type = summary.data_type().ok();
+
+        let record_index = match data_type {
+            Some(DafDataType::Type2ChebyshevTriplet) => daf
+                .nth_data::<Type2ChebyshevSet>(daf_idx, idx)
+                .ok()
+                .and_then(|data| data.spline_idx(epoch, summary).ok()),
+            Some(DafDataType::Type3ChebyshevSextuplet) => daf
+                .nth_data::<Type3ChebyshevSet>(daf_idx, idx)
+                .ok()
+                .and_then(|data| data.spline_idx(epoch, summary).ok()),
+            _ => None,
+        }
+        .map(|spline_idx| spline_idx - 1);
+
+        Self {
+            kind,
+            alias: Some(alias.to_string()),
+            from_id,
+            to_id,
+            epoch,
+            segment_name,
+            segment_index: Some((daf_idx, idx)),
+            data_type,
+            record_index,
+        }
+    }
+}
+
+impl fmt::Display for LinkProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {} @ {}: {}",
+            self.from_id, self.to_id, self.epoch, self.kind
+        )?;
+        if let Some(alias) = &self.alias {
+            write!(f, " {alias}")?;
+        }
+        if let Some(name) = &self.segment_name {
+            write!(f, " segment `{name}`")?;
+        }
+        if let Some(data_type) = &self.data_type {
+            write!(f, " ({data_type:?})")?;
+        }
+        if let Some(record_index) = self.record_index {
+            write!(f, " record #{record_index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    /// Returns the same state as `translate`, and the list of the SPK segments used to compute it, starting with the path
+    /// from the observer to the common node, followed by the path from the target to the common node.
+    ///
+    /// With an aberration correction, the path of the observer is computed to the solar system barycenter at the requested epoch,
+    /// and the path of the target is computed to the solar system barycenter at the light time corrected epoch.
+    pub fn translate_explain(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> Result<(CartesianState, Vec<LinkProvenance>), EphemerisError> {
+        let state = self.translate(target_frame, observer_frame, epoch, ab_corr)?;
+
+        let mut links = Vec::new();
+        if observer_frame == target_frame {
+            return Ok((state, links));
+        }
+
+        match ab_corr {
+            None => {
+                let (_, _, common_node) =
+                    self.common_ephemeris_path(observer_frame, target_frame, epoch)?;
+                self.ephemeris_provenance(observer_frame, common_node, epoch, &mut links)?;
+                self.ephemeris_provenance(target_frame, common_node, epoch, &mut links)?;
+            }
+            Some(ab_corr) => {
+                let (_, _, common_node) =
+                    self.common_ephemeris_path(observer_frame, SSB_J2000, epoch)?;
+                self.ephemeris_provenance(observer_frame, common_node, epoch, &mut links)?;
+
+                // The light time is recovered from the corrected range, which the stellar aberration does not change.
+                let lt_sign = if ab_corr.transmit_mode { 1.0 } else { -1.0 };
+                let epoch_lt = epoch
+                    + lt_sign * (state.radius_km.norm() / SPEED_OF_LIGHT_KM_S) * TimeUnit::Second;
+                let (_, _, common_node) =
+                    self.common_ephemeris_path(target_frame, SSB_J2000, epoch_lt)?;
+                self.ephemeris_provenance(target_frame, common_node, epoch_lt, &mut links)?;
+            }
+        }
+
+        Ok((state, links))
+    }
+
+    /// Returns the same state as `transform`, and the list of the data used to compute it: first the SPK segments as in `translate_explain`,
+    /// then the orientation data from the target frame to the common orientation, and from the observer frame to the common orientation.
+    pub fn transform_explain(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<(CartesianState, Vec<LinkProvenance>)> {
+        let state = self.transform(target_frame, observer_frame, epoch, ab_corr)?;
+
+        let (_, mut links) = self
+            .translate_explain(target_frame, observer_frame, epoch, ab_corr)
+            .context(EphemerisSnafu {
+                action: "explaining transform",
+            })?;
+
+        if !target_frame.orient_origin_match(observer_frame) {
+            let (_, _, common_node) = self
+                .common_orientation_path(target_frame, observer_frame, epoch)
+                .context(OrientationSnafu {
+                    action: "explaining transform",
+                })?;
+            for frame in [target_frame, observer_frame] {
+                self.orientation_provenance(frame, common_node, epoch, &mut links)
+                    .context(OrientationSnafu {
+                        action: "explaining transform",
+                    })?;
+            }
+        }
+
+        Ok((state, links))
+    }
+
+    /// Appends the provenance of each SPK segment from the provided frame up to the common node.
+    fn ephemeris_provenance(
+        &self,
+        mut frame: Frame,
+        common_node: NaifId,
+        epoch: Epoch,
+        links: &mut Vec<LinkProvenance>,
+    ) -> Result<(), EphemerisError> {
+        for _ in 0..MAX_TREE_DEPTH {
+            if frame.ephem_origin_id_match(common_node) {
+                return Ok(());
+            }
+
+            let (summary, spk_no, daf_idx, idx_in_spk) =
+                self.spk_summary_at_epoch(frame.ephemeris_id, epoch)?;
+
+            let (alias, spk) = self
+                .spk_data
+                .get_index(spk_no)
+                .ok_or(EphemerisError::Unreachable)?;
+
+            links.push(LinkProvenance::segment(
+                "SPK",
+                alias,
+                spk,
+                summary,
+                daf_idx,
+                idx_in_spk,
+                summary.target_id,
+                summary.center_id,
+                epoch,
+            ));
+
+            frame = frame.with_ephem(summary.center_id);
+        }
+
+        // The common node is always found within the maximum tree depth when the path exists.
+        Err(EphemerisError::Unreachable)
+    }
+
+    /// Appends the provenance of each orientation link from the provided frame up to the common node, following the same search
+    /// order as `rotation_to_parent`.
+    fn orientation_provenance(
+        &self,
+        mut frame: Frame,
+        common_node: NaifId,
+        epoch: Epoch,
+        links: &mut Vec<LinkProvenance>,
+    ) -> Result<(), OrientationError> {
+        for _ in 0..MAX_TREE_DEPTH {
+            if frame.orient_origin_id_match(common_node) || frame.orient_origin_id_match(J2000) {
+                return Ok(());
+            }
+
+            let id = frame.orientation_id;
+
+            let parent_id = if frame.orient_origin_id_match(ECLIPJ2000) {
+                links.push(LinkProvenance::dataset("built-in", None, id, J2000, epoch));
+                J2000
+            } else if let Ok((summary, bpc_no, daf_idx, idx_in_bpc)) =
+                self.bpc_summary_at_epoch(id, epoch)
+            {
+                let (alias, bpc) = self
+                    .bpc_data
+                    .get_index(bpc_no)
+                    .ok_or(OrientationError::Unreachable)?;
+
+                links.push(LinkProvenance::segment(
+                    "BPC",
+                    alias,
+                    bpc,
+                    summary,
+                    daf_idx,
+                    idx_in_bpc,
+                    summary.frame_id,
+                    summary.inertial_frame_id,
+                    epoch,
+                ));
+                summary.inertial_frame_id
+            } else {
+                let parent_id = self.rotation_to_parent(frame, epoch)?.from;
+
+                let link = if let Some((alias, _)) = self
+                    .planetary_data
+                    .iter()
+                    .rev()
+                    .find(|(_, data)| data.get_by_id(id).is_ok())
+                {
+                    LinkProvenance::dataset("planetary data", Some(alias), id, parent_id, epoch)
+                } else {
+                    let alias = self
+                        .euler_param_data
+                        .iter()
+                        .rev()
+                        .find(|(_, data)| data.get_by_id(id).is_ok())
+                        .map(|(alias, _)| alias);
+                    LinkProvenance::dataset("Euler parameters", alias, id, parent_id, epoch)
+                };
+                links.push(link);
+                parent_id
+            };
+
+            frame = frame.with_orient(parent_id);
+        }
+
+        // The common node is always found within the maximum tree depth when the path exists.
+        Err(OrientationError::Unreachable)
+    }
+}
+
+#[cfg(test)]
+mod ut_provenance {
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000};
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::naif::daf::{DafDataType, DafSegment};
+    use crate::naif::SPK;
+    use crate::prelude::Almanac;
+    use hifitime::Epoch;
+
+    /// Builds an SPK with a Moon segment made of two degree one Chebyshev Type 2 records.
+    fn moon_spk() -> SPK {
+        let records = [
+            [[384_000.0, 1.0], [0.0; 2], [0.0; 2]],
+            [[384_002.0, 1.0], [0.0; 2], [0.0; 2]],
+        ];
+        test_spk(&[DafSegment {
+            name: "MOON".to_string(),
+            ..chebyshev_segment(301, 399, 0.0, 86_400.0, &records)
+        }])
+    }
+
+    #[test]
+    fn explain_translation() {
+        let almanac = Almanac::default().with_spk_as(moon_spk(), Some("moon.bsp".to_string()));
+        let epoch = Epoch::from_et_seconds(100_000.0);
+
+        let (state, links) = almanac
+            .translate_explain(MOON_J2000, EARTH_J2000, epoch, None)
+            .unwrap();
+        assert_eq!(
+            state,
+            almanac
+                .translate(MOON_J2000, EARTH_J2000, epoch, None)
+                .unwrap()
+        );
+        assert_eq!(links.len(), 1);
+        let link = &links[0];
+        assert_eq!(link.kind, "SPK");
+        assert_eq!(link.alias.as_deref(), Some("moon.bsp"));
+        assert_eq!((link.from_id, link.to_id), (301, 399));
+        assert_eq!(link.segment_name.as_deref(), Some("MOON"));
+        assert_eq!(link.segment_index, Some((None, 0)));
+        assert_eq!(link.data_type, Some(DafDataType::Type2ChebyshevTriplet));
+        assert_eq!(link.record_index, Some(1));
+
+        // Reversing the query reports the same segment
+        let (_, links) = almanac
+            .translate_explain(EARTH_J2000, MOON_J2000, epoch, None)
+            .unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].from_id, 301);
+
+        // Without any rotation, the transform uses the same segments
+        let (_, links) = almanac
+            .transform_explain(MOON_J2000, EARTH_J2000, epoch, None)
+            .unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].kind, "SPK");
+    }
+}
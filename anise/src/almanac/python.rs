@@ -8,20 +8,30 @@
  * Documentation: https://nyxspace.com/
  */
 
-use super::{planetary::PlanetaryDataError, Almanac};
+use super::{
+    planetary::PlanetaryDataError, Almanac, AlmanacSettings, Extrapolation, SegmentPrecedence,
+    TopocentricAxes,
+};
 use crate::constants::orientations::J2000;
-use crate::ephemerides::ephemeris::Ephemeris;
+use crate::ephemerides::ephemeris::{Ephemeris, FrameCovariance, LocalFrame};
 use crate::errors::EphemerisSnafu;
 use crate::{
-    astro::{Aberration, AzElRange, Location, Occultation},
+    astro::{
+        Aberration, AzElRange, EclipseWindow, Location, Occultation, OccultationKind,
+        OccultationWindow, RaDec,
+    },
     ephemerides::EphemerisError,
     errors::AlmanacResult,
-    math::{cartesian::CartesianState, rotation::DCM},
+    math::{
+        cartesian::CartesianState,
+        rotation::{Quaternion, DCM},
+        Vector3,
+    },
     orientations::OrientationError,
     prelude::{Frame, Orbit},
     NaifId,
 };
-use hifitime::{Epoch, TimeScale, TimeSeries};
+use hifitime::{ut1::Ut1Provider, Duration, Epoch, TimeScale, TimeSeries};
 use ndarray::Array1;
 use numpy::PyArray1;
 use pyo3::prelude::*;
@@ -214,6 +224,211 @@ impl Almanac {
         self.bpc_unload(alias)
     }
 
+    /// Unloads (in-place) the kernel loaded with the provided alias, which is the path of the file if it was loaded with `load`, regardless of its type.
+    ///
+    /// Unlike `spk_unload` and `bpc_unload`, this preserves the loading order of the remaining kernels, and therefore their precedence.
+    ///
+    /// :type alias: str
+    /// :rtype: None
+    #[pyo3(name = "unload")]
+    fn py_unload(&mut self, alias: &str) -> AlmanacResult<()> {
+        self.unload(alias)
+    }
+
+    /// Returns a copy of this Almanac with the provided segment precedence policy.
+    ///
+    /// :type precedence: SegmentPrecedence
+    /// :rtype: Almanac
+    #[pyo3(name = "with_segment_precedence")]
+    fn py_with_segment_precedence(&self, precedence: SegmentPrecedence) -> Self {
+        self.clone().with_segment_precedence(precedence)
+    }
+
+    /// Returns a copy of this Almanac where loading an SPK or a BPC fails if any of its segments covers the same ID over
+    /// an overlapping time span as a segment of an already loaded kernel.
+    ///
+    /// :type strict: bool
+    /// :rtype: Almanac
+    #[pyo3(name = "with_strict_overlap")]
+    fn py_with_strict_overlap(&self, strict: bool) -> Self {
+        self.clone().with_strict_overlap(strict)
+    }
+
+    /// Returns a copy of this Almanac where loading a kernel or a dataset fails if the memory held by the Almanac would then
+    /// exceed the provided number of bytes. There is no budget if None.
+    ///
+    /// :type budget_bytes: int, optional
+    /// :rtype: Almanac
+    #[pyo3(name = "with_memory_budget", signature=(budget_bytes=None))]
+    fn py_with_memory_budget(&self, budget_bytes: Option<usize>) -> Self {
+        self.clone().with_memory_budget(budget_bytes)
+    }
+
+    /// Returns the number of bytes held by each loaded kernel and dataset, as a list of (kind, alias, bytes) tuples.
+    ///
+    /// :rtype: typing.List[typing.Tuple[str, str, int]]
+    #[pyo3(name = "memory_report")]
+    fn py_memory_report(&self) -> Vec<(String, String, usize)> {
+        self.memory_report()
+            .usage
+            .into_iter()
+            .map(|usage| (usage.kind.to_string(), usage.alias, usage.bytes))
+            .collect()
+    }
+
+    /// Prepares this Almanac for latency sensitive queries of the provided frames between the provided epochs, and returns the
+    /// number of segments touched, of bytes touched, and of evaluations as a tuple.
+    ///
+    /// :type frames: typing.List[Frame]
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :rtype: typing.Tuple[int, int, int]
+    #[pyo3(name = "warm_up")]
+    fn py_warm_up(
+        &self,
+        frames: Vec<Frame>,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AlmanacResult<(usize, usize, usize)> {
+        let report = self.warm_up(&frames, start_epoch, end_epoch)?;
+        Ok((
+            report.segments_touched,
+            report.bytes_touched,
+            report.evaluations,
+        ))
+    }
+
+    /// Returns a copy of this Almanac with the provided settings.
+    ///
+    /// :type settings: AlmanacSettings
+    /// :rtype: Almanac
+    #[pyo3(name = "with_settings")]
+    fn py_with_settings(&self, settings: AlmanacSettings) -> Self {
+        self.clone().with_settings(settings)
+    }
+
+    /// Returns a copy of this Almanac with the provided extrapolation policy.
+    ///
+    /// :type extrapolation: Extrapolation
+    /// :rtype: Almanac
+    #[pyo3(name = "with_extrapolation")]
+    fn py_with_extrapolation(&self, extrapolation: Extrapolation) -> Self {
+        self.clone().with_extrapolation(extrapolation)
+    }
+
+    /// Returns a copy of this Almanac which uses the provided UT1 provider for the rotation of the IAU Earth frame.
+    ///
+    /// :type provider: Ut1Provider
+    /// :rtype: Almanac
+    #[pyo3(name = "with_ut1_provider")]
+    fn py_with_ut1_provider(&self, provider: PyRef<Ut1Provider>) -> Self {
+        self.clone().with_ut1_provider(provider.clone())
+    }
+
+    /// Returns the provided covariance expressed in the axes of the new frame, at the epoch of that covariance.
+    ///
+    /// The covariance is rotated with the 6x6 state DCM between both frames, P' = M P M^T, which includes the time derivative of the
+    /// rotation: when transforming to or from a rotating frame (e.g. a body fixed frame), the velocity covariance is coupled to the
+    /// position covariance by the rotation rate of that frame.
+    ///
+    /// :type covariance: FrameCovariance
+    /// :type new_frame: Frame
+    /// :rtype: FrameCovariance
+    #[pyo3(name = "transform_covariance_to")]
+    fn py_transform_covariance_to(
+        &self,
+        covariance: FrameCovariance,
+        new_frame: Frame,
+    ) -> AlmanacResult<FrameCovariance> {
+        self.transform_covariance_to(covariance, new_frame)
+    }
+
+    /// Returns the TDB - UT1 difference at the provided epoch, if this Almanac has a UT1 provider which covers it.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "tdb_minus_ut1")]
+    fn py_tdb_minus_ut1(&self, epoch: Epoch) -> Option<Duration> {
+        self.tdb_minus_ut1(epoch)
+    }
+
+    /// Returns a copy of this Almanac where a topocentric frame anchored at the provided geodetic site of the body fixed frame is registered,
+    /// such that `Frame(id, id)` may then be used as the target or observer of `transform` and `transform_to`.
+    ///
+    /// :type id: int
+    /// :type latitude_deg: float
+    /// :type longitude_deg: float
+    /// :type height_km: float
+    /// :type body_frame: Frame
+    /// :type axes: TopocentricAxes
+    /// :rtype: Almanac
+    #[pyo3(name = "with_topocentric_frame")]
+    fn py_with_topocentric_frame(
+        &self,
+        id: NaifId,
+        latitude_deg: f64,
+        longitude_deg: f64,
+        height_km: f64,
+        body_frame: Frame,
+        axes: TopocentricAxes,
+    ) -> AlmanacResult<Self> {
+        self.clone().with_topocentric_frame(
+            id,
+            latitude_deg,
+            longitude_deg,
+            height_km,
+            body_frame,
+            axes,
+        )
+    }
+
+    /// Returns the DCM from the axes of the registered topocentric frame to the orientation of the provided frame at the provided epoch,
+    /// including its time derivative from the rotation of the body fixed frame of the site.
+    ///
+    /// :type topocentric_frame: Frame
+    /// :type to_frame: Frame
+    /// :type epoch: Epoch
+    /// :rtype: DCM
+    #[pyo3(name = "topocentric_dcm")]
+    fn py_topocentric_dcm(
+        &self,
+        topocentric_frame: Frame,
+        to_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<DCM> {
+        self.topocentric_dcm(topocentric_frame, to_frame, epoch)
+    }
+
+    /// Sets (in-place) the priority tag of the SPK or BPC loaded with the provided alias, used by the `HighestPriorityTag` precedence.
+    ///
+    /// :type alias: str
+    /// :type priority: int
+    /// :rtype: None
+    #[pyo3(name = "set_kernel_priority")]
+    fn py_set_kernel_priority(&mut self, alias: &str, priority: i32) -> AlmanacResult<()> {
+        self.set_kernel_priority(alias, priority)
+    }
+
+    /// Unloads (in-place) the SPK at the provided index in the loading order, i.e. zero is the first loaded SPK.
+    /// The loading order of the remaining SPKs is preserved.
+    ///
+    /// :type index: int
+    /// :rtype: None
+    #[pyo3(name = "spk_unload_index")]
+    fn py_spk_unload_index(&mut self, index: usize) -> Result<(), EphemerisError> {
+        self.spk_unload_index(index)
+    }
+
+    /// Unloads (in-place) the BPC at the provided index in the loading order, i.e. zero is the first loaded BPC.
+    /// The loading order of the remaining BPCs is preserved.
+    ///
+    /// :type index: int
+    /// :rtype: None
+    #[pyo3(name = "bpc_unload_index")]
+    fn py_bpc_unload_index(&mut self, index: usize) -> Result<(), OrientationError> {
+        self.bpc_unload_index(index)
+    }
+
     /// Load a new DAF/SPK file in place of the one in the provided alias.
     ///
     /// This reuses the existing memory buffer, growing it only if the new file
@@ -407,6 +622,375 @@ impl Almanac {
         self.occultation(back_frame, front_frame, observer, ab_corr)
     }
 
+    /// Classifies the occultation of the `back_frame` object by the `front_frame` object as seen from the observer, modeling both objects
+    /// as spheres of their mean equatorial radius. This is the equivalent of the SPICE `occult` routine.
+    ///
+    /// The back object is visible if the front object is further from the observer than the back object, even if their disks overlap.
+    ///
+    /// :type back_frame: Frame
+    /// :type front_frame: Frame
+    /// :type observer: Orbit
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: OccultationKind
+    #[pyo3(name = "occultation_kind", signature=(
+        back_frame,
+        front_frame,
+        observer,
+        ab_corr=None,
+    ))]
+    fn py_occultation_kind(
+        &self,
+        back_frame: Frame,
+        front_frame: Frame,
+        observer: Orbit,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<OccultationKind> {
+        self.occultation_kind(back_frame, front_frame, observer, ab_corr)
+    }
+
+    /// Searches for the time intervals when the `back_frame` object is occulted by the `front_frame` object as seen from the origin of
+    /// the observer frame, between the start and end epochs. This is the equivalent of the SPICE `gfoclt` routine.
+    ///
+    /// If a kind is provided, only the windows of that exact kind are returned, otherwise all of the windows where the back object
+    /// is not fully visible are returned. The time span is sampled at the provided step, which must be shorter than the shortest
+    /// window to find, and the bounds of each window are then refined by bisection to ten milliseconds.
+    /// Windows in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// :type back_frame: Frame
+    /// :type front_frame: Frame
+    /// :type observer: Frame
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type kind: OccultationKind, optional
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List[OccultationWindow]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "occultation_windows", signature=(
+        back_frame,
+        front_frame,
+        observer,
+        start_epoch,
+        end_epoch,
+        step,
+        kind=None,
+        ab_corr=None,
+    ))]
+    fn py_occultation_windows(
+        &self,
+        back_frame: Frame,
+        front_frame: Frame,
+        observer: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        kind: Option<OccultationKind>,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<OccultationWindow>> {
+        self.occultation_windows(
+            back_frame,
+            front_frame,
+            observer,
+            kind,
+            start_epoch,
+            end_epoch,
+            step,
+            ab_corr,
+        )
+    }
+
+    /// Searches for the eclipses of the Sun by the eclipsing body as seen from the origin of the observer frame, e.g. a spacecraft
+    /// ephemeris, between the start and end epochs, returning the penumbra and umbra entry and exit epochs of each eclipse.
+    ///
+    /// This is the occultation search of the Sun by the eclipsing body, where the penumbra is any kind of occultation and the umbra is
+    /// a total occultation. Eclipses in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// :type observer: Frame
+    /// :type eclipsing_frame: Frame
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List[EclipseWindow]
+    #[pyo3(name = "eclipse_windows", signature=(
+        observer,
+        eclipsing_frame,
+        start_epoch,
+        end_epoch,
+        step,
+        ab_corr=None,
+    ))]
+    fn py_eclipse_windows(
+        &self,
+        observer: Frame,
+        eclipsing_frame: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<EclipseWindow>> {
+        self.eclipse_windows(
+            observer,
+            eclipsing_frame,
+            start_epoch,
+            end_epoch,
+            step,
+            ab_corr,
+        )
+    }
+
+    /// Returns the first of the occulting bodies whose ellipsoid blocks the line of sight from the origin of the observer frame to
+    /// the origin of the target frame at the provided epoch, or None if the line of sight is clear.
+    ///
+    /// Each body is modeled by the ellipsoid of its frame, fetched from the loaded planetary data if the frame does not define it.
+    /// The axes of the ellipsoid are those of the frame, so use body fixed frames (e.g. IAU Earth) to account for the orientation
+    /// of oblate bodies. Only the segment between the observer and the target is checked, so a body behind the target does not
+    /// block the line of sight, and a body whose center is the observer or the target is ignored.
+    ///
+    /// :type observer: Frame
+    /// :type target: Frame
+    /// :type epoch: Epoch
+    /// :type occulting_bodies: typing.List[Frame]
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: Frame
+    #[pyo3(name = "line_of_sight", signature=(observer, target, epoch, occulting_bodies, ab_corr=None))]
+    fn py_line_of_sight(
+        &self,
+        observer: Frame,
+        target: Frame,
+        epoch: Epoch,
+        occulting_bodies: Vec<Frame>,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Option<Frame>> {
+        self.line_of_sight(observer, target, epoch, &occulting_bodies, ab_corr)
+    }
+
+    /// Searches for the time intervals when the line of sight from the origin of the observer frame to the origin of the target
+    /// frame is not blocked by any of the occulting bodies, cf. `line_of_sight`, between the start and end epochs.
+    ///
+    /// The time span is sampled at the provided step, which must be shorter than the shortest window to find, and the bounds of
+    /// each window are then refined by bisection to ten milliseconds. Windows in progress at the start or the end epoch are
+    /// clipped to these epochs.
+    ///
+    /// :type observer: Frame
+    /// :type target: Frame
+    /// :type occulting_bodies: typing.List[Frame]
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List[typing.Tuple[Epoch, Epoch]]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "line_of_sight_windows", signature=(
+        observer,
+        target,
+        occulting_bodies,
+        start_epoch,
+        end_epoch,
+        step,
+        ab_corr=None,
+    ))]
+    fn py_line_of_sight_windows(
+        &self,
+        observer: Frame,
+        target: Frame,
+        occulting_bodies: Vec<Frame>,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Epoch)>> {
+        self.line_of_sight_windows(
+            observer,
+            target,
+            &occulting_bodies,
+            start_epoch,
+            end_epoch,
+            step,
+            ab_corr,
+        )
+    }
+
+    /// Returns the DCM from the observer frame to the body frame of the target for the nadir pointing attitude law with yaw steering.
+    ///
+    /// The body Z axis points to the nadir, i.e. the center of the observer frame. The body Y axis is perpendicular to the direction of
+    /// the Sun, which is in the body X-Z plane on the +X side: solar arrays rotating about the body Y axis can always face the Sun.
+    /// When the Sun is along the nadir direction, the yaw is undefined and the body Y axis is the negative orbit normal, like in LVLH.
+    ///
+    /// The observer frame must be inertial, and the time derivative of the DCM is not set.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type body_frame_id: int
+    /// :type epoch: Epoch
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: DCM
+    #[pyo3(name = "nadir_yaw_steering_dcm", signature=(target_frame, observer_frame, body_frame_id, epoch, ab_corr=None))]
+    fn py_nadir_yaw_steering_dcm(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<DCM> {
+        self.nadir_yaw_steering_dcm(target_frame, observer_frame, body_frame_id, epoch, ab_corr)
+    }
+
+    /// Returns the DCM from the observer frame to the body frame of the target for the Sun pointing attitude law with a roll constraint.
+    ///
+    /// The body Z axis points to the Sun, and the body X axis is as close as possible to the roll constraint, which is a direction
+    /// in the observer frame (e.g. the ecliptic pole, or the orbit normal). When the roll constraint is along the direction of the
+    /// Sun, the roll is undefined and an arbitrary perpendicular direction is used instead.
+    ///
+    /// The observer frame must be inertial, and the time derivative of the DCM is not set.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type body_frame_id: int
+    /// :type roll_constraint: typing.List[float]
+    /// :type epoch: Epoch
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: DCM
+    #[pyo3(name = "sun_pointing_dcm", signature=(target_frame, observer_frame, body_frame_id, roll_constraint, epoch, ab_corr=None))]
+    fn py_sun_pointing_dcm(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        roll_constraint: [f64; 3],
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<DCM> {
+        self.sun_pointing_dcm(
+            target_frame,
+            observer_frame,
+            body_frame_id,
+            Vector3::from(roll_constraint),
+            epoch,
+            ab_corr,
+        )
+    }
+
+    /// Samples the nadir pointing attitude law with yaw steering from the start to the end epoch, both included, at the provided step,
+    /// cf. `nadir_yaw_steering_dcm`. Each quaternion rotates from the observer frame to the body frame.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type body_frame_id: int
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List[typing.Tuple[Epoch, Quaternion]]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "nadir_yaw_steering_profile", signature=(
+        target_frame,
+        observer_frame,
+        body_frame_id,
+        start_epoch,
+        end_epoch,
+        step,
+        ab_corr=None,
+    ))]
+    fn py_nadir_yaw_steering_profile(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Quaternion)>> {
+        self.nadir_yaw_steering_profile(
+            target_frame,
+            observer_frame,
+            body_frame_id,
+            start_epoch,
+            end_epoch,
+            step,
+            ab_corr,
+        )
+    }
+
+    /// Samples the Sun pointing attitude law with a roll constraint from the start to the end epoch, both included, at the provided step,
+    /// cf. `sun_pointing_dcm`. Each quaternion rotates from the observer frame to the body frame.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type body_frame_id: int
+    /// :type roll_constraint: typing.List[float]
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type step: Duration
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: typing.List[typing.Tuple[Epoch, Quaternion]]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "sun_pointing_profile", signature=(
+        target_frame,
+        observer_frame,
+        body_frame_id,
+        roll_constraint,
+        start_epoch,
+        end_epoch,
+        step,
+        ab_corr=None,
+    ))]
+    fn py_sun_pointing_profile(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        body_frame_id: NaifId,
+        roll_constraint: [f64; 3],
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<(Epoch, Quaternion)>> {
+        self.sun_pointing_profile(
+            target_frame,
+            observer_frame,
+            body_frame_id,
+            Vector3::from(roll_constraint),
+            start_epoch,
+            end_epoch,
+            step,
+            ab_corr,
+        )
+    }
+
+    /// Returns the DCM from the local frame of the trajectory of the target, as seen from the observer, to the observer frame,
+    /// evaluated at any epoch covered by the ephemeris of the target. The observer frame must be inertial.
+    ///
+    /// This is a dynamic frame: unlike `Orbit.dcm_to_inertial`, which assumes two body dynamics, the time derivative of the DCM
+    /// is computed from the ephemeris of the target one millisecond before and after the epoch, so it accounts for all of the
+    /// dynamics of the trajectory. The time derivative is not set if either of these states cannot be computed.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type local_frame: LocalFrame
+    /// :type epoch: Epoch
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: DCM
+    #[pyo3(name = "local_frame_dcm_to_inertial", signature=(
+        target_frame,
+        observer_frame,
+        local_frame,
+        epoch,
+        ab_corr=None,
+    ))]
+    fn py_local_frame_dcm_to_inertial(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        local_frame: LocalFrame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<DCM> {
+        self.local_frame_dcm_to_inertial(target_frame, observer_frame, local_frame, epoch, ab_corr)
+    }
+
     /// Computes the solar eclipsing of the observer due to the eclipsing_frame.
     ///
     /// This function calls `occultation` where the back object is the Sun in the J2000 frame, and the front object
@@ -430,6 +1014,32 @@ impl Almanac {
         self.solar_eclipsing(eclipsing_frame, observer, ab_corr)
     }
 
+    /// Computes the fraction of the solar disk visible from the observer despite the eclipsing body, which is modeled by its ellipsoid:
+    /// zero in umbra, one in full sunlight, and continuous in penumbra. This is the illumination factor used in power and solar
+    /// radiation pressure models.
+    ///
+    /// The eclipsing body is modeled as a disk whose radius is the extent of its ellipsoid in the direction of the Sun, as seen
+    /// from the observer. The axes of the ellipsoid are those of the eclipsing frame, so use the body fixed frame of the
+    /// eclipsing body (e.g. IAU Earth) to account for its oblateness, which requires the orientation data of that frame.
+    ///
+    /// :type eclipsing_frame: Frame
+    /// :type observer: Orbit
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: float
+    #[pyo3(name = "shadow_factor", signature=(
+        eclipsing_frame,
+        observer,
+        ab_corr=None,
+    ))]
+    fn py_shadow_factor(
+        &self,
+        eclipsing_frame: Frame,
+        observer: Orbit,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<f64> {
+        self.shadow_factor(eclipsing_frame, observer, ab_corr)
+    }
+
     /// Computes the solar eclipsing of all the observers due to the eclipsing_frame, computed in parallel under the hood.
     ///
     /// Note: if any computation fails, the error will be printed to the stderr.
@@ -527,6 +1137,34 @@ impl Almanac {
         self.transform(target_frame, observer_frame, epoch, ab_corr)
     }
 
+    /// Returns the right ascension, declination, and range of the target as seen from the observer, with their rates of change, in
+    /// the orientation of the observer frame at the desired epoch.
+    ///
+    /// # SPICE Compatibility
+    /// This is the equivalent of calling `recrad` on the position returned by `spkezr`: use a light time and stellar aberration
+    /// correction (e.g. Aberration("CN+S")) for the apparent right ascension and declination of the target.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type epoch: Epoch
+    /// :type ab_corr: Aberration, optional
+    /// :rtype: RaDec
+    #[pyo3(name = "radec", signature=(
+        target_frame,
+        observer_frame,
+        epoch,
+        ab_corr=None,
+    ))]
+    fn py_radec(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<RaDec> {
+        self.radec(target_frame, observer_frame, epoch, ab_corr)
+    }
+
     /// Returns a chronologically sorted list of the Cartesian states that transform the `from_frame` to the `to_frame` for each epoch of the time series, computed in parallel under the hood.
     /// Note: if any transformation fails, the error will be printed to the stderr.
     ///
@@ -834,6 +1472,36 @@ impl Almanac {
         Ok(PyArray1::<f64>::from_owned_array(py, omega))
     }
 
+    /// Returns the provided vector, expressed in the body frame of a spacecraft (e.g. an instrument boresight), in the target frame.
+    ///
+    /// The body frame is the orientation ID of the spacecraft attitude, which must be loaded as Euler parameter or BPC data, and
+    /// the rotation from the body frame to the target frame is computed through the orientation tree, like [Almanac::rotate].
+    ///
+    /// :type sc_id: int
+    /// :type body_vector: list
+    /// :type target_frame: Frame
+    /// :type epoch: Epoch
+    /// :rtype: numpy.ndarray
+    #[pyo3(name = "body_vector_in_frame", signature = (sc_id, body_vector, target_frame, epoch))]
+    pub fn py_body_vector_in_frame<'py>(
+        &self,
+        py: Python<'py>,
+        sc_id: NaifId,
+        body_vector: [f64; 3],
+        target_frame: Frame,
+        epoch: Epoch,
+    ) -> Result<Bound<'py, PyArray1<f64>>, OrientationError> {
+        let data: Vec<f64> = self
+            .body_vector_in_frame(sc_id, Vector3::from(body_vector), target_frame, epoch)?
+            .iter()
+            .copied()
+            .collect();
+
+        let vector = Array1::from_shape_vec((3,), data).unwrap();
+
+        Ok(PyArray1::<f64>::from_owned_array(py, vector))
+    }
+
     /// Returns the angular velocity vector in rad/s of the from_frame wrt to the J2000 frame.
     ///
     /// :type from_frame: Frame
@@ -0,0 +1,289 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch, Unit};
+
+use super::Almanac;
+use crate::astro::Aberration;
+use crate::errors::{AlmanacError, AlmanacResult};
+use crate::math::cartesian::CartesianState;
+use crate::math::interpolation::InterpolationError;
+use crate::math::Vector3;
+use crate::prelude::Frame;
+
+/// States of a set of target frames as seen from an observer frame, precomputed on a uniform grid over a time horizon, for
+/// consumers with hard real-time constraints like hardware-in-the-loop simulators.
+///
+/// The samples of all of the targets are stored in a single contiguous array. Evaluating a state computes the index of the
+/// enclosing samples from the epoch and applies a cubic Hermite interpolation of the position and velocity between them: it
+/// never allocates, never searches, and its cost does not depend on the epoch, the loaded kernels, or the frame tree.
+///
+/// The interpolation is exact for motions up to cubic in time, and its error otherwise scales with the fourth power of the
+/// step, so the step should be chosen from the dynamics of the targets (e.g. one minute in low Earth orbit).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RealTimeTable {
+    pub observer: Frame,
+    /// Target frames, in the order of their indexes in `evaluate`
+    pub targets: Vec<Frame>,
+    pub ab_corr: Option<Aberration>,
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+    /// Number of samples of each target, including both ends of the horizon
+    num_samples: usize,
+    /// Step between samples in seconds, which is the requested step reduced to evenly divide the horizon
+    step_s: f64,
+    /// Position and velocity of each sample, target after target
+    data: Vec<f64>,
+}
+
+impl RealTimeTable {
+    /// Returns the step between two samples, at most the requested step.
+    pub fn step(&self) -> Duration {
+        self.step_s * Unit::Second
+    }
+
+    /// Returns the number of samples of each target.
+    pub fn num_samples(&self) -> usize {
+        self.num_samples
+    }
+
+    /// Returns the number of bytes of the precomputed samples.
+    pub fn data_bytes(&self) -> usize {
+        self.data.len() * core::mem::size_of::<f64>()
+    }
+
+    /// Returns the index of the provided target frame, to be resolved once before the real-time loop.
+    pub fn target_index(&self, target: Frame) -> Option<usize> {
+        self.targets.iter().position(|frame| *frame == target)
+    }
+
+    /// Returns the state of the target of the provided index at the provided epoch, in the observer frame.
+    ///
+    /// Returns an error if the epoch is outside of the time horizon of this table or if there is no such target.
+    pub fn evaluate(
+        &self,
+        target_idx: usize,
+        epoch: Epoch,
+    ) -> Result<CartesianState, InterpolationError> {
+        if epoch < self.start_epoch || epoch > self.end_epoch {
+            return Err(InterpolationError::NoInterpolationData {
+                req: epoch,
+                start: self.start_epoch,
+                end: self.end_epoch,
+            });
+        }
+        if target_idx >= self.targets.len() {
+            return Err(InterpolationError::MissingInterpolationData { epoch });
+        }
+
+        let elapsed_s = (epoch - self.start_epoch).to_seconds();
+        // The last interval is closed on both ends.
+        let idx = ((elapsed_s / self.step_s) as usize).min(self.num_samples - 2);
+        let s = (elapsed_s / self.step_s - idx as f64).clamp(0.0, 1.0);
+
+        let offset = (target_idx * self.num_samples + idx) * 6;
+        let sample = |at: usize| {
+            (
+                Vector3::new(self.data[at], self.data[at + 1], self.data[at + 2]),
+                Vector3::new(self.data[at + 3], self.data[at + 4], self.data[at + 5]),
+            )
+        };
+        let (r0, v0) = sample(offset);
+        let (r1, v1) = sample(offset + 6);
+
+        // Cubic Hermite basis functions and their derivatives with respect to the normalized time
+        let (s2, s3) = (s * s, s * s * s);
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = -2.0 * s3 + 3.0 * s2;
+        let h11 = s3 - s2;
+        let dh00 = 6.0 * s2 - 6.0 * s;
+        let dh10 = 3.0 * s2 - 4.0 * s + 1.0;
+        let dh11 = 3.0 * s2 - 2.0 * s;
+
+        let h = self.step_s;
+        Ok(CartesianState {
+            radius_km: h00 * r0 + h10 * h * v0 + h01 * r1 + h11 * h * v1,
+            velocity_km_s: (dh00 * (r0 - r1)) / h + dh10 * v0 + dh11 * v1,
+            epoch,
+            frame: self.observer,
+        })
+    }
+}
+
+impl fmt::Display for RealTimeTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "real-time table of {} targets as seen from {:x} from {} to {} every {} ({} B)",
+            self.targets.len(),
+            self.observer,
+            self.start_epoch,
+            self.end_epoch,
+            self.step(),
+            self.data_bytes()
+        )
+    }
+}
+
+impl Almanac {
+    /// Precomputes the states of the provided targets as seen from the observer frame over the provided time horizon, with a
+    /// step of at most the provided step, for queries with a bounded latency, cf. [RealTimeTable].
+    ///
+    /// The states are computed with `transform`, so the targets and the observer may be any frames supported by it, including
+    /// body fixed frames. Any error of these computations is returned, so the table covers the full horizon once built.
+    pub fn real_time_table(
+        &self,
+        targets: &[Frame],
+        observer: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<RealTimeTable> {
+        let span_s = (end_epoch - start_epoch).to_seconds();
+        let max_step_s = step.to_seconds();
+        if !(span_s > 0.0 && max_step_s > 0.0) {
+            return Err(AlmanacError::GenericError {
+                err: format!(
+                    "real-time table requires a positive step ({step}) and time horizon ({start_epoch} to {end_epoch})"
+                ),
+            });
+        }
+
+        let num_intervals = (span_s / max_step_s).ceil().max(1.0) as usize;
+        let num_samples = num_intervals + 1;
+        let step_s = span_s / num_intervals as f64;
+
+        let mut data = Vec::with_capacity(targets.len() * num_samples * 6);
+        for target in targets {
+            for i in 0..num_samples {
+                let epoch = if i == num_intervals {
+                    end_epoch
+                } else {
+                    start_epoch + (i as f64 * step_s) * Unit::Second
+                };
+                let state = self.transform(*target, observer, epoch, ab_corr)?;
+                data.extend_from_slice(state.radius_km.as_slice());
+                data.extend_from_slice(state.velocity_km_s.as_slice());
+            }
+        }
+
+        Ok(RealTimeTable {
+            observer,
+            targets: targets.to_vec(),
+            ab_corr,
+            start_epoch,
+            end_epoch,
+            num_samples,
+            step_s,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_realtime {
+    use crate::constants::frames::{EARTH_J2000, SSB_J2000};
+    use crate::math::interpolation::InterpolationError;
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn real_time_table() {
+        let spk = test_spk(&[
+            chebyshev_segment(
+                399,
+                0,
+                0.0,
+                86_400.0,
+                &[[[1.0e8, 1.0e6, 5.0e3], [2.0e7, -3.0e5, 1.0e4], [0.0; 3]]],
+            ),
+            chebyshev_segment(
+                -85,
+                0,
+                0.0,
+                86_400.0,
+                &[[
+                    [1.0e8, 1.0e6, -7.0e3],
+                    [2.0e7, 0.0, 2.0e4],
+                    [1.0e3, 5.0e2, 0.0],
+                ]],
+            ),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        let sc = Frame::from_ephem_j2000(-85);
+
+        let start = Epoch::from_et_seconds(1_000.0);
+        let end = Epoch::from_et_seconds(80_000.0);
+        let table = almanac
+            .real_time_table(
+                &[sc, SSB_J2000],
+                EARTH_J2000,
+                start,
+                end,
+                Unit::Minute * 7,
+                None,
+            )
+            .unwrap();
+        println!("{table}");
+        assert!(table.step() <= Unit::Minute * 7);
+        assert_eq!(table.num_samples(), 190);
+        assert_eq!(table.data_bytes(), 2 * 190 * 6 * 8);
+        assert_eq!(table.target_index(SSB_J2000), Some(1));
+        assert_eq!(table.target_index(EARTH_J2000), None);
+
+        // Quadratic motions are interpolated exactly, including at the samples and at both ends.
+        for (idx, target) in [sc, SSB_J2000].into_iter().enumerate() {
+            for epoch in [
+                start,
+                start + Unit::Second * 0.25,
+                start + table.step() * 3,
+                start + Unit::Hour * 11.123,
+                end - Unit::Second * 1,
+                end,
+            ] {
+                let state = table.evaluate(idx, epoch).unwrap();
+                let expected = almanac.transform(target, EARTH_J2000, epoch, None).unwrap();
+                assert_eq!(state.epoch, epoch);
+                assert_eq!(state.frame, EARTH_J2000);
+                assert!(
+                    (state.radius_km - expected.radius_km).norm() < 1e-6,
+                    "{epoch}: {state} vs {expected}"
+                );
+                assert!((state.velocity_km_s - expected.velocity_km_s).norm() < 1e-9);
+            }
+        }
+
+        assert!(matches!(
+            table.evaluate(0, end + Unit::Second * 1),
+            Err(InterpolationError::NoInterpolationData { .. })
+        ));
+        assert!(table.evaluate(0, start - Unit::Second * 1).is_err());
+        assert!(table.evaluate(2, start).is_err());
+
+        // The horizon must be within the loaded data.
+        assert!(almanac
+            .real_time_table(
+                &[sc],
+                EARTH_J2000,
+                start,
+                start + Unit::Day * 1,
+                Unit::Minute * 1,
+                None
+            )
+            .is_err());
+        assert!(almanac
+            .real_time_table(&[sc], EARTH_J2000, end, start, Unit::Minute * 1, None)
+            .is_err());
+    }
+}
@@ -0,0 +1,160 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Epoch, TimeSeries};
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::errors::{AlmanacPhysicsSnafu, AlmanacResult};
+use crate::math::cartesian::CartesianState;
+use crate::prelude::{Aberration, Frame};
+use crate::NaifId;
+
+/// State of a deputy object relative to a chief object at one epoch, as computed by [Almanac::relative_state_history].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RelativeState {
+    pub epoch: Epoch,
+    /// Distance between both objects
+    pub range_km: f64,
+    /// Rate of change of the distance between both objects, positive when they move apart
+    pub range_rate_km_s: f64,
+    /// Offset of the deputy in the RIC frame of the chief, cf. [CartesianState::ric_difference]
+    pub ric: CartesianState,
+}
+
+impl fmt::Display for RelativeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: range {:.6} km, range-rate {:.6} km/s, RIC [{:.6}, {:.6}, {:.6}] km",
+            self.epoch,
+            self.range_km,
+            self.range_rate_km_s,
+            self.ric.radius_km.x,
+            self.ric.radius_km.y,
+            self.ric.radius_km.z
+        )
+    }
+}
+
+impl Almanac {
+    /// Returns the state of the deputy object relative to the chief object (e.g. two spacecraft of a formation) at each epoch of the
+    /// time series: their range, range-rate, and the RIC offset of the deputy with respect to the chief.
+    ///
+    /// The states of both objects are computed in the provided frame, which should be inertial for the RIC frame to be meaningful
+    /// (e.g. `EARTH_J2000` for Earth orbiters). The range and range-rate do not depend on the frame.
+    pub fn relative_state_history(
+        &self,
+        deputy_id: NaifId,
+        chief_id: NaifId,
+        frame: Frame,
+        time_series: TimeSeries,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<RelativeState>> {
+        time_series
+            .map(|epoch| {
+                let deputy = self.state_of(deputy_id, frame, epoch, ab_corr)?;
+                let chief = self.state_of(chief_id, frame, epoch, ab_corr)?;
+
+                let relative = (deputy - chief).context(AlmanacPhysicsSnafu {
+                    action: "relative state",
+                })?;
+                let range_km = relative.rmag_km();
+                let range_rate_km_s = if range_km > f64::EPSILON {
+                    relative.radius_km.dot(&relative.velocity_km_s) / range_km
+                } else {
+                    // Colocated objects: the rate of change of the distance is the relative speed.
+                    relative.vmag_km_s()
+                };
+
+                let ric = chief.ric_difference(&deputy).context(AlmanacPhysicsSnafu {
+                    action: "relative RIC offset",
+                })?;
+
+                Ok(RelativeState {
+                    epoch,
+                    range_km,
+                    range_rate_km_s,
+                    ric,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod ut_relative {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Orbit};
+    use hifitime::{Epoch, TimeSeries, Unit};
+
+    #[test]
+    fn relative_state_history() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        // Leader-follower formation on a circular orbit, 0.1 degree apart, and a deputy slightly higher.
+        let chief = Orbit::try_keplerian(7000.0, 0.0, 51.6, 10.0, 0.0, 20.0, epoch, frame).unwrap();
+        let follower =
+            Orbit::try_keplerian(7000.0, 0.0, 51.6, 10.0, 0.0, 19.9, epoch, frame).unwrap();
+        let higher =
+            Orbit::try_keplerian(7001.0, 0.0, 51.6, 10.0, 0.0, 20.0, epoch, frame).unwrap();
+
+        let mut almanac = Almanac::default();
+        for (naif_id, orbit) in [(-10, chief), (-11, follower), (-12, higher)] {
+            let mut ephem = Ephemeris::new(format!("{naif_id}"));
+            for minute in (0..=120).step_by(2) {
+                ephem.insert_orbit(
+                    orbit
+                        .propagate_to_epoch(epoch + Unit::Minute * minute)
+                        .unwrap(),
+                );
+            }
+            almanac = almanac.with_spk(
+                ephem
+                    .to_spice_bsp(naif_id, Some(DafDataType::Type13HermiteUnequalStep))
+                    .unwrap(),
+            );
+        }
+
+        let series = TimeSeries::inclusive(epoch, epoch + Unit::Hour * 1, Unit::Minute * 10);
+        let history = almanac
+            .relative_state_history(-11, -10, EARTH_J2000, series.clone(), None)
+            .unwrap();
+        assert_eq!(history.len(), 7);
+        let chord_km = 2.0 * 7000.0 * (0.05_f64).to_radians().sin();
+        for rel in &history {
+            println!("{rel}");
+            // Constant separation, trailing in the in-track direction
+            assert!((rel.range_km - chord_km).abs() < 1e-4, "{rel}");
+            assert!(rel.range_rate_km_s.abs() < 1e-6, "{rel}");
+            assert!(rel.ric.radius_km.y < -0.99 * chord_km);
+            assert!(rel.ric.radius_km.z.abs() < 1e-4);
+        }
+
+        // The higher deputy drifts behind the chief.
+        let history = almanac
+            .relative_state_history(-12, -10, EARTH_J2000, series, None)
+            .unwrap();
+        assert!((history[0].ric.radius_km.x - 1.0).abs() < 1e-4);
+        assert!(history[0].range_rate_km_s.abs() < 1e-6);
+        assert!(history.last().unwrap().range_km > 1.0 + 1e-2);
+        assert!(history.last().unwrap().range_rate_km_s > 0.0);
+        assert!(history.last().unwrap().ric.radius_km.y < 0.0);
+
+        // Unknown objects
+        let series = TimeSeries::inclusive(epoch, epoch + Unit::Hour * 1, Unit::Minute * 10);
+        assert!(almanac
+            .relative_state_history(-99, -10, EARTH_J2000, series, None)
+            .is_err());
+    }
+}
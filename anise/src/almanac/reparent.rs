@@ -0,0 +1,150 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::TimeSeries;
+use snafu::ResultExt;
+
+use super::{Almanac, SegmentPrecedence};
+use crate::constants::orientations::J2000;
+use crate::ephemerides::ephemeris::Ephemeris;
+use crate::errors::{AlmanacError, AlmanacResult, EphemerisSnafu};
+use crate::naif::daf::DafDataType;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+impl Almanac {
+    /// Returns a copy of this Almanac where the ephemeris of the object is defined relative to the new parent over the time series,
+    /// e.g. to define a lander relative to a rover, itself defined relative to Mars, for the geometry of multi-asset surface missions.
+    ///
+    /// The state of the object relative to the new parent is sampled at each epoch of the time series with the currently loaded data,
+    /// and stored as a Type 13 Hermite SPK segment which takes precedence over the previous data of the object, so the whole tree
+    /// (e.g. the state of the lander relative to Mars or to the Earth) remains consistent. The time series should be dense enough for the
+    /// Hermite interpolation of the relative motion, and the previous data of the object is still used outside of it. Re-parenting the
+    /// same object again replaces the previous re-parenting.
+    ///
+    /// # Errors
+    /// + The new parent is the object itself, or the object is an ancestor of the new parent at any epoch, which would create a cycle;
+    /// + The state of the object relative to the new parent cannot be computed at any epoch, or the time series is empty.
+    pub fn with_reparented(
+        mut self,
+        object_id: NaifId,
+        new_parent_id: NaifId,
+        time_series: TimeSeries,
+    ) -> AlmanacResult<Self> {
+        let action = "re-parenting ephemeris";
+        let object = Frame::new(object_id, J2000);
+        let parent = Frame::new(new_parent_id, J2000);
+
+        let mut ephem = Ephemeris::new(format!("{object_id} relative to {new_parent_id}"));
+        for epoch in time_series {
+            let (len, path) = self
+                .ephemeris_path_to_root(parent, epoch)
+                .context(EphemerisSnafu { action })?;
+            if new_parent_id == object_id || path[..len].contains(&Some(object_id)) {
+                return Err(AlmanacError::GenericError {
+                    err: format!(
+                        "cannot re-parent {object_id} to {new_parent_id}: {object_id} is an ancestor of {new_parent_id} at {epoch}"
+                    ),
+                });
+            }
+
+            ephem.insert_orbit(self.transform(object, parent, epoch, None)?);
+        }
+
+        let spk = ephem
+            .to_spice_bsp(object_id, Some(DafDataType::Type13HermiteUnequalStep))
+            .context(EphemerisSnafu { action })?;
+
+        // Replace any previous re-parenting of this object, and search this SPK first regardless of the segment precedence.
+        let alias = format!("re-parenting of {object_id}");
+        self.spk_data.shift_remove(&alias);
+        self = self.with_spk_as(spk, Some(alias.clone()));
+        if self.settings.segment_precedence == SegmentPrecedence::FirstLoaded {
+            self.spk_data.move_index(self.spk_data.len() - 1, 0);
+        }
+        self.kernel_priority.insert(alias, i32::MAX);
+        self.refresh_priority_order();
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod ut_reparent {
+    use crate::constants::frames::{EARTH_J2000, MARS_J2000};
+    use crate::naif::daf::test_utils::{linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Epoch, TimeSeries, Unit};
+
+    #[test]
+    fn reparent_surface_assets() {
+        // Mars relative to the Earth, a rover and a lander both defined relative to Mars.
+        let (rover_id, lander_id) = (-1001, -1002);
+        let spk = test_spk(&[
+            linear_segment(499, 399, [7.8e7, 0.0, 0.0], [0.0, 24.0, 0.0]),
+            linear_segment(rover_id, 499, [3390.0, 0.0, 0.0], [0.0, 1e-4, 0.0]),
+            linear_segment(lander_id, 499, [3390.0, 2.0, 0.5], [0.0, 0.0, 0.0]),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+        let (rover, lander) = (
+            Frame::from_ephem_j2000(rover_id),
+            Frame::from_ephem_j2000(lander_id),
+        );
+
+        let start = Epoch::from_et_seconds(1_000.0);
+        let series = || TimeSeries::inclusive(start, start + Unit::Hour * 2, Unit::Minute * 10);
+        let reparented = almanac
+            .clone()
+            .with_reparented(lander_id, rover_id, series())
+            .unwrap();
+
+        // The lander is now a child of the rover, and the chains through it are unchanged.
+        let epoch = start + Unit::Minute * 35;
+        let (len, path) = reparented.ephemeris_path_to_root(lander, epoch).unwrap();
+        assert_eq!(path[0], Some(rover_id));
+        assert_eq!(path[1], Some(499));
+        assert!(len >= 3);
+        for observer in [rover, MARS_J2000, EARTH_J2000] {
+            let expected = almanac.transform(lander, observer, epoch, None).unwrap();
+            let state = reparented.transform(lander, observer, epoch, None).unwrap();
+            assert!((state.radius_km - expected.radius_km).norm() < 1e-6);
+            assert!((state.velocity_km_s - expected.velocity_km_s).norm() < 1e-9);
+        }
+
+        // Re-parenting again replaces the previous re-parenting.
+        let back = reparented
+            .clone()
+            .with_reparented(lander_id, 499, series())
+            .unwrap();
+        assert_eq!(back.spk_data.len(), reparented.spk_data.len());
+        assert_eq!(
+            back.ephemeris_path_to_root(lander, epoch).unwrap().1[0],
+            Some(499)
+        );
+
+        // Cycles are rejected: the rover cannot be a child of the lander, which is now its child.
+        assert!(reparented
+            .clone()
+            .with_reparented(rover_id, lander_id, series())
+            .is_err());
+        assert!(almanac
+            .clone()
+            .with_reparented(rover_id, rover_id, series())
+            .is_err());
+        // Outside of the data
+        assert!(almanac
+            .with_reparented(
+                lander_id,
+                rover_id,
+                TimeSeries::inclusive(start, start + Unit::Day * 2, Unit::Hour * 1)
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::Epoch;
+use indexmap::IndexMap;
+use snafu::ResultExt;
+
+use super::{Almanac, SegmentPrecedence};
+use crate::ephemerides::SPKSnafu;
+use crate::errors::{AlmanacResult, EphemerisSnafu, OrientationSnafu};
+use crate::naif::daf::{DAFError, DafDataType, NAIFSummaryRecord, DAF};
+use crate::orientations::BPCSnafu;
+use crate::structure::dataset::{DataSet, DataSetT};
+use crate::NaifId;
+
+/// Description of a segment of a loaded SPK.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpkSegmentReport {
+    /// Name of the segment, as stored in the name record
+    pub name: String,
+    pub target_id: NaifId,
+    pub center_id: NaifId,
+    pub frame_id: NaifId,
+    pub data_type: DafDataType,
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+}
+
+/// Description of a segment of a loaded BPC.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BpcSegmentReport {
+    /// Name of the segment, as stored in the name record
+    pub name: String,
+    pub frame_id: NaifId,
+    pub inertial_frame_id: NaifId,
+    pub data_type: DafDataType,
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+}
+
+/// Description of a loaded DAF kernel (SPK or BPC) and of all of its segments, in the order in which they are stored in the file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KernelReport<S> {
+    /// Alias of this kernel in the Almanac, typically its path
+    pub alias: String,
+    /// Priority tag of this kernel, if one was set, used by the `HighestPriorityTag` precedence
+    pub priority: Option<i32>,
+    pub segments: Vec<S>,
+}
+
+/// Description of a loaded ANISE dataset (planetary data, spacecraft data, Euler parameters, locations, instruments).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataSetReport {
+    /// Alias of this dataset in the Almanac, typically its path
+    pub alias: String,
+    /// Number of entries in this dataset
+    pub num_entries: usize,
+}
+
+/// Structured description of everything loaded in an [Almanac], where each list is in loading order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlmanacReport {
+    /// Precedence policy deciding which of the loaded SPKs and BPCs answers a query when several of them cover it
+    pub segment_precedence: SegmentPrecedence,
+    pub spk: Vec<KernelReport<SpkSegmentReport>>,
+    pub bpc: Vec<KernelReport<BpcSegmentReport>>,
+    pub planetary_data: Vec<DataSetReport>,
+    pub spacecraft_data: Vec<DataSetReport>,
+    pub euler_param_data: Vec<DataSetReport>,
+    pub location_data: Vec<DataSetReport>,
+    pub instrument_data: Vec<DataSetReport>,
+    pub articulation_data: Vec<DataSetReport>,
+    pub traverse_data: Vec<DataSetReport>,
+    pub eop_data: Vec<DataSetReport>,
+}
+
+impl Almanac {
+    /// Returns a structured description of everything loaded in this Almanac: the segments of each SPK and BPC,
+    /// and the number of entries of each ANISE dataset. Unlike `describe`, this does not print anything.
+    pub fn report(&self) -> AlmanacResult<AlmanacReport> {
+        let mut report = AlmanacReport {
+            segment_precedence: self.settings.segment_precedence,
+            ..Default::default()
+        };
+
+        for (alias, spk) in &self.spk_data {
+            let mut segments = Vec::new();
+            for (name, summary) in named_summaries(spk)
+                .context(SPKSnafu {
+                    action: "building report",
+                })
+                .context(EphemerisSnafu {
+                    action: "building report",
+                })?
+            {
+                segments.push(SpkSegmentReport {
+                    name,
+                    target_id: summary.target_id,
+                    center_id: summary.center_id,
+                    frame_id: summary.frame_id,
+                    data_type: summary.data_type().context(EphemerisSnafu {
+                        action: "building report",
+                    })?,
+                    start_epoch: summary.start_epoch(),
+                    end_epoch: summary.end_epoch(),
+                });
+            }
+            report.spk.push(KernelReport {
+                alias: alias.clone(),
+                priority: self.kernel_priority(alias),
+                segments,
+            });
+        }
+
+        for (alias, bpc) in &self.bpc_data {
+            let mut segments = Vec::new();
+            for (name, summary) in named_summaries(bpc)
+                .context(BPCSnafu {
+                    action: "building report",
+                })
+                .context(OrientationSnafu {
+                    action: "building report",
+                })?
+            {
+                segments.push(BpcSegmentReport {
+                    name,
+                    frame_id: summary.frame_id,
+                    inertial_frame_id: summary.inertial_frame_id,
+                    data_type: summary.data_type().context(OrientationSnafu {
+                        action: "building report",
+                    })?,
+                    start_epoch: summary.start_epoch(),
+                    end_epoch: summary.end_epoch(),
+                });
+            }
+            report.bpc.push(KernelReport {
+                alias: alias.clone(),
+                priority: self.kernel_priority(alias),
+                segments,
+            });
+        }
+
+        report.planetary_data = dataset_reports(&self.planetary_data);
+        report.spacecraft_data = dataset_reports(&self.spacecraft_data);
+        report.euler_param_data = dataset_reports(&self.euler_param_data);
+        report.location_data = dataset_reports(&self.location_data);
+        report.instrument_data = dataset_reports(&self.instrument_data);
+        report.articulation_data = dataset_reports(&self.articulation_data);
+        report.traverse_data = dataset_reports(&self.traverse_data);
+
+        report.eop_data = self
+            .eop_data
+            .iter()
+            .map(|(alias, data)| DataSetReport {
+                alias: alias.clone(),
+                num_entries: data.len(),
+            })
+            .collect();
+
+        Ok(report)
+    }
+}
+
+/// Returns the description of each of the provided datasets, in loading order.
+fn dataset_reports<T: DataSetT>(datasets: &IndexMap<String, DataSet<T>>) -> Vec<DataSetReport> {
+    datasets
+        .iter()
+        .map(|(alias, data)| DataSetReport {
+            alias: alias.clone(),
+            num_entries: data.len(),
+        })
+        .collect()
+}
+
+/// Returns all of the non-empty summaries of this DAF with their names, following the summary record linked list.
+fn named_summaries<R: NAIFSummaryRecord>(daf: &DAF<R>) -> Result<Vec<(String, R)>, DAFError> {
+    let mut segments = Vec::new();
+    let summary_size = daf.file_record()?.summary_size();
+    // NOTE: Using the explicit loop and index here to we can fetch the name record correctly.
+    let mut idx = None;
+    loop {
+        let name_rcrd = daf.name_record(idx)?;
+        let daf_summary = daf.daf_summary(idx)?;
+        for (sno, summary) in daf
+            .data_summaries(idx)?
+            .iter()
+            .take(daf_summary.num_summaries())
+            .enumerate()
+        {
+            if summary.is_empty() {
+                continue;
+            }
+            segments.push((name_rcrd.nth_name(sno, summary_size).to_string(), *summary));
+        }
+
+        if daf_summary.is_final_record() || Some(daf_summary.next_record()) == idx {
+            break;
+        }
+        idx = Some(daf_summary.next_record());
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod ut_almanac_report {
+    use crate::prelude::Almanac;
+
+    #[test]
+    fn report_nothing_loaded() {
+        let report = Almanac::default().report().unwrap();
+        assert!(report.spk.is_empty());
+        assert!(report.bpc.is_empty());
+        assert!(report.planetary_data.is_empty());
+    }
+
+    #[test]
+    fn report_spk_segments() {
+        use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+        use crate::naif::daf::{DafDataType, DafSegment};
+        use hifitime::Epoch;
+
+        let spk = test_spk(&[DafSegment {
+            name: "MOON".to_string(),
+            ..fixed_segment(301, 399, [0.0; 3])
+        }]);
+        let almanac = Almanac::default().with_spk_as(spk, Some("moon.bsp".to_string()));
+
+        let report = almanac.report().unwrap();
+        assert_eq!(report.spk.len(), 1);
+        assert_eq!(report.spk[0].alias, "moon.bsp");
+        assert_eq!(report.spk[0].segments.len(), 1);
+        let segment = &report.spk[0].segments[0];
+        assert_eq!(segment.name, "MOON");
+        assert_eq!(segment.target_id, 301);
+        assert_eq!(segment.center_id, 399);
+        assert_eq!(segment.data_type, DafDataType::Type2ChebyshevTriplet);
+        assert!(
+            (segment.start_epoch - Epoch::from_et_seconds(0.0)).abs()
+                < hifitime::Unit::Microsecond * 1
+        );
+        assert!(
+            (segment.end_epoch - segment.start_epoch - hifitime::Unit::Day * 1).abs()
+                < hifitime::Unit::Microsecond * 1
+        );
+    }
+
+    #[test]
+    fn report_precedence() {
+        use crate::almanac::SegmentPrecedence;
+        use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+
+        let mut almanac = Almanac::default()
+            .with_spk_as(
+                test_spk(&[fixed_segment(301, 399, [0.0; 3])]),
+                Some("a.bsp".to_string()),
+            )
+            .with_spk_as(
+                test_spk(&[fixed_segment(301, 399, [1.0; 3])]),
+                Some("b.bsp".to_string()),
+            )
+            .with_segment_precedence(SegmentPrecedence::HighestPriorityTag);
+        almanac.set_kernel_priority("a.bsp", 2).unwrap();
+
+        let report = almanac.report().unwrap();
+        assert_eq!(
+            report.segment_precedence,
+            SegmentPrecedence::HighestPriorityTag
+        );
+        assert_eq!(report.spk[0].priority, Some(2));
+        assert_eq!(report.spk[1].priority, None);
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::{Almanac, SegmentPrecedence};
+use crate::astro::Aberration;
+use crate::ephemerides::EphemerisError;
+use crate::math::cartesian::CartesianState;
+use crate::naif::spk::summary::SPKSummaryRecord;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+/// Defines what happens when the ephemeris of an object is queried outside of the time span covered by the loaded SPKs.
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, from_py_object, module = "anise")
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Return an error (SPICE behavior)
+    #[default]
+    Error,
+    /// Hold the state at the nearest bound of the coverage of this object. Gaps in the coverage remain errors.
+    Clamp,
+}
+
+impl fmt::Display for Extrapolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Settings of an Almanac, applied to all of its queries unless a query explicitly overrides them.
+///
+/// :rtype: AlmanacSettings
+#[cfg_attr(
+    feature = "python",
+    pyclass(get_all, set_all, from_py_object, module = "anise")
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AlmanacSettings {
+    /// Aberration correction used by `translate_default`. All of the other queries, e.g. `transform` or the AER computations,
+    /// use the aberration correction they are called with, even if it is `None`.
+    pub aberration: Option<Aberration>,
+    /// Behavior of ephemeris queries outside of the coverage of the loaded SPKs
+    pub extrapolation: Extrapolation,
+    /// Policy selecting which SPK or BPC answers a query when several provide data for the same object and epoch
+    pub segment_precedence: SegmentPrecedence,
+    /// If set, loading an SPK or BPC fails if it provides data for the same ID and time span as an already loaded one
+    pub strict_overlap: bool,
+    /// If set, loading a kernel or a dataset fails if the memory held by the Almanac would then exceed this number of bytes
+    pub memory_budget_bytes: Option<usize>,
+}
+
+impl AlmanacSettings {
+    /// Returns a copy of these settings with the provided default aberration correction.
+    pub fn with_aberration(mut self, aberration: Option<Aberration>) -> Self {
+        self.aberration = aberration;
+        self
+    }
+
+    /// Returns a copy of these settings with the provided extrapolation policy.
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    /// Returns a copy of these settings with the provided segment precedence policy.
+    pub fn with_segment_precedence(mut self, precedence: SegmentPrecedence) -> Self {
+        self.segment_precedence = precedence;
+        self
+    }
+
+    /// Returns a copy of these settings with the provided memory budget, in bytes.
+    pub fn with_memory_budget(mut self, budget_bytes: Option<usize>) -> Self {
+        self.memory_budget_bytes = budget_bytes;
+        self
+    }
+
+    /// Returns a copy of these settings with the provided strict overlap mode.
+    pub fn with_strict_overlap(mut self, strict: bool) -> Self {
+        self.strict_overlap = strict;
+        self
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AlmanacSettings {
+    /// Initializes the default settings: geometric queries, errors outside of the coverage, last loaded kernel precedence, and overlapping kernels allowed.
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} (@{self:p})")
+    }
+}
+
+impl Almanac {
+    /// Returns a copy of this Almanac with the provided settings.
+    pub fn with_settings(mut self, settings: AlmanacSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Returns a copy of this Almanac with the provided extrapolation policy.
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.settings.extrapolation = extrapolation;
+        self
+    }
+
+    /// Returns the SPK summary of the provided ID (as `spk_summary_at_epoch`) and the epoch at which its ephemeris should be
+    /// evaluated given the extrapolation policy, i.e. the provided epoch unless it is outside of the coverage of that ID and the
+    /// policy is to clamp. The coverage is only computed if no summary covers the provided epoch.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn spk_summary_with_extrapolation(
+        &self,
+        id: NaifId,
+        epoch: Epoch,
+    ) -> Result<((&SPKSummaryRecord, usize, Option<usize>, usize), Epoch), EphemerisError> {
+        let err = match self.spk_summary_at_epoch(id, epoch) {
+            Ok(found) => return Ok((found, epoch)),
+            Err(err) => err,
+        };
+
+        match self.settings.extrapolation {
+            Extrapolation::Error => Err(err),
+            Extrapolation::Clamp => {
+                let (start, end) = self.spk_domain(id)?;
+                let clamped = if epoch < start {
+                    start
+                } else if epoch > end {
+                    end
+                } else {
+                    // Gaps in the coverage remain errors.
+                    return Err(err);
+                };
+                Ok((self.spk_summary_at_epoch(id, clamped)?, clamped))
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Almanac {
+    /// Returns the Cartesian state of the target frame as seen from the observer frame at the provided epoch, corrected with the
+    /// default aberration correction of the settings of this Almanac. Use `translate` to override that correction.
+    ///
+    /// :type target_frame: Frame
+    /// :type observer_frame: Frame
+    /// :type epoch: Epoch
+    /// :rtype: Orbit
+    pub fn translate_default(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+    ) -> Result<CartesianState, EphemerisError> {
+        self.translate(
+            target_frame,
+            observer_frame,
+            epoch,
+            self.settings.aberration,
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_settings {
+    use super::{AlmanacSettings, Extrapolation};
+    use crate::almanac::SegmentPrecedence;
+    use crate::astro::Aberration;
+    use crate::constants::frames::{EARTH_J2000, SSB_J2000};
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::naif::SPK;
+    use crate::prelude::Almanac;
+    use hifitime::Epoch;
+
+    /// Builds an SPK with an Earth segment from 0 to 86400 s past J2000 TDB, where X = 1000 + t km with t in [-1, 1].
+    fn earth_spk() -> SPK {
+        test_spk(&[chebyshev_segment(
+            399,
+            0,
+            0.0,
+            86_400.0,
+            &[[[1000.0, 1.0], [0.0; 2], [0.0; 2]]],
+        )])
+    }
+
+    #[test]
+    fn extrapolation() {
+        let almanac = Almanac::default().with_spk(earth_spk());
+        let after = Epoch::from_et_seconds(90000.0);
+
+        assert!(almanac
+            .translate(EARTH_J2000, SSB_J2000, after, None)
+            .is_err());
+
+        let almanac = almanac.with_extrapolation(Extrapolation::Clamp);
+        let state = almanac
+            .translate(EARTH_J2000, SSB_J2000, after, None)
+            .unwrap();
+        // Held at the end of the coverage, but reported at the requested epoch
+        assert!((state.radius_km.x - 1001.0).abs() < 1e-9);
+        assert_eq!(state.epoch, after);
+
+        let state = almanac
+            .translate(EARTH_J2000, SSB_J2000, Epoch::from_et_seconds(-10.0), None)
+            .unwrap();
+        assert!((state.radius_km.x - 999.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn default_aberration() {
+        let settings = AlmanacSettings::default()
+            .with_aberration(Aberration::LT)
+            .with_segment_precedence(SegmentPrecedence::FirstLoaded);
+        assert_eq!(settings.extrapolation, Extrapolation::Error);
+        assert!(!settings.strict_overlap);
+
+        let almanac = Almanac::default()
+            .with_spk(earth_spk())
+            .with_settings(settings);
+        assert_eq!(almanac.settings, settings);
+
+        let epoch = Epoch::from_et_seconds(43200.0);
+        assert_eq!(
+            almanac
+                .translate_default(EARTH_J2000, SSB_J2000, epoch)
+                .unwrap(),
+            almanac
+                .translate(EARTH_J2000, SSB_J2000, epoch, Aberration::LT)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,281 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::errors::{AlmanacError, AlmanacPhysicsSnafu, AlmanacResult, OrientationSnafu};
+use crate::math::Vector3;
+use crate::prelude::Frame;
+use crate::structure::dataset::DataSetError;
+use crate::structure::lookuptable::LutError;
+use crate::structure::spacecraft::SpacecraftData;
+use crate::NaifId;
+
+/// Obscuration of a spacecraft component by the other components along a direction, as computed by [Almanac::self_shadowing].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComponentObscuration {
+    /// Index of this component in the components of the spacecraft data
+    pub index: usize,
+    /// Orientation ID of the frame of this component
+    pub frame_id: NaifId,
+    /// Fraction of the sample points of this component which are obscured, between 0 (fully visible) and 1 (fully obscured)
+    pub obscured_fraction: f64,
+    /// Indexes of the components obscuring at least one sample point of this component
+    pub obscured_by: Vec<usize>,
+}
+
+impl ComponentObscuration {
+    /// Returns true if at least one sample point of this component is obscured.
+    pub fn is_obscured(&self) -> bool {
+        self.obscured_fraction > 0.0
+    }
+}
+
+impl fmt::Display for ComponentObscuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "component #{} (frame {}): {:.1}% obscured by {:?}",
+            self.index,
+            self.frame_id,
+            self.obscured_fraction * 100.0,
+            self.obscured_by
+        )
+    }
+}
+
+impl Almanac {
+    /// Returns the spacecraft data from its ID, searching through all loaded spacecraft datasets in reverse order.
+    pub fn spacecraft_data_from_id(&self, id: NaifId) -> AlmanacResult<SpacecraftData> {
+        for data in self.spacecraft_data.values().rev() {
+            if let Ok(datum) = data.get_by_id(id) {
+                return Ok(datum);
+            }
+        }
+
+        Err(AlmanacError::TLDataSet {
+            action: "spacecraft data from ID",
+            source: DataSetError::DataSetLut {
+                action: "seeking spacecraft data by ID",
+                source: LutError::UnknownId { id },
+            },
+        })
+    }
+
+    /// Computes a coarse self-shadowing of the components of a spacecraft along the provided direction (e.g. to the Sun, or to
+    /// a target seen by a sensor), expressed in the direction frame. This is useful for power and sensor blinding analyses.
+    ///
+    /// The spacecraft data is fetched by the ID of the spacecraft, which is also the orientation ID of its body frame. Each
+    /// component is placed in the body frame at the epoch through the orientation tree, so articulated appendages move with
+    /// their articulation. A sample point of a component (its center, and the corners of a cuboid) is obscured if the ray from
+    /// that point along the direction intersects any other component.
+    ///
+    /// # Notes
+    /// This is a coarse check: primitives are sampled at a handful of points, and components do not obscure themselves.
+    pub fn self_shadowing(
+        &self,
+        sc_id: NaifId,
+        direction: Vector3,
+        direction_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<Vec<ComponentObscuration>> {
+        let data = self.spacecraft_data_from_id(sc_id)?;
+        let body_frame = direction_frame.with_orient(sc_id);
+
+        // Every frame is rotated into the direction frame, which is a common ancestor of the body and component frames.
+        let body_to_direction =
+            self.rotate(body_frame, direction_frame, epoch)
+                .context(OrientationSnafu {
+                    action: "rotating the body frame into the self-shadowing direction frame",
+                })?;
+        let direction_body = body_to_direction.transpose() * direction;
+
+        // Rotation from the body frame to the frame of each component
+        let mut body_to_components = Vec::with_capacity(data.components.len());
+        for component in &data.components {
+            let component_to_direction = self
+                .rotate(
+                    direction_frame.with_orient(component.frame_id),
+                    direction_frame,
+                    epoch,
+                )
+                .context(OrientationSnafu {
+                    action: "rotating a spacecraft component frame into the self-shadowing direction frame",
+                })?;
+            body_to_components.push(
+                (component_to_direction.transpose() * body_to_direction).context(
+                    AlmanacPhysicsSnafu {
+                        action: "rotating the body frame into a spacecraft component frame",
+                    },
+                )?,
+            );
+        }
+
+        let mut obscurations = Vec::with_capacity(data.components.len());
+        for (index, component) in data.components.iter().enumerate() {
+            let to_body = body_to_components[index].transpose();
+            let points_m = component.primitive.sample_points_m();
+
+            let mut obscured_count = 0;
+            let mut obscured_by = Vec::new();
+            for point_m in &points_m {
+                let point_body_m = component.origin_m + to_body * *point_m;
+                let mut obscured = false;
+                for (other_index, other) in data.components.iter().enumerate() {
+                    if other_index == index {
+                        continue;
+                    }
+                    let to_other = body_to_components[other_index];
+                    if other.primitive.ray_intersects(
+                        to_other * (point_body_m - other.origin_m),
+                        to_other * direction_body,
+                    ) {
+                        obscured = true;
+                        if !obscured_by.contains(&other_index) {
+                            obscured_by.push(other_index);
+                        }
+                    }
+                }
+                if obscured {
+                    obscured_count += 1;
+                }
+            }
+
+            obscured_by.sort_unstable();
+
+            obscurations.push(ComponentObscuration {
+                index,
+                frame_id: component.frame_id,
+                obscured_fraction: obscured_count as f64 / points_m.len() as f64,
+                obscured_by,
+            });
+        }
+
+        Ok(obscurations)
+    }
+}
+
+#[cfg(test)]
+mod ut_shadowing {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::rotation::Quaternion;
+    use crate::math::Vector3;
+    use crate::prelude::Almanac;
+    use crate::structure::articulation::Articulation;
+    use crate::structure::spacecraft::{Component, Primitive, SpacecraftData};
+    use crate::structure::{ArticulationDataSet, EulerParameterDataSet, SpacecraftDataSet};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn components_shadow_articulated_array() {
+        let start = Epoch::from_et_seconds(0.0);
+        // Body aligned with J2000, and a solar array on +Y rotating about the body Y axis at 1 deg/s.
+        let mut body = EulerParameterDataSet::default();
+        body.push(
+            Quaternion::identity(-85000, 1),
+            Some(-85000),
+            Some("SC_BODY"),
+        )
+        .unwrap();
+        let sada = Articulation::new(
+            -85000,
+            Vector3::y(),
+            &[(start, 0.0), (start + Unit::Second * 180, 180.0)],
+        )
+        .unwrap();
+        let mut articulations = ArticulationDataSet::default();
+        articulations
+            .push(sada, Some(-85010), Some("SC_SADA"))
+            .unwrap();
+
+        // Bus of 2 m, the array as a thin panel in the XY plane of its frame, a mast on -X of the array, and a narrow sunshade
+        // above the array.
+        let sc = SpacecraftData {
+            components: vec![
+                Component {
+                    frame_id: -85000,
+                    origin_m: Vector3::zeros(),
+                    primitive: Primitive::Cuboid {
+                        center_m: Vector3::zeros(),
+                        half_extents_m: Vector3::new(1.0, 1.0, 1.0),
+                    },
+                },
+                Component {
+                    frame_id: -85010,
+                    origin_m: Vector3::new(0.0, 1.0, 0.0),
+                    primitive: Primitive::Cuboid {
+                        center_m: Vector3::new(0.0, 2.0, 0.0),
+                        half_extents_m: Vector3::new(0.5, 1.0, 0.01),
+                    },
+                },
+                Component {
+                    frame_id: -85000,
+                    origin_m: Vector3::zeros(),
+                    primitive: Primitive::Sphere {
+                        center_m: Vector3::new(-10.0, 3.0, 0.0),
+                        radius_m: 2.0,
+                    },
+                },
+                Component {
+                    frame_id: -85000,
+                    origin_m: Vector3::zeros(),
+                    primitive: Primitive::Cuboid {
+                        center_m: Vector3::new(0.0, 3.0, 3.0),
+                        half_extents_m: Vector3::new(0.1, 1.0, 0.01),
+                    },
+                },
+            ],
+            ..Default::default()
+        };
+        let mut spacecraft = SpacecraftDataSet::default();
+        spacecraft.push(sc, Some(-85000), Some("SC")).unwrap();
+
+        let almanac = Almanac::default()
+            .with_euler_parameters(body)
+            .with_articulation_data(articulations)
+            .with_spacecraft_data(spacecraft);
+
+        // Sun along -X: the mast shadows the whole array.
+        let obscurations = almanac
+            .self_shadowing(-85000, -Vector3::x(), EARTH_J2000, start)
+            .unwrap();
+        assert_eq!(obscurations.len(), 4);
+        assert_eq!(obscurations[1].obscured_fraction, 1.0);
+        assert_eq!(obscurations[1].obscured_by, vec![2]);
+        assert!(!obscurations[2].is_obscured());
+        assert!(!obscurations[3].is_obscured());
+
+        // Sun along +Z: the array faces the Sun and only its center is behind the sunshade.
+        let obscurations = almanac
+            .self_shadowing(-85000, Vector3::z(), EARTH_J2000, start)
+            .unwrap();
+        assert_eq!(obscurations[1].obscured_fraction, 1.0 / 9.0);
+        assert_eq!(obscurations[1].obscured_by, vec![3]);
+        assert!(!obscurations[0].is_obscured(), "{}", obscurations[0]);
+
+        // After 90 seconds, the array is rotated by 90 degrees about Y, edge on to the Sun, and entirely behind the sunshade.
+        let obscurations = almanac
+            .self_shadowing(-85000, Vector3::z(), EARTH_J2000, start + Unit::Second * 90)
+            .unwrap();
+        assert_eq!(
+            obscurations[1].obscured_fraction, 1.0,
+            "{}",
+            obscurations[1]
+        );
+
+        // Unknown spacecraft
+        assert!(almanac
+            .self_shadowing(-99000, Vector3::z(), EARTH_J2000, start)
+            .is_err());
+    }
+}
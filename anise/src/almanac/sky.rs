@@ -0,0 +1,365 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt::Write;
+use hifitime::{Duration, Epoch, TimeScale, TimeSeries};
+
+use super::Almanac;
+use crate::constants::celestial_objects::celestial_name_from_id;
+use crate::errors::{AlmanacError, AlmanacResult};
+use crate::prelude::{Aberration, Frame};
+use crate::NaifId;
+
+/// Apparent position of a body on the celestial sphere of the observer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkyPoint {
+    /// NAIF ID of the body
+    pub id: NaifId,
+    pub name: String,
+    /// Right ascension in the orientation of the observer frame, in degrees between 0 and 360
+    pub ra_deg: f64,
+    /// Declination in the orientation of the observer frame, in degrees
+    pub dec_deg: f64,
+    pub range_km: f64,
+    /// Apparent angular radius of the body, or zero if its shape is not in the loaded planetary data
+    pub angular_radius_deg: f64,
+}
+
+/// Circle on the celestial sphere of the observer around a body (e.g. the Sun or the Moon), which instruments must not point into.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AvoidanceCircle {
+    /// NAIF ID of the body
+    pub id: NaifId,
+    pub name: String,
+    /// Right ascension of the center of the circle, in degrees between 0 and 360
+    pub ra_deg: f64,
+    /// Declination of the center of the circle, in degrees
+    pub dec_deg: f64,
+    /// Angular radius of the circle: the exclusion angle plus the apparent angular radius of the body, in degrees
+    pub radius_deg: f64,
+}
+
+/// Celestial sphere annotations at a single epoch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkySnapshot {
+    pub epoch: Epoch,
+    pub bodies: Vec<SkyPoint>,
+    pub avoidance: Vec<AvoidanceCircle>,
+}
+
+/// Time history of the celestial sphere annotations of an observer, as built by [Almanac::sky_annotations].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkyAnnotations {
+    pub observer_frame: Frame,
+    pub snapshots: Vec<SkySnapshot>,
+}
+
+impl SkyAnnotations {
+    /// Exports these annotations to JSON for visualization front-ends, with the following schema:
+    ///
+    /// ```text
+    /// {"observer": "Earth J2000",
+    ///  "snapshots": [{"epoch": "2025-01-01T00:00:00.000000Z", "tdb_s": 788961669.184,
+    ///                 "bodies": [{"id": 301, "name": "Moon", "ra_deg": 12.3, "dec_deg": 4.5, "range_km": 384400.0, "angular_radius_deg": 0.26}],
+    ///                 "avoidance": [{"id": 10, "name": "Sun", "ra_deg": 281.2, "dec_deg": -23.0, "radius_deg": 30.27}]}]}
+    /// ```
+    ///
+    /// Epochs are ISO 8601 in UTC, along with the TDB seconds past J2000 for unambiguous interpolation. Non finite numbers are exported as `null`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"observer\":");
+        push_json_str(&mut json, &format!("{:x}", self.observer_frame));
+        json.push_str(",\"snapshots\":[");
+        for (i, snapshot) in self.snapshots.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"epoch\":");
+            let utc = snapshot.epoch.to_time_scale(TimeScale::UTC).to_isoformat();
+            push_json_str(&mut json, &format!("{utc}Z"));
+            json.push_str(",\"tdb_s\":");
+            push_json_f64(&mut json, snapshot.epoch.to_tdb_seconds());
+
+            json.push_str(",\"bodies\":[");
+            for (j, body) in snapshot.bodies.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                let _ = write!(json, "{{\"id\":{},\"name\":", body.id);
+                push_json_str(&mut json, &body.name);
+                for (key, value) in [
+                    ("ra_deg", body.ra_deg),
+                    ("dec_deg", body.dec_deg),
+                    ("range_km", body.range_km),
+                    ("angular_radius_deg", body.angular_radius_deg),
+                ] {
+                    let _ = write!(json, ",\"{key}\":");
+                    push_json_f64(&mut json, value);
+                }
+                json.push('}');
+            }
+
+            json.push_str("],\"avoidance\":[");
+            for (j, circle) in snapshot.avoidance.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                let _ = write!(json, "{{\"id\":{},\"name\":", circle.id);
+                push_json_str(&mut json, &circle.name);
+                for (key, value) in [
+                    ("ra_deg", circle.ra_deg),
+                    ("dec_deg", circle.dec_deg),
+                    ("radius_deg", circle.radius_deg),
+                ] {
+                    let _ = write!(json, ",\"{key}\":");
+                    push_json_f64(&mut json, value);
+                }
+                json.push('}');
+            }
+            json.push_str("]}");
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+/// Appends the provided string as a JSON string literal.
+fn push_json_str(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Appends the provided number as a JSON number, or `null` if it is not finite.
+fn push_json_f64(json: &mut String, value: f64) {
+    if value.is_finite() {
+        // The debug representation always includes the decimal point and round trips.
+        let _ = write!(json, "{value:?}");
+    } else {
+        json.push_str("null");
+    }
+}
+
+impl Almanac {
+    /// Samples the apparent right ascension and declination of the provided bodies, and the avoidance circles around the provided
+    /// bodies with their exclusion angles in degrees (e.g. 30 degrees around the Sun), as seen from the observer frame, from the start
+    /// to the end epoch, both included, at the provided step.
+    ///
+    /// The right ascension and declination are computed in the orientation of the observer frame, which should be inertial (e.g. J2000).
+    /// The apparent angular radius of each body uses its semi major equatorial radius from the loaded planetary data, if available.
+    /// Use [SkyAnnotations::to_json] to export the result to planetarium or user interface front-ends.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sky_annotations(
+        &self,
+        observer_frame: Frame,
+        bodies: &[Frame],
+        avoidance: &[(Frame, f64)],
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        step: Duration,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<SkyAnnotations> {
+        if let Some((frame, angle_deg)) = avoidance
+            .iter()
+            .find(|(_, angle_deg)| !(0.0..180.0).contains(angle_deg))
+        {
+            return Err(AlmanacError::GenericError {
+                err: format!(
+                    "exclusion angle of {frame:x} must be between 0 and 180 deg, got {angle_deg}"
+                ),
+            });
+        }
+
+        // The radii do not depend on the epoch.
+        let radius_km = |frame: Frame| {
+            self.frame_info(frame)
+                .ok()
+                .and_then(|frame| frame.shape)
+                .map_or(0.0, |shape| shape.semi_major_equatorial_radius_km)
+        };
+        let name = |id: NaifId| {
+            celestial_name_from_id(id).map_or_else(|| format!("{id}"), |name| name.to_string())
+        };
+        let body_radii = bodies
+            .iter()
+            .map(|frame| radius_km(*frame))
+            .collect::<Vec<_>>();
+        let avoidance_radii = avoidance
+            .iter()
+            .map(|(frame, _)| radius_km(*frame))
+            .collect::<Vec<_>>();
+
+        // Returns the right ascension, the declination, the range, and the apparent angular radius of the body.
+        let observe = |frame: Frame, radius_km: f64, epoch: Epoch| -> AlmanacResult<_> {
+            let state = self.transform(frame, observer_frame, epoch, ab_corr)?;
+            let range_km = state.rmag_km();
+            let angular_radius_deg = if range_km > radius_km {
+                (radius_km / range_km).asin().to_degrees()
+            } else {
+                // The observer is within the body, which then fills the sky.
+                180.0
+            };
+            Ok((
+                state.right_ascension_deg(),
+                state.declination_deg(),
+                range_km,
+                angular_radius_deg,
+            ))
+        };
+
+        let snapshots = TimeSeries::inclusive(start_epoch, end_epoch, step)
+            .map(|epoch| {
+                let bodies = bodies
+                    .iter()
+                    .zip(&body_radii)
+                    .map(|(frame, radius_km)| {
+                        let (ra_deg, dec_deg, range_km, angular_radius_deg) =
+                            observe(*frame, *radius_km, epoch)?;
+                        Ok(SkyPoint {
+                            id: frame.ephemeris_id,
+                            name: name(frame.ephemeris_id),
+                            ra_deg,
+                            dec_deg,
+                            range_km,
+                            angular_radius_deg,
+                        })
+                    })
+                    .collect::<AlmanacResult<Vec<_>>>()?;
+
+                let avoidance = avoidance
+                    .iter()
+                    .zip(&avoidance_radii)
+                    .map(|((frame, angle_deg), radius_km)| {
+                        let (ra_deg, dec_deg, _, angular_radius_deg) =
+                            observe(*frame, *radius_km, epoch)?;
+                        Ok(AvoidanceCircle {
+                            id: frame.ephemeris_id,
+                            name: name(frame.ephemeris_id),
+                            ra_deg,
+                            dec_deg,
+                            radius_deg: (angle_deg + angular_radius_deg).min(180.0),
+                        })
+                    })
+                    .collect::<AlmanacResult<Vec<_>>>()?;
+
+                Ok(SkySnapshot {
+                    epoch,
+                    bodies,
+                    avoidance,
+                })
+            })
+            .collect::<AlmanacResult<Vec<_>>>()?;
+
+        Ok(SkyAnnotations {
+            observer_frame,
+            snapshots,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_sky {
+    use super::push_json_str;
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000, SUN_J2000};
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn sky_annotations() {
+        let au_km = 149_597_870.7;
+        let spk = test_spk(&[
+            fixed_segment(399, 0, [au_km, 0.0, 0.0]),
+            fixed_segment(10, 0, [0.0, 0.0, 0.0]),
+            fixed_segment(301, 399, [0.0, 384_400.0, 0.0]),
+            fixed_segment(-85, 399, [0.0, 0.0, 7000.0]),
+        ]);
+        let almanac = Almanac::default()
+            .load("../data/pck08.pca")
+            .unwrap()
+            .with_spk(spk);
+
+        let start = Epoch::from_et_seconds(1_000.0);
+        let sky = almanac
+            .sky_annotations(
+                EARTH_J2000,
+                &[MOON_J2000, Frame::from_ephem_j2000(-85)],
+                &[(SUN_J2000, 30.0), (MOON_J2000, 10.0)],
+                start,
+                start + Unit::Hour * 2,
+                Unit::Hour * 1,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(sky.snapshots.len(), 3);
+        for snapshot in &sky.snapshots {
+            let moon = &snapshot.bodies[0];
+            assert_eq!((moon.id, moon.name.as_str()), (301, "Moon"));
+            assert!((moon.ra_deg - 90.0).abs() < 1e-9);
+            assert!(moon.dec_deg.abs() < 1e-9);
+            assert!((moon.range_km - 384_400.0).abs() < 1e-6);
+            // About a quarter of a degree
+            assert!((moon.angular_radius_deg - 0.259).abs() < 1e-3);
+
+            // Unknown bodies are named from their ID, and have no shape.
+            let sc = &snapshot.bodies[1];
+            assert_eq!(sc.name, "-85");
+            assert!((sc.dec_deg - 90.0).abs() < 1e-9);
+            assert_eq!(sc.angular_radius_deg, 0.0);
+
+            let sun = &snapshot.avoidance[0];
+            assert_eq!(sun.name, "Sun");
+            assert!((sun.ra_deg - 180.0).abs() < 1e-9);
+            assert!((sun.radius_deg - 30.267).abs() < 1e-3);
+            let moon_circle = &snapshot.avoidance[1];
+            assert!((moon_circle.radius_deg - 10.0 - moon.angular_radius_deg).abs() < 1e-12);
+        }
+
+        let json = sky.to_json();
+        println!("{json}");
+        assert!(json.starts_with(
+            "{\"observer\":\"Earth J2000\",\"snapshots\":[{\"epoch\":\"2000-01-01T12:15:"
+        ));
+        assert!(json.contains("\"bodies\":[{\"id\":301,\"name\":\"Moon\",\"ra_deg\":90.0,"));
+        assert!(json.contains("\"avoidance\":[{\"id\":10,\"name\":\"Sun\",\"ra_deg\":180.0,"));
+        assert_eq!(json.matches("\"tdb_s\"").count(), 3);
+        assert!(json.ends_with("]}]}"));
+
+        let mut escaped = String::new();
+        push_json_str(&mut escaped, "a \"b\"\\\n");
+        assert_eq!(escaped, r#""a \"b\"\\\n""#);
+
+        // Invalid exclusion angles
+        assert!(almanac
+            .sky_annotations(
+                EARTH_J2000,
+                &[],
+                &[(SUN_J2000, -1.0)],
+                start,
+                start,
+                Unit::Hour * 1,
+                None,
+            )
+            .is_err());
+    }
+}
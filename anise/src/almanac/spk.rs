@@ -48,24 +48,43 @@ impl Almanac {
         if self.spk_data.insert(alias, spk).is_some() {
             warn!("{msg}");
         }
+        self.refresh_priority_order();
         self
     }
 
     /// Unloads the SPK with the provided alias.
     /// **WARNING:** This causes the order of the loaded files to be perturbed, which may be an issue if several SPKs with the same IDs are loaded.
     pub fn spk_unload(&mut self, alias: &str) -> Result<(), EphemerisError> {
+        self.kernel_priority.remove(alias);
         if self.spk_data.swap_remove(alias).is_none() {
             Err(EphemerisError::AliasNotFound {
                 alias: alias.to_string(),
                 action: "unload ephemeris",
             })
         } else {
+            self.refresh_priority_order();
             Ok(())
         }
     }
 }
 
 impl Almanac {
+    /// Unloads the SPK at the provided index in the loading order, i.e. zero is the first loaded SPK.
+    /// The loading order of the remaining SPKs is preserved.
+    pub fn spk_unload_index(&mut self, index: usize) -> Result<(), EphemerisError> {
+        match self.spk_data.shift_remove_index(index) {
+            Some((alias, _)) => {
+                self.kernel_priority.remove(&alias);
+                self.refresh_priority_order();
+                Ok(())
+            }
+            None => Err(EphemerisError::AliasNotFound {
+                alias: format!("SPK #{index}"),
+                action: "unload ephemeris",
+            }),
+        }
+    }
+
     pub fn num_loaded_spk(&self) -> usize {
         self.spk_data.len()
     }
@@ -76,16 +95,11 @@ impl Almanac {
         name: &str,
         epoch: Epoch,
     ) -> Result<(&SPKSummaryRecord, usize, Option<usize>, usize), EphemerisError> {
-        for (spk_no, spk) in self.spk_data.values().rev().enumerate() {
+        for spk_no in self.spk_precedence() {
+            let spk = &self.spk_data[spk_no];
             if let Ok((summary, daf_idx, idx_in_spk)) = spk.summary_from_name_at_epoch(name, epoch)
             {
-                // NOTE: We're iterating backward, so the correct SPK number is "total loaded" minus "current iteration".
-                return Ok((
-                    summary,
-                    self.num_loaded_spk() - spk_no - 1,
-                    daf_idx,
-                    idx_in_spk,
-                ));
+                return Ok((summary, spk_no, daf_idx, idx_in_spk));
             }
         }
 
@@ -107,15 +121,10 @@ impl Almanac {
         id: i32,
         epoch: Epoch,
     ) -> Result<(&SPKSummaryRecord, usize, Option<usize>, usize), EphemerisError> {
-        for (spk_no, spk) in self.spk_data.values().rev().enumerate() {
+        for spk_no in self.spk_precedence() {
+            let spk = &self.spk_data[spk_no];
             if let Ok((summary, daf_idx, idx_in_spk)) = spk.summary_from_id_at_epoch(id, epoch) {
-                // NOTE: We're iterating backward, so the correct SPK number is "total loaded" minus "current iteration".
-                return Ok((
-                    summary,
-                    self.num_loaded_spk() - spk_no - 1,
-                    daf_idx,
-                    idx_in_spk,
-                ));
+                return Ok((summary, spk_no, daf_idx, idx_in_spk));
             }
         }
 
@@ -140,15 +149,10 @@ impl Almanac {
         &self,
         name: &str,
     ) -> Result<(&SPKSummaryRecord, usize, Option<usize>, usize), EphemerisError> {
-        for (spk_no, spk) in self.spk_data.values().rev().enumerate() {
+        for spk_no in self.spk_precedence() {
+            let spk = &self.spk_data[spk_no];
             if let Ok((summary, daf_idx, idx_in_spk)) = spk.summary_from_name(name) {
-                // NOTE: We're iterating backward, so the correct SPK number is "total loaded" minus "current iteration".
-                return Ok((
-                    summary,
-                    self.num_loaded_spk() - spk_no - 1,
-                    daf_idx,
-                    idx_in_spk,
-                ));
+                return Ok((summary, spk_no, daf_idx, idx_in_spk));
             }
         }
 
@@ -169,15 +173,10 @@ impl Almanac {
         &self,
         id: i32,
     ) -> Result<(&SPKSummaryRecord, usize, Option<usize>, usize), EphemerisError> {
-        for (spk_no, spk) in self.spk_data.values().rev().enumerate() {
+        for spk_no in self.spk_precedence() {
+            let spk = &self.spk_data[spk_no];
             if let Ok((summary, daf_idx, idx_in_spk)) = spk.summary_from_id(id) {
-                // NOTE: We're iterating backward, so the correct SPK number is "total loaded" minus "current iteration".
-                return Ok((
-                    summary,
-                    self.num_loaded_spk() - spk_no - 1,
-                    daf_idx,
-                    idx_in_spk,
-                ));
+                return Ok((summary, spk_no, daf_idx, idx_in_spk));
             }
         }
 
@@ -201,7 +200,8 @@ impl Almanac {
     /// :rtype: typing.List
     pub fn spk_summaries(&self, id: NaifId) -> Result<Vec<SPKSummaryRecord>, EphemerisError> {
         let mut summaries = vec![];
-        for spk in self.spk_data.values().rev() {
+        for spk_no in self.spk_precedence() {
+            let spk = &self.spk_data[spk_no];
             for these_summaries in spk.iter_summary_blocks().flatten() {
                 for summary in these_summaries {
                     if summary.id() == id {
@@ -246,6 +246,54 @@ impl Almanac {
         Ok((start, end))
     }
 
+    /// Returns the time windows during which the provided ID has ephemeris data in any of the loaded SPKs, i.e. the union
+    /// of the windows of all of its segments, sorted chronologically. This is equivalent to SPICE's `spkcov` across all loaded files.
+    ///
+    /// Unlike `spk_domain`, this reports any gaps between segments.
+    ///
+    /// :type id: int
+    /// :rtype: typing.List
+    pub fn spk_coverage(&self, id: NaifId) -> Result<Vec<(Epoch, Epoch)>, EphemerisError> {
+        let summaries = self.spk_summaries(id)?;
+
+        Ok(union_windows(
+            summaries
+                .iter()
+                .map(|summary| (summary.start_epoch(), summary.end_epoch()))
+                .collect(),
+        ))
+    }
+
+    /// Returns the time windows within `[start, end]` during which none of the loaded SPKs provide ephemeris data for the provided ID,
+    /// i.e. the gaps between its segments, between the files, and before or after its loaded data. An empty list means that the whole
+    /// analysis window is covered.
+    ///
+    /// # Note
+    /// This only checks the segments of the provided ID: querying its state may also require the data of its centers up to the common ephemeris root.
+    ///
+    /// :type id: int
+    /// :type start: Epoch
+    /// :type end: Epoch
+    /// :rtype: typing.List
+    pub fn spk_gaps(
+        &self,
+        id: NaifId,
+        start: Epoch,
+        end: Epoch,
+    ) -> Result<Vec<(Epoch, Epoch)>, EphemerisError> {
+        let coverage = match self.spk_coverage(id) {
+            Ok(coverage) => coverage,
+            // An ID without any segment is one large gap, but an empty Almanac is an error.
+            Err(EphemerisError::SPK {
+                source: DAFError::SummaryIdError { .. },
+                ..
+            }) if self.num_loaded_spk() > 0 => vec![],
+            Err(e) => return Err(e),
+        };
+
+        Ok(gaps_in_windows(&coverage, start, end))
+    }
+
     /// Returns a map of each loaded SPK ID to its domain validity.
     ///
     /// # Warning
@@ -279,6 +327,99 @@ impl Almanac {
 
         Ok(domains)
     }
+
+    /// Returns the decoded text of the comment area of the SPK loaded with the provided alias, if that SPK has any comments.
+    ///
+    /// Mission kernels typically document their provenance and usage constraints in the comment area.
+    ///
+    /// :type alias: str
+    /// :rtype: typing.Optional[str]
+    pub fn spk_comments(&self, alias: &str) -> Result<Option<String>, EphemerisError> {
+        let spk = self
+            .spk_data
+            .get(alias)
+            .ok_or_else(|| EphemerisError::AliasNotFound {
+                alias: alias.to_string(),
+                action: "read SPK comments",
+            })?;
+
+        spk.comments().map_err(|source| EphemerisError::SPK {
+            action: "reading SPK comments",
+            source,
+        })
+    }
+}
+
+/// Merges the provided time windows into a chronologically sorted list of disjoint windows.
+/// Windows that overlap or touch are merged together.
+pub(crate) fn union_windows(mut windows: Vec<(Epoch, Epoch)>) -> Vec<(Epoch, Epoch)> {
+    windows.sort_by_key(|(start, _)| *start);
+
+    let mut merged: Vec<(Epoch, Epoch)> = Vec::with_capacity(windows.len());
+    for (start, end) in windows {
+        match merged.last_mut() {
+            Some((_, cur_end)) if start <= *cur_end => {
+                if end > *cur_end {
+                    *cur_end = end;
+                }
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+/// Returns the intersection of two chronologically sorted lists of disjoint windows.
+pub(crate) fn intersect_windows(
+    windows: &[(Epoch, Epoch)],
+    others: &[(Epoch, Epoch)],
+) -> Vec<(Epoch, Epoch)> {
+    let mut intersection = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < windows.len() && j < others.len() {
+        let start = windows[i].0.max(others[j].0);
+        let end = windows[i].1.min(others[j].1);
+        if start <= end {
+            intersection.push((start, end));
+        }
+        // Move past the window which ends first
+        if windows[i].1 < others[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    intersection
+}
+
+/// Returns the windows within `[start, end]` which are not covered by the provided sorted and disjoint windows.
+pub(crate) fn gaps_in_windows(
+    windows: &[(Epoch, Epoch)],
+    start: Epoch,
+    end: Epoch,
+) -> Vec<(Epoch, Epoch)> {
+    let mut gaps = Vec::new();
+    let mut cursor = start;
+    for &(w_start, w_end) in windows {
+        if cursor >= end {
+            break;
+        }
+        if w_end <= cursor {
+            continue;
+        }
+        if w_start > cursor {
+            gaps.push((cursor, w_start.min(end)));
+        }
+        cursor = cursor.max(w_end);
+    }
+
+    if cursor < end {
+        gaps.push((cursor, end));
+    }
+
+    gaps
 }
 
 #[cfg(test)]
@@ -311,6 +452,76 @@ mod ut_almanac_spk {
                 .is_err(),
             "empty Almanac should report an error"
         );
+        assert!(
+            almanac.spk_comments("invalid alias").is_err(),
+            "empty Almanac should report an error"
+        );
+        assert!(
+            almanac.spk_coverage(301).is_err(),
+            "empty Almanac should report an error"
+        );
+        assert!(
+            almanac.spk_gaps(301, e, e).is_err(),
+            "empty Almanac should report an error"
+        );
+    }
+
+    #[test]
+    fn union_of_windows() {
+        use super::union_windows;
+        use hifitime::Unit;
+
+        let e0 = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let windows = vec![
+            (e0 + Unit::Day * 10, e0 + Unit::Day * 12),
+            (e0, e0 + Unit::Day * 2),
+            (e0 + Unit::Day * 1, e0 + Unit::Day * 3),
+            (e0 + Unit::Day * 3, e0 + Unit::Day * 4),
+            (e0 + Unit::Day * 10, e0 + Unit::Day * 11),
+        ];
+
+        assert_eq!(
+            union_windows(windows),
+            vec![
+                (e0, e0 + Unit::Day * 4),
+                (e0 + Unit::Day * 10, e0 + Unit::Day * 12)
+            ]
+        );
+        assert!(union_windows(vec![]).is_empty());
+    }
+
+    #[test]
+    fn gaps_of_windows() {
+        use super::gaps_in_windows;
+        use hifitime::Unit;
+
+        let e0 = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let windows = [
+            (e0 + Unit::Day * 1, e0 + Unit::Day * 4),
+            (e0 + Unit::Day * 10, e0 + Unit::Day * 12),
+        ];
+
+        // Window covering everything
+        assert_eq!(
+            gaps_in_windows(&windows, e0, e0 + Unit::Day * 20),
+            vec![
+                (e0, e0 + Unit::Day * 1),
+                (e0 + Unit::Day * 4, e0 + Unit::Day * 10),
+                (e0 + Unit::Day * 12, e0 + Unit::Day * 20)
+            ]
+        );
+        // Window fully covered
+        assert!(gaps_in_windows(&windows, e0 + Unit::Day * 2, e0 + Unit::Day * 3).is_empty());
+        // Window ending inside the gap
+        assert_eq!(
+            gaps_in_windows(&windows, e0 + Unit::Day * 2, e0 + Unit::Day * 5),
+            vec![(e0 + Unit::Day * 4, e0 + Unit::Day * 5)]
+        );
+        // No coverage at all
+        assert_eq!(
+            gaps_in_windows(&[], e0, e0 + Unit::Day * 5),
+            vec![(e0, e0 + Unit::Day * 5)]
+        );
     }
 
     #[test]
@@ -335,4 +546,30 @@ mod ut_almanac_spk {
             "empty Almanac should report an error"
         );
     }
+
+    #[test]
+    fn unload_preserves_order() {
+        use crate::naif::SPK;
+
+        let mut almanac = Almanac::default();
+        for alias in ["a.bsp", "b.bsp", "c.bsp", "d.bsp"] {
+            almanac = almanac.with_spk_as(SPK::default(), Some(alias.to_string()));
+        }
+
+        almanac.unload("b.bsp").unwrap();
+        assert_eq!(
+            almanac.list_kernels(Some(true), None, None, None, None, None),
+            vec!["a.bsp", "c.bsp", "d.bsp"]
+        );
+
+        almanac.spk_unload_index(0).unwrap();
+        assert_eq!(
+            almanac.list_kernels(Some(true), None, None, None, None, None),
+            vec!["c.bsp", "d.bsp"]
+        );
+
+        assert!(almanac.unload("b.bsp").is_err());
+        assert!(almanac.spk_unload_index(2).is_err());
+        assert_eq!(almanac.num_loaded_spk(), 2);
+    }
 }
@@ -0,0 +1,340 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::Almanac;
+use crate::errors::{AlmanacError, AlmanacPhysicsSnafu, AlmanacResult, OrientationSnafu};
+use crate::math::cartesian::CartesianState;
+use crate::math::rotation::DCM;
+use crate::math::{Matrix3, Vector3};
+use crate::prelude::{Frame, Orbit};
+use crate::NaifId;
+
+/// Axes of a topocentric frame.
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, from_py_object, module = "anise")
+)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TopocentricAxes {
+    /// East, North, Up
+    ENU,
+    /// South, East, Zenith, as used by the azimuth, elevation, and range computations
+    SEZ,
+    /// North, East, Down
+    NED,
+}
+
+impl fmt::Display for TopocentricAxes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A topocentric frame anchored at a geodetic site of a body, as registered in an Almanac.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TopocentricFrame {
+    /// Body fixed frame on which the site rests, including the shape of the body
+    pub body_frame: Frame,
+    pub axes: TopocentricAxes,
+    /// Position of the site in the body fixed frame, in km
+    pub site_km: Vector3,
+    /// Rotation matrix from the topocentric axes to the body fixed frame
+    pub rot_mat: Matrix3,
+}
+
+impl TopocentricFrame {
+    /// Builds the topocentric frame of the provided geodetic site on the ellipsoid of the body fixed frame.
+    pub fn new(
+        latitude_deg: f64,
+        longitude_deg: f64,
+        height_km: f64,
+        body_frame: Frame,
+        axes: TopocentricAxes,
+    ) -> AlmanacResult<Self> {
+        let site = Orbit::try_latlongalt(
+            latitude_deg,
+            longitude_deg,
+            height_km,
+            Epoch::from_tdb_seconds(0.0),
+            body_frame,
+        )
+        .context(AlmanacPhysicsSnafu {
+            action: "building topocentric site",
+        })?;
+
+        let sez_to_body = site
+            .dcm3x3_from_topocentric_to_body_fixed()
+            .context(AlmanacPhysicsSnafu {
+                action: "building topocentric axes",
+            })?
+            .rot_mat;
+
+        let rot_mat = match axes {
+            TopocentricAxes::SEZ => sez_to_body,
+            // South is minus North, so (S, E, Z) = (-N, E, U)
+            TopocentricAxes::ENU => {
+                sez_to_body * Matrix3::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0)
+            }
+            // (S, E, Z) = (-N, E, -D)
+            TopocentricAxes::NED => {
+                sez_to_body * Matrix3::new(-1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0)
+            }
+        };
+
+        Ok(Self {
+            body_frame,
+            axes,
+            site_km: site.radius_km,
+            rot_mat,
+        })
+    }
+
+    /// Returns the state of the site in the body fixed frame at the provided epoch.
+    pub fn site_state(&self, epoch: Epoch) -> CartesianState {
+        CartesianState {
+            radius_km: self.site_km,
+            velocity_km_s: Vector3::zeros(),
+            epoch,
+            frame: self.body_frame,
+        }
+    }
+
+    /// Returns the constant DCM from the axes of this topocentric frame, whose orientation ID is provided, to the body fixed frame.
+    pub fn dcm_to_body_fixed(&self, orientation_id: NaifId) -> DCM {
+        DCM {
+            rot_mat: self.rot_mat,
+            rot_mat_dt: None,
+            from: orientation_id,
+            to: self.body_frame.orientation_id,
+        }
+    }
+
+    /// Converts the provided state, expressed in the body fixed frame, into this topocentric frame.
+    pub fn from_body_fixed(&self, state: CartesianState, frame: Frame) -> CartesianState {
+        CartesianState {
+            radius_km: self.rot_mat.transpose() * (state.radius_km - self.site_km),
+            velocity_km_s: self.rot_mat.transpose() * state.velocity_km_s,
+            epoch: state.epoch,
+            frame,
+        }
+    }
+
+    /// Converts the provided state, expressed in this topocentric frame, into the body fixed frame.
+    pub fn to_body_fixed(&self, state: CartesianState) -> CartesianState {
+        CartesianState {
+            radius_km: self.rot_mat * state.radius_km + self.site_km,
+            velocity_km_s: self.rot_mat * state.velocity_km_s,
+            epoch: state.epoch,
+            frame: self.body_frame,
+        }
+    }
+}
+
+impl Almanac {
+    /// Registers a topocentric frame anchored at the provided geodetic site of the body fixed frame, such that `Frame::new(id, id)`
+    /// may then be used as the target or observer of `transform` and `transform_to`. The shape of the body is fetched from
+    /// the loaded planetary data if the body frame does not define it.
+    ///
+    /// # Notes
+    /// + The ID should not be used by any loaded SPK or BPC, as the topocentric frame takes precedence.
+    /// + Aberration corrections to or from a topocentric frame are computed with respect to the center of its body.
+    pub fn with_topocentric_frame(
+        mut self,
+        id: NaifId,
+        latitude_deg: f64,
+        longitude_deg: f64,
+        height_km: f64,
+        body_frame: Frame,
+        axes: TopocentricAxes,
+    ) -> AlmanacResult<Self> {
+        let body_frame = if body_frame.shape.is_some() {
+            body_frame
+        } else {
+            self.frame_info(body_frame).unwrap_or(body_frame)
+        };
+
+        let topo = TopocentricFrame::new(latitude_deg, longitude_deg, height_km, body_frame, axes)?;
+        self.topocentric_frames.insert(id, topo);
+        Ok(self)
+    }
+
+    /// Returns the registered topocentric frame of the provided frame, if any.
+    pub fn topocentric_frame(&self, frame: Frame) -> Option<&TopocentricFrame> {
+        self.topocentric_frames.get(&frame.ephemeris_id)
+    }
+
+    /// Returns the DCM from the axes of the registered topocentric frame to the orientation of the provided frame at the provided epoch,
+    /// e.g. to point a surface asset, or to express the attitude of a rover in its local East-North-Up or North-East-Down axes.
+    ///
+    /// This is a dynamic frame: the DCM includes its time derivative from the rotation of the body fixed frame of the site, so it may
+    /// be applied to full states, e.g. a velocity in the topocentric axes is converted into an inertial velocity.
+    pub fn topocentric_dcm(
+        &self,
+        topocentric_frame: Frame,
+        to_frame: Frame,
+        epoch: Epoch,
+    ) -> AlmanacResult<DCM> {
+        let topo = self.topocentric_frame(topocentric_frame).ok_or_else(|| {
+            AlmanacError::GenericError {
+                err: format!("{topocentric_frame} is not a registered topocentric frame"),
+            }
+        })?;
+        let action = "computing topocentric DCM";
+
+        let body_to_frame = self
+            .rotate(topo.body_frame, to_frame, epoch)
+            .context(OrientationSnafu { action })?;
+
+        (body_to_frame * topo.dcm_to_body_fixed(topocentric_frame.orientation_id))
+            .context(AlmanacPhysicsSnafu { action })
+    }
+}
+
+#[cfg(test)]
+mod ut_topocentric {
+    use super::TopocentricAxes;
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME, SSB_J2000};
+    use crate::math::cartesian::CartesianState;
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::naif::SPK;
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use hifitime::Epoch;
+
+    /// Builds an SPK with an Earth segment from 0 to 86400 s past J2000 TDB, where X = 1000 + t km with t in [-1, 1].
+    fn earth_spk() -> SPK {
+        test_spk(&[chebyshev_segment(
+            399,
+            0,
+            0.0,
+            86_400.0,
+            &[[[1000.0, 1.0], [0.0; 2], [0.0; 2]]],
+        )])
+    }
+
+    #[test]
+    fn topocentric_transforms() {
+        // A spherical Earth whose "body fixed" frame is J2000, so that the site is fixed inertially at +X
+        let earth = EARTH_J2000.with_ellipsoid(Ellipsoid::from_sphere(6378.0));
+        let almanac = Almanac::default()
+            .with_spk(earth_spk())
+            .with_topocentric_frame(-1, 0.0, 0.0, 0.0, earth, TopocentricAxes::ENU)
+            .unwrap()
+            .with_topocentric_frame(-2, 0.0, 0.0, 1.0, earth, TopocentricAxes::SEZ)
+            .unwrap();
+        let enu = Frame::new(-1, -1);
+        let sez = Frame::new(-2, -2);
+        assert_eq!(
+            almanac.topocentric_frame(enu).unwrap().axes,
+            TopocentricAxes::ENU
+        );
+        assert!(almanac.topocentric_frame(SSB_J2000).is_none());
+
+        let epoch = Epoch::from_et_seconds(43200.0);
+
+        // The site as seen from the SSB
+        let site = almanac.transform(enu, SSB_J2000, epoch, None).unwrap();
+        assert!((site.radius_km.x - 7378.0).abs() < 1e-9);
+        assert!(site.radius_km.yz().norm() < 1e-9);
+        assert!((site.velocity_km_s.x - 1.0 / 43200.0).abs() < 1e-12);
+
+        // The SSB as seen from the site: straight down along Up
+        let ssb = almanac.transform(SSB_J2000, enu, epoch, None).unwrap();
+        assert_eq!(ssb.frame, enu);
+        assert!((ssb.radius_km.z + 7378.0).abs() < 1e-9);
+        assert!(ssb.radius_km.xy().norm() < 1e-9);
+
+        // A point north and east of the site
+        let state = almanac
+            .transform_to(
+                crate::math::cartesian::CartesianState::new(
+                    10.0,
+                    20.0,
+                    30.0,
+                    0.0,
+                    0.0,
+                    0.0,
+                    epoch,
+                    EARTH_J2000,
+                ),
+                enu,
+                None,
+            )
+            .unwrap();
+        // East is +Y, North is +Z, and Up is +X
+        assert!((state.radius_km.x - 20.0).abs() < 1e-9);
+        assert!((state.radius_km.y - 30.0).abs() < 1e-9);
+        assert!((state.radius_km.z - (10.0 - 6378.0)).abs() < 1e-9);
+
+        // South is -Z, East is +Y, Zenith is +X, and this site is 1 km higher
+        let in_sez = almanac.transform_to(state, sez, None).unwrap();
+        assert!((in_sez.radius_km.x + 30.0).abs() < 1e-9);
+        assert!((in_sez.radius_km.y - 20.0).abs() < 1e-9);
+        assert!((in_sez.radius_km.z - (9.0 - 6378.0)).abs() < 1e-9);
+
+        // And back
+        let back = almanac.transform_to(in_sez, EARTH_J2000, None).unwrap();
+        assert!((back.radius_km - crate::math::Vector3::new(10.0, 20.0, 30.0)).norm() < 1e-9);
+
+        // The shape is required
+        assert!(Almanac::default()
+            .with_topocentric_frame(-3, 0.0, 0.0, 0.0, EARTH_J2000, TopocentricAxes::ENU)
+            .is_err());
+    }
+
+    #[test]
+    fn topocentric_dcm() {
+        let almanac = Almanac::default()
+            .load("../data/pck08.pca")
+            .unwrap()
+            .with_topocentric_frame(-5, 45.0, 10.0, 0.2, IAU_EARTH_FRAME, TopocentricAxes::NED)
+            .unwrap()
+            .with_topocentric_frame(-6, 45.0, 10.0, 0.2, IAU_EARTH_FRAME, TopocentricAxes::ENU)
+            .unwrap();
+        let (ned, enu) = (Frame::new(-5, -5), Frame::new(-6, -6));
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 6, 1);
+
+        let ned_dcm = almanac.topocentric_dcm(ned, EARTH_J2000, epoch).unwrap();
+        let enu_dcm = almanac.topocentric_dcm(enu, EARTH_J2000, epoch).unwrap();
+        assert_eq!(ned_dcm.from, -5);
+        assert_eq!(ned_dcm.to, EARTH_J2000.orientation_id);
+        // North and East are swapped, and Down is minus Up.
+        let (n, e) = (ned_dcm.rot_mat.column(0), ned_dcm.rot_mat.column(1));
+        assert!((n - enu_dcm.rot_mat.column(1)).norm() < 1e-12);
+        assert!((e - enu_dcm.rot_mat.column(0)).norm() < 1e-12);
+        assert!((ned_dcm.rot_mat.column(2) + enu_dcm.rot_mat.column(2)).norm() < 1e-12);
+        // North points towards the pole, which is within a degree of the J2000 Z axis.
+        assert!((n.z - 45.0_f64.to_radians().cos()).abs() < 1e-2);
+
+        // The DCM rotates with the Earth, so it matches the transformation of a state fixed in the topocentric frame.
+        let local = CartesianState::new(1.0, 2.0, -0.5, 0.0, 0.0, 0.0, epoch, ned);
+        let state = almanac.transform_to(local, EARTH_J2000, None).unwrap();
+        let site = almanac.transform(ned, EARTH_J2000, epoch, None).unwrap();
+        let rotated = ned_dcm.state_dcm() * local.to_cartesian_pos_vel();
+        assert!((rotated.fixed_rows::<3>(0) - (state.radius_km - site.radius_km)).norm() < 1e-9);
+        assert!(
+            (rotated.fixed_rows::<3>(3) - (state.velocity_km_s - site.velocity_km_s)).norm()
+                < 1e-12
+        );
+        assert!(rotated.fixed_rows::<3>(3).norm() > 1e-4);
+
+        // Only registered topocentric frames
+        assert!(almanac
+            .topocentric_dcm(IAU_EARTH_FRAME, EARTH_J2000, epoch)
+            .is_err());
+    }
+}
@@ -0,0 +1,130 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Epoch, TimeSeries};
+
+use super::Almanac;
+use crate::errors::AlmanacResult;
+use crate::prelude::{Aberration, Frame};
+
+/// Range and range-rate of a spacecraft as observed by a station at one epoch, as computed by [Almanac::range_observables].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RangeObservable {
+    /// Epoch of the observation at the station
+    pub epoch: Epoch,
+    pub range_km: f64,
+    /// Rate of change of the range, positive when the spacecraft moves away from the station
+    pub range_rate_km_s: f64,
+}
+
+impl fmt::Display for RangeObservable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: range {:.6} km, range-rate {:.6} km/s",
+            self.epoch, self.range_km, self.range_rate_km_s
+        )
+    }
+}
+
+impl Almanac {
+    /// Returns the range and range-rate of the spacecraft as observed by the station at each epoch of the time series, e.g. to
+    /// simulate the tracking data of an orbit determination. The station frame may be any frame whose origin is the station,
+    /// like a topocentric frame or a traverse.
+    ///
+    /// The observables are computed from the state of the spacecraft relative to the station, with the provided aberration
+    /// correction: use a converged light time correction (e.g. [Aberration::CN]) to place the spacecraft at the epoch at which the
+    /// received signal left it, or a transmission correction (e.g. [Aberration::XCN]) for the signal transmitted by the station. The
+    /// stellar aberration corrections only change the direction of the spacecraft, hence not its range.
+    pub fn range_observables(
+        &self,
+        station_frame: Frame,
+        spacecraft_frame: Frame,
+        time_series: TimeSeries,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<Vec<RangeObservable>> {
+        time_series
+            .map(|epoch| {
+                let state = self.transform(spacecraft_frame, station_frame, epoch, ab_corr)?;
+
+                let range_km = state.rmag_km();
+                let range_rate_km_s = if range_km > f64::EPSILON {
+                    state.radius_km.dot(&state.velocity_km_s) / range_km
+                } else {
+                    // Colocated objects: the rate of change of the range is their relative speed.
+                    state.vmag_km_s()
+                };
+
+                Ok(RangeObservable {
+                    epoch,
+                    range_km,
+                    range_rate_km_s,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod ut_tracking {
+    use crate::constants::SPEED_OF_LIGHT_KM_S;
+    use crate::naif::daf::test_utils::{fixed_segment, linear_segment, test_spk};
+    use crate::prelude::{Aberration, Almanac, Frame};
+    use hifitime::{Epoch, TimeSeries, Unit};
+
+    #[test]
+    fn range_observables() {
+        let epoch = Epoch::from_et_seconds(43_200.0);
+        let speed_km_s = 10.0;
+        let x0_km = 1.0e6;
+
+        // A station at rest, and a spacecraft moving away from it along the X axis.
+        let almanac = Almanac::default().with_spk(test_spk(&[
+            fixed_segment(-10, 0, [0.0; 3]),
+            linear_segment(-11, 0, [x0_km, 0.0, 0.0], [speed_km_s, 0.0, 0.0]),
+        ]));
+        let station = Frame::from_ephem_j2000(-10);
+        let spacecraft = Frame::from_ephem_j2000(-11);
+        let series = || TimeSeries::inclusive(epoch, epoch + Unit::Minute * 30, Unit::Minute * 10);
+
+        let geometric = almanac
+            .range_observables(station, spacecraft, series(), None)
+            .unwrap();
+        assert_eq!(geometric.len(), 4);
+        for obs in &geometric {
+            println!("{obs}");
+            let dt_s = (obs.epoch - epoch).to_seconds();
+            assert!((obs.range_km - (x0_km + speed_km_s * dt_s)).abs() < 1e-6);
+            assert!((obs.range_rate_km_s - speed_km_s).abs() < 1e-9);
+        }
+
+        // With light time, the spacecraft is observed where it was when the signal left it.
+        let light_time = almanac
+            .range_observables(station, spacecraft, series(), Aberration::CN)
+            .unwrap();
+        let stellar = almanac
+            .range_observables(station, spacecraft, series(), Aberration::CN_S)
+            .unwrap();
+        for (obs, stellar_obs) in light_time.iter().zip(&stellar) {
+            let dt_s = (obs.epoch - epoch).to_seconds();
+            let expected_km =
+                (x0_km + speed_km_s * dt_s) / (1.0 + speed_km_s / SPEED_OF_LIGHT_KM_S);
+            assert!(
+                (obs.range_km - expected_km).abs() < 1e-6,
+                "{} != {expected_km}",
+                obs.range_km
+            );
+            assert!((obs.range_rate_km_s - speed_km_s).abs() < 1e-3);
+            // The stellar aberration does not change the range.
+            assert!((stellar_obs.range_km - obs.range_km).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch};
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::astro::{LambertSolution, TransferDirection};
+use crate::errors::{AlmanacError, AlmanacPhysicsSnafu, AlmanacResult};
+use crate::math::Vector3;
+use crate::prelude::Frame;
+
+/// A direct (single revolution, prograde) transfer between two bodies, as found by [Almanac::transfer_windows].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransferWindow {
+    pub departure_epoch: Epoch,
+    pub arrival_epoch: Epoch,
+    /// Hyperbolic excess velocity vector with respect to the departure body, in the central frame
+    pub departure_vinf_km_s: Vector3,
+    /// Hyperbolic excess velocity vector with respect to the arrival body, in the central frame
+    pub arrival_vinf_km_s: Vector3,
+    /// Angle swept by the transfer about the central body, in degrees
+    pub transfer_angle_deg: f64,
+}
+
+impl TransferWindow {
+    /// Returns the time of flight of this transfer.
+    pub fn time_of_flight(&self) -> Duration {
+        self.arrival_epoch - self.departure_epoch
+    }
+
+    /// Returns the characteristic energy (C3) of the departure, i.e. the square of the departure v-infinity.
+    pub fn c3_km2_s2(&self) -> f64 {
+        self.departure_vinf_km_s.norm_squared()
+    }
+
+    /// Returns the sum of the departure and arrival v-infinity magnitudes, which is the metric used to rank transfers.
+    pub fn total_vinf_km_s(&self) -> f64 {
+        self.departure_vinf_km_s.norm() + self.arrival_vinf_km_s.norm()
+    }
+}
+
+impl fmt::Display for TransferWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "depart {} arrive {} ({}): v-inf {:.3} km/s (C3 = {:.3} km^2/s^2) -> {:.3} km/s",
+            self.departure_epoch,
+            self.arrival_epoch,
+            self.time_of_flight(),
+            self.departure_vinf_km_s.norm(),
+            self.c3_km2_s2(),
+            self.arrival_vinf_km_s.norm()
+        )
+    }
+}
+
+impl Almanac {
+    /// Scans the direct transfers from the departure body to the arrival body about the central body (e.g. the Sun), for departures
+    /// every `departure_step` between the start and end epochs and times of flight every `tof_step` between the min and max times of flight.
+    ///
+    /// Each transfer is the prograde single revolution Lambert solution between the positions of both bodies. For each departure epoch,
+    /// the time of flight minimizing the sum of the departure and arrival v-infinity magnitudes is kept, and the local minima of that sum
+    /// over the departure epochs are returned as the transfer windows, best first. These are the valleys of a porkchop plot, so the steps
+    /// should be small compared to the synodic period of both bodies (e.g. a few days for interplanetary transfers). The first and last
+    /// departure epochs are never reported, so the scan should extend past the epochs of interest.
+    ///
+    /// The central frame should be inertial, and its gravitational parameter is fetched from the planetary data if it is not set.
+    /// Transfers which cannot be computed (e.g. exactly 180 degree transfers) are skipped, but ephemeris errors are returned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_windows(
+        &self,
+        departure_frame: Frame,
+        arrival_frame: Frame,
+        central_frame: Frame,
+        departure_start: Epoch,
+        departure_end: Epoch,
+        departure_step: Duration,
+        min_tof: Duration,
+        max_tof: Duration,
+        tof_step: Duration,
+    ) -> AlmanacResult<Vec<TransferWindow>> {
+        if departure_end < departure_start
+            || departure_step <= Duration::ZERO
+            || tof_step <= Duration::ZERO
+            || min_tof <= Duration::ZERO
+            || max_tof < min_tof
+        {
+            return Err(AlmanacError::GenericError {
+                err: format!(
+                    "transfer scan requires ordered departure epochs ({departure_start} to {departure_end}), positive steps ({departure_step}, {tof_step}), and times of flight ({min_tof} to {max_tof})"
+                ),
+            });
+        }
+
+        let mut central_frame = central_frame;
+        if central_frame.mu_km3_s2.is_none() {
+            central_frame =
+                self.frame_info(central_frame)
+                    .map_err(|e| AlmanacError::GenericError {
+                        err: format!("{e} when fetching {central_frame} frame data"),
+                    })?;
+        }
+        let mu_km3_s2 = central_frame.mu_km3_s2().context(AlmanacPhysicsSnafu {
+            action: "transfer scan",
+        })?;
+
+        // Best transfer of each departure epoch
+        let mut best = Vec::new();
+        let mut departure_epoch = departure_start;
+        while departure_epoch <= departure_end {
+            let departure =
+                self.transform(departure_frame, central_frame, departure_epoch, None)?;
+
+            let mut best_here: Option<TransferWindow> = None;
+            let mut tof = min_tof;
+            while tof <= max_tof {
+                let arrival_epoch = departure_epoch + tof;
+                let arrival = self.transform(arrival_frame, central_frame, arrival_epoch, None)?;
+
+                if let Ok(sol) = LambertSolution::solve(
+                    departure.radius_km,
+                    arrival.radius_km,
+                    tof,
+                    mu_km3_s2,
+                    TransferDirection::Prograde,
+                ) {
+                    let window = TransferWindow {
+                        departure_epoch,
+                        arrival_epoch,
+                        departure_vinf_km_s: sol.v_init_km_s - departure.velocity_km_s,
+                        arrival_vinf_km_s: sol.v_final_km_s - arrival.velocity_km_s,
+                        transfer_angle_deg: sol.transfer_angle_deg,
+                    };
+                    if best_here
+                        .is_none_or(|other| window.total_vinf_km_s() < other.total_vinf_km_s())
+                    {
+                        best_here = Some(window);
+                    }
+                }
+
+                tof += tof_step;
+            }
+
+            best.push(best_here);
+            departure_epoch += departure_step;
+        }
+
+        // Local minima over the departure epochs, where a plateau yields its first epoch. The bounds of the scan are excluded
+        // since the actual minimum may be outside of it.
+        let cost = |window: &Option<TransferWindow>| {
+            window.map_or(f64::INFINITY, |window| window.total_vinf_km_s())
+        };
+        let mut windows = best
+            .windows(3)
+            .filter_map(|triplet| {
+                let window = triplet[1]?;
+                let here = window.total_vinf_km_s();
+                (here < cost(&triplet[0]) && here <= cost(&triplet[2])).then_some(window)
+            })
+            .collect::<Vec<_>>();
+
+        windows.sort_by(|a, b| a.total_vinf_km_s().total_cmp(&b.total_vinf_km_s()));
+
+        Ok(windows)
+    }
+}
+
+#[cfg(test)]
+mod ut_transfer {
+    use crate::constants::frames::{EARTH_J2000, SUN_J2000};
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use hifitime::{Epoch, Unit};
+
+    /// Circular heliocentric orbit of the provided body, sampled every two days over the provided number of days.
+    fn circular(naif_id: i32, sma_km: f64, inc_deg: f64, ta_deg: f64, days: i64) -> Ephemeris {
+        let sun = SUN_J2000.with_mu_km3_s2(132_712_440_041.939_4);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let orbit =
+            Orbit::try_keplerian(sma_km, 0.0, inc_deg, 0.0, 0.0, ta_deg, epoch, sun).unwrap();
+
+        let mut ephem = Ephemeris::new(format!("{naif_id}"));
+        for day in (0..=days).step_by(2) {
+            ephem.insert_orbit(orbit.propagate_to_epoch(epoch + Unit::Day * day).unwrap());
+        }
+        ephem
+    }
+
+    #[test]
+    fn transfer_windows() {
+        let au_km = 149_597_870.7;
+        let days = 2_200;
+        let mut almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        for (naif_id, ephem) in [
+            (399, circular(399, au_km, 0.0, 100.0, days)),
+            (499, circular(499, 1.523_7 * au_km, 0.0, 140.0, days)),
+        ] {
+            let spk = ephem
+                .to_spice_bsp(naif_id, Some(DafDataType::Type13HermiteUnequalStep))
+                .unwrap();
+            almanac = almanac.with_spk(spk);
+        }
+        let mars = Frame::from_ephem_j2000(499);
+
+        let start = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let windows = almanac
+            .transfer_windows(
+                EARTH_J2000,
+                mars,
+                SUN_J2000,
+                start,
+                start + Unit::Day * 1_700,
+                Unit::Day * 4,
+                Unit::Day * 100,
+                Unit::Day * 400,
+                Unit::Day * 4,
+            )
+            .unwrap();
+        for window in &windows {
+            println!("{window}");
+        }
+
+        // Two Hohmann transfers of 2.94 and 2.65 km/s in 259 days, one synodic period (780 days) apart.
+        assert_eq!(windows.len(), 2);
+        for window in &windows {
+            assert!((window.departure_vinf_km_s.norm() - 2.94).abs() < 0.05);
+            assert!((window.arrival_vinf_km_s.norm() - 2.65).abs() < 0.05);
+            assert!((window.time_of_flight().to_unit(Unit::Day) - 259.0).abs() < 5.0);
+            assert!((window.transfer_angle_deg - 180.0).abs() < 5.0);
+        }
+        let spacing = (windows[0].departure_epoch - windows[1].departure_epoch).abs();
+        assert!(
+            (spacing.to_unit(Unit::Day) - 780.0).abs() < 20.0,
+            "{spacing}"
+        );
+        // Ranked best first
+        assert!(windows
+            .windows(2)
+            .all(|pair| pair[0].total_vinf_km_s() <= pair[1].total_vinf_km_s()));
+
+        assert!(almanac
+            .transfer_windows(
+                EARTH_J2000,
+                mars,
+                SUN_J2000,
+                start,
+                start + Unit::Day * 10,
+                Unit::Day * 0,
+                Unit::Day * 100,
+                Unit::Day * 400,
+                Unit::Day * 4,
+            )
+            .is_err());
+    }
+}
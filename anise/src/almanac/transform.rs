@@ -12,19 +12,30 @@ use hifitime::{Epoch, Unit as TimeUnit};
 use snafu::ResultExt;
 
 use crate::{
+    astro::RaDec,
     constants::{
         frames::{EARTH_J2000, SUN_J2000},
         orientations::J2000,
     },
-    errors::{AlmanacResult, EphemerisSnafu, OrientationSnafu},
+    errors::{AlmanacError, AlmanacPhysicsSnafu, AlmanacResult, EphemerisSnafu, OrientationSnafu},
     math::{cartesian::CartesianState, units::LengthUnit, Vector3},
     orientations::OrientationPhysicsSnafu,
     prelude::{Aberration, Frame},
+    structure::traverse::Traverse,
     NaifId,
 };
 
+#[cfg(feature = "analysis")]
+use crate::{
+    ephemerides::ephemeris::{FrameCovariance, LocalFrame},
+    math::rotation::DCM,
+    prelude::Orbit,
+};
+
 use super::Almanac;
 
+const TRAVERSE: &str = "computing state of traverse";
+
 impl Almanac {
     /// Returns the Cartesian state needed to transform the `target_frame` to the `observer_frame`.
     ///
@@ -38,6 +49,9 @@ impl Almanac {
     ///
     /// # Note
     /// The units will be those of the underlying ephemeris data (typically km and km/s)
+    ///
+    /// Either frame may be a topocentric frame registered with `with_topocentric_frame`, or have the ephemeris ID of a loaded traverse,
+    /// in which case the aberration corrections are computed with respect to the center body of the traverse.
     pub fn transform(
         &self,
         target_frame: Frame,
@@ -45,6 +59,22 @@ impl Almanac {
         epoch: Epoch,
         ab_corr: Option<Aberration>,
     ) -> AlmanacResult<CartesianState> {
+        if let Some(topo) = self.topocentric_frame(observer_frame) {
+            let state = self.transform(target_frame, topo.body_frame, epoch, ab_corr)?;
+            return Ok(topo.from_body_fixed(state, observer_frame));
+        } else if let Some(topo) = self.topocentric_frame(target_frame) {
+            return self.transform_to(topo.site_state(epoch), observer_frame, ab_corr);
+        } else if let Some(traverse) = self.traverse_from_id(observer_frame.ephemeris_id) {
+            let center_frame = observer_frame.with_ephem(traverse.center_id);
+            let state = self.transform(target_frame, center_frame, epoch, ab_corr)?;
+            return self.relative_to_traverse(state, &traverse, observer_frame);
+        } else if let Some(traverse) = self.traverse_from_id(target_frame.ephemeris_id) {
+            let state = traverse
+                .state(epoch)
+                .context(AlmanacPhysicsSnafu { action: TRAVERSE })?;
+            return self.transform_to(state, observer_frame, ab_corr);
+        }
+
         // Translate
         let state = self
             .translate(target_frame, observer_frame, epoch, ab_corr)
@@ -66,12 +96,42 @@ impl Almanac {
     }
 
     /// Returns the provided state as seen from the observer frame, given the aberration.
+    ///
+    /// Either the frame of the state or the observer frame may be a topocentric frame registered with `with_topocentric_frame`, or have
+    /// the ephemeris ID of a loaded traverse.
     pub fn transform_to(
         &self,
         mut state: CartesianState,
         observer_frame: Frame,
         ab_corr: Option<Aberration>,
     ) -> AlmanacResult<CartesianState> {
+        if let Some(topo) = self.topocentric_frame(state.frame) {
+            state = topo.to_body_fixed(state);
+        }
+        if let Some(topo) = self.topocentric_frame(observer_frame) {
+            let state = self.transform_to(state, topo.body_frame, ab_corr)?;
+            return Ok(topo.from_body_fixed(state, observer_frame));
+        }
+        if let Some(traverse) = self.traverse_from_id(state.frame.ephemeris_id) {
+            // Express the state relative to the center body of the traverse.
+            let center_frame = state.frame.with_ephem(traverse.center_id);
+            let asset = self.transform_to(
+                traverse
+                    .state(state.epoch)
+                    .context(AlmanacPhysicsSnafu { action: TRAVERSE })?,
+                center_frame,
+                None,
+            )?;
+            state.radius_km += asset.radius_km;
+            state.velocity_km_s += asset.velocity_km_s;
+            state.frame = center_frame;
+        }
+        if let Some(traverse) = self.traverse_from_id(observer_frame.ephemeris_id) {
+            let center_frame = observer_frame.with_ephem(traverse.center_id);
+            let state = self.transform_to(state, center_frame, ab_corr)?;
+            return self.relative_to_traverse(state, &traverse, observer_frame);
+        }
+
         // If the input and final rotations differ, rotate into J2000 first
         state = if state.frame.orient_origin_match(observer_frame) {
             state
@@ -96,6 +156,26 @@ impl Almanac {
             })
     }
 
+    /// Returns the provided state, expressed relative to the center body of the traverse, relative to the traversing asset instead.
+    fn relative_to_traverse(
+        &self,
+        mut state: CartesianState,
+        traverse: &Traverse,
+        observer_frame: Frame,
+    ) -> AlmanacResult<CartesianState> {
+        let asset = self.transform_to(
+            traverse
+                .state(state.epoch)
+                .context(AlmanacPhysicsSnafu { action: TRAVERSE })?,
+            state.frame,
+            None,
+        )?;
+        state.radius_km -= asset.radius_km;
+        state.velocity_km_s -= asset.velocity_km_s;
+        state.frame = observer_frame;
+        Ok(state)
+    }
+
     /// Returns the Cartesian state of the object as seen from the provided observer frame (essentially `spkezr`).
     ///
     /// # Note
@@ -180,6 +260,85 @@ impl Almanac {
             })
     }
 
+    /// Returns the DCM from the local frame of the trajectory of the target, as seen from the observer, to the observer frame,
+    /// evaluated at any epoch covered by the ephemeris of the target. The observer frame must be inertial.
+    ///
+    /// This is a dynamic frame: unlike [Orbit::dcm_to_inertial], which assumes two body dynamics, the time derivative of the DCM
+    /// is computed from the ephemeris of the target one millisecond before and after the epoch, so it accounts for all of the
+    /// dynamics of the trajectory. The time derivative is not set if either of these states cannot be computed.
+    #[cfg(feature = "analysis")]
+    pub fn local_frame_dcm_to_inertial(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        local_frame: LocalFrame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<DCM> {
+        let dcm3x3 = |state: Orbit| {
+            match local_frame {
+                LocalFrame::Inertial => Ok(DCM::identity(
+                    state.frame.orientation_id,
+                    state.frame.orientation_id,
+                )),
+                LocalFrame::RIC => state.dcm3x3_from_ric_to_inertial(),
+                LocalFrame::VNC => state.dcm3x3_from_vnc_to_inertial(),
+                LocalFrame::RCN => state.dcm3x3_from_rcn_to_inertial(),
+                LocalFrame::LVLH => state.dcm3x3_from_lvlh_to_inertial(),
+            }
+            .context(AlmanacPhysicsSnafu {
+                action: "computing local frame DCM",
+            })
+        };
+
+        let mut dcm = dcm3x3(self.transform(target_frame, observer_frame, epoch, ab_corr)?)?;
+
+        if let (Ok(pre), Ok(post)) = (
+            self.transform(
+                target_frame,
+                observer_frame,
+                epoch - TimeUnit::Millisecond * 1,
+                ab_corr,
+            ),
+            self.transform(
+                target_frame,
+                observer_frame,
+                epoch + TimeUnit::Millisecond * 1,
+                ab_corr,
+            ),
+        ) {
+            dcm.rot_mat_dt = Some(500.0 * (dcm3x3(post)?.rot_mat - dcm3x3(pre)?.rot_mat));
+        }
+
+        Ok(dcm)
+    }
+
+    /// Returns the provided covariance expressed in the axes of the new frame, at the epoch of that covariance.
+    ///
+    /// The covariance is rotated with the 6x6 state DCM between both frames, P' = M P M^T, which includes the time derivative of the
+    /// rotation: when transforming to or from a rotating frame (e.g. a body fixed frame), the velocity covariance is coupled to the
+    /// position covariance by the rotation rate of that frame. The covariance does not depend on the origin of the frames, since
+    /// the state of that origin is assumed to be exact.
+    #[cfg(feature = "analysis")]
+    pub fn transform_covariance_to(
+        &self,
+        covariance: FrameCovariance,
+        new_frame: Frame,
+    ) -> AlmanacResult<FrameCovariance> {
+        let dcm = self
+            .rotate(covariance.frame, new_frame, covariance.epoch)
+            .context(OrientationSnafu {
+                action: "transforming covariance",
+            })?;
+        let state_dcm = dcm.state_dcm();
+
+        Ok(FrameCovariance {
+            matrix: state_dcm * covariance.matrix * state_dcm.transpose(),
+            frame: new_frame,
+            epoch: covariance.epoch,
+        })
+    }
+
     /// Returns the unitary 3D vector between two [Frame]s (solid bodies) at desired [Epoch]
     pub fn unit_vector(
         &self,
@@ -210,4 +369,138 @@ impl Almanac {
     ) -> AlmanacResult<Vector3> {
         self.unit_vector(SUN_J2000, EARTH_J2000, epoch, ab_corr)
     }
+
+    /// Returns the right ascension, declination, and range of the target as seen from the observer, with their rates of change, in
+    /// the orientation of the observer frame at the desired [Epoch].
+    ///
+    /// # SPICE Compatibility
+    /// This is the equivalent of calling `recrad` on the position returned by `spkezr`: use a light time and stellar aberration
+    /// correction (e.g. [Aberration::CN_S]) for the apparent right ascension and declination of the target.
+    pub fn radec(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Option<Aberration>,
+    ) -> AlmanacResult<RaDec> {
+        Ok(self
+            .transform(target_frame, observer_frame, epoch, ab_corr)?
+            .radec())
+    }
+
+    /// Returns the provided state propagated to the provided epoch with two body dynamics around the center of its frame, using
+    /// the gravitational parameter of the loaded planetary data if the frame of the state does not include it.
+    pub fn propagate_two_body(
+        &self,
+        mut state: CartesianState,
+        new_epoch: Epoch,
+    ) -> AlmanacResult<CartesianState> {
+        if state.frame.mu_km3_s2.is_none() {
+            state.frame = self
+                .frame_info(state.frame)
+                .map_err(|e| AlmanacError::GenericError {
+                    err: format!("{e} when fetching {} frame data", state.frame),
+                })?;
+        }
+
+        state
+            .propagate_to_epoch(new_epoch)
+            .context(AlmanacPhysicsSnafu {
+                action: "two body propagation",
+            })
+    }
+}
+
+#[cfg(all(test, feature = "analysis"))]
+mod ut_local_frame {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::ephemerides::ephemeris::LocalFrame;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{linear_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::Epoch;
+
+    #[test]
+    fn local_frame_along_trajectory() {
+        // Straight line flyby of the Earth at 7000 km, at 7.5 km/s along Y, from 0 to 1 day past J2000 TDB
+        let spk = test_spk(&[linear_segment(
+            -85,
+            399,
+            [7000.0, 0.0, 0.0],
+            [0.0, 7.5, 0.0],
+        )]);
+        let almanac = Almanac::default().with_spk(spk);
+        let spacecraft = Frame::from_ephem_j2000(-85);
+
+        // At closest approach, the radial direction rotates at v / r, as the trajectory is not a two body orbit.
+        let dcm = almanac
+            .local_frame_dcm_to_inertial(
+                spacecraft,
+                EARTH_J2000,
+                LocalFrame::LVLH,
+                Epoch::from_et_seconds(43_200.0),
+                None,
+            )
+            .unwrap();
+        assert!((dcm.rot_mat.column(2) + Vector3::x()).norm() < 1e-12);
+        let omega_rad_s = dcm.angular_velocity_rad_s().unwrap();
+        assert!((omega_rad_s.norm() - 7.5 / 7000.0).abs() < 1e-9);
+
+        // No rate at the edge of the ephemeris
+        let edge = almanac
+            .local_frame_dcm_to_inertial(
+                spacecraft,
+                EARTH_J2000,
+                LocalFrame::RIC,
+                Epoch::from_et_seconds(0.0),
+                None,
+            )
+            .unwrap();
+        assert!(edge.rot_mat_dt.is_none());
+    }
+
+    #[test]
+    fn covariance_frame_transformation() {
+        use crate::constants::frames::{IAU_EARTH_FRAME, MOON_J2000};
+        use crate::ephemerides::ephemeris::FrameCovariance;
+        use crate::math::{Matrix3, Matrix6};
+
+        let almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 6, 1);
+
+        // Uncorrelated position covariance of 1 km^2, without velocity uncertainty.
+        let mut matrix = Matrix6::zeros();
+        matrix
+            .fixed_view_mut::<3, 3>(0, 0)
+            .copy_from(&Matrix3::identity());
+        let covar = FrameCovariance::new(matrix, EARTH_J2000, epoch);
+        assert_eq!(covar.sigmas()[0], 1.0);
+
+        // The position uncertainty is invariant by rotation, but the rotation of the body fixed frame couples it into the velocity.
+        let fixed = almanac
+            .transform_covariance_to(covar, IAU_EARTH_FRAME)
+            .unwrap();
+        assert_eq!(fixed.frame, IAU_EARTH_FRAME);
+        assert_eq!(fixed.epoch, epoch);
+        assert!((fixed.matrix.fixed_view::<3, 3>(0, 0).trace() - 3.0).abs() < 1e-12);
+        let omega_rad_s: f64 = 7.292_115e-5;
+        let vel_trace = fixed.matrix.fixed_view::<3, 3>(3, 3).trace();
+        assert!(
+            (vel_trace / (2.0 * omega_rad_s.powi(2)) - 1.0).abs() < 1e-4,
+            "{vel_trace:e}"
+        );
+        assert!((fixed.matrix - fixed.matrix.transpose()).norm() < 1e-15);
+
+        // Round trip
+        let back = almanac.transform_covariance_to(fixed, EARTH_J2000).unwrap();
+        assert!((back.matrix - covar.matrix).norm() < 1e-12);
+
+        // Only the orientation matters.
+        let moon = almanac.transform_covariance_to(covar, MOON_J2000).unwrap();
+        assert_eq!(moon.matrix, covar.matrix);
+
+        assert!(almanac
+            .transform_covariance_to(covar, Frame::new(399, 123_456))
+            .is_err());
+    }
 }
@@ -0,0 +1,211 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::Epoch;
+use snafu::ResultExt;
+use std::collections::BTreeSet;
+
+use super::Almanac;
+use crate::ephemerides::SPKSnafu;
+use crate::errors::{AlmanacResult, EphemerisSnafu, OrientationSnafu};
+use crate::naif::daf::{DAFError, DafSegment, NAIFSummaryRecord, DAF};
+use crate::naif::{BPC, SPK};
+use crate::orientations::BPCSnafu;
+use crate::NaifId;
+
+/// Kernels trimmed to a mission phase, cf. [Almanac::trim_for_mission].
+#[derive(Clone, Debug)]
+pub struct PhaseKernels {
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+    /// Trimmed ephemeris data, if any loaded SPK covers the phase
+    pub spk: Option<SPK>,
+    /// Trimmed orientation data, if any loaded BPC covers the phase
+    pub bpc: Option<BPC>,
+}
+
+impl Almanac {
+    /// Builds a minimal kernel set for each of the provided mission phases, given as inclusive start and end epochs, in the same order.
+    ///
+    /// The bodies are the NAIF IDs of the SPK targets and of the BPC frames to keep. The centers of the kept SPK segments are kept
+    /// as well, recursively, so that the ephemeris of each body can be computed up to the root of the kept data.
+    ///
+    /// The segments of all of the loaded kernels are merged into one SPK and one BPC per phase, in the order in which the Almanac
+    /// searches them, so the same segment takes precedence in the trimmed kernels. The coverage of each segment is clipped to the
+    /// phase and the Chebyshev segments are trimmed to the records covering the phase, cf. [DAF::trimmed_segments].
+    /// The output only depends on the loaded data and on the arguments, so trimming the same data twice yields identical files.
+    pub fn trim_for_mission(
+        &self,
+        phases: &[(Epoch, Epoch)],
+        bodies: &[NaifId],
+    ) -> AlmanacResult<Vec<PhaseKernels>> {
+        let mut kernels = Vec::with_capacity(phases.len());
+
+        for (start_epoch, end_epoch) in phases.iter().copied() {
+            let spks = self
+                .spk_precedence()
+                .map(|idx| &self.spk_data[idx])
+                .collect::<Vec<_>>();
+
+            // Add the centers of the kept segments until the set of kept IDs no longer changes.
+            let mut ids = bodies.iter().copied().collect::<BTreeSet<_>>();
+            loop {
+                let mut centers = BTreeSet::new();
+                for spk in &spks {
+                    for summary in spk
+                        .trimmed_segments(start_epoch, end_epoch, |s| {
+                            ids.contains(&s.target_id) && !ids.contains(&s.center_id)
+                        })
+                        .context(SPKSnafu {
+                            action: "trimming kernels",
+                        })
+                        .context(EphemerisSnafu {
+                            action: "trimming kernels",
+                        })?
+                        .iter()
+                        .map(|segment| segment.summary)
+                    {
+                        centers.insert(summary.center_id);
+                    }
+                }
+                if centers.is_empty() {
+                    break;
+                }
+                ids.extend(centers);
+            }
+
+            let spk = merge_segments(spks.into_iter(), start_epoch, end_epoch, |s| {
+                ids.contains(&s.target_id)
+            })
+            .context(SPKSnafu {
+                action: "trimming kernels",
+            })
+            .context(EphemerisSnafu {
+                action: "trimming kernels",
+            })?;
+
+            let bpc = merge_segments(
+                self.bpc_precedence().map(|idx| &self.bpc_data[idx]),
+                start_epoch,
+                end_epoch,
+                |s| bodies.contains(&s.frame_id),
+            )
+            .context(BPCSnafu {
+                action: "trimming kernels",
+            })
+            .context(OrientationSnafu {
+                action: "trimming kernels",
+            })?;
+
+            kernels.push(PhaseKernels {
+                start_epoch,
+                end_epoch,
+                spk,
+                bpc,
+            });
+        }
+
+        Ok(kernels)
+    }
+}
+
+/// Writes the trimmed segments of all of the provided DAFs into a new DAF with the format of the first one which has any.
+fn merge_segments<'a, R, I, F>(
+    dafs: I,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    filter: F,
+) -> Result<Option<DAF<R>>, DAFError>
+where
+    R: NAIFSummaryRecord + 'a,
+    I: Iterator<Item = &'a DAF<R>>,
+    F: Fn(&R) -> bool,
+{
+    let mut file_rcrd = None;
+    let mut segments: Vec<DafSegment<R>> = Vec::new();
+    for daf in dafs {
+        let trimmed = daf.trimmed_segments(start_epoch, end_epoch, &filter)?;
+        if !trimmed.is_empty() && file_rcrd.is_none() {
+            file_rcrd = Some(daf.file_record()?);
+        }
+        segments.extend(trimmed);
+    }
+
+    match file_rcrd {
+        Some(file_rcrd) => Ok(Some(DAF::from_segments(&file_rcrd, &segments)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod ut_trim {
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000, SSB_J2000};
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::prelude::Almanac;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn trim_for_mission() {
+        // One record per day over ten days, where X = 1000 + day + t km.
+        let records = (0..10)
+            .map(|day| [[1000.0 + day as f64, 1.0], [0.0; 2], [0.0; 2]])
+            .collect::<Vec<_>>();
+        let segment =
+            |target_id, center_id| chebyshev_segment(target_id, center_id, 0.0, 86_400.0, &records);
+        let spk = test_spk(&[segment(399, 0), segment(301, 399), segment(599, 0)]);
+        let almanac = Almanac::default().with_spk(spk);
+
+        let day = |n: f64| Epoch::from_et_seconds(n * 86_400.0);
+        let phases = [
+            (day(1.5), day(3.0)),
+            (day(7.0), day(7.5)),
+            (day(11.0), day(12.0)),
+        ];
+        let kernels = almanac.trim_for_mission(&phases, &[301]).unwrap();
+        assert_eq!(kernels.len(), 3);
+        // No BPC is loaded, and nothing covers the last phase.
+        assert!(kernels.iter().all(|k| k.bpc.is_none()));
+        assert!(kernels[2].spk.is_none());
+
+        for (kernel, (start, end)) in kernels.iter().zip(phases).take(2) {
+            let spk = kernel.spk.clone().unwrap();
+            let summaries = spk.data_summaries(None).unwrap();
+            // The Moon and its center are kept, but not Jupiter
+            let ids = summaries
+                .iter()
+                .take(2)
+                .map(|s| (s.target_id, s.center_id))
+                .collect::<Vec<_>>();
+            assert_eq!(ids, vec![(399, 0), (301, 399)]);
+            assert_eq!(spk.daf_summary(None).unwrap().num_summaries(), 2);
+
+            let trimmed = Almanac::default().with_spk(spk);
+            for epoch in [start, start + Unit::Hour * 5, end] {
+                let expected = almanac
+                    .translate(MOON_J2000, SSB_J2000, epoch, None)
+                    .unwrap();
+                let state = trimmed
+                    .translate(MOON_J2000, SSB_J2000, epoch, None)
+                    .unwrap();
+                assert_eq!(expected.radius_km, state.radius_km);
+            }
+            assert!(trimmed
+                .translate(EARTH_J2000, SSB_J2000, end + Unit::Hour * 1, None)
+                .is_err());
+        }
+
+        // Deterministic output
+        let again = almanac.trim_for_mission(&phases, &[301]).unwrap();
+        assert_eq!(
+            again[0].spk.as_ref().unwrap().bytes,
+            kernels[0].spk.as_ref().unwrap().bytes
+        );
+    }
+}
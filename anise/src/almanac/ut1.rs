@@ -0,0 +1,122 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::sync::Arc;
+
+use hifitime::{Duration, Epoch, TimeScale};
+
+use super::Almanac;
+
+/// A source of the UT1 time scale, typically built from the Earth orientation parameters published by the IERS.
+///
+/// When an Almanac has a UT1 source, the prime meridian of the IAU Earth frame is driven by UT1 instead of TDB, such that the
+/// rotation of this frame is consistent with the sidereal angles computed by the time library with the same data.
+///
+/// This is implemented for the hifitime `Ut1Provider` (with the `ut1` feature), and for any function of the epoch returning TAI - UT1.
+pub trait Ut1Source: Send + Sync {
+    /// Returns the TAI - UT1 difference at the provided epoch, or None if this source does not cover this epoch.
+    fn tai_minus_ut1(&self, epoch: Epoch) -> Option<Duration>;
+}
+
+impl<F> Ut1Source for F
+where
+    F: Fn(Epoch) -> Option<Duration> + Send + Sync,
+{
+    fn tai_minus_ut1(&self, epoch: Epoch) -> Option<Duration> {
+        self(epoch)
+    }
+}
+
+#[cfg(feature = "ut1")]
+impl Ut1Source for hifitime::ut1::Ut1Provider {
+    fn tai_minus_ut1(&self, epoch: Epoch) -> Option<Duration> {
+        epoch.ut1_offset(self)
+    }
+}
+
+impl Almanac {
+    /// Returns a copy of this Almanac which uses the provided UT1 source for the rotation of the IAU Earth frame, cf. [Ut1Source].
+    pub fn with_ut1_provider(mut self, provider: impl Ut1Source + 'static) -> Self {
+        self.set_ut1_provider(Some(Arc::new(provider)));
+        self
+    }
+
+    /// Sets (or removes) the UT1 source used for the rotation of the IAU Earth frame.
+    pub fn set_ut1_provider(&mut self, provider: Option<Arc<dyn Ut1Source>>) {
+        self.ut1_provider = provider;
+    }
+
+    /// Returns the TDB - UT1 difference at the provided epoch, as used for the rotation of the IAU Earth frame.
+    ///
+    /// Returns None if this Almanac has no UT1 source or if it does not cover this epoch.
+    pub fn tdb_minus_ut1(&self, epoch: Epoch) -> Option<Duration> {
+        let tai_minus_ut1 = self.ut1_provider.as_ref()?.tai_minus_ut1(epoch)?;
+        // Both readings are counted from J2000 noon, since the TDB durations are.
+        let tai_past_j2000 = epoch.to_time_scale(TimeScale::TAI)
+            - Epoch::from_gregorian_tai_hms(2000, 1, 1, 12, 0, 0);
+        Some(epoch.to_tdb_duration() - tai_past_j2000 + tai_minus_ut1)
+    }
+}
+
+#[cfg(test)]
+mod ut_ut1 {
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME};
+    use crate::constants::orientations::J2000;
+    use crate::prelude::Almanac;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn ut1_earth_rotation() {
+        let almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 6, 1);
+        assert!(almanac.tdb_minus_ut1(epoch).is_none());
+
+        // TAI - UT1 was about 37.1 seconds in mid 2025.
+        let with_ut1 = almanac.clone().with_ut1_provider(|epoch: Epoch| {
+            (epoch >= Epoch::from_gregorian_utc_at_midnight(2025, 1, 1))
+                .then_some(Unit::Second * 37.1)
+        });
+
+        let tdb_minus_ut1 = with_ut1.tdb_minus_ut1(epoch).unwrap();
+        assert!((tdb_minus_ut1.to_seconds() - 69.284).abs() < 2e-3);
+
+        // The prime meridian lags by the rotation of the Earth over TDB - UT1, and the pole is unchanged.
+        let tdb = almanac.rotate(IAU_EARTH_FRAME, EARTH_J2000, epoch).unwrap();
+        let ut1 = with_ut1
+            .rotate(IAU_EARTH_FRAME, EARTH_J2000, epoch)
+            .unwrap();
+        assert_eq!(ut1.from, tdb.from);
+        assert_eq!(ut1.to, J2000);
+        assert!((ut1.rot_mat.column(2) - tdb.rot_mat.column(2)).norm() < 1e-12);
+        let lag_deg = tdb
+            .rot_mat
+            .column(0)
+            .angle(&ut1.rot_mat.column(0))
+            .to_degrees();
+        let expected_deg = 360.9856235 * tdb_minus_ut1.to_unit(Unit::Day);
+        assert!((lag_deg - expected_deg).abs() < 1e-6, "{lag_deg} deg");
+
+        // Same as evaluating the TDB model at the epoch whose TDB reading is the UT1 reading.
+        let shifted = almanac
+            .rotate(IAU_EARTH_FRAME, EARTH_J2000, epoch - tdb_minus_ut1)
+            .unwrap();
+        assert!((ut1.rot_mat.column(0) - shifted.rot_mat.column(0)).norm() < 1e-9);
+        assert!((ut1.rot_mat_dt.unwrap() - shifted.rot_mat_dt.unwrap()).norm() < 1e-12);
+
+        // Epochs outside of the UT1 data cannot be rotated.
+        assert!(with_ut1
+            .rotate(
+                IAU_EARTH_FRAME,
+                EARTH_J2000,
+                Epoch::from_gregorian_utc_at_midnight(2024, 6, 1)
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,263 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use core::hint::black_box;
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::constants::orientations::J2000;
+use crate::errors::{AlmanacError, AlmanacResult, EphemerisSnafu, OrientationSnafu};
+use crate::naif::daf::{NAIFSummaryRecord, DAF};
+use crate::prelude::Frame;
+use crate::{NaifId, DBL_SIZE};
+
+/// Stride at which the data of the segments is touched, i.e. the size of a memory page on most platforms.
+const PAGE_SIZE: usize = 4096;
+
+/// Summary of the work done by [Almanac::warm_up].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WarmUpReport {
+    /// Number of SPK and BPC segments whose data was touched, counted once per frame using them
+    pub segments_touched: usize,
+    /// Number of bytes of segment data touched
+    pub bytes_touched: usize,
+    /// Number of translations and rotations evaluated
+    pub evaluations: usize,
+}
+
+impl fmt::Display for WarmUpReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "touched {} segments ({} B), evaluated {} translations and rotations",
+            self.segments_touched, self.bytes_touched, self.evaluations
+        )
+    }
+}
+
+/// Touches the data of the segments of the provided IDs which overlap the time span, and adds the middle of each overlap to the
+/// epochs to evaluate.
+fn touch_segments<R: NAIFSummaryRecord>(
+    daf: &DAF<R>,
+    ids: &[NaifId],
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    epochs: &mut Vec<Epoch>,
+    report: &mut WarmUpReport,
+) {
+    for summaries in daf.iter_summary_blocks().flatten() {
+        for summary in summaries {
+            if summary.is_empty()
+                || !ids.contains(&summary.id())
+                || summary.start_epoch() > end_epoch
+                || summary.end_epoch() < start_epoch
+            {
+                continue;
+            }
+
+            let start = (summary.start_index() - 1) * DBL_SIZE;
+            let end = summary.end_index() * DBL_SIZE;
+            if let Some(data) = daf.bytes.get(start..end) {
+                // Read one byte per page, and the last one, so that every page of the segment is resident.
+                let checksum = data
+                    .iter()
+                    .step_by(PAGE_SIZE)
+                    .chain(data.last())
+                    .fold(0_u8, |acc, byte| acc ^ byte);
+                black_box(checksum);
+                report.segments_touched += 1;
+                report.bytes_touched += data.len();
+            }
+
+            let overlap_start = summary.start_epoch().max(start_epoch);
+            let overlap_end = summary.end_epoch().min(end_epoch);
+            epochs.push(overlap_start + (overlap_end - overlap_start) * 0.5);
+        }
+    }
+}
+
+impl Almanac {
+    /// Prepares this Almanac for latency sensitive queries of the provided frames over the provided time span, e.g. before starting
+    /// a real-time loop, so that the first queries of the loop are not slower than the following ones.
+    ///
+    /// For each frame, this touches every memory page of the SPK and BPC segments on its ephemeris and orientation paths which
+    /// overlap the time span, and evaluates its translation to the ephemeris root and its rotation to J2000 at both bounds of the
+    /// time span and in the middle of each of these segments. Any error of these evaluations is returned, which also checks that
+    /// the loaded data covers the frames before the loop starts.
+    ///
+    /// # Notes
+    /// + The paths are those at the bounds of the time span: segments only used in the middle of the span are touched if they
+    ///   share an ID with the paths at the bounds, which is the case unless the centers of the frames change within the span.
+    /// + This does not lock the pages in memory: under memory pressure, the operating system may still swap them out.
+    pub fn warm_up(
+        &self,
+        frames: &[Frame],
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AlmanacResult<WarmUpReport> {
+        if start_epoch > end_epoch {
+            return Err(AlmanacError::GenericError {
+                err: format!("warm up start epoch {start_epoch} is after end epoch {end_epoch}"),
+            });
+        }
+
+        let mut report = WarmUpReport::default();
+
+        let ephemeris_root = if self.num_loaded_spk() > 0 {
+            Some(self.try_find_ephemeris_root().context(EphemerisSnafu {
+                action: "warming up the ephemeris root",
+            })?)
+        } else {
+            None
+        };
+
+        for frame in frames {
+            let mut ephemeris_ids = Vec::new();
+            let mut orientation_ids = Vec::new();
+
+            for epoch in [start_epoch, end_epoch] {
+                let resolved = self.resolve_frame_switch(*frame, epoch);
+
+                if ephemeris_root.is_some() {
+                    let (len, path) =
+                        self.ephemeris_path_to_root(resolved, epoch)
+                            .context(EphemerisSnafu {
+                                action: "warming up the ephemeris path",
+                            })?;
+                    ephemeris_ids.push(resolved.ephemeris_id);
+                    ephemeris_ids.extend(path.iter().take(len).flatten());
+                }
+
+                if self.num_loaded_bpc() > 0 && resolved.orientation_id != J2000 {
+                    let (len, path) = self.orientation_path_to_root(resolved, epoch).context(
+                        OrientationSnafu {
+                            action: "warming up the orientation path",
+                        },
+                    )?;
+                    orientation_ids.push(resolved.orientation_id);
+                    orientation_ids.extend(path.iter().take(len).flatten());
+                }
+            }
+
+            let mut epochs = vec![start_epoch, end_epoch];
+            for spk in self.spk_data.values() {
+                touch_segments(
+                    spk,
+                    &ephemeris_ids,
+                    start_epoch,
+                    end_epoch,
+                    &mut epochs,
+                    &mut report,
+                );
+            }
+            for bpc in self.bpc_data.values() {
+                touch_segments(
+                    bpc,
+                    &orientation_ids,
+                    start_epoch,
+                    end_epoch,
+                    &mut epochs,
+                    &mut report,
+                );
+            }
+            epochs.sort();
+            epochs.dedup();
+
+            for epoch in epochs {
+                if let Some(root) = ephemeris_root {
+                    self.translate(*frame, Frame::new(root, frame.orientation_id), epoch, None)
+                        .context(EphemerisSnafu {
+                            action: "warming up translation",
+                        })?;
+                    report.evaluations += 1;
+                }
+
+                if frame.orientation_id != J2000 {
+                    self.rotate(*frame, frame.with_orient(J2000), epoch)
+                        .context(OrientationSnafu {
+                            action: "warming up rotation",
+                        })?;
+                    report.evaluations += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod ut_warm_up {
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000};
+    use crate::errors::AlmanacError;
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn warm_up_paths() {
+        // Fixed position with respect to the center, over the day starting at the provided TDB seconds past J2000
+        let fixed = |target_id, center_id, start_s| {
+            chebyshev_segment(
+                target_id,
+                center_id,
+                start_s,
+                86_400.0,
+                &[[[1000.0, 0.0], [0.0; 2], [0.0; 2]]],
+            )
+        };
+        let spk = test_spk(&[
+            fixed(3, 0, 0.0),
+            fixed(3, 0, 86_400.0),
+            fixed(399, 3, 0.0),
+            fixed(399, 3, 86_400.0),
+            fixed(301, 3, 0.0),
+            fixed(301, 3, 86_400.0),
+            fixed(-85, 399, 0.0),
+        ]);
+        let almanac = Almanac::default().with_spk(spk);
+
+        let start = Epoch::from_et_seconds(3_600.0);
+        let end = start + Unit::Hour * 2;
+
+        // The Earth uses the first segments of the Earth and of the EMB
+        let report = almanac.warm_up(&[EARTH_J2000], start, end).unwrap();
+        assert_eq!(report.segments_touched, 2);
+        assert_eq!(report.bytes_touched, 2 * 12 * 8);
+        // Both bounds, and the middle of the time span for both segments
+        assert_eq!(report.evaluations, 3);
+        println!("{report}");
+
+        let report = almanac
+            .warm_up(&[EARTH_J2000, MOON_J2000], start, end + Unit::Day * 1)
+            .unwrap();
+        // Both segments of the EMB are touched for each frame
+        assert_eq!(report.segments_touched, 4 + 4);
+
+        // Spacecraft data only covers the first day
+        assert!(almanac
+            .warm_up(&[Frame::from_ephem_j2000(-85)], start, end)
+            .is_ok());
+        assert!(almanac
+            .warm_up(&[Frame::from_ephem_j2000(-85)], start, end + Unit::Day * 1)
+            .is_err());
+
+        assert!(matches!(
+            almanac.warm_up(&[EARTH_J2000], end, start),
+            Err(AlmanacError::GenericError { .. })
+        ));
+        assert_eq!(
+            almanac.warm_up(&[], start, end).unwrap(),
+            Default::default()
+        );
+    }
+}
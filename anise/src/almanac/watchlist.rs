@@ -0,0 +1,316 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch, TimeSeries, Unit};
+use serde_derive::{Deserialize, Serialize};
+use snafu::ResultExt;
+
+use super::Almanac;
+use crate::astro::Aberration;
+use crate::constants::frames::SUN_J2000;
+use crate::errors::{AlmanacResult, EphemerisSnafu};
+use crate::prelude::Frame;
+
+/// Frames of interest whose geometry, as seen from an observer, is summarized every day, e.g. for automated operations reports.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "analysis", derive(Serialize, Deserialize))]
+pub struct Watchlist {
+    pub observer: Frame,
+    pub targets: Vec<Frame>,
+    /// Bodies which may eclipse the targets from the Sun, which must include their shape or have it in the loaded planetary data
+    pub eclipsing_bodies: Vec<Frame>,
+    /// Sampling step of the summaries, defaults to ten minutes
+    pub step: Duration,
+    pub ab_corr: Option<Aberration>,
+}
+
+impl Watchlist {
+    /// Initializes an empty watchlist for the provided observer.
+    pub fn new(observer: Frame) -> Self {
+        Self {
+            observer,
+            targets: Vec::new(),
+            eclipsing_bodies: Vec::new(),
+            step: Unit::Minute * 10,
+            ab_corr: None,
+        }
+    }
+
+    /// Adds a target to this watchlist.
+    pub fn with_target(mut self, target: Frame) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// Adds a body which may eclipse the targets from the Sun.
+    pub fn with_eclipsing_body(mut self, body: Frame) -> Self {
+        self.eclipsing_bodies.push(body);
+        self
+    }
+
+    /// Sets the sampling step of the summaries.
+    pub fn with_step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the aberration correction of all of the computations.
+    pub fn with_aberration(mut self, ab_corr: Option<Aberration>) -> Self {
+        self.ab_corr = ab_corr;
+        self
+    }
+}
+
+/// Summary of the geometry of a target of a watchlist over a time span.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TargetSummary {
+    pub target: Frame,
+    pub min_distance_km: f64,
+    pub min_distance_epoch: Epoch,
+    pub max_distance_km: f64,
+    pub max_distance_epoch: Epoch,
+    /// Smallest Sun-target-observer angle, NaN if the target is the Sun
+    pub min_phase_angle_deg: f64,
+    /// Largest Sun-target-observer angle, NaN if the target is the Sun
+    pub max_phase_angle_deg: f64,
+    /// Set if the center of the target was partially eclipsed from the Sun by any of the eclipsing bodies at any sample
+    pub penumbra: bool,
+    /// Set if the center of the target was fully eclipsed from the Sun by any of the eclipsing bodies at any sample
+    pub umbra: bool,
+    /// Time spent at least partially eclipsed, to the precision of the sampling step
+    pub eclipse_duration: Duration,
+}
+
+/// Summary of all of the targets of a watchlist over a time span, in the order of the watchlist.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WatchlistSummary {
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+    pub observer: Frame,
+    pub targets: Vec<TargetSummary>,
+}
+
+impl Almanac {
+    /// Summarizes the geometry of each target of the watchlist over the provided time span: its distance to the observer, its phase
+    /// angle (i.e. the Sun-target-observer angle), and whether it was eclipsed from the Sun by any of the eclipsing bodies.
+    ///
+    /// The time span is sampled at the step of the watchlist, including both bounds, so the extrema are precise to that step.
+    pub fn watchlist_summary(
+        &self,
+        watchlist: &Watchlist,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AlmanacResult<WatchlistSummary> {
+        let mut targets = Vec::with_capacity(watchlist.targets.len());
+
+        for target in &watchlist.targets {
+            let mut summary = TargetSummary {
+                target: *target,
+                min_distance_km: f64::INFINITY,
+                min_distance_epoch: start_epoch,
+                max_distance_km: f64::NEG_INFINITY,
+                max_distance_epoch: start_epoch,
+                min_phase_angle_deg: f64::NAN,
+                max_phase_angle_deg: f64::NAN,
+                penumbra: false,
+                umbra: false,
+                eclipse_duration: Duration::ZERO,
+            };
+            let is_sun = target.ephem_origin_id_match(SUN_J2000.ephemeris_id);
+
+            for epoch in TimeSeries::inclusive(start_epoch, end_epoch, watchlist.step) {
+                let state =
+                    self.transform(*target, watchlist.observer, epoch, watchlist.ab_corr)?;
+
+                let distance_km = state.rmag_km();
+                if distance_km < summary.min_distance_km {
+                    summary.min_distance_km = distance_km;
+                    summary.min_distance_epoch = epoch;
+                }
+                if distance_km > summary.max_distance_km {
+                    summary.max_distance_km = distance_km;
+                    summary.max_distance_epoch = epoch;
+                }
+
+                if is_sun {
+                    continue;
+                }
+
+                let to_sun = self
+                    .translate(SUN_J2000, *target, epoch, watchlist.ab_corr)
+                    .context(EphemerisSnafu {
+                        action: "computing watchlist phase angle",
+                    })?;
+                let phase_angle_deg = to_sun
+                    .r_hat()
+                    .dot(&(-state.r_hat()))
+                    .clamp(-1.0, 1.0)
+                    .acos()
+                    .to_degrees();
+                // f64::min and f64::max ignore NaN, so the first sample initializes both extrema.
+                summary.min_phase_angle_deg = summary.min_phase_angle_deg.min(phase_angle_deg);
+                summary.max_phase_angle_deg = summary.max_phase_angle_deg.max(phase_angle_deg);
+
+                let mut eclipsed = false;
+                for body in &watchlist.eclipsing_bodies {
+                    if body.ephem_origin_match(*target) {
+                        continue;
+                    }
+                    let occultation = self.solar_eclipsing(*body, state, watchlist.ab_corr)?;
+                    if occultation.is_obstructed() {
+                        summary.umbra = true;
+                    } else if occultation.is_partial() {
+                        summary.penumbra = true;
+                    }
+                    eclipsed |= !occultation.is_visible();
+                }
+                if eclipsed && epoch < end_epoch {
+                    summary.eclipse_duration += watchlist.step.min(end_epoch - epoch);
+                }
+            }
+
+            targets.push(summary);
+        }
+
+        Ok(WatchlistSummary {
+            start_epoch,
+            end_epoch,
+            observer: watchlist.observer,
+            targets,
+        })
+    }
+
+    /// Summarizes the watchlist over the day starting at the provided epoch, cf. `watchlist_summary`.
+    pub fn watchlist_daily_summary(
+        &self,
+        watchlist: &Watchlist,
+        day_start: Epoch,
+    ) -> AlmanacResult<WatchlistSummary> {
+        self.watchlist_summary(watchlist, day_start, day_start + Unit::Day * 1)
+    }
+}
+
+impl fmt::Display for TargetSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:e}: range {:.3} km ({}) to {:.3} km ({}), phase {:.3} to {:.3} deg",
+            self.target,
+            self.min_distance_km,
+            self.min_distance_epoch,
+            self.max_distance_km,
+            self.max_distance_epoch,
+            self.min_phase_angle_deg,
+            self.max_phase_angle_deg
+        )?;
+        if self.umbra {
+            write!(f, ", UMBRA")?;
+        } else if self.penumbra {
+            write!(f, ", PENUMBRA")?;
+        }
+        if self.eclipse_duration > Duration::ZERO {
+            write!(f, " ({})", self.eclipse_duration)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for WatchlistSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Watchlist of {:x} from {} to {}",
+            self.observer, self.start_epoch, self.end_epoch
+        )?;
+        for target in &self.targets {
+            write!(f, "\n  {target}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod ut_watchlist {
+    use super::Watchlist;
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000, SUN_J2000, VENUS_J2000};
+    use crate::naif::daf::test_utils::{fixed_segment, linear_segment, test_spk};
+    use crate::prelude::Almanac;
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use crate::structure::planetocentric::PlanetaryData;
+    use crate::structure::PlanetaryDataSet;
+    use hifitime::{Epoch, Unit};
+
+    const AU_KM: f64 = 1.5e8;
+
+    #[test]
+    fn daily_summary() {
+        let mut dataset = PlanetaryDataSet::default();
+        for (id, radius_km) in [(10, 695_700.0), (399, 6378.0)] {
+            let data = PlanetaryData {
+                object_id: id,
+                shape: Some(Ellipsoid::from_sphere(radius_km)),
+                ..Default::default()
+            };
+            dataset.push(data, Some(id), None).unwrap();
+        }
+
+        // The Sun at the SSB, the Earth one "AU" away, the full Moon behind it moving away, and Venus at quadrature.
+        let almanac = Almanac::default()
+            .with_spk(test_spk(&[
+                fixed_segment(10, 0, [0.0; 3]),
+                fixed_segment(399, 0, [AU_KM, 0.0, 0.0]),
+                linear_segment(
+                    301,
+                    0,
+                    [AU_KM + 384_400.0, 0.0, 0.0],
+                    [1000.0 / 43_200.0, 0.0, 0.0],
+                ),
+                fixed_segment(2, 0, [0.0, AU_KM, 0.0]),
+            ]))
+            .with_planetary_data(dataset);
+
+        let watchlist = Watchlist::new(EARTH_J2000)
+            .with_target(MOON_J2000)
+            .with_target(VENUS_J2000)
+            .with_target(SUN_J2000)
+            .with_eclipsing_body(EARTH_J2000)
+            .with_step(Unit::Hour * 1);
+
+        let start = Epoch::from_et_seconds(0.0);
+        let summary = almanac.watchlist_daily_summary(&watchlist, start).unwrap();
+        assert_eq!(summary.end_epoch, start + Unit::Day * 1);
+        assert_eq!(summary.targets.len(), 3);
+
+        let moon = &summary.targets[0];
+        assert!((moon.min_distance_km - 383_400.0).abs() < 1e-6);
+        assert_eq!(moon.min_distance_epoch, start);
+        assert!((moon.max_distance_km - 385_400.0).abs() < 1e-6);
+        assert_eq!(moon.max_distance_epoch, summary.end_epoch);
+        assert!(moon.max_phase_angle_deg.abs() < 1e-6);
+        assert!(moon.umbra && !moon.penumbra);
+        assert_eq!(moon.eclipse_duration, Unit::Day * 1);
+
+        let venus = &summary.targets[1];
+        assert!((venus.min_distance_km - 2.0_f64.sqrt() * AU_KM).abs() < 1e-6);
+        assert!((venus.min_phase_angle_deg - 45.0).abs() < 1e-9);
+        assert!((venus.max_phase_angle_deg - 45.0).abs() < 1e-9);
+        assert!(!venus.umbra && !venus.penumbra);
+
+        let sun = &summary.targets[2];
+        assert!((sun.min_distance_km - AU_KM).abs() < 1e-6);
+        assert!(sun.min_phase_angle_deg.is_nan());
+
+        let report = format!("{summary}");
+        assert!(report.starts_with("Watchlist of Earth J2000 from"));
+        assert!(report.contains("\n  Moon: range 383400.000 km"));
+        assert!(report.contains("UMBRA (1 day)"));
+    }
+}
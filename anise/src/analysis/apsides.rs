@@ -0,0 +1,179 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+
+use super::event::{Condition, Event, EventEdge};
+use super::expr::ScalarExpr;
+use super::specs::{FrameSpec, StateSpec};
+use super::vector_expr::VectorExpr;
+use super::AnalysisResult;
+use crate::almanac::Almanac;
+use crate::prelude::{Frame, Orbit};
+
+/// Kind of an apsis passage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ApsisKind {
+    /// Closest point to the center body, where the radial rate changes from negative to positive
+    Periapsis,
+    /// Farthest point from the center body, where the radial rate changes from positive to negative
+    Apoapsis,
+}
+
+/// A periapsis or apoapsis passage of a target about its center body, as found by [Almanac::report_apsides].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ApsisPassage {
+    pub kind: ApsisKind,
+    /// State of the target with respect to the center body at the passage
+    pub orbit: Orbit,
+}
+
+impl ApsisPassage {
+    pub fn epoch(&self) -> Epoch {
+        self.orbit.epoch
+    }
+
+    /// Returns the distance between the target and the center body at the passage.
+    pub fn radius_km(&self) -> f64 {
+        self.orbit.rmag_km()
+    }
+}
+
+impl fmt::Display for ApsisPassage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at {}: {:.6} km",
+            self.kind,
+            self.epoch(),
+            self.radius_km()
+        )
+    }
+}
+
+impl Event {
+    /// Radial rate event finder of the provided state specification, in km/s: its zero crossings are the apsis passages of the target
+    /// about the observer, which are rising edges at periapsis and falling edges at apoapsis.
+    ///
+    /// Unlike [Event::periapsis] and [Event::apoapsis], this is purely geometric: it does not need the gravitational parameter of the
+    /// observer frame, and applies to any trajectory, including those which are not Keplerian.
+    pub fn radial_rate_zero(state_spec: StateSpec) -> Self {
+        Self::new(
+            ScalarExpr::DotProduct {
+                a: VectorExpr::Unit(Box::new(VectorExpr::Radius(state_spec.clone()))),
+                b: VectorExpr::Velocity(state_spec),
+            },
+            Condition::Equals(0.0),
+        )
+    }
+}
+
+impl Almanac {
+    /// Reports the periapsis and apoapsis passages of the target about the center body between the start and end epochs, sorted
+    /// chronologically, by searching the sign changes of the radial rate of the target with the event finder, cf. [Event::radial_rate_zero].
+    ///
+    /// Passages closer than the epoch precision of the event finder (10 ms) to the bounds of the search window may be missed.
+    pub fn report_apsides(
+        &self,
+        target_frame: Frame,
+        center_frame: Frame,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<ApsisPassage>> {
+        let state_spec = StateSpec {
+            target_frame: FrameSpec::Loaded(target_frame),
+            observer_frame: FrameSpec::Loaded(center_frame),
+            ab_corr: None,
+        };
+        let event = Event::radial_rate_zero(state_spec.clone());
+
+        Ok(self
+            .report_events(&state_spec, &event, start_epoch, end_epoch)?
+            .into_iter()
+            .filter_map(|details| {
+                let kind = match details.edge {
+                    EventEdge::Rising => ApsisKind::Periapsis,
+                    EventEdge::Falling => ApsisKind::Apoapsis,
+                    // The radial rate touches zero without changing sign, e.g. on a circular orbit.
+                    _ => return None,
+                };
+                Some(ApsisPassage {
+                    kind,
+                    orbit: details.orbit,
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod ut_apsides {
+    use super::ApsisKind;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn report_apsides() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        // Molniya orbit, starting a quarter of an orbit after periapsis.
+        let orbit =
+            Orbit::try_keplerian(26_600.0, 0.74, 63.4, 10.0, 270.0, 120.0, epoch, frame).unwrap();
+        let period = orbit.period().unwrap();
+
+        let mut ephem = Ephemeris::new("molniya".to_string());
+        for minute in 0..=(26 * 60) {
+            ephem.insert_orbit(
+                orbit
+                    .propagate_to_epoch(epoch + Unit::Minute * minute)
+                    .unwrap(),
+            );
+        }
+        let almanac = Almanac::default().with_spk(
+            ephem
+                .to_spice_bsp(-100, Some(DafDataType::Type13HermiteUnequalStep))
+                .unwrap(),
+        );
+
+        let apsides = almanac
+            .report_apsides(
+                Frame::from_ephem_j2000(-100),
+                EARTH_J2000,
+                epoch,
+                epoch + Unit::Hour * 25,
+            )
+            .unwrap();
+        for apsis in &apsides {
+            println!("{apsis}");
+        }
+
+        // Two revolutions of about 12 hours: apoapsis, periapsis, apoapsis, periapsis.
+        assert_eq!(apsides.len(), 4);
+        let rp_km = 26_600.0 * (1.0 - 0.74);
+        let ra_km = 26_600.0 * (1.0 + 0.74);
+        let first_periapsis = epoch + period * (1.0 - orbit.ma_deg().unwrap() / 360.0);
+        for (k, apsis) in apsides.iter().enumerate() {
+            if k % 2 == 0 {
+                assert_eq!(apsis.kind, ApsisKind::Apoapsis);
+                assert!((apsis.radius_km() - ra_km).abs() < 1e-3, "{apsis}");
+            } else {
+                assert_eq!(apsis.kind, ApsisKind::Periapsis);
+                assert!((apsis.radius_km() - rp_km).abs() < 1e-3, "{apsis}");
+            }
+            assert!(apsis.orbit.radius_km.dot(&apsis.orbit.velocity_km_s).abs() < 1e-2);
+        }
+        assert!((apsides[1].epoch() - first_periapsis).abs() < Unit::Second * 1);
+        assert!((apsides[3].epoch() - apsides[1].epoch() - period).abs() < Unit::Second * 1);
+    }
+}
@@ -739,6 +739,35 @@ impl VisibilityArc {
         self.fall.orbit.epoch
     }
 
+    /// Returns the culmination of this pass, i.e. the epoch and value of the maximum elevation in degrees, if any AER data is available.
+    /// The maximum is refined by fitting a parabola through the highest AER sample and its two neighbors.
+    ///
+    /// :rtype: typing.Tuple
+    pub fn culmination(&self) -> Option<(Epoch, f64)> {
+        let (k, max) = self
+            .aer_data
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.elevation_deg.total_cmp(&b.elevation_deg))?;
+
+        if k == 0 || k + 1 == self.aer_data.len() {
+            return Some((max.epoch, max.elevation_deg));
+        }
+
+        let y0 = self.aer_data[k - 1].elevation_deg;
+        let y1 = max.elevation_deg;
+        let y2 = self.aer_data[k + 1].elevation_deg;
+        let denom = y0 - 2.0 * y1 + y2;
+        if denom.abs() < f64::EPSILON {
+            return Some((max.epoch, max.elevation_deg));
+        }
+        // Offset of the vertex of the parabola, in number of samples from the highest sample.
+        let offset = (0.5 * (y0 - y2) / denom).clamp(-1.0, 1.0);
+        let step = self.aer_data[k + 1].epoch - max.epoch;
+
+        Some((max.epoch + offset * step, y1 - 0.25 * (y0 - y2) * offset))
+    }
+
     #[cfg(feature = "python")]
     fn __str__(&self) -> String {
         format!("{self}")
@@ -763,3 +792,74 @@ impl fmt::Display for VisibilityArc {
         )
     }
 }
+
+/// Summary of a pass of an object over a location, typically used for scheduling.
+#[cfg_attr(feature = "python", pyclass)]
+#[cfg_attr(feature = "python", pyo3(module = "anise.analysis", get_all))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PassSummary {
+    /// :rtype: str
+    pub location_ref: String,
+    /// Acquisition of signal, i.e. the object rises above the terrain and elevation masks, or the start of the search
+    /// :rtype: Epoch
+    pub aos_epoch: Epoch,
+    /// Loss of signal, i.e. the object sets below the terrain and elevation masks, or the end of the search
+    /// :rtype: Epoch
+    pub los_epoch: Epoch,
+    /// :rtype: Duration
+    pub duration: Duration,
+    /// :rtype: float
+    pub aos_azimuth_deg: f64,
+    /// :rtype: float
+    pub los_azimuth_deg: f64,
+    /// Epoch of the maximum elevation
+    /// :rtype: Epoch
+    pub culmination_epoch: Epoch,
+    /// :rtype: float
+    pub max_elevation_deg: f64,
+    /// Smallest angle between the object and the Sun as seen from the location
+    /// :rtype: float
+    pub min_sun_separation_deg: f64,
+    /// Set if the Sun was ever within the exclusion angle of the object during the pass
+    /// :rtype: bool
+    pub sun_interference: bool,
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl PassSummary {
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+    fn __repr__(&self) -> String {
+        format!("{self}@{self:p}")
+    }
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+    fn __ne__(&self, other: &Self) -> bool {
+        self != other
+    }
+}
+
+impl fmt::Display for PassSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: AOS {} (az. {:.3} deg)    LOS {} (az. {:.3} deg)    max el. {:.3} deg at {}    ({}){}",
+            self.location_ref,
+            self.aos_epoch,
+            self.aos_azimuth_deg,
+            self.los_epoch,
+            self.los_azimuth_deg,
+            self.max_elevation_deg,
+            self.culmination_epoch,
+            self.duration,
+            if self.sun_interference {
+                " SUN INTERFERENCE"
+            } else {
+                ""
+            }
+        )
+    }
+}
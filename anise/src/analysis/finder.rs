@@ -0,0 +1,275 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::{Duration, Epoch};
+use snafu::ResultExt;
+
+use super::{
+    event::Condition,
+    utils::{adaptive_step_scanner, brent_solver},
+    AlmanacFunctionSnafu, AnalysisError, AnalysisResult, Event, ScalarExpr,
+};
+use crate::almanac::Almanac;
+use crate::errors::AlmanacResult;
+
+impl Almanac {
+    /// Searches for the epochs when the provided scalar function of the epoch equals a value, or reaches a local minimum or maximum,
+    /// between the start and end epochs. This is the equivalent of the SPICE `gfuds` routine.
+    ///
+    /// Unlike [Almanac::report_events], the function may be any computation derived from Almanac queries (e.g. a separation angle,
+    /// a distance, an elevation), and it is provided the Almanac on which this search is called. The same adaptive step scanner and
+    /// Brent solver are used, so the epoch precision drives both the convergence and the scanning step. The function is assumed
+    /// to be continuous: for angles which wrap around, return a continuous function of the angle instead (e.g. its sine).
+    ///
+    /// This method may only be used for equality, minimum, and maximum conditions. For spanned conditions, use [Almanac::find_event_arcs].
+    pub fn find_events<F>(
+        &self,
+        function: F,
+        condition: Condition,
+        epoch_precision: Duration,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<Epoch>>
+    where
+        F: Fn(&Almanac, Epoch) -> AlmanacResult<f64>,
+    {
+        let eval = |epoch: Epoch| function(self, epoch).context(AlmanacFunctionSnafu { epoch });
+
+        match condition {
+            Condition::Equals(value) => {
+                let event = function_event(Condition::Equals(value), epoch_precision);
+                let f_eval = |epoch: Epoch| Ok(eval(epoch)? - value);
+
+                Ok(find_roots(f_eval, &event, start_epoch, end_epoch)?)
+            }
+            Condition::Minimum() | Condition::Maximum() => {
+                let event = function_event(Condition::Equals(0.0), epoch_precision);
+                let h_tiny = epoch_precision * 10.0;
+                // Central difference, or one sided at the bounds of the data.
+                let f_deriv = |epoch: Epoch| -> AnalysisResult<f64> {
+                    match (eval(epoch + h_tiny), eval(epoch - h_tiny)) {
+                        (Ok(next), Ok(prev)) => Ok((next - prev) / (2.0 * h_tiny.to_seconds())),
+                        (Ok(next), Err(_)) => Ok((next - eval(epoch)?) / h_tiny.to_seconds()),
+                        (Err(_), Ok(prev)) => Ok((eval(epoch)? - prev) / h_tiny.to_seconds()),
+                        (Err(e), Err(_)) => Err(e),
+                    }
+                };
+
+                // The neighbors are further than the precision of the roots to classify the extrema.
+                let mut extrema = Vec::new();
+                for epoch in find_roots(f_deriv, &event, start_epoch, end_epoch)? {
+                    let value = eval(epoch)?;
+                    let prev = eval(epoch - h_tiny).ok();
+                    let next = eval(epoch + h_tiny).ok();
+                    let is_match = |neighbor: Option<f64>| match (condition, neighbor) {
+                        (Condition::Minimum(), Some(neighbor)) => value <= neighbor,
+                        (_, Some(neighbor)) => value >= neighbor,
+                        (_, None) => true,
+                    };
+                    if is_match(prev) && is_match(next) {
+                        extrema.push(epoch);
+                    }
+                }
+
+                Ok(extrema)
+            }
+            Condition::Between(..) | Condition::LessThan(..) | Condition::GreaterThan(..) => {
+                Err(AnalysisError::InvalidEventEval {
+                    err: format!(
+                        "cannot find individual events on a condition like {condition:?}, use find_event_arcs"
+                    ),
+                })
+            }
+        }
+    }
+
+    /// Searches for the time intervals when the provided scalar function of the epoch is within the range of the condition, between
+    /// the start and end epochs, cf. [Almanac::find_events]. Intervals in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// This method may only be used for less than, greater than, and between conditions. For individual events, use [Almanac::find_events].
+    pub fn find_event_arcs<F>(
+        &self,
+        function: F,
+        condition: Condition,
+        epoch_precision: Duration,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<(Epoch, Epoch)>>
+    where
+        F: Fn(&Almanac, Epoch) -> AlmanacResult<f64>,
+    {
+        // Positive when the function is within the range of the condition.
+        let f_eval = |epoch: Epoch| -> AnalysisResult<f64> {
+            let value = function(self, epoch).context(AlmanacFunctionSnafu { epoch })?;
+            match condition {
+                Condition::LessThan(max_val) => Ok(max_val - value),
+                Condition::GreaterThan(min_val) => Ok(value - min_val),
+                Condition::Between(min_val, max_val) => Ok((value - min_val).min(max_val - value)),
+                Condition::Equals(..) | Condition::Minimum() | Condition::Maximum() => {
+                    Err(AnalysisError::InvalidEventEval {
+                        err: format!(
+                            "cannot find event arcs on an individual condition like {condition:?}, use find_events"
+                        ),
+                    })
+                }
+            }
+        };
+
+        let event = function_event(Condition::Equals(0.0), epoch_precision);
+        let mut inside = f_eval(start_epoch)? >= 0.0;
+        let mut arc_start = inside.then_some(start_epoch);
+        let mut arcs = Vec::new();
+
+        // Each crossing flips the state.
+        for epoch in find_roots(f_eval, &event, start_epoch, end_epoch)? {
+            if inside {
+                if let Some(start) = arc_start.take() {
+                    arcs.push((start, epoch));
+                }
+            } else {
+                arc_start = Some(epoch);
+            }
+            inside = !inside;
+        }
+
+        if let Some(start) = arc_start {
+            arcs.push((start, end_epoch));
+        }
+
+        Ok(arcs)
+    }
+}
+
+/// Builds the event used to parametrize the scanner and the solver, which only use its condition and epoch precision.
+fn function_event(condition: Condition, epoch_precision: Duration) -> Event {
+    Event {
+        scalar: ScalarExpr::Constant(0.0),
+        condition,
+        epoch_precision,
+        ab_corr: None,
+    }
+}
+
+/// Returns the sorted epochs of the zero crossings of the evaluator.
+fn find_roots<F>(
+    evaluator: F,
+    event: &Event,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+) -> AnalysisResult<Vec<Epoch>>
+where
+    F: Fn(Epoch) -> AnalysisResult<f64>,
+{
+    let brackets = adaptive_step_scanner(&evaluator, event, start_epoch, end_epoch)?;
+    let mut roots = brackets
+        .iter()
+        .filter_map(|(start, end)| brent_solver(&evaluator, event, *start, *end).ok())
+        .collect::<Vec<Epoch>>();
+    roots.sort();
+    roots.dedup();
+
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod ut_finder {
+    use crate::analysis::prelude::Condition;
+    use crate::errors::AlmanacError;
+    use crate::prelude::Almanac;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn find_function_events() {
+        let almanac = Almanac::default();
+        let origin = Epoch::from_et_seconds(0.0);
+        // The scan is offset from the extrema, where the derivative is exactly zero by symmetry.
+        let start = origin + Unit::Second * 0.25;
+        let end = Epoch::from_et_seconds(10_000.0);
+        let precision = Unit::Millisecond * 1;
+        // Sine with a period of 4000 seconds: zeros every 2000 s, maxima at 1000 + 4000k s, and minima at 3000 + 4000k s.
+        let sine = |_: &Almanac, epoch: Epoch| {
+            Ok((2.0 * core::f64::consts::PI * (epoch - origin).to_seconds() / 4000.0).sin())
+        };
+        let close = |found: &[Epoch], expected_s: &[f64]| {
+            assert_eq!(found.len(), expected_s.len(), "{found:?}");
+            for (epoch, expected) in found.iter().zip(expected_s) {
+                assert!(
+                    (*epoch - Epoch::from_et_seconds(*expected)).abs() < Unit::Millisecond * 5,
+                    "{epoch} != {expected}"
+                );
+            }
+        };
+
+        let crossings = almanac
+            .find_events(sine, Condition::Equals(0.5), precision, start, end)
+            .unwrap();
+        // sin(x) = 0.5 at x = π/6 and 5π/6, i.e. 333.3 and 1666.7 seconds in each period.
+        close(
+            &crossings,
+            &[
+                1000.0 / 3.0,
+                5000.0 / 3.0,
+                4000.0 + 1000.0 / 3.0,
+                4000.0 + 5000.0 / 3.0,
+                8000.0 + 1000.0 / 3.0,
+                8000.0 + 5000.0 / 3.0,
+            ],
+        );
+
+        let maxima = almanac
+            .find_events(sine, Condition::Maximum(), precision, start, end)
+            .unwrap();
+        close(&maxima, &[1000.0, 5000.0, 9000.0]);
+        let minima = almanac
+            .find_events(sine, Condition::Minimum(), precision, start, end)
+            .unwrap();
+        close(&minima, &[3000.0, 7000.0]);
+
+        let arcs = almanac
+            .find_event_arcs(sine, Condition::GreaterThan(0.5), precision, start, end)
+            .unwrap();
+        assert_eq!(arcs.len(), 3);
+        close(
+            &arcs.iter().flat_map(|(a, b)| [*a, *b]).collect::<Vec<_>>(),
+            &[
+                1000.0 / 3.0,
+                5000.0 / 3.0,
+                4000.0 + 1000.0 / 3.0,
+                4000.0 + 5000.0 / 3.0,
+                8000.0 + 1000.0 / 3.0,
+                8000.0 + 5000.0 / 3.0,
+            ],
+        );
+
+        // Arcs in progress at the bounds are clipped
+        let arcs = almanac
+            .find_event_arcs(sine, Condition::Between(-0.5, 0.5), precision, start, end)
+            .unwrap();
+        assert_eq!(arcs.first().unwrap().0, start);
+        assert_eq!(arcs.len(), 6);
+        close(&[arcs[0].1], &[1000.0 / 3.0]);
+
+        // Conditions are checked, and errors of the function are propagated
+        assert!(almanac
+            .find_events(sine, Condition::LessThan(0.0), precision, start, end)
+            .is_err());
+        assert!(almanac
+            .find_event_arcs(sine, Condition::Minimum(), precision, start, end)
+            .is_err());
+        let failing = |_: &Almanac, _: Epoch| -> Result<f64, AlmanacError> {
+            Err(AlmanacError::GenericError {
+                err: "no data".to_string(),
+            })
+        };
+        assert!(almanac
+            .find_event_arcs(failing, Condition::LessThan(0.0), precision, start, end)
+            .is_err());
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use hifitime::Epoch;
+
+use super::elements::OrbitalElement;
+use super::event::{Condition, Event, EventDetails, EventEdge};
+use super::expr::ScalarExpr;
+use super::specs::{FrameSpec, StateSpec};
+use super::AnalysisResult;
+use crate::almanac::Almanac;
+use crate::prelude::Frame;
+
+impl Almanac {
+    /// Reports the crossings of the provided planetodetic latitude by the sub-point of the target on the body of the body fixed frame,
+    /// between the start and end epochs, sorted chronologically. Rising edges are northward crossings, and falling edges are southward
+    /// crossings. The shape of the body is fetched from the loaded planetary data if the body fixed frame does not define it.
+    ///
+    /// Crossings where the sub-point only touches the latitude (e.g. at the maximum latitude of the ground track) are not reported.
+    pub fn report_latitude_crossings(
+        &self,
+        target_frame: Frame,
+        body_fixed_frame: Frame,
+        latitude_deg: f64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<EventDetails>> {
+        self.report_subpoint_crossings(
+            target_frame,
+            body_fixed_frame,
+            OrbitalElement::Latitude,
+            latitude_deg,
+            start_epoch,
+            end_epoch,
+        )
+    }
+
+    /// Reports the crossings of the provided planetodetic longitude by the sub-point of the target on the body of the body fixed frame,
+    /// between the start and end epochs, sorted chronologically. Rising edges are eastward crossings, and falling edges are westward
+    /// crossings.
+    pub fn report_longitude_crossings(
+        &self,
+        target_frame: Frame,
+        body_fixed_frame: Frame,
+        longitude_deg: f64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<EventDetails>> {
+        self.report_subpoint_crossings(
+            target_frame,
+            body_fixed_frame,
+            OrbitalElement::Longitude,
+            longitude_deg,
+            start_epoch,
+            end_epoch,
+        )
+    }
+
+    fn report_subpoint_crossings(
+        &self,
+        target_frame: Frame,
+        body_fixed_frame: Frame,
+        element: OrbitalElement,
+        value_deg: f64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<EventDetails>> {
+        let body_fixed_frame = if body_fixed_frame.shape.is_some() {
+            body_fixed_frame
+        } else {
+            self.frame_info(body_fixed_frame)
+                .unwrap_or(body_fixed_frame)
+        };
+
+        let state_spec = StateSpec {
+            target_frame: FrameSpec::Loaded(target_frame),
+            observer_frame: FrameSpec::Loaded(body_fixed_frame),
+            ab_corr: None,
+        };
+        let event = Event::new(ScalarExpr::Element(element), Condition::Equals(value_deg));
+
+        Ok(self
+            .report_events(&state_spec, &event, start_epoch, end_epoch)?
+            .into_iter()
+            // The angular difference wraps at the opposite angle, e.g. at the antimeridian of the longitude, which is not a crossing.
+            .filter(|details| {
+                matches!(details.edge, EventEdge::Rising | EventEdge::Falling)
+                    && details.value.abs() < 90.0
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod ut_latlong {
+    use crate::analysis::event::EventEdge;
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME};
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn report_latlong_crossings() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let orbit =
+            Orbit::try_keplerian(7000.0, 0.001, 51.6, 40.0, 30.0, 0.0, epoch, frame).unwrap();
+
+        let mut ephem = Ephemeris::new("leo".to_string());
+        for minute in 0..=300 {
+            ephem.insert_orbit(
+                orbit
+                    .propagate_to_epoch(epoch + Unit::Minute * minute)
+                    .unwrap(),
+            );
+        }
+        let almanac = Almanac::default()
+            .load("../data/pck08.pca")
+            .unwrap()
+            .with_spk(
+                ephem
+                    .to_spice_bsp(-100, Some(DafDataType::Type13HermiteUnequalStep))
+                    .unwrap(),
+            );
+        let sc = Frame::from_ephem_j2000(-100);
+        let end = epoch + Unit::Minute * 295;
+
+        // About three revolutions starting just south of 30 degrees North, which the ground track crosses northward then southward.
+        let crossings = almanac
+            .report_latitude_crossings(sc, IAU_EARTH_FRAME, 30.0, epoch, end)
+            .unwrap();
+        assert_eq!(crossings.len(), 7);
+        for (k, crossing) in crossings.iter().enumerate() {
+            println!("{crossing}");
+            let expected = if k % 2 == 0 {
+                EventEdge::Rising
+            } else {
+                EventEdge::Falling
+            };
+            assert_eq!(crossing.edge, expected);
+            assert!((crossing.orbit.latitude_deg().unwrap() - 30.0).abs() < 1e-3);
+        }
+        // The maximum latitude is only touched.
+        assert!(almanac
+            .report_latitude_crossings(sc, IAU_EARTH_FRAME, 60.0, epoch, end)
+            .unwrap()
+            .is_empty());
+
+        // The prograde ground track moves eastward, and only crosses each meridian once per revolution, excluding its antimeridian.
+        let crossings = almanac
+            .report_longitude_crossings(sc, IAU_EARTH_FRAME, -170.0, epoch, end)
+            .unwrap();
+        assert_eq!(crossings.len(), 3);
+        for crossing in &crossings {
+            println!("{crossing}");
+            assert_eq!(crossing.edge, EventEdge::Rising);
+            assert!((crossing.orbit.longitude_deg() + 170.0).abs() < 1e-3);
+        }
+    }
+}
@@ -20,12 +20,17 @@ use rayon::prelude::*;
 use snafu::prelude::*;
 use std::collections::HashMap;
 
+pub mod apsides;
 pub mod dcm_expr;
 pub mod elements;
 pub mod event;
 pub mod event_ops;
 pub mod expr;
+pub mod finder;
+pub mod latlong;
+pub mod nodes;
 pub mod report;
+pub mod schedule;
 pub mod search;
 pub mod specs;
 pub mod vector_expr;
@@ -43,12 +48,17 @@ use vector_expr::VectorExpr;
 pub mod python;
 
 pub mod prelude {
+    pub use super::apsides::{ApsisKind, ApsisPassage};
     pub use super::dcm_expr::DcmExpr;
     pub use super::elements::OrbitalElement;
-    pub use super::event::{Condition, Event, EventArc, EventDetails, EventEdge, VisibilityArc};
+    pub use super::event::{
+        Condition, Event, EventArc, EventDetails, EventEdge, PassSummary, VisibilityArc,
+    };
     pub use super::event_ops::find_arc_intersections;
     pub use super::expr::ScalarExpr;
-    pub use super::report::{ReportScalars, ScalarsTable};
+    pub use super::nodes::{NodeCrossing, NodeKind};
+    pub use super::report::{OutputUnits, ReportScalars, ScalarsTable};
+    pub use super::schedule::{ScheduledActivity, SimpleSchedule};
     pub use super::specs::{FrameSpec, Plane, StateSpec, StateSpecTrait};
     pub use super::vector_expr::VectorExpr;
     pub use crate::prelude::Frame;
@@ -118,6 +128,14 @@ pub enum AnalysisError {
         #[snafu(source(from(AlmanacError, Box::new)))]
         source: Box<AlmanacError>,
     },
+    #[snafu(display(
+        "evaluating the search function at {epoch} encountered an Almanac error {source}"
+    ))]
+    AlmanacFunction {
+        epoch: Epoch,
+        #[snafu(source(from(AlmanacError, Box::new)))]
+        source: Box<AlmanacError>,
+    },
     #[snafu(display("{err}"))]
     GenericAnalysisError { err: String },
 }
@@ -972,6 +990,35 @@ mod ut_analysis {
             );
         }
 
+        // Pass summaries match the visibility arcs, with the culmination inside the pass.
+        let passes = almanac
+            .report_passes(
+                &lro_state_spec,
+                2,
+                start_epoch,
+                start_epoch + Unit::Day * 3,
+                Unit::Minute * 5,
+                None,
+                5.0,
+                0.0,
+            )
+            .unwrap();
+        assert_eq!(passes.len(), vis_arcs.len());
+        for (pass, arc) in passes.iter().zip(&vis_arcs) {
+            println!("{pass}");
+            assert_eq!(pass.aos_epoch, arc.start_epoch());
+            assert_eq!(pass.los_epoch, arc.end_epoch());
+            assert!(pass.culmination_epoch > pass.aos_epoch);
+            assert!(pass.culmination_epoch < pass.los_epoch);
+            let max_sampled = arc
+                .aer_data
+                .iter()
+                .map(|aer| aer.elevation_deg)
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert!(pass.max_elevation_deg >= max_sampled);
+            assert_eq!(pass.sun_interference, pass.min_sun_separation_deg < 5.0);
+        }
+
         // Test for a condition that is always met.
         let fpa_always_lt = Event {
             scalar: ScalarExpr::Element(OrbitalElement::FlightPathAngle),
@@ -0,0 +1,217 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+
+use super::event::{Condition, Event, EventEdge};
+use super::expr::ScalarExpr;
+use super::specs::{FrameSpec, StateSpec};
+use super::vector_expr::VectorExpr;
+use super::{AnalysisError, AnalysisResult};
+use crate::almanac::Almanac;
+use crate::math::{angles::between_pm_180, Vector3};
+use crate::prelude::{Frame, Orbit};
+
+/// Kind of a node crossing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NodeKind {
+    /// Crossing of the reference plane towards its normal (e.g. northward for the equator)
+    Ascending,
+    /// Crossing of the reference plane away from its normal (e.g. southward for the equator)
+    Descending,
+}
+
+/// A crossing of the reference plane by a target, as found by [Almanac::report_node_crossings].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NodeCrossing {
+    pub kind: NodeKind,
+    /// State of the target in the reference frame at the crossing
+    pub orbit: Orbit,
+    /// Longitude of the node in the reference plane, in degrees between -180 and 180, measured from the projection of the X axis
+    /// of the reference frame onto the plane (or of its Y axis if the plane is normal to the X axis). For the equator of a body fixed
+    /// frame, this is the longitude of the node, and for the equator of an inertial frame, its right ascension.
+    pub longitude_deg: f64,
+}
+
+impl NodeCrossing {
+    pub fn epoch(&self) -> Epoch {
+        self.orbit.epoch
+    }
+}
+
+impl fmt::Display for NodeCrossing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} node at {}: longitude {:.6} deg",
+            self.kind,
+            self.epoch(),
+            self.longitude_deg
+        )
+    }
+}
+
+impl Almanac {
+    /// Reports the crossings of the reference plane by the target between the start and end epochs, sorted chronologically, with
+    /// the longitude of each node. The reference plane contains the origin of the reference frame and is normal to the provided
+    /// vector, expressed in the reference frame, which defaults to its Z axis: the equator of the reference frame.
+    ///
+    /// The crossings are the sign changes of the component of the unit position vector of the target, as seen from the reference
+    /// frame, along the plane normal, which are found with the event finder. Use a body fixed frame (e.g. `IAU_EARTH_FRAME`) for the
+    /// geographic longitude of the nodes, and an inertial frame for their right ascension.
+    pub fn report_node_crossings(
+        &self,
+        target_frame: Frame,
+        reference_frame: Frame,
+        plane_normal: Option<Vector3>,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> AnalysisResult<Vec<NodeCrossing>> {
+        let normal = plane_normal.unwrap_or_else(Vector3::z);
+        if normal.norm() <= f64::EPSILON {
+            return Err(AnalysisError::InvalidEventEval {
+                err: "the normal of the reference plane of node crossings must not be zero"
+                    .to_string(),
+            });
+        }
+        let normal = normal.normalize();
+
+        // In-plane basis from which the longitude is measured.
+        let in_plane = |axis: Vector3| axis - axis.dot(&normal) * normal;
+        let e1 = if in_plane(Vector3::x()).norm() > 1e-6 {
+            in_plane(Vector3::x()).normalize()
+        } else {
+            in_plane(Vector3::y()).normalize()
+        };
+        let e2 = normal.cross(&e1);
+
+        let state_spec = StateSpec {
+            target_frame: FrameSpec::Loaded(target_frame),
+            observer_frame: FrameSpec::Loaded(reference_frame),
+            ab_corr: None,
+        };
+        let event = Event::new(
+            ScalarExpr::DotProduct {
+                a: VectorExpr::Unit(Box::new(VectorExpr::Radius(state_spec.clone()))),
+                b: VectorExpr::Fixed {
+                    x: normal.x,
+                    y: normal.y,
+                    z: normal.z,
+                },
+            },
+            Condition::Equals(0.0),
+        );
+
+        Ok(self
+            .report_events(&state_spec, &event, start_epoch, end_epoch)?
+            .into_iter()
+            .filter_map(|details| {
+                let kind = match details.edge {
+                    EventEdge::Rising => NodeKind::Ascending,
+                    EventEdge::Falling => NodeKind::Descending,
+                    // The trajectory touches the plane without crossing it.
+                    _ => return None,
+                };
+                let r = details.orbit.radius_km;
+                Some(NodeCrossing {
+                    kind,
+                    orbit: details.orbit,
+                    longitude_deg: between_pm_180(r.dot(&e2).atan2(r.dot(&e1)).to_degrees()),
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod ut_nodes {
+    use super::NodeKind;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::math::Vector3;
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn report_node_crossings() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        // Inclined orbit with a RAAN of 40 degrees, starting just after its ascending node.
+        let orbit =
+            Orbit::try_keplerian(7000.0, 0.01, 51.6, 40.0, 30.0, 10.0, epoch, frame).unwrap();
+        let period = orbit.period().unwrap();
+
+        let mut ephem = Ephemeris::new("leo".to_string());
+        for minute in 0..=200 {
+            ephem.insert_orbit(
+                orbit
+                    .propagate_to_epoch(epoch + Unit::Minute * minute)
+                    .unwrap(),
+            );
+        }
+        let almanac = Almanac::default().with_spk(
+            ephem
+                .to_spice_bsp(-100, Some(DafDataType::Type13HermiteUnequalStep))
+                .unwrap(),
+        );
+        let sc = Frame::from_ephem_j2000(-100);
+
+        let nodes = almanac
+            .report_node_crossings(sc, EARTH_J2000, None, epoch, epoch + period * 1.5)
+            .unwrap();
+        for node in &nodes {
+            println!("{node}");
+        }
+
+        // Descending, ascending, descending: the nodes are fixed in the inertial frame for a Keplerian orbit.
+        assert_eq!(nodes.len(), 3);
+        for (k, node) in nodes.iter().enumerate() {
+            let (kind, longitude_deg) = if k % 2 == 0 {
+                (NodeKind::Descending, 40.0 - 180.0)
+            } else {
+                (NodeKind::Ascending, 40.0)
+            };
+            assert_eq!(node.kind, kind);
+            assert!((node.longitude_deg - longitude_deg).abs() < 1e-4, "{node}");
+            assert!(node.orbit.radius_km.z.abs() < 1e-2);
+        }
+        assert!((nodes[2].epoch() - nodes[0].epoch() - period).abs() < Unit::Second * 1);
+
+        // Crossings of the plane normal to the X axis, whose longitude is measured from the Y axis.
+        let nodes = almanac
+            .report_node_crossings(
+                sc,
+                EARTH_J2000,
+                Some(Vector3::x() * 2.0),
+                epoch,
+                epoch + period,
+            )
+            .unwrap();
+        assert_eq!(nodes.len(), 2);
+        for node in &nodes {
+            assert!(node.orbit.radius_km.x.abs() < 1e-2);
+            let r = node.orbit.radius_km;
+            let expected_deg = r.z.atan2(r.y).to_degrees();
+            assert!((node.longitude_deg - expected_deg).abs() < 1e-3, "{node}");
+        }
+
+        assert!(almanac
+            .report_node_crossings(
+                sc,
+                EARTH_J2000,
+                Some(Vector3::zeros()),
+                epoch,
+                epoch + period
+            )
+            .is_err());
+    }
+}
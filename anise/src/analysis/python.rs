@@ -13,6 +13,7 @@ use pyo3::prelude::*;
 use pyo3::types::PyType;
 use std::collections::HashMap;
 
+use crate::errors::AlmanacError;
 use crate::prelude::{Aberration, Almanac, Frame, Orbit};
 use crate::NaifId;
 
@@ -20,7 +21,7 @@ pub use crate::analysis::elements::OrbitalElement;
 use crate::analysis::specs::{OrthogonalFrame, Plane};
 use crate::math::rotation::DCM;
 
-use super::event::{Event, EventArc, EventDetails, VisibilityArc};
+use super::event::{Condition, Event, EventArc, EventDetails, PassSummary, VisibilityArc};
 use super::prelude::{ScalarExpr, VectorExpr};
 use super::report::PyReportScalars;
 use super::specs::{DcmExpr, FrameSpec, StateSpec, StateSpecTrait};
@@ -113,6 +114,69 @@ impl Almanac {
         })
     }
 
+    /// Searches for the epochs when the provided scalar function of the epoch equals a value, or reaches a local minimum or maximum,
+    /// between the start and end epochs. This is the equivalent of the SPICE `gfuds` routine.
+    ///
+    /// Unlike `report_events`, the function may be any computation derived from Almanac queries (e.g. a separation angle,
+    /// a distance, an elevation): it is called with the epoch and must return a float. The same adaptive step scanner and
+    /// Brent solver are used, so the epoch precision drives both the convergence and the scanning step. The function is assumed
+    /// to be continuous: for angles which wrap around, return a continuous function of the angle instead (e.g. its sine).
+    ///
+    /// This method may only be used for equality, minimum, and maximum conditions. For spanned conditions, use `find_event_arcs`.
+    ///
+    /// :type function: typing.Callable[[Epoch], float]
+    /// :type condition: Condition
+    /// :type epoch_precision: Duration
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :rtype: typing.List[Epoch]
+    #[pyo3(name = "find_events")]
+    fn py_find_events(
+        &self,
+        function: Bound<'_, PyAny>,
+        condition: Condition,
+        epoch_precision: Duration,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<Epoch>, AnalysisError> {
+        self.find_events(
+            |_, epoch| call_function(&function, epoch),
+            condition,
+            epoch_precision,
+            start_epoch,
+            end_epoch,
+        )
+    }
+
+    /// Searches for the time intervals when the provided scalar function of the epoch is within the range of the condition, between
+    /// the start and end epochs, cf. `find_events`. Intervals in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// This method may only be used for less than, greater than, and between conditions. For individual events, use `find_events`.
+    ///
+    /// :type function: typing.Callable[[Epoch], float]
+    /// :type condition: Condition
+    /// :type epoch_precision: Duration
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :rtype: typing.List[typing.Tuple[Epoch, Epoch]]
+    #[pyo3(name = "find_event_arcs")]
+    fn py_find_event_arcs(
+        &self,
+        function: Bound<'_, PyAny>,
+        condition: Condition,
+        epoch_precision: Duration,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Vec<(Epoch, Epoch)>, AnalysisError> {
+        self.find_event_arcs(
+            |_, epoch| call_function(&function, epoch),
+            condition,
+            epoch_precision,
+            start_epoch,
+            end_epoch,
+        )
+    }
+
     /// Report the list of visibility arcs for the desired location ID.
     ///
     /// :type state_spec: StateSpec
@@ -145,6 +209,53 @@ impl Almanac {
             )
         })
     }
+
+    /// Report the pass summaries (AOS, LOS, culmination, and Sun interference) of the object over the desired location ID.
+    ///
+    /// A pass is a visibility arc where the elevation of the object above the terrain mask of the location (or above the horizon
+    /// if the terrain mask is not set) is greater than the provided elevation mask, e.g. zero to report every visibility arc.
+    /// Passes in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// The AER data is sampled at the provided sample rate, which drives the accuracy of the culmination and Sun separation.
+    /// Sun interference is flagged if the angular separation between the Sun and the object, as seen from the location,
+    /// is less than the provided exclusion angle at any sample of the pass.
+    ///
+    /// :type state_spec: StateSpec
+    /// :type location_id: int
+    /// :type start_epoch: Epoch
+    /// :type end_epoch: Epoch
+    /// :type sample_rate: Duration
+    /// :type obstructing_body: Frame, optional
+    /// :type sun_exclusion_deg: float
+    /// :type elevation_mask_deg: float
+    /// :rtype: list
+    #[pyo3(name = "report_passes", signature=(state_spec, location_id, start_epoch, end_epoch, sample_rate, obstructing_body=None, sun_exclusion_deg=5.0, elevation_mask_deg=0.0))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_report_passes(
+        &self,
+        py: Python,
+        state_spec: PyStateSpec,
+        location_id: i32,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        sample_rate: Duration,
+        obstructing_body: Option<Frame>,
+        sun_exclusion_deg: f64,
+        elevation_mask_deg: f64,
+    ) -> Result<Vec<PassSummary>, AnalysisError> {
+        py.detach(|| {
+            self.report_passes(
+                &StateSpec::from(state_spec),
+                location_id,
+                start_epoch,
+                end_epoch,
+                sample_rate,
+                obstructing_body,
+                sun_exclusion_deg,
+                elevation_mask_deg,
+            )
+        })
+    }
 }
 
 /// ScalarExpr defines a scalar computation from a (set of) vector expression(s).
@@ -1625,3 +1736,13 @@ impl From<PyDcmExpr> for DcmExpr {
         })
     }
 }
+
+/// Calls the Python function of the epoch provided to the event finders.
+fn call_function(function: &Bound<'_, PyAny>, epoch: Epoch) -> Result<f64, AlmanacError> {
+    function
+        .call1((epoch,))
+        .and_then(|value| value.extract::<f64>())
+        .map_err(|e| AlmanacError::GenericError {
+            err: format!("{e} when calling the search function at {epoch}"),
+        })
+}
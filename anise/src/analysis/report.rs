@@ -9,6 +9,9 @@
  */
 
 use crate::analysis::{specs::StateSpecTrait, ScalarExpr, StateSpec};
+use crate::format::ProductFormat;
+use crate::math::angles::AngleConvention;
+use crate::math::units::{LengthUnit, TimeUnit};
 use csv::Writer;
 use hifitime::Epoch;
 use serde::{Deserialize, Serialize};
@@ -102,6 +105,88 @@ impl PyReportScalars {
     }
 }
 
+/// Units in which a scalars table is exported, e.g. to deliver products in meters, days, and radians without post-processing.
+///
+/// The scalar expressions are always computed in kilometers, seconds, and degrees: only the exported values are converted. A column
+/// is converted if its header ends with its unit in parentheses, like `Rmag (km)` or `range-rate from location #1 (km/s)`, which is
+/// the case of the columns that are not aliased. Other units, like hours of local time or percentages, are unchanged. The geodetic
+/// longitude column, `Longitude (deg)`, is also converted to the range and direction of the longitudes of the angle convention.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OutputUnits {
+    pub length: LengthUnit,
+    pub time: TimeUnit,
+    pub angles: AngleConvention,
+}
+
+impl Default for OutputUnits {
+    fn default() -> Self {
+        Self {
+            length: LengthUnit::Kilometer,
+            time: TimeUnit::Second,
+            angles: AngleConvention::default(),
+        }
+    }
+}
+
+impl OutputUnits {
+    /// Returns the conversion factor from the provided unit (e.g. `km^2/s^2`) to these units, and the converted unit, or None
+    /// if that unit is not made of kilometers, seconds, and degrees only.
+    fn convert(&self, unit: &str) -> Option<(f64, String)> {
+        let mut factor = 1.0;
+        let mut converted = Vec::new();
+
+        for (part_no, part) in unit.split('/').enumerate() {
+            if part_no > 1 {
+                return None;
+            }
+            let sign = if part_no == 0 { 1 } else { -1 };
+
+            let (symbol, exponent) = match part.split_once('^') {
+                Some((symbol, exponent)) => (symbol, exponent.parse::<i32>().ok()?),
+                None => (part, 1),
+            };
+
+            let (unit_factor, new_symbol) = match symbol {
+                "km" => (
+                    LengthUnit::Kilometer.from_meters() / self.length.from_meters(),
+                    self.length.to_string(),
+                ),
+                "s" => (1.0 / self.time.in_seconds(), time_unit_symbol(self.time)),
+                "deg" => (
+                    self.angles.unit.from_degrees(),
+                    self.angles.unit.to_string(),
+                ),
+                _ => return None,
+            };
+
+            factor *= unit_factor.powi(sign * exponent);
+            if exponent == 1 {
+                converted.push(new_symbol);
+            } else {
+                converted.push(format!("{new_symbol}^{exponent}"));
+            }
+        }
+
+        Some((factor, converted.join("/")))
+    }
+}
+
+/// Returns the abbreviation of a time unit used in the headers of the exported products.
+fn time_unit_symbol(unit: TimeUnit) -> String {
+    match unit {
+        TimeUnit::Nanosecond => "ns",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Second => "s",
+        TimeUnit::Minute => "min",
+        TimeUnit::Hour => "h",
+        TimeUnit::Day => "day",
+        TimeUnit::Week => "week",
+        TimeUnit::Century => "century",
+    }
+    .to_string()
+}
+
 #[derive(Debug, Clone)]
 pub struct ScalarsRow {
     pub epoch: Epoch,
@@ -116,8 +201,80 @@ pub struct ScalarsTable {
 }
 
 impl ScalarsTable {
+    /// Returns a copy of this scalars table where the values and headers are converted into the provided output units.
+    pub fn to_units(&self, units: OutputUnits) -> Self {
+        let mut headers = Vec::with_capacity(self.headers.len());
+        let mut factors = Vec::with_capacity(self.headers.len());
+        let mut longitudes = Vec::with_capacity(self.headers.len());
+
+        for header in &self.headers {
+            let conversion = header
+                .strip_suffix(')')
+                .and_then(|prefix| prefix.rsplit_once('('))
+                .and_then(|(name, unit)| {
+                    units
+                        .convert(unit)
+                        .map(|(factor, unit)| (factor, format!("{name}({unit})")))
+                });
+
+            longitudes.push(header == "Longitude (deg)");
+            match conversion {
+                Some((factor, header)) => {
+                    headers.push(header);
+                    factors.push(factor);
+                }
+                None => {
+                    headers.push(header.clone());
+                    factors.push(1.0);
+                }
+            }
+        }
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| ScalarsRow {
+                epoch: row.epoch,
+                values: row
+                    .values
+                    .iter()
+                    .zip(&factors)
+                    .zip(&longitudes)
+                    .map(|((value, factor), is_longitude)| {
+                        if *is_longitude {
+                            units.angles.longitude(*value)
+                        } else {
+                            value * factor
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self { headers, rows }
+    }
+
+    /// Export this scalars table to CSV in the provided output units
+    pub fn to_csv_in_units(
+        &self,
+        path: PathBuf,
+        units: OutputUnits,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.to_units(units).to_csv(path)
+    }
+
     /// Export this scalars table to CSV
     pub fn to_csv(&self, path: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        self.to_csv_with_format(path, &ProductFormat::default())
+    }
+
+    /// Export this scalars table to CSV, formatting the epochs and values with the provided product format, e.g. to write a
+    /// fixed number of significant digits and Julian dates.
+    pub fn to_csv_with_format(
+        &self,
+        path: PathBuf,
+        format: &ProductFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         if self.rows.is_empty() {
             return Ok(());
         }
@@ -125,7 +282,7 @@ impl ScalarsTable {
         let mut wtr = Writer::from_path(path)?;
 
         // Write the epoch header in the proper timescale
-        wtr.write_field(format!("Epoch ({})", self.rows[0].epoch.time_scale))?;
+        wtr.write_field(format.epoch_header(self.rows[0].epoch))?;
         // Write all the other headers from our struct
         for header in &self.headers {
             wtr.write_field(header)?;
@@ -134,11 +291,11 @@ impl ScalarsTable {
         wtr.write_record(None::<&[u8]>)?;
 
         for row in &self.rows {
-            wtr.write_field(row.epoch.to_string())?;
+            wtr.write_field(format.format_epoch(row.epoch))?;
 
             // Write all f64 values
             for value in &row.values {
-                wtr.write_field(value.to_string())?;
+                wtr.write_field(format.format_f64(*value))?;
             }
 
             // Finalize this data row
@@ -152,3 +309,126 @@ impl ScalarsTable {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod ut_report {
+    use super::{OutputUnits, ScalarsRow, ScalarsTable};
+    use crate::math::angles::{AngleConvention, LongitudeDirection, LongitudeRange};
+    use crate::math::units::{AngleUnit, LengthUnit, TimeUnit};
+    use hifitime::Epoch;
+
+    #[test]
+    fn output_units() {
+        let table = ScalarsTable {
+            headers: vec![
+                "Rmag (km)".to_string(),
+                "range-rate from location #1 (km/s)".to_string(),
+                "C3 (km^2/s^2)".to_string(),
+                "Period (s)".to_string(),
+                "beta angle (deg)".to_string(),
+                "my alias".to_string(),
+            ],
+            rows: vec![ScalarsRow {
+                epoch: Epoch::from_et_seconds(0.0),
+                values: vec![7000.0, 1.5, 2.0, 86_400.0, 45.0, 3.0],
+            }],
+        };
+
+        // The default units leave the table unchanged
+        let same = table.to_units(OutputUnits::default());
+        assert_eq!(same.headers, table.headers);
+        assert_eq!(same.rows[0].values, table.rows[0].values);
+
+        let converted = table.to_units(OutputUnits {
+            length: LengthUnit::Meter,
+            time: TimeUnit::Day,
+            ..Default::default()
+        });
+        assert_eq!(
+            converted.headers,
+            vec![
+                "Rmag (m)",
+                "range-rate from location #1 (m/day)",
+                "C3 (m^2/day^2)",
+                "Period (day)",
+                "beta angle (deg)",
+                "my alias",
+            ]
+        );
+        let expected = [
+            7.0e6,
+            1.5e3 * 86_400.0,
+            2.0e6 * 86_400.0_f64.powi(2),
+            1.0,
+            45.0,
+            3.0,
+        ];
+        for (value, expected) in converted.rows[0].values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 1e-9 * expected.abs(),
+                "{value} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn output_angles() {
+        let table = ScalarsTable {
+            headers: vec![
+                "Longitude (deg)".to_string(),
+                "Inclination (deg)".to_string(),
+                "azimuth rate (deg/s)".to_string(),
+                "Rmag (km)".to_string(),
+            ],
+            rows: vec![ScalarsRow {
+                epoch: Epoch::from_et_seconds(0.0),
+                values: vec![-75.0, 51.6, 0.5, 7000.0],
+            }],
+        };
+
+        let same = table.to_units(OutputUnits::default());
+        assert_eq!(same.headers, table.headers);
+        assert_eq!(same.rows[0].values, table.rows[0].values);
+
+        let west_360 = table.to_units(OutputUnits {
+            angles: AngleConvention {
+                longitude_range: LongitudeRange::ZeroTo360,
+                longitude_direction: LongitudeDirection::WestPositive,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert_eq!(west_360.headers, table.headers);
+        assert_eq!(west_360.rows[0].values, vec![75.0, 51.6, 0.5, 7000.0]);
+
+        let radians = table.to_units(OutputUnits {
+            angles: AngleConvention {
+                unit: AngleUnit::Radian,
+                longitude_range: LongitudeRange::ZeroTo360,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert_eq!(
+            radians.headers,
+            vec![
+                "Longitude (rad)",
+                "Inclination (rad)",
+                "azimuth rate (rad/s)",
+                "Rmag (km)"
+            ]
+        );
+        let expected = [
+            285.0_f64.to_radians(),
+            51.6_f64.to_radians(),
+            0.5_f64.to_radians(),
+            7000.0,
+        ];
+        for (value, expected) in radians.rows[0].values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 1e-12 * expected.abs(),
+                "{value} != {expected}"
+            );
+        }
+    }
+}
@@ -0,0 +1,252 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt::Write as _;
+use hifitime::{Epoch, TimeScale};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use super::event::{EventArc, PassSummary};
+use crate::astro::OccultationWindow;
+use crate::constants::celestial_objects::SUN;
+use crate::format::{EpochFormat, ProductFormat};
+
+/// An activity of a simple schedule, i.e. a named time window, optionally allocated to a resource like a ground station.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledActivity {
+    pub name: String,
+    /// Type of activity, e.g. `PASS`, `ECLIPSE`, or `OCCULTATION`
+    pub activity_type: String,
+    /// Resource used by this activity, e.g. the name of the ground station of a pass
+    pub resource: Option<String>,
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+}
+
+/// A schedule of time windows, exported in the XML format of the CCSDS Simple Schedule Format (CCSDS 902.1-B-1) such that
+/// operations scheduling tools can ingest the passes, eclipses, and occultations computed by ANISE.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimpleSchedule {
+    pub originator: String,
+    pub description: String,
+    /// Epoch written as the generation time of the schedule, set to the current time upon initialization
+    pub creation_epoch: Epoch,
+    pub activities: Vec<ScheduledActivity>,
+}
+
+impl SimpleSchedule {
+    /// Initializes an empty schedule, generated now.
+    pub fn new(originator: String, description: String) -> Self {
+        Self {
+            originator,
+            description,
+            creation_epoch: Epoch::now().unwrap_or_default(),
+            activities: Vec::new(),
+        }
+    }
+
+    /// Adds the provided pass summaries of the target as `PASS` activities allocated to the location of each pass.
+    pub fn with_pass_summaries(mut self, target: &str, passes: &[PassSummary]) -> Self {
+        self.activities
+            .extend(passes.iter().map(|pass| ScheduledActivity {
+                name: format!("{target} pass over {}", pass.location_ref),
+                activity_type: "PASS".to_string(),
+                resource: Some(pass.location_ref.clone()),
+                start_epoch: pass.aos_epoch,
+                end_epoch: pass.los_epoch,
+            }));
+        self
+    }
+
+    /// Adds the provided occultation windows, as `ECLIPSE` activities if the Sun is occulted and as `OCCULTATION` activities otherwise.
+    pub fn with_occultations(mut self, windows: &[OccultationWindow]) -> Self {
+        self.activities
+            .extend(windows.iter().map(|window| ScheduledActivity {
+                name: format!(
+                    "{} of {:e} by {:e}",
+                    window.kind, window.back_frame, window.front_frame
+                ),
+                activity_type: if window.back_frame.ephem_origin_id_match(SUN) {
+                    "ECLIPSE".to_string()
+                } else {
+                    "OCCULTATION".to_string()
+                },
+                resource: None,
+                start_epoch: window.start_epoch,
+                end_epoch: window.end_epoch,
+            }));
+        self
+    }
+
+    /// Adds the provided event arcs, e.g. from [crate::prelude::Almanac::report_event_arcs], as activities of the provided name and type.
+    pub fn with_event_arcs(mut self, name: &str, activity_type: &str, arcs: &[EventArc]) -> Self {
+        self.activities
+            .extend(arcs.iter().map(|arc| ScheduledActivity {
+                name: name.to_string(),
+                activity_type: activity_type.to_string(),
+                resource: None,
+                start_epoch: arc.start_epoch(),
+                end_epoch: arc.end_epoch(),
+            }));
+        self
+    }
+
+    /// Exports this schedule to the XML of the CCSDS Simple Schedule Format, with the activities sorted chronologically:
+    ///
+    /// ```text
+    /// <?xml version="1.0" encoding="UTF-8"?>
+    /// <simpleSchedule xmlns="urn:ccsds:schema:ssf:1.0">
+    ///   <header>
+    ///     <originator>ANISE</originator>
+    ///     <generationTime>2025-01-01T00:00:00.000000000Z</generationTime>
+    ///     <description>LEO passes</description>
+    ///   </header>
+    ///   <scheduledPackage>
+    ///     <scheduledActivity id="1">
+    ///       <name>LEO pass over Madrid</name>
+    ///       <type>PASS</type>
+    ///       <resource>Madrid</resource>
+    ///       <startTime>2025-01-01T00:12:30.000000000Z</startTime>
+    ///       <endTime>2025-01-01T00:21:03.000000000Z</endTime>
+    ///     </scheduledActivity>
+    ///   </scheduledPackage>
+    /// </simpleSchedule>
+    /// ```
+    ///
+    /// All times are ISO 8601 in UTC.
+    pub fn to_xml(&self) -> String {
+        let format = ProductFormat {
+            epoch: EpochFormat::Iso8601,
+            time_scale: Some(TimeScale::UTC),
+            ..Default::default()
+        };
+        let fmt_epoch = |epoch: Epoch| format!("{}Z", format.format_epoch(epoch));
+
+        let mut activities = self.activities.iter().collect::<Vec<_>>();
+        activities.sort_by_key(|activity| (activity.start_epoch, activity.end_epoch));
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<simpleSchedule xmlns=\"urn:ccsds:schema:ssf:1.0\">\n");
+        xml.push_str("  <header>\n");
+        push_xml_element(&mut xml, 4, "originator", &self.originator);
+        push_xml_element(
+            &mut xml,
+            4,
+            "generationTime",
+            &fmt_epoch(self.creation_epoch),
+        );
+        push_xml_element(&mut xml, 4, "description", &self.description);
+        xml.push_str("  </header>\n");
+        xml.push_str("  <scheduledPackage>\n");
+        for (i, activity) in activities.iter().enumerate() {
+            let _ = writeln!(xml, "    <scheduledActivity id=\"{}\">", i + 1);
+            push_xml_element(&mut xml, 6, "name", &activity.name);
+            push_xml_element(&mut xml, 6, "type", &activity.activity_type);
+            if let Some(resource) = &activity.resource {
+                push_xml_element(&mut xml, 6, "resource", resource);
+            }
+            push_xml_element(&mut xml, 6, "startTime", &fmt_epoch(activity.start_epoch));
+            push_xml_element(&mut xml, 6, "endTime", &fmt_epoch(activity.end_epoch));
+            xml.push_str("    </scheduledActivity>\n");
+        }
+        xml.push_str("  </scheduledPackage>\n");
+        xml.push_str("</simpleSchedule>\n");
+        xml
+    }
+
+    /// Writes the XML of this schedule, as exported by [SimpleSchedule::to_xml], to the provided path.
+    pub fn write_xml<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(self.to_xml().as_bytes())?;
+        writer.flush()
+    }
+}
+
+/// Writes an indented XML element with escaped text content.
+fn push_xml_element(xml: &mut String, indent: usize, tag: &str, text: &str) {
+    let _ = write!(xml, "{:indent$}<{tag}>", "");
+    for c in text.chars() {
+        match c {
+            '&' => xml.push_str("&amp;"),
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            '"' => xml.push_str("&quot;"),
+            '\'' => xml.push_str("&apos;"),
+            c => xml.push(c),
+        }
+    }
+    let _ = writeln!(xml, "</{tag}>");
+}
+
+#[cfg(test)]
+mod ut_schedule {
+    use super::SimpleSchedule;
+    use crate::analysis::event::PassSummary;
+    use crate::astro::{OccultationKind, OccultationWindow};
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000, SUN_J2000};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn simple_schedule_xml() {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+
+        let pass = PassSummary {
+            location_ref: "Madrid".to_string(),
+            aos_epoch: epoch + Unit::Minute * 90,
+            los_epoch: epoch + Unit::Minute * 100,
+            duration: Unit::Minute * 10,
+            aos_azimuth_deg: 10.0,
+            los_azimuth_deg: 170.0,
+            culmination_epoch: epoch + Unit::Minute * 95,
+            max_elevation_deg: 45.0,
+            min_sun_separation_deg: 90.0,
+            sun_interference: false,
+        };
+        let eclipse = OccultationWindow {
+            start_epoch: epoch + Unit::Minute * 30,
+            end_epoch: epoch + Unit::Minute * 65,
+            kind: OccultationKind::Total,
+            back_frame: SUN_J2000,
+            front_frame: EARTH_J2000,
+        };
+        let occultation = OccultationWindow {
+            back_frame: MOON_J2000,
+            start_epoch: epoch + Unit::Minute * 120,
+            end_epoch: epoch + Unit::Minute * 121,
+            ..eclipse
+        };
+
+        let mut schedule =
+            SimpleSchedule::new("ANISE".to_string(), "LEO <ops> & eclipses".to_string())
+                .with_pass_summaries("LEO", &[pass])
+                .with_occultations(&[occultation, eclipse]);
+        schedule.creation_epoch = epoch;
+
+        let xml = schedule.to_xml();
+        println!("{xml}");
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<simpleSchedule"));
+        assert!(xml.contains("<generationTime>2025-01-01T00:00:00.000000000Z</generationTime>"));
+        assert!(xml.contains("<description>LEO &lt;ops&gt; &amp; eclipses</description>"));
+        assert!(xml.contains("<resource>Madrid</resource>"));
+        assert!(xml.contains("<startTime>2025-01-01T01:30:00.000000000Z</startTime>"));
+        assert!(xml.contains("<endTime>2025-01-01T01:40:00.000000000Z</endTime>"));
+        assert_eq!(xml.matches("<scheduledActivity ").count(), 3);
+
+        // The activities are sorted chronologically.
+        let eclipse_pos = xml.find("<type>ECLIPSE</type>").unwrap();
+        let pass_pos = xml.find("<type>PASS</type>").unwrap();
+        let occultation_pos = xml.find("<type>OCCULTATION</type>").unwrap();
+        assert!(eclipse_pos < pass_pos && pass_pos < occultation_pos);
+        assert!(xml.contains("<name>total occultation of Sun by Earth</name>"));
+    }
+}
@@ -11,13 +11,15 @@
 use crate::{
     almanac::Almanac,
     analysis::{
-        event::{EventEdge, VisibilityArc},
+        event::{EventEdge, PassSummary, VisibilityArc},
         event_ops::find_arc_intersections,
         utils::{adaptive_step_scanner, brent_solver},
         AlmanacVisibilitySnafu, AnalysisResult,
     },
     astro::AzElRange,
+    constants::frames::SUN_J2000,
     frames::Frame,
+    prelude::Orbit,
 };
 use hifitime::{Duration, Epoch, TimeSeries};
 use rayon::prelude::*;
@@ -426,9 +428,34 @@ impl Almanac {
         end_epoch: Epoch,
         sample_rate: Duration,
         obstructing_body: Option<Frame>,
+    ) -> Result<Vec<VisibilityArc>, AnalysisError> {
+        self.visibility_arcs_above_mask(
+            state_spec,
+            location_id,
+            0.0,
+            start_epoch,
+            end_epoch,
+            sample_rate,
+            obstructing_body,
+        )
+    }
+
+    /// Report the list of visibility arcs for the desired location ID, where the elevation of the object above the terrain mask
+    /// of the location (or above the horizon if the terrain mask is not set) is greater than the provided elevation mask.
+    #[allow(clippy::too_many_arguments)]
+    fn visibility_arcs_above_mask<S: StateSpecTrait>(
+        &self,
+        state_spec: &S,
+        location_id: i32,
+        elevation_mask_deg: f64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        sample_rate: Duration,
+        obstructing_body: Option<Frame>,
     ) -> Result<Vec<VisibilityArc>, AnalysisError> {
         // Find the event arcs first to ensure that the location is valid so we can unwrap safely after the loop.
-        let event = Event::visible_from_location_id(location_id, obstructing_body);
+        let mut event = Event::visible_from_location_id(location_id, obstructing_body);
+        event.condition = Condition::GreaterThan(elevation_mask_deg);
         let event_arcs = self.report_event_arcs(state_spec, &event, start_epoch, end_epoch)?;
 
         // Find the location info
@@ -483,4 +510,248 @@ impl Almanac {
 
         Ok(arcs)
     }
+
+    /// Report the pass summaries (AOS, LOS, culmination, and Sun interference) of the object over the desired location ID.
+    ///
+    /// A pass is a visibility arc where the elevation of the object above the terrain mask of the location (or above the horizon
+    /// if the terrain mask is not set) is greater than the provided elevation mask, e.g. zero to report every visibility arc.
+    /// Passes in progress at the start or the end epoch are clipped to these epochs.
+    ///
+    /// The AER data is sampled at the provided sample rate, which drives the accuracy of the culmination and Sun separation.
+    /// Sun interference is flagged if the angular separation between the Sun and the object, as seen from the location,
+    /// is less than the provided exclusion angle at any sample of the pass.
+    #[allow(clippy::too_many_arguments)]
+    pub fn report_passes<S: StateSpecTrait>(
+        &self,
+        state_spec: &S,
+        location_id: i32,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        sample_rate: Duration,
+        obstructing_body: Option<Frame>,
+        sun_exclusion_deg: f64,
+        elevation_mask_deg: f64,
+    ) -> Result<Vec<PassSummary>, AnalysisError> {
+        let arcs = self.visibility_arcs_above_mask(
+            state_spec,
+            location_id,
+            elevation_mask_deg,
+            start_epoch,
+            end_epoch,
+            sample_rate,
+            obstructing_body,
+        )?;
+
+        let mut passes = Vec::with_capacity(arcs.len());
+        for arc in arcs {
+            let min_sun_separation_deg = arc
+                .aer_data
+                .par_iter()
+                .map(|obj_aer| {
+                    let sun = Orbit::zero_at_epoch(obj_aer.epoch, SUN_J2000);
+                    let sun_aer = self
+                        .azimuth_elevation_range_sez_from_location_id(
+                            sun,
+                            location_id,
+                            None,
+                            state_spec.ab_corr(),
+                        )
+                        .context(AlmanacVisibilitySnafu { state: sun })?;
+                    Ok(angular_separation_deg(obj_aer, &sun_aer))
+                })
+                .collect::<Result<Vec<f64>, AnalysisError>>()?
+                .into_iter()
+                .fold(f64::INFINITY, f64::min);
+
+            let (culmination_epoch, max_elevation_deg) = arc
+                .culmination()
+                .unwrap_or((arc.rise.orbit.epoch, arc.rise.value));
+
+            let aos_azimuth_deg = self
+                .azimuth_elevation_range_sez_from_location_id(
+                    arc.rise.orbit,
+                    location_id,
+                    None,
+                    state_spec.ab_corr(),
+                )
+                .context(AlmanacVisibilitySnafu {
+                    state: arc.rise.orbit,
+                })?
+                .azimuth_deg;
+
+            let los_azimuth_deg = self
+                .azimuth_elevation_range_sez_from_location_id(
+                    arc.fall.orbit,
+                    location_id,
+                    None,
+                    state_spec.ab_corr(),
+                )
+                .context(AlmanacVisibilitySnafu {
+                    state: arc.fall.orbit,
+                })?
+                .azimuth_deg;
+
+            passes.push(PassSummary {
+                location_ref: arc.location_ref.clone(),
+                aos_epoch: arc.start_epoch(),
+                los_epoch: arc.end_epoch(),
+                duration: arc.duration(),
+                aos_azimuth_deg,
+                los_azimuth_deg,
+                culmination_epoch,
+                max_elevation_deg,
+                min_sun_separation_deg,
+                sun_interference: min_sun_separation_deg < sun_exclusion_deg,
+            });
+        }
+
+        Ok(passes)
+    }
+}
+
+/// Returns the angle in degrees between the two directions defined by their azimuth and elevation.
+fn angular_separation_deg(a: &AzElRange, b: &AzElRange) -> f64 {
+    let (sin_el_a, cos_el_a) = a.elevation_deg.to_radians().sin_cos();
+    let (sin_el_b, cos_el_b) = b.elevation_deg.to_radians().sin_cos();
+    let cos_sep = sin_el_a * sin_el_b
+        + cos_el_a * cos_el_b * (a.azimuth_deg - b.azimuth_deg).to_radians().cos();
+    cos_sep.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+#[cfg(test)]
+mod ut_search {
+    use crate::analysis::specs::{FrameSpec, StateSpec};
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME};
+    use crate::ephemerides::ephemeris::Ephemeris;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::naif::daf::DafDataType;
+    use crate::prelude::{Almanac, Frame, Orbit};
+    use crate::structure::location::Location;
+    use crate::structure::LocationDataSet;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn report_passes_above_mask() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_et_seconds(0.0);
+        let orbit =
+            Orbit::try_keplerian(7000.0, 0.001, 51.6, 40.0, 30.0, 0.0, epoch, frame).unwrap();
+
+        let mut ephem = Ephemeris::new("leo".to_string());
+        for minute in 0..=300 {
+            ephem.insert_orbit(
+                orbit
+                    .propagate_to_epoch(epoch + Unit::Minute * minute)
+                    .unwrap(),
+            );
+        }
+        let almanac = Almanac::default()
+            .load("../data/pck08.pca")
+            .unwrap()
+            .with_spk(test_spk(&[fixed_segment(10, 399, [1.5e8, 0.0, 0.0])]))
+            .with_spk(
+                ephem
+                    .to_spice_bsp(-100, Some(DafDataType::Type13HermiteUnequalStep))
+                    .unwrap(),
+            );
+        let sc = Frame::from_ephem_j2000(-100);
+
+        // Place the station at the sub-point of the spacecraft 20 minutes in, such that it flies overhead.
+        let subpoint = almanac
+            .transform(
+                sc,
+                almanac.frame_info(IAU_EARTH_FRAME).unwrap(),
+                epoch + Unit::Minute * 20,
+                None,
+            )
+            .unwrap();
+        let mut locations = LocationDataSet::default();
+        locations
+            .push(
+                Location {
+                    latitude_deg: subpoint.latitude_deg().unwrap(),
+                    longitude_deg: subpoint.longitude_deg(),
+                    height_km: 0.0,
+                    frame: IAU_EARTH_FRAME.into(),
+                    terrain_mask: vec![],
+                    terrain_mask_ignored: true,
+                },
+                Some(1000),
+                Some("Overhead"),
+            )
+            .unwrap();
+        let almanac = almanac.with_location_data(locations);
+
+        let state_spec = StateSpec {
+            target_frame: FrameSpec::Loaded(sc),
+            observer_frame: FrameSpec::Loaded(EARTH_J2000),
+            ab_corr: None,
+        };
+        let end = epoch + Unit::Minute * 295;
+        let passes_above = |elevation_mask_deg: f64| {
+            almanac.report_passes(
+                &state_spec,
+                1000,
+                epoch,
+                end,
+                Unit::Second * 5,
+                None,
+                5.0,
+                elevation_mask_deg,
+            )
+        };
+
+        let passes = passes_above(10.0).unwrap();
+        assert!(!passes.is_empty());
+        for pass in &passes {
+            println!("{pass}");
+            assert_eq!(pass.location_ref, "Overhead (#1000)");
+            assert!(pass.duration > Unit::Minute * 1);
+            assert!(pass.max_elevation_deg > 10.0);
+            assert!(
+                pass.aos_epoch <= pass.culmination_epoch
+                    && pass.culmination_epoch <= pass.los_epoch
+            );
+            for epoch in [pass.aos_epoch, pass.los_epoch] {
+                let state = almanac.transform(sc, EARTH_J2000, epoch, None).unwrap();
+                let elevation_deg = almanac
+                    .azimuth_elevation_range_sez_from_location_id(state, 1000, None, None)
+                    .unwrap()
+                    .elevation_deg;
+                assert!((elevation_deg - 10.0).abs() < 1e-2, "{elevation_deg}");
+            }
+            assert_eq!(pass.sun_interference, pass.min_sun_separation_deg < 5.0);
+        }
+        // The overhead pass culminates at the zenith.
+        assert!(
+            (passes[0].culmination_epoch - (epoch + Unit::Minute * 20)).abs() < Unit::Second * 5
+        );
+        assert!(passes[0].max_elevation_deg > 85.0);
+
+        // Without a mask, the passes are the visibility arcs, and a higher mask shortens them.
+        let arcs = almanac
+            .report_visibility_arcs(&state_spec, 1000, epoch, end, Unit::Second * 5, None)
+            .unwrap();
+        let horizon_passes = passes_above(0.0).unwrap();
+        assert_eq!(horizon_passes.len(), arcs.len());
+        for (pass, arc) in horizon_passes.iter().zip(&arcs) {
+            assert_eq!(pass.aos_epoch, arc.start_epoch());
+            assert_eq!(pass.los_epoch, arc.end_epoch());
+        }
+        assert!(horizon_passes[0].duration > passes[0].duration);
+        assert!(passes_above(45.0).unwrap()[0].duration < passes[0].duration);
+
+        assert!(almanac
+            .report_passes(
+                &state_spec,
+                1001,
+                epoch,
+                end,
+                Unit::Second * 5,
+                None,
+                5.0,
+                10.0
+            )
+            .is_err());
+    }
 }
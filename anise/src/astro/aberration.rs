@@ -15,6 +15,7 @@ use crate::{
 };
 
 use core::fmt;
+use core::str::FromStr;
 
 #[cfg(feature = "analysis")]
 use serde::{Deserialize, Serialize};
@@ -121,8 +122,15 @@ impl Aberration {
     /// + `XLT+S`: unconverged light time, with stellar aberration, transmission mode
     /// + `XCN`: converged light time, no stellar aberration, transmission mode
     /// + `XCN+S`: converged light time, with stellar aberration, transmission mode
+    ///
+    /// The flag is case insensitive and may include whitespace, e.g. `lt + s` is `LT+S`.
     pub fn new(flag: &str) -> PhysicsResult<Option<Self>> {
-        match flag.trim() {
+        let flag = flag
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_uppercase();
+        match flag.as_str() {
             "NONE" => Ok(Self::NONE),
             "LT" => Ok(Self::LT),
             "LT+S" => Ok(Self::LT_S),
@@ -139,6 +147,17 @@ impl Aberration {
     }
 }
 
+impl FromStr for Aberration {
+    type Err = PhysicsError;
+
+    /// Parses a SPICE aberration flag as in [Aberration::new]. Since `NONE` is represented as `None`, it cannot be parsed into an Aberration.
+    fn from_str(flag: &str) -> Result<Self, Self::Err> {
+        Self::new(flag)?.ok_or(PhysicsError::AberrationError {
+            action: "parse `NONE` into an Aberration, use `Aberration::new` instead",
+        })
+    }
+}
+
 #[cfg(feature = "python")]
 #[pymethods]
 impl Aberration {
@@ -152,6 +171,8 @@ impl Aberration {
     /// + `XLT+S`: unconverged light time, with stellar aberration, transmission mode
     /// + `XCN`: converged light time, no stellar aberration, transmission mode
     /// + `XCN+S`: converged light time, with stellar aberration, transmission mode
+    ///
+    /// The name is case insensitive and may include whitespace, e.g. `lt + s` is `LT+S`.
     #[new]
     fn py_new(name: String) -> PhysicsResult<Self> {
         match Self::new(&name)? {
@@ -328,6 +349,63 @@ pub fn stellar_aberration(
     Ok(app_target_pos_km)
 }
 
+/// Returns the apparent position and velocity of the target, corrected for stellar aberration.
+///
+/// # Arguments
+///
+/// + `target_pos_km`: the (light time corrected) position of a target object with respect to the observer in kilometers
+/// + `target_vel_km_s`: the (light time corrected) velocity of a target object with respect to the observer in kilometers per second
+/// + `obs_wrt_ssb_vel_km_s`: the velocity of the observer with respect to the Solar System Barycenter in kilometers per second
+/// + `obs_wrt_ssb_acc_km_s2`: the acceleration of the observer with respect to the Solar System Barycenter in kilometers per second squared
+/// + `ab_corr`: the [Aberration] correction
+///
+/// # Errors
+///
+/// Same as [stellar_aberration].
+///
+/// # Algorithm
+/// Since the rotation axis `h = u X v/c` is orthogonal to the target position `r`, the rotation of [stellar_aberration] is equivalent to
+///
+/// `r' = r sqrt(1 - |h|^2) + h X r`
+///
+/// The corrected velocity is the time derivative of this expression, where the rate of change of `h` depends on the rate of change of the
+/// direction to the target and on the acceleration of the observer. This matches the velocity correction of NAIF's `zzstelab`.
+pub fn stellar_aberration_state(
+    target_pos_km: Vector3,
+    target_vel_km_s: Vector3,
+    obs_wrt_ssb_vel_km_s: Vector3,
+    obs_wrt_ssb_acc_km_s2: Vector3,
+    ab_corr: Aberration,
+) -> PhysicsResult<(Vector3, Vector3)> {
+    let app_target_pos_km = stellar_aberration(target_pos_km, obs_wrt_ssb_vel_km_s, ab_corr)?;
+
+    let r_norm = target_pos_km.norm();
+    if r_norm < f64::EPSILON {
+        return Ok((app_target_pos_km, target_vel_km_s));
+    }
+
+    let sign = if ab_corr.transmit_mode { -1.0 } else { 1.0 };
+    let vbyc = sign * obs_wrt_ssb_vel_km_s / SPEED_OF_LIGHT_KM_S;
+    let abyc = sign * obs_wrt_ssb_acc_km_s2 / SPEED_OF_LIGHT_KM_S;
+
+    let u = target_pos_km / r_norm;
+    // Rate of change of the unit vector pointing to the target.
+    let u_dot = (target_vel_km_s - u * u.dot(&target_vel_km_s)) / r_norm;
+
+    let h = u.cross(&vbyc);
+    let h_dot = u_dot.cross(&vbyc) + u.cross(&abyc);
+
+    let cos_phi = (1.0 - h.dot(&h)).sqrt();
+    let cos_phi_dot = -h.dot(&h_dot) / cos_phi;
+
+    let app_target_vel_km_s = target_vel_km_s * cos_phi
+        + target_pos_km * cos_phi_dot
+        + h_dot.cross(&target_pos_km)
+        + h.cross(&target_vel_km_s);
+
+    Ok((app_target_pos_km, app_target_vel_km_s))
+}
+
 #[cfg(test)]
 mod ut_aberration {
     #[test]
@@ -344,4 +422,74 @@ mod ut_aberration {
         assert_eq!(format!("{:?}", Aberration::XCN.unwrap()), "XCN");
         assert_eq!(format!("{:?}", Aberration::XCN_S.unwrap()), "XCN+S");
     }
+
+    #[test]
+    fn from_str() {
+        use super::Aberration;
+        use core::str::FromStr;
+
+        assert_eq!(
+            Aberration::from_str("LT+S").unwrap(),
+            Aberration::LT_S.unwrap()
+        );
+        assert_eq!(
+            Aberration::from_str(" lt + s ").unwrap(),
+            Aberration::LT_S.unwrap()
+        );
+        assert_eq!(
+            "xcn+S".parse::<Aberration>().unwrap(),
+            Aberration::XCN_S.unwrap()
+        );
+        assert_eq!(Aberration::from_str("Cn").unwrap(), Aberration::CN.unwrap());
+        assert_eq!(Aberration::new(" none ").unwrap(), Aberration::NONE);
+        assert!(Aberration::from_str("NONE").is_err());
+        assert!(Aberration::from_str("LT+").is_err());
+        assert!(Aberration::from_str("").is_err());
+    }
+
+    #[test]
+    fn stellar_aberration_velocity() {
+        use super::{stellar_aberration, stellar_aberration_state, Aberration};
+        use crate::math::Vector3;
+
+        let pos_km = Vector3::new(1.5e8, -2.0e7, 3.0e6);
+        let vel_km_s = Vector3::new(-5.0, 25.0, 1.0);
+        let obs_vel_km_s = Vector3::new(10.0, 28.0, -3.0);
+        let obs_acc_km_s2 = Vector3::new(-6.0e-6, 2.0e-6, 1.0e-7);
+
+        for ab_corr in [Aberration::LT_S.unwrap(), Aberration::XLT_S.unwrap()] {
+            let (app_pos_km, app_vel_km_s) =
+                stellar_aberration_state(pos_km, vel_km_s, obs_vel_km_s, obs_acc_km_s2, ab_corr)
+                    .unwrap();
+            assert_eq!(
+                app_pos_km,
+                stellar_aberration(pos_km, obs_vel_km_s, ab_corr).unwrap()
+            );
+
+            // Compare with the central difference of the apparent position of a target and observer in uniform (accelerated) motion
+            let dt_s = 10.0;
+            let apparent_at = |t_s: f64| {
+                stellar_aberration(
+                    pos_km + vel_km_s * t_s,
+                    obs_vel_km_s + obs_acc_km_s2 * t_s,
+                    ab_corr,
+                )
+                .unwrap()
+            };
+            let numerical_vel_km_s = (apparent_at(dt_s) - apparent_at(-dt_s)) / (2.0 * dt_s);
+            assert!((app_vel_km_s - numerical_vel_km_s).norm() < 1e-8);
+            // The correction of the velocity is not negligible
+            assert!((app_vel_km_s - vel_km_s).norm() > 1e-4);
+        }
+
+        // Only available with stellar aberration
+        assert!(stellar_aberration_state(
+            pos_km,
+            vel_km_s,
+            obs_vel_km_s,
+            obs_acc_km_s2,
+            Aberration::LT.unwrap()
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,263 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+use hifitime::{Duration, Epoch};
+
+use super::{orbit::Orbit, PhysicsResult};
+use crate::errors::{MathError, PhysicsError};
+use crate::math::{Vector3, Vector6};
+use crate::prelude::Frame;
+
+/// Canonical unit system of a central body, where the gravitational parameter is one distance unit (DU) cubed per time unit (TU) squared.
+///
+/// The distance unit is chosen (e.g. the radius of the central body for orbiters, or the distance between the primaries of the circular
+/// restricted three body problem), and the time unit follows from the gravitational parameter: TU = sqrt(DU^3 / GM). A circular orbit of
+/// one DU then has a speed of one DU/TU and a period of 2 pi TU.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CanonicalUnits {
+    /// Distance unit, in km
+    pub du_km: f64,
+    /// Time unit, in seconds
+    pub tu_s: f64,
+}
+
+impl CanonicalUnits {
+    /// Returns the canonical units of the provided gravitational parameter and distance unit.
+    ///
+    /// # Errors
+    /// Either value is not strictly positive and finite.
+    pub fn new(mu_km3_s2: f64, du_km: f64) -> PhysicsResult<Self> {
+        for (value, msg) in [
+            (mu_km3_s2, "the gravitational parameter must be positive"),
+            (du_km, "the canonical distance unit must be positive"),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(PhysicsError::AppliedMath {
+                    source: MathError::DomainError { value, msg },
+                });
+            }
+        }
+
+        Ok(Self {
+            du_km,
+            tu_s: (du_km.powi(3) / mu_km3_s2).sqrt(),
+        })
+    }
+
+    /// Returns the canonical units of the central body of the provided frame, where the distance unit is its semi major equatorial radius.
+    /// The frame must have its gravitational parameter and its shape, e.g. as returned by `Almanac::frame_info`.
+    pub fn from_frame(frame: Frame) -> PhysicsResult<Self> {
+        Self::new(frame.mu_km3_s2()?, frame.semi_major_radius_km()?)
+    }
+
+    /// Returns the canonical units of the central body of the provided frame with the provided distance unit.
+    pub fn from_frame_with_du(frame: Frame, du_km: f64) -> PhysicsResult<Self> {
+        Self::new(frame.mu_km3_s2()?, du_km)
+    }
+
+    /// Returns the gravitational parameter of these units, in km^3/s^2.
+    pub fn mu_km3_s2(&self) -> f64 {
+        self.du_km.powi(3) / self.tu_s.powi(2)
+    }
+
+    /// Returns the velocity unit (DU/TU), in km/s.
+    pub fn vu_km_s(&self) -> f64 {
+        self.du_km / self.tu_s
+    }
+
+    /// Returns the time unit as a Duration.
+    pub fn tu(&self) -> Duration {
+        Duration::from_seconds(self.tu_s)
+    }
+
+    /// Converts a distance in km to DU.
+    pub fn length_to_canonical(&self, km: f64) -> f64 {
+        km / self.du_km
+    }
+
+    /// Converts a distance in DU to km.
+    pub fn length_from_canonical(&self, du: f64) -> f64 {
+        du * self.du_km
+    }
+
+    /// Converts a speed in km/s to DU/TU.
+    pub fn velocity_to_canonical(&self, km_s: f64) -> f64 {
+        km_s / self.vu_km_s()
+    }
+
+    /// Converts a speed in DU/TU to km/s.
+    pub fn velocity_from_canonical(&self, du_tu: f64) -> f64 {
+        du_tu * self.vu_km_s()
+    }
+
+    /// Converts a duration to TU.
+    pub fn duration_to_canonical(&self, duration: Duration) -> f64 {
+        duration.to_seconds() / self.tu_s
+    }
+
+    /// Converts a duration in TU to a Duration.
+    pub fn duration_from_canonical(&self, tu: f64) -> Duration {
+        Duration::from_seconds(tu * self.tu_s)
+    }
+
+    /// Converts a specific energy in km^2/s^2 to DU^2/TU^2.
+    pub fn energy_to_canonical(&self, km2_s2: f64) -> f64 {
+        km2_s2 / self.vu_km_s().powi(2)
+    }
+
+    /// Converts a specific energy in DU^2/TU^2 to km^2/s^2.
+    pub fn energy_from_canonical(&self, du2_tu2: f64) -> f64 {
+        du2_tu2 * self.vu_km_s().powi(2)
+    }
+
+    /// Returns the position (DU) and velocity (DU/TU) of the provided state in these units.
+    pub fn state_to_canonical(&self, state: &Orbit) -> Vector6 {
+        let r = state.radius_km / self.du_km;
+        let v = state.velocity_km_s / self.vu_km_s();
+        Vector6::new(r.x, r.y, r.z, v.x, v.y, v.z)
+    }
+
+    /// Returns the state of the provided position (DU) and velocity (DU/TU) at the provided epoch in the provided frame.
+    pub fn state_from_canonical(&self, pos_vel: Vector6, epoch: Epoch, frame: Frame) -> Orbit {
+        let r: Vector3 = pos_vel.fixed_rows::<3>(0) * self.du_km;
+        let v: Vector3 = pos_vel.fixed_rows::<3>(3) * self.vu_km_s();
+        Orbit::new(r.x, r.y, r.z, v.x, v.y, v.z, epoch, frame)
+    }
+
+    /// Returns the Keplerian elements of the provided state in these units: the semi major axis in DU, the eccentricity, and the
+    /// inclination, RAAN, argument of periapsis, and true anomaly in degrees.
+    ///
+    /// The elements are computed with the gravitational parameter of the frame of the state, which should match that of these units.
+    pub fn keplerian_to_canonical(&self, state: &Orbit) -> PhysicsResult<[f64; 6]> {
+        Ok([
+            self.length_to_canonical(state.sma_km()?),
+            state.ecc()?,
+            state.inc_deg()?,
+            state.raan_deg()?,
+            state.aop_deg()?,
+            state.ta_deg()?,
+        ])
+    }
+
+    /// Returns the state of the provided Keplerian elements, with the semi major axis in DU and the angles in degrees, at the provided
+    /// epoch in the provided frame, whose gravitational parameter is set to that of these units.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_keplerian_from_canonical(
+        &self,
+        sma_du: f64,
+        ecc: f64,
+        inc_deg: f64,
+        raan_deg: f64,
+        aop_deg: f64,
+        ta_deg: f64,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Orbit> {
+        Orbit::try_keplerian(
+            self.length_from_canonical(sma_du),
+            ecc,
+            inc_deg,
+            raan_deg,
+            aop_deg,
+            ta_deg,
+            epoch,
+            frame.with_mu_km3_s2(self.mu_km3_s2()),
+        )
+    }
+}
+
+impl fmt::Display for CanonicalUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "1 DU = {} km, 1 TU = {} s, 1 DU/TU = {} km/s",
+            self.du_km,
+            self.tu_s,
+            self.vu_km_s()
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_canonical {
+    use super::CanonicalUnits;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::Vector6;
+    use crate::prelude::{Frame, Orbit};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use core::f64::consts::TAU;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn canonical_units() {
+        let mu_km3_s2 = 398_600.435_436;
+        let frame = EARTH_J2000
+            .with_mu_km3_s2(mu_km3_s2)
+            .with_ellipsoid(Ellipsoid::WGS84);
+        let units = CanonicalUnits::from_frame(frame).unwrap();
+        println!("{units}");
+
+        // Vallado, table D-1: 1 TU = 806.81 s and 1 DU/TU = 7.905 km/s for the Earth
+        assert_eq!(units.du_km, 6378.137);
+        assert!((units.tu_s - 806.811).abs() < 1e-3);
+        assert!((units.vu_km_s() - 7.905_366).abs() < 1e-6);
+        assert!((units.mu_km3_s2() - mu_km3_s2).abs() < 1e-6);
+
+        // A circular orbit of one DU has a unit speed and a period of 2 pi TU.
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let orbit = units
+            .try_keplerian_from_canonical(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, epoch, frame)
+            .unwrap();
+        let pos_vel = units.state_to_canonical(&orbit);
+        assert!((pos_vel - Vector6::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0)).norm() < 1e-12);
+        assert!((units.duration_to_canonical(orbit.period().unwrap()) - TAU).abs() < 1e-9);
+        assert!((units.energy_to_canonical(orbit.energy_km2_s2().unwrap()) + 0.5).abs() < 1e-12);
+
+        // Round trips
+        let orbit =
+            Orbit::try_keplerian(26_600.0, 0.74, 63.4, 10.0, 270.0, 45.0, epoch, frame).unwrap();
+        let back = units.state_from_canonical(units.state_to_canonical(&orbit), epoch, frame);
+        assert!((back.radius_km - orbit.radius_km).norm() < 1e-9);
+        assert!((back.velocity_km_s - orbit.velocity_km_s).norm() < 1e-12);
+        let elements = units.keplerian_to_canonical(&orbit).unwrap();
+        assert!((elements[0] - 26_600.0 / 6378.137).abs() < 1e-9);
+        let [sma_du, ecc, inc_deg, raan_deg, aop_deg, ta_deg] = elements;
+        let back = units
+            .try_keplerian_from_canonical(
+                sma_du, ecc, inc_deg, raan_deg, aop_deg, ta_deg, epoch, frame,
+            )
+            .unwrap();
+        assert!((back.radius_km - orbit.radius_km).norm() < 1e-6);
+        assert_eq!(
+            units.duration_from_canonical(units.duration_to_canonical(Unit::Hour * 3)),
+            Unit::Hour * 3
+        );
+        assert!(
+            (units.length_from_canonical(units.length_to_canonical(42_164.0)) - 42_164.0).abs()
+                < 1e-9
+        );
+        assert!(
+            (units.velocity_from_canonical(units.velocity_to_canonical(3.07)) - 3.07).abs() < 1e-12
+        );
+        assert!((units.energy_from_canonical(units.energy_to_canonical(-4.7)) + 4.7).abs() < 1e-12);
+
+        // Earth-Moon system, with the distance between the primaries as the distance unit
+        let em = CanonicalUnits::new(398_600.435_436 + 4_902.800_066, 384_400.0).unwrap();
+        assert!((em.tu().to_unit(Unit::Day) - 4.342).abs() < 1e-3);
+
+        // Invalid units
+        assert!(CanonicalUnits::new(0.0, 1.0).is_err());
+        assert!(CanonicalUnits::new(1.0, -1.0).is_err());
+        assert!(CanonicalUnits::from_frame(EARTH_J2000.with_mu_km3_s2(mu_km3_s2)).is_err());
+        assert!(CanonicalUnits::from_frame_with_du(Frame::from_ephem_j2000(-85), 1.0).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+use hifitime::Epoch;
+use snafu::ensure;
+
+use super::PhysicsResult;
+use crate::{
+    errors::{FlybyPeriapsisSnafu, PhysicsError, VelocitySnafu},
+    frames::Frame,
+    math::Vector3,
+};
+
+/// Patched-conic model of a gravity assist (flyby) about a celestial body.
+///
+/// The flyby is modeled as an instantaneous rotation of the hyperbolic excess velocity vector (v-infinity) with respect to the flyby body.
+/// It is fully defined by the incoming v-infinity vector and the B-plane target, where the B-plane is the plane perpendicular to the incoming
+/// asymptote and containing the center of the flyby body.
+///
+/// # B-plane frame
+/// + `S` is the unit vector along the incoming v-infinity;
+/// + `T` is the unit vector of `S` crossed with the Z axis of the flyby frame (e.g. the body's pole or the ecliptic pole);
+/// + `R` completes the right handed frame, i.e. `R = S x T`.
+///
+/// # Frame data
+/// The frame of the flyby must include its gravitational parameter and its shape, e.g. by fetching it from `almanac.frame_info(...)`,
+/// as the periapsis of the flyby is constrained to be above the largest equatorial radius of the flyby body.
+///
+/// # Algorithm
+/// For a v-infinity magnitude `v` and a B-plane magnitude `b`, the periapsis radius and the eccentricity of the hyperbola are:
+///
+/// `rp = mu / v^2 * (sqrt(1 + (b v^2 / mu)^2) - 1)` and `e = 1 + rp v^2 / mu`
+///
+/// The turn angle `δ` is such that `sin(δ/2) = 1/e`, and the outgoing v-infinity is the incoming one rotated by `δ` towards `-B`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Flyby {
+    /// Epoch of the (instantaneous) flyby
+    pub epoch: Epoch,
+    /// Frame of the flyby body, must include the gravitational parameter and the shape of the body
+    pub frame: Frame,
+    /// Incoming hyperbolic excess velocity vector with respect to the flyby body
+    pub vinf_in_km_s: Vector3,
+    /// B-plane target component along T
+    pub b_dot_t_km: f64,
+    /// B-plane target component along R
+    pub b_dot_r_km: f64,
+}
+
+impl Flyby {
+    /// Initializes a new flyby from the incoming v-infinity vector and the B-plane target.
+    ///
+    /// # Errors
+    /// + The flyby frame does not define its gravitational parameter or its shape;
+    /// + The incoming v-infinity vector is zero or parallel to the Z axis of the frame (B-plane is undefined);
+    /// + The periapsis radius is below the largest equatorial radius of the flyby body.
+    pub fn from_b_plane(
+        vinf_in_km_s: Vector3,
+        b_dot_t_km: f64,
+        b_dot_r_km: f64,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Self> {
+        let me = Self {
+            epoch,
+            frame,
+            vinf_in_km_s,
+            b_dot_t_km,
+            b_dot_r_km,
+        };
+        // Ensure that the B-plane is defined
+        me.b_plane_basis()?;
+        // Check the periapsis constraint
+        let body_radius_km = frame.semi_major_radius_km()?;
+        let periapsis_km = me.periapsis_km()?;
+        ensure!(
+            periapsis_km > body_radius_km,
+            FlybyPeriapsisSnafu {
+                periapsis_km,
+                body_radius_km
+            }
+        );
+
+        Ok(me)
+    }
+
+    /// Initializes a new flyby from the incoming v-infinity vector, the desired periapsis radius, and the angle of the B vector
+    /// in the B-plane, measured from T towards R, in degrees.
+    ///
+    /// Refer to [Self::from_b_plane] for the possible errors.
+    pub fn from_periapsis(
+        vinf_in_km_s: Vector3,
+        periapsis_km: f64,
+        b_plane_angle_deg: f64,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Self> {
+        let vinf_km_s = vinf_in_km_s.norm();
+        ensure!(
+            vinf_km_s > f64::EPSILON,
+            VelocitySnafu {
+                action: "flyby v-infinity is zero"
+            }
+        );
+        let mu_km3_s2 = frame.mu_km3_s2()?;
+        let b_mag_km =
+            periapsis_km * (1.0 + 2.0 * mu_km3_s2 / (periapsis_km * vinf_km_s.powi(2))).sqrt();
+        let (sin_theta, cos_theta) = b_plane_angle_deg.to_radians().sin_cos();
+
+        Self::from_b_plane(
+            vinf_in_km_s,
+            b_mag_km * cos_theta,
+            b_mag_km * sin_theta,
+            epoch,
+            frame,
+        )
+    }
+
+    /// Returns the unit vectors (S, T, R) of the B-plane frame, expressed in the flyby frame.
+    pub fn b_plane_basis(&self) -> PhysicsResult<(Vector3, Vector3, Vector3)> {
+        ensure!(
+            self.vinf_km_s() > f64::EPSILON,
+            VelocitySnafu {
+                action: "flyby v-infinity is zero"
+            }
+        );
+        let s_hat = self.vinf_in_km_s.normalize();
+        let t_vec = s_hat.cross(&Vector3::z());
+        ensure!(
+            t_vec.norm() > 1e-12,
+            VelocitySnafu {
+                action:
+                    "flyby v-infinity is parallel to the Z axis of the frame, B-plane undefined"
+            }
+        );
+        let t_hat = t_vec.normalize();
+        let r_hat = s_hat.cross(&t_hat);
+        Ok((s_hat, t_hat, r_hat))
+    }
+
+    /// Returns the B vector of this flyby, expressed in the flyby frame.
+    pub fn b_vector_km(&self) -> PhysicsResult<Vector3> {
+        let (_, t_hat, r_hat) = self.b_plane_basis()?;
+        Ok(self.b_dot_t_km * t_hat + self.b_dot_r_km * r_hat)
+    }
+
+    /// Returns the magnitude of the hyperbolic excess velocity in km/s, which is preserved by the flyby.
+    pub fn vinf_km_s(&self) -> f64 {
+        self.vinf_in_km_s.norm()
+    }
+
+    /// Returns the magnitude of the B vector in km, i.e. the impact parameter of the flyby.
+    pub fn b_mag_km(&self) -> f64 {
+        self.b_dot_t_km.hypot(self.b_dot_r_km)
+    }
+
+    /// Returns the angle of the B vector in the B-plane, measured from T towards R, in degrees.
+    pub fn b_plane_angle_deg(&self) -> f64 {
+        self.b_dot_r_km.atan2(self.b_dot_t_km).to_degrees()
+    }
+
+    /// Returns the radius of periapsis of the flyby hyperbola, in km.
+    pub fn periapsis_km(&self) -> PhysicsResult<f64> {
+        let mu_km3_s2 = self.frame.mu_km3_s2()?;
+        let v2 = self.vinf_km_s().powi(2);
+        ensure!(
+            v2 > f64::EPSILON,
+            VelocitySnafu {
+                action: "flyby v-infinity is zero"
+            }
+        );
+        Ok(mu_km3_s2 / v2 * ((1.0 + (self.b_mag_km() * v2 / mu_km3_s2).powi(2)).sqrt() - 1.0))
+    }
+
+    /// Returns the altitude of periapsis of the flyby above the largest equatorial radius of the flyby body, in km.
+    pub fn periapsis_altitude_km(&self) -> PhysicsResult<f64> {
+        Ok(self.periapsis_km()? - self.frame.semi_major_radius_km()?)
+    }
+
+    /// Returns the eccentricity of the flyby hyperbola.
+    pub fn ecc(&self) -> PhysicsResult<f64> {
+        Ok(1.0 + self.periapsis_km()? * self.vinf_km_s().powi(2) / self.frame.mu_km3_s2()?)
+    }
+
+    /// Returns the turn angle of the flyby, i.e. the angle between the incoming and outgoing v-infinity vectors, in degrees.
+    pub fn turn_angle_deg(&self) -> PhysicsResult<f64> {
+        Ok(2.0 * (1.0 / self.ecc()?).asin().to_degrees())
+    }
+
+    /// Returns the outgoing hyperbolic excess velocity vector, expressed in the flyby frame.
+    pub fn vinf_out_km_s(&self) -> PhysicsResult<Vector3> {
+        let (s_hat, t_hat, r_hat) = self.b_plane_basis()?;
+        let (sin_delta, cos_delta) = self.turn_angle_deg()?.to_radians().sin_cos();
+
+        // If the B vector is null, the flyby is a direct impact, which the periapsis check prevents.
+        let b_hat = if self.b_mag_km() > f64::EPSILON {
+            (self.b_dot_t_km * t_hat + self.b_dot_r_km * r_hat) / self.b_mag_km()
+        } else {
+            return Err(PhysicsError::RadiusError {
+                action: "flyby B vector is zero",
+            });
+        };
+
+        Ok(self.vinf_km_s() * (cos_delta * s_hat - sin_delta * b_hat))
+    }
+
+    /// Returns the velocity change imparted by the flyby, i.e. the outgoing minus the incoming v-infinity vectors.
+    pub fn delta_v_km_s(&self) -> PhysicsResult<Vector3> {
+        Ok(self.vinf_out_km_s()? - self.vinf_in_km_s)
+    }
+
+    /// Returns the maximum turn angle achievable with this v-infinity magnitude while remaining above the provided altitude
+    /// over the largest equatorial radius of the flyby body, in degrees.
+    pub fn max_turn_angle_deg(&self, min_altitude_km: f64) -> PhysicsResult<f64> {
+        let rp_min_km = self.frame.semi_major_radius_km()? + min_altitude_km;
+        let ecc = 1.0 + rp_min_km * self.vinf_km_s().powi(2) / self.frame.mu_km3_s2()?;
+        Ok(2.0 * (1.0 / ecc).asin().to_degrees())
+    }
+}
+
+impl fmt::Display for Flyby {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "flyby of {:e} @ {}: v-inf = {:.6} km/s    B·T = {:.3} km    B·R = {:.3} km    rp = {:.3} km    turn angle = {:.6} deg",
+            self.frame,
+            self.epoch,
+            self.vinf_km_s(),
+            self.b_dot_t_km,
+            self.b_dot_r_km,
+            self.periapsis_km().unwrap_or(f64::NAN),
+            self.turn_angle_deg().unwrap_or(f64::NAN)
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_flyby {
+    use super::Flyby;
+    use crate::constants::frames::MARS_BARYCENTER_J2000;
+    use crate::errors::PhysicsError;
+    use crate::math::Vector3;
+    use crate::prelude::Epoch;
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+
+    #[test]
+    fn flyby_geometry() {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let mars = MARS_BARYCENTER_J2000
+            .with_mu_km3_s2(42_828.37)
+            .with_ellipsoid(Ellipsoid::from_spheroid(3396.19, 3376.2));
+
+        let vinf_in = Vector3::new(3.0, 4.0, 0.5);
+        let flyby = Flyby::from_periapsis(vinf_in, 3396.19 + 500.0, 30.0, epoch, mars).unwrap();
+
+        // Periapsis and B-plane angle are recovered
+        assert!((flyby.periapsis_km().unwrap() - 3896.19).abs() < 1e-9);
+        assert!((flyby.periapsis_altitude_km().unwrap() - 500.0).abs() < 1e-9);
+        assert!((flyby.b_plane_angle_deg() - 30.0).abs() < 1e-12);
+        // Hyperbola is valid
+        assert!(flyby.ecc().unwrap() > 1.0);
+
+        // The v-infinity magnitude is preserved and the outgoing vector is rotated by the turn angle
+        let vinf_out = flyby.vinf_out_km_s().unwrap();
+        assert!((vinf_out.norm() - vinf_in.norm()).abs() < 1e-12);
+        let angle_deg = vinf_in.angle(&vinf_out).to_degrees();
+        assert!((angle_deg - flyby.turn_angle_deg().unwrap()).abs() < 1e-9);
+
+        // The bending happens towards the body, i.e. away from the B vector, in the plane of S and B.
+        let b_vec = flyby.b_vector_km().unwrap();
+        assert!(flyby.delta_v_km_s().unwrap().dot(&b_vec) < 0.0);
+        assert!(vinf_out.dot(&vinf_in.cross(&b_vec)).abs() < 1e-9);
+
+        // Lower periapsis bends more, and the maximum turn angle is reached at the surface
+        let lower = Flyby::from_periapsis(vinf_in, 3396.19 + 200.0, 30.0, epoch, mars).unwrap();
+        assert!(lower.turn_angle_deg().unwrap() > flyby.turn_angle_deg().unwrap());
+        assert!(
+            (lower.max_turn_angle_deg(200.0).unwrap() - lower.turn_angle_deg().unwrap()).abs()
+                < 1e-9
+        );
+
+        println!("{flyby}");
+    }
+
+    #[test]
+    fn flyby_constraints() {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let mars = MARS_BARYCENTER_J2000
+            .with_mu_km3_s2(42_828.37)
+            .with_ellipsoid(Ellipsoid::from_spheroid(3396.19, 3376.2));
+
+        // Impacting flyby
+        assert!(matches!(
+            Flyby::from_b_plane(Vector3::new(3.0, 4.0, 0.5), 1000.0, 0.0, epoch, mars),
+            Err(PhysicsError::FlybyPeriapsis { .. })
+        ));
+
+        // Undefined B-plane
+        assert!(Flyby::from_b_plane(Vector3::new(0.0, 0.0, 5.0), 1e4, 0.0, epoch, mars).is_err());
+
+        // Missing planetary data
+        assert!(Flyby::from_b_plane(
+            Vector3::new(3.0, 4.0, 0.5),
+            1e4,
+            0.0,
+            epoch,
+            MARS_BARYCENTER_J2000
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,322 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::f64::consts::PI;
+use core::fmt;
+
+use hifitime::{Epoch, DAYS_PER_YEAR, JD_J2000, SECONDS_PER_YEAR};
+use snafu::ensure;
+
+use super::PhysicsResult;
+use crate::{
+    constants::{
+        celestial_objects::EARTH,
+        orientations::{IAU_EARTH, ITRF93},
+    },
+    errors::HelmertFrameSnafu,
+    frames::FrameUid,
+    math::{cartesian::CartesianState, Matrix3, Vector3},
+};
+
+/// Milliarcseconds to radians
+const MAS_TO_RAD: f64 = PI / (180.0 * 3600.0 * 1000.0);
+
+/// Realizations of the International Terrestrial Reference Frame (ITRF).
+///
+/// The loaded Earth-fixed frame (e.g. `ITRF93` from a high precision Earth BPC) provides the orientation of the Earth,
+/// whereas each ITRF realization differs from the others by a small (millimeter level) Helmert transformation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ItrfRealization {
+    ITRF2005,
+    ITRF2008,
+    ITRF2014,
+    ITRF2020,
+}
+
+impl ItrfRealization {
+    /// Returns the IERS published Helmert transformation from ITRF2020 to this realization.
+    pub const fn helmert_from_itrf2020(&self) -> HelmertTransform {
+        match self {
+            Self::ITRF2005 => HelmertTransform::ITRF2020_TO_ITRF2005,
+            Self::ITRF2008 => HelmertTransform::ITRF2020_TO_ITRF2008,
+            Self::ITRF2014 => HelmertTransform::ITRF2020_TO_ITRF2014,
+            Self::ITRF2020 => HelmertTransform::IDENTITY,
+        }
+    }
+
+    /// Returns the Helmert transformation from this realization to the `other` realization, going through ITRF2020.
+    pub fn helmert_to(&self, other: Self) -> HelmertTransform {
+        other
+            .helmert_from_itrf2020()
+            .compose(&self.helmert_from_itrf2020().inverse())
+    }
+}
+
+impl fmt::Display for ItrfRealization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Fourteen parameter (seven parameters and their rates) Helmert transformation between two realizations of a terrestrial reference frame.
+///
+/// The parameters follow the IERS conventions and units: at epoch `t`, each parameter is `P(t) = P + dP/dt * (t - t0)`, and the transformation is
+///
+/// `X2 = X1 + T + D X1 + R X1` where `R = [[0, -R3, R2], [R3, 0, -R1], [-R2, R1, 0]]`
+///
+/// This is a linearized transformation, valid because all of the parameters are tiny: its inverse is obtained by negating all of the parameters.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HelmertTransform {
+    /// Translation (T1, T2, T3) in millimeters
+    pub translation_mm: [f64; 3],
+    /// Translation rate in millimeters per year
+    pub translation_rate_mm_yr: [f64; 3],
+    /// Scale factor (D) in parts per billion
+    pub scale_ppb: f64,
+    /// Scale factor rate in parts per billion per year
+    pub scale_rate_ppb_yr: f64,
+    /// Rotation (R1, R2, R3) in milliarcseconds
+    pub rotation_mas: [f64; 3],
+    /// Rotation rate in milliarcseconds per year
+    pub rotation_rate_mas_yr: [f64; 3],
+    /// Reference epoch of the parameters, as a decimal year (e.g. 2015.0)
+    pub reference_year: f64,
+}
+
+impl HelmertTransform {
+    /// Identity transformation
+    pub const IDENTITY: Self = Self {
+        translation_mm: [0.0; 3],
+        translation_rate_mm_yr: [0.0; 3],
+        scale_ppb: 0.0,
+        scale_rate_ppb_yr: 0.0,
+        rotation_mas: [0.0; 3],
+        rotation_rate_mas_yr: [0.0; 3],
+        reference_year: 2015.0,
+    };
+
+    /// ITRF2020 to ITRF2014, as published by the IERS
+    pub const ITRF2020_TO_ITRF2014: Self = Self {
+        translation_mm: [-1.4, -0.9, 1.4],
+        translation_rate_mm_yr: [0.0, -0.1, 0.2],
+        scale_ppb: -0.42,
+        scale_rate_ppb_yr: 0.0,
+        rotation_mas: [0.0; 3],
+        rotation_rate_mas_yr: [0.0; 3],
+        reference_year: 2015.0,
+    };
+
+    /// ITRF2020 to ITRF2008, as published by the IERS
+    pub const ITRF2020_TO_ITRF2008: Self = Self {
+        translation_mm: [0.2, 1.0, 3.3],
+        translation_rate_mm_yr: [0.0, -0.1, 0.1],
+        scale_ppb: -0.29,
+        scale_rate_ppb_yr: 0.03,
+        rotation_mas: [0.0; 3],
+        rotation_rate_mas_yr: [0.0; 3],
+        reference_year: 2015.0,
+    };
+
+    /// ITRF2020 to ITRF2005, as published by the IERS
+    pub const ITRF2020_TO_ITRF2005: Self = Self {
+        translation_mm: [2.7, 0.1, -1.4],
+        translation_rate_mm_yr: [0.3, -0.1, 0.1],
+        scale_ppb: 0.65,
+        scale_rate_ppb_yr: 0.03,
+        rotation_mas: [0.0; 3],
+        rotation_rate_mas_yr: [0.0; 3],
+        reference_year: 2015.0,
+    };
+
+    /// Returns the inverse of this transformation, to first order.
+    pub fn inverse(&self) -> Self {
+        Self {
+            translation_mm: self.translation_mm.map(|x| -x),
+            translation_rate_mm_yr: self.translation_rate_mm_yr.map(|x| -x),
+            scale_ppb: -self.scale_ppb,
+            scale_rate_ppb_yr: -self.scale_rate_ppb_yr,
+            rotation_mas: self.rotation_mas.map(|x| -x),
+            rotation_rate_mas_yr: self.rotation_rate_mas_yr.map(|x| -x),
+            reference_year: self.reference_year,
+        }
+    }
+
+    /// Returns the transformation applying `first` and then `self`, to first order.
+    /// The parameters of `first` are moved to the reference epoch of `self` prior to being summed.
+    pub fn compose(&self, first: &Self) -> Self {
+        let first = first.at_reference_year(self.reference_year);
+        let sum = |a: [f64; 3], b: [f64; 3]| [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+        Self {
+            translation_mm: sum(self.translation_mm, first.translation_mm),
+            translation_rate_mm_yr: sum(self.translation_rate_mm_yr, first.translation_rate_mm_yr),
+            scale_ppb: self.scale_ppb + first.scale_ppb,
+            scale_rate_ppb_yr: self.scale_rate_ppb_yr + first.scale_rate_ppb_yr,
+            rotation_mas: sum(self.rotation_mas, first.rotation_mas),
+            rotation_rate_mas_yr: sum(self.rotation_rate_mas_yr, first.rotation_rate_mas_yr),
+            reference_year: self.reference_year,
+        }
+    }
+
+    /// Returns the same transformation with its parameters expressed at the provided reference year.
+    pub fn at_reference_year(&self, reference_year: f64) -> Self {
+        let dt_yr = reference_year - self.reference_year;
+        let propagate = |p: [f64; 3], dp: [f64; 3]| {
+            [
+                p[0] + dp[0] * dt_yr,
+                p[1] + dp[1] * dt_yr,
+                p[2] + dp[2] * dt_yr,
+            ]
+        };
+        Self {
+            translation_mm: propagate(self.translation_mm, self.translation_rate_mm_yr),
+            scale_ppb: self.scale_ppb + self.scale_rate_ppb_yr * dt_yr,
+            rotation_mas: propagate(self.rotation_mas, self.rotation_rate_mas_yr),
+            reference_year,
+            ..*self
+        }
+    }
+
+    /// Returns the translation (km), the scale factor (unitless), and the rotation matrix at the provided epoch.
+    fn parameters_at(&self, epoch: Epoch) -> (Vector3, f64, Matrix3) {
+        let me = self.at_reference_year(decimal_year(epoch));
+        (
+            Vector3::from(me.translation_mm) * 1e-6,
+            me.scale_ppb * 1e-9,
+            skew(Vector3::from(me.rotation_mas) * MAS_TO_RAD),
+        )
+    }
+
+    /// Returns the rate of the translation (km/s), of the scale factor (1/s), and of the rotation matrix (1/s).
+    fn rates(&self) -> (Vector3, f64, Matrix3) {
+        (
+            Vector3::from(self.translation_rate_mm_yr) * 1e-6 / SECONDS_PER_YEAR,
+            self.scale_rate_ppb_yr * 1e-9 / SECONDS_PER_YEAR,
+            skew(Vector3::from(self.rotation_rate_mas_yr) * MAS_TO_RAD / SECONDS_PER_YEAR),
+        )
+    }
+
+    /// Applies this transformation to the provided state, which must be expressed in an Earth centered, Earth-fixed frame.
+    ///
+    /// The returned state is in the same frame as the input state since ANISE does not distinguish between ITRF realizations:
+    /// it is up to the caller to track which realization the state is expressed in.
+    ///
+    /// # Errors
+    /// + The state is not centered on the Earth, or is not in the `ITRF93` nor `IAU_EARTH` orientation.
+    pub fn apply(&self, state: CartesianState) -> PhysicsResult<CartesianState> {
+        ensure!(
+            state.frame.ephem_origin_id_match(EARTH)
+                && (state.frame.orient_origin_id_match(ITRF93)
+                    || state.frame.orient_origin_id_match(IAU_EARTH)),
+            HelmertFrameSnafu {
+                frame: FrameUid::from(state.frame)
+            }
+        );
+
+        let (t_km, d, r) = self.parameters_at(state.epoch);
+        let (t_dot_km_s, d_dot, r_dot) = self.rates();
+
+        let mut out = state;
+        out.radius_km = state.radius_km + t_km + d * state.radius_km + r * state.radius_km;
+        out.velocity_km_s = state.velocity_km_s
+            + t_dot_km_s
+            + d_dot * state.radius_km
+            + r_dot * state.radius_km
+            + d * state.velocity_km_s
+            + r * state.velocity_km_s;
+
+        Ok(out)
+    }
+}
+
+impl fmt::Display for HelmertTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Helmert @ {:.1}: T = {:?} mm (+ {:?} mm/yr)    D = {} ppb (+ {} ppb/yr)    R = {:?} mas (+ {:?} mas/yr)",
+            self.reference_year,
+            self.translation_mm,
+            self.translation_rate_mm_yr,
+            self.scale_ppb,
+            self.scale_rate_ppb_yr,
+            self.rotation_mas,
+            self.rotation_rate_mas_yr
+        )
+    }
+}
+
+/// Returns the epoch as a decimal year in the TT time scale, e.g. 2015.0 is January 1st, 2015 at 12:00 TT.
+fn decimal_year(epoch: Epoch) -> f64 {
+    2000.0 + (epoch.to_jde_tt_days() - JD_J2000) / DAYS_PER_YEAR
+}
+
+/// Returns the skew symmetric matrix of the provided small rotation angles, per the IERS sign convention.
+fn skew(r: Vector3) -> Matrix3 {
+    Matrix3::new(0.0, -r.z, r.y, r.z, 0.0, -r.x, -r.y, r.x, 0.0)
+}
+
+#[cfg(test)]
+mod ut_helmert {
+    use super::*;
+    use crate::constants::frames::{EARTH_ITRF93, EARTH_J2000};
+    use hifitime::Unit;
+
+    #[test]
+    fn itrf2020_to_itrf2014() {
+        // At the reference epoch, only the translation and the scale matter.
+        let epoch = Epoch::from_jde_tdb(JD_J2000 + 15.0 * DAYS_PER_YEAR);
+        let state = CartesianState::new(6378.137, 0.0, 0.0, 0.0, 0.465, 0.0, epoch, EARTH_ITRF93);
+
+        let helmert = ItrfRealization::ITRF2020.helmert_to(ItrfRealization::ITRF2014);
+        let out = helmert.apply(state).unwrap();
+        let delta_km = out.radius_km - state.radius_km;
+        // T1 + D * X = -1.4 mm - 0.42e-9 * 6378137 mm
+        assert!((delta_km.x - (-1.4e-6 - 0.42e-9 * 6378.137)).abs() < 1e-9);
+        assert!((delta_km.y - (-0.9e-6)).abs() < 1e-9);
+        assert!((delta_km.z - 1.4e-6).abs() < 1e-9);
+        assert_eq!(out.frame, state.frame);
+
+        // Round trip
+        let back = ItrfRealization::ITRF2014
+            .helmert_to(ItrfRealization::ITRF2020)
+            .apply(out)
+            .unwrap();
+        assert!((back.radius_km - state.radius_km).norm() < 1e-12);
+
+        // Ten years later, the rates have moved the translation along Z by 2 mm
+        let later = helmert.apply(CartesianState {
+            epoch: epoch + Unit::Day * 10.0 * DAYS_PER_YEAR,
+            ..state
+        });
+        let delta_km = later.unwrap().radius_km - state.radius_km;
+        assert!((delta_km.z - 3.4e-6).abs() < 1e-9);
+
+        // Identity
+        let same = ItrfRealization::ITRF2014
+            .helmert_to(ItrfRealization::ITRF2014)
+            .apply(state)
+            .unwrap();
+        assert!((same.radius_km - state.radius_km).norm() < 1e-15);
+    }
+
+    #[test]
+    fn helmert_requires_earth_fixed() {
+        let state = CartesianState::new(
+            6378.137,
+            0.0,
+            0.0,
+            0.0,
+            7.5,
+            0.0,
+            Epoch::from_jde_tdb(JD_J2000),
+            EARTH_J2000,
+        );
+        assert!(HelmertTransform::ITRF2020_TO_ITRF2014.apply(state).is_err());
+    }
+}
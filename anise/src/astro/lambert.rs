@@ -0,0 +1,267 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::f64::consts::{PI, TAU};
+use core::fmt;
+
+use hifitime::Duration;
+
+use super::{orbit_propagation::stumpff, PhysicsResult};
+use crate::{
+    errors::{MathError, PhysicsError},
+    math::Vector3,
+};
+
+/// Maximum number of bisections of the universal variable.
+const MAX_ITERATIONS: usize = 256;
+
+/// Direction of motion of a Lambert transfer about the Z axis of its frame, which sets whether it is the short way or the long way.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// Counter-clockwise about the Z axis, i.e. the direction of motion of the planets in the ecliptic or equatorial frames
+    #[default]
+    Prograde,
+    /// Clockwise about the Z axis
+    Retrograde,
+}
+
+/// Solution of the single revolution Lambert problem: the conic connecting two positions in a given time of flight.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LambertSolution {
+    /// Velocity at the initial position
+    pub v_init_km_s: Vector3,
+    /// Velocity at the final position
+    pub v_final_km_s: Vector3,
+    /// Angle swept between both positions, in degrees
+    pub transfer_angle_deg: f64,
+}
+
+impl LambertSolution {
+    /// Solves the Lambert problem between the initial and final positions (in the same inertial frame) with the provided time of flight
+    /// and gravitational parameter of the central body, for a transfer of less than one revolution in the provided direction.
+    ///
+    /// # Algorithm
+    /// This uses the universal variable formulation of Bate, Mueller, and White (Vallado, Algorithm 58), where the universal variable is
+    /// bisected: the time of flight increases monotonically with it, so this converges for all elliptical, parabolic, and hyperbolic transfers.
+    ///
+    /// # Errors
+    /// + The time of flight is not positive;
+    /// + Both positions are colinear (transfer angle of zero or 180 degrees), in which case the plane of the transfer is undefined;
+    /// + The solution did not converge.
+    pub fn solve(
+        r_init_km: Vector3,
+        r_final_km: Vector3,
+        tof: Duration,
+        mu_km3_s2: f64,
+        direction: TransferDirection,
+    ) -> PhysicsResult<Self> {
+        let tof_s = tof.to_seconds();
+        if tof_s <= 0.0 {
+            return Err(PhysicsError::AppliedMath {
+                source: MathError::DomainError {
+                    value: tof_s,
+                    msg: "the time of flight of a Lambert transfer must be positive",
+                },
+            });
+        }
+
+        let r_init = r_init_km.norm();
+        let r_final = r_final_km.norm();
+        if r_init <= f64::EPSILON || r_final <= f64::EPSILON {
+            return Err(PhysicsError::RadiusError {
+                action: "cannot solve a Lambert transfer from or to the center of the frame",
+            });
+        }
+
+        let cos_dnu = (r_init_km.dot(&r_final_km) / (r_init * r_final)).clamp(-1.0, 1.0);
+        let mut dnu_rad = cos_dnu.acos();
+        let counter_clockwise = r_init_km.cross(&r_final_km).z >= 0.0;
+        if counter_clockwise != (direction == TransferDirection::Prograde) {
+            dnu_rad = TAU - dnu_rad;
+        }
+
+        // Sign of the transfer, positive for the short way
+        let t_m = if dnu_rad < PI { 1.0 } else { -1.0 };
+        let a = t_m * (r_init * r_final * (1.0 + cos_dnu)).sqrt();
+        let colinear = a.abs() < 1e-6 * (r_init * r_final).sqrt();
+        if colinear || !(1e-12..=TAU - 1e-12).contains(&dnu_rad) {
+            return Err(PhysicsError::RadiusError {
+                action: "Lambert transfers between colinear positions are undefined",
+            });
+        }
+
+        let sqrt_mu = mu_km3_s2.sqrt();
+        // Returns the y auxiliary variable and the time of flight for a universal variable, or None if y is negative.
+        let tof_at = |z: f64| -> Option<(f64, f64)> {
+            let (c, s) = stumpff(z);
+            let y = r_init + r_final + a * (z * s - 1.0) / c.sqrt();
+            if y < 0.0 {
+                return None;
+            }
+            let x = (y / c).sqrt();
+            Some((y, (x.powi(3) * s + a * y.sqrt()) / sqrt_mu))
+        };
+
+        // The time of flight tends to infinity as z tends to (2 pi)^2, and decreases down to zero as z decreases.
+        let mut z_up = TAU * TAU;
+        let mut z_low = -TAU * TAU;
+        let mut extensions = 0;
+        while tof_at(z_low).is_some_and(|(_, t)| t > tof_s) {
+            z_low *= 2.0;
+            extensions += 1;
+            if extensions > 64 {
+                return Err(PhysicsError::AppliedMath {
+                    source: MathError::MaxIterationsReached {
+                        iter: extensions,
+                        action: "bracketing the Lambert universal variable",
+                    },
+                });
+            }
+        }
+
+        let mut solution = None;
+        for _ in 0..MAX_ITERATIONS {
+            let z = 0.5 * (z_low + z_up);
+            match tof_at(z) {
+                Some((y, t)) => {
+                    solution = Some(y);
+                    if (t - tof_s).abs() <= 1e-12 * tof_s {
+                        break;
+                    } else if t < tof_s {
+                        z_low = z;
+                    } else {
+                        z_up = z;
+                    }
+                }
+                None => z_low = z,
+            }
+            if z_up - z_low <= f64::EPSILON * z_up.abs().max(1.0) {
+                break;
+            }
+        }
+
+        let y = solution.ok_or(PhysicsError::AppliedMath {
+            source: MathError::MaxIterationsReached {
+                iter: MAX_ITERATIONS,
+                action: "solving the Lambert problem",
+            },
+        })?;
+
+        // Lagrange coefficients
+        let f = 1.0 - y / r_init;
+        let g = a * (y / mu_km3_s2).sqrt();
+        let g_dot = 1.0 - y / r_final;
+
+        Ok(Self {
+            v_init_km_s: (r_final_km - f * r_init_km) / g,
+            v_final_km_s: (g_dot * r_final_km - r_init_km) / g,
+            transfer_angle_deg: dnu_rad.to_degrees(),
+        })
+    }
+
+    /// Returns whether this transfer sweeps more than 180 degrees.
+    pub fn is_long_way(&self) -> bool {
+        self.transfer_angle_deg > PI.to_degrees()
+    }
+}
+
+impl fmt::Display for LambertSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lambert transfer of {:.3} deg: v_init = {:.6} km/s, v_final = {:.6} km/s",
+            self.transfer_angle_deg,
+            self.v_init_km_s.norm(),
+            self.v_final_km_s.norm()
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_lambert {
+    use super::{LambertSolution, TransferDirection};
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::Vector3;
+    use crate::prelude::Orbit;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn lambert_transfers() {
+        let mu_km3_s2 = 398_600.435_436;
+        let frame = EARTH_J2000.with_mu_km3_s2(mu_km3_s2);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+
+        // Vallado, example 7-5: short way transfer of 76 minutes
+        let sol = LambertSolution::solve(
+            Vector3::new(15_945.34, 0.0, 0.0),
+            Vector3::new(12_214.838_99, 10_249.467_31, 0.0),
+            Unit::Minute * 76,
+            mu_km3_s2,
+            TransferDirection::Prograde,
+        )
+        .unwrap();
+        assert!((sol.v_init_km_s - Vector3::new(2.058_913, 2.915_965, 0.0)).norm() < 1e-5);
+        assert!((sol.v_final_km_s - Vector3::new(-3.451_565, 0.910_315, 0.0)).norm() < 1e-5);
+        assert!(!sol.is_long_way());
+
+        // The solution is the state of the orbit which connects both positions, for all kinds of conics and both directions.
+        for (sma_km, ecc, inc_deg, hours) in [
+            (7000.0, 0.001, 28.5, 0.4),
+            (24_000.0, 0.7, 63.4, 3.0),
+            (24_000.0, 0.7, 63.4, 9.0),
+            (-20_000.0, 1.8, 10.0, 2.0),
+            (-60_000.0, 1.1, 150.0, 30.0),
+        ] {
+            let state =
+                Orbit::try_keplerian(sma_km, ecc, inc_deg, 10.0, 20.0, 30.0, epoch, frame).unwrap();
+            let later = state
+                .propagate_to_epoch(epoch + Unit::Hour * hours)
+                .unwrap();
+            let direction = if inc_deg < 90.0 {
+                TransferDirection::Prograde
+            } else {
+                TransferDirection::Retrograde
+            };
+
+            let sol = LambertSolution::solve(
+                state.radius_km,
+                later.radius_km,
+                Unit::Hour * hours,
+                mu_km3_s2,
+                direction,
+            )
+            .unwrap();
+            assert!(
+                (sol.v_init_km_s - state.velocity_km_s).norm() < 1e-8,
+                "a = {sma_km}, e = {ecc}: {sol}"
+            );
+            assert!((sol.v_final_km_s - later.velocity_km_s).norm() < 1e-8);
+        }
+
+        // Invalid transfers
+        let r = Vector3::new(7000.0, 0.0, 0.0);
+        assert!(LambertSolution::solve(
+            r,
+            -r,
+            Unit::Hour * 1,
+            mu_km3_s2,
+            TransferDirection::Prograde
+        )
+        .is_err());
+        assert!(LambertSolution::solve(
+            r,
+            Vector3::new(0.0, 7000.0, 0.0),
+            Unit::Hour * -1,
+            mu_km3_s2,
+            TransferDirection::Prograde
+        )
+        .is_err());
+    }
+}
@@ -28,14 +28,36 @@ pub(crate) mod aberration;
 pub use aberration::Aberration;
 
 pub(crate) mod occultation;
-pub use occultation::Occultation;
+pub use occultation::{EclipseWindow, Occultation, OccultationKind, OccultationWindow};
+
+pub(crate) mod flyby;
+pub use flyby::Flyby;
+
+pub(crate) mod lambert;
+pub use lambert::{LambertSolution, TransferDirection};
+
+pub(crate) mod canonical;
+pub use canonical::CanonicalUnits;
+
+pub(crate) mod helmert;
+pub use helmert::{HelmertTransform, ItrfRealization};
+
+pub(crate) mod radec;
+pub use radec::RaDec;
+
+pub(crate) mod refraction;
+pub use refraction::{RefractionModel, MIN_REFRACTION_ELEVATION_DEG};
 
 pub mod orbit;
+#[cfg(feature = "analysis")]
+pub mod orbit_bplane;
 pub mod orbit_equinoctial;
+pub use orbit_equinoctial::EquinoctialElements;
 pub mod orbit_geodetic;
 #[cfg(feature = "analysis")]
 pub mod orbit_gradient;
 pub mod orbit_mean_elements;
+pub mod orbit_propagation;
 
 pub use crate::structure::location::{Location, TerrainMask};
 
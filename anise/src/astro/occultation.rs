@@ -13,7 +13,7 @@ use core::fmt;
 
 use crate::{constants::celestial_objects::SUN, frames::Frame};
 
-use hifitime::Epoch;
+use hifitime::{Duration, Epoch};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -157,6 +157,153 @@ impl fmt::Display for Occultation {
     }
 }
 
+/// Classification of an occultation of a back object by a front object as seen from an observer, from the least to the most occulted.
+///
+/// This matches the SPICE `occult` classification, where `Visible` is SPICE's "no occultation".
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, from_py_object, module = "anise.astro")
+)]
+pub enum OccultationKind {
+    /// The back object is fully visible, either because the disks do not overlap or because the front object is behind it
+    Visible,
+    /// The disks of both objects overlap but neither contains the other
+    Partial,
+    /// The disk of the front object is fully within the disk of the back object (e.g. an annular solar eclipse)
+    Annular,
+    /// The back object is fully hidden by the front object (e.g. umbra)
+    Total,
+}
+
+impl fmt::Display for OccultationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Visible => write!(f, "no occultation"),
+            Self::Partial => write!(f, "partial occultation"),
+            Self::Annular => write!(f, "annular occultation"),
+            Self::Total => write!(f, "total occultation"),
+        }
+    }
+}
+
+/// Time interval during which the back object is occulted by the front object, as returned by the occultation search of the Almanac.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(get_all, set_all, from_py_object, module = "anise.astro")
+)]
+pub struct OccultationWindow {
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+    /// Most severe occultation kind sampled during this window
+    pub kind: OccultationKind,
+    pub back_frame: Frame,
+    pub front_frame: Frame,
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl OccultationWindow {
+    /// Returns the duration of this occultation window
+    ///
+    /// :rtype: Duration
+    pub fn duration(&self) -> Duration {
+        self.end_epoch - self.start_epoch
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+#[cfg(feature = "python")]
+impl OccultationWindow {
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self} (@{self:p})")
+    }
+}
+
+impl fmt::Display for OccultationWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {:e} due to {:e} from {} to {} ({})",
+            self.kind,
+            self.back_frame,
+            self.front_frame,
+            self.start_epoch,
+            self.end_epoch,
+            self.duration()
+        )
+    }
+}
+
+/// Eclipse of the Sun by an eclipsing body as seen from an observer, as returned by the eclipse search of the Almanac.
+///
+/// The penumbra bounds the whole eclipse, i.e. when any part of the solar disk is hidden, and the umbra is the part of the
+/// eclipse where the solar disk is fully hidden, if any.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(get_all, set_all, from_py_object, module = "anise.astro")
+)]
+pub struct EclipseWindow {
+    pub penumbra_entry_epoch: Epoch,
+    pub penumbra_exit_epoch: Epoch,
+    /// Entry into the umbra, if the eclipse is total at some point
+    pub umbra_entry_epoch: Option<Epoch>,
+    /// Exit from the umbra, if the eclipse is total at some point
+    pub umbra_exit_epoch: Option<Epoch>,
+    pub eclipsing_frame: Frame,
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl EclipseWindow {
+    /// Returns the duration of this eclipse, from penumbra entry to penumbra exit
+    ///
+    /// :rtype: Duration
+    pub fn duration(&self) -> Duration {
+        self.penumbra_exit_epoch - self.penumbra_entry_epoch
+    }
+
+    /// Returns the duration of the umbra of this eclipse, if any
+    ///
+    /// :rtype: Duration
+    pub fn umbra_duration(&self) -> Option<Duration> {
+        Some(self.umbra_exit_epoch? - self.umbra_entry_epoch?)
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+#[cfg(feature = "python")]
+impl EclipseWindow {
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self} (@{self:p})")
+    }
+}
+
+impl fmt::Display for EclipseWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "eclipse by {:e} from {} to {} ({})",
+            self.eclipsing_frame,
+            self.penumbra_entry_epoch,
+            self.penumbra_exit_epoch,
+            self.duration()
+        )?;
+        if let (Some(entry), Some(exit)) = (self.umbra_entry_epoch, self.umbra_exit_epoch) {
+            write!(f, " with umbra from {entry} to {exit} ({})", exit - entry)?;
+        }
+        Ok(())
+    }
+}
+
 impl PartialOrd for Occultation {
     /// Provides an ordering of the occultation by percentage, if the back and front objects match
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
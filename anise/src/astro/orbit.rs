@@ -8,18 +8,21 @@
  * Documentation: https://nyxspace.com/
  */
 
-use super::utils::mean_anomaly_to_true_anomaly_rad;
 use super::PhysicsResult;
 
 use crate::{
-    astro::utils::true_anomaly_to_mean_anomaly_rad,
     errors::{
-        HyperbolicTrueAnomalySnafu, InfiniteValueSnafu, MathError, ParabolicEccentricitySnafu,
-        ParabolicSemiParamSnafu, PhysicsError, RadiusSnafu, VelocitySnafu,
+        HyperbolicSnafu, HyperbolicTrueAnomalySnafu, InfiniteValueSnafu, MathError,
+        NotHyperbolicSnafu, ParabolicEccentricitySnafu, ParabolicSemiParamSnafu, PhysicsError,
+        RadiusSnafu, VelocitySnafu,
     },
     math::{
         angles::{between_0_360, between_pm_180},
         cartesian::CartesianState,
+        kepler::{
+            eccentric_to_mean_anomaly_rad, eccentric_to_true_anomaly_rad, mean_to_true_anomaly_rad,
+            true_to_eccentric_anomaly_rad, true_to_mean_anomaly_rad,
+        },
         rotation::DCM,
         Matrix3, Vector3, Vector6,
     },
@@ -66,10 +69,6 @@ impl Orbit {
         epoch: Epoch,
         frame: Frame,
     ) -> PhysicsResult<Self> {
-        let mu_km3_s2 = frame.mu_km3_s2()?;
-        if mu_km3_s2.abs() < f64::EPSILON {
-            warn!("GM is near zero ({mu_km3_s2} km^3/s^2): expect rounding errors!",);
-        }
         // Algorithm from GMAT's StateConversionUtil::KeplerianToCartesian
         let ecc = if ecc < 0.0 {
             warn!("eccentricity cannot be negative: sign of eccentricity changed");
@@ -94,10 +93,78 @@ impl Orbit {
             (1.0 - ecc).abs() >= ECC_EPSILON,
             ParabolicEccentricitySnafu { limit: ECC_EPSILON }
         );
-        if ecc > 1.0 {
-            let ta_deg = between_0_360(ta_deg);
+        let p_km = sma * (1.0 - ecc.powi(2));
+        ensure!(p_km.abs() >= f64::EPSILON, ParabolicSemiParamSnafu { p_km });
+
+        Self::try_keplerian_semi_parameter(
+            p_km, ecc, inc_deg, raan_deg, aop_deg, ta_deg, epoch, frame,
+        )
+    }
+
+    /// Attempts to create a new Orbit from the Keplerian orbital elements using the radius of periapsis instead of the semi
+    /// major axis, which also supports parabolic orbits (e = 1) since the radius of periapsis is always finite.
+    ///
+    /// **Units:** km, none, degrees, degrees, degrees, degrees
+    ///
+    /// For parabolic and hyperbolic orbits, the true anomaly is wrapped into [-180, 180] degrees, where negative values are on
+    /// the inbound leg, and must be strictly within the asymptotes (within 180 degrees for parabolic orbits).
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_keplerian_periapsis(
+        periapsis_km: f64,
+        ecc: f64,
+        inc_deg: f64,
+        raan_deg: f64,
+        aop_deg: f64,
+        ta_deg: f64,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Self> {
+        ensure!(
+            periapsis_km > f64::EPSILON,
+            RadiusSnafu {
+                action: "radius of periapsis is negative"
+            }
+        );
+        let ecc = if ecc < 0.0 {
+            warn!("eccentricity cannot be negative: sign of eccentricity changed");
+            -ecc
+        } else {
+            ecc
+        };
+
+        Self::try_keplerian_semi_parameter(
+            periapsis_km * (1.0 + ecc),
+            ecc,
+            inc_deg,
+            raan_deg,
+            aop_deg,
+            ta_deg,
+            epoch,
+            frame,
+        )
+    }
+
+    /// Creates a new Orbit from the semi parameter and the other Keplerian orbital elements, for any kind of conic.
+    #[allow(clippy::too_many_arguments)]
+    fn try_keplerian_semi_parameter(
+        p_km: f64,
+        ecc: f64,
+        inc_deg: f64,
+        raan_deg: f64,
+        aop_deg: f64,
+        ta_deg: f64,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Self> {
+        let mu_km3_s2 = frame.mu_km3_s2()?;
+        if mu_km3_s2.abs() < f64::EPSILON {
+            warn!("GM is near zero ({mu_km3_s2} km^3/s^2): expect rounding errors!",);
+        }
+        if ecc >= 1.0 {
+            // The radius is infinite on the asymptotes, i.e. where 1 + e cos(ta) = 0.
+            let ta_deg = between_pm_180(ta_deg);
             ensure!(
-                ta_deg <= (PI - (1.0 / ecc).acos()).to_degrees(),
+                ta_deg.abs() < (-1.0 / ecc).acos().to_degrees(),
                 HyperbolicTrueAnomalySnafu { ta_deg }
             );
         }
@@ -116,9 +183,6 @@ impl Orbit {
         let raan_rad = raan_deg.to_radians();
         let aop_rad = aop_deg.to_radians();
         let ta_rad = ta_deg.to_radians();
-        let p_km = sma * (1.0 - ecc.powi(2));
-
-        ensure!(p_km.abs() >= f64::EPSILON, ParabolicSemiParamSnafu { p_km });
 
         // NOTE: At this point GMAT computes 1+ecc**2 and checks whether it's very small.
         // It then reports that the radius may be too large. We've effectively already done
@@ -237,7 +301,7 @@ impl Orbit {
     /// # Implementation notes
     /// This function starts by converting the mean anomaly to true anomaly, and then it initializes the orbit
     /// using the keplerian(..) method.
-    /// The conversion is from GMAT's MeanToTrueAnomaly function, transliterated originally by Claude and GPT4 with human adjustments.
+    /// The conversion solves Kepler's equation for the eccentric anomaly, which is then converted to the true anomaly.
     #[allow(clippy::too_many_arguments)]
     pub fn try_keplerian_mean_anomaly(
         sma_km: f64,
@@ -250,7 +314,8 @@ impl Orbit {
         frame: Frame,
     ) -> PhysicsResult<Self> {
         // Start by computing the true anomaly
-        let ta_rad = mean_anomaly_to_true_anomaly_rad(ma_deg.to_radians(), ecc)?;
+        let ta_rad = mean_to_true_anomaly_rad(ma_deg.to_radians(), ecc)
+            .map_err(|source| PhysicsError::AppliedMath { source })?;
 
         Self::try_keplerian(
             sma_km,
@@ -324,6 +389,45 @@ impl Orbit {
                 / self.frame.mu_km3_s2()?,
         )
     }
+
+    /// Returns the unit vector of the outgoing asymptote of this hyperbolic orbit, i.e. the direction of the velocity when
+    /// leaving the sphere of influence, or an error if the orbit is not hyperbolic.
+    pub fn asymptote_out_unit_vector(&self) -> PhysicsResult<Vector3> {
+        let (e_hat, q_hat, ecc) = self.asymptote_basis()?;
+        Ok(-e_hat / ecc + (1.0 - ecc.powi(-2)).sqrt() * q_hat)
+    }
+
+    /// Returns the unit vector of the incoming asymptote of this hyperbolic orbit, i.e. the direction of the velocity when
+    /// entering the sphere of influence, or an error if the orbit is not hyperbolic.
+    pub fn asymptote_in_unit_vector(&self) -> PhysicsResult<Vector3> {
+        let (e_hat, q_hat, ecc) = self.asymptote_basis()?;
+        Ok(e_hat / ecc + (1.0 - ecc.powi(-2)).sqrt() * q_hat)
+    }
+
+    /// Returns the time derivative of the RIC to inertial rotation matrix assuming two body dynamics, where the orbital momentum is
+    /// constant and the radial unit vector rotates with the transverse velocity.
+    fn ric_rot_mat_dt(&self) -> PhysicsResult<Matrix3> {
+        let r_hat = self.r_hat();
+        let c_hat = self.hvec()? / self.hmag()?;
+        let r_hat_dt =
+            (self.velocity_km_s - r_hat.dot(&self.velocity_km_s) * r_hat) / self.rmag_km();
+        let i_hat_dt = c_hat.cross(&r_hat_dt);
+
+        Ok(Matrix3::from_columns(&[
+            r_hat_dt,
+            i_hat_dt,
+            Vector3::zeros(),
+        ]))
+    }
+
+    /// Returns the unit vectors towards the periapsis and 90 degrees ahead of it in the orbital plane, and the eccentricity.
+    fn asymptote_basis(&self) -> PhysicsResult<(Vector3, Vector3, f64)> {
+        let evec = self.evec()?;
+        let ecc = evec.norm();
+        ensure!(ecc > 1.0, NotHyperbolicSnafu { ecc });
+        let e_hat = evec / ecc;
+        Ok((e_hat, self.h_hat()?.cross(&e_hat), ecc))
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -422,7 +526,8 @@ impl Orbit {
     /// 7. Return the DCM structure with a 6x6 state DCM.
     ///
     /// # Note on the time derivative
-    /// If the pre or post states cannot be computed, then the time derivative of the DCM will _not_ be set.
+    /// If the pre or post states cannot be computed (e.g. for circular orbits, or if the frame has no gravitational parameter), then the
+    /// time derivative of the DCM is computed analytically from this state, such that it is always set.
     /// Further note that most astrodynamics tools do *not* account for the time derivative in the RIC frame.
     ///
     /// :rtype: DCM
@@ -433,10 +538,10 @@ impl Orbit {
                 let dcm_post = post.dcm3x3_from_ric_to_inertial()?;
                 Some(500.0 * (dcm_post.rot_mat - dcm_pre.rot_mat))
             } else {
-                None
+                Some(self.ric_rot_mat_dt()?)
             }
         } else {
-            None
+            Some(self.ric_rot_mat_dt()?)
         };
 
         Ok(DCM {
@@ -603,6 +708,68 @@ impl Orbit {
         })
     }
 
+    /// Builds the rotation matrix that rotates from this state's inertial frame to this state's LVLH frame (local vertical, local horizontal),
+    /// the reference of the common nadir pointing and yaw steering attitude modes.
+    ///
+    /// # Frame warning
+    /// If the state is NOT in an inertial frame, then this computation is INVALID.
+    ///
+    /// # Algorithm
+    /// 1. Build the Z vector as the nadir direction, i.e. -\hat{r}
+    /// 2. Build the Y vector as the negative orbit normal, i.e. the opposite of the orbital momentum unit vector -\hat{h}
+    /// 3. Build the X vector as the cross product of Y and Z, which is along the velocity for circular orbits
+    /// 4. Return the DCM structure **without** accounting for the transport theorem.
+    ///
+    /// :rtype: DCM
+    pub fn dcm3x3_from_lvlh_to_inertial(&self) -> PhysicsResult<DCM> {
+        let z = -self.r_hat();
+        let y = -self.h_hat()?;
+        let x = y.cross(&z);
+
+        Ok(DCM {
+            rot_mat: Matrix3::from_columns(&[x, y, z]),
+            rot_mat_dt: None,
+            from: uuid_from_epoch(self.frame.orientation_id, self.epoch),
+            to: self.frame.orientation_id,
+        })
+    }
+
+    /// Builds the rotation matrix that rotates from this state's inertial frame to this state's LVLH frame (local vertical, local horizontal),
+    /// the reference of the common nadir pointing and yaw steering attitude modes.
+    ///
+    /// # Frame warning
+    /// If the state is NOT in an inertial frame, then this computation is INVALID.
+    ///
+    /// # Algorithm
+    /// 1. Build the LVLH DCM of this state, cf. `dcm3x3_from_lvlh_to_inertial`
+    /// 2. Compute the difference between the DCMs of the pre and post states (+/- 1 ms), to build the DCM time derivative
+    /// 3. Return the DCM structure with a 6x6 DCM with the time derivative of the LVLH frame set.
+    ///
+    /// # Note on the time derivative
+    /// If the pre or post states cannot be computed, then the time derivative of the DCM will _not_ be set.
+    ///
+    /// :rtype: DCM
+    pub fn dcm_from_lvlh_to_inertial(&self) -> PhysicsResult<DCM> {
+        let rot_mat_dt = if let Ok(pre) = self.at_epoch(self.epoch - Unit::Millisecond * 1) {
+            if let Ok(post) = self.at_epoch(self.epoch + Unit::Millisecond * 1) {
+                let dcm_pre = pre.dcm3x3_from_lvlh_to_inertial()?;
+                let dcm_post = post.dcm3x3_from_lvlh_to_inertial()?;
+                Some(500.0 * (dcm_post.rot_mat - dcm_pre.rot_mat))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        Ok(DCM {
+            rot_mat: self.dcm3x3_from_lvlh_to_inertial()?.rot_mat,
+            rot_mat_dt,
+            from: uuid_from_epoch(self.frame.orientation_id, self.epoch),
+            to: self.frame.orientation_id,
+        })
+    }
+
     /// Returns the DCM to rotate this orbit from the provided local frame to the inertial frame.
     ///
     /// :type local_frame: LocalFrame
@@ -617,6 +784,7 @@ impl Orbit {
             LocalFrame::RIC => self.dcm_from_ric_to_inertial(),
             LocalFrame::RCN => self.dcm_from_rcn_to_inertial(),
             LocalFrame::VNC => self.dcm_from_vnc_to_inertial(),
+            LocalFrame::LVLH => self.dcm_from_lvlh_to_inertial(),
         }
     }
 
@@ -655,6 +823,45 @@ impl Orbit {
         )
     }
 
+    /// Creates a new Orbit from the Keplerian orbital elements using the radius of periapsis instead of the semi major axis,
+    /// which also supports parabolic orbits.
+    ///
+    /// **Units:** km, none, degrees, degrees, degrees, degrees
+    ///
+    /// :type periapsis_km: float
+    /// :type ecc: float
+    /// :type inc_deg: float
+    /// :type raan_deg: float
+    /// :type aop_deg: float
+    /// :type ta_deg: float
+    /// :type epoch: Epoch
+    /// :type frame: Frame
+    /// :rtype: Orbit
+    #[cfg(feature = "python")]
+    #[classmethod]
+    pub fn from_keplerian_periapsis(
+        _cls: &Bound<'_, PyType>,
+        periapsis_km: f64,
+        ecc: f64,
+        inc_deg: f64,
+        raan_deg: f64,
+        aop_deg: f64,
+        ta_deg: f64,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Self> {
+        Self::try_keplerian_periapsis(
+            periapsis_km,
+            ecc,
+            inc_deg,
+            raan_deg,
+            aop_deg,
+            ta_deg,
+            epoch,
+            frame,
+        )
+    }
+
     /// Attempts to create a new Orbit from the provided radii of apoapsis and periapsis, in kilometers
     ///
     /// :type r_a_km: float
@@ -689,7 +896,7 @@ impl Orbit {
     /// # Implementation notes
     /// This function starts by converting the mean anomaly to true anomaly, and then it initializes the orbit
     /// using the keplerian(..) method.
-    /// The conversion is from GMAT's MeanToTrueAnomaly function, transliterated originally by Claude and GPT4 with human adjustments.
+    /// The conversion solves Kepler's equation for the eccentric anomaly, which is then converted to the true anomaly.
     ///
     /// :type sma_km: float
     /// :type ecc: float
@@ -808,10 +1015,16 @@ impl Orbit {
         Ok(me)
     }
 
-    /// Returns the period
+    /// Returns the period, or an error if the orbit is parabolic or hyperbolic
     ///
     /// :rtype: Duration
     pub fn period(&self) -> PhysicsResult<Duration> {
+        let ecc = self.ecc()?;
+        ensure!(
+            (1.0 - ecc).abs() >= ECC_EPSILON,
+            ParabolicEccentricitySnafu { limit: ECC_EPSILON }
+        );
+        ensure!(ecc < 1.0, HyperbolicSnafu { ecc });
         Ok(TAU
             * (self.sma_km()?.powi(3) / self.frame.mu_km3_s2()?)
                 .sqrt()
@@ -1206,20 +1419,49 @@ impl Orbit {
         Ok(self.sma_km()? * (1.0 + self.ecc()?))
     }
 
-    /// Returns the eccentric anomaly in degrees
+    /// Returns the eccentric anomaly in degrees, or the hyperbolic anomaly in degrees for hyperbolic orbits
     ///
-    /// This is a conversion from GMAT's StateConversionUtil::TrueToEccentricAnomaly
+    /// This is a conversion from GMAT's StateConversionUtil::TrueToEccentricAnomaly. The hyperbolic anomaly is negative on the
+    /// inbound leg of the hyperbola.
     ///
     /// :rtype: float
     pub fn ea_deg(&self) -> PhysicsResult<f64> {
+        let ecc = self.ecc()?;
+        if ecc > 1.0 {
+            return Ok(
+                true_to_eccentric_anomaly_rad(self.ta_deg()?.to_radians(), ecc)
+                    .map_err(|source| PhysicsError::AppliedMath { source })?
+                    .to_degrees(),
+            );
+        }
         let (sin_ta, cos_ta) = self.ta_deg()?.to_radians().sin_cos();
-        let ecc_cos_ta = self.ecc()? * cos_ta;
-        let sin_ea = ((1.0 - self.ecc()?.powi(2)).sqrt() * sin_ta) / (1.0 + ecc_cos_ta);
-        let cos_ea = (self.ecc()? + cos_ta) / (1.0 + ecc_cos_ta);
+        let ecc_cos_ta = ecc * cos_ta;
+        let sin_ea = ((1.0 - ecc.powi(2)).sqrt() * sin_ta) / (1.0 + ecc_cos_ta);
+        let cos_ea = (ecc + cos_ta) / (1.0 + ecc_cos_ta);
         // The atan2 function is a bit confusing: https://doc.rust-lang.org/std/primitive.f64.html#method.atan2 .
         Ok(sin_ea.atan2(cos_ea).to_degrees())
     }
 
+    /// Mutates this orbit to change the eccentric anomaly, or the hyperbolic anomaly for hyperbolic orbits
+    ///
+    /// :type new_ea_deg: float
+    /// :rtype: None
+    pub fn set_ea_deg(&mut self, new_ea_deg: f64) -> PhysicsResult<()> {
+        let ta_rad = eccentric_to_true_anomaly_rad(new_ea_deg.to_radians(), self.ecc()?)
+            .map_err(|source| PhysicsError::AppliedMath { source })?;
+        self.set_ta_deg(ta_rad.to_degrees())
+    }
+
+    /// Returns a copy of the state with a new eccentric anomaly, or a new hyperbolic anomaly for hyperbolic orbits
+    ///
+    /// :type new_ea_deg: float
+    /// :rtype: Orbit
+    pub fn with_ea_deg(&self, new_ea_deg: f64) -> PhysicsResult<Self> {
+        let mut me = *self;
+        me.set_ea_deg(new_ea_deg)?;
+        Ok(me)
+    }
+
     /// Returns the flight path angle in degrees
     ///
     /// :rtype: float
@@ -1234,28 +1476,48 @@ impl Orbit {
 
     /// Returns the mean anomaly in degrees
     ///
-    /// This is a conversion from GMAT's StateConversionUtil::TrueToMeanAnomaly
+    /// This is a conversion from GMAT's StateConversionUtil::TrueToMeanAnomaly. For hyperbolic orbits, this is `e sinh(H) - H`
+    /// where H is the hyperbolic anomaly, which is negative on the inbound leg of the hyperbola.
     ///
     /// :rtype: float
     pub fn ma_deg(&self) -> PhysicsResult<f64> {
-        if self.ecc()?.abs() < ECC_EPSILON {
+        let ecc = self.ecc()?;
+        if ecc.abs() < ECC_EPSILON {
             Err(PhysicsError::ParabolicEccentricity { limit: ECC_EPSILON })
-        } else if self.ecc()? < 1.0 {
+        } else if ecc < 1.0 {
             Ok(between_0_360(
-                (self.ea_deg()?.to_radians() - self.ecc()? * self.ea_deg()?.to_radians().sin())
+                (self.ea_deg()?.to_radians() - ecc * self.ea_deg()?.to_radians().sin())
                     .to_degrees(),
             ))
         } else {
-            // From GMAT's TrueToHyperbolicAnomaly
             Ok(
-                ((self.ta_deg()?.to_radians().sin() * (self.ecc()?.powi(2) - 1.0)).sqrt()
-                    / (1.0 + self.ecc()? * self.ta_deg()?.to_radians().cos()))
-                .asinh()
-                .to_degrees(),
+                eccentric_to_mean_anomaly_rad(self.ea_deg()?.to_radians(), ecc)
+                    .map_err(|source| PhysicsError::AppliedMath { source })?
+                    .to_degrees(),
             )
         }
     }
 
+    /// Mutates this orbit to change the mean anomaly, solving Kepler's equation for the new eccentric (or hyperbolic) anomaly
+    ///
+    /// :type new_ma_deg: float
+    /// :rtype: None
+    pub fn set_ma_deg(&mut self, new_ma_deg: f64) -> PhysicsResult<()> {
+        let ta_rad = mean_to_true_anomaly_rad(new_ma_deg.to_radians(), self.ecc()?)
+            .map_err(|source| PhysicsError::AppliedMath { source })?;
+        self.set_ta_deg(ta_rad.to_degrees())
+    }
+
+    /// Returns a copy of the state with a new mean anomaly
+    ///
+    /// :type new_ma_deg: float
+    /// :rtype: Orbit
+    pub fn with_ma_deg(&self, new_ma_deg: f64) -> PhysicsResult<Self> {
+        let mut me = *self;
+        me.set_ma_deg(new_ma_deg)?;
+        Ok(me)
+    }
+
     /// Returns the semi parameter (or semilatus rectum)
     ///
     /// :rtype: float
@@ -1332,6 +1594,27 @@ impl Orbit {
         Ok(-self.frame.mu_km3_s2()? / self.sma_km()?)
     }
 
+    /// Returns the hyperbolic excess velocity in km/s, i.e. the square root of the $C_3$, or an error if the orbit is not hyperbolic
+    ///
+    /// :rtype: float
+    pub fn vinf_km_s(&self) -> PhysicsResult<f64> {
+        let ecc = self.ecc()?;
+        ensure!(ecc > 1.0, NotHyperbolicSnafu { ecc });
+        Ok(self.c3_km2_s2()?.sqrt())
+    }
+
+    /// Returns the right ascension and declination of the outgoing asymptote in degrees, in the frame of this orbit, e.g. the
+    /// RLA and DLA of a launch or departure hyperbola, or an error if the orbit is not hyperbolic
+    ///
+    /// :rtype: typing.Tuple
+    pub fn asymptote_out_ra_dec_deg(&self) -> PhysicsResult<(f64, f64)> {
+        let s_hat = self.asymptote_out_unit_vector()?;
+        Ok((
+            between_0_360(s_hat.y.atan2(s_hat.x).to_degrees()),
+            s_hat.z.clamp(-1.0, 1.0).asin().to_degrees(),
+        ))
+    }
+
     /// Returns the Longitude of the Ascending Node (LTAN), or an error of equatorial orbits
     ///
     /// :rtype: float
@@ -1512,7 +1795,7 @@ impl Orbit {
         let nu_rad_at_radius = cos_nu_rad_at_radius.acos();
 
         // Calculate mean anomaly at target radius
-        let m_rad_at_radius = true_anomaly_to_mean_anomaly_rad(nu_rad_at_radius, ecc)
+        let m_rad_at_radius = true_to_mean_anomaly_rad(nu_rad_at_radius, ecc)
             .map_err(|e| PhysicsError::AppliedMath { source: e })?;
 
         // Get current mean anomaly
@@ -1562,6 +1845,10 @@ impl Orbit {
     /// Returns a Cartesian state representing the RIC difference between self and other, in position and velocity (with transport theorem).
     /// Refer to dcm_from_ric_to_inertial for details on the RIC frame.
     ///
+    /// The velocity is the rate of change of the position difference as seen in the rotating RIC frame of `self`, i.e. the inertial
+    /// velocity difference minus the rotation of that frame, which is the convention of orbit determination residuals and of relative
+    /// motion models like the Clohessy-Wiltshire equations: two objects on the same circular orbit have a zero RIC velocity difference.
+    ///
     /// # Algorithm
     /// 1. Compute the difference between `other` and `self`
     /// 2. Compute the RIC DCM of `self`
@@ -1592,6 +1879,26 @@ impl Orbit {
         rslt.frame.strip();
         Ok(rslt)
     }
+
+    /// Returns this state relative to the other state, expressed in the provided local frame of the other state, in position and velocity (with transport theorem).
+    /// For example, `chaser.in_frame_of(&target, LocalFrame::RIC)` is the relative motion of the chaser in the RIC frame of the target, and
+    /// is the same as `target.ric_difference(&chaser)`.
+    ///
+    /// # Algorithm
+    /// 1. Compute the difference between `self` and `other`
+    /// 2. Compute the DCM from the local frame of `other` to the inertial frame
+    /// 3. Rotate the difference into the local frame of `other`
+    /// 4. Strip the astrodynamical information from the frame, enabling only computations from `CartesianState`
+    ///
+    /// :type other: Orbit
+    /// :type local_frame: LocalFrame
+    /// :rtype: Orbit
+    #[cfg(feature = "analysis")]
+    pub fn in_frame_of(&self, other: &Self, local_frame: LocalFrame) -> PhysicsResult<Self> {
+        let mut rslt = (other.dcm_to_inertial(local_frame)?.transpose() * (*self - *other)?)?;
+        rslt.frame.strip();
+        Ok(rslt)
+    }
 }
 
 #[allow(clippy::format_in_format_args)]
@@ -0,0 +1,300 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+use hifitime::{Duration, Epoch, Unit};
+use hyperdual::linalg::norm;
+use hyperdual::{Float, OHyperdual};
+use nalgebra::{Matrix2x3, Matrix3x6, U7};
+
+use super::orbit::Orbit;
+use super::orbit_gradient::OrbitGrad;
+use super::PhysicsResult;
+use crate::errors::PhysicsError;
+use crate::math::{Matrix3, Vector3};
+use crate::prelude::Frame;
+
+type Dual = OHyperdual<f64, U7>;
+
+/// B-plane parameters of a hyperbolic orbit, with their partial derivatives with respect to the Cartesian state of the orbit.
+///
+/// # B-plane frame
+/// + `S` is the unit vector along the incoming asymptote, i.e. the direction of the incoming v-infinity;
+/// + `T` is the unit vector of `S` crossed with the Z axis of the frame of the orbit;
+/// + `R` completes the right handed frame, i.e. `R = S x T`.
+///
+/// This is the same convention as [crate::astro::Flyby], so the B·T and B·R of a flyby are recovered from the state of its hyperbola.
+///
+/// The B vector goes from the center of the frame to the intersection of the incoming asymptote with the B-plane, which is the
+/// plane perpendicular to `S` containing the center of the frame.
+#[derive(Copy, Clone, Debug)]
+pub struct BPlane {
+    /// B vector component along R, in km, with its partials
+    pub b_dot_r: Dual,
+    /// B vector component along T, in km, with its partials
+    pub b_dot_t: Dual,
+    /// Time of flight from the state to periapsis, in seconds, with its partials: negative after periapsis
+    pub ltof: Dual,
+    /// Unit vector S, expressed in the frame of the orbit
+    pub s_hat: Vector3,
+    /// Unit vector T, expressed in the frame of the orbit
+    pub t_hat: Vector3,
+    /// Unit vector R, expressed in the frame of the orbit
+    pub r_hat: Vector3,
+    /// Epoch of the orbit
+    pub epoch: Epoch,
+    /// Frame of the orbit
+    pub frame: Frame,
+}
+
+impl BPlane {
+    /// Returns the B vector component along R, in km.
+    pub fn b_dot_r_km(&self) -> f64 {
+        self.b_dot_r[0]
+    }
+
+    /// Returns the B vector component along T, in km.
+    pub fn b_dot_t_km(&self) -> f64 {
+        self.b_dot_t[0]
+    }
+
+    /// Returns the magnitude of the B vector in km, i.e. the impact parameter.
+    pub fn b_mag_km(&self) -> f64 {
+        self.b_dot_t_km().hypot(self.b_dot_r_km())
+    }
+
+    /// Returns the angle of the B vector in the B-plane, measured from T towards R, in degrees.
+    pub fn b_plane_angle_deg(&self) -> f64 {
+        self.b_dot_r_km().atan2(self.b_dot_t_km()).to_degrees()
+    }
+
+    /// Returns the time of flight from the state to periapsis along the hyperbola, which is negative after periapsis.
+    pub fn ltof(&self) -> Duration {
+        Unit::Second * self.ltof[0]
+    }
+
+    /// Returns the B vector, expressed in the frame of the orbit.
+    pub fn b_vector_km(&self) -> Vector3 {
+        self.b_dot_t_km() * self.t_hat + self.b_dot_r_km() * self.r_hat
+    }
+
+    /// Returns the rotation matrix from the frame of the orbit to the B-plane frame, whose rows are S, T, and R.
+    pub fn str_dcm(&self) -> Matrix3 {
+        Matrix3::from_rows(&[
+            self.s_hat.transpose(),
+            self.t_hat.transpose(),
+            self.r_hat.transpose(),
+        ])
+    }
+
+    /// Returns the Jacobian of B·R, B·T, and the time of flight to periapsis with respect to the Cartesian state of the orbit,
+    /// i.e. its position in km and velocity in km/s.
+    pub fn jacobian(&self) -> Matrix3x6<f64> {
+        Matrix3x6::from_fn(|i, j| [self.b_dot_r, self.b_dot_t, self.ltof][i][j + 1])
+    }
+
+    /// Returns the Jacobian of B·R and B·T with respect to the velocity of the orbit, as used to target a B-plane with a maneuver.
+    pub fn jacobian_velocity(&self) -> Matrix2x3<f64> {
+        Matrix2x3::from_fn(|i, j| [self.b_dot_r, self.b_dot_t][i][j + 4])
+    }
+}
+
+impl fmt::Display for BPlane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "B-plane of {:e} @ {}: B·R = {:.3} km    B·T = {:.3} km    LTOF = {}",
+            self.frame,
+            self.epoch,
+            self.b_dot_r_km(),
+            self.b_dot_t_km(),
+            self.ltof()
+        )
+    }
+}
+
+impl Orbit {
+    /// Computes the B-plane parameters of this hyperbolic orbit, refer to [BPlane] for the conventions.
+    ///
+    /// # Errors
+    /// + The orbit is not hyperbolic, or its frame does not define its gravitational parameter;
+    /// + The incoming asymptote is parallel to the Z axis of the frame of the orbit (B-plane undefined).
+    pub fn b_plane(&self) -> PhysicsResult<BPlane> {
+        let grad = OrbitGrad::from(*self);
+        let ecc = grad.ecc()?.dual;
+        if ecc[0] <= 1.0 {
+            return Err(PhysicsError::NotHyperbolic { ecc: ecc[0] });
+        }
+
+        let one = Dual::from(1.0);
+        let scale = |v: nalgebra::Vector3<Dual>, s: Dual| v.map(|c| c * s);
+        let unit = |v: nalgebra::Vector3<Dual>| scale(v, one / norm(&v));
+
+        let e_hat = unit(grad.evec()?);
+        let h_hat = unit(grad.hvec());
+        let n_hat = h_hat.cross(&e_hat);
+
+        // Cosine and sine of the angle between the eccentricity vector and the incoming asymptote.
+        let cos_s = one / ecc;
+        let sin_s = (one - cos_s.powi(2)).sqrt();
+
+        let s_hat = scale(e_hat, cos_s) + scale(n_hat, sin_s);
+        let b_vec = scale(
+            scale(e_hat, sin_s) - scale(n_hat, cos_s),
+            grad.semi_minor_axis_km()?.dual,
+        );
+
+        let t_vec = s_hat.cross(&nalgebra::Vector3::new(
+            Dual::from(0.0),
+            Dual::from(0.0),
+            one,
+        ));
+        if norm(&t_vec)[0] < 1e-12 {
+            return Err(PhysicsError::VelocityError {
+                action:
+                    "incoming asymptote is parallel to the Z axis of the frame, B-plane undefined",
+            });
+        }
+        let t_hat = unit(t_vec);
+        let r_hat = s_hat.cross(&t_hat);
+
+        // Time of flight to periapsis from the hyperbolic Kepler equation.
+        let hyp_anomaly = grad.hyperbolic_anomaly_deg()?.dual.to_radians();
+        let mean_motion =
+            (Dual::from(self.frame.mu_km3_s2()?) / (-grad.sma_km()?.dual).powi(3)).sqrt();
+        let ltof = -(ecc * hyp_anomaly.sinh() - hyp_anomaly) / mean_motion;
+
+        let real = |v: nalgebra::Vector3<Dual>| Vector3::new(v[0][0], v[1][0], v[2][0]);
+
+        Ok(BPlane {
+            b_dot_r: b_vec.dot(&r_hat),
+            b_dot_t: b_vec.dot(&t_hat),
+            ltof,
+            s_hat: real(s_hat),
+            t_hat: real(t_hat),
+            r_hat: real(r_hat),
+            epoch: self.epoch,
+            frame: self.frame,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_b_plane {
+    use crate::astro::Flyby;
+    use crate::constants::frames::MARS_BARYCENTER_J2000;
+    use crate::errors::PhysicsError;
+    use crate::math::Vector3;
+    use crate::prelude::{Epoch, Orbit};
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+
+    #[test]
+    fn flyby_b_plane() {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let mu_km3_s2 = 42_828.37;
+        let mars = MARS_BARYCENTER_J2000
+            .with_mu_km3_s2(mu_km3_s2)
+            .with_ellipsoid(Ellipsoid::from_spheroid(3396.19, 3376.2));
+
+        let vinf_in = Vector3::new(3.0, 4.0, 0.5);
+        let flyby = Flyby::from_periapsis(vinf_in, 3396.19 + 500.0, 30.0, epoch, mars).unwrap();
+
+        // Build the periapsis state of the flyby hyperbola from its B-plane geometry.
+        let (s_hat, _, _) = flyby.b_plane_basis().unwrap();
+        let b_hat = flyby.b_vector_km().unwrap() / flyby.b_mag_km();
+        let ecc = flyby.ecc().unwrap();
+        let (cos_s, sin_s) = (1.0 / ecc, (1.0 - 1.0 / ecc.powi(2)).sqrt());
+        let e_hat = cos_s * s_hat + sin_s * b_hat;
+        let n_hat = sin_s * s_hat - cos_s * b_hat;
+        let rp_km = flyby.periapsis_km().unwrap();
+        let vp_km_s = (vinf_in.norm_squared() + 2.0 * mu_km3_s2 / rp_km).sqrt();
+        let mut periapsis = Orbit::zero_at_epoch(epoch, mars);
+        periapsis.radius_km = rp_km * e_hat;
+        periapsis.velocity_km_s = vp_km_s * n_hat;
+
+        let b_plane = periapsis.b_plane().unwrap();
+        assert!(
+            (b_plane.b_dot_t_km() - flyby.b_dot_t_km).abs() < 1e-6,
+            "{b_plane}"
+        );
+        assert!(
+            (b_plane.b_dot_r_km() - flyby.b_dot_r_km).abs() < 1e-6,
+            "{b_plane}"
+        );
+        assert!((b_plane.b_plane_angle_deg() - 30.0).abs() < 1e-9);
+        assert!((b_plane.s_hat - vinf_in.normalize()).norm() < 1e-12);
+        assert!(b_plane.ltof().to_seconds().abs() < 1e-6);
+        assert!(
+            (b_plane.str_dcm() * b_plane.b_vector_km()
+                - Vector3::new(0.0, flyby.b_dot_t_km, flyby.b_dot_r_km))
+            .norm()
+                < 1e-6
+        );
+
+        // Before periapsis, the time of flight matches the hyperbolic Kepler equation in its tangent half angle form.
+        let p_km = rp_km * (1.0 + ecc);
+        let (sin_ta, cos_ta) = (-60.0_f64).to_radians().sin_cos();
+        let mut inbound = periapsis;
+        inbound.radius_km = p_km / (1.0 + ecc * cos_ta) * (cos_ta * e_hat + sin_ta * n_hat);
+        inbound.velocity_km_s =
+            (mu_km3_s2 / p_km).sqrt() * (-sin_ta * e_hat + (ecc + cos_ta) * n_hat);
+        let inbound_b_plane = inbound.b_plane().unwrap();
+        assert!((inbound_b_plane.b_dot_t_km() - flyby.b_dot_t_km).abs() < 1e-6);
+        assert!((inbound_b_plane.b_dot_r_km() - flyby.b_dot_r_km).abs() < 1e-6);
+
+        let hyp_anomaly =
+            2.0 * (((ecc - 1.0) / (ecc + 1.0)).sqrt() * (-30.0_f64).to_radians().tan()).atanh();
+        let mean_motion = (mu_km3_s2 / (rp_km / (ecc - 1.0)).powi(3)).sqrt();
+        let expected_ltof_s = -(ecc * hyp_anomaly.sinh() - hyp_anomaly) / mean_motion;
+        assert!(expected_ltof_s > 0.0);
+        assert!((inbound_b_plane.ltof().to_seconds() - expected_ltof_s).abs() < 1e-6);
+
+        // The partials match central finite differences
+        let jac = inbound_b_plane.jacobian();
+        let jac_v = inbound_b_plane.jacobian_velocity();
+        for j in 0..6 {
+            let mut delta = [0.0; 6];
+            delta[j] = if j < 3 { 1e-3 } else { 1e-6 };
+            let perturbed = |sign: f64| {
+                let mut orbit = inbound;
+                orbit.radius_km += sign * Vector3::new(delta[0], delta[1], delta[2]);
+                orbit.velocity_km_s += sign * Vector3::new(delta[3], delta[4], delta[5]);
+                let b_plane = orbit.b_plane().unwrap();
+                [
+                    b_plane.b_dot_r_km(),
+                    b_plane.b_dot_t_km(),
+                    b_plane.ltof().to_seconds(),
+                ]
+            };
+            let (plus, minus) = (perturbed(1.0), perturbed(-1.0));
+            for i in 0..3 {
+                let finite_diff = (plus[i] - minus[i]) / (2.0 * delta[j]);
+                assert!(
+                    (jac[(i, j)] - finite_diff).abs() < 1e-4 * finite_diff.abs().max(1.0),
+                    "({i}, {j}): {} != {finite_diff}",
+                    jac[(i, j)]
+                );
+            }
+            if j >= 3 {
+                assert_eq!(jac_v[(0, j - 3)], jac[(0, j)]);
+                assert_eq!(jac_v[(1, j - 3)], jac[(1, j)]);
+            }
+        }
+
+        // Elliptical orbits have no B-plane
+        let elliptical =
+            Orbit::try_keplerian(8000.0, 0.1, 30.0, 0.0, 0.0, 0.0, epoch, mars).unwrap();
+        assert!(matches!(
+            elliptical.b_plane(),
+            Err(PhysicsError::NotHyperbolic { .. })
+        ));
+    }
+}
@@ -8,8 +8,13 @@
  * Documentation: https://nyxspace.com/
  */
 
+use core::f64::consts::TAU;
+use core::fmt;
+
 use super::{orbit::Orbit, PhysicsResult};
 
+use crate::errors::{MathError, PhysicsError};
+use crate::math::Vector3;
 use crate::prelude::Frame;
 
 use hifitime::Epoch;
@@ -46,7 +51,199 @@ pub(crate) fn equinoctial_to_keplerian(
     (sma_km, ecc, inc_deg, raan_deg, aop_deg, ma_deg)
 }
 
+/// Equinoctial elements of an elliptical orbit, which are defined for circular and equatorial orbits, unlike the Keplerian elements.
+///
+/// The elements use the same conventions as [Orbit::try_equinoctial] and the `equinoctial_*` getters of an Orbit, extended with the
+/// retrograde factor `I`, which is +1 for direct orbits and -1 for retrograde orbits:
+/// + h = ecc sin(aop + I raan) and k = ecc cos(aop + I raan);
+/// + p = sin(inc / 2) sin(raan) and q = sin(inc / 2) cos(raan) for direct orbits, where sin(inc / 2) is replaced by
+///   cos(inc / 2) for retrograde orbits;
+/// + lambda = ma + aop + I raan.
+///
+/// The direct elements are singular for an inclination of 180 degrees, and the retrograde elements for an inclination of zero.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EquinoctialElements {
+    pub sma_km: f64,
+    pub h: f64,
+    pub k: f64,
+    pub p: f64,
+    pub q: f64,
+    /// Mean longitude, in degrees
+    pub lambda_deg: f64,
+    /// Set to true to use a retrograde factor of -1
+    pub retrograde: bool,
+}
+
+impl EquinoctialElements {
+    /// Returns the retrograde factor, i.e. -1 for retrograde elements and +1 otherwise.
+    pub fn retrograde_factor(&self) -> f64 {
+        if self.retrograde {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the unit vectors (f, g, w) of the equinoctial frame, expressed in the frame of the orbit.
+    fn basis(&self) -> (Vector3, Vector3, Vector3) {
+        let i_fact = self.retrograde_factor();
+        // Convert the sine of the half inclination into its tangent, as used by the classical equinoctial frame.
+        let scale = 1.0
+            / (1.0 - self.p.powi(2) - self.q.powi(2))
+                .max(f64::EPSILON)
+                .sqrt();
+        let (p, q) = (self.p * scale, self.q * scale);
+        let denom = 1.0 + p.powi(2) + q.powi(2);
+
+        let f_hat =
+            Vector3::new(1.0 - p.powi(2) + q.powi(2), 2.0 * p * q, -2.0 * i_fact * p) / denom;
+        let g_hat = Vector3::new(
+            2.0 * i_fact * p * q,
+            i_fact * (1.0 + p.powi(2) - q.powi(2)),
+            2.0 * q,
+        ) / denom;
+        (f_hat, g_hat, f_hat.cross(&g_hat))
+    }
+}
+
+impl fmt::Display for EquinoctialElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sma = {:.6} km\th = {:.6}\tk = {:.6}\tp = {:.6}\tq = {:.6}\tlambda = {:.6} deg{}",
+            self.sma_km,
+            self.h,
+            self.k,
+            self.p,
+            self.q,
+            self.lambda_deg,
+            if self.retrograde { " (retrograde)" } else { "" }
+        )
+    }
+}
+
 impl Orbit {
+    /// Returns the equinoctial elements of this orbit, computed directly from its Cartesian state so that they remain defined for
+    /// circular and equatorial orbits. Retrograde elements are returned if the inclination is greater than 90 degrees.
+    ///
+    /// # Errors
+    /// + The orbit is parabolic or hyperbolic;
+    /// + The frame does not define its gravitational parameter.
+    pub fn to_equinoctial(&self) -> PhysicsResult<EquinoctialElements> {
+        let ecc = self.ecc()?;
+        if ecc >= 1.0 {
+            return Err(PhysicsError::Hyperbolic { ecc });
+        }
+        let sma_km = self.sma_km()?;
+        let w_hat = self.hvec()? / self.hmag()?;
+        let retrograde = w_hat.z < 0.0;
+
+        let mut elements = EquinoctialElements {
+            sma_km,
+            h: 0.0,
+            k: 0.0,
+            p: 0.0,
+            q: 0.0,
+            lambda_deg: 0.0,
+            retrograde,
+        };
+        // The unit angular momentum is (sin(inc) sin(raan), -sin(inc) cos(raan), cos(inc)), and sin(inc) / sqrt(2 (1 + cos(inc)))
+        // is sin(inc / 2), or cos(inc / 2) with the retrograde factor.
+        let i_fact = elements.retrograde_factor();
+        let half_norm = (2.0 * (1.0 + i_fact * w_hat.z)).sqrt();
+        elements.p = w_hat.x / half_norm;
+        elements.q = -w_hat.y / half_norm;
+
+        let (f_hat, g_hat, _) = elements.basis();
+        let evec = self.evec()?;
+        elements.k = evec.dot(&f_hat);
+        elements.h = evec.dot(&g_hat);
+
+        // Eccentric longitude from the position in the equinoctial frame
+        let (x1_km, y1_km) = (self.radius_km.dot(&f_hat), self.radius_km.dot(&g_hat));
+        let (h, k) = (elements.h, elements.k);
+        let beta = 1.0 / (1.0 + (1.0 - h.powi(2) - k.powi(2)).sqrt());
+        let cos_f = k
+            + ((1.0 - k.powi(2) * beta) * x1_km - h * k * beta * y1_km)
+                / (sma_km * (1.0 - h.powi(2) - k.powi(2)).sqrt());
+        let sin_f = h
+            + ((1.0 - h.powi(2) * beta) * y1_km - h * k * beta * x1_km)
+                / (sma_km * (1.0 - h.powi(2) - k.powi(2)).sqrt());
+        let ecc_long = sin_f.atan2(cos_f);
+
+        elements.lambda_deg = (ecc_long + h * ecc_long.cos() - k * ecc_long.sin())
+            .rem_euclid(TAU)
+            .to_degrees();
+
+        Ok(elements)
+    }
+
+    /// Builds an orbit from the provided equinoctial elements, without converting them into Keplerian elements, so that circular
+    /// and equatorial orbits are supported.
+    ///
+    /// # Errors
+    /// + The elements are not those of an elliptical orbit;
+    /// + The frame does not define its gravitational parameter.
+    pub fn from_equinoctial(
+        elements: EquinoctialElements,
+        epoch: Epoch,
+        frame: Frame,
+    ) -> PhysicsResult<Self> {
+        let EquinoctialElements { sma_km, h, k, .. } = elements;
+        let ecc = h.hypot(k);
+        if ecc >= 1.0 || sma_km <= 0.0 {
+            return Err(PhysicsError::Hyperbolic { ecc });
+        }
+        if elements.p.hypot(elements.q) > 1.0 {
+            return Err(PhysicsError::AppliedMath {
+                source: MathError::DomainError {
+                    value: elements.p.hypot(elements.q),
+                    msg: "equinoctial p and q are the sine or cosine of half the inclination, so their norm must be at most one",
+                },
+            });
+        }
+        let mu_km3_s2 = frame.mu_km3_s2()?;
+
+        // Solve the equinoctial Kepler equation lambda = F + h cos(F) - k sin(F) for the eccentric longitude F.
+        let lambda_rad = elements.lambda_deg.to_radians();
+        let mut ecc_long = lambda_rad;
+        let mut converged = false;
+        for _ in 0..100 {
+            let (sin_f, cos_f) = ecc_long.sin_cos();
+            let delta =
+                (ecc_long + h * cos_f - k * sin_f - lambda_rad) / (1.0 - h * sin_f - k * cos_f);
+            ecc_long -= delta;
+            if delta.abs() < 1e-14 {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(PhysicsError::AppliedMath {
+                source: MathError::MaxIterationsReached {
+                    iter: 100,
+                    action: "solving the equinoctial Kepler equation",
+                },
+            });
+        }
+
+        let (sin_f, cos_f) = ecc_long.sin_cos();
+        let beta = 1.0 / (1.0 + (1.0 - ecc.powi(2)).sqrt());
+        let x1_km = sma_km * ((1.0 - h.powi(2) * beta) * cos_f + h * k * beta * sin_f - k);
+        let y1_km = sma_km * ((1.0 - k.powi(2) * beta) * sin_f + h * k * beta * cos_f - h);
+
+        let rmag_km = sma_km * (1.0 - k * cos_f - h * sin_f);
+        let factor = (mu_km3_s2 * sma_km).sqrt() / rmag_km;
+        let x1_dot_km_s = factor * (h * k * beta * cos_f - (1.0 - h.powi(2) * beta) * sin_f);
+        let y1_dot_km_s = factor * ((1.0 - k.powi(2) * beta) * cos_f - h * k * beta * sin_f);
+
+        let (f_hat, g_hat, _) = elements.basis();
+        let mut orbit = Self::zero_at_epoch(epoch, frame);
+        orbit.radius_km = x1_km * f_hat + y1_km * g_hat;
+        orbit.velocity_km_s = x1_dot_km_s * f_hat + y1_dot_km_s * g_hat;
+        Ok(orbit)
+    }
+
     /// Attempts to create a new Orbit from the Equinoctial orbital elements.
     ///
     /// Note that this function computes the Keplerian elements from the equinoctial and then
@@ -117,3 +314,87 @@ impl Orbit {
         Ok(self.raan_deg()? + self.aop_deg()? + self.ma_deg()?)
     }
 }
+
+#[cfg(test)]
+mod ut_equinoctial {
+    use super::EquinoctialElements;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::errors::PhysicsError;
+    use crate::prelude::Orbit;
+    use hifitime::Epoch;
+
+    #[test]
+    fn equinoctial_round_trip() {
+        let eme2k = EARTH_J2000.with_mu_km3_s2(398600.4418);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+
+        // Direct orbit: the elements match the getters and the Keplerian initializer.
+        let orbit =
+            Orbit::try_keplerian(8000.0, 0.2, 30.0, 45.0, 60.0, 75.0, epoch, eme2k).unwrap();
+        let elements = orbit.to_equinoctial().unwrap();
+        assert!(!elements.retrograde);
+        assert!((elements.sma_km - 8000.0).abs() < 1e-9);
+        assert!((elements.h - orbit.equinoctial_h().unwrap()).abs() < 1e-12);
+        assert!((elements.k - orbit.equinoctial_k().unwrap()).abs() < 1e-12);
+        assert!((elements.p - orbit.equinoctial_p().unwrap()).abs() < 1e-12);
+        assert!((elements.q - orbit.equinoctial_q().unwrap()).abs() < 1e-12);
+        let lambda_deg = orbit
+            .equinoctial_lambda_mean_deg()
+            .unwrap()
+            .rem_euclid(360.0);
+        assert!(
+            (elements.lambda_deg - lambda_deg).abs() < 1e-9,
+            "{elements}"
+        );
+
+        let keplerian = Orbit::try_equinoctial(
+            elements.sma_km,
+            elements.h,
+            elements.k,
+            elements.p,
+            elements.q,
+            elements.lambda_deg,
+            epoch,
+            eme2k,
+        )
+        .unwrap();
+
+        for rebuilt in [
+            Orbit::from_equinoctial(elements, epoch, eme2k).unwrap(),
+            keplerian,
+        ] {
+            assert!((rebuilt.radius_km - orbit.radius_km).norm() < 1e-6);
+            assert!((rebuilt.velocity_km_s - orbit.velocity_km_s).norm() < 1e-9);
+        }
+
+        // Circular equatorial orbits and retrograde orbits, including an inclination of 180 degrees.
+        for (ecc, inc_deg) in [(0.0, 0.0), (1e-4, 0.0), (0.1, 120.0), (0.0, 180.0)] {
+            let orbit =
+                Orbit::try_keplerian(7000.0, ecc, inc_deg, 10.0, 20.0, 30.0, epoch, eme2k).unwrap();
+            let elements = orbit.to_equinoctial().unwrap();
+            assert_eq!(elements.retrograde, inc_deg > 90.0);
+            assert!(elements.p.is_finite() && elements.q.is_finite());
+
+            let rebuilt = Orbit::from_equinoctial(elements, epoch, eme2k).unwrap();
+            assert!(
+                (rebuilt.radius_km - orbit.radius_km).norm() < 1e-6,
+                "ecc = {ecc}, inc = {inc_deg}: {elements}"
+            );
+            assert!((rebuilt.velocity_km_s - orbit.velocity_km_s).norm() < 1e-9);
+        }
+
+        // Hyperbolic orbits have no equinoctial elements
+        let hyperbola =
+            Orbit::try_keplerian(-8000.0, 1.5, 30.0, 45.0, 60.0, 0.0, epoch, eme2k).unwrap();
+        assert!(matches!(
+            hyperbola.to_equinoctial(),
+            Err(PhysicsError::Hyperbolic { .. })
+        ));
+        let invalid = EquinoctialElements {
+            h: 0.8,
+            k: 0.8,
+            ..elements
+        };
+        assert!(Orbit::from_equinoctial(invalid, epoch, eme2k).is_err());
+    }
+}
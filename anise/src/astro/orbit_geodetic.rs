@@ -10,14 +10,17 @@
 
 use super::PhysicsResult;
 use crate::{
+    constants::orientations::J2000,
+    errors::{AlmanacError, AlmanacPhysicsSnafu, AlmanacResult, OrientationSnafu, PhysicsError},
     math::{
         angles::{between_0_360, between_pm_180},
         cartesian::CartesianState,
         Vector3,
     },
-    prelude::Frame,
+    prelude::{Almanac, Frame},
 };
 use hifitime::Epoch;
+use snafu::ResultExt;
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -84,6 +87,7 @@ impl CartesianState {
     /// Refer to [try_latlongalt_omega] if you need to build a fixed point with a non-zero angular velocity in the definition frame.
     ///
     /// NOTE: This computation differs from the spherical coordinates because we consider the flattening of body.
+    /// To reference the geodetic coordinates to WGS84 instead of the IAU Earth radii, set the shape of the frame with `frame.with_ellipsoid(Ellipsoid::WGS84)`.
     /// Reference: G. Xu and Y. Xu, "GPS", DOI 10.1007/978-3-662-50367-6_2, 2016
     pub fn try_latlongalt(
         latitude_deg: f64,
@@ -142,6 +146,61 @@ impl CartesianState {
             frame,
         ))
     }
+
+    /// Creates the state of a static site from its latitude (φ), longitude (λ) and height (in km) with respect to the ellipsoid of the
+    /// provided body fixed frame (e.g. IAU Earth or ITRF93), where the velocity is that of the site due to the rotation of this frame
+    /// at this epoch with respect to J2000, as computed from the orientation data loaded in the Almanac.
+    ///
+    /// The shape and gravitational parameter of the frame are fetched from the planetary data of the Almanac unless the provided frame
+    /// already defines them, so a frame set with `frame.with_ellipsoid(Ellipsoid::WGS84)` keeps its ellipsoid.
+    ///
+    /// The returned state is expressed in the axes of the body fixed frame, but its velocity is the inertial velocity of the site, so
+    /// ground stations can be used wherever an orbit is expected (e.g. to compute the angular momentum of the site). Because of that, do
+    /// NOT transform this state into another frame: the rotation of the body fixed frame would be accounted for twice. Use [try_latlongalt]
+    /// instead to build a site which will be transformed.
+    pub fn from_latlonalt(
+        latitude_deg: f64,
+        longitude_deg: f64,
+        height_km: f64,
+        epoch: Epoch,
+        body_fixed_frame: Frame,
+        almanac: &Almanac,
+    ) -> AlmanacResult<Self> {
+        let mut frame = body_fixed_frame;
+        if frame.shape.is_none() || frame.mu_km3_s2.is_none() {
+            let info = almanac
+                .frame_info(frame)
+                .map_err(|e| AlmanacError::GenericError {
+                    err: format!("{e} when fetching {frame} frame data"),
+                })?;
+            frame.shape = frame.shape.or(info.shape);
+            frame.mu_km3_s2 = frame.mu_km3_s2.or(info.mu_km3_s2);
+        }
+
+        let site = Self::try_latlongalt(latitude_deg, longitude_deg, height_km, epoch, frame)
+            .context(AlmanacPhysicsSnafu {
+                action: "building a site from its geodetic coordinates",
+            })?;
+
+        // The site is static in the body fixed frame, so its inertial velocity is the derivative of the rotation to J2000 applied to
+        // its position, and is expressed back in the body fixed axes.
+        let dcm = almanac
+            .rotate(frame, frame.with_orient(J2000), epoch)
+            .context(OrientationSnafu {
+                action: "computing the rotation of a body fixed frame",
+            })?;
+        let rot_mat_dt = dcm.rot_mat_dt.ok_or(AlmanacError::AlmanacPhysics {
+            action: "computing the velocity of a site",
+            source: Box::new(PhysicsError::DCMMissingDerivative {
+                action: "computing the rotation of a body fixed frame",
+            }),
+        })?;
+
+        Ok(Self {
+            velocity_km_s: dcm.rot_mat.transpose() * rot_mat_dt * site.radius_km,
+            ..site
+        })
+    }
 }
 
 #[cfg_attr(feature = "python", pymethods)]
@@ -390,3 +449,50 @@ impl CartesianState {
         Ok(self.latlongalt()?.2)
     }
 }
+
+#[cfg(test)]
+mod ut_orbit_geodetic {
+    use crate::constants::frames::{EARTH_J2000, IAU_EARTH_FRAME};
+    use crate::prelude::{Almanac, Orbit};
+    use hifitime::Epoch;
+
+    #[test]
+    fn site_from_latlonalt() {
+        let almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 6, 1);
+
+        let site =
+            Orbit::from_latlonalt(40.4, -3.95, 0.8, epoch, IAU_EARTH_FRAME, &almanac).unwrap();
+        assert_eq!(site.epoch, epoch);
+        assert!(site.frame.shape.is_some() && site.frame.mu_km3_s2.is_some());
+
+        // Same position as the static site, with the velocity of the rotation of the Earth, about 465 m/s at the equator.
+        let frame = almanac.frame_info(IAU_EARTH_FRAME).unwrap();
+        let fixed = Orbit::try_latlongalt(40.4, -3.95, 0.8, epoch, frame).unwrap();
+        assert_eq!(site.radius_km, fixed.radius_km);
+        let (lat_deg, long_deg, alt_km) = site.latlongalt().unwrap();
+        assert!((lat_deg - 40.4).abs() < 1e-9);
+        assert!((long_deg - 356.05).abs() < 1e-9);
+        assert!((alt_km - 0.8).abs() < 1e-9);
+
+        // The velocity is the inertial one, expressed in the body fixed axes.
+        let inertial = almanac.transform_to(fixed, EARTH_J2000, None).unwrap();
+        assert!((site.vmag_km_s() - inertial.vmag_km_s()).abs() < 1e-12);
+        assert!(site.radius_km.dot(&site.velocity_km_s).abs() < 1e-6);
+        assert!(site.velocity_km_s.z.abs() < 1e-6);
+
+        let equator =
+            Orbit::from_latlonalt(0.0, 0.0, 0.0, epoch, IAU_EARTH_FRAME, &almanac).unwrap();
+        assert!((equator.vmag_km_s() - 0.4651).abs() < 1e-3);
+        assert!(equator.velocity_km_s.y > 0.0);
+
+        let pole = Orbit::from_latlonalt(90.0, 0.0, 0.0, epoch, IAU_EARTH_FRAME, &almanac).unwrap();
+        assert!(pole.vmag_km_s() < 1e-6);
+
+        // Frames without orientation data cannot define a site.
+        assert!(
+            Orbit::from_latlonalt(0.0, 0.0, 0.0, epoch, EARTH_J2000.with_orient(-1), &almanac)
+                .is_err()
+        );
+    }
+}
@@ -8,12 +8,14 @@
  * Documentation: https://nyxspace.com/
  */
 
-use super::utils::mean_anomaly_to_true_anomaly_rad;
 use super::{orbit::Orbit, orbit_equinoctial::equinoctial_to_keplerian, PhysicsResult};
 
 use crate::{
-    errors::MeanElementSnafu,
-    math::angles::{between_0_360, between_0_tau},
+    errors::{MeanElementSnafu, PhysicsError},
+    math::{
+        angles::{between_0_360, between_0_tau},
+        kepler::mean_to_true_anomaly_rad,
+    },
 };
 use core::f64::consts::PI;
 
@@ -92,7 +94,8 @@ fn brouwer_mean_short_to_osculating_kep(
     let gm2 = k2 / smap.powi(2);
     let gm2p = gm2 / eta.powi(4);
 
-    let tap = mean_anomaly_to_true_anomaly_rad(mean_anom, eccp)?;
+    let tap = mean_to_true_anomaly_rad(mean_anom, eccp)
+        .map_err(|source| PhysicsError::AppliedMath { source })?;
     let rp = p / (1.0 + eccp * tap.cos());
     let adr = smap / rp;
 
@@ -0,0 +1,241 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::f64::consts::TAU;
+
+use super::{orbit::Orbit, PhysicsResult};
+use crate::errors::{MathError, PhysicsError};
+
+use hifitime::Epoch;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Maximum number of iterations of the solver of the universal Kepler equation.
+const MAX_ITERATIONS: usize = 64;
+
+/// Returns the Stumpff functions C(z) and S(z), using their series expansion near zero to avoid the cancellations.
+pub(crate) fn stumpff(z: f64) -> (f64, f64) {
+    if z.abs() < 1e-3 {
+        let c = 1.0 / 2.0 - z / 24.0 + z.powi(2) / 720.0 - z.powi(3) / 40_320.0;
+        let s = 1.0 / 6.0 - z / 120.0 + z.powi(2) / 5_040.0 - z.powi(3) / 362_880.0;
+        (c, s)
+    } else if z > 0.0 {
+        let sqrt_z = z.sqrt();
+        (
+            (1.0 - sqrt_z.cos()) / z,
+            (sqrt_z - sqrt_z.sin()) / sqrt_z.powi(3),
+        )
+    } else {
+        let sqrt_mz = (-z).sqrt();
+        (
+            (sqrt_mz.cosh() - 1.0) / -z,
+            (sqrt_mz.sinh() - sqrt_mz) / sqrt_mz.powi(3),
+        )
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Orbit {
+    /// Returns this orbit propagated to the provided epoch with two body dynamics, around the center of its frame.
+    ///
+    /// This uses the Lagrange coefficients computed from the universal anomaly, so it applies to circular, elliptical, parabolic,
+    /// and hyperbolic orbits, and to both forward and backward propagation. The gravitational parameter is that of the frame,
+    /// typically set from the planetary data of the Almanac (cf. `Almanac::propagate_two_body` if it may be missing).
+    ///
+    /// # Astrodynamics note
+    /// This is akin to a two body propagation ONLY without any other force models applied.
+    /// Use Nyx for high fidelity propagation.
+    ///
+    /// :type new_epoch: Epoch
+    /// :rtype: Orbit
+    pub fn propagate_to_epoch(&self, new_epoch: Epoch) -> PhysicsResult<Self> {
+        let mu_km3_s2 = self.frame.mu_km3_s2()?;
+        let sqrt_mu = mu_km3_s2.sqrt();
+
+        let r0_km = self.rmag_km();
+        if r0_km <= f64::EPSILON {
+            return Err(PhysicsError::RadiusError {
+                action: "cannot propagate a state at the center of its frame",
+            });
+        }
+
+        // Reciprocal of the semi major axis, positive for elliptical orbits
+        let alpha = 2.0 / r0_km - self.vmag_km_s().powi(2) / mu_km3_s2;
+        let sigma0 = self.radius_km.dot(&self.velocity_km_s) / sqrt_mu;
+
+        let mut dt_s = (new_epoch - self.epoch).to_seconds();
+        if alpha > 0.0 {
+            // Remove the full revolutions, which keeps the universal anomaly small.
+            let period_s = TAU / (mu_km3_s2 * alpha.powi(3)).sqrt();
+            dt_s -= (dt_s / period_s).trunc() * period_s;
+        }
+        if dt_s == 0.0 {
+            return Ok(Self {
+                epoch: new_epoch,
+                ..*self
+            });
+        }
+
+        // Initial guess of Vallado, Algorithm 8
+        let mut chi = if alpha > 1e-12 {
+            sqrt_mu * dt_s * alpha
+        } else if alpha < -1e-12 {
+            let sma_km = 1.0 / alpha;
+            let guess = dt_s.signum()
+                * (-sma_km).sqrt()
+                * ((-2.0 * mu_km3_s2 * alpha * dt_s)
+                    / (self.radius_km.dot(&self.velocity_km_s)
+                        + dt_s.signum() * (-mu_km3_s2 * sma_km).sqrt() * (1.0 - r0_km * alpha)))
+                    .ln();
+            if guess.is_finite() {
+                guess
+            } else {
+                sqrt_mu * dt_s / r0_km
+            }
+        } else {
+            sqrt_mu * dt_s / r0_km
+        };
+
+        // Laguerre-Conway iterations on the universal Kepler equation, which converge from any initial guess.
+        const ORDER: f64 = 5.0;
+        let mut converged = false;
+        let (mut c, mut s) = stumpff(alpha * chi * chi);
+        for _ in 0..MAX_ITERATIONS {
+            let z = alpha * chi * chi;
+            (c, s) = stumpff(z);
+            let f = sigma0 * chi * chi * c + (1.0 - alpha * r0_km) * chi.powi(3) * s + r0_km * chi
+                - sqrt_mu * dt_s;
+            // The derivative is the radius at the current guess, which is always positive.
+            let df = chi * chi * c + sigma0 * chi * (1.0 - z * s) + r0_km * (1.0 - z * c);
+            let ddf = sigma0 * (1.0 - z * c) + (1.0 - alpha * r0_km) * chi * (1.0 - z * s);
+
+            let disc = ((ORDER - 1.0).powi(2) * df * df - ORDER * (ORDER - 1.0) * f * ddf)
+                .abs()
+                .sqrt();
+            let delta = ORDER * f / (df + df.signum() * disc);
+            chi -= delta;
+
+            if delta.abs() <= 1e-13 * chi.abs().max(1.0) {
+                converged = true;
+                (c, s) = stumpff(alpha * chi * chi);
+                break;
+            }
+        }
+
+        if !converged || !chi.is_finite() {
+            return Err(PhysicsError::AppliedMath {
+                source: MathError::MaxIterationsReached {
+                    iter: MAX_ITERATIONS,
+                    action: "solving the universal Kepler equation",
+                },
+            });
+        }
+
+        // Lagrange coefficients
+        let chi2 = chi * chi;
+        let f = 1.0 - chi2 / r0_km * c;
+        let g = dt_s - chi2 * chi / sqrt_mu * s;
+        let radius_km = f * self.radius_km + g * self.velocity_km_s;
+        let r_km = radius_km.norm();
+        let f_dot = sqrt_mu / (r_km * r0_km) * chi * (alpha * chi2 * s - 1.0);
+        let g_dot = 1.0 - chi2 / r_km * c;
+        let velocity_km_s = f_dot * self.radius_km + g_dot * self.velocity_km_s;
+
+        Ok(Self {
+            radius_km,
+            velocity_km_s,
+            epoch: new_epoch,
+            frame: self.frame,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_propagation {
+    use super::Orbit;
+    use crate::constants::frames::EARTH_J2000;
+    use crate::math::Vector3;
+    use crate::prelude::Almanac;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn two_body_propagation() {
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+
+        // Vallado, example 2-4: r = (1131.340, -2282.343, 6672.423) km, v = (-5.64305, 4.30333, 2.42879) km/s, 40 min later
+        let state = Orbit::new(
+            1131.340, -2282.343, 6672.423, -5.64305, 4.30333, 2.42879, epoch, frame,
+        );
+        let later = state.propagate_to_epoch(epoch + Unit::Minute * 40).unwrap();
+        assert_eq!(later.epoch, epoch + Unit::Minute * 40);
+        assert!((later.radius_km - Vector3::new(-4219.7527, 4363.0292, -3958.7666)).norm() < 1e-3);
+        assert!((later.velocity_km_s - Vector3::new(3.689866, -1.916735, -6.112511)).norm() < 1e-5);
+
+        // Keplerian elements other than the anomaly are preserved, for all kinds of orbits and both directions.
+        for (sma_km, ecc) in [
+            (7000.0, 0.0),
+            (24_000.0, 0.7),
+            (300_000.0, 0.99),
+            (-20_000.0, 1.8),
+            (-100_000.0, 1.01),
+        ] {
+            let state =
+                Orbit::try_keplerian(sma_km, ecc, 28.5, 10.0, 20.0, 30.0, epoch, frame).unwrap();
+            for hours in [-5.0, -0.1, 0.0, 0.5, 3.0, 71.0] {
+                let later = state
+                    .propagate_to_epoch(epoch + Unit::Hour * hours)
+                    .unwrap();
+                assert!(
+                    (later.energy_km2_s2().unwrap() - state.energy_km2_s2().unwrap()).abs()
+                        < 1e-9 * state.energy_km2_s2().unwrap().abs(),
+                    "a = {sma_km}, e = {ecc}, {hours} h"
+                );
+                assert!((later.hvec().unwrap() - state.hvec().unwrap()).norm() < 1e-6);
+                if ecc > 0.0 {
+                    assert!((later.evec().unwrap() - state.evec().unwrap()).norm() < 1e-9);
+                }
+
+                // Going back returns the initial state
+                let back = later.propagate_to_epoch(epoch).unwrap();
+                assert!(
+                    (back.radius_km - state.radius_km).norm() < 1e-6 * state.rmag_km(),
+                    "a = {sma_km}, e = {ecc}, {hours} h"
+                );
+            }
+        }
+
+        // Matches the mean anomaly based propagation of elliptical orbits, including over many revolutions.
+        let state =
+            Orbit::try_keplerian(24_000.0, 0.7, 28.5, 10.0, 20.0, 30.0, epoch, frame).unwrap();
+        let later = epoch + Unit::Day * 30 + Unit::Minute * 7;
+        let expected = state.at_epoch(later).unwrap();
+        let propagated = state.propagate_to_epoch(later).unwrap();
+        assert!((expected.radius_km - propagated.radius_km).norm() < 1e-4);
+
+        // Frames without a gravitational parameter cannot be propagated
+        let state = Orbit::new(7000.0, 0.0, 0.0, 0.0, 7.5, 0.0, epoch, EARTH_J2000);
+        assert!(state.propagate_to_epoch(later).is_err());
+
+        // Unless the Almanac provides it
+        let almanac = Almanac::default().load("../data/pck08.pca").unwrap();
+        let propagated = almanac.propagate_two_body(state, later).unwrap();
+        assert_eq!(propagated.epoch, later);
+        assert!(propagated.frame.mu_km3_s2.is_some());
+        let expected = Orbit {
+            frame: almanac.frame_info(EARTH_J2000).unwrap(),
+            ..state
+        }
+        .propagate_to_epoch(later)
+        .unwrap();
+        assert_eq!(propagated, expected);
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+use hifitime::Epoch;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::orbit::Orbit;
+use crate::frames::Frame;
+use crate::math::angles::between_0_360;
+
+/// Right ascension, declination, and range of a state with their rates of change, as returned by [Orbit::radec].
+///
+/// The angles are measured in the XY plane and from the XY plane of the frame of the state, e.g. the equator of the J2000 frame.
+/// This is the equivalent of the SPICE `recrad` routine, along with the time derivatives of the angles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(get_all, set_all, from_py_object, module = "anise.astro")
+)]
+pub struct RaDec {
+    pub epoch: Epoch,
+    pub frame: Frame,
+    /// Right ascension, between 0 and 360 degrees
+    pub ra_deg: f64,
+    /// Declination, between -90 and +90 degrees
+    pub dec_deg: f64,
+    pub range_km: f64,
+    pub ra_rate_deg_s: f64,
+    pub dec_rate_deg_s: f64,
+    pub range_rate_km_s: f64,
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+#[cfg(feature = "python")]
+impl RaDec {
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self} (@{self:p})")
+    }
+}
+
+impl fmt::Display for RaDec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: RA = {:.6} deg ({:.6e} deg/s), Dec = {:.6} deg ({:.6e} deg/s), range = {:.6} km ({:.6} km/s) in {:x}",
+            self.epoch,
+            self.ra_deg,
+            self.ra_rate_deg_s,
+            self.dec_deg,
+            self.dec_rate_deg_s,
+            self.range_km,
+            self.range_rate_km_s,
+            self.frame
+        )
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Orbit {
+    /// Returns the right ascension, declination, and range of this state with their rates of change, in the frame of this state.
+    ///
+    /// The angles and their rates are zero for a zero radius, and the right ascension and its rate are zero along the Z axis, where
+    /// they are undefined.
+    ///
+    /// :rtype: RaDec
+    pub fn radec(&self) -> RaDec {
+        let r = self.radius_km;
+        let v = self.velocity_km_s;
+
+        let range_km = self.rmag_km();
+        // Projection of the radius onto the XY plane
+        let rho_km = r.x.hypot(r.y);
+
+        let (ra_deg, ra_rate_deg_s) = if rho_km > f64::EPSILON {
+            (
+                between_0_360(r.y.atan2(r.x).to_degrees()),
+                ((r.x * v.y - r.y * v.x) / rho_km.powi(2)).to_degrees(),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        let (dec_deg, dec_rate_deg_s, range_rate_km_s) = if range_km > f64::EPSILON {
+            let rho_dot_km_s = if rho_km > f64::EPSILON {
+                (r.x * v.x + r.y * v.y) / rho_km
+            } else {
+                0.0
+            };
+            (
+                r.z.atan2(rho_km).to_degrees(),
+                ((v.z * rho_km - r.z * rho_dot_km_s) / range_km.powi(2)).to_degrees(),
+                r.dot(&v) / range_km,
+            )
+        } else {
+            (0.0, 0.0, self.vmag_km_s())
+        };
+
+        RaDec {
+            epoch: self.epoch,
+            frame: self.frame,
+            ra_deg,
+            dec_deg,
+            range_km,
+            ra_rate_deg_s,
+            dec_rate_deg_s,
+            range_rate_km_s,
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_radec {
+    use crate::constants::frames::EARTH_J2000;
+    use crate::prelude::Orbit;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn radec_rates() {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let state = Orbit::new(-4000.0, -3000.0, 5000.0, 1.5, -6.0, 2.5, epoch, EARTH_J2000);
+
+        let radec = state.radec();
+        println!("{radec}");
+        assert!((radec.ra_deg - state.right_ascension_deg()).abs() < 1e-12);
+        assert!((radec.dec_deg - state.declination_deg()).abs() < 1e-12);
+        assert!((radec.range_km - state.rmag_km()).abs() < 1e-12);
+        assert!((0.0..360.0).contains(&radec.ra_deg));
+
+        // The rates match the finite differences of the angles along the straight line motion.
+        let dt_s = 1e-3;
+        let later = Orbit {
+            radius_km: state.radius_km + state.velocity_km_s * dt_s,
+            epoch: epoch + Unit::Second * dt_s,
+            ..state
+        }
+        .radec();
+        let earlier = Orbit {
+            radius_km: state.radius_km - state.velocity_km_s * dt_s,
+            epoch: epoch - Unit::Second * dt_s,
+            ..state
+        }
+        .radec();
+
+        let ra_rate = (later.ra_deg - earlier.ra_deg) / (2.0 * dt_s);
+        let dec_rate = (later.dec_deg - earlier.dec_deg) / (2.0 * dt_s);
+        let range_rate = (later.range_km - earlier.range_km) / (2.0 * dt_s);
+        assert!((radec.ra_rate_deg_s - ra_rate).abs() < 1e-9);
+        assert!((radec.dec_rate_deg_s - dec_rate).abs() < 1e-9);
+        assert!((radec.range_rate_km_s - range_rate).abs() < 1e-9);
+
+        // Along the pole, the right ascension is undefined and set to zero.
+        let pole = Orbit::new(0.0, 0.0, -7000.0, 1.0, 0.0, 0.0, epoch, EARTH_J2000).radec();
+        assert_eq!(pole.ra_deg, 0.0);
+        assert_eq!(pole.ra_rate_deg_s, 0.0);
+        assert!((pole.dec_deg + 90.0).abs() < 1e-12);
+    }
+}
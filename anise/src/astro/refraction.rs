@@ -0,0 +1,176 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+use super::AzElRange;
+
+/// Geometric elevations below this value (in degrees) are not corrected for refraction, as the models are not valid there.
+pub const MIN_REFRACTION_ELEVATION_DEG: f64 = -1.0;
+
+/// Atmospheric refraction models, used to convert a geometric (true) elevation into an apparent elevation.
+///
+/// Refraction bends the signal towards the ground, so the apparent elevation of an object is always above its geometric elevation.
+/// The correction is largest at the horizon (about half a degree) and vanishes at zenith.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RefractionModel {
+    /// Optical refraction for standard conditions (1010 hPa, 10 °C), using Sæmundsson's inversion of Bennett's formula,
+    /// i.e. `R = 1.02 / tan(h + 10.3 / (h + 5.11))` arcminutes, where `h` is the geometric elevation in degrees.
+    Bennett,
+    /// Optical refraction of [RefractionModel::Bennett] scaled to the local pressure (in hPa) and temperature (in degrees Celsius)
+    /// at the station, i.e. `R * (P / 1010) * (283 / (273 + T))`, as recommended by Meeus (Astronomical Algorithms, chapter 16).
+    BennettMeteo {
+        pressure_hpa: f64,
+        temperature_deg_c: f64,
+    },
+    /// Radio refraction per ITU-R P.834 for the reference atmosphere, where the station altitude is in kilometers above mean sea level.
+    ItuRadio { station_altitude_km: f64 },
+}
+
+impl RefractionModel {
+    /// Returns the refraction correction in degrees to add to the provided geometric elevation (in degrees) to obtain the apparent elevation.
+    ///
+    /// This returns zero below [MIN_REFRACTION_ELEVATION_DEG] because these models are not applicable there.
+    pub fn correction_deg(&self, elevation_deg: f64) -> f64 {
+        if !elevation_deg.is_finite() || elevation_deg < MIN_REFRACTION_ELEVATION_DEG {
+            return 0.0;
+        }
+
+        let correction_deg = match *self {
+            Self::Bennett => bennett_deg(elevation_deg),
+            Self::BennettMeteo {
+                pressure_hpa,
+                temperature_deg_c,
+            } => {
+                bennett_deg(elevation_deg)
+                    * (pressure_hpa / 1010.0)
+                    * (283.0 / (273.0 + temperature_deg_c))
+            }
+            Self::ItuRadio {
+                station_altitude_km: h,
+            } => {
+                let theta = elevation_deg;
+                1.0 / (1.314
+                    + 0.6437 * theta
+                    + 0.02869 * theta.powi(2)
+                    + h * (0.2305 + 0.09428 * theta + 0.01096 * theta.powi(2))
+                    + 0.008583 * h.powi(2))
+            }
+        };
+
+        // Near zenith, Bennett's formula may be very slightly negative.
+        correction_deg.max(0.0)
+    }
+
+    /// Returns the apparent elevation in degrees from the provided geometric elevation in degrees.
+    pub fn apparent_elevation_deg(&self, elevation_deg: f64) -> f64 {
+        elevation_deg + self.correction_deg(elevation_deg)
+    }
+}
+
+/// Bennett's refraction in degrees for standard conditions, at the provided geometric elevation in degrees.
+fn bennett_deg(elevation_deg: f64) -> f64 {
+    let arg_deg = elevation_deg + 10.3 / (elevation_deg + 5.11);
+    1.02 / arg_deg.to_radians().tan() / 60.0
+}
+
+impl fmt::Display for RefractionModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bennett => write!(f, "Bennett"),
+            Self::BennettMeteo {
+                pressure_hpa,
+                temperature_deg_c,
+            } => write!(f, "Bennett ({pressure_hpa} hPa, {temperature_deg_c} °C)"),
+            Self::ItuRadio {
+                station_altitude_km,
+            } => write!(f, "ITU-R P.834 (station at {station_altitude_km} km)"),
+        }
+    }
+}
+
+impl AzElRange {
+    /// Returns the refraction correction in degrees of this geometric elevation for the provided model.
+    pub fn refraction_deg(&self, model: RefractionModel) -> f64 {
+        model.correction_deg(self.elevation_deg)
+    }
+
+    /// Returns a copy of this AER where the elevation is the apparent elevation per the provided refraction model.
+    ///
+    /// The azimuth, range, and range rate are not modified. This should only be applied once, on a geometric elevation.
+    pub fn with_refraction(&self, model: RefractionModel) -> Self {
+        let mut me = *self;
+        me.elevation_deg = model.apparent_elevation_deg(self.elevation_deg);
+        me
+    }
+}
+
+#[cfg(test)]
+mod ut_refraction {
+    use super::*;
+
+    #[test]
+    fn bennett() {
+        let model = RefractionModel::Bennett;
+        // Refraction at the horizon is about 29 arcminutes with this formula.
+        let horizon_arcmin = model.correction_deg(0.0) * 60.0;
+        assert!((horizon_arcmin - 29.0).abs() < 0.5, "{horizon_arcmin}");
+        // About 5.4 arcmin at 10 deg, about 1 arcmin at 45 deg
+        assert!((model.correction_deg(10.0) * 60.0 - 5.4).abs() < 0.05);
+        assert!((model.correction_deg(45.0) * 60.0 - 1.0).abs() < 0.05);
+        assert_eq!(model.correction_deg(90.0), 0.0);
+        assert_eq!(model.correction_deg(-5.0), 0.0);
+        assert!(model.apparent_elevation_deg(5.0) > 5.0);
+    }
+
+    #[test]
+    fn bennett_meteo() {
+        // The standard conditions of Bennett's formula yield the same correction.
+        let standard = RefractionModel::BennettMeteo {
+            pressure_hpa: 1010.0,
+            temperature_deg_c: 10.0,
+        };
+        for elevation_deg in [0.0, 5.0, 20.0, 60.0] {
+            assert!(
+                (standard.correction_deg(elevation_deg)
+                    - RefractionModel::Bennett.correction_deg(elevation_deg))
+                .abs()
+                    < 1e-12
+            );
+        }
+        // A high altitude observatory on a cold night: lower pressure reduces the refraction, colder air increases it.
+        let summit = RefractionModel::BennettMeteo {
+            pressure_hpa: 615.0,
+            temperature_deg_c: 0.0,
+        };
+        let expected_deg =
+            RefractionModel::Bennett.correction_deg(10.0) * (615.0 / 1010.0) * (283.0 / 273.0);
+        assert!((summit.correction_deg(10.0) - expected_deg).abs() < 1e-12);
+        assert!(summit.correction_deg(10.0) < RefractionModel::Bennett.correction_deg(10.0));
+        assert_eq!(summit.correction_deg(-5.0), 0.0);
+    }
+
+    #[test]
+    fn itu_radio() {
+        let sea_level = RefractionModel::ItuRadio {
+            station_altitude_km: 0.0,
+        };
+        let mountain = RefractionModel::ItuRadio {
+            station_altitude_km: 2.0,
+        };
+        // At the horizon, the sea level correction is 1/1.314 degrees.
+        assert!((sea_level.correction_deg(0.0) - 1.0 / 1.314).abs() < 1e-12);
+        // Thinner atmosphere means less refraction
+        assert!(mountain.correction_deg(5.0) < sea_level.correction_deg(5.0));
+        // Monotonically decreasing with elevation
+        assert!(sea_level.correction_deg(30.0) < sea_level.correction_deg(5.0));
+        assert!(sea_level.correction_deg(90.0) < 0.01);
+    }
+}
@@ -8,9 +8,10 @@
  * Documentation: https://nyxspace.com/
  */
 
-use std::f64::consts::{PI, TAU};
+use std::f64::consts::TAU;
 
 use crate::errors::{MathError, PhysicsError};
+use crate::math::kepler::{mean_to_true_anomaly_rad, true_to_mean_anomaly_rad};
 
 use super::PhysicsResult;
 
@@ -43,11 +44,7 @@ pub fn compute_mean_to_true_anomaly_rad(ma_radians: f64, ecc: f64) -> PhysicsRes
     mean_anomaly_to_true_anomaly_rad(ma_radians, ecc)
 }
 
-/// Computes the true anomaly from the given mean anomaly for an orbit.
-///
-/// The computation process varies depending on whether the orbit is elliptical (eccentricity less than or equal to 1)
-/// or hyperbolic (eccentricity greater than 1). In each case, the method uses an iterative algorithm to find a
-/// sufficiently accurate approximation of the true anomaly.
+/// Computes the true anomaly, within [0, 2π), from the given mean anomaly for an elliptical or hyperbolic orbit.
 ///
 /// # Arguments
 ///
@@ -56,169 +53,11 @@ pub fn compute_mean_to_true_anomaly_rad(ma_radians: f64, ecc: f64) -> PhysicsRes
 ///
 /// # Remarks
 ///
-/// This function uses GTDS MathSpec Equations 3-180, 3-181, and 3-186 for the iterative computation process.
-///
-/// Source: GMAT source code (`compute_mean_to_true_anomaly`)
+/// This wraps [mean_to_true_anomaly_rad], which solves Kepler's equation for the eccentric anomaly.
 pub fn mean_anomaly_to_true_anomaly_rad(ma_radians: f64, ecc: f64) -> PhysicsResult<f64> {
-    let rm = ma_radians;
-    if ecc <= 1.0 {
-        // Elliptical orbit
-        let mut e2 = rm + ecc * rm.sin(); // GTDS MathSpec Equation 3-182
-
-        let mut iter = 0;
-
-        loop {
-            iter += 1;
-            if iter > 1000 {
-                return Err(PhysicsError::AppliedMath {
-                    source: MathError::MaxIterationsReached {
-                        iter,
-                        action: "computing the true anomaly from the mean anomaly",
-                    },
-                });
-            }
-
-            // GTDS MathSpec Equation 3-180  Note: a little difference here is that it uses Cos(E) instead of Cos(E-0.5*f)
-            let normalized_anomaly = 1.0 - ecc * e2.cos();
-
-            if normalized_anomaly.abs() < MA_EPSILON {
-                return Err(PhysicsError::AppliedMath {
-                    source: MathError::DomainError {
-                        value: normalized_anomaly,
-                        msg: "normalized anomaly too small",
-                    },
-                });
-            }
-
-            // GTDS MathSpec Equation 3-181
-            let e1 = e2 - (e2 - ecc * e2.sin() - rm) / normalized_anomaly;
-
-            if (e2 - e1).abs() < MA_EPSILON {
-                break;
-            }
-
-            e2 = e1;
-        }
-
-        let mut e = e2;
-
-        if e < 0.0 {
-            e += TAU;
-        }
-
-        let c = (e - PI).abs();
-
-        let mut ta = if c >= 1.0e-08 {
-            let normalized_anomaly = 1.0 - ecc;
-
-            if (normalized_anomaly).abs() < MA_EPSILON {
-                return Err(PhysicsError::AppliedMath {
-                    source: MathError::DomainError {
-                        value: normalized_anomaly,
-                        msg: "normalized anomaly too small",
-                    },
-                });
-            }
-
-            let eccentricity_ratio = (1.0 + ecc) / normalized_anomaly; // temp2 = (1+ecc)/(1-ecc)
-
-            if eccentricity_ratio < 0.0 {
-                return Err(PhysicsError::AppliedMath {
-                    source: MathError::DomainError {
-                        value: eccentricity_ratio,
-                        msg: "eccentricity ratio too small",
-                    },
-                });
-            }
-
-            let f = eccentricity_ratio.sqrt();
-            let g = (e / 2.0).tan();
-            // tan(TA/2) = Sqrt[(1+ecc)/(1-ecc)] * tan(E/2)
-            2.0 * (f * g).atan()
-        } else {
-            e
-        };
-
-        if ta < 0.0 {
-            ta += TAU;
-        }
-        Ok(ta)
-    } else {
-        //---------------------------------------------------------
-        // hyperbolic orbit
-        //---------------------------------------------------------
-
-        // For hyperbolic orbit, anomaly is nolonger to be an angle so we cannot use mod of 2*PI to mean anomaly.
-        // We need to keep its original value for calculation.
-        //if (rm > PI)                       // incorrect
-        //   rm = rm - TWO_PI;               // incorrect
-
-        //f2 = ecc * Sinh(rm) - rm;          // incorrect
-        //f2 = rm / 2;                       // incorrect  // GTDS MathSpec Equation 3-186
-        let mut f2: f64 = 0.0; // This is the correct initial value for hyperbolic eccentric anomaly.
-        let mut iter = 0;
-
-        loop {
-            iter += 1;
-            if iter > 1000 {
-                return Err(PhysicsError::AppliedMath {
-                    source: MathError::MaxIterationsReached {
-                        iter,
-                        action: "computing the true anomaly from the mean anomaly",
-                    },
-                });
-            }
-
-            let normalizer = ecc * f2.cosh() - 1.0;
-
-            if normalizer.abs() < MA_EPSILON {
-                return Err(PhysicsError::AppliedMath {
-                    source: MathError::DomainError {
-                        value: normalizer,
-                        msg: "normalized anomaly too small (hyperbolic case)",
-                    },
-                });
-            }
-
-            let f1 = f2 - (ecc * f2.sinh() - f2 - rm) / normalizer; // GTDS MathSpec Equation 3-186
-            if (f2 - f1).abs() < MA_EPSILON {
-                break;
-            }
-            f2 = f1;
-        }
-
-        let f = f2;
-        let normalized_anomaly = ecc - 1.0;
-
-        if normalized_anomaly.abs() < MA_EPSILON {
-            return Err(PhysicsError::AppliedMath {
-                source: MathError::DomainError {
-                    value: normalized_anomaly,
-                    msg: "normalized anomaly too small (hyperbolic case)",
-                },
-            });
-        }
-
-        let eccentricity_ratio = (ecc + 1.0) / normalized_anomaly; // temp2 = (ecc+1)/(ecc-1)
-
-        if eccentricity_ratio < 0.0 {
-            return Err(PhysicsError::AppliedMath {
-                source: MathError::DomainError {
-                    value: eccentricity_ratio,
-                    msg: "eccentricity ratio too small (hyperbolic case)",
-                },
-            });
-        }
-
-        let e = eccentricity_ratio.sqrt();
-        let g = (f / 2.0).tanh();
-        let mut ta = 2.0 * (e * g).atan(); // tan(TA/2) = Sqrt[(ecc+1)/(ecc-1)] * Tanh(F/2)    where: F is hyperbolic centric anomaly
-
-        if ta < 0.0 {
-            ta += TAU;
-        }
-        Ok(ta)
-    }
+    let ta_rad = mean_to_true_anomaly_rad(ma_radians, ecc)
+        .map_err(|source| PhysicsError::AppliedMath { source })?;
+    Ok(ta_rad.rem_euclid(TAU))
 }
 
 /// Computes the mean anomaly from the true anomaly.
@@ -230,36 +69,11 @@ pub fn mean_anomaly_to_true_anomaly_rad(ma_radians: f64, ecc: f64) -> PhysicsRes
 ///
 /// # Returns
 ///
-/// The mean anomaly in radians (normalized to `[0, 2*PI)`), or a `MathError` if the computation fails.
+/// The mean anomaly in radians (normalized to `[0, 2*PI)`), or a `MathError` if the computation fails, e.g. for parabolic orbits.
+///
+/// This wraps [true_to_mean_anomaly_rad].
 pub fn true_anomaly_to_mean_anomaly_rad(nu_rad: f64, ecc: f64) -> Result<f64, MathError> {
-    // Step 1: Calculate Eccentric Anomaly E_rad
-    // The true_anomaly_to_eccentric_anomaly_rad function already checks for ecc < 0.0
-    let e_rad = true_anomaly_to_eccentric_anomaly_rad(nu_rad, ecc)?;
-
-    // Step 2: Calculate Mean Anomaly M_rad based on eccentricity
-    let m_rad = if ecc < 1.0 {
-        // Elliptical or circular orbit: M = E - e * sin(E)
-        e_rad - ecc * e_rad.sin()
-    } else {
-        // Hyperbolic orbit: M = e * sinh(E) - E
-        ecc * e_rad.sinh() - e_rad
-    };
-
-    // Step 3: Normalize M_rad to be within the range [0, 2*PI)
-    // For hyperbolic orbits, mean anomaly is not an angle in the same way,
-    // but Kepler's equation for hyperbolic orbits (M = e*sinh(H) - H) is often used with M directly.
-    // However, the problem asks to normalize it to [0, 2*PI), which implies it's being treated
-    // as an angle for some purpose (perhaps consistency or specific conventions in this library).
-    // If this normalization is not desired for hyperbolic cases in a broader context,
-    // this part might need reconsideration for those cases.
-    // For now, strictly following the requirement.
-    let mut m_normalized_rad = m_rad % TAU;
-    if m_normalized_rad < 0.0 {
-        m_normalized_rad += TAU;
-    }
-
-    // Step 4: Return Ok(m_normalized_rad)
-    Ok(m_normalized_rad)
+    Ok(true_to_mean_anomaly_rad(nu_rad, ecc)?.rem_euclid(TAU))
 }
 
 /// Computes the eccentric anomaly from the true anomaly.
@@ -559,15 +373,10 @@ mod ut_utils {
 
     #[test]
     fn test_ta_to_ma_parabolic() {
-        // 4. Parabolic (e=1.0)
+        // 4. Parabolic (e=1.0): the mean anomaly is not defined
         let ecc = 1.0;
-        // TA=0 -> EA=0 -> MA = 1.0*sinh(0) - 0 = 0
-        let res1 = true_anomaly_to_mean_anomaly_rad(0.0, ecc);
-        f64_eq_tol!(res1.unwrap(), 0.0, TEST_EPS, "");
-
-        // TA=PI/2 -> EA=0 -> MA = 1.0*sinh(0) - 0 = 0
-        let res2 = true_anomaly_to_mean_anomaly_rad(PI / 2.0, ecc);
-        f64_eq_tol!(res2.unwrap(), 0.0, TEST_EPS, "");
+        assert!(true_anomaly_to_mean_anomaly_rad(0.0, ecc).is_err());
+        assert!(true_anomaly_to_mean_anomaly_rad(PI / 2.0, ecc).is_err());
     }
 
     #[test]
@@ -11,6 +11,12 @@
 /// Speed of light in kilometers per second (km/s)
 pub const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
 
+/// Astronomical unit in kilometers (km), as defined by the IAU 2012 Resolution B2
+pub const ASTRONOMICAL_UNIT_KM: f64 = 149_597_870.7;
+
+/// Solar flux at one astronomical unit in watts per square meter (W/m^2), i.e. the nominal total solar irradiance of the IAU 2015 Resolution B3
+pub const SOLAR_FLUX_AT_1_AU_W_M2: f64 = 1361.0;
+
 pub mod celestial_objects {
     use crate::{ephemerides::EphemerisError, NaifId};
 
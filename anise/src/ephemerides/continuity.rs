@@ -0,0 +1,324 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+use super::{EphemInterpolationSnafu, EphemerisError, SPKSnafu};
+use crate::almanac::Almanac;
+use crate::math::interpolation::InterpDecodingSnafu;
+use crate::math::Vector3;
+use crate::naif::daf::datatypes::{ChebyshevSpectrum, Type2ChebyshevSet, Type3ChebyshevSet};
+use crate::naif::daf::{DafDataType, NAIFSummaryRecord};
+use crate::naif::spk::summary::SPKSummaryRecord;
+use crate::NaifId;
+
+/// A discontinuity in the ephemeris of an SPK segment, found at the boundary between two consecutive interpolation records.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Discontinuity {
+    /// Alias of the SPK containing this segment
+    pub alias: String,
+    pub target_id: NaifId,
+    pub center_id: NaifId,
+    /// Epoch of the boundary between both records
+    pub epoch: Epoch,
+    /// Position of the next record minus the position of the previous record at the boundary, in kilometers
+    pub position_jump_km: Vector3,
+    /// Velocity of the next record minus the velocity of the previous record at the boundary, in kilometers per second
+    pub velocity_jump_km_s: Vector3,
+}
+
+impl fmt::Display for Discontinuity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} wrt {} in {} @ {}: position jump of {:.6} km, velocity jump of {:.9} km/s",
+            self.target_id,
+            self.center_id,
+            self.alias,
+            self.epoch,
+            self.position_jump_km.norm(),
+            self.velocity_jump_km_s.norm()
+        )
+    }
+}
+
+/// Spectrum of the Chebyshev coefficients of each record of an SPK segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentSpectrum {
+    /// Alias of the SPK containing this segment
+    pub alias: String,
+    pub target_id: NaifId,
+    pub center_id: NaifId,
+    pub data_type: DafDataType,
+    pub records: Vec<ChebyshevSpectrum>,
+}
+
+impl SegmentSpectrum {
+    /// Returns the largest estimated truncation error of all of the records of this segment, in kilometers.
+    pub fn max_truncation_error_km(&self) -> f64 {
+        self.records
+            .iter()
+            .map(|record| record.truncation_error())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Almanac {
+    /// Scans all of the loaded SPK segments of the provided ID for discontinuities between consecutive interpolation records.
+    ///
+    /// Each record boundary is evaluated with both the previous and the next record, and reported if the norm of the position jump
+    /// exceeds `position_tol_km` or if the norm of the velocity jump exceeds `velocity_tol_km_s`.
+    ///
+    /// # Limitations
+    /// Only Chebyshev segments (Type 2 and Type 3) are split into independent records. Other segment types are interpolated over
+    /// a sliding window of states, and are skipped.
+    pub fn spk_discontinuities(
+        &self,
+        id: NaifId,
+        position_tol_km: f64,
+        velocity_tol_km_s: f64,
+    ) -> Result<Vec<Discontinuity>, EphemerisError> {
+        let mut discontinuities = Vec::new();
+
+        self.for_each_chebyshev_segment(id, |alias, summary, data| {
+            let jumps = match data {
+                ChebyshevData::Type2(data) => data.record_boundary_jumps(),
+                ChebyshevData::Type3(data) => data.record_boundary_jumps(),
+            }
+            .context(EphemInterpolationSnafu)?;
+
+            for (epoch, position_jump_km, velocity_jump_km_s) in jumps {
+                if position_jump_km.norm() > position_tol_km
+                    || velocity_jump_km_s.norm() > velocity_tol_km_s
+                {
+                    discontinuities.push(Discontinuity {
+                        alias: alias.to_string(),
+                        target_id: summary.target_id,
+                        center_id: summary.center_id,
+                        epoch,
+                        position_jump_km,
+                        velocity_jump_km_s,
+                    });
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(discontinuities)
+    }
+
+    /// Returns the spectrum of the position Chebyshev coefficients of each record of all of the loaded SPK segments of the provided ID.
+    ///
+    /// This is useful to check whether a resampled or cropped kernel preserved the accuracy of the original one: the magnitude of the
+    /// highest degree coefficients estimates the truncation error of each record.
+    ///
+    /// # Limitations
+    /// Only Chebyshev segments (Type 2 and Type 3) are reported, other segment types are skipped.
+    pub fn spk_chebyshev_spectrum(
+        &self,
+        id: NaifId,
+    ) -> Result<Vec<SegmentSpectrum>, EphemerisError> {
+        let mut spectra = Vec::new();
+
+        self.for_each_chebyshev_segment(id, |alias, summary, data| {
+            let records = match data {
+                ChebyshevData::Type2(data) => data.coefficient_spectrum(),
+                ChebyshevData::Type3(data) => data.coefficient_spectrum(),
+            }
+            .context(InterpDecodingSnafu)
+            .context(EphemInterpolationSnafu)?;
+
+            spectra.push(SegmentSpectrum {
+                alias: alias.to_string(),
+                target_id: summary.target_id,
+                center_id: summary.center_id,
+                data_type: summary.data_type()?,
+                records,
+            });
+            Ok(())
+        })?;
+
+        Ok(spectra)
+    }
+
+    /// Returns the record boundary epochs of all of the loaded SPK segments of the provided ID, sorted chronologically and without duplicates.
+    ///
+    /// Refer to [crate::naif::SPK::segment_record_boundaries] for the definition of a record boundary for each segment type.
+    pub fn spk_record_boundaries(&self, id: NaifId) -> Result<Vec<Epoch>, EphemerisError> {
+        // Ensures that we return an error if this ID isn't loaded at all.
+        self.spk_summaries(id)?;
+
+        let mut boundaries = Vec::new();
+        for spk_no in self.spk_precedence() {
+            let (_, spk) = self
+                .spk_data
+                .get_index(spk_no)
+                .ok_or(EphemerisError::Unreachable)?;
+
+            for (handle, _) in spk.segment_handles(id).context(SPKSnafu {
+                action: "fetching record boundaries",
+            })? {
+                boundaries.extend(spk.segment_record_boundaries(handle)?);
+            }
+        }
+
+        boundaries.sort();
+        boundaries.dedup();
+        Ok(boundaries)
+    }
+
+    /// Calls `f` on each Chebyshev segment of the provided ID, in the order in which the loaded SPKs are searched.
+    fn for_each_chebyshev_segment<F>(&self, id: NaifId, mut f: F) -> Result<(), EphemerisError>
+    where
+        F: FnMut(&str, &SPKSummaryRecord, ChebyshevData) -> Result<(), EphemerisError>,
+    {
+        // Ensures that we return an error if this ID isn't loaded at all.
+        self.spk_summaries(id)?;
+
+        for spk_no in self.spk_precedence() {
+            let (alias, spk) = self
+                .spk_data
+                .get_index(spk_no)
+                .ok_or(EphemerisError::Unreachable)?;
+
+            let mut daf_idx = None;
+            loop {
+                let summaries = spk.data_summaries(daf_idx).context(SPKSnafu {
+                    action: "fetching summaries for Chebyshev diagnostics",
+                })?;
+
+                for (idx_in_spk, summary) in summaries.iter().enumerate() {
+                    if summary.is_empty() || summary.id() != id {
+                        continue;
+                    }
+
+                    let data = match summary.data_type()? {
+                        DafDataType::Type2ChebyshevTriplet => ChebyshevData::Type2(
+                            spk.nth_data::<Type2ChebyshevSet>(daf_idx, idx_in_spk)
+                                .context(SPKSnafu {
+                                    action: "fetching data for Chebyshev diagnostics",
+                                })?,
+                        ),
+                        DafDataType::Type3ChebyshevSextuplet => ChebyshevData::Type3(
+                            spk.nth_data::<Type3ChebyshevSet>(daf_idx, idx_in_spk)
+                                .context(SPKSnafu {
+                                    action: "fetching data for Chebyshev diagnostics",
+                                })?,
+                        ),
+                        _ => continue,
+                    };
+
+                    f(alias, summary, data)?;
+                }
+
+                let daf_summary = spk.daf_summary(daf_idx).context(SPKSnafu {
+                    action: "fetching summaries for Chebyshev diagnostics",
+                })?;
+                if daf_summary.is_final_record() || Some(daf_summary.next_record()) == daf_idx {
+                    break;
+                }
+                daf_idx = Some(daf_summary.next_record());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+enum ChebyshevData<'a> {
+    Type2(Type2ChebyshevSet<'a>),
+    Type3(Type3ChebyshevSet<'a>),
+}
+
+#[cfg(test)]
+mod ut_continuity {
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::naif::daf::DafDataType;
+    use crate::naif::SPK;
+    use crate::prelude::Almanac;
+    use hifitime::Epoch;
+
+    /// Builds an SPK with a Moon segment made of two degree one Chebyshev Type 2 records, where X jumps by -0.5 km at the boundary.
+    fn discontinuous_spk() -> SPK {
+        // Record 1: X = t, ends at 1.0, and record 2: X = 1.5 + t, starts at 0.5
+        let records = [
+            [[0.0, 1.0], [0.0; 2], [0.0; 2]],
+            [[1.5, 1.0], [0.0; 2], [0.0; 2]],
+        ];
+        test_spk(&[chebyshev_segment(301, 399, 0.0, 86_400.0, &records)])
+    }
+
+    #[test]
+    fn spk_discontinuities() {
+        let almanac =
+            Almanac::default().with_spk_as(discontinuous_spk(), Some("moon.bsp".to_string()));
+
+        let found = almanac.spk_discontinuities(301, 1e-3, 1e-6).unwrap();
+        assert_eq!(found.len(), 1);
+        let jump = &found[0];
+        assert_eq!(jump.alias, "moon.bsp");
+        assert_eq!(jump.center_id, 399);
+        assert!((jump.epoch.to_et_seconds() - 86400.0).abs() < 1e-6);
+        assert!((jump.position_jump_km.x + 0.5).abs() < 1e-12);
+        assert!(jump.position_jump_km.y.abs() < 1e-12);
+        // Both records have the same slope
+        assert!(jump.velocity_jump_km_s.norm() < 1e-12);
+
+        // Above the tolerance, nothing is reported
+        assert!(almanac
+            .spk_discontinuities(301, 1.0, 1e-6)
+            .unwrap()
+            .is_empty());
+
+        // Unknown IDs are an error
+        assert!(almanac.spk_discontinuities(399, 1.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn spk_record_boundaries() {
+        let almanac =
+            Almanac::default().with_spk_as(discontinuous_spk(), Some("moon.bsp".to_string()));
+
+        let boundaries = almanac.spk_record_boundaries(301).unwrap();
+        assert_eq!(
+            boundaries,
+            vec![
+                Epoch::from_et_seconds(0.0),
+                Epoch::from_et_seconds(86400.0),
+                Epoch::from_et_seconds(172800.0)
+            ]
+        );
+
+        assert!(almanac.spk_record_boundaries(399).is_err());
+    }
+
+    #[test]
+    fn spk_chebyshev_spectrum() {
+        let almanac =
+            Almanac::default().with_spk_as(discontinuous_spk(), Some("moon.bsp".to_string()));
+
+        let spectra = almanac.spk_chebyshev_spectrum(301).unwrap();
+        assert_eq!(spectra.len(), 1);
+        let segment = &spectra[0];
+        assert_eq!(segment.alias, "moon.bsp");
+        assert_eq!(segment.data_type, DafDataType::Type2ChebyshevTriplet);
+        assert_eq!(segment.records.len(), 2);
+        assert_eq!(segment.records[0].magnitudes, vec![0.0, 1.0]);
+        assert_eq!(segment.records[1].magnitudes, vec![1.5, 1.0]);
+        assert_eq!(segment.records[1].truncation_error(), 1.0);
+        // Degree one is too low to compute a decay
+        assert_eq!(segment.records[0].decay_ratio(), None);
+        assert_eq!(segment.max_truncation_error_km(), 1.0);
+
+        assert!(almanac.spk_chebyshev_spectrum(399).is_err());
+    }
+}
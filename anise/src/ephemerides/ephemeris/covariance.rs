@@ -8,8 +8,10 @@
  * Documentation: https://nyxspace.com/
  */
 
-use crate::math::Matrix6;
+use crate::math::{Matrix6, Vector6};
+use crate::prelude::Frame;
 use core::fmt;
+use hifitime::Epoch;
 use nalgebra::SymmetricEigen;
 
 #[cfg(feature = "python")]
@@ -24,6 +26,8 @@ pub enum LocalFrame {
     RIC,
     VNC,
     RCN,
+    /// Local vertical, local horizontal: Z is nadir, Y is the negative orbit normal, and X completes the frame
+    LVLH,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -41,6 +45,47 @@ impl fmt::Display for Covariance {
     }
 }
 
+/// A position and velocity covariance expressed in the axes of a frame at an epoch, e.g. the covariance of an orbit determination
+/// solution, which may be transformed into the axes of any other frame with `Almanac::transform_covariance_to`.
+///
+/// Unlike [Covariance], which is expressed in a local frame of an orbit, this covariance does not require the state it describes.
+///
+/// :type covar: numpy.ndarray
+/// :type frame: Frame
+/// :type epoch: Epoch
+/// :rtype: FrameCovariance
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "python", pyclass)]
+#[cfg_attr(feature = "python", pyo3(module = "anise.astro"))]
+pub struct FrameCovariance {
+    /// Covariance of the position (km) and velocity (km/s)
+    pub matrix: Matrix6,
+    pub frame: Frame,
+    pub epoch: Epoch,
+}
+
+impl FrameCovariance {
+    pub fn new(matrix: Matrix6, frame: Frame, epoch: Epoch) -> Self {
+        Self {
+            matrix,
+            frame,
+            epoch,
+        }
+    }
+
+    /// Returns the standard deviations of the position (km) and velocity (km/s) components, i.e. the square root of the diagonal.
+    pub fn sigmas(&self) -> Vector6 {
+        self.matrix.diagonal().map(f64::sqrt)
+    }
+}
+
+impl fmt::Display for FrameCovariance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Covariance in {} at {}", self.frame, self.epoch)?;
+        write!(f, "{:.6}", self.matrix)
+    }
+}
+
 /// Computes the Matrix Logarithm of a Symmetric Positive Definite matrix.
 /// Returns None if the matrix is not positive definite (has eigenvalues <= 0).
 fn matrix_log_spd(mat: Matrix6) -> Option<Matrix6> {
@@ -37,7 +37,7 @@ mod record;
 mod spk;
 mod stk;
 
-pub use covariance::{Covariance, LocalFrame};
+pub use covariance::{Covariance, FrameCovariance, LocalFrame};
 pub use record::EphemerisRecord;
 
 /// Initializes a new Ephemeris from the list of Orbit instances and a given object ID.
@@ -535,8 +535,9 @@ impl IntoIterator for Ephemeris {
 mod ut_oem {
     use super::{Almanac, DataType, Ephemeris, LocalFrame};
     use crate::analysis::prelude::OrbitalElement;
+    use crate::format::{EpochFormat, ExponentStyle, FloatFormat, ProductFormat};
     use crate::prelude::NAIFSummaryRecord;
-    use hifitime::{Epoch, TimeSeries, Unit};
+    use hifitime::{Epoch, TimeScale, TimeSeries, Unit};
     use nalgebra::{Matrix6, SymmetricEigen, Vector6};
     use std::{fs::File, io::Write};
 
@@ -622,6 +623,38 @@ mod ut_oem {
         let ephem2 = Ephemeris::from_ccsds_oem_file(outpath).unwrap();
         assert_eq!(ephem2, ephem);
 
+        // Build it with a fixed number of significant digits, in another time system
+        let format = ProductFormat {
+            float: FloatFormat::Scientific {
+                significant_digits: 12,
+                exponent: ExponentStyle::Upper,
+            },
+            epoch: EpochFormat::Iso8601,
+            time_scale: Some(TimeScale::TAI),
+        };
+        ephem
+            .write_ccsds_oem_with_format(outpath, None, None, &format)
+            .unwrap();
+        let contents = std::fs::read_to_string(outpath).unwrap();
+        assert!(contents.contains("TIME_SYSTEM = TAI"));
+        assert!(contents.contains("2020-06-01T12:00:37.000000000 "));
+
+        let ephem3 = Ephemeris::from_ccsds_oem_file(outpath).unwrap();
+        assert_eq!(ephem3.state_data.len(), ephem.state_data.len());
+        for (rec3, rec) in ephem3.state_data.values().zip(ephem.state_data.values()) {
+            assert_eq!(rec3.orbit.epoch, rec.orbit.epoch);
+            assert!((rec3.orbit.radius_km - rec.orbit.radius_km).norm() < 1e-6);
+        }
+
+        // Julian dates are not valid CCSDS epochs
+        let jd_format = ProductFormat {
+            epoch: EpochFormat::JulianDate { decimals: 9 },
+            ..format
+        };
+        assert!(ephem
+            .write_ccsds_oem_with_format(outpath, None, None, &jd_format)
+            .is_err());
+
         // Build the SPK/BSP file as Type13 first
         let my_spk = ephem
             .to_spice_bsp(-159, Some(DataType::Type13HermiteUnequalStep))
@@ -9,6 +9,7 @@
  */
 
 use super::{EphemerisError, OEMTimeParsingSnafu};
+use crate::format::{EpochFormat, ProductFormat};
 use crate::math::{Matrix6, Vector6};
 use crate::naif::daf::data_types::DataType;
 use crate::prelude::{Frame, Orbit};
@@ -274,6 +275,7 @@ impl Ephemeris {
                         "EME2000" | "ICRF" => cov_frame = Some(LocalFrame::Inertial),
                         "RSW" | "RTN" => cov_frame = Some(LocalFrame::RIC),
                         "TNW" => cov_frame = Some(LocalFrame::VNC),
+                        "LVLH" => cov_frame = Some(LocalFrame::LVLH),
                         _ => {
                             return Err(EphemerisError::OEMParsingError {
                                 lno,
@@ -377,6 +379,35 @@ impl Ephemeris {
         path: P,
         originator: Option<String>,
         object_name: Option<String>,
+    ) -> Result<(), EphemerisError> {
+        self.write_oem(path, originator, object_name, None)
+    }
+
+    /// Export this Ephemeris to CCSDS OEM format, formatting the states, covariances, and epochs with the provided product format.
+    ///
+    /// The epochs must be formatted as ISO 8601 or day of year dates, which are the formats allowed by CCSDS, and the default
+    /// epoch format is written as ISO 8601. If the product format sets a time scale, it is used as the time system of the OEM.
+    pub fn write_ccsds_oem_with_format<P: AsRef<Path>>(
+        &self,
+        path: P,
+        originator: Option<String>,
+        object_name: Option<String>,
+        format: &ProductFormat,
+    ) -> Result<(), EphemerisError> {
+        if let EpochFormat::JulianDate { .. } = format.epoch {
+            return Err(EphemerisError::OEMWritingError {
+                details: "CCSDS OEM epochs cannot be written as Julian dates".to_string(),
+            });
+        }
+        self.write_oem(path, originator, object_name, Some(format))
+    }
+
+    fn write_oem<P: AsRef<Path>>(
+        &self,
+        path: P,
+        originator: Option<String>,
+        object_name: Option<String>,
+        format: Option<&ProductFormat>,
     ) -> Result<(), EphemerisError> {
         if self.state_data.is_empty() {
             return Err(EphemerisError::OEMParsingError {
@@ -394,8 +425,25 @@ impl Ephemeris {
             details: format!("{e}"),
         };
 
-        // Epoch formmatter.
+        // Epoch and value formatters.
         let iso8601_no_ts = Format::from_str("%Y-%m-%dT%H:%M:%S.%f").unwrap();
+        let fmt_epoch = |epoch: Epoch| match format {
+            Some(format) => {
+                let format = match format.epoch {
+                    EpochFormat::DayOfYear => *format,
+                    _ => ProductFormat {
+                        epoch: EpochFormat::Iso8601,
+                        ..*format
+                    },
+                };
+                format.format_epoch(epoch)
+            }
+            None => Formatter::new(epoch, iso8601_no_ts).to_string(),
+        };
+        let fmt_f64 = |value: f64| match format {
+            Some(format) => format.format_f64(value),
+            None => format!("{value:E}"),
+        };
 
         // Write mandatory metadata
         writeln!(writer, "CCSDS_OEM_VERS = 2.0\n").map_err(err_hdlr)?;
@@ -448,7 +496,10 @@ impl Ephemeris {
         .map_err(err_hdlr)?;
 
         writeln!(writer, "\tCENTER_NAME = {center}",).map_err(err_hdlr)?;
-        writeln!(writer, "\tTIME_SYSTEM = {}", first_orbit.epoch.time_scale).map_err(err_hdlr)?;
+        let time_system = format
+            .and_then(|format| format.time_scale)
+            .unwrap_or(first_orbit.epoch.time_scale);
+        writeln!(writer, "\tTIME_SYSTEM = {time_system}").map_err(err_hdlr)?;
         writeln!(
             writer,
             "\tINTERPOLATION = {}",
@@ -462,30 +513,20 @@ impl Ephemeris {
 
         writeln!(writer, "\tINTERPOLATION_DEGREE = {}", self.degree).map_err(err_hdlr)?;
 
-        writeln!(
-            writer,
-            "\tSTART_TIME = {}",
-            Formatter::new(first_orbit.epoch, iso8601_no_ts),
-        )
-        .map_err(err_hdlr)?;
+        writeln!(writer, "\tSTART_TIME = {}", fmt_epoch(first_orbit.epoch),).map_err(err_hdlr)?;
         writeln!(
             writer,
             "\tUSEABLE_START_TIME = {}",
-            Formatter::new(first_orbit.epoch, iso8601_no_ts),
+            fmt_epoch(first_orbit.epoch),
         )
         .map_err(err_hdlr)?;
         writeln!(
             writer,
             "\tUSEABLE_STOP_TIME = {}",
-            Formatter::new(last_orbit.epoch, iso8601_no_ts),
-        )
-        .map_err(err_hdlr)?;
-        writeln!(
-            writer,
-            "\tSTOP_TIME = {}",
-            Formatter::new(last_orbit.epoch, iso8601_no_ts),
+            fmt_epoch(last_orbit.epoch),
         )
         .map_err(err_hdlr)?;
+        writeln!(writer, "\tSTOP_TIME = {}", fmt_epoch(last_orbit.epoch),).map_err(err_hdlr)?;
 
         writeln!(writer, "META_STOP\n").map_err(err_hdlr)?;
 
@@ -493,14 +534,14 @@ impl Ephemeris {
             let orbit = entry.orbit;
             writeln!(
                 writer,
-                "{} {:E} {:E} {:E} {:E} {:E} {:E}",
-                Formatter::new(*epoch, iso8601_no_ts),
-                orbit.radius_km.x,
-                orbit.radius_km.y,
-                orbit.radius_km.z,
-                orbit.velocity_km_s.x,
-                orbit.velocity_km_s.y,
-                orbit.velocity_km_s.z
+                "{} {} {} {} {} {} {}",
+                fmt_epoch(*epoch),
+                fmt_f64(orbit.radius_km.x),
+                fmt_f64(orbit.radius_km.y),
+                fmt_f64(orbit.radius_km.z),
+                fmt_f64(orbit.velocity_km_s.x),
+                fmt_f64(orbit.velocity_km_s.y),
+                fmt_f64(orbit.velocity_km_s.z)
             )
             .map_err(err_hdlr)?;
         }
@@ -516,8 +557,7 @@ impl Ephemeris {
                     writeln!(writer, "COVARIANCE_START").map_err(err_hdlr)?;
                     cov_started = true;
                 }
-                writeln!(writer, "EPOCH = {}", Formatter::new(*epoch, iso8601_no_ts),)
-                    .map_err(err_hdlr)?;
+                writeln!(writer, "EPOCH = {}", fmt_epoch(*epoch)).map_err(err_hdlr)?;
 
                 writeln!(
                     writer,
@@ -526,6 +566,7 @@ impl Ephemeris {
                         LocalFrame::Inertial => "EME2000",
                         LocalFrame::RIC => "RTN",
                         LocalFrame::VNC => "TNW",
+                        LocalFrame::LVLH => "LVLH",
                         LocalFrame::RCN =>
                             return Err(EphemerisError::OEMWritingError {
                                 details: "RCN frame is not supported for OEM covariance export"
@@ -543,7 +584,8 @@ impl Ephemeris {
                 for row in 0..6 {
                     let mut line = String::new();
                     for col in 0..row + 1 {
-                        line.push_str(&format!("{:E} ", covar.matrix[(col, row)]));
+                        line.push_str(&fmt_f64(covar.matrix[(col, row)]));
+                        line.push(' ');
                     }
                     writeln!(writer, "{}", line.trim()).map_err(err_hdlr)?;
                 }
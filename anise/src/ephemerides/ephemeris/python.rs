@@ -8,13 +8,17 @@
  * Documentation: https://nyxspace.com/
  */
 
-use super::{Covariance, Ephemeris, EphemerisError, EphemerisRecord, LocalFrame, Orbit};
+use super::{
+    Covariance, Ephemeris, EphemerisError, EphemerisRecord, FrameCovariance, LocalFrame, Orbit,
+};
 use crate::naif::daf::data_types::DataType;
 use crate::naif::daf::DafDataType;
+use crate::prelude::Frame;
 use crate::NaifId;
+use hifitime::Epoch;
 use nalgebra::Matrix6;
 use ndarray::Array2;
-use numpy::{PyArray2, PyReadonlyArray2, PyUntypedArrayMethods};
+use numpy::{PyArray1, PyArray2, PyReadonlyArray2, PyUntypedArrayMethods};
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
@@ -221,6 +225,67 @@ impl Covariance {
     }
 }
 
+#[pymethods]
+impl FrameCovariance {
+    #[new]
+    fn py_new<'py>(
+        covar: PyReadonlyArray2<'py, f64>,
+        frame: Frame,
+        epoch: Epoch,
+    ) -> PyResult<Self> {
+        if covar.shape() != [6, 6] {
+            return Err(PyErr::new::<PyTypeError, _>("covariance must be 6x6"));
+        }
+
+        let matrix = Matrix6::from_row_iterator(covar.as_array().iter().copied());
+
+        Ok(Self::new(matrix, frame, epoch))
+    }
+
+    /// Returns the 6x6 covariance of the position (km) and velocity (km/s).
+    /// Warning: you MUST manually install numpy to call this function.
+    /// :rtype: numpy.ndarray
+    #[getter]
+    fn get_matrix<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<f64>>> {
+        // Extract data from SMatrix (column-major order, hence the transpose)
+        let data: Vec<f64> = self.matrix.transpose().iter().copied().collect();
+
+        let matrix = Array2::from_shape_vec((6, 6), data).unwrap();
+
+        Ok(PyArray2::<f64>::from_owned_array(py, matrix))
+    }
+
+    /// :rtype: Frame
+    #[getter]
+    fn get_frame(&self) -> Frame {
+        self.frame
+    }
+
+    /// :rtype: Epoch
+    #[getter]
+    fn get_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the standard deviations of the position (km) and velocity (km/s) components, i.e. the square root of the diagonal.
+    ///
+    /// :rtype: numpy.ndarray
+    #[pyo3(name = "sigmas")]
+    fn py_sigmas<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice(py, self.sigmas().as_slice())
+    }
+
+    /// :rtype: str
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    /// :rtype: str
+    fn __repr__(&self) -> String {
+        format!("{self}@{self:p}")
+    }
+}
+
 #[pyclass]
 struct EphemerisIterator {
     ephem: Py<Ephemeris>,
@@ -16,6 +16,7 @@ use crate::{
     naif::daf::DAFError, prelude::FrameUid, NaifId,
 };
 
+pub mod continuity;
 #[cfg(feature = "analysis")]
 pub mod ephemeris;
 pub mod paths;
@@ -65,6 +66,14 @@ pub enum EphemerisError {
         #[snafu(source(from(EphemerisError, Box::new)))] // This ensures the source error is boxed
         source: Box<EphemerisError>,
     },
+    #[snafu(display(
+        "could not resample segment #{idx} within {tolerance_km} km with up to {max_records} records"
+    ))]
+    ResampleTolerance {
+        idx: usize,
+        tolerance_km: f64,
+        max_records: usize,
+    },
     #[snafu(display("unknown name associated with NAIF ID {id}"))]
     IdToName { id: NaifId },
     #[snafu(display("unknown NAIF ID associated with `{name}`"))]
@@ -75,7 +75,7 @@ impl Almanac {
         }
 
         // Grab the summary data, which we use to find the paths
-        let summary = self.spk_summary_at_epoch(source.ephemeris_id, epoch)?.0;
+        let ((summary, ..), _) = self.spk_summary_with_extrapolation(source.ephemeris_id, epoch)?;
 
         let mut center_id = summary.center_id;
 
@@ -88,7 +88,7 @@ impl Almanac {
         }
 
         for _ in 0..MAX_TREE_DEPTH {
-            let summary = self.spk_summary_at_epoch(center_id, epoch)?.0;
+            let ((summary, ..), _) = self.spk_summary_with_extrapolation(center_id, epoch)?;
             center_id = summary.center_id;
             of_path[of_path_len] = Some(center_id);
             of_path_len += 1;
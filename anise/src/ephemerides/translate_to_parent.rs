@@ -9,20 +9,13 @@
  */
 
 use log::trace;
-use snafu::ResultExt;
 
-use super::{EphemerisError, SPKSnafu};
+use super::EphemerisError;
 use crate::almanac::Almanac;
-use crate::ephemerides::EphemInterpolationSnafu;
 use crate::hifitime::Epoch;
 use crate::math::cartesian::CartesianState;
 use crate::math::Vector3;
-use crate::naif::daf::datatypes::modified_diff::ModifiedDiffType1;
-use crate::naif::daf::datatypes::{
-    HermiteSetType12, HermiteSetType13, LagrangeSetType8, LagrangeSetType9, Type2ChebyshevSet,
-    Type3ChebyshevSet,
-};
-use crate::naif::daf::{DAFError, DafDataType, NAIFDataSet, NAIFSummaryRecord};
+use crate::naif::spk::segment::SegmentHandle;
 use crate::prelude::Frame;
 
 #[cfg(feature = "python")]
@@ -42,9 +35,9 @@ impl Almanac {
         source: Frame,
         epoch: Epoch,
     ) -> Result<(Vector3, Vector3, Frame), EphemerisError> {
-        // First, let's find the SPK summary for this frame.
-        let (summary, spk_no, daf_idx, idx_in_spk) =
-            self.spk_summary_at_epoch(source.ephemeris_id, epoch)?;
+        // First, let's find the SPK summary for this frame. Outside of the coverage, the extrapolation policy may clamp the epoch.
+        let ((summary, spk_no, daf_idx, idx_in_spk), epoch) =
+            self.spk_summary_with_extrapolation(source.ephemeris_id, epoch)?;
 
         let new_frame = source.with_ephem(summary.center_id);
 
@@ -57,81 +50,14 @@ impl Almanac {
             .ok_or(EphemerisError::Unreachable)?;
 
         // Now let's simply evaluate the data
-
-        let (pos_km, vel_km_s) = match summary.data_type()? {
-            DafDataType::Type1ModifiedDifferenceArray => {
-                let data = spk_data
-                    .nth_data::<ModifiedDiffType1>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            DafDataType::Type2ChebyshevTriplet => {
-                let data = spk_data
-                    .nth_data::<Type2ChebyshevSet>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            DafDataType::Type3ChebyshevSextuplet => {
-                let data = spk_data
-                    .nth_data::<Type3ChebyshevSet>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            DafDataType::Type8LagrangeEqualStep => {
-                let data = spk_data
-                    .nth_data::<LagrangeSetType8>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            DafDataType::Type9LagrangeUnequalStep => {
-                let data = spk_data
-                    .nth_data::<LagrangeSetType9>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            DafDataType::Type12HermiteEqualStep => {
-                let data = spk_data
-                    .nth_data::<HermiteSetType12>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            DafDataType::Type13HermiteUnequalStep => {
-                let data = spk_data
-                    .nth_data::<HermiteSetType13>(daf_idx, idx_in_spk)
-                    .context(SPKSnafu {
-                        action: "fetching data for interpolation",
-                    })?;
-                data.evaluate(epoch, summary)
-                    .context(EphemInterpolationSnafu)?
-            }
-            dtype => {
-                return Err(EphemerisError::SPK {
-                    action: "translation to parent",
-                    source: DAFError::UnsupportedDatatype {
-                        dtype,
-                        kind: "SPK computations",
-                    },
-                })
-            }
-        };
+        let (pos_km, vel_km_s) = spk_data.evaluate_summary(
+            SegmentHandle {
+                daf_idx,
+                idx: idx_in_spk,
+            },
+            summary,
+            epoch,
+        )?;
 
         Ok((pos_km, vel_km_s, new_frame))
     }
@@ -13,7 +13,7 @@ use snafu::ResultExt;
 use super::EphemerisError;
 use super::EphemerisPhysicsSnafu;
 use crate::almanac::Almanac;
-use crate::astro::aberration::stellar_aberration;
+use crate::astro::aberration::stellar_aberration_state;
 use crate::astro::Aberration;
 use crate::constants::frames::SSB_J2000;
 use crate::constants::SPEED_OF_LIGHT_KM_S;
@@ -48,6 +48,8 @@ impl Almanac {
     /// 2.  Initialize the state vectors for both the forward (observer to common ancestor) and backward (target to common ancestor) paths.
     /// 3.  Iteratively traverse the ephemeris tree from the observer and target frames up to the common ancestor, accumulating the state vectors at each step using `translation_parts_to_parent`.
     /// 4.  If aberration corrections are requested, calculate the one-way light time and apply the correction to the target's position.
+    ///     In transmission mode (`XLT`, `XCN`, `XLT+S`, `XCN+S`), the target is evaluated at the epoch _plus_ the light time, i.e. where a signal
+    ///     emitted by the observer at the epoch reaches the target, as needed for uplink and antenna pointing predicts.
     /// 5.  The final state is the difference between the backward and forward state vectors.
     pub fn translate(
         &self,
@@ -58,7 +60,7 @@ impl Almanac {
     ) -> Result<CartesianState, EphemerisError> {
         if observer_frame == target_frame {
             // Both frames match, return this frame's hash (i.e. no need to go higher up).
-            return Ok(CartesianState::zero(observer_frame));
+            return Ok(CartesianState::zero_at_epoch(epoch, observer_frame));
         }
 
         // If there is no frame info, the user hasn't loaded this frame, but might still want to compute a translation.
@@ -70,132 +72,195 @@ impl Almanac {
         match ab_corr {
             None => {
                 // Geometric case (no aberration correction)
-                let (node_count, _path, common_node) =
-                    self.common_ephemeris_path(observer_frame, target_frame, epoch)?;
-
-                // The `fwrd` variables store the state of the observer frame relative to the common ancestor.
-                let (mut pos_fwrd, mut vel_fwrd, mut frame_fwrd) =
-                    if observer_frame.ephem_origin_id_match(common_node) {
-                        // Observer is the common ancestor, so state is zero.
-                        (Vector3::zeros(), Vector3::zeros(), observer_frame)
-                    } else {
-                        self.translation_parts_to_parent(observer_frame, epoch)?
-                    };
-
-                // The `bwrd` variables store the state of the target frame relative to the common ancestor.
-                let (mut pos_bwrd, mut vel_bwrd, mut frame_bwrd) =
-                    if target_frame.ephem_origin_id_match(common_node) {
-                        // Target is the common ancestor, so state is zero.
-                        (Vector3::zeros(), Vector3::zeros(), target_frame)
-                    } else {
-                        self.translation_parts_to_parent(target_frame, epoch)?
-                    };
-
-                // Traverse the ephemeris tree from both the observer and target up to the common ancestor.
-                for _ in 0..node_count {
-                    if !frame_fwrd.ephem_origin_id_match(common_node) {
-                        // Accumulate the state from the current forward frame to its parent.
-                        let (cur_pos_fwrd, cur_vel_fwrd, cur_frame_fwrd) =
-                            self.translation_parts_to_parent(frame_fwrd, epoch)?;
-
-                        pos_fwrd += cur_pos_fwrd;
-                        vel_fwrd += cur_vel_fwrd;
-                        frame_fwrd = cur_frame_fwrd;
-                    }
-
-                    if !frame_bwrd.ephem_origin_id_match(common_node) {
-                        // Accumulate the state from the current backward frame to its parent.
-                        let (cur_pos_bwrd, cur_vel_bwrd, cur_frame_bwrd) =
-                            self.translation_parts_to_parent(frame_bwrd, epoch)?;
-
-                        pos_bwrd += cur_pos_bwrd;
-                        vel_bwrd += cur_vel_bwrd;
-                        frame_bwrd = cur_frame_bwrd;
-                    }
-                }
+                // Frame switches are resolved here, i.e. at the epoch of each geometric evaluation, including light time corrected epochs.
+                let target_id = self.resolve_frame_switch(target_frame, epoch).ephemeris_id;
+                let observer_id = self
+                    .resolve_frame_switch(observer_frame, epoch)
+                    .ephemeris_id;
+                // Resolve the aliases exactly once, so that chained or cyclic switches cannot recurse.
+                let mut state = self.translate_unswitched(
+                    target_frame.with_ephem(target_id),
+                    observer_frame.with_ephem(observer_id),
+                    epoch,
+                )?;
+                state.frame = observer_frame.with_orient(target_frame.orientation_id);
+                Ok(state)
+            }
+            Some(ab_corr) => {
+                let (radius_km, velocity_km_s) = self.aberration_corrected_parts(
+                    |epoch| {
+                        let tgt_ssb = self.translate(target_frame, SSB_J2000, epoch, None)?;
+                        Ok((tgt_ssb.radius_km, tgt_ssb.velocity_km_s))
+                    },
+                    observer_frame,
+                    epoch,
+                    ab_corr,
+                )?;
 
-                // The final state is the difference between the state of the target and the observer, both relative to the common ancestor.
                 Ok(CartesianState {
-                    radius_km: pos_bwrd - pos_fwrd,
-                    velocity_km_s: vel_bwrd - vel_fwrd,
+                    radius_km,
+                    velocity_km_s,
                     epoch,
                     frame: observer_frame.with_orient(target_frame.orientation_id),
                 })
             }
-            Some(ab_corr) => {
-                // Aberration correction case. This is a rewrite of NAIF SPICE's `spkapo`.
-
-                // Find the geometric position of the observer body with respect to the solar system barycenter (SSB).
-                let obs_ssb = self.translate(observer_frame, SSB_J2000, epoch, None)?;
-                let obs_ssb_pos_km = obs_ssb.radius_km;
-                let obs_ssb_vel_km_s = obs_ssb.velocity_km_s;
-
-                // Find the geometric position of the target body with respect to the SSB at the same epoch.
-                let tgt_ssb = self.translate(target_frame, SSB_J2000, epoch, None)?;
-                let tgt_ssb_pos_km = tgt_ssb.radius_km;
-                let tgt_ssb_vel_km_s = tgt_ssb.velocity_km_s;
-
-                // Calculate the initial relative position and velocity.
-                let mut rel_pos_km = tgt_ssb_pos_km - obs_ssb_pos_km;
-                let mut rel_vel_km_s = tgt_ssb_vel_km_s - obs_ssb_vel_km_s;
-
-                // Compute the initial one-way light time.
-                let mut one_way_lt_s = rel_pos_km.norm() / SPEED_OF_LIGHT_KM_S;
-
-                // Iteratively correct for the one-way light time.
-                // The number of iterations depends on whether a converged solution is requested.
-                let num_it = if ab_corr.converged { 3 } else { 1 };
-                let lt_sign = if ab_corr.transmit_mode { 1.0 } else { -1.0 };
-
-                for _ in 0..num_it {
-                    // Calculate the light-time corrected epoch.
-                    let epoch_lt = epoch + lt_sign * one_way_lt_s * TimeUnit::Second;
-                    // Find the position of the target at the corrected epoch.
-                    let tgt_ssb = self
-                        .translate(target_frame, SSB_J2000, epoch_lt, None)
-                        .map_err(|e| EphemerisError::LightTimeCorrection {
-                            epoch,
-                            epoch_lt,
-                            ab_corr,
-                            source: Box::new(e),
-                        })?;
-                    let tgt_ssb_pos_km = tgt_ssb.radius_km;
-                    let tgt_ssb_vel_km_s = tgt_ssb.velocity_km_s;
-                    // Update the relative position.
-                    rel_pos_km = tgt_ssb_pos_km - obs_ssb_pos_km;
-                    let r_norm = rel_pos_km.norm();
-                    // Update the light-time corrected relative velocity.
-                    let geometric_rel_vel = tgt_ssb_vel_km_s - obs_ssb_vel_km_s;
-                    if r_norm > 0.0 {
-                        let inv_c_r = 1.0 / (SPEED_OF_LIGHT_KM_S * r_norm);
-                        let r_dot_v_rel = rel_pos_km.dot(&geometric_rel_vel);
-                        let r_dot_v_tgt = rel_pos_km.dot(&tgt_ssb_vel_km_s);
-                        // The rate of change of light time.
-                        let dlt = (inv_c_r * r_dot_v_rel) / (1.0 - lt_sign * r_dot_v_tgt * inv_c_r);
-                        rel_vel_km_s = tgt_ssb_vel_km_s * (1.0 + lt_sign * dlt) - obs_ssb_vel_km_s;
-                    } else {
-                        rel_vel_km_s = geometric_rel_vel;
-                    }
-                    // Update the one-way light time for the next iteration.
-                    one_way_lt_s = r_norm / SPEED_OF_LIGHT_KM_S;
-                }
+        }
+    }
 
-                // If stellar aberration correction is requested, apply it now.
-                if ab_corr.stellar {
-                    rel_pos_km = stellar_aberration(rel_pos_km, obs_ssb_vel_km_s, ab_corr)
-                        .context(EphemerisPhysicsSnafu {
-                            action: "computing stellar aberration",
-                        })?;
-                }
+    /// Returns the position and velocity of the target as seen from the observer frame at the provided epoch, corrected with the
+    /// provided aberration correction, where `target_ssb` returns the geometric position and velocity of the target with respect
+    /// to the solar system barycenter at any epoch.
+    fn aberration_corrected_parts<F>(
+        &self,
+        target_ssb: F,
+        observer_frame: Frame,
+        epoch: Epoch,
+        ab_corr: Aberration,
+    ) -> Result<(Vector3, Vector3), EphemerisError>
+    where
+        F: Fn(Epoch) -> Result<(Vector3, Vector3), EphemerisError>,
+    {
+        // This is a rewrite of NAIF SPICE's `spkapo`.
 
-                Ok(CartesianState {
-                    radius_km: rel_pos_km,
-                    velocity_km_s: rel_vel_km_s,
+        // Find the geometric position of the observer body with respect to the solar system barycenter (SSB).
+        let obs_ssb = self.translate(observer_frame, SSB_J2000, epoch, None)?;
+        let obs_ssb_pos_km = obs_ssb.radius_km;
+        let obs_ssb_vel_km_s = obs_ssb.velocity_km_s;
+
+        // Find the geometric position of the target body with respect to the SSB at the same epoch.
+        let (tgt_ssb_pos_km, tgt_ssb_vel_km_s) = target_ssb(epoch)?;
+
+        // Calculate the initial relative position and velocity.
+        let mut rel_pos_km = tgt_ssb_pos_km - obs_ssb_pos_km;
+        let mut rel_vel_km_s = tgt_ssb_vel_km_s - obs_ssb_vel_km_s;
+
+        // Compute the initial one-way light time.
+        let mut one_way_lt_s = rel_pos_km.norm() / SPEED_OF_LIGHT_KM_S;
+
+        // Iteratively correct for the one-way light time.
+        // The number of iterations depends on whether a converged solution is requested.
+        let num_it = if ab_corr.converged { 3 } else { 1 };
+        let lt_sign = if ab_corr.transmit_mode { 1.0 } else { -1.0 };
+
+        for _ in 0..num_it {
+            // Calculate the light-time corrected epoch.
+            let epoch_lt = epoch + lt_sign * one_way_lt_s * TimeUnit::Second;
+            // Find the position of the target at the corrected epoch.
+            let (tgt_ssb_pos_km, tgt_ssb_vel_km_s) =
+                target_ssb(epoch_lt).map_err(|e| EphemerisError::LightTimeCorrection {
                     epoch,
-                    frame: observer_frame.with_orient(target_frame.orientation_id),
-                })
+                    epoch_lt,
+                    ab_corr,
+                    source: Box::new(e),
+                })?;
+            // Update the relative position.
+            rel_pos_km = tgt_ssb_pos_km - obs_ssb_pos_km;
+            let r_norm = rel_pos_km.norm();
+            // Update the light-time corrected relative velocity.
+            let geometric_rel_vel = tgt_ssb_vel_km_s - obs_ssb_vel_km_s;
+            if r_norm > 0.0 {
+                let inv_c_r = 1.0 / (SPEED_OF_LIGHT_KM_S * r_norm);
+                let r_dot_v_rel = rel_pos_km.dot(&geometric_rel_vel);
+                let r_dot_v_tgt = rel_pos_km.dot(&tgt_ssb_vel_km_s);
+                // The rate of change of light time.
+                let dlt = (inv_c_r * r_dot_v_rel) / (1.0 - lt_sign * r_dot_v_tgt * inv_c_r);
+                rel_vel_km_s = tgt_ssb_vel_km_s * (1.0 + lt_sign * dlt) - obs_ssb_vel_km_s;
+            } else {
+                rel_vel_km_s = geometric_rel_vel;
             }
+            // Update the one-way light time for the next iteration.
+            one_way_lt_s = r_norm / SPEED_OF_LIGHT_KM_S;
         }
+
+        // If stellar aberration correction is requested, apply it now.
+        if ab_corr.stellar {
+            // The rate of change of the correction depends on the acceleration of the observer, computed
+            // by central differences over one second on each side, like SPICE's `spkacs`.
+            let obs_ssb_acc_km_s2 = (self
+                .translate(observer_frame, SSB_J2000, epoch + TimeUnit::Second, None)?
+                .velocity_km_s
+                - self
+                    .translate(observer_frame, SSB_J2000, epoch - TimeUnit::Second, None)?
+                    .velocity_km_s)
+                / 2.0;
+
+            (rel_pos_km, rel_vel_km_s) = stellar_aberration_state(
+                rel_pos_km,
+                rel_vel_km_s,
+                obs_ssb_vel_km_s,
+                obs_ssb_acc_km_s2,
+                ab_corr,
+            )
+            .context(EphemerisPhysicsSnafu {
+                action: "computing stellar aberration",
+            })?;
+        }
+
+        Ok((rel_pos_km, rel_vel_km_s))
+    }
+
+    /// Geometric translation between frames which are not resolved as frame switches.
+    fn translate_unswitched(
+        &self,
+        target_frame: Frame,
+        observer_frame: Frame,
+        epoch: Epoch,
+    ) -> Result<CartesianState, EphemerisError> {
+        if target_frame.ephem_origin_match(observer_frame) {
+            return Ok(CartesianState::zero_at_epoch(epoch, observer_frame));
+        }
+
+        let (node_count, _path, common_node) =
+            self.common_ephemeris_path(observer_frame, target_frame, epoch)?;
+
+        // The `fwrd` variables store the state of the observer frame relative to the common ancestor.
+        let (mut pos_fwrd, mut vel_fwrd, mut frame_fwrd) =
+            if observer_frame.ephem_origin_id_match(common_node) {
+                // Observer is the common ancestor, so state is zero.
+                (Vector3::zeros(), Vector3::zeros(), observer_frame)
+            } else {
+                self.translation_parts_to_parent(observer_frame, epoch)?
+            };
+
+        // The `bwrd` variables store the state of the target frame relative to the common ancestor.
+        let (mut pos_bwrd, mut vel_bwrd, mut frame_bwrd) =
+            if target_frame.ephem_origin_id_match(common_node) {
+                // Target is the common ancestor, so state is zero.
+                (Vector3::zeros(), Vector3::zeros(), target_frame)
+            } else {
+                self.translation_parts_to_parent(target_frame, epoch)?
+            };
+
+        // Traverse the ephemeris tree from both the observer and target up to the common ancestor.
+        for _ in 0..node_count {
+            if !frame_fwrd.ephem_origin_id_match(common_node) {
+                // Accumulate the state from the current forward frame to its parent.
+                let (cur_pos_fwrd, cur_vel_fwrd, cur_frame_fwrd) =
+                    self.translation_parts_to_parent(frame_fwrd, epoch)?;
+
+                pos_fwrd += cur_pos_fwrd;
+                vel_fwrd += cur_vel_fwrd;
+                frame_fwrd = cur_frame_fwrd;
+            }
+
+            if !frame_bwrd.ephem_origin_id_match(common_node) {
+                // Accumulate the state from the current backward frame to its parent.
+                let (cur_pos_bwrd, cur_vel_bwrd, cur_frame_bwrd) =
+                    self.translation_parts_to_parent(frame_bwrd, epoch)?;
+
+                pos_bwrd += cur_pos_bwrd;
+                vel_bwrd += cur_vel_bwrd;
+                frame_bwrd = cur_frame_bwrd;
+            }
+        }
+
+        // The final state is the difference between the state of the target and the observer, both relative to the common ancestor.
+        Ok(CartesianState {
+            radius_km: pos_bwrd - pos_fwrd,
+            velocity_km_s: vel_bwrd - vel_fwrd,
+            epoch,
+            frame: observer_frame.with_orient(target_frame.orientation_id),
+        })
     }
 
     /// Returns the geometric position vector, velocity vector, and acceleration vector needed to translate the `from_frame` to the `to_frame`, where the distance is in km, the velocity in km/s, and the acceleration in km/s^2.
@@ -210,6 +275,11 @@ impl Almanac {
 
     /// Translates the provided Cartesian state into the requested observer frame
     ///
+    /// # Aberration corrections
+    /// The light time is iterated on the provided state itself, as in `translate`: the center of its frame is evaluated at the light
+    /// time corrected epoch, and the offset of the state from that center, only known at its epoch, is propagated linearly (with its
+    /// velocity) to that epoch. The stellar aberration, if requested, is then applied to the full relative position.
+    ///
     /// **WARNING:** This function only performs the translation and no rotation _whatsoever_. Use the `transform_to` function instead to include rotations.
     pub fn translate_to(
         &self,
@@ -217,8 +287,32 @@ impl Almanac {
         mut observer_frame: Frame,
         ab_corr: Option<Aberration>,
     ) -> Result<CartesianState, EphemerisError> {
-        let frame_state = self.translate(state.frame, observer_frame, state.epoch, ab_corr)?;
-        let mut new_state = state.add_unchecked(&frame_state);
+        let mut new_state = match ab_corr {
+            None => {
+                let frame_state = self.translate(state.frame, observer_frame, state.epoch, None)?;
+                state.add_unchecked(&frame_state)
+            }
+            Some(ab_corr) => {
+                let (radius_km, velocity_km_s) = self.aberration_corrected_parts(
+                    |epoch| {
+                        let center_ssb = self.translate(state.frame, SSB_J2000, epoch, None)?;
+                        let dt_s = (epoch - state.epoch).to_seconds();
+                        Ok((
+                            center_ssb.radius_km + state.radius_km + dt_s * state.velocity_km_s,
+                            center_ssb.velocity_km_s + state.velocity_km_s,
+                        ))
+                    },
+                    observer_frame,
+                    state.epoch,
+                    ab_corr,
+                )?;
+                CartesianState {
+                    radius_km,
+                    velocity_km_s,
+                    ..state
+                }
+            }
+        };
 
         // If there is no frame info, the user hasn't loaded this frame, but might still want to compute a translation.
         if let Ok(obs_frame_info) = self.frame_info(observer_frame) {
@@ -257,3 +351,141 @@ impl Almanac {
         self.translate_to(state, observer_frame, ab_corr)
     }
 }
+
+#[cfg(test)]
+mod ut_translations {
+    use crate::astro::Aberration;
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000};
+    use crate::constants::SPEED_OF_LIGHT_KM_S;
+    use crate::math::cartesian::CartesianState;
+    use crate::math::Vector3;
+    use crate::naif::daf::test_utils::{fixed_segment, linear_segment, test_spk};
+    use crate::naif::SPK;
+    use crate::prelude::{Almanac, Frame};
+    use hifitime::Epoch;
+
+    /// Builds an SPK where the Earth is fixed at the SSB, and the Moon moves along X at 1 km/s, at 384400 km at noon.
+    fn earth_moon_spk() -> SPK {
+        test_spk(&[
+            fixed_segment(399, 0, [0.0; 3]),
+            linear_segment(301, 399, [384_400.0, 0.0, 0.0], [1.0, 0.0, 0.0]),
+        ])
+    }
+
+    #[test]
+    fn translate_to_light_time() {
+        let almanac = Almanac::default().with_spk(earth_moon_spk());
+        let epoch = Epoch::from_et_seconds(43200.0);
+
+        // The Moon itself is corrected like in translate
+        let moon_lt = almanac
+            .translate(MOON_J2000, EARTH_J2000, epoch, Aberration::LT)
+            .unwrap();
+        let moon_lt_s = 384400.0 / SPEED_OF_LIGHT_KM_S;
+        assert!((moon_lt.radius_km.x - (384400.0 - moon_lt_s)).abs() < 1e-6);
+
+        let at_moon = CartesianState {
+            radius_km: Vector3::zeros(),
+            velocity_km_s: Vector3::zeros(),
+            epoch,
+            frame: MOON_J2000,
+        };
+        let state = almanac
+            .translate_to(at_moon, EARTH_J2000, Aberration::LT)
+            .unwrap();
+        assert!((state.radius_km - moon_lt.radius_km).norm() < 1e-9);
+
+        // An object 1000 km ahead of the Moon, also moving at 1 km/s relative to it
+        let object = CartesianState {
+            radius_km: Vector3::new(1000.0, 0.0, 0.0),
+            velocity_km_s: Vector3::new(1.0, 0.0, 0.0),
+            ..at_moon
+        };
+        let state = almanac
+            .translate_to(object, EARTH_J2000, Aberration::LT)
+            .unwrap();
+        let object_lt_s = 385400.0 / SPEED_OF_LIGHT_KM_S;
+        // Both the Moon and the relative motion are corrected for light time
+        assert!((state.radius_km.x - (385400.0 - 2.0 * object_lt_s)).abs() < 1e-2);
+
+        let geometric = almanac.translate_to(object, EARTH_J2000, None).unwrap();
+        assert_eq!(geometric.radius_km.x, 385400.0);
+
+        // A probe fixed 100000 km from the Earth along Y, as seen from the Moon whose motion causes stellar aberration
+        let probe = fixed_segment(-1000, 399, [0.0, 100_000.0, 0.0]);
+        let almanac = almanac.with_spk(test_spk(&[probe]));
+        let probe_from_earth = CartesianState {
+            radius_km: Vector3::new(0.0, 100_000.0, 0.0),
+            velocity_km_s: Vector3::zeros(),
+            epoch,
+            frame: EARTH_J2000,
+        };
+
+        // The probe as an offset from the Earth is corrected exactly like the probe itself.
+        for ab_corr in [Aberration::LT_S, Aberration::CN_S, Aberration::XLT_S] {
+            let expected = almanac
+                .translate(Frame::from_ephem_j2000(-1000), MOON_J2000, epoch, ab_corr)
+                .unwrap();
+            let state = almanac
+                .translate_to(probe_from_earth, MOON_J2000, ab_corr)
+                .unwrap();
+            assert!(
+                (state.radius_km - expected.radius_km).norm() < 1e-9,
+                "{ab_corr:?}: {state} != {expected}"
+            );
+            assert!((state.velocity_km_s - expected.velocity_km_s).norm() < 1e-12);
+        }
+        let stellar = almanac
+            .translate_to(probe_from_earth, MOON_J2000, Aberration::LT_S)
+            .unwrap();
+        let lt_only = almanac
+            .translate_to(probe_from_earth, MOON_J2000, Aberration::LT)
+            .unwrap();
+        assert!((stellar.radius_km - lt_only.radius_km).norm() > 1e-3);
+    }
+
+    #[test]
+    fn translate_transmission() {
+        let almanac = Almanac::default().with_spk(earth_moon_spk());
+        let epoch = Epoch::from_et_seconds(43200.0);
+        let c = SPEED_OF_LIGHT_KM_S;
+
+        // The Moon recedes at 1 km/s, so a signal sent now reaches it further away than where it is now,
+        // whereas the signal received now left it when it was closer.
+        let xlt = almanac
+            .translate(MOON_J2000, EARTH_J2000, epoch, Aberration::XLT)
+            .unwrap();
+        let lt = almanac
+            .translate(MOON_J2000, EARTH_J2000, epoch, Aberration::LT)
+            .unwrap();
+        assert!((xlt.radius_km.x - (384400.0 + 384400.0 / c)).abs() < 1e-9);
+        assert!((lt.radius_km.x - (384400.0 - 384400.0 / c)).abs() < 1e-9);
+
+        // The range rate is scaled by the rate of change of the light time
+        assert!((xlt.velocity_km_s.x - (1.0 + (1.0 / c) / (1.0 - 1.0 / c))).abs() < 1e-12);
+        assert!((lt.velocity_km_s.x - (1.0 - (1.0 / c) / (1.0 + 1.0 / c))).abs() < 1e-12);
+
+        // Converged: the light time solves lt = (384400 + lt) / c
+        let xcn = almanac
+            .translate(MOON_J2000, EARTH_J2000, epoch, Aberration::XCN)
+            .unwrap();
+        assert!((xcn.radius_km.x - (384400.0 + 384400.0 / (c - 1.0))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn translate_to_itself() {
+        let almanac = Almanac::default().with_spk(earth_moon_spk());
+        let epoch = Epoch::from_et_seconds(43200.0);
+
+        // The zero state is at the requested epoch, with or without aberration corrections.
+        for ab_corr in [None, Aberration::LT] {
+            let state = almanac
+                .translate(MOON_J2000, MOON_J2000, epoch, ab_corr)
+                .unwrap();
+            assert_eq!(state.epoch, epoch);
+            assert_eq!(state.frame, MOON_J2000);
+            assert_eq!(state.radius_km, Vector3::zeros());
+            assert_eq!(state.velocity_km_s, Vector3::zeros());
+        }
+    }
+}
@@ -8,7 +8,7 @@
  * Documentation: https://nyxspace.com/
  */
 
-use hifitime::Epoch;
+use hifitime::{Duration, Epoch};
 use snafu::prelude::*;
 
 use crate::ephemerides::EphemerisError;
@@ -59,6 +59,31 @@ pub enum AlmanacError {
     },
     #[snafu(display("{err}"))]
     GenericError { err: String },
+    #[snafu(display(
+        "strict mode: {kind} `{alias}` overlaps with `{other}` for ID {id} from {start} to {end}"
+    ))]
+    KernelOverlap {
+        kind: &'static str,
+        alias: String,
+        other: String,
+        id: NaifId,
+        start: Epoch,
+        end: Epoch,
+    },
+    #[snafu(display(
+        "memory budget: loading {kind} `{alias}` requires {needed_bytes} bytes but {used_bytes} of {budget_bytes} bytes are used"
+    ))]
+    MemoryBudget {
+        kind: &'static str,
+        alias: String,
+        needed_bytes: usize,
+        used_bytes: usize,
+        budget_bytes: usize,
+    },
+    #[snafu(display("could not parse the Earth orientation parameters on line {lno}: {details}"))]
+    EopParsing { lno: usize, details: String },
+    #[snafu(display("no loaded Earth orientation parameters cover {epoch}"))]
+    MissingEop { epoch: Epoch },
     #[cfg(feature = "metaload")]
     #[snafu(display("processing file #{fno} ({file:?}) caused an error: {source}"))]
     Meta {
@@ -213,6 +238,15 @@ pub enum PhysicsError {
     NotHyperbolic { ecc: f64 },
     #[snafu(display("calculation requires non-hyperbolic orbit, but its eccentricity is {ecc}"))]
     Hyperbolic { ecc: f64 },
+    #[snafu(display("flyby periapsis radius of {periapsis_km} km is below the body radius of {body_radius_km} km"))]
+    FlybyPeriapsis {
+        periapsis_km: f64,
+        body_radius_km: f64,
+    },
+    #[snafu(display(
+        "Helmert transformations require an Earth centered, Earth-fixed frame, but got {frame}"
+    ))]
+    HelmertFrame { frame: FrameUid },
     #[snafu(display("mean element computation failed: {detail}"))]
     MeanElement { detail: &'static str },
     #[snafu(display("infinite value encountered when {action}"))]
@@ -229,6 +263,39 @@ pub enum PhysicsError {
     NoCovariance { action: &'static str },
     #[snafu(display("partials are not yet defined for this orbital element"))]
     PartialsNotYetDefined,
+    #[snafu(display(
+        "slew rate limit of {max_rate_deg_s} deg/s and acceleration limit of {max_accel_deg_s2} deg/s^2 must be positive"
+    ))]
+    SlewLimits {
+        max_rate_deg_s: f64,
+        max_accel_deg_s2: f64,
+    },
+    #[snafu(display(
+        "slewing {angle_deg} deg requires at least {min_duration} but only {duration} is available"
+    ))]
+    SlewDuration {
+        angle_deg: f64,
+        min_duration: Duration,
+        duration: Duration,
+    },
+    #[snafu(display("invalid articulation: {detail}"))]
+    InvalidArticulation { detail: &'static str },
+    #[snafu(display(
+        "no articulation angle at {epoch}: the angle profile spans {start} to {end}"
+    ))]
+    ArticulationEpoch {
+        epoch: Epoch,
+        start: Epoch,
+        end: Epoch,
+    },
+    #[snafu(display("invalid traverse: {detail}"))]
+    InvalidTraverse { detail: &'static str },
+    #[snafu(display("no traverse position at {epoch}: the path spans {start} to {end}"))]
+    TraverseEpoch {
+        epoch: Epoch,
+        start: Epoch,
+        end: Epoch,
+    },
 }
 
 impl From<IOErrorKind> for InputOutputError {
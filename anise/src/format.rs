@@ -0,0 +1,225 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Formatting of the numbers and epochs written in generated products, like the CSV reports and the CCSDS OEM files.
+//!
+//! The output never depends on the locale of the machine and always uses the same number of digits, so that the products
+//! generated by two runs can be compared with a plain text diff.
+
+use core::str::FromStr;
+use hifitime::efmt::{Format, Formatter};
+use hifitime::{Epoch, TimeScale};
+
+/// Style of the exponent of numbers formatted in scientific notation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ExponentStyle {
+    /// `1.500e+03`
+    Lower,
+    /// `1.500E+03`, as used in CCSDS products
+    #[default]
+    Upper,
+    /// `1.500D+03`, i.e. Fortran double precision, as used in SPICE text kernels
+    Fortran,
+}
+
+impl ExponentStyle {
+    fn symbol(&self) -> char {
+        match self {
+            Self::Lower => 'e',
+            Self::Upper => 'E',
+            Self::Fortran => 'D',
+        }
+    }
+}
+
+/// Formatting of floating point values.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FloatFormat {
+    /// Shortest representation that parses back to the same value, e.g. `7000.123`: this is the default of Rust.
+    #[default]
+    Shortest,
+    /// Fixed number of digits after the decimal point, e.g. `7000.123000` for six decimals.
+    Fixed { decimals: usize },
+    /// Scientific notation with a fixed number of significant digits and an exponent with a sign and at least two digits,
+    /// e.g. `7.00012300E+03` for nine significant digits.
+    Scientific {
+        significant_digits: usize,
+        exponent: ExponentStyle,
+    },
+}
+
+/// Formatting of epochs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EpochFormat {
+    /// Default representation of hifitime, e.g. `2025-01-01T12:00:00 UTC`, which omits the zero sub-seconds.
+    #[default]
+    Default,
+    /// ISO 8601 calendar date with nanoseconds and without the time scale, e.g. `2025-01-01T12:00:00.000000000`.
+    Iso8601,
+    /// Year and day of year with nanoseconds, e.g. `2025-001T12:00:00.000000000`, which is also a valid CCSDS date.
+    DayOfYear,
+    /// Julian date in days with a fixed number of decimals, e.g. `2460677.000000000` for nine decimals (below 0.1 ms).
+    JulianDate { decimals: usize },
+}
+
+/// Formatting of a generated product, selecting the format of its values and epochs, and the time scale of its epochs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProductFormat {
+    pub float: FloatFormat,
+    pub epoch: EpochFormat,
+    /// Time scale of the epochs, or the time scale of each epoch if None.
+    pub time_scale: Option<TimeScale>,
+}
+
+/// Returns positive zero for negative zero, and the value otherwise.
+fn unsigned_zero(value: f64) -> f64 {
+    if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+impl ProductFormat {
+    /// Returns the provided value formatted as configured.
+    ///
+    /// The shortest format is the default representation of Rust, as used by the exporters before the product formats, so it keeps
+    /// the sign of negative zero. With the fixed and scientific formats, negative zero and negative values rounded to zero are
+    /// written as zero. Non finite values are written as `NaN`, `inf`, and `-inf`.
+    pub fn format_f64(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let repr = match self.float {
+            FloatFormat::Shortest => return value.to_string(),
+            FloatFormat::Fixed { decimals } => format!("{:.decimals$}", unsigned_zero(value)),
+            FloatFormat::Scientific {
+                significant_digits,
+                exponent,
+            } => {
+                let precision = significant_digits.max(1) - 1;
+                let repr = format!("{:.precision$e}", unsigned_zero(value));
+                // Rust writes the exponent as `e3` or `e-3`, which is normalized to a signed exponent of at least two digits.
+                let (mantissa, exp) = repr.split_once('e').unwrap();
+                let exp = exp.parse::<i32>().unwrap();
+                let sign = if exp < 0 { '-' } else { '+' };
+                format!("{mantissa}{}{sign}{:02}", exponent.symbol(), exp.abs())
+            }
+        };
+
+        // Small negative values rounded to zero are written as zero.
+        match repr.strip_prefix('-') {
+            Some(unsigned) if unsigned.parse::<f64>() == Ok(0.0) => unsigned.to_string(),
+            _ => repr,
+        }
+    }
+
+    /// Returns the provided epoch formatted as configured, in the configured time scale.
+    pub fn format_epoch(&self, epoch: Epoch) -> String {
+        let epoch = match self.time_scale {
+            Some(ts) => epoch.to_time_scale(ts),
+            None => epoch,
+        };
+
+        match self.epoch {
+            EpochFormat::Default => epoch.to_string(),
+            EpochFormat::Iso8601 => {
+                Formatter::new(epoch, Format::from_str("%Y-%m-%dT%H:%M:%S.%f").unwrap()).to_string()
+            }
+            EpochFormat::DayOfYear => {
+                Formatter::new(epoch, Format::from_str("%Y-%jT%H:%M:%S.%f").unwrap()).to_string()
+            }
+            EpochFormat::JulianDate { decimals } => {
+                let jde_days = match epoch.time_scale {
+                    TimeScale::UTC => epoch.to_jde_utc_days(),
+                    TimeScale::TT => epoch.to_jde_tt_days(),
+                    TimeScale::ET => epoch.to_jde_et_days(),
+                    TimeScale::TDB => epoch.to_jde_tdb_days(),
+                    _ => epoch.to_jde_tai_days(),
+                };
+                format!("{jde_days:.decimals$}")
+            }
+        }
+    }
+
+    /// Returns the header of the epoch column of a table whose first epoch is provided, e.g. `Epoch (UTC)` or `Epoch (JD TDB)`.
+    pub fn epoch_header(&self, first_epoch: Epoch) -> String {
+        let time_scale = self.time_scale.unwrap_or(first_epoch.time_scale);
+        match self.epoch {
+            EpochFormat::JulianDate { .. } => {
+                let time_scale = match time_scale {
+                    TimeScale::UTC | TimeScale::TT | TimeScale::ET | TimeScale::TDB => time_scale,
+                    _ => TimeScale::TAI,
+                };
+                format!("Epoch (JD {time_scale})")
+            }
+            _ => format!("Epoch ({time_scale})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_format {
+    use super::{EpochFormat, ExponentStyle, FloatFormat, ProductFormat};
+    use hifitime::{Epoch, TimeScale};
+
+    #[test]
+    fn floats() {
+        let mut format = ProductFormat::default();
+        assert_eq!(format.format_f64(7000.125), "7000.125");
+        // The default format keeps the output of the exporters, including the sign of negative zero.
+        assert_eq!(format.format_f64(-0.0), "-0");
+        assert_eq!(format.format_f64(f64::NAN), "NaN");
+
+        format.float = FloatFormat::Fixed { decimals: 4 };
+        assert_eq!(format.format_f64(7000.125), "7000.1250");
+        assert_eq!(format.format_f64(-1.0e-9), "0.0000");
+
+        format.float = FloatFormat::Scientific {
+            significant_digits: 6,
+            exponent: ExponentStyle::Upper,
+        };
+        assert_eq!(format.format_f64(7000.1251), "7.00013E+03");
+        assert_eq!(format.format_f64(-1.5e-12), "-1.50000E-12");
+        assert_eq!(format.format_f64(0.0), "0.00000E+00");
+        assert_eq!(format.format_f64(1.0e123), "1.00000E+123");
+
+        format.float = FloatFormat::Scientific {
+            significant_digits: 1,
+            exponent: ExponentStyle::Fortran,
+        };
+        assert_eq!(format.format_f64(250.0), "2D+02");
+    }
+
+    #[test]
+    fn epochs() {
+        let epoch = Epoch::from_gregorian_utc(2025, 2, 3, 12, 0, 0, 5);
+
+        let mut format = ProductFormat::default();
+        assert_eq!(format.format_epoch(epoch), epoch.to_string());
+        assert_eq!(format.epoch_header(epoch), "Epoch (UTC)");
+
+        format.epoch = EpochFormat::Iso8601;
+        assert_eq!(format.format_epoch(epoch), "2025-02-03T12:00:00.000000005");
+
+        format.epoch = EpochFormat::DayOfYear;
+        assert_eq!(format.format_epoch(epoch), "2025-034T12:00:00.000000005");
+
+        format.epoch = EpochFormat::JulianDate { decimals: 6 };
+        assert_eq!(format.format_epoch(epoch), "2460710.000000");
+        assert_eq!(format.epoch_header(epoch), "Epoch (JD UTC)");
+
+        format.time_scale = Some(TimeScale::TAI);
+        format.epoch = EpochFormat::Iso8601;
+        assert_eq!(format.format_epoch(epoch), "2025-02-03T12:00:37.000000005");
+        assert_eq!(format.epoch_header(epoch), "Epoch (TAI)");
+    }
+}
@@ -392,6 +392,25 @@ impl Frame {
             })?
             .polar_radius_km)
     }
+
+    /// Returns the radius of the Laplace sphere of influence of this frame's body in km, given its distance to the central body
+    /// (typically the semi-major axis of its orbit) and the frame of that central body.
+    ///
+    /// :type distance_km: float
+    /// :type central_frame: Frame
+    /// :rtype: float
+    pub fn soi_radius_km(&self, distance_km: f64, central_frame: Frame) -> PhysicsResult<f64> {
+        Ok(distance_km * (self.mu_km3_s2()? / central_frame.mu_km3_s2()?).powf(0.4))
+    }
+
+    /// Returns the radius of the Hill sphere of this frame's body in km, given its distance to the central body and the frame of that central body.
+    ///
+    /// :type distance_km: float
+    /// :type central_frame: Frame
+    /// :rtype: float
+    pub fn hill_radius_km(&self, distance_km: f64, central_frame: Frame) -> PhysicsResult<f64> {
+        Ok(distance_km * (self.mu_km3_s2()? / (3.0 * central_frame.mu_km3_s2()?)).cbrt())
+    }
 }
 
 impl Encode for Frame {
@@ -524,6 +543,23 @@ mod frame_ut {
         );
     }
 
+    #[test]
+    fn soi_hill_radii() {
+        use crate::constants::frames::SUN_J2000;
+        let earth = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let sun = SUN_J2000.with_mu_km3_s2(132_712_440_041.279_42);
+        let au_km = crate::constants::ASTRONOMICAL_UNIT_KM;
+
+        // Earth SoI is about 924,000 km and its Hill sphere is about 1.5 million km
+        let soi_km = earth.soi_radius_km(au_km, sun).unwrap();
+        assert!((soi_km - 924_645.0).abs() < 1_000.0, "{soi_km}");
+        let hill_km = earth.hill_radius_km(au_km, sun).unwrap();
+        assert!((hill_km - 1_496_558.0).abs() < 1_000.0, "{hill_km}");
+
+        assert!(EARTH_J2000.soi_radius_km(au_km, sun).is_err());
+        assert!(earth.hill_radius_km(au_km, SUN_J2000).is_err());
+    }
+
     #[test]
     fn ccsds_name_to_frame() {
         assert_eq!(Frame::from_name("Earth", "ICRF").unwrap(), EARTH_J2000);
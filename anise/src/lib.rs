@@ -21,6 +21,7 @@ pub mod astro;
 pub mod constants;
 pub mod ephemerides;
 pub mod errors;
+pub mod format;
 pub mod frames;
 pub mod math;
 pub mod naif;
@@ -10,6 +10,8 @@
 
 use std::f64::consts::TAU;
 
+use super::units::AngleUnit;
+
 /// Returns the provided angle bounded between 0.0 and 360.0.
 ///
 /// This function takes an angle (in degrees) and normalizes it to the range [0, 360).
@@ -55,3 +57,83 @@ pub fn between_pm_x(angle: f64, x: f64) -> f64 {
     }
     bounded
 }
+
+/// Range of the longitudes output by ANISE.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum LongitudeRange {
+    /// Longitudes between -180 and +180 degrees, as computed by [crate::prelude::Orbit::longitude_deg]
+    #[default]
+    PlusMinus180,
+    /// Longitudes between 0 and 360 degrees, as computed by [crate::prelude::Orbit::longitude_360_deg]
+    ZeroTo360,
+}
+
+/// Direction in which the longitudes output by ANISE increase.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum LongitudeDirection {
+    #[default]
+    EastPositive,
+    WestPositive,
+}
+
+/// Output policy of the angles, i.e. their unit and the convention of the longitudes, applied to the reports and the CLI.
+///
+/// ANISE always computes angles in degrees and longitudes positive East: this policy only converts the output values. The
+/// default policy is the one of the computations except for the range of the longitudes, which is the range of [crate::prelude::Orbit::longitude_deg].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct AngleConvention {
+    pub unit: AngleUnit,
+    pub longitude_range: LongitudeRange,
+    pub longitude_direction: LongitudeDirection,
+}
+
+impl AngleConvention {
+    /// Returns the provided angle, in degrees, in the unit of this convention.
+    pub fn angle(&self, angle_deg: f64) -> f64 {
+        angle_deg * self.unit.from_degrees()
+    }
+
+    /// Returns the provided longitude, in degrees positive East, in the range, direction, and unit of this convention.
+    pub fn longitude(&self, longitude_deg: f64) -> f64 {
+        let longitude_deg = match self.longitude_direction {
+            LongitudeDirection::EastPositive => longitude_deg,
+            LongitudeDirection::WestPositive => -longitude_deg,
+        };
+        let longitude_deg = match self.longitude_range {
+            LongitudeRange::PlusMinus180 => between_pm_180(longitude_deg),
+            LongitudeRange::ZeroTo360 => between_0_360(longitude_deg),
+        };
+        self.angle(longitude_deg)
+    }
+}
+
+#[cfg(test)]
+mod ut_angles {
+    use super::{AngleConvention, AngleUnit, LongitudeDirection, LongitudeRange};
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn angle_convention() {
+        let default = AngleConvention::default();
+        assert_eq!(default.angle(123.0), 123.0);
+        assert_eq!(default.longitude(-75.0), -75.0);
+        assert_eq!(default.longitude(285.0), -75.0);
+
+        let radians = AngleConvention {
+            unit: AngleUnit::Radian,
+            ..Default::default()
+        };
+        assert!((radians.angle(90.0) - FRAC_PI_2).abs() < f64::EPSILON);
+        assert!((radians.longitude(270.0) + FRAC_PI_2).abs() < f64::EPSILON);
+
+        let west_360 = AngleConvention {
+            longitude_range: LongitudeRange::ZeroTo360,
+            longitude_direction: LongitudeDirection::WestPositive,
+            ..Default::default()
+        };
+        assert_eq!(west_360.longitude(-75.0), 75.0);
+        assert_eq!(west_360.longitude(75.0), 285.0);
+        // Only longitudes depend on the direction
+        assert_eq!(west_360.angle(-75.0), -75.0);
+    }
+}
@@ -0,0 +1,329 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Compact fixed-point encoding of states and quaternions, e.g. to stream geometry products over a link of limited bandwidth.
+//!
+//! All of the encoded values are big endian. The resolution is not stored in the encoded bytes, so the decoder must be configured
+//! like the encoder.
+
+use core::f64::consts::FRAC_1_SQRT_2;
+
+use hifitime::{Duration, Epoch};
+
+use super::cartesian::CartesianState;
+use super::rotation::Quaternion;
+use super::Vector3;
+use crate::errors::MathError;
+use crate::prelude::Frame;
+use crate::NaifId;
+
+/// Fixed-point encoding of Cartesian states, where the epoch, each position component, and each velocity component are encoded
+/// as a signed 32-bit integer count of their resolution.
+///
+/// For example, with a resolution of 1 m and 1 mm/s, the position components must be within about 2.1 million km and the velocity
+/// components within about 2147 km/s. The epoch is encoded as an offset to the reference epoch: with a resolution of 1 ms, it
+/// must be within about 24 days of the reference epoch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CompactStateCodec {
+    pub reference_epoch: Epoch,
+    pub epoch_resolution: Duration,
+    pub position_resolution_km: f64,
+    pub velocity_resolution_km_s: f64,
+}
+
+impl CompactStateCodec {
+    /// Number of bytes of an encoded state.
+    pub const ENCODED_LEN: usize = 28;
+
+    /// Encodes the provided state, whose frame is not encoded.
+    ///
+    /// # Errors
+    /// + A resolution is not strictly positive;
+    /// + The epoch offset or a component cannot be represented with the configured resolution.
+    pub fn encode(&self, state: &CartesianState) -> Result<[u8; Self::ENCODED_LEN], MathError> {
+        let epoch_resolution_s = self.epoch_resolution.to_seconds();
+        let values = [
+            quantize(
+                (state.epoch - self.reference_epoch).to_seconds(),
+                epoch_resolution_s,
+            )?,
+            quantize(state.radius_km.x, self.position_resolution_km)?,
+            quantize(state.radius_km.y, self.position_resolution_km)?,
+            quantize(state.radius_km.z, self.position_resolution_km)?,
+            quantize(state.velocity_km_s.x, self.velocity_resolution_km_s)?,
+            quantize(state.velocity_km_s.y, self.velocity_resolution_km_s)?,
+            quantize(state.velocity_km_s.z, self.velocity_resolution_km_s)?,
+        ];
+
+        let mut bytes = [0; Self::ENCODED_LEN];
+        for (chunk, value) in bytes.chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&value.to_be_bytes());
+        }
+        Ok(bytes)
+    }
+
+    /// Decodes the provided bytes into a state in the provided frame.
+    pub fn decode(&self, bytes: &[u8], frame: Frame) -> Result<CartesianState, MathError> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(MathError::DomainError {
+                value: bytes.len() as f64,
+                msg: "invalid number of bytes for a compact state",
+            });
+        }
+
+        let mut values = bytes
+            .chunks_exact(4)
+            .map(|chunk| i32::from_be_bytes(chunk.try_into().unwrap()) as f64);
+        let mut next = |resolution: f64| values.next().unwrap() * resolution;
+
+        let epoch = self.reference_epoch + self.epoch_resolution * next(1.0);
+        let radius_km = Vector3::new(
+            next(self.position_resolution_km),
+            next(self.position_resolution_km),
+            next(self.position_resolution_km),
+        );
+        let velocity_km_s = Vector3::new(
+            next(self.velocity_resolution_km_s),
+            next(self.velocity_resolution_km_s),
+            next(self.velocity_resolution_km_s),
+        );
+
+        Ok(CartesianState {
+            radius_km,
+            velocity_km_s,
+            epoch,
+            frame,
+        })
+    }
+}
+
+/// Encoding of unit quaternions with the "smallest three" method: the component of largest magnitude is dropped, and the three
+/// other components, which are within ±1/√2, are encoded in the configured number of bits each.
+///
+/// The encoded quaternion is packed in `ceil((2 + 3 * bits) / 8)` bytes, where the first two bits are the index of the dropped
+/// component, e.g. six bytes for 15 bits per component. The frames of the quaternion are not encoded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CompactQuaternionCodec {
+    /// Number of bits per encoded component, between 2 and 20.
+    pub bits: u8,
+}
+
+impl Default for CompactQuaternionCodec {
+    /// Encodes quaternions in six bytes, with a resolution better than 0.01 degrees.
+    fn default() -> Self {
+        Self { bits: 15 }
+    }
+}
+
+impl CompactQuaternionCodec {
+    const MIN_BITS: u8 = 2;
+    const MAX_BITS: u8 = 20;
+
+    /// Number of bytes of an encoded quaternion.
+    pub fn encoded_len(&self) -> usize {
+        (2 + 3 * self.bits as usize).div_ceil(8)
+    }
+
+    /// Returns the worst case rotation angle between a quaternion and its decoded value, in degrees.
+    pub fn resolution_deg(&self) -> f64 {
+        // Each encoded component is rounded to half a step, and the error of the dropped component, which is at least 1/2, is at
+        // most √3 times the error of the encoded components. The rotation angle is twice the quaternion angle.
+        let half_step = FRAC_1_SQRT_2 / self.max_count() as f64;
+        (2.0 * (2.0 * 3.0_f64.sqrt() * half_step).min(1.0).asin()).to_degrees()
+    }
+
+    fn max_count(&self) -> u64 {
+        (1 << self.bits) - 1
+    }
+
+    fn check_bits(&self) -> Result<(), MathError> {
+        if (Self::MIN_BITS..=Self::MAX_BITS).contains(&self.bits) {
+            Ok(())
+        } else {
+            Err(MathError::DomainError {
+                value: self.bits as f64,
+                msg: "compact quaternion bits must be between 2 and 20",
+            })
+        }
+    }
+
+    /// Encodes the provided quaternion, which is normalized first.
+    pub fn encode(&self, q: &Quaternion) -> Result<Vec<u8>, MathError> {
+        self.check_bits()?;
+        let q = q.normalize();
+        let mut components = [q.w, q.x, q.y, q.z];
+        let largest = (0..4)
+            .max_by(|&i, &j| components[i].abs().total_cmp(&components[j].abs()))
+            .unwrap();
+        // q and -q are the same rotation, so the dropped component is made positive and need not be encoded.
+        if components[largest] < 0.0 {
+            components.iter_mut().for_each(|c| *c = -*c);
+        }
+
+        let max_count = self.max_count();
+        let mut packed = largest as u64;
+        for (i, component) in components.iter().enumerate() {
+            if i != largest {
+                let scaled = (component.clamp(-FRAC_1_SQRT_2, FRAC_1_SQRT_2) + FRAC_1_SQRT_2)
+                    / (2.0 * FRAC_1_SQRT_2);
+                packed = (packed << self.bits) | (scaled * max_count as f64).round() as u64;
+            }
+        }
+
+        let len = self.encoded_len();
+        packed <<= 8 * len - (2 + 3 * self.bits as usize);
+        Ok(packed.to_be_bytes()[8 - len..].to_vec())
+    }
+
+    /// Decodes the provided bytes into a quaternion of the provided frames.
+    pub fn decode(&self, bytes: &[u8], from: NaifId, to: NaifId) -> Result<Quaternion, MathError> {
+        self.check_bits()?;
+        let len = self.encoded_len();
+        if bytes.len() != len {
+            return Err(MathError::DomainError {
+                value: bytes.len() as f64,
+                msg: "invalid number of bytes for a compact quaternion",
+            });
+        }
+
+        let mut buf = [0; 8];
+        buf[8 - len..].copy_from_slice(bytes);
+        let packed = u64::from_be_bytes(buf) >> (8 * len - (2 + 3 * self.bits as usize));
+
+        let max_count = self.max_count();
+        let largest = (packed >> (3 * self.bits)) as usize;
+        let mut components = [0.0; 4];
+        let mut sum_sq = 0.0;
+        let mut shift = 3 * self.bits;
+        for (i, component) in components.iter_mut().enumerate() {
+            if i != largest {
+                shift -= self.bits;
+                let count = (packed >> shift) & max_count;
+                *component =
+                    (count as f64 / max_count as f64) * 2.0 * FRAC_1_SQRT_2 - FRAC_1_SQRT_2;
+                sum_sq += component.powi(2);
+            }
+        }
+        components[largest] = (1.0 - sum_sq).max(0.0).sqrt();
+
+        let [w, x, y, z] = components;
+        Ok(Quaternion::new(w, x, y, z, from, to))
+    }
+}
+
+/// Returns the provided value as a count of the provided resolution.
+fn quantize(value: f64, resolution: f64) -> Result<i32, MathError> {
+    if resolution.is_nan() || resolution <= 0.0 {
+        return Err(MathError::DomainError {
+            value: resolution,
+            msg: "compact encoding resolution must be strictly positive",
+        });
+    }
+    let count = (value / resolution).round();
+    if count.is_finite() && count >= i32::MIN as f64 && count <= i32::MAX as f64 {
+        Ok(count as i32)
+    } else {
+        Err(MathError::DomainError {
+            value,
+            msg: "value cannot be represented with the compact encoding resolution",
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_compact {
+    use super::{CompactQuaternionCodec, CompactStateCodec};
+    use crate::constants::frames::EARTH_J2000;
+    use crate::errors::MathError;
+    use crate::math::cartesian::CartesianState;
+    use crate::math::rotation::Quaternion;
+    use crate::math::Vector3;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn state_round_trip() {
+        let reference_epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let codec = CompactStateCodec {
+            reference_epoch,
+            epoch_resolution: Unit::Millisecond * 1,
+            position_resolution_km: 1e-3,
+            velocity_resolution_km_s: 1e-6,
+        };
+
+        let state = CartesianState::new(
+            -2436.45,
+            -2436.45,
+            6891.037,
+            5.088_611,
+            -5.088_611,
+            0.0,
+            reference_epoch + Unit::Hour * 7.25 + Unit::Microsecond * 300,
+            EARTH_J2000,
+        );
+
+        let bytes = codec.encode(&state).unwrap();
+        assert_eq!(bytes.len(), CompactStateCodec::ENCODED_LEN);
+        let decoded = codec.decode(&bytes, EARTH_J2000).unwrap();
+
+        assert_eq!(decoded.epoch, reference_epoch + Unit::Hour * 7.25);
+        assert!((decoded.radius_km - state.radius_km).amax() <= 0.5e-3);
+        assert!((decoded.velocity_km_s - state.velocity_km_s).amax() <= 0.5e-6);
+
+        // Out of range values and invalid resolutions are rejected
+        let mut far = state;
+        far.radius_km = Vector3::new(1e7, 0.0, 0.0);
+        assert!(matches!(
+            codec.encode(&far),
+            Err(MathError::DomainError { .. })
+        ));
+        let invalid = CompactStateCodec {
+            position_resolution_km: 0.0,
+            ..codec
+        };
+        assert!(invalid.encode(&state).is_err());
+        assert!(codec.decode(&bytes[1..], EARTH_J2000).is_err());
+    }
+
+    #[test]
+    fn quaternion_round_trip() {
+        let angle_deg = |q1: &Quaternion, q2: &Quaternion| {
+            let dot = q1.w * q2.w + q1.x * q2.x + q1.y * q2.y + q1.z * q2.z;
+            (2.0 * dot.abs().min(1.0).acos()).to_degrees()
+        };
+
+        for bits in [4, 10, 15, 20] {
+            let codec = CompactQuaternionCodec { bits };
+            for (w, x, y, z) in [
+                (1.0, 0.0, 0.0, 0.0),
+                (-0.1, 0.7, -0.3, 0.2),
+                (0.5, -0.5, 0.5, -0.5),
+                (0.3, 0.1, -0.2, -0.9),
+            ] {
+                let q = Quaternion::new(w, x, y, z, 1, -85000);
+                let bytes = codec.encode(&q).unwrap();
+                assert_eq!(bytes.len(), codec.encoded_len());
+                let decoded = codec.decode(&bytes, 1, -85000).unwrap();
+                assert_eq!((decoded.from, decoded.to), (1, -85000));
+                assert!(
+                    angle_deg(&q, &decoded) <= codec.resolution_deg(),
+                    "{bits} bits: {q} -> {decoded}"
+                );
+            }
+        }
+
+        let codec = CompactQuaternionCodec::default();
+        assert_eq!(codec.encoded_len(), 6);
+        assert!(codec.resolution_deg() < 1e-2);
+        assert!(CompactQuaternionCodec { bits: 21 }
+            .encode(&Quaternion::identity(1, 2))
+            .is_err());
+        assert!(codec.decode(&[0; 5], 1, 2).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Geometry of a tri-axial ellipsoid centered at the origin and aligned with the axes of its frame, defined by its three radii
+//! in km, i.e. (x/a)^2 + (y/b)^2 + (z/c)^2 = 1. These routines do not depend on the Almanac, e.g. to be used with the radii of
+//! the `shape` of the planetary data of a body, expressed in its body fixed frame.
+
+use super::Vector3;
+
+/// Returns the parameters `t` of the points `origin + t * direction` where the line of the ray crosses the surface of the
+/// ellipsoid, in increasing order, or None if the line does not intersect the ellipsoid.
+///
+/// The parameters may be negative, i.e. behind the origin: this allows checking whether the origin is inside the ellipsoid.
+pub fn ray_intersection_parameters(
+    radii_km: &Vector3,
+    origin: &Vector3,
+    direction: &Vector3,
+) -> Option<(f64, f64)> {
+    // Scale to the unit sphere space: P' = [x/a, y/b, z/c]
+    let origin = origin.component_div(radii_km);
+    let direction = direction.component_div(radii_km);
+
+    // |O' + t*D'|^2 = 1, i.e. (D' . D')t^2 + 2(O' . D')t + (O' . O' - 1) = 0
+    let a_coeff = direction.dot(&direction);
+    let b_coeff = 2.0 * origin.dot(&direction);
+    let c_coeff = origin.dot(&origin) - 1.0;
+
+    if a_coeff == 0.0 {
+        return None;
+    }
+
+    let discriminant = b_coeff * b_coeff - 4.0 * a_coeff * c_coeff;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let sqrt_disc = discriminant.sqrt();
+    Some((
+        (-b_coeff - sqrt_disc) / (2.0 * a_coeff),
+        (-b_coeff + sqrt_disc) / (2.0 * a_coeff),
+    ))
+}
+
+/// Returns the first intersection of the ray from `origin` along `direction` with the surface of the ellipsoid, or None if the
+/// ray does not intersect the ellipsoid.
+///
+/// This is functionally equivalent to the SPICE routine `surfpt_c`. If the origin is inside the ellipsoid, the intersection is
+/// where the ray exits the ellipsoid. Intersections closer than 1e-9 times the direction norm are ignored, so that a ray
+/// starting on the surface does not return its own origin.
+pub fn ray_intersection(
+    radii_km: &Vector3,
+    origin: &Vector3,
+    direction: &Vector3,
+) -> Option<Vector3> {
+    let (t_near, t_far) = ray_intersection_parameters(radii_km, origin, direction)?;
+
+    let t = if t_near > 1e-9 {
+        t_near
+    } else if t_far > 1e-9 {
+        t_far
+    } else {
+        // Intersection is behind
+        return None;
+    };
+
+    Some(origin + direction * t)
+}
+
+/// Returns the outward unit normal vector of the ellipsoid at the provided surface point.
+///
+/// This is functionally equivalent to the SPICE routine `surfnm_c`. The gradient of (x/a)^2 + (y/b)^2 + (z/c)^2 is
+/// [ 2x/a^2, 2y/b^2, 2z/c^2 ], so for a point off the surface, this is the normal of the scaled ellipsoid through that point.
+pub fn surface_normal(radii_km: &Vector3, surface_point: &Vector3) -> Vector3 {
+    surface_point
+        .component_div(&radii_km.component_mul(radii_km))
+        .normalize()
+}
+
+/// Returns the point on the surface of the ellipsoid nearest to the provided point, and the altitude of that point, i.e. its
+/// signed distance to the surface, negative inside the ellipsoid.
+///
+/// This is functionally equivalent to the SPICE routine `nearpt_c`, and is solved by Newton iterations on the Lagrange
+/// multiplier of the distance minimization.
+pub fn nearest_point(radii_km: &Vector3, point: &Vector3) -> (Vector3, f64) {
+    let radii_sq = radii_km.component_mul(radii_km);
+    let lower_bound = -radii_sq.min();
+
+    // The surface point is x_i = p_i a_i^2 / (a_i^2 + lambda), where lambda solves sum((p_i a_i / (a_i^2 + lambda))^2) = 1.
+    let mut lambda = 0.0;
+    for _ in 0..100 {
+        let mut f = -1.0;
+        let mut df = 0.0;
+        for i in 0..3 {
+            let denom = radii_sq[i] + lambda;
+            let term = point[i].powi(2) * radii_sq[i] / denom.powi(2);
+            f += term;
+            df -= 2.0 * term / denom;
+        }
+        if f.abs() < 1e-15 || df == 0.0 {
+            break;
+        }
+        let mut next = lambda - f / df;
+        if next <= lower_bound {
+            // Stay on the branch of the solution, i.e. do not cross the smallest radius.
+            next = (lambda + lower_bound) / 2.0;
+        }
+        if (next - lambda).abs() <= 1e-15 * lambda.abs().max(1.0) {
+            lambda = next;
+            break;
+        }
+        lambda = next;
+    }
+
+    let surface = Vector3::new(
+        point.x * radii_sq.x / (radii_sq.x + lambda),
+        point.y * radii_sq.y / (radii_sq.y + lambda),
+        point.z * radii_sq.z / (radii_sq.z + lambda),
+    );
+
+    // Project onto the surface to remove the residual of the iterations.
+    let surface = surface / level(radii_km, &surface).sqrt();
+
+    let distance = (point - surface).norm();
+    let altitude = if level(radii_km, point) < 1.0 {
+        -distance
+    } else {
+        distance
+    };
+
+    (surface, altitude)
+}
+
+/// Returns the value of (x/a)^2 + (y/b)^2 + (z/c)^2 at the provided point, which is less than one inside the ellipsoid, one on
+/// its surface, and greater than one outside of it.
+pub fn level(radii_km: &Vector3, point: &Vector3) -> f64 {
+    point.component_div(radii_km).norm_squared()
+}
+
+#[cfg(test)]
+mod ut_ellipsoid_math {
+    use super::{
+        level, nearest_point, ray_intersection, ray_intersection_parameters, surface_normal,
+    };
+    use crate::math::Vector3;
+
+    #[test]
+    fn ray_intersection_sphere() {
+        let radii = Vector3::new(1.0, 1.0, 1.0);
+        let origin = Vector3::new(-5.0, 0.0, 0.0);
+
+        let (t_near, t_far) = ray_intersection_parameters(&radii, &origin, &Vector3::x()).unwrap();
+        assert!((t_near - 4.0).abs() < 1e-12);
+        assert!((t_far - 6.0).abs() < 1e-12);
+
+        let point = ray_intersection(&radii, &origin, &Vector3::x()).unwrap();
+        assert!((point - Vector3::new(-1.0, 0.0, 0.0)).norm() < 1e-12);
+
+        // Pointing away and missing
+        assert!(ray_intersection(&radii, &origin, &-Vector3::x()).is_none());
+        assert!(ray_intersection(&radii, &origin, &Vector3::y()).is_none());
+        assert!(ray_intersection_parameters(&radii, &origin, &Vector3::zeros()).is_none());
+
+        // From the inside, the ray exits the sphere
+        let point = ray_intersection(&radii, &Vector3::zeros(), &Vector3::z()).unwrap();
+        assert!((point - Vector3::z()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn ray_intersection_triaxial() {
+        let radii = Vector3::new(3.0, 2.0, 1.0);
+
+        for (direction, expected) in [
+            (Vector3::x(), Vector3::new(-3.0, 0.0, 0.0)),
+            (-Vector3::x(), Vector3::new(3.0, 0.0, 0.0)),
+        ] {
+            let origin = -direction * 10.0;
+            let point = ray_intersection(&radii, &origin, &direction).unwrap();
+            assert!((point - expected).norm() < 1e-12);
+        }
+
+        let origin = Vector3::new(1.0, -10.0, 0.5);
+        let point = ray_intersection(&radii, &origin, &Vector3::y()).unwrap();
+        assert!((level(&radii, &point) - 1.0).abs() < 1e-12);
+        assert!(point.y < 0.0);
+        assert!((point.x - 1.0).abs() < 1e-12 && (point.z - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn normal_and_nearest_point() {
+        let radii = Vector3::new(3.0, 2.0, 1.0);
+
+        assert!(
+            (surface_normal(&radii, &Vector3::new(0.0, 2.0, 0.0)) - Vector3::y()).norm() < 1e-12
+        );
+
+        for point in [
+            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(4.0, 3.0, 2.0),
+            Vector3::new(-1.0, 0.5, 0.2),
+            Vector3::new(0.1, 0.2, 5.0),
+        ] {
+            let (nearest, altitude) = nearest_point(&radii, &point);
+            assert!((level(&radii, &nearest) - 1.0).abs() < 1e-12);
+            assert!(((point - nearest).norm() - altitude.abs()).abs() < 1e-12);
+            assert_eq!(altitude < 0.0, level(&radii, &point) < 1.0);
+
+            // The offset from the nearest point is along the normal
+            let offset = point - nearest;
+            assert!(offset.cross(&surface_normal(&radii, &nearest)).norm() < 1e-9);
+        }
+
+        let (nearest, altitude) = nearest_point(&radii, &Vector3::new(0.0, 0.0, 5.0));
+        assert!((nearest - Vector3::z()).norm() < 1e-12);
+        assert!((altitude - 4.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Conversions between planetodetic coordinates and Cartesian positions in the body fixed frame of an oblate spheroid, e.g.
+//! using the `equatorial_radius_km` and `flattening` of the planetary data of that body.
+
+use super::Vector3;
+
+/// Returns the position in km in the body fixed frame of the point at the provided planetodetic latitude (φ) and longitude (λ)
+/// in degrees and height in km above the spheroid defined by its equatorial radius in km and its flattening ratio.
+pub fn latlon_to_cartesian(
+    latitude_deg: f64,
+    longitude_deg: f64,
+    height_km: f64,
+    equatorial_radius_km: f64,
+    flattening: f64,
+) -> Vector3 {
+    let e2 = flattening * (2.0 - flattening);
+    let (sin_lat, cos_lat) = latitude_deg.to_radians().sin_cos();
+    let (sin_long, cos_long) = longitude_deg.to_radians().sin_cos();
+    // Radius of curvature in the prime vertical
+    let n_km = equatorial_radius_km / (1.0 - e2 * sin_lat.powi(2)).sqrt();
+
+    Vector3::new(
+        (n_km + height_km) * cos_lat * cos_long,
+        (n_km + height_km) * cos_lat * sin_long,
+        (n_km * (1.0 - e2) + height_km) * sin_lat,
+    )
+}
+
+/// Returns the planetodetic latitude (φ) and longitude (λ) in degrees, and the height in km, of the provided position in km in the body
+/// fixed frame of the spheroid defined by its equatorial radius in km and its flattening ratio.
+/// The latitude is between -90 and +90 degrees and the longitude between -180 and +180 degrees.
+///
+/// # Algorithm
+/// Bowring's method, refined once from the reduced latitude of its first estimate, which is not iterative and accurate to well below
+/// the millimeter from the center of the body to beyond geostationary altitudes.
+/// The height uses Bowring's 1985 formula, which remains valid near the poles.
+///
+/// Reference: B. R. Bowring, "Transformation from spatial to geographical coordinates", Survey Review, 1976.
+pub fn cartesian_to_latlon(
+    position_km: &Vector3,
+    equatorial_radius_km: f64,
+    flattening: f64,
+) -> (f64, f64, f64) {
+    let a_km = equatorial_radius_km;
+    let b_km = a_km * (1.0 - flattening);
+    let e2 = flattening * (2.0 - flattening);
+    let e_prime2 = e2 / (1.0 - e2);
+
+    let (x, y, z) = (position_km.x, position_km.y, position_km.z);
+    let p = (x.powi(2) + y.powi(2)).sqrt();
+    let longitude_deg = y.atan2(x).to_degrees();
+
+    // Initial reduced latitude from the geocentric position, then its update from the first estimate of the latitude.
+    let mut beta = (a_km * z).atan2(b_km * p);
+    let mut lat = 0.0;
+    for _ in 0..2 {
+        let (sin_beta, cos_beta) = beta.sin_cos();
+        lat = (z + e_prime2 * b_km * sin_beta.powi(3)).atan2(p - e2 * a_km * cos_beta.powi(3));
+        beta = ((1.0 - flattening) * lat.sin()).atan2(lat.cos());
+    }
+
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let n_km = a_km / (1.0 - e2 * sin_lat.powi(2)).sqrt();
+    let height_km = p * cos_lat + z * sin_lat - a_km.powi(2) / n_km;
+
+    (lat.to_degrees(), longitude_deg, height_km)
+}
+
+#[cfg(test)]
+mod ut_geodetic {
+    use super::{cartesian_to_latlon, latlon_to_cartesian};
+    use crate::constants::frames::IAU_EARTH_FRAME;
+    use crate::prelude::Orbit;
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use hifitime::Epoch;
+
+    #[test]
+    fn round_trip() {
+        let a_km = 6378.137;
+        let f = 1.0 / 298.257223563;
+
+        for lat_deg in [-90.0, -89.9, -45.0, 0.0, 10.0, 52.3, 89.99, 90.0] {
+            for long_deg in [-179.0, 0.0, 33.3, 120.0] {
+                for height_km in [-10.0, 0.0, 0.5, 400.0, 35_786.0] {
+                    let r_km = latlon_to_cartesian(lat_deg, long_deg, height_km, a_km, f);
+                    let (lat, long, height) = cartesian_to_latlon(&r_km, a_km, f);
+                    assert!((lat - lat_deg).abs() < 1e-9, "{lat} != {lat_deg}");
+                    assert!((height - height_km).abs() < 1e-7, "{height} != {height_km}");
+                    if lat_deg.abs() < 90.0 {
+                        assert!((long - long_deg).abs() < 1e-9, "{long} != {long_deg}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_orbit() {
+        let shape = Ellipsoid::from_spheroid(6378.1366, 6356.7519);
+        let frame = IAU_EARTH_FRAME.with_ellipsoid(shape);
+        let orbit =
+            Orbit::try_latlongalt(40.4, -3.95, 0.8, Epoch::from_tdb_seconds(0.0), frame).unwrap();
+
+        let r_km = latlon_to_cartesian(40.4, -3.95, 0.8, 6378.1366, shape.flattening());
+        assert!((r_km - orbit.radius_km).norm() < 1e-9);
+
+        let (lat, long, height) =
+            cartesian_to_latlon(&orbit.radius_km, 6378.1366, shape.flattening());
+        let (orbit_lat, _, orbit_height) = orbit.latlongalt().unwrap();
+        assert!((lat - orbit_lat).abs() < 1e-9);
+        assert!((long + 3.95).abs() < 1e-12);
+        assert!((height - orbit_height).abs() < 1e-7);
+    }
+}
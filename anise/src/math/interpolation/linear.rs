@@ -0,0 +1,31 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+/// Returns the index of the first sample of the piecewise linear segment which contains `t`, and the elapsed fraction of that
+/// segment, or None if `t` is outside of the samples, which must be in strictly increasing order.
+///
+/// A single sample defines a constant, so it contains any `t` with a zero fraction.
+pub(crate) fn linear_segment(samples: &[f64], t: f64) -> Option<(usize, f64)> {
+    match samples {
+        [] => None,
+        [_] => Some((0, 0.0)),
+        [start, .., end] => {
+            if !(*start..=*end).contains(&t) {
+                return None;
+            }
+            let idx = samples
+                .partition_point(|sample| *sample <= t)
+                .clamp(1, samples.len() - 1)
+                - 1;
+            let (t0, t1) = (samples[idx], samples[idx + 1]);
+            Some((idx, (t - t0) / (t1 - t0)))
+        }
+    }
+}
@@ -11,11 +11,13 @@
 mod chebyshev;
 mod hermite;
 mod lagrange;
+mod linear;
 
 pub use chebyshev::{chebyshev_eval, chebyshev_eval_poly};
 pub use hermite::hermite_eval;
 use hifitime::Epoch;
 pub use lagrange::lagrange_eval;
+pub(crate) use linear::linear_segment;
 use snafu::Snafu;
 
 use crate::errors::{DecodingError, MathError};
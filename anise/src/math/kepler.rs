@@ -0,0 +1,324 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Conversions between the mean, eccentric, and true anomalies of elliptical and hyperbolic orbits.
+//!
+//! For hyperbolic orbits, the eccentric anomaly is the hyperbolic anomaly H and the mean anomaly is `e sinh(H) - H`.
+//! All of the anomalies are in radians. For elliptical orbits, the conversions preserve the revolution of the anomaly, e.g.
+//! a mean anomaly of 3π yields an eccentric anomaly and a true anomaly within (2π, 4π).
+
+use core::f64::consts::{PI, TAU};
+
+use crate::errors::MathError;
+
+/// Eccentricities within this distance of one are considered parabolic, for which the anomalies are not defined.
+pub const PARABOLIC_ECC_EPSILON: f64 = 1e-12;
+
+/// Maximum number of iterations of the Kepler equation solvers.
+pub const KEPLER_MAX_ITERATIONS: usize = 64;
+
+fn check_ecc(ecc: f64) -> Result<(), MathError> {
+    if !(0.0..).contains(&ecc) {
+        Err(MathError::DomainError {
+            value: ecc,
+            msg: "eccentricity cannot be negative",
+        })
+    } else if (ecc - 1.0).abs() < PARABOLIC_ECC_EPSILON {
+        Err(MathError::DomainError {
+            value: ecc,
+            msg: "anomalies are not defined for parabolic orbits",
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Solves the safeguarded Newton iterations of `f(x) = 0` with `f` increasing over the bracket `[lower, upper]`.
+fn solve_bracketed(
+    mut lower: f64,
+    mut upper: f64,
+    initial_guess: f64,
+    f_df: impl Fn(f64) -> (f64, f64),
+    action: &'static str,
+) -> Result<f64, MathError> {
+    let mut x = initial_guess.clamp(lower, upper);
+    for _ in 0..KEPLER_MAX_ITERATIONS {
+        let (f, df) = f_df(x);
+        if f == 0.0 {
+            return Ok(x);
+        } else if f < 0.0 {
+            lower = x;
+        } else {
+            upper = x;
+        }
+
+        let newton = x - f / df;
+        // Bisect whenever the Newton step leaves the bracket, which guarantees the convergence.
+        let next = if newton > lower && newton < upper && df > 0.0 {
+            newton
+        } else {
+            0.5 * (lower + upper)
+        };
+
+        if (next - x).abs() <= 4.0 * f64::EPSILON * next.abs().max(1.0) || upper - lower <= 0.0 {
+            return Ok(next);
+        }
+        x = next;
+    }
+
+    Err(MathError::MaxIterationsReached {
+        iter: KEPLER_MAX_ITERATIONS,
+        action,
+    })
+}
+
+/// Solves Kepler's equation `M = E - e sin(E)` for the eccentric anomaly of an elliptical orbit.
+pub fn solve_kepler_elliptic_rad(ma_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    if !(0.0..1.0).contains(&ecc) {
+        return Err(MathError::DomainError {
+            value: ecc,
+            msg: "elliptical Kepler equation requires an eccentricity within [0, 1)",
+        });
+    }
+    if !ma_rad.is_finite() {
+        return Err(MathError::DomainError {
+            value: ma_rad,
+            msg: "mean anomaly must be finite",
+        });
+    }
+
+    // Solve in [0, π] and restore the sign and revolution after, since E - M has the sign of sin(M) and is at most e.
+    let revolutions = (ma_rad / TAU).round();
+    let reduced = ma_rad - revolutions * TAU;
+    let m = reduced.abs();
+
+    let ea = solve_bracketed(
+        m,
+        (m + ecc).min(PI),
+        // Starting guess of Danby, which is robust up to high eccentricities
+        m + 0.85 * ecc,
+        |ea| (ea - ecc * ea.sin() - m, 1.0 - ecc * ea.cos()),
+        "solving the elliptical Kepler equation",
+    )?;
+
+    Ok(ea.copysign(reduced) + revolutions * TAU)
+}
+
+/// Solves the hyperbolic Kepler equation `M = e sinh(H) - H` for the hyperbolic anomaly.
+pub fn solve_kepler_hyperbolic_rad(ma_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    if ecc <= 1.0 {
+        return Err(MathError::DomainError {
+            value: ecc,
+            msg: "hyperbolic Kepler equation requires an eccentricity greater than one",
+        });
+    }
+    if !ma_rad.is_finite() {
+        return Err(MathError::DomainError {
+            value: ma_rad,
+            msg: "mean anomaly must be finite",
+        });
+    }
+
+    // The equation is odd, so solve for |M|: since (e - 1) sinh(H) <= e sinh(H) - H <= e sinh(H), the root is between
+    // asinh(|M| / e) and asinh(|M| / (e - 1)).
+    let m = ma_rad.abs();
+    let lower = (m / ecc).asinh();
+    let upper = (m / (ecc - 1.0)).asinh();
+    let ha = solve_bracketed(
+        lower,
+        upper,
+        lower,
+        |ha| (ecc * ha.sinh() - ha - m, ecc * ha.cosh() - 1.0),
+        "solving the hyperbolic Kepler equation",
+    )?;
+
+    Ok(ha.copysign(ma_rad))
+}
+
+/// Returns the eccentric anomaly (hyperbolic anomaly if hyperbolic) from the mean anomaly.
+pub fn mean_to_eccentric_anomaly_rad(ma_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    check_ecc(ecc)?;
+    if ecc < 1.0 {
+        solve_kepler_elliptic_rad(ma_rad, ecc)
+    } else {
+        solve_kepler_hyperbolic_rad(ma_rad, ecc)
+    }
+}
+
+/// Returns the mean anomaly from the eccentric anomaly (hyperbolic anomaly if hyperbolic).
+pub fn eccentric_to_mean_anomaly_rad(ea_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    check_ecc(ecc)?;
+    if ecc < 1.0 {
+        Ok(ea_rad - ecc * ea_rad.sin())
+    } else {
+        Ok(ecc * ea_rad.sinh() - ea_rad)
+    }
+}
+
+/// Returns the true anomaly from the eccentric anomaly (hyperbolic anomaly if hyperbolic).
+pub fn eccentric_to_true_anomaly_rad(ea_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    check_ecc(ecc)?;
+    if ecc < 1.0 {
+        // The half angle form keeps the true anomaly in the revolution of the eccentric anomaly.
+        let (sin_half, cos_half) = (0.5 * ea_rad).sin_cos();
+        let half_ta = ((1.0 + ecc).sqrt() * sin_half).atan2((1.0 - ecc).sqrt() * cos_half);
+        let revolutions = ((0.5 * ea_rad - half_ta) / PI).round();
+        Ok(2.0 * (half_ta + revolutions * PI))
+    } else {
+        Ok(2.0 * (((ecc + 1.0) / (ecc - 1.0)).sqrt() * (0.5 * ea_rad).tanh()).atan())
+    }
+}
+
+/// Returns the eccentric anomaly (hyperbolic anomaly if hyperbolic) from the true anomaly.
+///
+/// For hyperbolic orbits, the true anomaly is wrapped into (-π, π] and must be within the asymptotes, i.e. its cosine must be
+/// greater than -1/e.
+pub fn true_to_eccentric_anomaly_rad(ta_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    check_ecc(ecc)?;
+    if ecc < 1.0 {
+        let (sin_half, cos_half) = (0.5 * ta_rad).sin_cos();
+        let half_ea = ((1.0 - ecc).sqrt() * sin_half).atan2((1.0 + ecc).sqrt() * cos_half);
+        let revolutions = ((0.5 * ta_rad - half_ea) / PI).round();
+        Ok(2.0 * (half_ea + revolutions * PI))
+    } else {
+        let ta_rad = ta_rad - (ta_rad / TAU).round() * TAU;
+        if 1.0 + ecc * ta_rad.cos() <= 0.0 {
+            return Err(MathError::DomainError {
+                value: ta_rad.to_degrees(),
+                msg: "true anomaly (deg) is beyond the asymptotes of the hyperbola",
+            });
+        }
+        Ok(2.0 * (((ecc - 1.0) / (ecc + 1.0)).sqrt() * (0.5 * ta_rad).tan()).atanh())
+    }
+}
+
+/// Returns the true anomaly from the mean anomaly.
+pub fn mean_to_true_anomaly_rad(ma_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    eccentric_to_true_anomaly_rad(mean_to_eccentric_anomaly_rad(ma_rad, ecc)?, ecc)
+}
+
+/// Returns the mean anomaly from the true anomaly, cf. [true_to_eccentric_anomaly_rad] for hyperbolic orbits.
+pub fn true_to_mean_anomaly_rad(ta_rad: f64, ecc: f64) -> Result<f64, MathError> {
+    eccentric_to_mean_anomaly_rad(true_to_eccentric_anomaly_rad(ta_rad, ecc)?, ecc)
+}
+
+#[cfg(test)]
+mod ut_kepler {
+    use super::*;
+
+    #[test]
+    fn elliptic_round_trips() {
+        for ecc in [0.0, 1e-9, 0.1, 0.5, 0.9, 0.99, 0.999_999] {
+            for i in -40..=40 {
+                let ma_rad = i as f64 * 0.3;
+                let ea_rad = solve_kepler_elliptic_rad(ma_rad, ecc).unwrap();
+                let residual = ea_rad - ecc * ea_rad.sin() - ma_rad;
+                assert!(
+                    residual.abs() < 1e-13,
+                    "e = {ecc}, M = {ma_rad}: {residual:e}"
+                );
+                // Same revolution
+                assert!((ea_rad - ma_rad).abs() <= ecc + 1e-12);
+
+                let ta_rad = eccentric_to_true_anomaly_rad(ea_rad, ecc).unwrap();
+                assert!((ta_rad - ea_rad).abs() < PI);
+                let ea_back = true_to_eccentric_anomaly_rad(ta_rad, ecc).unwrap();
+                assert!((ea_back - ea_rad).abs() < 1e-9, "e = {ecc}, M = {ma_rad}");
+                let ma_back = true_to_mean_anomaly_rad(ta_rad, ecc).unwrap();
+                assert!((ma_back - ma_rad).abs() < 1e-9, "e = {ecc}, M = {ma_rad}");
+            }
+        }
+
+        // Known value (Vallado, example 2-1): M = 235.4 deg, e = 0.4 yields E = 220.512074767522 deg
+        let ea_deg = solve_kepler_elliptic_rad(235.4_f64.to_radians(), 0.4)
+            .unwrap()
+            .to_degrees();
+        assert!((ea_deg - 220.512_074_767_522).abs() < 1e-9, "{ea_deg}");
+
+        // Circular orbits: all anomalies are equal
+        let ta_rad = mean_to_true_anomaly_rad(1.234, 0.0).unwrap();
+        assert!((ta_rad - 1.234).abs() < 1e-15);
+    }
+
+    #[test]
+    fn hyperbolic_round_trips() {
+        for ecc in [1.000_001, 1.01, 1.5, 3.0, 50.0] {
+            for ma_rad in [-1e4, -100.0, -1.0, -1e-6, 0.0, 1e-6, 0.5, 10.0, 1e3, 1e6] {
+                let ha_rad = solve_kepler_hyperbolic_rad(ma_rad, ecc).unwrap();
+                let residual = ecc * ha_rad.sinh() - ha_rad - ma_rad;
+                assert!(
+                    residual.abs() < 1e-12 * ma_rad.abs().max(1.0),
+                    "e = {ecc}, M = {ma_rad}: {residual:e}"
+                );
+
+                let ta_rad = eccentric_to_true_anomaly_rad(ha_rad, ecc).unwrap();
+                assert!(1.0 + ecc * ta_rad.cos() >= 0.0);
+                if ha_rad.abs() < 20.0 {
+                    let ma_back = true_to_mean_anomaly_rad(ta_rad, ecc).unwrap();
+                    assert!(
+                        (ma_back - ma_rad).abs() < 1e-7 * ma_rad.abs().max(1.0),
+                        "e = {ecc}, M = {ma_rad}: {ma_back}"
+                    );
+                }
+            }
+        }
+
+        // Known value (Vallado, example 2-3 with e = 2.4, M = 235.4 deg): H = 1.601376144 rad
+        let ha_rad = solve_kepler_hyperbolic_rad(235.4_f64.to_radians(), 2.4).unwrap();
+        assert!((ha_rad - 1.601_376_144).abs() < 1e-8, "{ha_rad}");
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert!(mean_to_true_anomaly_rad(1.0, 1.0).is_err());
+        assert!(mean_to_true_anomaly_rad(1.0, -0.1).is_err());
+        assert!(solve_kepler_elliptic_rad(f64::NAN, 0.1).is_err());
+        assert!(solve_kepler_elliptic_rad(1.0, 1.5).is_err());
+        assert!(solve_kepler_hyperbolic_rad(1.0, 0.5).is_err());
+        // Beyond the asymptotes of a hyperbola of e = 2, i.e. beyond 120 deg
+        assert!(true_to_eccentric_anomaly_rad(130_f64.to_radians(), 2.0).is_err());
+        assert!(true_to_eccentric_anomaly_rad(-110_f64.to_radians(), 2.0).is_ok());
+    }
+
+    #[test]
+    fn orbit_anomalies() {
+        use crate::constants::frames::EARTH_J2000;
+        use crate::prelude::Orbit;
+        use hifitime::Epoch;
+
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+
+        let orbit =
+            Orbit::try_keplerian(24_000.0, 0.7, 28.5, 10.0, 20.0, 30.0, epoch, frame).unwrap();
+        let moved = orbit.with_ma_deg(250.0).unwrap();
+        assert!((moved.ma_deg().unwrap() - 250.0).abs() < 1e-9);
+        assert!((moved.sma_km().unwrap() - 24_000.0).abs() < 1e-6);
+        let moved = orbit.with_ea_deg(100.0).unwrap();
+        assert!((moved.ea_deg().unwrap() - 100.0).abs() < 1e-9);
+
+        // Outbound leg of a hyperbola
+        let orbit =
+            Orbit::try_keplerian(-20_000.0, 1.8, 28.5, 10.0, 20.0, 30.0, epoch, frame).unwrap();
+        let ha_rad = true_to_eccentric_anomaly_rad(30_f64.to_radians(), 1.8).unwrap();
+        assert!((orbit.ea_deg().unwrap().to_radians() - ha_rad).abs() < 1e-12);
+        assert!(
+            (orbit.ma_deg().unwrap().to_radians() - (1.8 * ha_rad.sinh() - ha_rad)).abs() < 1e-12
+        );
+        let moved = orbit.with_ma_deg(300.0).unwrap();
+        assert!((moved.ma_deg().unwrap() - 300.0).abs() < 1e-9);
+        assert!(
+            (moved.ea_deg().unwrap().to_radians()
+                - solve_kepler_hyperbolic_rad(300_f64.to_radians(), 1.8).unwrap())
+            .abs()
+                < 1e-12
+        );
+    }
+}
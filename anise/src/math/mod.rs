@@ -19,8 +19,13 @@ pub mod angles;
 pub mod cartesian;
 #[cfg(feature = "python")]
 mod cartesian_py;
+pub mod compact;
+pub mod ellipsoid;
+pub mod geodetic;
 pub mod interpolation;
+pub mod kepler;
 pub mod rotation;
+pub mod uncertain;
 pub mod units;
 
 use nalgebra::allocator::Allocator;
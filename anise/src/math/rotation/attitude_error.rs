@@ -0,0 +1,307 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::Epoch;
+use snafu::ensure;
+
+use super::{EulerSequence, DCM};
+use crate::astro::PhysicsResult;
+use crate::errors::{EpochMismatchSnafu, InvalidRotationSnafu};
+use crate::math::Vector3;
+
+/// Attitude error of an estimated orientation with respect to a reference orientation at a given epoch.
+///
+/// The error rotation is `C_est * C_ref^T`, i.e. the rotation from the reference destination frame to the estimated one,
+/// such that the estimated orientation is the error rotation applied after the reference orientation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AttitudeError {
+    pub epoch: Epoch,
+    /// Principal rotation angle of the error rotation, between 0 and 180 degrees
+    pub angle_deg: f64,
+    /// Euler angles of the error rotation in the sequence of the computation, in degrees
+    pub axis_errors_deg: Vector3,
+    /// Difference of the angular velocities of the estimated and reference DCMs, if both define a rotation rate
+    pub rate_error_deg_s: Option<Vector3>,
+}
+
+impl AttitudeError {
+    /// Computes the attitude error of the estimated orientation with respect to the reference, which must rotate between the same frames.
+    pub fn new(
+        epoch: Epoch,
+        estimated: DCM,
+        reference: DCM,
+        sequence: EulerSequence,
+    ) -> PhysicsResult<Self> {
+        ensure!(
+            estimated.from == reference.from && estimated.to == reference.to,
+            InvalidRotationSnafu {
+                action: "compute attitude error",
+                from1: estimated.from,
+                to1: estimated.to,
+                from2: reference.from,
+                to2: reference.to
+            }
+        );
+
+        let error = DCM {
+            rot_mat: estimated.rot_mat * reference.rot_mat.transpose(),
+            rot_mat_dt: None,
+            from: reference.to,
+            to: estimated.to,
+        };
+
+        // The principal angle from the trace is ill-conditioned for small angles, so use the norm of the skew part too.
+        let sin_angle = Vector3::new(
+            error.rot_mat[(1, 2)] - error.rot_mat[(2, 1)],
+            error.rot_mat[(2, 0)] - error.rot_mat[(0, 2)],
+            error.rot_mat[(0, 1)] - error.rot_mat[(1, 0)],
+        )
+        .norm()
+            / 2.0;
+        let cos_angle = (error.rot_mat.trace() - 1.0) / 2.0;
+
+        let rate_error_deg_s = match (
+            estimated.angular_velocity_deg_s(),
+            reference.angular_velocity_deg_s(),
+        ) {
+            (Some(estimated), Some(reference)) => Some(estimated - reference),
+            _ => None,
+        };
+
+        Ok(Self {
+            epoch,
+            angle_deg: sin_angle.atan2(cos_angle).to_degrees(),
+            axis_errors_deg: error.euler_angles_deg(sequence),
+            rate_error_deg_s,
+        })
+    }
+}
+
+impl fmt::Display for AttitudeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {:.6} deg (axes: {:.6}, {:.6}, {:.6} deg)",
+            self.epoch,
+            self.angle_deg,
+            self.axis_errors_deg[0],
+            self.axis_errors_deg[1],
+            self.axis_errors_deg[2]
+        )?;
+        if let Some(rate) = self.rate_error_deg_s {
+            write!(f, ", rate error {:.6} deg/s", rate.norm())?;
+        }
+        Ok(())
+    }
+}
+
+/// Attitude errors of an estimated time series of orientations with respect to a reference time series, with their statistics.
+///
+/// This is typically used to compare the orientations computed by ANISE with those of a C-kernel or of a simulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttitudeErrorReport {
+    pub sequence: EulerSequence,
+    pub errors: Vec<AttitudeError>,
+}
+
+impl AttitudeErrorReport {
+    /// Computes the attitude errors of two time series of DCMs or quaternions, which must be sampled at the same epochs.
+    /// The samples are paired in order, so the extra samples of the longer series, if any, are ignored.
+    pub fn from_series<E, R>(
+        estimated: &[(Epoch, E)],
+        reference: &[(Epoch, R)],
+        sequence: EulerSequence,
+    ) -> PhysicsResult<Self>
+    where
+        E: Copy + Into<DCM>,
+        R: Copy + Into<DCM>,
+    {
+        let mut errors = Vec::with_capacity(estimated.len());
+        for ((epoch, estimated), (ref_epoch, reference)) in estimated.iter().zip(reference) {
+            ensure!(
+                epoch == ref_epoch,
+                EpochMismatchSnafu {
+                    action: "computing attitude errors",
+                    epoch1: *epoch,
+                    epoch2: *ref_epoch
+                }
+            );
+            errors.push(AttitudeError::new(
+                *epoch,
+                (*estimated).into(),
+                (*reference).into(),
+                sequence,
+            )?);
+        }
+
+        Ok(Self { sequence, errors })
+    }
+
+    /// Returns the largest error angle in degrees, or zero if the report is empty.
+    pub fn max_angle_deg(&self) -> f64 {
+        self.errors
+            .iter()
+            .map(|error| error.angle_deg)
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the mean error angle in degrees, or NaN if the report is empty.
+    pub fn mean_angle_deg(&self) -> f64 {
+        self.errors.iter().map(|error| error.angle_deg).sum::<f64>() / self.errors.len() as f64
+    }
+
+    /// Returns the root mean square of the error angles in degrees, or NaN if the report is empty.
+    pub fn rms_angle_deg(&self) -> f64 {
+        (self
+            .errors
+            .iter()
+            .map(|error| error.angle_deg.powi(2))
+            .sum::<f64>()
+            / self.errors.len() as f64)
+            .sqrt()
+    }
+
+    /// Returns the largest absolute error about each axis of the sequence, in degrees.
+    pub fn max_abs_axis_errors_deg(&self) -> Vector3 {
+        self.errors.iter().fold(Vector3::zeros(), |max, error| {
+            max.zip_map(&error.axis_errors_deg, |max, err| max.max(err.abs()))
+        })
+    }
+
+    /// Returns the root mean square of the error about each axis of the sequence, in degrees.
+    pub fn rms_axis_errors_deg(&self) -> Vector3 {
+        (self
+            .errors
+            .iter()
+            .map(|error| error.axis_errors_deg.component_mul(&error.axis_errors_deg))
+            .sum::<Vector3>()
+            / self.errors.len() as f64)
+            .map(f64::sqrt)
+    }
+
+    /// Returns the root mean square of the norm of the angular rate errors in deg/s, if any sample defines one.
+    pub fn rms_rate_error_deg_s(&self) -> Option<f64> {
+        let rates = self
+            .errors
+            .iter()
+            .filter_map(|error| error.rate_error_deg_s)
+            .collect::<Vec<_>>();
+        if rates.is_empty() {
+            None
+        } else {
+            Some(
+                (rates.iter().map(|rate| rate.norm_squared()).sum::<f64>() / rates.len() as f64)
+                    .sqrt(),
+            )
+        }
+    }
+}
+
+impl fmt::Display for AttitudeErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max_axes = self.max_abs_axis_errors_deg();
+        write!(
+            f,
+            "{} attitude errors: max {:.6} deg, mean {:.6} deg, RMS {:.6} deg, max {:?} axes {:.6}, {:.6}, {:.6} deg",
+            self.errors.len(),
+            self.max_angle_deg(),
+            self.mean_angle_deg(),
+            self.rms_angle_deg(),
+            self.sequence,
+            max_axes[0],
+            max_axes[1],
+            max_axes[2]
+        )?;
+        if let Some(rate) = self.rms_rate_error_deg_s() {
+            write!(f, ", RMS rate {rate:.6} deg/s")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod ut_attitude_error {
+    use super::{AttitudeError, AttitudeErrorReport};
+    use crate::math::rotation::{EulerSequence, Quaternion, DCM};
+    use crate::math::{Matrix3, Vector3};
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn attitude_errors() {
+        let epoch = Epoch::from_et_seconds(0.0);
+        let reference =
+            DCM::from_euler_angles(EulerSequence::ZYX, Vector3::new(0.3, -0.2, 1.1), 1, -85000);
+        // Small 3-2-1 errors applied after the reference
+        let errors_deg = Vector3::new(0.01, -0.02, 0.005);
+        let delta = DCM::from_euler_angles(
+            EulerSequence::ZYX,
+            errors_deg.map(|e| e.to_radians()),
+            -85000,
+            -85000,
+        );
+        let estimated = DCM {
+            rot_mat: delta.rot_mat * reference.rot_mat,
+            ..reference
+        };
+
+        let error = AttitudeError::new(epoch, estimated, reference, EulerSequence::ZYX).unwrap();
+        assert!((error.axis_errors_deg - errors_deg).norm() < 1e-10);
+        // The principal angle matches that of the error rotation
+        let (_, angle_rad) = Quaternion::from(delta).uvec_angle_rad();
+        assert!((error.angle_deg - angle_rad.to_degrees()).abs() < 1e-10);
+        assert!(error.rate_error_deg_s.is_none());
+
+        // Identical orientations have no error, even with quaternions
+        let q = Quaternion::from(reference);
+        let report = AttitudeErrorReport::from_series(
+            &[(epoch, q), (epoch + Unit::Second * 1, q)],
+            &[(epoch, reference), (epoch + Unit::Second * 1, reference)],
+            EulerSequence::XYZ,
+        )
+        .unwrap();
+        assert!(report.max_angle_deg() < 1e-6);
+
+        // Rate errors
+        let spin = |rate_rad_s: f64| DCM {
+            rot_mat_dt: Some(
+                Matrix3::new(0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+                    * rate_rad_s
+                    * reference.rot_mat,
+            ),
+            ..reference
+        };
+        let report = AttitudeErrorReport::from_series(
+            &[(epoch, estimated), (epoch, spin(2e-3))],
+            &[(epoch, reference), (epoch, spin(1e-3))],
+            EulerSequence::ZYX,
+        )
+        .unwrap();
+        assert_eq!(report.errors.len(), 2);
+        assert!((report.max_angle_deg() - error.angle_deg).abs() < 1e-12);
+        assert!((report.mean_angle_deg() - error.angle_deg / 2.0).abs() < 1e-12);
+        assert!((report.rms_angle_deg() - error.angle_deg / 2.0_f64.sqrt()).abs() < 1e-12);
+        assert!((report.max_abs_axis_errors_deg() - errors_deg.abs()).norm() < 1e-10);
+        let rate = report.rms_rate_error_deg_s().unwrap();
+        assert!((rate - 1e-3_f64.to_degrees()).abs() < 1e-12);
+
+        // Mismatched epochs and frames are rejected
+        assert!(AttitudeErrorReport::from_series(
+            &[(epoch, estimated)],
+            &[(epoch + Unit::Second * 1, reference)],
+            EulerSequence::ZYX,
+        )
+        .is_err());
+        assert!(
+            AttitudeError::new(epoch, estimated.transpose(), reference, EulerSequence::ZYX)
+                .is_err()
+        );
+    }
+}
@@ -8,11 +8,13 @@
  * Documentation: https://nyxspace.com/
  */
 
+#[cfg(feature = "analysis")]
+use crate::ephemerides::ephemeris::LocalFrame;
 use crate::{
     astro::PhysicsResult,
     errors::{InvalidRotationSnafu, InvalidStateRotationSnafu, PhysicsError},
     math::{cartesian::CartesianState, Matrix3, Matrix6, Vector3, Vector6},
-    prelude::Frame,
+    prelude::{Frame, Orbit},
     NaifId,
 };
 use nalgebra::Vector4;
@@ -198,6 +200,44 @@ impl DCM {
         })
     }
 
+    /// Builds the rotation from the RIC frame (radial, in-track, cross-track) of the provided orbit to its inertial frame,
+    /// with its time derivative. The transpose of this DCM rotates inertial states into the RIC frame.
+    ///
+    /// Refer to [Orbit::dcm_from_ric_to_inertial] for details.
+    pub fn from_ric(orbit: &Orbit) -> PhysicsResult<Self> {
+        orbit.dcm_from_ric_to_inertial()
+    }
+
+    /// Builds the rotation from the VNC frame (velocity, normal, conormal) of the provided orbit to its inertial frame,
+    /// with its time derivative. The transpose of this DCM rotates inertial states into the VNC frame.
+    ///
+    /// Refer to [Orbit::dcm_from_vnc_to_inertial] for details.
+    pub fn from_vnc(orbit: &Orbit) -> PhysicsResult<Self> {
+        orbit.dcm_from_vnc_to_inertial()
+    }
+
+    /// Builds the rotation from the RCN frame (radial, cross, normal) of the provided orbit to its inertial frame,
+    /// with its time derivative. The transpose of this DCM rotates inertial states into the RCN frame.
+    ///
+    /// Refer to [Orbit::dcm_from_rcn_to_inertial] for details.
+    pub fn from_rcn(orbit: &Orbit) -> PhysicsResult<Self> {
+        orbit.dcm_from_rcn_to_inertial()
+    }
+
+    /// Builds the rotation from the LVLH frame (local vertical, local horizontal) of the provided orbit to its inertial frame,
+    /// with its time derivative. The transpose of this DCM rotates inertial states into the LVLH frame.
+    ///
+    /// Refer to [Orbit::dcm_from_lvlh_to_inertial] for details.
+    pub fn from_lvlh(orbit: &Orbit) -> PhysicsResult<Self> {
+        orbit.dcm_from_lvlh_to_inertial()
+    }
+
+    /// Builds the rotation from the provided local frame of the orbit to its inertial frame, cf. [Orbit::dcm_to_inertial].
+    #[cfg(feature = "analysis")]
+    pub fn from_local_frame(orbit: &Orbit, local_frame: LocalFrame) -> PhysicsResult<Self> {
+        orbit.dcm_to_inertial(local_frame)
+    }
+
     /// Returns the 6x6 DCM to rotate a state. If the time derivative of this DCM is defined, this 6x6 accounts for the transport theorem.
     pub fn state_dcm(&self) -> Matrix6 {
         let mut full_dcm = Matrix6::zeros();
@@ -694,4 +734,61 @@ mod ut_dcm {
         let z_mapped = dcm * Vector3::z();
         assert!((z_mapped - Vector3::z()).norm() < 1e-12);
     }
+
+    #[cfg(feature = "analysis")]
+    #[test]
+    fn test_local_frames() {
+        use crate::constants::frames::EARTH_J2000;
+        use crate::ephemerides::ephemeris::LocalFrame;
+        use crate::prelude::Orbit;
+        use hifitime::Epoch;
+
+        let frame = EARTH_J2000.with_mu_km3_s2(398_600.435_436);
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let target =
+            Orbit::try_keplerian(7_000.0, 0.01, 51.6, 30.0, 45.0, 60.0, epoch, frame).unwrap();
+
+        for (dcm, local_frame) in [
+            (DCM::from_ric(&target).unwrap(), LocalFrame::RIC),
+            (DCM::from_vnc(&target).unwrap(), LocalFrame::VNC),
+            (DCM::from_rcn(&target).unwrap(), LocalFrame::RCN),
+            (DCM::from_lvlh(&target).unwrap(), LocalFrame::LVLH),
+        ] {
+            assert!(dcm.is_valid(1e-12, 1e-12));
+            assert!(dcm.rot_mat_dt.is_some());
+            assert_eq!(dcm, DCM::from_local_frame(&target, local_frame).unwrap());
+        }
+        assert!(DCM::from_local_frame(&target, LocalFrame::Inertial)
+            .unwrap()
+            .is_identity());
+
+        // A chaser one kilometer above the target is along the radial axis, and one kilometer ahead is along the velocity axis.
+        let mut above = target;
+        above.radius_km += target.r_hat();
+        let ric = above.in_frame_of(&target, LocalFrame::RIC).unwrap();
+        assert!((ric.radius_km - Vector3::x()).norm() < 1e-12);
+        let mut ahead = target;
+        ahead.radius_km += target.velocity_km_s / target.vmag_km_s();
+        let vnc = ahead.in_frame_of(&target, LocalFrame::VNC).unwrap();
+        assert!((vnc.radius_km - Vector3::x()).norm() < 1e-12);
+        assert!(vnc.frame.mu_km3_s2.is_none());
+
+        // Consistent with the RIC and VNC differences
+        let chaser = target.add_raan_deg(0.1).unwrap().add_ta_deg(-0.2).unwrap();
+        for (rel, diff) in [
+            (
+                chaser.in_frame_of(&target, LocalFrame::RIC).unwrap(),
+                target.ric_difference(&chaser).unwrap(),
+            ),
+            (
+                chaser.in_frame_of(&target, LocalFrame::VNC).unwrap(),
+                target.vnc_difference(&chaser).unwrap(),
+            ),
+        ] {
+            assert!((rel.radius_km - diff.radius_km).norm() < 1e-9);
+            assert!((rel.velocity_km_s - diff.velocity_km_s).norm() < 1e-12);
+        }
+        let inertial = chaser.in_frame_of(&target, LocalFrame::Inertial).unwrap();
+        assert!((inertial.radius_km - (chaser.radius_km - target.radius_km)).norm() < 1e-9);
+    }
 }
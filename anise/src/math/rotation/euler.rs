@@ -0,0 +1,212 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::{r1, r2, r3, DCM};
+use crate::math::{Matrix3, Vector3};
+use crate::NaifId;
+
+/// Sequence of the three rotations of a set of Euler angles, listed in the order in which the rotations are applied.
+///
+/// For example, `ZYX` is the aerospace 3-2-1 sequence of yaw, pitch, and roll: the DCM is `R1(roll) * R2(pitch) * R3(yaw)`.
+/// All rotations are passive, like [DCM::r1], [DCM::r2], and [DCM::r3].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerSequence {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerSequence {
+    /// Returns the zero-indexed axes of the first, second, and third rotations.
+    pub const fn axes(&self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Returns true if the first and third rotations are about the same axis (e.g. 3-1-3), false for Tait-Bryan sequences (e.g. 3-2-1).
+    pub const fn is_symmetric(&self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+}
+
+impl DCM {
+    /// Builds the DCM of the provided Euler angles, in radians and in the order of the sequence.
+    pub fn from_euler_angles(
+        sequence: EulerSequence,
+        angles_rad: Vector3,
+        from: NaifId,
+        to: NaifId,
+    ) -> Self {
+        let rot = |axis: usize, angle_rad: f64| match axis {
+            0 => r1(angle_rad),
+            1 => r2(angle_rad),
+            _ => r3(angle_rad),
+        };
+        let [i, j, k] = sequence.axes();
+
+        Self {
+            rot_mat: rot(k, angles_rad[2]) * rot(j, angles_rad[1]) * rot(i, angles_rad[0]),
+            rot_mat_dt: None,
+            from,
+            to,
+        }
+    }
+
+    /// Returns the Euler angles of this DCM in radians, in the order of the sequence.
+    ///
+    /// The first and third angles are within [-π, π]. The second angle is within [-π/2, π/2] for Tait-Bryan sequences,
+    /// and within [0, π] for symmetric sequences. At the singularities of the sequence (e.g. a pitch of ±90 degrees in a
+    /// 3-2-1 sequence), the third angle is set to zero and the first angle holds the combined rotation.
+    pub fn euler_angles_rad(&self, sequence: EulerSequence) -> Vector3 {
+        let c = &self.rot_mat;
+        let [i, j, k] = sequence.axes();
+
+        if sequence.is_symmetric() {
+            // The third axis is the one not used by the sequence.
+            let k = 3 - i - j;
+            let sign = parity(i, j, k);
+
+            let second = c[(i, i)].clamp(-1.0, 1.0).acos();
+            if second.sin().abs() < 1e-12 {
+                Vector3::new(singular(c, j, k, sign), second, 0.0)
+            } else {
+                let first = c[(i, j)].atan2(-sign * c[(i, k)]);
+                let third = c[(j, i)].atan2(sign * c[(k, i)]);
+                Vector3::new(first, second, third)
+            }
+        } else {
+            let sign = parity(i, j, k);
+
+            let second = (sign * c[(k, i)]).clamp(-1.0, 1.0).asin();
+            if second.cos().abs() < 1e-12 {
+                Vector3::new(singular(c, j, k, sign), second, 0.0)
+            } else {
+                let first = (-sign * c[(k, j)]).atan2(c[(k, k)]);
+                let third = (-sign * c[(j, i)]).atan2(c[(i, i)]);
+                Vector3::new(first, second, third)
+            }
+        }
+    }
+
+    /// Returns the Euler angles of this DCM in degrees, in the order of the sequence, cf. [DCM::euler_angles_rad].
+    pub fn euler_angles_deg(&self, sequence: EulerSequence) -> Vector3 {
+        self.euler_angles_rad(sequence)
+            .map(|angle| angle.to_degrees())
+    }
+}
+
+/// Returns +1 if the axes are a cyclic permutation of X, Y, Z, and -1 otherwise.
+fn parity(i: usize, j: usize, k: usize) -> f64 {
+    if (i, j, k) == (0, 1, 2) || (i, j, k) == (1, 2, 0) || (i, j, k) == (2, 0, 1) {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// Returns the first angle of a singular DCM, where the first and third rotations are about the same (or opposite) axis.
+fn singular(c: &Matrix3, j: usize, k: usize, sign: f64) -> f64 {
+    // With the third angle set to zero, the DCM reduces to R_j(second) * R_i(first), whose j-th row only depends on the first angle.
+    (sign * c[(j, k)]).atan2(c[(j, j)])
+}
+
+#[cfg(test)]
+mod ut_euler {
+    use super::{EulerSequence, DCM};
+    use crate::math::Vector3;
+
+    const SEQUENCES: [EulerSequence; 12] = [
+        EulerSequence::XYZ,
+        EulerSequence::XZY,
+        EulerSequence::YXZ,
+        EulerSequence::YZX,
+        EulerSequence::ZXY,
+        EulerSequence::ZYX,
+        EulerSequence::XYX,
+        EulerSequence::XZX,
+        EulerSequence::YXY,
+        EulerSequence::YZY,
+        EulerSequence::ZXZ,
+        EulerSequence::ZYZ,
+    ];
+
+    #[test]
+    fn euler_round_trip() {
+        for sequence in SEQUENCES {
+            for angles_deg in [
+                Vector3::new(10.0, 20.0, 30.0),
+                Vector3::new(-170.0, 45.0, 95.0),
+                Vector3::new(60.0, 89.0, -120.0),
+                Vector3::new(0.0, 0.0, 0.0),
+            ] {
+                let mut angles_deg = angles_deg;
+                if sequence.is_symmetric() {
+                    angles_deg[1] = angles_deg[1].abs();
+                    if angles_deg[1] == 0.0 {
+                        // The middle angle of a symmetric sequence is singular at zero.
+                        continue;
+                    }
+                }
+                let dcm = DCM::from_euler_angles(
+                    sequence,
+                    angles_deg.map(|angle| angle.to_radians()),
+                    1,
+                    2,
+                );
+                let recovered = dcm.euler_angles_deg(sequence);
+                assert!(
+                    (recovered - angles_deg).norm() < 1e-9,
+                    "{sequence:?}: {recovered} != {angles_deg}"
+                );
+            }
+
+            // The singular case still rebuilds the same DCM
+            let singular = if sequence.is_symmetric() {
+                Vector3::new(30.0, 0.0, 40.0)
+            } else {
+                Vector3::new(30.0, 90.0, 40.0)
+            };
+            let dcm = DCM::from_euler_angles(sequence, singular.map(|a| a.to_radians()), 1, 2);
+            let rebuilt = DCM::from_euler_angles(sequence, dcm.euler_angles_rad(sequence), 1, 2);
+            assert!(
+                (dcm.rot_mat - rebuilt.rot_mat).norm() < 1e-9,
+                "{sequence:?} singular"
+            );
+        }
+
+        // The aerospace 3-2-1 sequence
+        let dcm = DCM::from_euler_angles(EulerSequence::ZYX, Vector3::new(0.1, 0.2, 0.3), 1, 2);
+        let expected =
+            DCM::r1(0.3, 1, 2).rot_mat * DCM::r2(0.2, 1, 2).rot_mat * DCM::r3(0.1, 1, 2).rot_mat;
+        assert!((dcm.rot_mat - expected).norm() < 1e-15);
+    }
+}
@@ -13,12 +13,18 @@ pub(crate) const EPSILON_RAD: f64 = 4.8e-6;
 /// Equality of f64 for rotations
 pub(crate) const EPSILON: f64 = 1e-12;
 
+mod attitude_error;
 mod dcm;
+mod euler;
 mod mrp;
 mod quaternion;
+mod slew;
+pub use attitude_error::{AttitudeError, AttitudeErrorReport};
 pub use dcm::DCM;
+pub use euler::EulerSequence;
 pub use mrp::MRP;
-pub use quaternion::{EulerParameter, Quaternion};
+pub use quaternion::{EulerParameter, Quaternion, QuaternionOrder, QuaternionSense};
+pub use slew::SlewPlan;
 
 #[cfg(feature = "python")]
 mod dcm_py;
@@ -272,6 +272,99 @@ impl EulerParameter {
     pub fn as_vector(&self) -> Vector4 {
         Vector4::new(self.w, self.x, self.y, self.z)
     }
+
+    /// Builds the quaternion transforming coordinates from the `from` frame to the `to` frame, given its components in the
+    /// provided order and sense, e.g. as exchanged with other software.
+    pub fn from_array(
+        components: [f64; 4],
+        order: QuaternionOrder,
+        sense: QuaternionSense,
+        from: NaifId,
+        to: NaifId,
+    ) -> Self {
+        let (w, x, y, z) = match order {
+            QuaternionOrder::ScalarFirst => {
+                (components[0], components[1], components[2], components[3])
+            }
+            QuaternionOrder::ScalarLast => {
+                (components[3], components[0], components[1], components[2])
+            }
+        };
+        let me = Self::new(w, x, y, z, from, to);
+        match sense {
+            QuaternionSense::Passive => me,
+            // The active quaternion is the conjugate of the passive one, for the same frames.
+            QuaternionSense::Active => Self {
+                x: -me.x,
+                y: -me.y,
+                z: -me.z,
+                ..me
+            },
+        }
+    }
+
+    /// Returns the components of this quaternion in the provided order and sense, e.g. to exchange it with other software.
+    pub fn to_array(&self, order: QuaternionOrder, sense: QuaternionSense) -> [f64; 4] {
+        let (w, x, y, z) = match sense {
+            QuaternionSense::Passive => (self.w, self.x, self.y, self.z),
+            QuaternionSense::Active => (self.w, -self.x, -self.y, -self.z),
+        };
+        match order {
+            QuaternionOrder::ScalarFirst => [w, x, y, z],
+            QuaternionOrder::ScalarLast => [x, y, z, w],
+        }
+    }
+
+    /// Builds this quaternion from a scalar first, active, Hamilton quaternion, as used by SPICE (e.g. `m2q` and C-kernels).
+    pub fn from_hamilton(w: f64, x: f64, y: f64, z: f64, from: NaifId, to: NaifId) -> Self {
+        Self::from_array(
+            [w, x, y, z],
+            QuaternionOrder::ScalarFirst,
+            QuaternionSense::Active,
+            from,
+            to,
+        )
+    }
+
+    /// Builds this quaternion from a scalar last, passive, JPL (or Shuster) quaternion, as used by many flight software and star trackers.
+    pub fn from_jpl(x: f64, y: f64, z: f64, w: f64, from: NaifId, to: NaifId) -> Self {
+        Self::from_array(
+            [x, y, z, w],
+            QuaternionOrder::ScalarLast,
+            QuaternionSense::Passive,
+            from,
+            to,
+        )
+    }
+}
+
+/// Order of the components of a quaternion when exchanged as an array.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, from_py_object, module = "anise.rotation")
+)]
+pub enum QuaternionOrder {
+    /// `[w, x, y, z]`, e.g. SPICE and ANISE
+    ScalarFirst,
+    /// `[x, y, z, w]`, e.g. the JPL convention, Eigen's storage, and scipy
+    ScalarLast,
+}
+
+/// Sense of a quaternion exchanged with other software, i.e. how it transforms the coordinates of a vector `v`
+/// from its `from` frame to its `to` frame. Both senses use the Hamilton product.
+///
+/// For the same frames, the components of the active quaternion are those of the conjugate of the passive quaternion.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, from_py_object, module = "anise.rotation")
+)]
+pub enum QuaternionSense {
+    /// `v_to = q* v_from q`, as in ANISE and identical to the JPL convention
+    Passive,
+    /// `v_to = q v_from q*`, i.e. the vector rotation formula, as in SPICE
+    Active,
 }
 
 #[cfg_attr(feature = "python", pymethods)]
@@ -320,6 +413,20 @@ impl EulerParameter {
         me
     }
 
+    /// Returns the components of this quaternion as a scalar first, active, Hamilton quaternion, as used by SPICE.
+    ///
+    /// :rtype: typing.List
+    pub fn to_hamilton(&self) -> [f64; 4] {
+        self.to_array(QuaternionOrder::ScalarFirst, QuaternionSense::Active)
+    }
+
+    /// Returns the components of this quaternion as a scalar last, passive, JPL (or Shuster) quaternion.
+    ///
+    /// :rtype: typing.List
+    pub fn to_jpl(&self) -> [f64; 4] {
+        self.to_array(QuaternionOrder::ScalarLast, QuaternionSense::Passive)
+    }
+
     /// Returns the short way rotation of this quaternion
     ///
     /// :rtype: Quaternion
@@ -786,4 +893,54 @@ mod ut_quaternion {
         let rotated = q * v;
         assert!((rotated - Vector3::x()).norm() < EPSILON);
     }
+
+    #[test]
+    fn test_conventions() {
+        use super::{QuaternionOrder, QuaternionSense};
+
+        for angle_rad in generate_angles() {
+            let (s, c) = (angle_rad / 2.0).sin_cos();
+            let q = Quaternion::about_z(angle_rad, 0, 1);
+
+            // SPICE's m2q of ROTATE(angle, 3) is (cos(angle/2), 0, 0, -sin(angle/2)).
+            let spice = Quaternion::from_hamilton(c, 0.0, 0.0, -s, 0, 1);
+            let jpl = Quaternion::from_jpl(0.0, 0.0, s, c, 0, 1);
+            assert_eq!(spice, q, "{spice} != {q}");
+            assert_eq!(jpl, q, "{jpl} != {q}");
+
+            let q = q.short();
+            let hamilton = q.to_hamilton();
+            assert!((Vector4::from(hamilton) - Vector4::new(q.w, 0.0, 0.0, -q.z)).norm() < EPSILON);
+            assert!(
+                (Vector4::from(q.to_jpl()) - Vector4::new(0.0, 0.0, q.z, q.w)).norm() < EPSILON
+            );
+
+            // The Hamilton quaternion rotates vectors actively: v' = q ⊗ v ⊗ q*
+            let hamilton_product = |a: [f64; 4], b: [f64; 4]| {
+                [
+                    a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3],
+                    a[0] * b[1] + a[1] * b[0] + a[2] * b[3] - a[3] * b[2],
+                    a[0] * b[2] - a[1] * b[3] + a[2] * b[0] + a[3] * b[1],
+                    a[0] * b[3] + a[1] * b[2] - a[2] * b[1] + a[3] * b[0],
+                ]
+            };
+            let v = Vector3::new(1.0, 2.0, 3.0);
+            let conj = [hamilton[0], -hamilton[1], -hamilton[2], -hamilton[3]];
+            let rotated = hamilton_product(hamilton_product(hamilton, [0.0, v.x, v.y, v.z]), conj);
+            vec3_eq(
+                Vector3::new(rotated[1], rotated[2], rotated[3]),
+                DCM::from(q) * v,
+                "active".to_string(),
+            );
+            vec3_eq(DCM::from(q) * v, q * v, "passive".to_string());
+
+            // Round trips in all conventions
+            for order in [QuaternionOrder::ScalarFirst, QuaternionOrder::ScalarLast] {
+                for sense in [QuaternionSense::Passive, QuaternionSense::Active] {
+                    let back = Quaternion::from_array(q.to_array(order, sense), order, sense, 0, 1);
+                    assert_eq!(back, q);
+                }
+            }
+        }
+    }
 }
@@ -8,7 +8,7 @@
  * Documentation: https://nyxspace.com/
  */
 
-use super::{EulerParameter, DCM};
+use super::{EulerParameter, QuaternionOrder, QuaternionSense, DCM};
 use crate::astro::PhysicsResult;
 use crate::NaifId;
 
@@ -80,6 +80,84 @@ impl EulerParameter {
         Self::about_z(angle_rad, from_id, to_id)
     }
 
+    /// Builds this quaternion from a scalar first, active, Hamilton quaternion, as used by SPICE (e.g. `m2q` and C-kernels).
+    ///
+    /// :type w: float
+    /// :type x: float
+    /// :type y: float
+    /// :type z: float
+    /// :type from_id: int
+    /// :type to_id: int
+    /// :rtype: Quaternion
+    #[classmethod]
+    #[pyo3(name="from_hamilton", signature=(w, x, y, z, from_id, to_id))]
+    fn py_from_hamilton(
+        _cls: &Bound<'_, PyType>,
+        w: f64,
+        x: f64,
+        y: f64,
+        z: f64,
+        from_id: NaifId,
+        to_id: NaifId,
+    ) -> Self {
+        Self::from_hamilton(w, x, y, z, from_id, to_id)
+    }
+
+    /// Builds this quaternion from a scalar last, passive, JPL (or Shuster) quaternion, as used by many flight software and star trackers.
+    ///
+    /// :type x: float
+    /// :type y: float
+    /// :type z: float
+    /// :type w: float
+    /// :type from_id: int
+    /// :type to_id: int
+    /// :rtype: Quaternion
+    #[classmethod]
+    #[pyo3(name="from_jpl", signature=(x, y, z, w, from_id, to_id))]
+    fn py_from_jpl(
+        _cls: &Bound<'_, PyType>,
+        x: f64,
+        y: f64,
+        z: f64,
+        w: f64,
+        from_id: NaifId,
+        to_id: NaifId,
+    ) -> Self {
+        Self::from_jpl(x, y, z, w, from_id, to_id)
+    }
+
+    /// Builds the quaternion transforming coordinates from the `from_id` frame to the `to_id` frame, given its components in the
+    /// provided order and sense, e.g. as exchanged with other software.
+    ///
+    /// :type components: typing.List
+    /// :type order: QuaternionOrder
+    /// :type sense: QuaternionSense
+    /// :type from_id: int
+    /// :type to_id: int
+    /// :rtype: Quaternion
+    #[classmethod]
+    #[pyo3(name="from_array", signature=(components, order, sense, from_id, to_id))]
+    fn py_from_array(
+        _cls: &Bound<'_, PyType>,
+        components: [f64; 4],
+        order: QuaternionOrder,
+        sense: QuaternionSense,
+        from_id: NaifId,
+        to_id: NaifId,
+    ) -> Self {
+        Self::from_array(components, order, sense, from_id, to_id)
+    }
+
+    /// Returns the components of this quaternion in the provided order and sense, e.g. to exchange it with other software.
+    ///
+    /// :type order: QuaternionOrder
+    /// :type sense: QuaternionSense
+    /// :rtype: typing.List
+    #[pyo3(name = "to_array", signature=(order, sense))]
+    fn py_to_array(&self, order: QuaternionOrder, sense: QuaternionSense) -> [f64; 4] {
+        self.to_array(order, sense)
+    }
+
     /// Returns the euler parameter derivative for this EP and the body angular velocity vector w
     /// dQ/dt = 1/2 [B(Q)] omega_rad_s
     ///
@@ -0,0 +1,325 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch, TimeSeries, Unit};
+use snafu::ensure;
+
+use super::Quaternion;
+use crate::astro::PhysicsResult;
+use crate::errors::{InvalidRotationSnafu, SlewDurationSnafu, SlewLimitsSnafu};
+use crate::math::Vector3;
+
+/// Rest-to-rest eigen-axis slew between two orientations, with a trapezoidal angular rate profile: constant acceleration up to the
+/// peak rate, an optional coast at the peak rate, and constant deceleration down to rest.
+///
+/// Both orientations rotate from the same reference frame to the body frame, and the eigen-axis is expressed in the body frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SlewPlan {
+    pub initial: Quaternion,
+    pub target: Quaternion,
+    pub start_epoch: Epoch,
+    /// Unit vector of the eigen-axis, in the body frame, or zero if both orientations are identical
+    pub axis: Vector3,
+    /// Angle of the slew about the eigen-axis, between 0 and 180 degrees
+    pub angle_deg: f64,
+    /// Peak angular rate about the eigen-axis, reached at the end of the acceleration
+    pub peak_rate_deg_s: f64,
+    /// Angular acceleration magnitude during the acceleration and the deceleration
+    pub accel_deg_s2: f64,
+    /// Duration of each of the acceleration and the deceleration
+    pub accel_duration: Duration,
+    /// Duration of the coast at the peak rate, between the acceleration and the deceleration
+    pub coast_duration: Duration,
+}
+
+impl SlewPlan {
+    /// Plans the shortest slew from the initial to the target orientation starting at the provided epoch, within the rate and acceleration limits.
+    pub fn new(
+        initial: Quaternion,
+        target: Quaternion,
+        start_epoch: Epoch,
+        max_rate_deg_s: f64,
+        max_accel_deg_s2: f64,
+    ) -> PhysicsResult<Self> {
+        let (axis, angle_rad) = eigen_axis(initial, target, max_rate_deg_s, max_accel_deg_s2)?;
+        let max_rate = max_rate_deg_s.to_radians();
+        let accel = max_accel_deg_s2.to_radians();
+
+        // The peak rate is not reached if the slew is too short: the profile is then triangular.
+        let (peak_rate, accel_s, coast_s) = if angle_rad < max_rate.powi(2) / accel {
+            let accel_s = (angle_rad / accel).sqrt();
+            (accel * accel_s, accel_s, 0.0)
+        } else {
+            let accel_s = max_rate / accel;
+            (max_rate, accel_s, angle_rad / max_rate - accel_s)
+        };
+
+        Ok(Self {
+            initial,
+            target,
+            start_epoch,
+            axis,
+            angle_deg: angle_rad.to_degrees(),
+            peak_rate_deg_s: peak_rate.to_degrees(),
+            accel_deg_s2: max_accel_deg_s2,
+            accel_duration: Unit::Second * accel_s,
+            coast_duration: Unit::Second * coast_s,
+        })
+    }
+
+    /// Plans the slew from the initial orientation at the start epoch to the target orientation at the end epoch, using the lowest peak
+    /// rate which fits the rate and acceleration limits. Returns an error if the slew cannot be completed in time.
+    pub fn between(
+        initial: Quaternion,
+        start_epoch: Epoch,
+        target: Quaternion,
+        end_epoch: Epoch,
+        max_rate_deg_s: f64,
+        max_accel_deg_s2: f64,
+    ) -> PhysicsResult<Self> {
+        let fastest = Self::new(
+            initial,
+            target,
+            start_epoch,
+            max_rate_deg_s,
+            max_accel_deg_s2,
+        )?;
+        let duration = end_epoch - start_epoch;
+        ensure!(
+            fastest.duration() <= duration,
+            SlewDurationSnafu {
+                angle_deg: fastest.angle_deg,
+                min_duration: fastest.duration(),
+                duration
+            }
+        );
+
+        // Solve angle = rate * (duration - rate / accel) for the lowest rate.
+        let angle_rad = fastest.angle_deg.to_radians();
+        let accel = max_accel_deg_s2.to_radians();
+        let duration_s = duration.to_seconds();
+        let discriminant = (accel * duration_s).powi(2) - 4.0 * accel * angle_rad;
+        let peak_rate = (accel * duration_s - discriminant.max(0.0).sqrt()) / 2.0;
+        let accel_s = peak_rate / accel;
+
+        Ok(Self {
+            peak_rate_deg_s: peak_rate.to_degrees(),
+            accel_duration: Unit::Second * accel_s,
+            coast_duration: Unit::Second * (duration_s - 2.0 * accel_s).max(0.0),
+            ..fastest
+        })
+    }
+
+    /// Returns the total duration of the slew.
+    pub fn duration(&self) -> Duration {
+        self.accel_duration * 2 + self.coast_duration
+    }
+
+    /// Returns the epoch at which the target orientation is reached.
+    pub fn end_epoch(&self) -> Epoch {
+        self.start_epoch + self.duration()
+    }
+
+    /// Returns the orientation and the angular velocity in deg/s of the body frame, in the body frame, at the provided epoch.
+    /// The orientation is the initial one before the slew, and the target one after it, with a zero angular velocity.
+    pub fn state_at(&self, epoch: Epoch) -> (Quaternion, Vector3) {
+        let t = (epoch - self.start_epoch)
+            .to_seconds()
+            .clamp(0.0, self.duration().to_seconds());
+        let accel = self.accel_deg_s2.to_radians();
+        let peak_rate = self.peak_rate_deg_s.to_radians();
+        let accel_s = self.accel_duration.to_seconds();
+        let coast_s = self.coast_duration.to_seconds();
+        let total_s = 2.0 * accel_s + coast_s;
+
+        let (angle_rad, rate_rad_s) = if t < accel_s {
+            (0.5 * accel * t * t, accel * t)
+        } else if t < accel_s + coast_s {
+            (
+                0.5 * peak_rate * accel_s + peak_rate * (t - accel_s),
+                peak_rate,
+            )
+        } else {
+            let remaining = total_s - t;
+            (
+                self.angle_deg.to_radians() - 0.5 * accel * remaining * remaining,
+                accel * remaining,
+            )
+        };
+
+        if t >= total_s {
+            return (self.target, Vector3::zeros());
+        }
+
+        let (s, c) = (angle_rad / 2.0).sin_cos();
+        let delta = Quaternion {
+            w: c,
+            x: s * self.axis.x,
+            y: s * self.axis.y,
+            z: s * self.axis.z,
+            from: self.initial.to,
+            to: self.initial.to,
+        };
+        let attitude = (delta * self.initial)
+            .expect("slew rotation is compatible with the initial orientation");
+
+        (attitude, (rate_rad_s * self.axis).map(f64::to_degrees))
+    }
+
+    /// Samples the slew from its start to its end epoch, both included, at the provided step, returning the epoch, the orientation,
+    /// and the angular velocity in deg/s of the body frame at each sample, cf. [SlewPlan::state_at].
+    pub fn profile(&self, step: Duration) -> Vec<(Epoch, Quaternion, Vector3)> {
+        TimeSeries::inclusive(self.start_epoch, self.end_epoch(), step)
+            .chain(core::iter::once(self.end_epoch()))
+            .scan(None, |prev: &mut Option<Epoch>, epoch| {
+                // The end epoch is appended if the step does not land on it.
+                let keep = *prev != Some(epoch);
+                *prev = Some(epoch);
+                Some(keep.then_some(epoch))
+            })
+            .flatten()
+            .map(|epoch| {
+                let (attitude, rate_deg_s) = self.state_at(epoch);
+                (epoch, attitude, rate_deg_s)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SlewPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slew of {:.6} deg from {} to {} ({}), peak rate {:.6} deg/s",
+            self.angle_deg,
+            self.start_epoch,
+            self.end_epoch(),
+            self.duration(),
+            self.peak_rate_deg_s
+        )
+    }
+}
+
+/// Returns the eigen-axis and the short way angle in radians of the rotation from the initial to the target orientation.
+fn eigen_axis(
+    initial: Quaternion,
+    target: Quaternion,
+    max_rate_deg_s: f64,
+    max_accel_deg_s2: f64,
+) -> PhysicsResult<(Vector3, f64)> {
+    ensure!(
+        initial.from == target.from && initial.to == target.to,
+        InvalidRotationSnafu {
+            action: "plan slew",
+            from1: initial.from,
+            to1: initial.to,
+            from2: target.from,
+            to2: target.to
+        }
+    );
+    ensure!(
+        max_rate_deg_s > 0.0 && max_accel_deg_s2 > 0.0,
+        SlewLimitsSnafu {
+            max_rate_deg_s,
+            max_accel_deg_s2
+        }
+    );
+
+    let delta = (target * initial.conjugate())?.short();
+    let (axis, angle_rad) = delta.uvec_angle_rad();
+    if axis.norm() == 0.0 {
+        Ok((axis, 0.0))
+    } else {
+        Ok((axis, angle_rad))
+    }
+}
+
+#[cfg(test)]
+mod ut_slew {
+    use super::SlewPlan;
+    use crate::math::rotation::Quaternion;
+    use crate::math::Vector3;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn eigen_axis_slew() {
+        let start = Epoch::from_et_seconds(0.0);
+        let initial = Quaternion::about_x(0.3, 1, -85000);
+        let target =
+            (Quaternion::about_z(90.0_f64.to_radians(), -85000, -85000) * initial).unwrap();
+
+        // 90 degrees at up to 1 deg/s and 0.1 deg/s^2: 10 s of acceleration, 80 s of coast, and 10 s of deceleration.
+        let plan = SlewPlan::new(initial, target, start, 1.0, 0.1).unwrap();
+        assert!((plan.angle_deg - 90.0).abs() < 1e-9);
+        assert!((plan.axis - Vector3::z()).norm() < 1e-12);
+        assert!((plan.accel_duration.to_seconds() - 10.0).abs() < 1e-6);
+        assert!((plan.coast_duration.to_seconds() - 80.0).abs() < 1e-6);
+        assert!((plan.end_epoch() - (start + Unit::Second * 100)).abs() < Unit::Microsecond * 1);
+
+        let (attitude, rate) = plan.state_at(start - Unit::Second * 1);
+        assert_eq!(attitude, initial);
+        assert_eq!(rate, Vector3::zeros());
+        let (attitude, rate) = plan.state_at(start + Unit::Second * 50);
+        assert_eq!(
+            attitude,
+            (Quaternion::about_z(45.0_f64.to_radians(), -85000, -85000) * initial).unwrap()
+        );
+        assert!((rate - Vector3::new(0.0, 0.0, 1.0)).norm() < 1e-12);
+        assert_eq!(plan.state_at(plan.end_epoch()).0, target);
+
+        // The angular velocity is consistent with the time derivative of the quaternion
+        let epoch = start + Unit::Second * 5;
+        let h = Unit::Millisecond * 1;
+        let (q, rate_deg_s) = plan.state_at(epoch);
+        let (prev, _) = plan.state_at(epoch - h);
+        let (next, _) = plan.state_at(epoch + h);
+        let dq = q.derivative(rate_deg_s.map(f64::to_radians));
+        let dq_fd = (next.as_vector() - prev.as_vector()) / (2.0 * h.to_seconds());
+        assert!((dq.as_vector() - dq_fd).norm() < 1e-9, "{dq} vs {dq_fd}");
+
+        // The profile includes the end epoch, and the rate never exceeds the limit
+        let profile = plan.profile(Unit::Second * 30);
+        assert_eq!(profile.len(), 5);
+        assert_eq!(profile.last().unwrap().1, target);
+        assert!(profile
+            .iter()
+            .all(|(_, _, rate)| rate.norm() <= 1.0 + 1e-12));
+
+        // A short slew never reaches the rate limit
+        let short_target =
+            (Quaternion::about_z(0.1_f64.to_radians(), -85000, -85000) * initial).unwrap();
+        let short = SlewPlan::new(initial, short_target, start, 1.0, 0.1).unwrap();
+        assert_eq!(short.coast_duration, Unit::Second * 0);
+        assert!((short.duration().to_seconds() - 2.0).abs() < 1e-6);
+
+        // A slew between two epochs uses the available time
+        let slow = SlewPlan::between(initial, start, target, start + Unit::Second * 200, 1.0, 0.1)
+            .unwrap();
+        assert!((slow.duration().to_seconds() - 200.0).abs() < 1e-6);
+        assert!(slow.peak_rate_deg_s < 0.5);
+        assert_eq!(slow.state_at(slow.end_epoch()).0, target);
+        // Both profiles are symmetric, so they are half way at half of their durations
+        assert_eq!(
+            slow.state_at(start + Unit::Second * 100).0,
+            plan.state_at(start + Unit::Second * 50).0
+        );
+        assert!(
+            SlewPlan::between(initial, start, target, start + Unit::Second * 60, 1.0, 0.1).is_err()
+        );
+
+        // Identical orientations, invalid limits, and mismatched frames
+        let still = SlewPlan::new(initial, initial, start, 1.0, 0.1).unwrap();
+        assert_eq!(still.duration(), Unit::Second * 0);
+        assert_eq!(still.state_at(start).0, initial);
+        assert!(SlewPlan::new(initial, target, start, 0.0, 0.1).is_err());
+        assert!(SlewPlan::new(initial, initial.conjugate(), start, 1.0, 0.1).is_err());
+    }
+}
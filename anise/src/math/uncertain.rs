@@ -0,0 +1,55 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+/// A nominal value with its 1σ uncertainty, as propagated to first order from the uncertainty of its inputs.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Uncertain {
+    pub nominal: f64,
+    /// One standard deviation, zero if the inputs are exact
+    pub sigma: f64,
+}
+
+impl Uncertain {
+    pub const fn new(nominal: f64, sigma: f64) -> Self {
+        Self { nominal, sigma }
+    }
+
+    /// Returns the nominal value minus one sigma
+    pub fn lower_1sigma(&self) -> f64 {
+        self.nominal - self.sigma
+    }
+
+    /// Returns the nominal value plus one sigma
+    pub fn upper_1sigma(&self) -> f64 {
+        self.nominal + self.sigma
+    }
+
+    /// Returns the relative uncertainty, i.e. sigma divided by the magnitude of the nominal value
+    pub fn relative(&self) -> f64 {
+        self.sigma / self.nominal.abs()
+    }
+
+    /// Returns the power law `nominal^exponent` of this value, whose relative uncertainty is scaled by the exponent.
+    pub fn powf(&self, exponent: f64) -> Self {
+        let nominal = self.nominal.powf(exponent);
+        Self {
+            nominal,
+            sigma: (exponent * self.relative() * nominal).abs(),
+        }
+    }
+}
+
+impl fmt::Display for Uncertain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ± {} (1σ)", self.nominal, self.sigma)
+    }
+}
@@ -62,3 +62,32 @@ impl Display for LengthUnit {
         }
     }
 }
+
+/// Defines the angular units of the angles output by ANISE, which are computed in degrees.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub enum AngleUnit {
+    #[default]
+    Degree,
+    Radian,
+}
+
+impl AngleUnit {
+    /// Returns the conversion factor from degrees to this angular unit.
+    /// E.g. To convert degrees into Self::Radian, multiply by PI / 180.
+    #[must_use]
+    pub const fn from_degrees(&self) -> f64 {
+        match self {
+            Self::Degree => 1.0,
+            Self::Radian => core::f64::consts::PI / 180.0,
+        }
+    }
+}
+
+impl Display for AngleUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Degree => write!(f, "deg"),
+            Self::Radian => write!(f, "rad"),
+        }
+    }
+}
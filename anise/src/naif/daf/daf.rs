@@ -41,6 +41,10 @@ macro_rules! io_imports {
 io_imports!();
 
 pub(crate) const RCRD_LEN: usize = 1024;
+/// Number of characters of comments stored in each reserved record of a DAF.
+pub(crate) const COMMENT_RCRD_LEN: usize = 1000;
+/// End of transmission character, marking the end of the comment area.
+pub(crate) const COMMENT_EOT: u8 = 0x04;
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct DAF<R: NAIFSummaryRecord> {
     pub bytes: BytesMut,
@@ -425,8 +429,11 @@ impl<R: NAIFSummaryRecord> DAF<R> {
         S::from_f64_slice(data).context(DecodingDataSnafu { kind: R::NAME, idx })
     }
 
+    /// Returns the decoded text of the comment area of this DAF, if any.
+    ///
+    /// The comment area is stored in the reserved records between the file record and the first summary record.
+    /// Each of these records holds up to 1000 characters, lines are separated by a null character, and the end of the comments is marked by an EOT character.
     pub fn comments(&self) -> Result<Option<String>, DAFError> {
-        let mut rslt = String::new();
         // FWRD has the initial record of the summary. So we assume that all records between the second record and that one are comments
         // Note: fwrd_idx is 1-based index of the first summary record. So records < fwrd_idx (starting at 2) are comments.
         // In 0-based indexing (where Rec 1 is index 0), comments are at indices 1 .. fwrd_idx-1.
@@ -437,13 +444,15 @@ impl<R: NAIFSummaryRecord> DAF<R> {
         let end_idx = self.file_record()?.fwrd_idx();
         let loop_end = if end_idx > 1 { end_idx - 1 } else { 1 };
 
+        let mut raw = Vec::new();
         for rid in 1..loop_end {
+            // Only the first 1000 characters of each record are part of the comment area.
             let bytes_slice = match self
                 .bytes
-                .get(rid * RCRD_LEN..(rid + 1) * RCRD_LEN)
+                .get(rid * RCRD_LEN..rid * RCRD_LEN + COMMENT_RCRD_LEN)
                 .ok_or_else(|| DecodingError::InaccessibleBytes {
                     start: rid * RCRD_LEN,
-                    end: (rid + 1) * RCRD_LEN,
+                    end: rid * RCRD_LEN + COMMENT_RCRD_LEN,
                     size: self.bytes.len(),
                 }) {
                 Ok(it) => it,
@@ -455,43 +464,21 @@ impl<R: NAIFSummaryRecord> DAF<R> {
                 }
             };
 
-            let s = match core::str::from_utf8(bytes_slice) {
-                Ok(s) => s,
-                Err(e) => {
-                    // At this point, we know that the bytes are accessible because the embedded `match`
-                    // did not fail, so we can perform a direct access.
-                    core::str::from_utf8(&bytes_slice[..e.valid_up_to()]).unwrap()
-                }
-            };
-
-            // Optimization: Avoid allocating intermediate strings.
-            // Identify the start and end of meaningful content (skipping whitespace and nulls).
-            // Then append the content, replacing nulls with newlines.
-
-            // Find first non-padding char
-            if let Some((start, _)) = s
-                .char_indices()
-                .find(|(_, c)| !c.is_whitespace() && *c != '\0')
-            {
-                // Find last non-padding char
-                // safe to unwrap because we found at least one char above
-                let (end_idx, end_char) = s
-                    .char_indices()
-                    .rev()
-                    .find(|(_, c)| !c.is_whitespace() && *c != '\0')
-                    .unwrap();
-                let end = end_idx + end_char.len_utf8();
-
-                for c in s[start..end].chars() {
-                    if c == '\0' {
-                        rslt.push('\n');
-                    } else {
-                        rslt.push(c);
-                    }
+            // Stop at the end of transmission marker, if any.
+            match bytes_slice.iter().position(|b| *b == COMMENT_EOT) {
+                Some(eot) => {
+                    raw.extend_from_slice(&bytes_slice[..eot]);
+                    break;
                 }
+                None => raw.extend_from_slice(bytes_slice),
             }
         }
 
+        // Skip the padding (whitespace and nulls) around the comments, and replace the line separators (nulls) with new lines.
+        let rslt = String::from_utf8_lossy(&raw)
+            .trim_matches(|c: char| c.is_whitespace() || c == '\0')
+            .replace('\0', "\n");
+
         if rslt.is_empty() {
             Ok(None)
         } else {
@@ -739,4 +726,113 @@ mod daf_ut {
             panic!("No comments found!");
         }
     }
+
+    #[test]
+    fn test_set_comments() {
+        use crate::naif::daf::{FileRecord, NAIFRecord, NAIFSummaryRecord, SummaryRecord};
+        use crate::naif::spk::summary::SPKSummaryRecord;
+        use zerocopy::IntoBytes;
+
+        // Record 1: File Record, Record 2: Summary, Record 3: Name, Record 4: Data (16 doubles)
+        let mut file_record = FileRecord::spk("TEST");
+        file_record.free_addr = 3 * 128 + 17;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(file_record.as_bytes());
+        bytes.resize(1024, 0);
+
+        let summary_rcrd = SummaryRecord {
+            next_record: 0.0,
+            prev_record: 0.0,
+            num_summaries: 1.0,
+        };
+        let data_summary = SPKSummaryRecord {
+            start_epoch_et_s: 0.0,
+            end_epoch_et_s: 86400.0,
+            target_id: 399,
+            center_id: 3,
+            frame_id: 1,
+            data_type_i: 9,
+            start_idx: 3 * 128 + 1,
+            end_idx: 3 * 128 + 16,
+        };
+        let mut rcrd = vec![0u8; 1024];
+        rcrd[..SummaryRecord::SIZE].copy_from_slice(summary_rcrd.as_bytes());
+        rcrd[SummaryRecord::SIZE..SummaryRecord::SIZE + SPKSummaryRecord::SIZE]
+            .copy_from_slice(data_summary.as_bytes());
+        bytes.extend(rcrd);
+        bytes.extend(vec![0u8; 1024]);
+        let data: Vec<f64> = (0..16).map(f64::from).collect();
+        let mut data_rcrd = vec![0u8; 1024];
+        data_rcrd[..16 * 8].copy_from_slice(data.as_bytes());
+        bytes.extend(data_rcrd);
+
+        let mut daf = super::DAF::<SPKSummaryRecord>::parse(&bytes[..]).unwrap();
+        assert_eq!(daf.comments().unwrap(), None);
+
+        let read_data = |daf: &super::DAF<SPKSummaryRecord>| -> Vec<f64> {
+            let summary = daf.data_summaries(None).unwrap()[0];
+            let start = (summary.start_index() - 1) * 8;
+            let end = summary.end_index() * 8;
+            daf.bytes[start..end]
+                .chunks(8)
+                .map(|b| f64::from_ne_bytes(b.try_into().unwrap()))
+                .collect()
+        };
+
+        // Comments spanning two reserved records
+        let long_comment = format!("Provenance: unit test\n{}\nEnd.", "x".repeat(1500));
+        daf.set_comments(&long_comment).unwrap();
+        assert_eq!(daf.comments().unwrap().unwrap(), long_comment);
+        assert_eq!(daf.file_record().unwrap().forward, 4);
+        assert_eq!(daf.file_record().unwrap().backward, 4);
+        assert_eq!(daf.file_record().unwrap().free_addr, 5 * 128 + 17);
+        assert_eq!(
+            daf.data_summaries(None).unwrap()[0].start_index(),
+            5 * 128 + 1
+        );
+        assert_eq!(read_data(&daf), data);
+
+        // Appending keeps the previous comments
+        daf.append_comments("Appended line").unwrap();
+        assert_eq!(
+            daf.comments().unwrap().unwrap(),
+            format!("{long_comment}\nAppended line")
+        );
+        assert_eq!(read_data(&daf), data);
+
+        // Shrinking the comment area
+        daf.set_comments("Short").unwrap();
+        assert_eq!(daf.comments().unwrap().unwrap(), "Short");
+        assert_eq!(daf.file_record().unwrap().forward, 3);
+        assert_eq!(read_data(&daf), data);
+
+        // Removing the comments entirely restores the original file
+        daf.set_comments("").unwrap();
+        assert_eq!(daf.comments().unwrap(), None);
+        assert_eq!(&daf.bytes[..], &bytes[..]);
+
+        // Non ASCII comments are rejected
+        assert_eq!(
+            daf.set_comments("Δv"),
+            Err(DAFError::InvalidComment {
+                kind: "SPKSummaryRecord",
+                found: 'Δ'
+            })
+        );
+
+        // A malformed file record pointing to no summary record is rejected instead of underflowing.
+        let mut malformed = bytes.clone();
+        let mut bad_record = file_record.clone();
+        bad_record.forward = 0;
+        malformed[..FileRecord::SIZE].copy_from_slice(bad_record.as_bytes());
+        let mut daf = super::DAF::<SPKSummaryRecord>::parse(&malformed[..]).unwrap();
+        assert_eq!(
+            daf.set_comments("Provenance"),
+            Err(DAFError::InvalidIndex {
+                kind: "SPKSummaryRecord",
+                idx: 0
+            })
+        );
+    }
 }
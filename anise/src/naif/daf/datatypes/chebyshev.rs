@@ -35,7 +35,7 @@ impl Type2ChebyshevSet<'_> {
         (self.rsize - 2) / 3 - 1
     }
 
-    fn spline_idx<S: NAIFSummaryRecord>(
+    pub(crate) fn spline_idx<S: NAIFSummaryRecord>(
         &self,
         epoch: Epoch,
         summary: &S,
@@ -59,6 +59,123 @@ impl Type2ChebyshevSet<'_> {
 
         Ok(((ephem_start_delta_s / window_duration_s) as usize + 1).min(self.num_records))
     }
+
+    /// Evaluates the n-th record of this set at the provided epoch, even if that epoch is outside of the record's interval.
+    pub fn evaluate_record(
+        &self,
+        n: usize,
+        epoch: Epoch,
+    ) -> Result<(Vector3, Vector3), InterpolationError> {
+        let window_duration_s = self.interval_length.to_seconds();
+        let radius_s = window_duration_s / 2.0;
+
+        // Now, build the X, Y, Z data from the record data.
+        let record = self.nth_record(n).context(InterpDecodingSnafu)?;
+
+        let normalized_time = (epoch.to_et_seconds() - record.midpoint_et_s) / radius_s;
+
+        let mut state = Vector3::zeros();
+        let mut rate = Vector3::zeros();
+
+        for (cno, coeffs) in [record.x_coeffs, record.y_coeffs, record.z_coeffs]
+            .iter()
+            .enumerate()
+        {
+            let (val, deriv) =
+                chebyshev_eval(normalized_time, coeffs, radius_s, epoch, self.degree())?;
+            state[cno] = val;
+            rate[cno] = deriv;
+        }
+
+        Ok((state, rate))
+    }
+
+    /// Returns the start epoch of each record of this set followed by the end epoch of the last record, i.e. all of the record edges.
+    pub fn record_boundaries(&self) -> Result<Vec<Epoch>, DecodingError> {
+        let mut boundaries = Vec::with_capacity(self.num_records + 1);
+        for n in 0..self.num_records {
+            let record = self.nth_record(n)?;
+            boundaries.push(record.midpoint_epoch() - record.radius);
+            if n + 1 == self.num_records {
+                boundaries.push(record.midpoint_epoch() + record.radius);
+            }
+        }
+        Ok(boundaries)
+    }
+
+    /// Returns the jump in position and velocity at each boundary between consecutive records, computed as the evaluation
+    /// of the next record minus the evaluation of the previous record at the end epoch of the previous record.
+    pub fn record_boundary_jumps(
+        &self,
+    ) -> Result<Vec<(Epoch, Vector3, Vector3)>, InterpolationError> {
+        let radius_s = self.interval_length.to_seconds() / 2.0;
+        let mut jumps = Vec::with_capacity(self.num_records.saturating_sub(1));
+        for n in 1..self.num_records {
+            let prev = self.nth_record(n - 1).context(InterpDecodingSnafu)?;
+            let boundary = Epoch::from_et_seconds(prev.midpoint_et_s + radius_s);
+            let (prev_pos, prev_vel) = self.evaluate_record(n - 1, boundary)?;
+            let (next_pos, next_vel) = self.evaluate_record(n, boundary)?;
+            jumps.push((boundary, next_pos - prev_pos, next_vel - prev_vel));
+        }
+        Ok(jumps)
+    }
+
+    /// Returns the spectrum of the Chebyshev coefficients of each record of this set.
+    pub fn coefficient_spectrum(&self) -> Result<Vec<ChebyshevSpectrum>, DecodingError> {
+        (0..self.num_records)
+            .map(|n| {
+                let record = self.nth_record(n)?;
+                Ok(ChebyshevSpectrum::from_coeffs(
+                    record.midpoint_epoch(),
+                    record.x_coeffs,
+                    record.y_coeffs,
+                    record.z_coeffs,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Magnitudes of the Chebyshev coefficients of a single record, used to assess how quickly the series converges.
+///
+/// Since every Chebyshev polynomial is bounded by one on the record interval, the magnitude of the coefficients of the highest degree
+/// is an estimate of the truncation error of this record: a well sampled record has coefficients decaying down to the noise floor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChebyshevSpectrum {
+    pub midpoint_epoch: Epoch,
+    /// Norm of the X, Y, Z coefficients of each degree, from degree zero up to the degree of the set, in the units of the data
+    pub magnitudes: Vec<f64>,
+}
+
+impl ChebyshevSpectrum {
+    /// Builds the spectrum from the coefficients of each axis, which must all have the same length.
+    pub fn from_coeffs(midpoint_epoch: Epoch, x: &[f64], y: &[f64], z: &[f64]) -> Self {
+        let magnitudes = x
+            .iter()
+            .zip(y)
+            .zip(z)
+            .map(|((x, y), z)| Vector3::new(*x, *y, *z).norm())
+            .collect();
+        Self {
+            midpoint_epoch,
+            magnitudes,
+        }
+    }
+
+    /// Returns the estimated truncation error of this record, i.e. the magnitude of the highest degree coefficients.
+    pub fn truncation_error(&self) -> f64 {
+        self.magnitudes.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the ratio of the highest degree magnitude to the degree one magnitude, or None if the degree is below two
+    /// or if the degree one coefficients are zero. Small ratios indicate a quickly converging series.
+    pub fn decay_ratio(&self) -> Option<f64> {
+        if self.magnitudes.len() < 3 || self.magnitudes[1] == 0.0 {
+            None
+        } else {
+            Some(self.truncation_error() / self.magnitudes[1])
+        }
+    }
 }
 
 impl fmt::Display for Type2ChebyshevSet<'_> {
@@ -153,30 +270,7 @@ impl<'a> NAIFDataSet<'a> for Type2ChebyshevSet<'a> {
     ) -> Result<(Vector3, Vector3), InterpolationError> {
         let spline_idx = self.spline_idx(epoch, summary)?;
 
-        let window_duration_s = self.interval_length.to_seconds();
-        let radius_s = window_duration_s / 2.0;
-
-        // Now, build the X, Y, Z data from the record data.
-        let record = self
-            .nth_record(spline_idx - 1)
-            .context(InterpDecodingSnafu)?;
-
-        let normalized_time = (epoch.to_et_seconds() - record.midpoint_et_s) / radius_s;
-
-        let mut state = Vector3::zeros();
-        let mut rate = Vector3::zeros();
-
-        for (cno, coeffs) in [record.x_coeffs, record.y_coeffs, record.z_coeffs]
-            .iter()
-            .enumerate()
-        {
-            let (val, deriv) =
-                chebyshev_eval(normalized_time, coeffs, radius_s, epoch, self.degree())?;
-            state[cno] = val;
-            rate[cno] = deriv;
-        }
-
-        Ok((state, rate))
+        self.evaluate_record(spline_idx - 1, epoch)
     }
 
     fn check_integrity(&self) -> Result<(), IntegrityError> {
@@ -392,4 +486,19 @@ mod chebyshev_ut {
         assert_eq!(state[1], 20.0);
         assert_eq!(state[2], 200.0);
     }
+
+    #[test]
+    fn spectrum_decay() {
+        use super::ChebyshevSpectrum;
+
+        let spectrum = ChebyshevSpectrum::from_coeffs(
+            Epoch::from_et_seconds(0.0),
+            &[7000.0, 300.0, 4.0, 1e-3],
+            &[0.0, 400.0, 3.0, 0.0],
+            &[0.0, 0.0, 0.0, 0.0],
+        );
+        assert_eq!(spectrum.magnitudes, vec![7000.0, 500.0, 5.0, 1e-3]);
+        assert_eq!(spectrum.truncation_error(), 1e-3);
+        assert_eq!(spectrum.decay_ratio(), Some(1e-3 / 500.0));
+    }
 }
@@ -21,6 +21,8 @@ use crate::{
     naif::daf::{NAIFDataRecord, NAIFDataSet, NAIFSummaryRecord},
 };
 
+use super::ChebyshevSpectrum;
+
 #[derive(PartialEq)]
 pub struct Type3ChebyshevSet<'a> {
     pub init_epoch: Epoch,
@@ -35,7 +37,7 @@ impl Type3ChebyshevSet<'_> {
         (self.rsize - 2) / 6 - 1
     }
 
-    fn spline_idx<S: NAIFSummaryRecord>(
+    pub(crate) fn spline_idx<S: NAIFSummaryRecord>(
         &self,
         epoch: Epoch,
         summary: &S,
@@ -59,6 +61,86 @@ impl Type3ChebyshevSet<'_> {
 
         Ok(((ephem_start_delta_s / window_duration_s) as usize + 1).min(self.num_records))
     }
+
+    /// Evaluates the n-th record of this set at the provided epoch, even if that epoch is outside of the record's interval.
+    pub fn evaluate_record(
+        &self,
+        n: usize,
+        epoch: Epoch,
+    ) -> Result<(Vector3, Vector3), InterpolationError> {
+        let window_duration_s = self.interval_length.to_seconds();
+        let radius_s = window_duration_s / 2.0;
+
+        let record = self.nth_record(n).context(InterpDecodingSnafu)?;
+
+        let normalized_time = (epoch.to_et_seconds() - record.midpoint_et_s) / radius_s;
+
+        let mut state = Vector3::zeros();
+        let mut rate = Vector3::zeros();
+
+        for (cno, coeffs) in [record.x_coeffs, record.y_coeffs, record.z_coeffs]
+            .iter()
+            .enumerate()
+        {
+            let val = chebyshev_eval_poly(normalized_time, coeffs, epoch, self.degree())?;
+            state[cno] = val;
+        }
+
+        for (cno, coeffs) in [record.vx_coeffs, record.vy_coeffs, record.vz_coeffs]
+            .iter()
+            .enumerate()
+        {
+            let val = chebyshev_eval_poly(normalized_time, coeffs, epoch, self.degree())?;
+            rate[cno] = val;
+        }
+
+        Ok((state, rate))
+    }
+
+    /// Returns the start epoch of each record of this set followed by the end epoch of the last record, i.e. all of the record edges.
+    pub fn record_boundaries(&self) -> Result<Vec<Epoch>, DecodingError> {
+        let mut boundaries = Vec::with_capacity(self.num_records + 1);
+        for n in 0..self.num_records {
+            let record = self.nth_record(n)?;
+            boundaries.push(record.midpoint_epoch() - record.radius);
+            if n + 1 == self.num_records {
+                boundaries.push(record.midpoint_epoch() + record.radius);
+            }
+        }
+        Ok(boundaries)
+    }
+
+    /// Returns the jump in position and velocity at each boundary between consecutive records, computed as the evaluation
+    /// of the next record minus the evaluation of the previous record at the end epoch of the previous record.
+    pub fn record_boundary_jumps(
+        &self,
+    ) -> Result<Vec<(Epoch, Vector3, Vector3)>, InterpolationError> {
+        let radius_s = self.interval_length.to_seconds() / 2.0;
+        let mut jumps = Vec::with_capacity(self.num_records.saturating_sub(1));
+        for n in 1..self.num_records {
+            let prev = self.nth_record(n - 1).context(InterpDecodingSnafu)?;
+            let boundary = Epoch::from_et_seconds(prev.midpoint_et_s + radius_s);
+            let (prev_pos, prev_vel) = self.evaluate_record(n - 1, boundary)?;
+            let (next_pos, next_vel) = self.evaluate_record(n, boundary)?;
+            jumps.push((boundary, next_pos - prev_pos, next_vel - prev_vel));
+        }
+        Ok(jumps)
+    }
+
+    /// Returns the spectrum of the position Chebyshev coefficients of each record of this set (the velocity coefficients are not included).
+    pub fn coefficient_spectrum(&self) -> Result<Vec<ChebyshevSpectrum>, DecodingError> {
+        (0..self.num_records)
+            .map(|n| {
+                let record = self.nth_record(n)?;
+                Ok(ChebyshevSpectrum::from_coeffs(
+                    record.midpoint_epoch(),
+                    record.x_coeffs,
+                    record.y_coeffs,
+                    record.z_coeffs,
+                ))
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for Type3ChebyshevSet<'_> {
@@ -153,35 +235,7 @@ impl<'a> NAIFDataSet<'a> for Type3ChebyshevSet<'a> {
     ) -> Result<(Vector3, Vector3), InterpolationError> {
         let spline_idx = self.spline_idx(epoch, summary)?;
 
-        let window_duration_s = self.interval_length.to_seconds();
-        let radius_s = window_duration_s / 2.0;
-
-        let record = self
-            .nth_record(spline_idx - 1)
-            .context(InterpDecodingSnafu)?;
-
-        let normalized_time = (epoch.to_et_seconds() - record.midpoint_et_s) / radius_s;
-
-        let mut state = Vector3::zeros();
-        let mut rate = Vector3::zeros();
-
-        for (cno, coeffs) in [record.x_coeffs, record.y_coeffs, record.z_coeffs]
-            .iter()
-            .enumerate()
-        {
-            let val = chebyshev_eval_poly(normalized_time, coeffs, epoch, self.degree())?;
-            state[cno] = val;
-        }
-
-        for (cno, coeffs) in [record.vx_coeffs, record.vy_coeffs, record.vz_coeffs]
-            .iter()
-            .enumerate()
-        {
-            let val = chebyshev_eval_poly(normalized_time, coeffs, epoch, self.degree())?;
-            rate[cno] = val;
-        }
-
-        Ok((state, rate))
+        self.evaluate_record(spline_idx - 1, epoch)
     }
 
     fn check_integrity(&self) -> Result<(), IntegrityError> {
@@ -22,14 +22,18 @@ pub(crate) const RCRD_LEN: usize = 1024;
 pub mod daf;
 pub(crate) mod data_types;
 pub mod mut_daf;
+pub mod writer;
 pub use data_types::DataType as DafDataType;
 pub mod file_record;
 pub mod name_record;
 pub mod summary_record;
+#[cfg(test)]
+pub(crate) mod test_utils;
 // Defines the supported data types
 pub mod datatypes;
 
 pub use daf::DAF;
+pub use writer::DafSegment;
 
 use crate::errors::DecodingError;
 use core::fmt::Debug;
@@ -231,6 +235,8 @@ pub enum DAFError {
     InvalidIndex { kind: &'static str, idx: usize },
     #[snafu(display("could not build data vector of type DAF/{kind}"))]
     DataBuildError { kind: &'static str },
+    #[snafu(display("DAF/{kind}: comments must be printable ASCII but found {found:?}"))]
+    InvalidComment { kind: &'static str, found: char },
 }
 
 // Manual implementation of PartialEq because IOError does not derive it, sadly.
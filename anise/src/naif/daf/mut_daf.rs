@@ -9,7 +9,9 @@
  */
 
 use super::{
-    daf::DAF, DAFError, DecodingNameSnafu, NAIFDataSet, NAIFSummaryRecord, NameRecord, RCRD_LEN,
+    daf::{COMMENT_EOT, COMMENT_RCRD_LEN, DAF},
+    DAFError, DecodingNameSnafu, DecodingSummarySnafu, NAIFDataSet, NAIFSummaryRecord, NameRecord,
+    RCRD_LEN,
 };
 use crate::{
     errors::DecodingError,
@@ -19,9 +21,155 @@ use crate::{
 use bytes::BytesMut;
 use hifitime::Epoch;
 use snafu::ResultExt;
-use zerocopy::IntoBytes;
+use zerocopy::{FromBytes, IntoBytes};
 
 impl<R: NAIFSummaryRecord> DAF<R> {
+    /// Replaces the comment area of this DAF file with the provided text, which must be printable ASCII (new lines are allowed).
+    ///
+    /// The number of reserved records is updated to fit the new comments, and all of the record pointers and data addresses
+    /// of the file are shifted accordingly, so the ephemeris or orientation data is unchanged. Providing an empty string removes the comment area.
+    pub fn set_comments(&mut self, comments: &str) -> Result<(), DAFError> {
+        let mut file_rcrd = self.file_record()?;
+        if file_rcrd.is_empty() {
+            return Err(DAFError::FileRecord {
+                kind: R::NAME,
+                source: FileRecordError::EmptyRecord,
+            });
+        }
+
+        // Encode the comments: lines are separated by a null character and the comments end with an EOT marker.
+        let mut encoded = Vec::with_capacity(comments.len() + 1);
+        for c in comments.chars() {
+            match c {
+                '\n' => encoded.push(0x0),
+                '\r' => continue,
+                ' '..='~' | '\t' => encoded.push(c as u8),
+                _ => {
+                    return Err(DAFError::InvalidComment {
+                        kind: R::NAME,
+                        found: c,
+                    })
+                }
+            }
+        }
+
+        let new_reserved = if encoded.is_empty() {
+            0
+        } else {
+            encoded.push(COMMENT_EOT);
+            encoded.len().div_ceil(COMMENT_RCRD_LEN)
+        };
+
+        let orig_fwrd = file_rcrd.fwrd_idx();
+        // The first summary record follows the file record, so a malformed file record may not point before it.
+        if orig_fwrd < 2 {
+            return Err(DAFError::InvalidIndex {
+                kind: R::NAME,
+                idx: orig_fwrd,
+            });
+        }
+        let orig_reserved = orig_fwrd.saturating_sub(2);
+        // Number of records by which the rest of the file is shifted
+        let shift = new_reserved as isize - orig_reserved as isize;
+        // Number of double precision words by which the data addresses are shifted
+        let word_shift = shift * (RCRD_LEN / DBL_SIZE) as isize;
+
+        let size = self.bytes.len();
+        let rest = self
+            .bytes
+            .get((orig_fwrd - 1) * RCRD_LEN..)
+            .ok_or(DecodingError::InaccessibleBytes {
+                start: (orig_fwrd - 1) * RCRD_LEN,
+                end: size,
+                size,
+            })
+            .context(DecodingSummarySnafu { kind: R::NAME })?;
+
+        // Update the file record bookkeeping
+        file_rcrd.forward = (file_rcrd.forward as isize + shift) as u32;
+        file_rcrd.backward = (file_rcrd.backward as isize + shift) as u32;
+        if file_rcrd.free_addr > 0 {
+            file_rcrd.free_addr = (file_rcrd.free_addr as isize + word_shift) as u32;
+        }
+
+        // Rebuild the file: file record, comment records, and the rest of the file unchanged.
+        let mut new_bytes = Vec::with_capacity(size + (shift * RCRD_LEN as isize).max(0) as usize);
+        new_bytes.extend_from_slice(file_rcrd.as_bytes());
+        new_bytes.resize(RCRD_LEN, 0x0);
+        for chunk in encoded.chunks(COMMENT_RCRD_LEN) {
+            let mut rcrd = [0x0; RCRD_LEN];
+            rcrd[..chunk.len()].copy_from_slice(chunk);
+            new_bytes.extend_from_slice(&rcrd);
+        }
+        new_bytes.extend_from_slice(rest);
+
+        if shift != 0 {
+            // Shift all of the pointers of the summary records, and all of the data addresses of the summaries.
+            let mut next_idx = file_rcrd.fwrd_idx();
+            while next_idx > 0 {
+                let rcrd_idx = (next_idx - 1) * RCRD_LEN;
+                let rcrd_bytes = new_bytes
+                    .get_mut(rcrd_idx..rcrd_idx + RCRD_LEN)
+                    .ok_or(DecodingError::InaccessibleBytes {
+                        start: rcrd_idx,
+                        end: rcrd_idx + RCRD_LEN,
+                        size,
+                    })
+                    .context(DecodingSummarySnafu { kind: R::NAME })?;
+
+                let (summary_bytes, data_summaries_bytes) =
+                    rcrd_bytes.split_at_mut(SummaryRecord::SIZE);
+
+                let mut summary = SummaryRecord::read_from_bytes(summary_bytes)
+                    .or(Err(DecodingError::Casting))
+                    .context(DecodingSummarySnafu { kind: R::NAME })?;
+
+                let num_summaries = summary.num_summaries();
+                if summary.next_record > 0.0 {
+                    summary.next_record += shift as f64;
+                }
+                if summary.prev_record > 0.0 {
+                    summary.prev_record += shift as f64;
+                }
+                summary_bytes.copy_from_slice(summary.as_bytes());
+
+                let data_summaries =
+                    <[R]>::mut_from_prefix_with_elems(data_summaries_bytes, num_summaries)
+                        .or(Err(DecodingError::Casting))
+                        .context(DecodingSummarySnafu { kind: R::NAME })?
+                        .0;
+
+                for data_summary in data_summaries.iter_mut().filter(|s| !s.is_empty()) {
+                    let start = data_summary.start_index() as isize + word_shift;
+                    let end = data_summary.end_index() as isize + word_shift;
+                    data_summary.update_indexes(start as usize, end as usize);
+                }
+
+                // Prevent infinite loops on corrupted files.
+                if summary.next_record() == next_idx {
+                    break;
+                }
+                next_idx = summary.next_record();
+            }
+        }
+
+        self.bytes = BytesMut::from_iter(new_bytes);
+
+        Ok(())
+    }
+
+    /// Appends the provided text, as new lines, to the comment area of this DAF file, cf. [Self::set_comments].
+    pub fn append_comments(&mut self, comments: &str) -> Result<(), DAFError> {
+        match self.comments()? {
+            Some(mut existing) => {
+                existing.push('\n');
+                existing.push_str(comments);
+                self.set_comments(&existing)
+            }
+            None => self.set_comments(comments),
+        }
+    }
+
     /// Sets the name record of this mutable DAF file to the one provided as a parameter.
     pub fn set_name_record(&mut self, new_name_record: NameRecord) -> Result<(), DAFError> {
         let rcrd_idx = self.file_record()?.fwrd_idx() * RCRD_LEN;
@@ -118,6 +266,30 @@ impl<R: NAIFSummaryRecord> DAF<R> {
         Ok(())
     }
 
+    /// Replaces the n-th summary of this DAF file, e.g. to change its data type after replacing its data. The data itself is not modified.
+    pub fn set_nth_summary(&mut self, idx: usize, new_summary: R) -> Result<(), DAFError> {
+        // Ensures that this summary exists
+        self.data_summaries(None)?
+            .get(idx)
+            .ok_or(DAFError::InvalidIndex { idx, kind: R::NAME })?;
+
+        let rcrd_idx =
+            (self.file_record()?.fwrd_idx() - 1) * RCRD_LEN + SummaryRecord::SIZE + idx * R::SIZE;
+        let size = self.bytes.len();
+        let summary_bytes = self
+            .bytes
+            .get_mut(rcrd_idx..rcrd_idx + R::SIZE)
+            .ok_or(DecodingError::InaccessibleBytes {
+                start: rcrd_idx,
+                end: rcrd_idx + R::SIZE,
+                size,
+            })
+            .context(DecodingSummarySnafu { kind: R::NAME })?;
+        summary_bytes.copy_from_slice(new_summary.as_bytes());
+
+        Ok(())
+    }
+
     /// Deletes the data for the n-th segment of this DAF file.
     pub fn delete_nth_data(&mut self, idx: usize) -> Result<(), DAFError> {
         // NOTE: This function will be rewritten in full after https://github.com/nyx-space/anise/issues/262
@@ -186,4 +358,73 @@ impl<R: NAIFSummaryRecord> DAF<R> {
 
         Ok(())
     }
+
+    /// Clears all of the data summaries of this DAF file that cannot be used, and returns a description of each of them.
+    ///
+    /// A summary cannot be used if its data type is not supported, if its data indexes are not within the file, or if its
+    /// epochs are not finite or are not ordered. Cleared summaries are empty, like deleted segments, so they are ignored by all queries,
+    /// and the rest of the file remains usable. This is used by the best effort loading of the Almanac.
+    pub fn clear_invalid_summaries(&mut self) -> Result<Vec<String>, DAFError> {
+        let mut cleared = Vec::new();
+        let mut visited = Vec::new();
+        let num_words = self.bytes.len() / DBL_SIZE;
+
+        let mut next_idx = self.file_record()?.fwrd_idx();
+        while next_idx > 0 && !visited.contains(&next_idx) {
+            visited.push(next_idx);
+            let daf_summary = self.daf_summary(Some(next_idx))?;
+
+            let invalid: Vec<(usize, String)> = self
+                .data_summaries(Some(next_idx))?
+                .iter()
+                .take(daf_summary.num_summaries())
+                .enumerate()
+                .filter(|(_, summary)| !summary.is_empty())
+                .filter_map(|(idx, summary)| {
+                    let reason = if let Err(e) = summary.data_type() {
+                        format!("{e}")
+                    } else if summary.start_index() == 0
+                        || summary.end_index() < summary.start_index()
+                        || summary.end_index() > num_words
+                    {
+                        format!(
+                            "data indexes {}..{} are not within the {num_words} words of the file",
+                            summary.start_index(),
+                            summary.end_index()
+                        )
+                    } else if !summary.start_epoch_et_s().is_finite()
+                        || !summary.end_epoch_et_s().is_finite()
+                        || summary.start_epoch_et_s() > summary.end_epoch_et_s()
+                    {
+                        format!(
+                            "invalid time span from {} to {} ET seconds",
+                            summary.start_epoch_et_s(),
+                            summary.end_epoch_et_s()
+                        )
+                    } else {
+                        return None;
+                    };
+                    Some((
+                        idx,
+                        format!(
+                            "{} segment #{idx} of ID {} in summary record {next_idx}: {reason}",
+                            R::NAME,
+                            summary.id()
+                        ),
+                    ))
+                })
+                .collect();
+
+            for (idx, reason) in invalid {
+                let rcrd_idx = (next_idx - 1) * RCRD_LEN + SummaryRecord::SIZE + idx * R::SIZE;
+                // The data summaries were read from these bytes, so they exist.
+                self.bytes[rcrd_idx..rcrd_idx + R::SIZE].copy_from_slice(R::default().as_bytes());
+                cleared.push(reason);
+            }
+
+            next_idx = daf_summary.next_record();
+        }
+
+        Ok(cleared)
+    }
 }
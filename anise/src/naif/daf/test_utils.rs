@@ -0,0 +1,81 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Synthetic SPK segments shared by the unit tests.
+
+use super::{DafDataType, DafSegment, FileRecord};
+use crate::naif::spk::summary::SPKSummaryRecord;
+use crate::naif::SPK;
+use crate::NaifId;
+
+/// Type 2 segment of the target relative to the center in J2000, made of consecutive records of `interval_s` seconds starting
+/// `start_s` TDB seconds past J2000, where each record holds the Chebyshev coefficients of X, Y, and Z in km.
+pub(crate) fn chebyshev_segment<const N: usize>(
+    target_id: NaifId,
+    center_id: NaifId,
+    start_s: f64,
+    interval_s: f64,
+    records: &[[[f64; N]; 3]],
+) -> DafSegment<SPKSummaryRecord> {
+    let mut data = Vec::with_capacity(records.len() * (2 + 3 * N) + 4);
+    for (k, coeffs) in records.iter().enumerate() {
+        data.push(start_s + (k as f64 + 0.5) * interval_s);
+        data.push(0.5 * interval_s);
+        for component in coeffs {
+            data.extend_from_slice(component);
+        }
+    }
+    data.extend_from_slice(&[
+        start_s,
+        interval_s,
+        (2 + 3 * N) as f64,
+        records.len() as f64,
+    ]);
+
+    DafSegment {
+        summary: SPKSummaryRecord {
+            start_epoch_et_s: start_s,
+            end_epoch_et_s: start_s + interval_s * records.len() as f64,
+            target_id,
+            center_id,
+            frame_id: 1,
+            data_type_i: DafDataType::Type2ChebyshevTriplet as i32,
+            start_idx: 0,
+            end_idx: 0,
+        },
+        name: format!("{target_id}"),
+        data,
+    }
+}
+
+/// Type 2 segment from 0 to 1 day past J2000 TDB, in uniform motion with respect to its center, at the provided position at noon.
+pub(crate) fn linear_segment(
+    target_id: NaifId,
+    center_id: NaifId,
+    position_km: [f64; 3],
+    velocity_km_s: [f64; 3],
+) -> DafSegment<SPKSummaryRecord> {
+    let coeffs = [0, 1, 2].map(|i| [position_km[i], velocity_km_s[i] * 43_200.0]);
+    chebyshev_segment(target_id, center_id, 0.0, 86_400.0, &[coeffs])
+}
+
+/// Type 2 segment from 0 to 1 day past J2000 TDB, at a fixed position with respect to its center.
+pub(crate) fn fixed_segment(
+    target_id: NaifId,
+    center_id: NaifId,
+    position_km: [f64; 3],
+) -> DafSegment<SPKSummaryRecord> {
+    linear_segment(target_id, center_id, position_km, [0.0; 3])
+}
+
+/// Builds an SPK from the provided segments, in order.
+pub(crate) fn test_spk(segments: &[DafSegment<SPKSummaryRecord>]) -> SPK {
+    SPK::from_segments(&FileRecord::spk("TEST"), segments).unwrap()
+}
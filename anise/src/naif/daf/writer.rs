@@ -0,0 +1,293 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::{
+    daf::DAF, DAFError, DafDataType, DecodingDataSnafu, FileRecord, NAIFRecord, NAIFSummaryRecord,
+    NameRecord, SummaryRecord, RCRD_LEN,
+};
+use crate::{errors::DecodingError, DBL_SIZE};
+use bytes::BytesMut;
+use hifitime::Epoch;
+use snafu::ResultExt;
+use zerocopy::IntoBytes;
+
+/// A segment of a DAF file, i.e. its summary, name, and data array, as written by [DAF::from_segments].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DafSegment<R: NAIFSummaryRecord> {
+    /// Summary of this segment, whose indexes are set when the file is built
+    pub summary: R,
+    pub name: String,
+    pub data: Vec<f64>,
+}
+
+impl<R: NAIFSummaryRecord> DAF<R> {
+    /// Builds a new DAF file from the provided segments, in order, without any comment area.
+    ///
+    /// The format of the file (identification, ND, NI, internal file name, and endianness) is that of the provided file record,
+    /// whose record pointers and first free address are recomputed. Each summary record is immediately followed by its name record,
+    /// and the data of all of the segments follows the last name record.
+    pub fn from_segments(
+        file_rcrd: &FileRecord,
+        segments: &[DafSegment<R>],
+    ) -> Result<Self, DAFError> {
+        let summary_size = file_rcrd.summary_size();
+        let per_block = ((RCRD_LEN - SummaryRecord::SIZE) / R::SIZE)
+            .min(NameRecord::default().num_entries(summary_size))
+            .max(1);
+        let num_blocks = segments.len().div_ceil(per_block).max(1);
+
+        let words_per_rcrd = RCRD_LEN / DBL_SIZE;
+        // Address of the first word of the data, right after the last name record.
+        let mut next_addr = (1 + 2 * num_blocks) * words_per_rcrd + 1;
+
+        let mut file_rcrd = file_rcrd.clone();
+        file_rcrd.forward = 2;
+        file_rcrd.backward = (2 * num_blocks) as u32;
+
+        let mut bytes = Vec::with_capacity((1 + 2 * num_blocks) * RCRD_LEN);
+        bytes.extend_from_slice(file_rcrd.as_bytes());
+        bytes.resize(RCRD_LEN, 0x0);
+
+        let mut data = Vec::new();
+        for block in 0..num_blocks {
+            let chunk = segments
+                .get(block * per_block..((block + 1) * per_block).min(segments.len()))
+                .unwrap_or_default();
+            let rcrd_num = 2 + 2 * block;
+
+            let daf_summary = SummaryRecord {
+                next_record: if block + 1 < num_blocks {
+                    (rcrd_num + 2) as f64
+                } else {
+                    0.0
+                },
+                prev_record: if block > 0 {
+                    (rcrd_num - 2) as f64
+                } else {
+                    0.0
+                },
+                num_summaries: chunk.len() as f64,
+            };
+
+            let mut summary_rcrd = Vec::with_capacity(RCRD_LEN);
+            summary_rcrd.extend_from_slice(daf_summary.as_bytes());
+            // The names are trimmed so we initialize the bytes with spaces.
+            let mut name_rcrd = NameRecord {
+                raw_names: [0x20; RCRD_LEN],
+            };
+
+            for (n, segment) in chunk.iter().enumerate() {
+                let mut summary = segment.summary;
+                summary.update_indexes(next_addr, next_addr + segment.data.len() - 1);
+                next_addr += segment.data.len();
+                summary_rcrd.extend_from_slice(summary.as_bytes());
+                name_rcrd.set_nth_name(n, summary_size, &segment.name);
+                data.extend_from_slice(&segment.data);
+            }
+
+            summary_rcrd.resize(RCRD_LEN, 0x0);
+            bytes.extend_from_slice(&summary_rcrd);
+            bytes.extend_from_slice(name_rcrd.as_bytes());
+        }
+
+        bytes.extend_from_slice(data.as_bytes());
+        // Pad to the nearest record.
+        bytes.resize(bytes.len().div_ceil(RCRD_LEN) * RCRD_LEN, 0x0);
+
+        // Now that the data is written, set the first free address.
+        file_rcrd.free_addr = next_addr as u32;
+        bytes[..FileRecord::SIZE].copy_from_slice(file_rcrd.as_bytes());
+
+        let mut daf = Self::parse(BytesMut::from(&bytes[..]))?;
+        daf.set_crc32();
+        Ok(daf)
+    }
+
+    /// Returns the segments of this DAF file which cover part of the provided time span and are selected by the filter, in file order.
+    ///
+    /// The coverage of each returned segment is clipped to the time span. The Chebyshev segments (Type 2 and 3) are also trimmed to
+    /// the records covering the time span, without changing their coefficients. The data of the other types is kept in full.
+    pub fn trimmed_segments<F: Fn(&R) -> bool>(
+        &self,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        filter: F,
+    ) -> Result<Vec<DafSegment<R>>, DAFError> {
+        let summary_size = self.file_record()?.summary_size();
+        let start_et_s = start_epoch.to_et_seconds();
+        let end_et_s = end_epoch.to_et_seconds();
+
+        let mut segments = Vec::new();
+        let mut rcrd_num = self.file_record()?.fwrd_idx();
+        while rcrd_num > 0 {
+            let daf_summary = self.daf_summary(Some(rcrd_num))?;
+            let name_rcrd = self.name_record(Some(rcrd_num))?;
+            let summaries = self.data_summaries(Some(rcrd_num))?;
+
+            for (n, summary) in summaries
+                .iter()
+                .take(daf_summary.num_summaries())
+                .enumerate()
+            {
+                if summary.is_empty()
+                    || summary.end_epoch_et_s() < start_et_s
+                    || summary.start_epoch_et_s() > end_et_s
+                    || !filter(summary)
+                {
+                    continue;
+                }
+
+                let full_data = self.segment_data(summary, n)?;
+                let data = match summary.data_type() {
+                    Ok(DafDataType::Type2ChebyshevTriplet)
+                    | Ok(DafDataType::Type3ChebyshevSextuplet) => {
+                        trim_chebyshev(&full_data, start_et_s, end_et_s).unwrap_or(full_data)
+                    }
+                    _ => full_data,
+                };
+
+                let mut summary = *summary;
+                summary.update_epochs(
+                    Epoch::from_et_seconds(summary.start_epoch_et_s().max(start_et_s)),
+                    Epoch::from_et_seconds(summary.end_epoch_et_s().min(end_et_s)),
+                );
+
+                segments.push(DafSegment {
+                    summary,
+                    name: name_rcrd.nth_name(n, summary_size).to_string(),
+                    data,
+                });
+            }
+
+            // Prevent infinite loops on corrupted files.
+            if daf_summary.is_final_record() || daf_summary.next_record() == rcrd_num {
+                break;
+            }
+            rcrd_num = daf_summary.next_record();
+        }
+
+        Ok(segments)
+    }
+
+    /// Returns a copy of the data array of the provided segment.
+    fn segment_data(&self, summary: &R, idx: usize) -> Result<Vec<f64>, DAFError> {
+        let start = (summary.start_index() - 1) * DBL_SIZE;
+        let end = summary.end_index() * DBL_SIZE;
+        let bytes = self
+            .bytes
+            .get(start..end)
+            .ok_or(DecodingError::InaccessibleBytes {
+                start,
+                end,
+                size: self.bytes.len(),
+            })
+            .context(DecodingDataSnafu { kind: R::NAME, idx })?;
+
+        Ok(bytes
+            .chunks_exact(DBL_SIZE)
+            .map(|chunk| f64::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect())
+    }
+}
+
+/// Returns the records of a Chebyshev data array (Type 2 or 3) which cover the provided time span, followed by the updated directory,
+/// or None if the directory of the array is inconsistent.
+fn trim_chebyshev(data: &[f64], start_et_s: f64, end_et_s: f64) -> Option<Vec<f64>> {
+    let directory = data.get(data.len().checked_sub(4)?..)?;
+    let (init_et_s, interval_s) = (directory[0], directory[1]);
+    let (rsize, num_records) = (directory[2] as usize, directory[3] as usize);
+    if interval_s <= 0.0 || rsize == 0 || num_records == 0 || rsize * num_records + 4 != data.len()
+    {
+        return None;
+    }
+
+    let first =
+        (((start_et_s - init_et_s) / interval_s).floor().max(0.0) as usize).min(num_records - 1);
+    // The record starting at the end epoch is kept, since it is the one evaluated at that epoch.
+    let last = ((((end_et_s - init_et_s) / interval_s).floor().max(0.0) as usize) + 1)
+        .clamp(first + 1, num_records);
+
+    let mut trimmed = data[first * rsize..last * rsize].to_vec();
+    trimmed.extend_from_slice(&[
+        init_et_s + first as f64 * interval_s,
+        interval_s,
+        rsize as f64,
+        (last - first) as f64,
+    ]);
+    Some(trimmed)
+}
+
+#[cfg(test)]
+mod ut_daf_writer {
+    use crate::constants::frames::{EARTH_J2000, SSB_J2000};
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::prelude::Almanac;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn write_and_trim() {
+        // Enough segments to span two summary records.
+        // One record per day over four days around the SSB, where X = 1000 + day + t km.
+        let records = (0..4)
+            .map(|day| [[1000.0 + day as f64, 1.0], [0.0; 2], [0.0; 2]])
+            .collect::<Vec<_>>();
+        let segments = (1..=30)
+            .map(|id| chebyshev_segment(id * 100 + 99, 0, 0.0, 86_400.0, &records))
+            .collect::<Vec<_>>();
+        let spk = test_spk(&segments);
+        assert_eq!(spk.iter_summary_blocks().count(), 2);
+        assert_eq!(spk.daf_summary(None).unwrap().num_summaries(), 25);
+        assert_eq!(spk.name_record(None).unwrap().nth_name(1, 5), "299");
+        spk.scrub().unwrap();
+
+        // Round trip of all of the segments
+        let all = spk
+            .trimmed_segments(
+                Epoch::from_et_seconds(-1.0),
+                Epoch::from_et_seconds(1e6),
+                |_| true,
+            )
+            .unwrap();
+        assert_eq!(all.len(), 30);
+        for (written, read) in segments.iter().zip(&all) {
+            assert_eq!(written.name, read.name);
+            assert_eq!(written.data, read.data);
+            assert_eq!(written.summary.target_id, read.summary.target_id);
+        }
+
+        // Trim the Earth to the second and third days
+        let start = Epoch::from_et_seconds(86_400.0 * 1.5);
+        let end = Epoch::from_et_seconds(86_400.0 * 2.5);
+        let earth = spk
+            .trimmed_segments(start, end, |s| s.target_id == 399)
+            .unwrap();
+        assert_eq!(earth.len(), 1);
+        assert_eq!(earth[0].data.len(), 2 * 8 + 4);
+        assert_eq!(earth[0].summary.start_epoch_et_s, 86_400.0 * 1.5);
+        assert_eq!(earth[0].summary.end_epoch_et_s, 86_400.0 * 2.5);
+
+        let trimmed = test_spk(&earth);
+        assert!(trimmed.bytes.len() < spk.bytes.len());
+
+        let full = Almanac::default().with_spk(spk);
+        let almanac = Almanac::default().with_spk(trimmed);
+        for epoch in [start, start + Unit::Hour * 7, end] {
+            let expected = full.translate(EARTH_J2000, SSB_J2000, epoch, None).unwrap();
+            let state = almanac
+                .translate(EARTH_J2000, SSB_J2000, epoch, None)
+                .unwrap();
+            assert_eq!(expected.radius_km, state.radius_km);
+            assert_eq!(expected.velocity_km_s, state.velocity_km_s);
+        }
+        assert!(almanac
+            .translate(EARTH_J2000, SSB_J2000, start - Unit::Hour * 1, None)
+            .is_err());
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::{collections::HashMap, str::FromStr};
+
+use log::{debug, warn};
+
+use super::{parser::Assignment, KPLItem, KPLValue, Parameter};
+
+/// Instrument kernel (IK) item, i.e. the `INS-xxxxx_*` keywords of a single instrument.
+#[derive(Debug, Default)]
+pub struct IKItem {
+    pub instrument_id: Option<i32>,
+    pub data: HashMap<Parameter, KPLValue>,
+}
+
+impl IKItem {
+    /// Splits an IK keyword like `INS-82360_FOV_SHAPE` into the instrument ID and the parameter name.
+    fn split_keyword(keyword: &str) -> Option<(i32, &str)> {
+        let (id, param) = keyword.strip_prefix("INS")?.split_once('_')?;
+        Some((id.parse::<i32>().ok()?, param))
+    }
+}
+
+impl KPLItem for IKItem {
+    type Parameter = Parameter;
+
+    /// Returns -1 on non-instrument tokens.
+    fn extract_key(data: &Assignment) -> i32 {
+        match Self::split_keyword(&data.keyword) {
+            Some((instrument_id, _)) => instrument_id,
+            None => -1,
+        }
+    }
+
+    fn data(&self) -> &HashMap<Self::Parameter, KPLValue> {
+        &self.data
+    }
+
+    fn parse(&mut self, data: Assignment) {
+        if let Some((instrument_id, param)) = Self::split_keyword(&data.keyword) {
+            if self.instrument_id.is_some() && self.instrument_id != Some(instrument_id) {
+                warn!(
+                    "Got instrument {instrument_id} but expected {:?}",
+                    self.instrument_id
+                );
+                return;
+            }
+            self.instrument_id = Some(instrument_id);
+
+            if let Ok(param) = Parameter::from_str(param) {
+                self.data.insert(param, data.to_value());
+            } else {
+                // Instrument kernels define many other parameters, e.g. the focal length or the pixel size.
+                debug!("Unsupported parameter `{param}` -- ignoring");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod ik_ut {
+    use super::{IKItem, KPLValue, Parameter};
+    use crate::naif::kpl::parser::parse_bytes;
+
+    #[test]
+    fn test_parse_ik() {
+        let ik = r#"
+Some comments about this instrument.
+
+\begindata
+
+      INS-82360_FOV_FRAME       = 'CASSINI_ISS_NAC'
+      INS-82360_FOV_SHAPE       = 'RECTANGLE'
+      INS-82360_BORESIGHT       = ( 0.0, 0.0, 1.0 )
+      INS-82360_FOV_BOUNDARY_CORNERS = (
+                 0.0030, 0.0030, 1.0
+                -0.0030, 0.0030, 1.0
+                -0.0030, -0.0030, 1.0
+                 0.0030, -0.0030, 1.0 )
+      INS-82360_FOCAL_LENGTH    = 2002.703
+
+\begintext
+
+      INS-99_FOV_SHAPE = 'CIRCLE'
+"#;
+        let assignments = parse_bytes::<_, IKItem>(&mut ik.as_bytes(), false).unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        let nac = &assignments[&-82360];
+        assert_eq!(nac.instrument_id, Some(-82360));
+        assert_eq!(
+            nac.data[&Parameter::FovFrame],
+            KPLValue::String("CASSINI_ISS_NAC".to_string())
+        );
+        assert_eq!(
+            nac.data[&Parameter::FovShape],
+            KPLValue::String("RECTANGLE".to_string())
+        );
+        assert_eq!(
+            nac.data[&Parameter::Boresight],
+            KPLValue::Matrix(vec![0.0, 0.0, 1.0])
+        );
+        assert_eq!(
+            nac.data[&Parameter::FovBoundaryCorners]
+                .to_vec_f64()
+                .unwrap()
+                .len(),
+            12
+        );
+        // The focal length is not an FOV parameter
+        assert_eq!(nac.data.len(), 4);
+    }
+}
@@ -17,6 +17,7 @@ use snafu::{whatever, Whatever};
 use self::parser::Assignment;
 
 pub mod fk;
+pub mod ik;
 
 pub mod parser;
 pub mod tpc;
@@ -105,6 +106,7 @@ pub enum Parameter {
     GeoMagNorthPoleCenterDipoleLatitude,
     GeoMagNorthPoleCenterDipoleLongitude,
     GravitationalParameter,
+    GravitationalParameterSigma,
     Class,
     Center,
     ClassId,
@@ -113,6 +115,15 @@ pub enum Parameter {
     Matrix,
     Units,
     Axes,
+    FovFrame,
+    FovShape,
+    Boresight,
+    FovClassSpec,
+    FovBoundaryCorners,
+    FovRefVector,
+    FovRefAngle,
+    FovCrossAngle,
+    FovAngleUnits,
 }
 
 impl FromStr for Parameter {
@@ -132,6 +143,7 @@ impl FromStr for Parameter {
             "N_GEOMAG_CTR_DIPOLE_LAT" => Ok(Self::GeoMagNorthPoleCenterDipoleLatitude),
             "N_GEOMAG_CTR_DIPOLE_LON" => Ok(Self::GeoMagNorthPoleCenterDipoleLongitude),
             "GM" => Ok(Self::GravitationalParameter),
+            "GM_SIGMA" => Ok(Self::GravitationalParameterSigma),
             "CLASS" => Ok(Self::Class),
             "CLASS_ID" => Ok(Self::ClassId),
             "CENTER" => Ok(Self::Center),
@@ -141,6 +153,15 @@ impl FromStr for Parameter {
             "UNITS" => Ok(Self::Units),
             "AXES" => Ok(Self::Axes),
             "MAX_PHASE_DEGREE" => Ok(Self::MaxPhaseDegree),
+            "FOV_FRAME" => Ok(Self::FovFrame),
+            "FOV_SHAPE" => Ok(Self::FovShape),
+            "BORESIGHT" => Ok(Self::Boresight),
+            "FOV_CLASS_SPEC" => Ok(Self::FovClassSpec),
+            "FOV_BOUNDARY_CORNERS" | "FOV_BOUNDARY" => Ok(Self::FovBoundaryCorners),
+            "FOV_REF_VECTOR" => Ok(Self::FovRefVector),
+            "FOV_REF_ANGLE" => Ok(Self::FovRefAngle),
+            "FOV_CROSS_ANGLE" => Ok(Self::FovCrossAngle),
+            "FOV_ANGLE_UNITS" => Ok(Self::FovAngleUnits),
             "GMLIST" | "NAME" | "SPEC" => {
                 whatever!("unsupported parameter `{s}`")
             }
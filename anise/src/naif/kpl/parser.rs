@@ -20,16 +20,19 @@ use log::{error, info, warn};
 
 use crate::constants::orientations::J2000;
 use crate::math::rotation::{r1, r2, r3, Quaternion, DCM};
-use crate::math::Matrix3;
+use crate::math::{perp_vector, Matrix3, Vector3};
 use crate::naif::kpl::fk::FKItem;
+use crate::naif::kpl::ik::IKItem;
 use crate::naif::kpl::tpc::TPCItem;
 use crate::naif::kpl::Parameter;
 use crate::structure::dataset::{DataSetError, DataSetType};
+use crate::structure::instrument::{FovShape, Instrument};
 use crate::structure::metadata::Metadata;
 use crate::structure::planetocentric::ellipsoid::Ellipsoid;
 use crate::structure::planetocentric::phaseangle::PhaseAngle;
+use crate::structure::planetocentric::radiometry::Radiometry;
 use crate::structure::planetocentric::{PlanetaryData, MAX_NUT_PREC_ANGLES};
-use crate::structure::{EulerParameterDataSet, PlanetaryDataSet};
+use crate::structure::{EulerParameterDataSet, InstrumentDataSet, PlanetaryDataSet};
 
 use super::{KPLItem, KPLValue};
 
@@ -60,7 +63,8 @@ impl Assignment {
         if vec.len() > 1 {
             KPLValue::Matrix(
                 vec.iter()
-                    .map(|s| s.parse::<f64>().unwrap_or(0.0))
+                    // Fortran double precision exponents, e.g. `-1.4D-12`, are valid KPL
+                    .map(|s| s.replace(['D', 'd'], "E").parse::<f64>().unwrap_or(0.0))
                     .collect(),
             )
         } else if vec.is_empty() {
@@ -149,6 +153,9 @@ pub fn parse_bytes<R: BufRead, I: KPLItem>(
 
 /// Converts two KPL/TPC files, one defining the planetary constants as text, and the other defining the gravity parameters, into the PlanetaryDataSet equivalent ANISE file.
 /// KPL/TPC files must be converted into "PCA" (Planetary Constant ANISE) files before being loaded into ANISE.
+///
+/// KPL/TPC files do not define radiometric constants, so the planets and the Moon are given the standard ones of `Radiometry::from_naif_id`.
+/// The 1σ uncertainty of the gravitational parameter is read from the optional `BODYnnn_GM_SIGMA` keyword of the gravity data.
 pub fn convert_tpc<P: AsRef<Path> + fmt::Debug>(
     pck: P,
     gm: P,
@@ -369,18 +376,20 @@ pub fn convert_tpc_items(
                                     });
                                 }
 
-                                if nut_prec.len() < 2 {
+                                if nut_prec.len() < 2 || nut_prec.len() > 3 {
                                     return Err(DataSetError::Conversion {
                                         action: format!(
-                                            "Expected nut prec data to be array of length 2 but was {}",
+                                            "Expected nut prec data to be array of length 2 or 3 but was {}",
                                             nut_prec.len()
                                         ),
                                     });
                                 }
 
+                                // Quadratic terms are only defined if the MAX_PHASE_DEGREE is 2, e.g. for Mars in the pck00011.
                                 coeffs[i] = PhaseAngle::<0> {
                                     offset_deg: nut_prec[0],
                                     rate_deg: nut_prec[1],
+                                    accel_deg: nut_prec.get(2).copied().unwrap_or(0.0),
                                     ..Default::default()
                                 };
                                 num += 1;
@@ -390,6 +399,16 @@ pub fn convert_tpc_items(
                             constant.nut_prec_angles = coeffs;
                         };
 
+                        constant.radiometry = Radiometry::from_naif_id(object_id);
+                        constant.mu_sigma_km3_s2 = match planetary_data
+                            .data
+                            .get(&Parameter::GravitationalParameterSigma)
+                        {
+                            Some(KPLValue::Float(sigma)) => Some(*sigma),
+                            Some(KPLValue::Matrix(sigma)) => sigma.first().copied(),
+                            _ => None,
+                        };
+
                         // Skip the DER serialization in full.
                         dataset.push(constant, Some(object_id), None)?;
                         info!("Added {object_id}");
@@ -614,3 +633,294 @@ pub fn convert_fk_items(
 
     Ok(dataset)
 }
+
+/// Converts a KPL/IK instrument kernel, that defines the field of view of instruments with the `INS-xxxxx_FOV_*` keywords, into the
+/// InstrumentDataSet equivalent ANISE file.
+///
+/// The instrument frame of each converted instrument has its +Z axis along the boresight, as expected by [Instrument], and the
+/// rotation of the instrument is from the FOV frame. The ID of the FOV frame is fetched by its name from the provided FK dataset,
+/// else it is assumed to be the instrument ID, which is the NAIF convention for most instrument frames.
+///
+/// Only circular and rectangular fields of view are supported, specified either by their boundary corners or by their angles.
+pub fn convert_ik<P: AsRef<Path> + fmt::Debug>(
+    ik_file_path: P,
+    fk: Option<&EulerParameterDataSet>,
+    show_comments: bool,
+) -> Result<InstrumentDataSet, DataSetError> {
+    let assignments = parse_file::<_, IKItem>(ik_file_path, show_comments)?;
+    convert_ik_items(assignments, fk)
+}
+
+pub fn convert_ik_items(
+    assignments: HashMap<i32, IKItem>,
+    fk: Option<&EulerParameterDataSet>,
+) -> Result<InstrumentDataSet, DataSetError> {
+    let mut dataset = InstrumentDataSet::default();
+
+    for (id, item) in assignments {
+        let get_string = |param: Parameter| -> Result<String, DataSetError> {
+            let value = item.data.get(&param).ok_or(DataSetError::Conversion {
+                action: format!("instrument {id} is missing the {param:?} parameter"),
+            })?;
+            value.to_string().map_err(|_| DataSetError::Conversion {
+                action: format!("{param:?} of instrument {id} must be a String but was {value:?}"),
+            })
+        };
+
+        let get_vector = |param: Parameter| -> Result<Vec<f64>, DataSetError> {
+            let value = item.data.get(&param).ok_or(DataSetError::Conversion {
+                action: format!("instrument {id} is missing the {param:?} parameter"),
+            })?;
+            value.to_vec_f64().map_err(|_| DataSetError::Conversion {
+                action: format!("{param:?} of instrument {id} must be a Matrix but was {value:?}"),
+            })
+        };
+
+        let get_angle_rad = |param: Parameter, to_rad: f64| -> Result<f64, DataSetError> {
+            match item.data.get(&param) {
+                Some(KPLValue::Float(angle)) => Ok(angle * to_rad),
+                Some(KPLValue::Integer(angle)) => Ok(f64::from(*angle) * to_rad),
+                Some(value) => Err(DataSetError::Conversion {
+                    action: format!(
+                        "{param:?} of instrument {id} must be a number but was {value:?}"
+                    ),
+                }),
+                None => Err(DataSetError::Conversion {
+                    action: format!("instrument {id} is missing the {param:?} parameter"),
+                }),
+            }
+        };
+
+        let shape = get_string(Parameter::FovShape)?;
+        if shape != "CIRCLE" && shape != "RECTANGLE" {
+            warn!(
+                "{id} has a {shape} FOV shape, only CIRCLE and RECTANGLE are supported -- ignoring"
+            );
+            continue;
+        }
+
+        let boresight = get_vector(Parameter::Boresight)?;
+        if boresight.len() != 3 {
+            return Err(DataSetError::Conversion {
+                action: format!(
+                    "Boresight of instrument {id} must be length 3 but was {}",
+                    boresight.len()
+                ),
+            });
+        }
+        let z_axis = Vector3::from_column_slice(&boresight).normalize();
+
+        let class_spec = match item.data.get(&Parameter::FovClassSpec) {
+            Some(_) => get_string(Parameter::FovClassSpec)?,
+            None => "CORNERS".to_string(),
+        };
+
+        // The instrument X axis is the reference direction of the FOV, orthogonal to the boresight.
+        let (reference, fov) = if class_spec == "ANGLES" {
+            let reference = get_vector(Parameter::FovRefVector)?;
+            if reference.len() != 3 {
+                return Err(DataSetError::Conversion {
+                    action: format!(
+                        "FovRefVector of instrument {id} must be length 3 but was {}",
+                        reference.len()
+                    ),
+                });
+            }
+
+            let to_rad = match get_string(Parameter::FovAngleUnits)?.as_str() {
+                "DEGREES" => 1.0_f64.to_radians(),
+                "RADIANS" => 1.0,
+                "ARCMINUTES" => (1.0_f64 / 60.0).to_radians(),
+                "ARCSECONDS" => (1.0_f64 / 3600.0).to_radians(),
+                units => {
+                    return Err(DataSetError::Conversion {
+                        action: format!(
+                            "unsupported FOV angle units `{units}` for instrument {id}"
+                        ),
+                    })
+                }
+            };
+
+            let ref_angle_deg = get_angle_rad(Parameter::FovRefAngle, to_rad)?.to_degrees();
+            let fov = if shape == "CIRCLE" {
+                FovShape::Conical {
+                    half_angle_deg: ref_angle_deg,
+                }
+            } else {
+                FovShape::Rectangular {
+                    x_half_angle_deg: ref_angle_deg,
+                    y_half_angle_deg: get_angle_rad(Parameter::FovCrossAngle, to_rad)?.to_degrees(),
+                }
+            };
+
+            (Vector3::from_column_slice(&reference), fov)
+        } else {
+            let corners = get_vector(Parameter::FovBoundaryCorners)?;
+            let corners = corners
+                .chunks_exact(3)
+                .map(Vector3::from_column_slice)
+                .collect::<Vec<Vector3>>();
+
+            if shape == "CIRCLE" {
+                let edge = corners.first().ok_or(DataSetError::Conversion {
+                    action: format!("no boundary vector for the circular FOV of instrument {id}"),
+                })?;
+                let fov = FovShape::Conical {
+                    half_angle_deg: edge.angle(&z_axis).to_degrees(),
+                };
+                (*edge, fov)
+            } else {
+                if corners.len() != 4 {
+                    return Err(DataSetError::Conversion {
+                        action: format!(
+                            "rectangular FOV of instrument {id} must have 4 corners but has {}",
+                            corners.len()
+                        ),
+                    });
+                }
+                // Consecutive corners share an edge of the rectangle, which defines the X axis.
+                let x_axis = perp_vector(&(corners[1] - corners[0]), &z_axis).normalize();
+                let y_axis = z_axis.cross(&x_axis);
+                let corner = corners[0];
+                let fov = FovShape::Rectangular {
+                    x_half_angle_deg: corner
+                        .dot(&x_axis)
+                        .abs()
+                        .atan2(corner.dot(&z_axis))
+                        .to_degrees(),
+                    y_half_angle_deg: corner
+                        .dot(&y_axis)
+                        .abs()
+                        .atan2(corner.dot(&z_axis))
+                        .to_degrees(),
+                };
+                (x_axis, fov)
+            }
+        };
+
+        let x_axis = perp_vector(&reference, &z_axis);
+        if x_axis.norm() < f64::EPSILON {
+            return Err(DataSetError::Conversion {
+                action: format!(
+                    "FOV reference direction of instrument {id} is along its boresight"
+                ),
+            });
+        }
+        let x_axis = x_axis.normalize();
+        let y_axis = z_axis.cross(&x_axis);
+
+        let frame_name = get_string(Parameter::FovFrame)?;
+        let frame_id = match fk.map(|fk| fk.get_by_name(&frame_name)) {
+            Some(Ok(q)) => q.from,
+            _ => {
+                warn!(
+                    "FOV frame `{frame_name}` of {id} not found in FK -- assuming its ID is {id}"
+                );
+                id
+            }
+        };
+
+        // The rows of the rotation matrix are the instrument axes expressed in the FOV frame.
+        let rot_mat =
+            Matrix3::from_rows(&[x_axis.transpose(), y_axis.transpose(), z_axis.transpose()]);
+
+        let instrument = Instrument {
+            q_to_i: DCM {
+                rot_mat,
+                from: frame_id,
+                to: id,
+                rot_mat_dt: None,
+            }
+            .into(),
+            offset_i: Vector3::zeros(),
+            fov,
+        };
+
+        dataset.push(instrument, Some(id), None)?;
+    }
+
+    dataset.set_crc32();
+    dataset.metadata = Metadata::default();
+    dataset.metadata.dataset_type = DataSetType::InstrumentData;
+
+    Ok(dataset)
+}
+
+#[cfg(test)]
+mod ik_convert_ut {
+    use super::{convert_ik_items, parse_bytes};
+    use crate::math::rotation::Quaternion;
+    use crate::math::Vector3;
+    use crate::naif::kpl::ik::IKItem;
+    use crate::structure::instrument::FovShape;
+    use crate::structure::EulerParameterDataSet;
+
+    #[test]
+    fn test_convert_ik() {
+        let ik = r#"
+\begindata
+
+      INS-1001_FOV_FRAME       = 'CAM_FRAME'
+      INS-1001_FOV_SHAPE       = 'RECTANGLE'
+      INS-1001_BORESIGHT       = ( 1.0, 0.0, 0.0 )
+      INS-1001_FOV_BOUNDARY_CORNERS = (
+                 1.0, 0.1, 0.2
+                 1.0, -0.1, 0.2
+                 1.0, -0.1, -0.2
+                 1.0, 0.1, -0.2 )
+
+      INS-1002_FOV_FRAME       = 'ANTENNA'
+      INS-1002_FOV_SHAPE       = 'CIRCLE'
+      INS-1002_BORESIGHT       = ( 0.0, 0.0, 1.0 )
+      INS-1002_FOV_CLASS_SPEC  = 'ANGLES'
+      INS-1002_FOV_REF_VECTOR  = ( 1.0, 0.0, 0.0 )
+      INS-1002_FOV_REF_ANGLE   = 30
+      INS-1002_FOV_ANGLE_UNITS = 'ARCMINUTES'
+
+      INS-1003_FOV_FRAME       = 'ANTENNA'
+      INS-1003_FOV_SHAPE       = 'POLYGON'
+\begintext
+"#;
+        let assignments = parse_bytes::<_, IKItem>(&mut ik.as_bytes(), false).unwrap();
+
+        let mut fk = EulerParameterDataSet::default();
+        fk.push(
+            Quaternion::identity(-500, -5),
+            Some(-500),
+            Some("CAM_FRAME"),
+        )
+        .unwrap();
+
+        let dataset = convert_ik_items(assignments, Some(&fk)).unwrap();
+        // The polygon is not supported
+        assert_eq!(dataset.len(), 2);
+
+        let camera = dataset.get_by_id(-1001).unwrap();
+        assert_eq!(camera.q_to_i.from, -500);
+        assert_eq!(camera.q_to_i.to, -1001);
+        // The boresight maps to the +Z axis of the instrument
+        assert!((camera.q_to_i * Vector3::x() - Vector3::z()).norm() < 1e-12);
+        match camera.fov {
+            FovShape::Rectangular {
+                x_half_angle_deg,
+                y_half_angle_deg,
+            } => {
+                // The first edge is along the Y axis of the FOV frame
+                assert!((x_half_angle_deg - 0.1_f64.atan().to_degrees()).abs() < 1e-12);
+                assert!((y_half_angle_deg - 0.2_f64.atan().to_degrees()).abs() < 1e-12);
+            }
+            _ => panic!("expected a rectangular FOV"),
+        }
+
+        // The antenna frame is not in the FK.
+        let antenna = dataset.get_by_id(-1002).unwrap();
+        assert_eq!(antenna.q_to_i.from, -1002);
+        assert!((antenna.q_to_i * Vector3::z() - Vector3::z()).norm() < 1e-12);
+        assert_eq!(
+            antenna.fov,
+            FovShape::Conical {
+                half_angle_deg: 0.5
+            }
+        );
+    }
+}
@@ -124,6 +124,52 @@ fn test_parse_pck() {
     );
 }
 
+#[test]
+fn test_parse_pck11_secular_terms() {
+    use crate::naif::kpl::parser::{convert_tpc, parse_file};
+    let assignments = parse_file::<_, TPCItem>("../data/pck00011.tpc", false).unwrap();
+
+    // The secular term of the lunar prime meridian uses a Fortran exponent
+    let expt_moon_pm = [38.3213, 13.17635815, -1.4e-12];
+    assert_eq!(
+        assignments[&301].data[&Parameter::PrimeMeridian],
+        KPLValue::Matrix(expt_moon_pm.into())
+    );
+
+    let dataset = convert_tpc("../data/pck00011.tpc", "../data/gm_de440.tpc").unwrap();
+
+    let moon = dataset.get_by_id(301).unwrap();
+    assert_eq!(moon.prime_meridian.unwrap().accel_deg, -1.4e-12);
+
+    // Mars nutation precession angles are quadratic: the fifth one is the Phobos libration
+    let mars = dataset.get_by_id(4).unwrap();
+    assert_eq!(mars.num_nut_prec_angles, 26);
+    assert_eq!(mars.nut_prec_angles[4].rate_deg, 41_215_158.184_200_5);
+    assert_eq!(mars.nut_prec_angles[4].accel_deg, 12.711923222);
+    assert_eq!(mars.nut_prec_angles[0].accel_deg, 0.0);
+}
+
+#[test]
+fn test_parse_gm_sigma() {
+    use crate::naif::kpl::parser::{convert_tpc_items, parse_bytes};
+    use std::io::Cursor;
+
+    let pck =
+        "KPL/PCK\n\\begindata\nBODY399_RADII = ( 6378.1366 6378.1366 6356.7519 )\n\\begintext\n";
+    let gm = "KPL/PCK\n\\begindata\nBODY399_GM = ( 3.986004354360959D+05 )\nBODY399_GM_SIGMA = ( 8.0D-03 )\n\\begintext\n";
+    let planetary_data = parse_bytes::<_, TPCItem>(&mut Cursor::new(pck), false).unwrap();
+    let gravity_data = parse_bytes::<_, TPCItem>(&mut Cursor::new(gm), false).unwrap();
+    assert_eq!(
+        gravity_data[&399].data[&Parameter::GravitationalParameterSigma],
+        KPLValue::Float(8.0e-3)
+    );
+
+    let dataset = convert_tpc_items(planetary_data, gravity_data).unwrap();
+    let earth = dataset.get_by_id(399).unwrap();
+    assert_eq!(earth.mu_sigma_km3_s2, Some(8.0e-3));
+    assert_eq!(earth.mu_km3_s2_1sigma().sigma, 8.0e-3);
+}
+
 #[test]
 fn test_parse_gm() {
     use crate::naif::kpl::parser::parse_file;
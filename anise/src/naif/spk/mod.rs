@@ -10,3 +10,7 @@
 
 // Defines how to read an SPK
 pub mod summary;
+// Rewrites SPK segments as Chebyshev segments
+pub mod resample;
+// Direct access to the segments of an SPK
+pub mod segment;
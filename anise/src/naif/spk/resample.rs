@@ -0,0 +1,298 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::f64::consts::PI;
+use hifitime::Epoch;
+use snafu::ResultExt;
+
+use crate::ephemerides::{EphemInterpolationSnafu, EphemerisError, SPKSnafu};
+use crate::math::interpolation::chebyshev_eval_poly;
+use crate::math::Vector3;
+use crate::naif::daf::datatypes::{Type2ChebyshevSet, Type3ChebyshevSet};
+use crate::naif::daf::{DAFError, DafDataType, DecodingDataSnafu, NAIFDataSet, NAIFSummaryRecord};
+use crate::naif::SPK;
+
+use super::segment::SegmentHandle;
+use super::summary::SPKSummaryRecord;
+
+/// Maximum number of records of a resampled segment.
+pub const MAX_RESAMPLED_RECORDS: usize = 1 << 16;
+
+impl SPK {
+    /// Rewrites the n-th segment of this SPK, of any supported type, as a Chebyshev segment of the requested type and degree.
+    ///
+    /// The original segment is sampled on the Chebyshev nodes of equal length records covering the whole segment. The number of records
+    /// starts at one and is doubled until the position error, evaluated between the nodes, is below `tolerance_km`. The target, center,
+    /// frame, and epochs of the segment are unchanged.
+    ///
+    /// # Limitations
+    /// + Only Type 2 (position only, the velocity is the derivative of the position) and Type 3 (position and velocity) are supported.
+    /// + The velocity coefficients of Type 3 are fitted like the position ones, but only the position error is checked.
+    /// + Like `set_nth_data`, only the segments of the first summary record can be resampled.
+    pub fn resample_nth_segment(
+        &mut self,
+        idx: usize,
+        data_type: DafDataType,
+        degree: usize,
+        tolerance_km: f64,
+    ) -> Result<(), EphemerisError> {
+        if !matches!(
+            data_type,
+            DafDataType::Type2ChebyshevTriplet | DafDataType::Type3ChebyshevSextuplet
+        ) {
+            return Err(EphemerisError::SPK {
+                action: "resampling segment",
+                source: DAFError::UnsupportedDatatype {
+                    dtype: data_type,
+                    kind: "SPK resampling",
+                },
+            });
+        }
+
+        let summary = *self
+            .data_summaries(None)
+            .context(SPKSnafu {
+                action: "resampling segment",
+            })?
+            .get(idx)
+            .ok_or(DAFError::InvalidIndex { idx, kind: "SPK" })
+            .context(SPKSnafu {
+                action: "resampling segment",
+            })?;
+
+        let mut num_records = 1;
+        let data = loop {
+            let (data, max_err_km) =
+                self.fit_chebyshev(idx, &summary, data_type, degree, num_records)?;
+            if max_err_km <= tolerance_km {
+                break data;
+            }
+            num_records *= 2;
+            if num_records > MAX_RESAMPLED_RECORDS {
+                return Err(EphemerisError::ResampleTolerance {
+                    idx,
+                    tolerance_km,
+                    max_records: MAX_RESAMPLED_RECORDS,
+                });
+            }
+        };
+
+        // Explicit calls because the Python bindings also define these as inherent methods.
+        let start = NAIFSummaryRecord::start_epoch(&summary);
+        let end = NAIFSummaryRecord::end_epoch(&summary);
+        match data_type {
+            DafDataType::Type2ChebyshevTriplet => {
+                let new_data = Type2ChebyshevSet::from_f64_slice(&data)
+                    .context(DecodingDataSnafu { kind: "SPK", idx })
+                    .context(SPKSnafu {
+                        action: "resampling segment",
+                    })?;
+                self.set_nth_data(idx, new_data, start, end)
+            }
+            _ => {
+                let new_data = Type3ChebyshevSet::from_f64_slice(&data)
+                    .context(DecodingDataSnafu { kind: "SPK", idx })
+                    .context(SPKSnafu {
+                        action: "resampling segment",
+                    })?;
+                self.set_nth_data(idx, new_data, start, end)
+            }
+        }
+        .context(SPKSnafu {
+            action: "resampling segment",
+        })?;
+
+        // The data was replaced, now update the data type of its summary.
+        let mut new_summary = self.data_summaries(None).context(SPKSnafu {
+            action: "resampling segment",
+        })?[idx];
+        new_summary.data_type_i = data_type as i32;
+        self.set_nth_summary(idx, new_summary).context(SPKSnafu {
+            action: "resampling segment",
+        })
+    }
+
+    /// Fits the n-th segment with the provided number of Chebyshev records, returning the DAF array and the maximum position error.
+    fn fit_chebyshev(
+        &self,
+        idx: usize,
+        summary: &SPKSummaryRecord,
+        data_type: DafDataType,
+        degree: usize,
+        num_records: usize,
+    ) -> Result<(Vec<f64>, f64), EphemerisError> {
+        let handle = SegmentHandle { daf_idx: None, idx };
+        let num_coeffs = degree + 1;
+        let with_velocity = data_type == DafDataType::Type3ChebyshevSextuplet;
+        let num_axes = if with_velocity { 6 } else { 3 };
+        let rsize = 2 + num_axes * num_coeffs;
+
+        let start_et_s = summary.start_epoch_et_s;
+        let interval_s = (summary.end_epoch_et_s - start_et_s) / num_records as f64;
+        let radius_s = interval_s / 2.0;
+
+        // Chebyshev nodes of the first kind, where the fit is exact.
+        let nodes = (0..num_coeffs)
+            .map(|k| (PI * (k as f64 + 0.5) / num_coeffs as f64).cos())
+            .collect::<Vec<f64>>();
+
+        let mut data = Vec::with_capacity(rsize * num_records + 4);
+        let mut max_err_km = 0.0_f64;
+
+        for rno in 0..num_records {
+            let midpoint_et_s = start_et_s + (rno as f64 + 0.5) * interval_s;
+
+            // Samples of each axis at each node
+            let mut samples = vec![vec![0.0; num_coeffs]; num_axes];
+            for (k, node) in nodes.iter().enumerate() {
+                let epoch = Epoch::from_et_seconds(midpoint_et_s + node * radius_s);
+                let (pos_km, vel_km_s) = self.evaluate_summary(handle, summary, epoch)?;
+                for axis in 0..3 {
+                    samples[axis][k] = pos_km[axis];
+                    if with_velocity {
+                        samples[axis + 3][k] = vel_km_s[axis];
+                    }
+                }
+            }
+
+            data.push(midpoint_et_s);
+            data.push(radius_s);
+            let mut coeffs = Vec::with_capacity(num_axes);
+            for axis_samples in &samples {
+                let axis_coeffs = (0..num_coeffs)
+                    .map(|j| {
+                        let sum = axis_samples
+                            .iter()
+                            .zip(&nodes)
+                            .map(|(sample, node)| sample * (j as f64 * node.acos()).cos())
+                            .sum::<f64>();
+                        if j == 0 {
+                            sum / num_coeffs as f64
+                        } else {
+                            2.0 * sum / num_coeffs as f64
+                        }
+                    })
+                    .collect::<Vec<f64>>();
+                data.extend_from_slice(&axis_coeffs);
+                coeffs.push(axis_coeffs);
+            }
+
+            // Check the position error between the nodes.
+            let num_checks = 2 * num_coeffs;
+            for j in 0..num_checks {
+                let normalized_time = -1.0 + (2 * j + 1) as f64 / num_checks as f64;
+                let epoch = Epoch::from_et_seconds(midpoint_et_s + normalized_time * radius_s);
+                let (pos_km, _) = self.evaluate_summary(handle, summary, epoch)?;
+                let mut fitted_km = Vector3::zeros();
+                for axis in 0..3 {
+                    fitted_km[axis] =
+                        chebyshev_eval_poly(normalized_time, &coeffs[axis], epoch, degree)
+                            .context(EphemInterpolationSnafu)?;
+                }
+                max_err_km = max_err_km.max((fitted_km - pos_km).norm());
+            }
+        }
+
+        data.push(start_et_s);
+        data.push(interval_s);
+        data.push(rsize as f64);
+        data.push(num_records as f64);
+
+        Ok((data, max_err_km))
+    }
+}
+
+#[cfg(test)]
+mod ut_resample {
+    use crate::constants::frames::{EARTH_J2000, MOON_J2000};
+    use crate::ephemerides::EphemerisError;
+    use crate::naif::daf::datatypes::Type3ChebyshevSet;
+    use crate::naif::daf::test_utils::{chebyshev_segment, test_spk};
+    use crate::naif::daf::{DafDataType, NAIFDataSet, NAIFSummaryRecord};
+    use crate::naif::SPK;
+    use crate::prelude::Almanac;
+    use hifitime::Epoch;
+
+    /// Builds an SPK where the Moon X position is (t / 1 day)^2 km, using two degree two Chebyshev Type 2 records.
+    fn quadratic_spk() -> SPK {
+        let records = [
+            [[0.375, 0.5, 0.125], [0.0; 3], [0.0; 3]],
+            [[2.375, 1.5, 0.125], [0.0; 3], [0.0; 3]],
+        ];
+        test_spk(&[chebyshev_segment(301, 399, 0.0, 86_400.0, &records)])
+    }
+
+    #[test]
+    fn resample_as_type2() {
+        let mut spk = quadratic_spk();
+        // A single quadratic record is exact
+        spk.resample_nth_segment(0, DafDataType::Type2ChebyshevTriplet, 2, 1e-9)
+            .unwrap();
+        let summary = spk.data_summaries(None).unwrap()[0];
+        assert_eq!(summary.target_id, 301);
+        assert_eq!(summary.end_idx - summary.start_idx + 1, 11 + 4);
+
+        let almanac = Almanac::default().with_spk(spk);
+        let state = almanac
+            .translate(
+                MOON_J2000,
+                EARTH_J2000,
+                Epoch::from_et_seconds(129600.0),
+                None,
+            )
+            .unwrap();
+        assert!((state.radius_km.x - 2.25).abs() < 1e-9);
+        assert!((state.velocity_km_s.x - 3.0 / 86400.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn resample_as_type3() {
+        let mut spk = quadratic_spk();
+        // A linear fit requires several records to reach a meter of accuracy
+        spk.resample_nth_segment(0, DafDataType::Type3ChebyshevSextuplet, 1, 1e-3)
+            .unwrap();
+        let summary = spk.data_summaries(None).unwrap()[0];
+        assert_eq!(
+            summary.data_type().unwrap(),
+            DafDataType::Type3ChebyshevSextuplet
+        );
+        let data = spk.nth_data::<Type3ChebyshevSet>(None, 0).unwrap();
+        assert_eq!(data.num_records, 32);
+        assert_eq!(data.degree(), 1);
+
+        let (pos_km, vel_km_s) = data
+            .evaluate(Epoch::from_et_seconds(100_000.0), &summary)
+            .unwrap();
+        let u = 100_000.0 / 86400.0;
+        assert!((pos_km.x - u * u).abs() < 1e-3);
+        assert!((vel_km_s.x - 2.0 * u / 86400.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn resample_errors() {
+        let mut spk = quadratic_spk();
+        assert!(spk
+            .resample_nth_segment(0, DafDataType::Type13HermiteUnequalStep, 2, 1.0)
+            .is_err());
+        assert!(spk
+            .resample_nth_segment(1, DafDataType::Type2ChebyshevTriplet, 2, 1.0)
+            .is_err());
+        // A constant cannot fit a quadratic at the nanometer level
+        assert!(matches!(
+            spk.resample_nth_segment(0, DafDataType::Type2ChebyshevTriplet, 0, 1e-12),
+            Err(EphemerisError::ResampleTolerance { .. })
+        ));
+        // And the segment was not modified
+        assert_eq!(
+            spk.data_summaries(None).unwrap()[0].data_type().unwrap(),
+            DafDataType::Type2ChebyshevTriplet
+        );
+    }
+}
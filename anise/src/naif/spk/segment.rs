@@ -0,0 +1,293 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use hifitime::{Duration, Epoch};
+use snafu::ResultExt;
+
+use crate::ephemerides::{EphemInterpolationSnafu, EphemerisError, SPKSnafu};
+use crate::math::interpolation::InterpDecodingSnafu;
+use crate::math::Vector3;
+use crate::naif::daf::datatypes::modified_diff::ModifiedDiffType1;
+use crate::naif::daf::datatypes::{
+    HermiteSetType12, HermiteSetType13, LagrangeSetType8, LagrangeSetType9, Type2ChebyshevSet,
+    Type3ChebyshevSet,
+};
+use crate::naif::daf::{DAFError, DafDataType, NAIFDataSet, NAIFSummaryRecord};
+use crate::naif::SPK;
+use crate::NaifId;
+
+use super::summary::SPKSummaryRecord;
+
+/// Identifies a segment in a specific SPK, regardless of which kernel the Almanac would use for its target.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SegmentHandle {
+    /// Index of the summary record containing this segment, None for the first summary record
+    pub daf_idx: Option<usize>,
+    /// Index of this segment in its summary record
+    pub idx: usize,
+}
+
+impl fmt::Display for SegmentHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.daf_idx {
+            Some(daf_idx) => write!(f, "segment #{} of summary record {daf_idx}", self.idx),
+            None => write!(f, "segment #{}", self.idx),
+        }
+    }
+}
+
+impl SPK {
+    /// Returns the handles and summaries of all of the segments of this SPK for the provided target ID, in the order in which they are stored.
+    pub fn segment_handles(
+        &self,
+        id: NaifId,
+    ) -> Result<Vec<(SegmentHandle, SPKSummaryRecord)>, DAFError> {
+        let mut handles = Vec::new();
+        let mut daf_idx = None;
+        loop {
+            for (idx, summary) in self.data_summaries(daf_idx)?.iter().enumerate() {
+                if !summary.is_empty() && summary.id() == id {
+                    handles.push((SegmentHandle { daf_idx, idx }, *summary));
+                }
+            }
+            let daf_summary = self.daf_summary(daf_idx)?;
+            if daf_summary.is_final_record() || Some(daf_summary.next_record()) == daf_idx {
+                break;
+            }
+            daf_idx = Some(daf_summary.next_record());
+        }
+        Ok(handles)
+    }
+
+    /// Evaluates the segment with the provided handle at the provided epoch, returning the position and velocity of its target with
+    /// respect to its center, in the frame of the segment. This does not use the frame tree, nor any other loaded kernel.
+    pub fn evaluate_segment(
+        &self,
+        handle: SegmentHandle,
+        epoch: Epoch,
+    ) -> Result<(Vector3, Vector3), EphemerisError> {
+        let summary = self
+            .data_summaries(handle.daf_idx)
+            .context(SPKSnafu {
+                action: "fetching segment summary",
+            })?
+            .get(handle.idx)
+            .ok_or(DAFError::InvalidIndex {
+                idx: handle.idx,
+                kind: "SPK",
+            })
+            .context(SPKSnafu {
+                action: "fetching segment summary",
+            })?;
+
+        self.evaluate_summary(handle, summary, epoch)
+    }
+
+    /// Returns the epochs of the record boundaries of the segment with the provided handle, in chronological order.
+    ///
+    /// Aligning a sampling grid on these epochs avoids straddling two records, which minimizes the growth of the interpolation
+    /// error when validating or resampling a segment. For Chebyshev segments (Type 2 and Type 3), these are the edges of each
+    /// record. Other segment types interpolate over a sliding window of states, so these are the epochs of each of the states.
+    pub fn segment_record_boundaries(
+        &self,
+        handle: SegmentHandle,
+    ) -> Result<Vec<Epoch>, EphemerisError> {
+        let action = "fetching record boundaries";
+        let SegmentHandle { daf_idx, idx } = handle;
+        let summary = *self
+            .data_summaries(daf_idx)
+            .context(SPKSnafu { action })?
+            .get(idx)
+            .ok_or(DAFError::InvalidIndex { idx, kind: "SPK" })
+            .context(SPKSnafu { action })?;
+
+        let from_et_s = |epochs: &[f64]| -> Vec<Epoch> {
+            epochs.iter().copied().map(Epoch::from_et_seconds).collect()
+        };
+        let equal_steps = |first: Epoch, step: Duration, count: usize| -> Vec<Epoch> {
+            (0..count).map(|n| first + step * (n as i64)).collect()
+        };
+
+        match summary.data_type()? {
+            DafDataType::Type1ModifiedDifferenceArray => Ok(from_et_s(
+                self.nth_data::<ModifiedDiffType1>(daf_idx, idx)
+                    .context(SPKSnafu { action })?
+                    .epoch_data,
+            )),
+            DafDataType::Type2ChebyshevTriplet => self
+                .nth_data::<Type2ChebyshevSet>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .record_boundaries()
+                .context(InterpDecodingSnafu)
+                .context(EphemInterpolationSnafu),
+            DafDataType::Type3ChebyshevSextuplet => self
+                .nth_data::<Type3ChebyshevSet>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .record_boundaries()
+                .context(InterpDecodingSnafu)
+                .context(EphemInterpolationSnafu),
+            DafDataType::Type8LagrangeEqualStep => {
+                let data = self
+                    .nth_data::<LagrangeSetType8>(daf_idx, idx)
+                    .context(SPKSnafu { action })?;
+                Ok(equal_steps(
+                    data.first_state_epoch,
+                    data.step_size,
+                    data.num_records,
+                ))
+            }
+            DafDataType::Type9LagrangeUnequalStep => Ok(from_et_s(
+                self.nth_data::<LagrangeSetType9>(daf_idx, idx)
+                    .context(SPKSnafu { action })?
+                    .epoch_data,
+            )),
+            DafDataType::Type12HermiteEqualStep => {
+                let data = self
+                    .nth_data::<HermiteSetType12>(daf_idx, idx)
+                    .context(SPKSnafu { action })?;
+                Ok(equal_steps(
+                    data.first_state_epoch,
+                    data.step_size,
+                    data.num_records,
+                ))
+            }
+            DafDataType::Type13HermiteUnequalStep => Ok(from_et_s(
+                self.nth_data::<HermiteSetType13>(daf_idx, idx)
+                    .context(SPKSnafu { action })?
+                    .epoch_data,
+            )),
+            dtype => Err(EphemerisError::SPK {
+                action,
+                source: DAFError::UnsupportedDatatype {
+                    dtype,
+                    kind: "SPK computations",
+                },
+            }),
+        }
+    }
+
+    /// Evaluates the segment with the provided handle and summary at the provided epoch.
+    pub(crate) fn evaluate_summary(
+        &self,
+        handle: SegmentHandle,
+        summary: &SPKSummaryRecord,
+        epoch: Epoch,
+    ) -> Result<(Vector3, Vector3), EphemerisError> {
+        let action = "fetching data for interpolation";
+        let SegmentHandle { daf_idx, idx } = handle;
+        match summary.data_type()? {
+            DafDataType::Type1ModifiedDifferenceArray => self
+                .nth_data::<ModifiedDiffType1>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            DafDataType::Type2ChebyshevTriplet => self
+                .nth_data::<Type2ChebyshevSet>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            DafDataType::Type3ChebyshevSextuplet => self
+                .nth_data::<Type3ChebyshevSet>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            DafDataType::Type8LagrangeEqualStep => self
+                .nth_data::<LagrangeSetType8>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            DafDataType::Type9LagrangeUnequalStep => self
+                .nth_data::<LagrangeSetType9>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            DafDataType::Type12HermiteEqualStep => self
+                .nth_data::<HermiteSetType12>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            DafDataType::Type13HermiteUnequalStep => self
+                .nth_data::<HermiteSetType13>(daf_idx, idx)
+                .context(SPKSnafu { action })?
+                .evaluate(epoch, summary),
+            dtype => {
+                return Err(EphemerisError::SPK {
+                    action: "evaluating segment",
+                    source: DAFError::UnsupportedDatatype {
+                        dtype,
+                        kind: "SPK computations",
+                    },
+                })
+            }
+        }
+        .context(EphemInterpolationSnafu)
+    }
+}
+
+#[cfg(test)]
+mod ut_segment {
+    use super::SegmentHandle;
+    use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+    use crate::naif::SPK;
+    use hifitime::Epoch;
+
+    /// Builds an SPK with two overlapping fixed Moon segments, at X = 1 km and X = 2 km respectively.
+    fn duplicate_moon_spk() -> SPK {
+        test_spk(&[
+            fixed_segment(301, 399, [1.0, 0.0, 0.0]),
+            fixed_segment(301, 399, [2.0, 0.0, 0.0]),
+        ])
+    }
+
+    #[test]
+    fn evaluate_each_segment() {
+        let spk = duplicate_moon_spk();
+        let epoch = Epoch::from_et_seconds(1000.0);
+
+        let handles = spk.segment_handles(301).unwrap();
+        assert_eq!(handles.len(), 2);
+        assert_eq!(
+            handles[1].0,
+            SegmentHandle {
+                daf_idx: None,
+                idx: 1
+            }
+        );
+        assert_eq!(handles[1].1.center_id, 399);
+
+        let (first_km, _) = spk.evaluate_segment(handles[0].0, epoch).unwrap();
+        let (second_km, vel_km_s) = spk.evaluate_segment(handles[1].0, epoch).unwrap();
+        assert_eq!(first_km.x, 1.0);
+        assert_eq!(second_km.x, 2.0);
+        assert_eq!(vel_km_s.norm(), 0.0);
+
+        assert!(spk.segment_handles(399).unwrap().is_empty());
+        assert_eq!(
+            spk.segment_record_boundaries(handles[1].0).unwrap(),
+            vec![Epoch::from_et_seconds(0.0), Epoch::from_et_seconds(86400.0)]
+        );
+        assert!(spk
+            .segment_record_boundaries(SegmentHandle {
+                daf_idx: None,
+                idx: 2
+            })
+            .is_err());
+
+        // Invalid handle
+        assert!(spk
+            .evaluate_segment(
+                SegmentHandle {
+                    daf_idx: None,
+                    idx: 2
+                },
+                epoch
+            )
+            .is_err());
+        // Outside of the segment
+        assert!(spk
+            .evaluate_segment(handles[0].0, Epoch::from_et_seconds(-86400.0))
+            .is_err());
+    }
+}
@@ -63,4 +63,6 @@ pub enum OrientationError {
     },
     #[snafu(display("unknown orientation ID associated with `{name}`"))]
     OrientationNameToId { name: String },
+    #[snafu(display("the UT1 source of the Almanac does not cover {epoch}"))]
+    MissingUt1 { epoch: Epoch },
 }
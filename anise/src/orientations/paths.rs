@@ -30,7 +30,10 @@ impl Almanac {
     /// 2. For each summary record in each BPC, follow the orientation branch all the way up until the end of this BPC or until the J2000.
     pub fn try_find_orientation_root(&self) -> Result<NaifId, OrientationError> {
         ensure!(
-            self.num_loaded_bpc() > 0 || !self.planetary_data.is_empty(),
+            self.num_loaded_bpc() > 0
+                || !self.planetary_data.is_empty()
+                || !self.euler_param_data.is_empty()
+                || !self.articulation_data.is_empty(),
             NoOrientationsLoadedSnafu
         );
 
@@ -69,6 +72,35 @@ impl Almanac {
             }
         }
 
+        // Finally, the parents of the Euler parameters and of the articulations.
+        for data in self.euler_param_data.values().rev() {
+            for id in data.lut.by_id.keys() {
+                if let Ok(ep) = data.get_by_id(*id) {
+                    if ep.to.abs() < common_center.abs() {
+                        common_center = ep.to;
+                        if common_center == J2000 {
+                            // there is nothing higher up
+                            return Ok(common_center);
+                        }
+                    }
+                }
+            }
+        }
+
+        for data in self.articulation_data.values().rev() {
+            for id in data.lut.by_id.keys() {
+                if let Ok(articulation) = data.get_by_id(*id) {
+                    if articulation.parent_id.abs() < common_center.abs() {
+                        common_center = articulation.parent_id;
+                        if common_center == J2000 {
+                            // there is nothing higher up
+                            return Ok(common_center);
+                        }
+                    }
+                }
+            }
+        }
+
         if common_center == ECLIPJ2000 {
             // Rotation from ecliptic J2000 to J2000 is embedded.
             common_center = J2000;
@@ -102,8 +134,8 @@ impl Almanac {
                 match self.get_planetary_data_from_id(source.orientation_id) {
                     Ok(planetary_data) => planetary_data.parent_id,
                     Err(_) => {
-                        // Finally, let's see if it's in the loaded Euler Parameters.
-                        self.euler_param_from_id(source.orientation_id)?.to
+                        // Finally, let's see if it's in the loaded Euler Parameters or articulations.
+                        self.euler_or_articulation_parent_id(source.orientation_id)?
                     }
                 }
             }
@@ -132,8 +164,8 @@ impl Almanac {
                     match self.get_planetary_data_from_id(inertial_frame_id) {
                         Ok(planetary_data) => planetary_data.parent_id,
                         Err(_) => {
-                            // Finally, let's see if it's in the loaded Euler Parameters.
-                            self.euler_param_from_id(inertial_frame_id)?.to
+                            // Finally, let's see if it's in the loaded Euler Parameters or articulations.
+                            self.euler_or_articulation_parent_id(inertial_frame_id)?
                         }
                     }
                 }
@@ -199,4 +231,15 @@ impl Almanac {
             Ok((items, common_path, common_node))
         }
     }
+
+    /// Returns the parent orientation ID of a frame defined by Euler parameters or, failing that, by an articulation.
+    fn euler_or_articulation_parent_id(&self, id: NaifId) -> Result<NaifId, OrientationError> {
+        match self.euler_param_from_id(id) {
+            Ok(ep) => Ok(ep.to),
+            Err(e) => self
+                .articulation_from_id(id)
+                .map(|articulation| articulation.parent_id)
+                .map_err(|_| e),
+        }
+    }
 }
@@ -13,8 +13,8 @@ use snafu::ResultExt;
 
 use super::{OrientationError, OrientationPhysicsSnafu};
 use crate::almanac::Almanac;
-use crate::constants::orientations::{ECLIPJ2000, J2000, J2000_TO_ECLIPJ2000_ANGLE_RAD};
-use crate::hifitime::Epoch;
+use crate::constants::orientations::{ECLIPJ2000, IAU_EARTH, J2000, J2000_TO_ECLIPJ2000_ANGLE_RAD};
+use crate::hifitime::{Duration, Epoch};
 use crate::math::rotation::{r1, r1_dot, r3, r3_dot, DCM};
 use crate::naif::daf::datatypes::Type2ChebyshevSet;
 use crate::naif::daf::{DAFError, DafDataType, NAIFDataSet, NAIFSummaryRecord};
@@ -112,17 +112,39 @@ impl Almanac {
                             Ok(parent) => parent,
                             Err(_) => planetary_data,
                         };
+                        let planetary_data = self.with_overrides(planetary_data);
+                        let system_data = self.with_overrides(system_data);
+
+                        // The rotation of the IAU Earth frame is driven by UT1 if the Almanac has a UT1 source.
+                        let tdb_minus_ut1 =
+                            if source.orientation_id == IAU_EARTH && self.ut1_provider.is_some() {
+                                self.tdb_minus_ut1(epoch)
+                                    .ok_or(OrientationError::MissingUt1 { epoch })?
+                            } else {
+                                Duration::ZERO
+                            };
 
                         return planetary_data
-                            .rotation_to_parent(epoch, &system_data)
+                            .rotation_to_parent_ut1(epoch, &system_data, tdb_minus_ut1)
                             .context(OrientationPhysicsSnafu);
                     }
                 }
 
                 trace!("query {source} wrt to its parent @ {epoch:E} using Euler parameter data");
-                // Finally, let's see if it's in the loaded Euler Parameters.
+                // Then, let's see if it's in the loaded Euler Parameters.
                 // We can call `into` because EPs can be converted directly into DCMs.
-                Ok(self.euler_param_from_id(source.orientation_id)?.into())
+                match self.euler_param_from_id(source.orientation_id) {
+                    Ok(ep) => Ok(ep.into()),
+                    Err(e) => {
+                        // Finally, let's see if it's an articulation, which may not exist either.
+                        let articulation = self
+                            .articulation_from_id(source.orientation_id)
+                            .map_err(|_| e)?;
+                        articulation
+                            .rotation_to_parent(source.orientation_id, epoch)
+                            .context(OrientationPhysicsSnafu)
+                    }
+                }
             }
         }
     }
@@ -20,6 +20,7 @@ use crate::math::rotation::DCM;
 use crate::math::units::*;
 use crate::math::Vector3;
 use crate::prelude::Frame;
+use crate::NaifId;
 
 impl Almanac {
     /// Returns the 6x6 DCM needed to rotation the `from_frame` to the `to_frame`.
@@ -36,6 +37,31 @@ impl Almanac {
     /// 3.  Iteratively traverse the orientation tree from the `from` and `to` frames up to the common ancestor, composing the DCMs at each step using `rotation_to_parent`.
     /// 4.  The final DCM is the composition of the forward and backward DCMs.
     pub fn rotate(
+        &self,
+        from_frame: Frame,
+        to_frame: Frame,
+        epoch: Epoch,
+    ) -> Result<DCM, OrientationError> {
+        // Rotate between the underlying frames of frame switches, but keep the IDs of the aliases in the DCM.
+        let from_id = self.resolve_frame_switch(from_frame, epoch).orientation_id;
+        let to_id = self.resolve_frame_switch(to_frame, epoch).orientation_id;
+        if from_id != from_frame.orientation_id || to_id != to_frame.orientation_id {
+            // Resolve the aliases exactly once, so that chained or cyclic switches cannot recurse.
+            let mut dcm = self.rotate_unswitched(
+                from_frame.with_orient(from_id),
+                to_frame.with_orient(to_id),
+                epoch,
+            )?;
+            dcm.from = from_frame.orientation_id;
+            dcm.to = to_frame.orientation_id;
+            return Ok(dcm);
+        }
+
+        self.rotate_unswitched(from_frame, to_frame, epoch)
+    }
+
+    /// Rotation between frames which are not resolved as frame switches.
+    fn rotate_unswitched(
         &self,
         from_frame: Frame,
         mut to_frame: Frame,
@@ -137,6 +163,23 @@ impl Almanac {
         (dcm * state).context(OrientationPhysicsSnafu {})
     }
 
+    /// Returns the provided vector, expressed in the body frame of a spacecraft (e.g. an instrument boresight), in the target frame.
+    ///
+    /// The body frame is the orientation ID of the spacecraft attitude, which must be loaded as Euler parameter or BPC data, and
+    /// the rotation from the body frame to the target frame is computed through the orientation tree, like [Almanac::rotate].
+    pub fn body_vector_in_frame(
+        &self,
+        sc_id: NaifId,
+        body_vector: Vector3,
+        target_frame: Frame,
+        epoch: Epoch,
+    ) -> Result<Vector3, OrientationError> {
+        let body_frame = Frame::new(target_frame.ephemeris_id, sc_id);
+        let dcm = self.rotate(body_frame, target_frame, epoch)?;
+
+        Ok(dcm * body_vector)
+    }
+
     /// Returns the angular velocity vector in rad/s of the from_frame wrt to the to_frame.
     ///
     /// This can be used to compute the angular velocity of the Earth ITRF93 frame with respect to the J2000 frame for example.
@@ -229,3 +272,119 @@ impl Almanac {
         (dcm * input_state).context(OrientationPhysicsSnafu {})
     }
 }
+
+#[cfg(test)]
+mod ut_rotations {
+    use crate::constants::frames::{EARTH_J2000, EME2000};
+    use crate::math::rotation::{Quaternion, DCM};
+    use crate::math::Vector3;
+    use crate::prelude::{Almanac, Frame};
+    use crate::structure::articulation::Articulation;
+    use crate::structure::dataset::DataSetType;
+    use crate::structure::{ArticulationDataSet, EulerParameterDataSet};
+    use bytes::BytesMut;
+    use core::f64::consts::FRAC_PI_2;
+    use der::Encode;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn body_vector_in_frame() {
+        // Spacecraft body frame rotated by 90 degrees about Z from J2000, and an instrument frame rotated by 90 degrees about Y from the body.
+        let body_to_j2000 = Quaternion::about_z(FRAC_PI_2, -85000, 1);
+        let instrument_to_body = Quaternion::about_y(FRAC_PI_2, -85100, -85000);
+        let mut dataset = EulerParameterDataSet::default();
+        dataset
+            .push(body_to_j2000, Some(-85000), Some("SC_BODY"))
+            .unwrap();
+        dataset
+            .push(instrument_to_body, Some(-85100), Some("SC_INSTRUMENT"))
+            .unwrap();
+        let almanac = Almanac::default().with_euler_parameters(dataset);
+        let epoch = Epoch::from_et_seconds(0.0);
+
+        let boresight = Vector3::new(0.0, 0.0, 1.0);
+        let in_j2000 = almanac
+            .body_vector_in_frame(-85000, boresight, EARTH_J2000, epoch)
+            .unwrap();
+        let expected = DCM::from(body_to_j2000) * boresight;
+        assert!((in_j2000 - expected).norm() < 1e-12, "{in_j2000}");
+        let x_in_j2000 = almanac
+            .body_vector_in_frame(-85000, Vector3::x(), EME2000, epoch)
+            .unwrap();
+        assert!((x_in_j2000 - DCM::from(body_to_j2000) * Vector3::x()).norm() < 1e-12);
+
+        // Chained through the body frame, and the norm is preserved
+        let in_j2000 = almanac
+            .body_vector_in_frame(-85100, Vector3::new(1.0, 2.0, 3.0), EARTH_J2000, epoch)
+            .unwrap();
+        let expected = DCM::from(body_to_j2000)
+            * (DCM::from(instrument_to_body) * Vector3::new(1.0, 2.0, 3.0));
+        assert!(
+            (in_j2000 - expected).norm() < 1e-12,
+            "{in_j2000} != {expected}"
+        );
+
+        // Unknown attitude
+        assert!(almanac
+            .body_vector_in_frame(-99000, boresight, EARTH_J2000, epoch)
+            .is_err());
+    }
+
+    #[test]
+    fn articulated_appendage() {
+        let start = Epoch::from_et_seconds(0.0);
+        // Body aligned with J2000, and a solar array rotating about the body Y axis at 1 deg/s.
+        let mut body = EulerParameterDataSet::default();
+        body.push(
+            Quaternion::identity(-85000, 1),
+            Some(-85000),
+            Some("SC_BODY"),
+        )
+        .unwrap();
+        let sada = Articulation::new(
+            -85000,
+            Vector3::y(),
+            &[(start, 0.0), (start + Unit::Second * 180, 180.0)],
+        )
+        .unwrap();
+        let mut arrays = ArticulationDataSet::default();
+        arrays.push(sada, Some(-85010), Some("SC_SA")).unwrap();
+        arrays.metadata.dataset_type = DataSetType::ArticulationData;
+        arrays.set_crc32();
+
+        // Articulation data is loaded like any other dataset.
+        let mut buf = vec![];
+        arrays.encode_to_vec(&mut buf).unwrap();
+        let almanac = Almanac::default()
+            .with_euler_parameters(body)
+            .load_from_bytes(BytesMut::from(&buf[..]))
+            .unwrap();
+        assert_eq!(almanac.articulation_data.len(), 1);
+
+        // The array normal along its +Z axis sweeps from +Z to +X of J2000.
+        for (elapsed_s, expected) in [(0, Vector3::z()), (90, Vector3::x()), (180, -Vector3::z())] {
+            let normal = almanac
+                .body_vector_in_frame(
+                    -85010,
+                    Vector3::z(),
+                    EARTH_J2000,
+                    start + Unit::Second * elapsed_s,
+                )
+                .unwrap();
+            assert!((normal - expected).norm() < 1e-9, "{elapsed_s}: {normal}");
+        }
+
+        // The rotation rate of the array is included, and the profile bounds are enforced.
+        let omega = almanac
+            .angular_velocity_deg_s(
+                Frame::new(399, -85010),
+                EARTH_J2000,
+                start + Unit::Second * 30,
+            )
+            .unwrap();
+        assert!((omega.norm() - 1.0).abs() < 1e-9, "{omega}");
+        assert!(almanac
+            .body_vector_in_frame(-85010, Vector3::z(), EARTH_J2000, start - Unit::Second * 1)
+            .is_err());
+    }
+}
@@ -0,0 +1,245 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+use core::fmt;
+use der::{Decode, Encode, Reader, Writer};
+use hifitime::Epoch;
+use snafu::{ensure, OptionExt};
+
+use super::dataset::DataSetT;
+use crate::astro::PhysicsResult;
+use crate::errors::{ArticulationEpochSnafu, InvalidArticulationSnafu};
+use crate::math::interpolation::linear_segment;
+use crate::math::rotation::DCM;
+use crate::math::{Matrix3, Vector3};
+use crate::NaifId;
+
+/// Articulation of an appendage (e.g. a solar array drive or a high gain antenna gimbal) about a single axis of its parent frame,
+/// driven by a profile of angles over time.
+///
+/// The articulation frame coincides with the parent frame at a zero angle, and rotates about the axis by the angle of the profile,
+/// which is linearly interpolated between its samples. A profile with a single sample defines a fixed angle at all epochs.
+/// Use an Euler parameter frame between the parent and the articulation to model a mounting rotation, and chain articulations
+/// to model multi-axis gimbals.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Articulation {
+    /// Orientation ID of the parent frame, e.g. the spacecraft body frame
+    pub parent_id: NaifId,
+    /// Unit vector of the rotation axis, in the parent frame
+    pub axis: Vector3,
+    /// Epochs of the samples of the angle profile, in seconds past J2000 TDB, in increasing order
+    pub epochs_tdb_s: Vec<f64>,
+    /// Angles of the samples of the angle profile, in degrees
+    pub angles_deg: Vec<f64>,
+}
+
+impl Articulation {
+    /// Builds a new articulation about the provided axis of the parent frame, from the samples of its angle profile in degrees.
+    pub fn new(parent_id: NaifId, axis: Vector3, profile: &[(Epoch, f64)]) -> PhysicsResult<Self> {
+        ensure!(
+            !profile.is_empty(),
+            InvalidArticulationSnafu {
+                detail: "the angle profile is empty"
+            }
+        );
+        ensure!(
+            axis.norm() > f64::EPSILON,
+            InvalidArticulationSnafu {
+                detail: "the rotation axis is zero"
+            }
+        );
+        ensure!(
+            profile.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            InvalidArticulationSnafu {
+                detail: "the epochs of the angle profile are not strictly increasing"
+            }
+        );
+
+        Ok(Self {
+            parent_id,
+            axis: axis.normalize(),
+            epochs_tdb_s: profile
+                .iter()
+                .map(|(epoch, _)| epoch.to_tdb_seconds())
+                .collect(),
+            angles_deg: profile.iter().map(|(_, angle_deg)| *angle_deg).collect(),
+        })
+    }
+
+    /// Returns the angle in degrees and the angular rate in deg/s of the articulation at the provided epoch.
+    pub fn angle_and_rate_deg(&self, epoch: Epoch) -> PhysicsResult<(f64, f64)> {
+        ensure!(
+            !self.epochs_tdb_s.is_empty() && self.epochs_tdb_s.len() == self.angles_deg.len(),
+            InvalidArticulationSnafu {
+                detail: "the angle profile is empty or inconsistent"
+            }
+        );
+
+        let (idx, fraction) = linear_segment(&self.epochs_tdb_s, epoch.to_tdb_seconds()).context(
+            ArticulationEpochSnafu {
+                epoch,
+                start: Epoch::from_tdb_seconds(self.epochs_tdb_s[0]),
+                end: Epoch::from_tdb_seconds(self.epochs_tdb_s[self.epochs_tdb_s.len() - 1]),
+            },
+        )?;
+
+        if self.angles_deg.len() == 1 {
+            return Ok((self.angles_deg[0], 0.0));
+        }
+
+        let (t0, t1) = (self.epochs_tdb_s[idx], self.epochs_tdb_s[idx + 1]);
+        let (a0, a1) = (self.angles_deg[idx], self.angles_deg[idx + 1]);
+
+        Ok((a0 + (a1 - a0) * fraction, (a1 - a0) / (t1 - t0)))
+    }
+
+    /// Returns the angle in degrees of the articulation at the provided epoch.
+    pub fn angle_deg(&self, epoch: Epoch) -> PhysicsResult<f64> {
+        Ok(self.angle_and_rate_deg(epoch)?.0)
+    }
+
+    /// Returns the DCM from the articulation frame, whose orientation ID is provided, to its parent frame at the provided epoch,
+    /// including its time derivative. Like Euler parameters, this DCM converts vectors of the articulation frame into the parent frame.
+    pub fn rotation_to_parent(&self, id: NaifId, epoch: Epoch) -> PhysicsResult<DCM> {
+        let (angle_deg, rate_deg_s) = self.angle_and_rate_deg(epoch)?;
+        let (s, c) = angle_deg.to_radians().sin_cos();
+        let k = self.axis.cross_matrix();
+        let rot_mat = Matrix3::identity() + s * k + (1.0 - c) * k * k;
+
+        Ok(DCM {
+            rot_mat,
+            rot_mat_dt: Some(rate_deg_s.to_radians() * k * rot_mat),
+            from: id,
+            to: self.parent_id,
+        })
+    }
+}
+
+impl DataSetT for Articulation {
+    const NAME: &'static str = "articulation data";
+}
+
+impl Encode for Articulation {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.parent_id.encoded_len()?
+            + self.axis.x.encoded_len()?
+            + self.axis.y.encoded_len()?
+            + self.axis.z.encoded_len()?
+            + self.epochs_tdb_s.encoded_len()?
+            + self.angles_deg.encoded_len()?
+    }
+
+    fn encode(&self, encoder: &mut impl Writer) -> der::Result<()> {
+        self.parent_id.encode(encoder)?;
+        self.axis.x.encode(encoder)?;
+        self.axis.y.encode(encoder)?;
+        self.axis.z.encode(encoder)?;
+        self.epochs_tdb_s.encode(encoder)?;
+        self.angles_deg.encode(encoder)
+    }
+}
+
+impl<'a> Decode<'a> for Articulation {
+    fn decode<R: Reader<'a>>(decoder: &mut R) -> der::Result<Self> {
+        let parent_id = decoder.decode()?;
+        let x = decoder.decode()?;
+        let y = decoder.decode()?;
+        let z = decoder.decode()?;
+
+        Ok(Self {
+            parent_id,
+            axis: Vector3::new(x, y, z),
+            epochs_tdb_s: decoder.decode()?,
+            angles_deg: decoder.decode()?,
+        })
+    }
+}
+
+impl fmt::Display for Articulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "articulation about [{:.6}, {:.6}, {:.6}] of {} with {} angle samples",
+            self.axis.x,
+            self.axis.y,
+            self.axis.z,
+            self.parent_id,
+            self.angles_deg.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_articulation {
+    use super::{Articulation, Decode, Encode};
+    use crate::math::rotation::DCM;
+    use crate::math::Vector3;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn articulation_profile() {
+        let start = Epoch::from_tdb_seconds(0.0);
+        // Solar array drive rotating about the body Y axis at 1 deg/s for 90 seconds, then holding.
+        let sada = Articulation::new(
+            -85000,
+            Vector3::new(0.0, 2.0, 0.0),
+            &[
+                (start, 0.0),
+                (start + Unit::Second * 90, 90.0),
+                (start + Unit::Second * 100, 90.0),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sada.axis, Vector3::y());
+
+        let (angle, rate) = sada.angle_and_rate_deg(start + Unit::Second * 30).unwrap();
+        assert!((angle - 30.0).abs() < 1e-9);
+        assert!((rate - 1.0).abs() < 1e-12);
+        assert_eq!(
+            sada.angle_and_rate_deg(start + Unit::Second * 95).unwrap(),
+            (90.0, 0.0)
+        );
+        assert!(sada.angle_deg(start - Unit::Second * 1).is_err());
+        assert!(sada.angle_deg(start + Unit::Second * 101).is_err());
+
+        // At 90 degrees about Y, the array normal along +Z is along +X of the body.
+        let dcm = sada
+            .rotation_to_parent(-85001, start + Unit::Second * 90)
+            .unwrap();
+        assert_eq!((dcm.from, dcm.to), (-85001, -85000));
+        assert!((dcm.rot_mat * Vector3::z() - Vector3::x()).norm() < 1e-12);
+        assert!((dcm.rot_mat.determinant() - 1.0).abs() < 1e-12);
+
+        // The time derivative matches a finite difference.
+        let epoch = start + Unit::Second * 45;
+        let h = Unit::Millisecond * 10;
+        let next = sada.rotation_to_parent(-85001, epoch + h).unwrap();
+        let prev = sada.rotation_to_parent(-85001, epoch - h).unwrap();
+        let dcm: DCM = sada.rotation_to_parent(-85001, epoch).unwrap();
+        let fd = (next.rot_mat - prev.rot_mat) / (2.0 * h.to_seconds());
+        assert!((dcm.rot_mat_dt.unwrap() - fd).norm() < 1e-9);
+
+        // A single sample is a fixed angle.
+        let fixed = Articulation::new(-85000, Vector3::z(), &[(start, 12.0)]).unwrap();
+        assert_eq!(
+            fixed.angle_deg(start + Unit::Day * 10).unwrap(),
+            fixed.angle_deg(start).unwrap()
+        );
+
+        // Invalid profiles
+        assert!(Articulation::new(-85000, Vector3::z(), &[]).is_err());
+        assert!(Articulation::new(-85000, Vector3::zeros(), &[(start, 0.0)]).is_err());
+        assert!(Articulation::new(-85000, Vector3::z(), &[(start, 0.0), (start, 1.0)]).is_err());
+
+        // Encoding round trip
+        let mut buf = vec![];
+        sada.encode_to_vec(&mut buf).unwrap();
+        assert_eq!(Articulation::from_der(&buf).unwrap(), sada);
+    }
+}
@@ -19,6 +19,9 @@ pub enum DataSetType {
     PlanetaryData,
     EulerParameterData,
     LocationData,
+    InstrumentData,
+    ArticulationData,
+    TraverseData,
 }
 
 impl TryFrom<u8> for DataSetType {
@@ -31,6 +34,9 @@ impl TryFrom<u8> for DataSetType {
             2 => Ok(DataSetType::PlanetaryData),
             3 => Ok(DataSetType::EulerParameterData),
             4 => Ok(DataSetType::LocationData),
+            5 => Ok(DataSetType::InstrumentData),
+            6 => Ok(DataSetType::ArticulationData),
+            7 => Ok(DataSetType::TraverseData),
             _ => Err("Invalid value for DataSetType"),
         }
     }
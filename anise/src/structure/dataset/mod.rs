@@ -21,7 +21,7 @@ use crate::{
 };
 use core::fmt;
 use core::ops::Deref;
-use der::{asn1::OctetString, Decode, Encode, Reader, Writer};
+use der::{asn1::OctetString, Decode, Encode, Reader, SliceReader, Writer};
 use log::{error, trace};
 use snafu::prelude::*;
 
@@ -114,6 +114,84 @@ impl<T: DataSetT> DataSet<T> {
         }
     }
 
+    /// Loads an Anise file from a pointer of bytes, skipping the data items that cannot be decoded instead of failing.
+    ///
+    /// Skipped data items are replaced by their default value and removed from the lookup table, so they cannot be fetched.
+    /// Returns the data set and a description of each skipped data item, and of the checksum mismatch if any. The checksum of the
+    /// returned data set is recomputed from the decoded data, so that subsequent scrubs check against what was loaded.
+    pub fn try_from_bytes_best_effort<B: Deref<Target = [u8]>>(
+        bytes: B,
+    ) -> Result<(Self, Vec<String>), DataSetError> {
+        let mut reader = SliceReader::new(&bytes)
+            .map_err(|err| DecodingError::DecodingDer { err })
+            .context(DataDecodingSnafu {
+                action: "loading data set from bytes",
+            })?;
+
+        let (metadata, lut, data_checksum, bytes_meta, der_octets) = (|| -> der::Result<_> {
+            let metadata: Metadata = reader.decode()?;
+            let lut: LookUpTable = reader.decode()?;
+            let data_checksum: u32 = reader.decode()?;
+            let bytes_meta: Vec<u32> = reader.decode()?;
+            let der_octets: OctetString = reader.decode()?;
+            Ok((metadata, lut, data_checksum, bytes_meta, der_octets))
+        })()
+        .map_err(|err| DecodingError::DecodingDer { err })
+        .context(DataDecodingSnafu {
+            action: "loading data set from bytes",
+        })?;
+
+        let mut me = Self {
+            metadata,
+            lut,
+            data_checksum,
+            data: Vec::new(),
+        };
+        let mut warnings = Vec::new();
+
+        let mut skip = |me: &mut Self, idx: usize, reason: String| {
+            warnings.push(format!("skipped {} #{idx}: {reason}", T::NAME));
+            me.data.push(T::default());
+            me.lut.by_id.retain(|_, item_idx| *item_idx as usize != idx);
+            me.lut
+                .by_name
+                .retain(|_, item_idx| *item_idx as usize != idx);
+        };
+
+        for (idx, item_bytes) in
+            Self::data_item_bytes(&bytes_meta, der_octets.as_bytes()).enumerate()
+        {
+            let decoded = match item_bytes {
+                Some(item_bytes) => T::from_der(item_bytes).map_err(|err| err.to_string()),
+                None => Err("its length is beyond the end of the data".to_string()),
+            };
+
+            match decoded {
+                Ok(item) => me.data.push(item),
+                Err(reason) => skip(&mut me, idx, reason),
+            }
+        }
+
+        // The items after the first one which does not fit cannot be located in the data.
+        let num_items = bytes_meta.first().copied().unwrap_or(0) as usize;
+        for idx in me.data.len()..num_items {
+            skip(
+                &mut me,
+                idx,
+                "a previous length is beyond the end of the data".to_string(),
+            );
+        }
+
+        if warnings.is_empty() {
+            if let Err(e) = me.check_integrity() {
+                warnings.push(format!("{}: {e}", T::NAME));
+            }
+        }
+        me.set_crc32();
+
+        Ok((me, warnings))
+    }
+
     /// Forces to load an Anise file from a pointer of bytes.
     /// **Panics** if the bytes cannot be interpreted as an Anise file.
     pub fn from_bytes<B: Deref<Target = [u8]>>(buf: B) -> Self {
@@ -442,6 +520,25 @@ impl<T: DataSetT> DataSet<T> {
         self.len() == 0
     }
 
+    /// Returns the slice of the encoded bytes of each data item, or None for the first data item whose length is not within the
+    /// bytes, after which the iteration stops since the offsets of the subsequent items are unknown.
+    fn data_item_bytes<'b>(
+        bytes_meta: &'b [u32],
+        bytes: &'b [u8],
+    ) -> impl Iterator<Item = Option<&'b [u8]>> {
+        // The first element of bytes_meta is the number of data items, and the subsequent elements are the lengths of each data item.
+        let num_items = bytes_meta.first().copied().unwrap_or(0) as usize;
+        let mut idx = Some(0_usize);
+        (0..num_items).map_while(move |meta_idx| {
+            let start = idx?;
+            let item_bytes = bytes_meta
+                .get(meta_idx + 1)
+                .and_then(|next_len| bytes.get(start..start.checked_add(*next_len as usize)?));
+            idx = item_bytes.map(|item_bytes| start + item_bytes.len());
+            Some(item_bytes)
+        })
+    }
+
     /// Returns this data as a data sequence, cloning all of the entries into this sequence.
     fn build_data_seq(&self) -> (Vec<u32>, OctetString) {
         let mut buf = Vec::new();
@@ -498,15 +595,16 @@ impl<'a, T: DataSetT> Decode<'a> for DataSet<T> {
         let bytes = der_octets.as_bytes();
 
         let mut data = vec![];
-        let mut idx = 0;
-        // The first element of bytes_meta is the number of data items.
-        for meta_idx in 0..*bytes_meta.first().unwrap() as usize {
-            // The subsequent elements are the lengths of each data item.
-            let next_len = *bytes_meta.get(meta_idx + 1).unwrap() as usize;
+        for item_bytes in Self::data_item_bytes(&bytes_meta, bytes) {
             // Decode each data item from its slice of the bytes.
-            let this_data = T::from_der(&bytes[idx..idx + next_len]).unwrap();
-            data.push(this_data);
-            idx += next_len;
+            match item_bytes {
+                Some(item_bytes) => data.push(T::from_der(item_bytes)?),
+                None => {
+                    return Err(decoder.error(der::ErrorKind::Length {
+                        tag: der::Tag::OctetString,
+                    }))
+                }
+            }
         }
 
         Ok(Self {
@@ -558,6 +656,17 @@ mod dataset_ut {
         assert_eq!(core::mem::size_of::<DataSet<SpacecraftData>>(), 232);
     }
 
+    #[test]
+    fn data_item_bytes_out_of_range() {
+        let bytes = [1_u8, 2, 3, 4, 5];
+        // The second item claims more bytes than available, so the third one cannot be located.
+        let items = SpacecraftDataSet::data_item_bytes(&[3, 2, 10, 1], &bytes).collect::<Vec<_>>();
+        assert_eq!(items, vec![Some(&bytes[..2]), None]);
+
+        let items = SpacecraftDataSet::data_item_bytes(&[2, 2, 3], &bytes).collect::<Vec<_>>();
+        assert_eq!(items, vec![Some(&bytes[..2]), Some(&bytes[2..])]);
+    }
+
     #[test]
     fn spacecraft_constants_lookup() {
         // Build some data first.
@@ -577,6 +686,7 @@ mod dataset_ut {
             }),
             mass: Some(Mass::from_dry_and_prop_masses(150.0, 50.6)),
             drag_data: Some(DragData::default()),
+            ..Default::default()
         };
         let srp_sc = SpacecraftData {
             srp_data: Some(SRPData::default()),
@@ -615,10 +725,10 @@ mod dataset_ut {
 
         // Build the lookup table
         dataset
-            .push(srp_sc, Some(-20), Some("SRP spacecraft"))
+            .push(srp_sc.clone(), Some(-20), Some("SRP spacecraft"))
             .unwrap();
         dataset
-            .push(full_sc, Some(-50), Some("Full spacecraft"))
+            .push(full_sc.clone(), Some(-50), Some("Full spacecraft"))
             .unwrap();
 
         dataset.set_crc32();
@@ -650,7 +760,7 @@ mod dataset_ut {
         // Grab a copy of the original data
         let mut sc = dataset.get_by_name("SRP spacecraft").unwrap();
         sc.srp_data.as_mut().unwrap().coeff_reflectivity = 1.1;
-        dataset.set_by_name("SRP spacecraft", sc).unwrap();
+        dataset.set_by_name("SRP spacecraft", sc.clone()).unwrap();
         // Ensure that we've modified only that entry
         assert_eq!(
             dataset.get_by_name("Full spacecraft").unwrap(),
@@ -720,6 +830,7 @@ mod dataset_ut {
             }),
             mass: Some(Mass::from_dry_and_prop_masses(150.0, 50.6)),
             drag_data: Some(DragData::default()),
+            ..Default::default()
         };
         let srp_sc = SpacecraftData {
             srp_data: Some(SRPData::default()),
@@ -730,19 +841,19 @@ mod dataset_ut {
 
         let mut dataset = DataSet::<SpacecraftData>::default();
         dataset
-            .push(srp_sc, Some(-20), Some("SRP spacecraft"))
+            .push(srp_sc.clone(), Some(-20), Some("SRP spacecraft"))
             .unwrap();
 
         dataset
-            .push(full_sc, Some(-50), Some("Full spacecraft"))
+            .push(full_sc.clone(), Some(-50), Some("Full spacecraft"))
             .unwrap();
 
         // Pushing without name as ID -51
-        dataset.push(full_sc, Some(-51), None).unwrap();
+        dataset.push(full_sc.clone(), Some(-51), None).unwrap();
 
         // Pushing without ID
         dataset
-            .push(srp_sc, None, Some("ID less SRP spacecraft"))
+            .push(srp_sc.clone(), None, Some("ID less SRP spacecraft"))
             .unwrap();
 
         // Make sure to set the CRC32.
@@ -774,7 +885,7 @@ mod dataset_ut {
         // Check that we can set by ID
         let mut repr = dataset.get_by_id(-50).unwrap();
         repr.mass.as_mut().unwrap().dry_mass_kg = 100.5;
-        dataset.set_by_id(-50, repr).unwrap();
+        dataset.set_by_id(-50, repr.clone()).unwrap();
         assert_eq!(
             dataset.get_by_id(-50).unwrap().mass.unwrap().dry_mass_kg,
             100.5,
@@ -10,6 +10,8 @@
 use der::{Decode, Encode, Reader, Writer};
 
 use crate::frames::FrameUid;
+use crate::math::angles::AngleConvention;
+use crate::math::units::AngleUnit;
 
 use serde_derive::{Deserialize, Serialize};
 
@@ -347,6 +349,27 @@ impl<'a> Decode<'a> for Location {
     }
 }
 
+impl Location {
+    /// Returns the description of this location, as its Display, with the latitude and longitude in the provided angle convention.
+    ///
+    /// The Display prints the longitude as stored, whereas the convention bounds it, so the description with the default
+    /// convention matches the Display only if the stored longitude is between -180 and +180 degrees.
+    pub fn describe(&self, angles: AngleConvention) -> String {
+        let decimals = match angles.unit {
+            AngleUnit::Degree => 3,
+            AngleUnit::Radian => 6,
+        };
+        format!(
+            "lat.: {:.decimals$} {unit}, long.: {:.decimals$} {unit}, alt.: {:.3} km on {}",
+            angles.angle(self.latitude_deg),
+            angles.longitude(self.longitude_deg),
+            self.height_km,
+            self.frame,
+            unit = angles.unit
+        )
+    }
+}
+
 impl core::fmt::Display for Location {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -362,6 +385,7 @@ impl core::fmt::Display for Location {
 mod ut_loc {
     use super::Location;
     use super::{Decode, Encode};
+    use crate::math::angles::{AngleConvention, LongitudeDirection, LongitudeRange};
 
     #[test]
     fn test_location() {
@@ -389,6 +413,27 @@ mod ut_loc {
             terrain_mask_ignored: false,
         };
 
+        assert_eq!(
+            dss65.describe(AngleConvention::default()),
+            format!("{dss65}")
+        );
+        assert!(dss65
+            .describe(AngleConvention {
+                longitude_range: LongitudeRange::ZeroTo360,
+                longitude_direction: LongitudeDirection::WestPositive,
+                ..Default::default()
+            })
+            .starts_with("lat.: 40.427 deg, long.: 355.749 deg"));
+        // A longitude stored outside of -180 to +180 degrees is bounded by the convention, but not by the Display.
+        let west = Location {
+            longitude_deg: 355.749_444,
+            ..dss65.clone()
+        };
+        assert!(format!("{west}").contains("long.: 355.749 deg"));
+        assert!(west
+            .describe(AngleConvention::default())
+            .contains("long.: -4.251 deg"));
+
         // Test Dhall serde
         let as_dhall = dss65.to_dhall().unwrap();
 
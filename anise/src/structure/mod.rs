@@ -12,6 +12,7 @@
  * This module only contains the serialization and deserialization components of ANISE.
  * All other computations are at a higher level module.
  */
+pub mod articulation;
 pub mod dataset;
 pub mod instrument;
 pub mod location;
@@ -20,9 +21,12 @@ pub mod metadata;
 pub mod planetocentric;
 pub mod semver;
 pub mod spacecraft;
+pub mod traverse;
 
+use articulation::Articulation;
 use instrument::Instrument;
 use location::Location;
+use traverse::Traverse;
 
 use self::{
     dataset::DataSet, planetocentric::PlanetaryData, semver::Semver, spacecraft::SpacecraftData,
@@ -46,3 +50,7 @@ pub type EulerParameterDataSet = DataSet<Quaternion>;
 pub type LocationDataSet = DataSet<Location>;
 /// Instrument Data Set allow mapping an ID and/or name to a Instrument.
 pub type InstrumentDataSet = DataSet<Instrument>;
+/// Articulation Data Set allow mapping an ID and/or name to a time varying Articulation of an appendage.
+pub type ArticulationDataSet = DataSet<Articulation>;
+/// Traverse Data Set allow mapping an ID and/or name to the time tagged surface path of an asset, e.g. a rover.
+pub type TraverseDataSet = DataSet<Traverse>;
@@ -8,7 +8,7 @@
  * Documentation: https://nyxspace.com/
  */
 
-use crate::math::Vector3;
+use crate::math::{ellipsoid, Vector3};
 use core::fmt;
 use der::{Decode, Encode, Reader, Writer};
 use serde_derive::{Deserialize, Serialize};
@@ -49,6 +49,17 @@ pub struct Ellipsoid {
 }
 
 impl Ellipsoid {
+    /// World Geodetic System 1984 (WGS84) reference ellipsoid, defined by its semi major axis of 6378.137 km and its inverse flattening of 298.257223563.
+    ///
+    /// This differs from the IAU Earth radii of the planetary constants (6378.1366 km and 6356.7519 km): use this ellipsoid in geodetic computations
+    /// where the products must be referenced to WGS84 exactly, e.g. `frame.with_ellipsoid(Ellipsoid::WGS84)`.
+    /// Source: NGA.STND.0036_1.0.0_WGS84, 2014
+    pub const WGS84: Self = Self {
+        semi_major_equatorial_radius_km: 6378.137,
+        semi_minor_equatorial_radius_km: 6378.137,
+        polar_radius_km: 6356.752314245179,
+    };
+
     /// Builds an ellipsoid as if it were a sphere
     pub fn from_sphere(radius_km: f64) -> Self {
         Self {
@@ -67,9 +78,18 @@ impl Ellipsoid {
         }
     }
 
+    /// Returns the three radii of this ellipsoid in km, i.e. the semi major equatorial, semi minor equatorial, and polar radii.
+    pub fn radii_km(&self) -> Vector3 {
+        Vector3::new(
+            self.semi_major_equatorial_radius_km,
+            self.semi_minor_equatorial_radius_km,
+            self.polar_radius_km,
+        )
+    }
+
     /// Computes the intersection of a ray defined by `view_point` and `view_direction` with the ellipsoid.
     ///
-    /// This is functionally equivalent to the SPICE routine `surfpt_c`.
+    /// This is functionally equivalent to the SPICE routine `surfpt_c`, cf. [ellipsoid::ray_intersection].
     ///
     /// # Arguments
     /// * `view_point` - The origin of the ray (e.g. spacecraft position in Body Fixed frame).
@@ -79,48 +99,7 @@ impl Ellipsoid {
     /// * `Some(Vector3)` - The Cartesian coordinates of the first intersection point on the surface.
     /// * `None` - If the ray does not intersect the ellipsoid.
     pub fn intersect(&self, view_point: Vector3, view_direction: Vector3) -> Option<Vector3> {
-        let a = self.semi_major_equatorial_radius_km;
-        let b = self.semi_minor_equatorial_radius_km;
-        let c = self.polar_radius_km;
-
-        // 1. Scale to Unit Sphere Space: P' = [x/a, y/b, z/c]
-        // We do this manually to avoid constructing a scaling matrix
-        let origin = Vector3::new(view_point.x / a, view_point.y / b, view_point.z / c);
-        let direction = Vector3::new(
-            view_direction.x / a,
-            view_direction.y / b,
-            view_direction.z / c,
-        );
-
-        // 2. Quadratic Equation: |O' + t*D'|^2 = 1
-        // (D' . D')t^2 + 2(O' . D')t + (O' . O' - 1) = 0
-        let a_coeff = direction.dot(&direction);
-        let b_coeff = 2.0 * origin.dot(&direction);
-        let c_coeff = origin.dot(&origin) - 1.0;
-
-        let discriminant = b_coeff * b_coeff - 4.0 * a_coeff * c_coeff;
-
-        if discriminant < 0.0 {
-            return None; // Ray misses
-        }
-
-        // 3. Solve for t
-        let sqrt_disc = discriminant.sqrt();
-        let t1 = (-b_coeff - sqrt_disc) / (2.0 * a_coeff);
-        let t2 = (-b_coeff + sqrt_disc) / (2.0 * a_coeff);
-
-        // 4. Select closest positive t
-        // Use a small epsilon to avoid finding the "origin" if we are already on the surface
-        let t = if t1 > 1e-9 {
-            t1
-        } else if t2 > 1e-9 {
-            t2
-        } else {
-            return None; // Intersection is behind
-        };
-
-        // 5. Unscale
-        Some(view_point + view_direction * t)
+        ellipsoid::ray_intersection(&self.radii_km(), &view_point, &view_direction)
     }
 
     /// Computes the unit normal vector at a specific point on the surface of the ellipsoid.
@@ -132,12 +111,7 @@ impl Ellipsoid {
     /// For an ellipsoid (x/a)^2 + (y/b)^2 + (z/c)^2 = 1, the gradient vector is:
     /// ∇f = [ 2x/a^2, 2y/b^2, 2z/c^2 ]
     pub fn surface_normal(&self, surface_point: Vector3) -> Vector3 {
-        Vector3::new(
-            surface_point.x / self.semi_major_equatorial_radius_km.powi(2),
-            surface_point.y / self.semi_minor_equatorial_radius_km.powi(2),
-            surface_point.z / self.polar_radius_km.powi(2),
-        )
-        .normalize()
+        ellipsoid::surface_normal(&self.radii_km(), &surface_point)
     }
 
     /// Computes the emission angle (epsilon) at a surface point.
@@ -174,6 +148,91 @@ impl Ellipsoid {
 
         normal.dot(&vec_to_sun).clamp(-1.0, 1.0).acos().to_degrees()
     }
+
+    /// Computes the point on the surface of the ellipsoid nearest to the provided point.
+    ///
+    /// This is functionally equivalent to the SPICE routine `nearpt_c`, cf. [ellipsoid::nearest_point] which also returns the
+    /// altitude of the point.
+    pub fn nearest_point(&self, point: Vector3) -> Vector3 {
+        ellipsoid::nearest_point(&self.radii_km(), &point).0
+    }
+
+    /// Computes the tangent point of the ray defined by `view_point` and `view_direction`, i.e. the point of the ray nearest to the
+    /// ellipsoid, and the point on the surface of the ellipsoid nearest to that tangent point.
+    ///
+    /// This is functionally equivalent to the SPICE routine `tangpt_c`. If the ray intersects the ellipsoid, both points are the
+    /// first intersection. If the ray points away from the ellipsoid, the tangent point is the view point.
+    ///
+    /// # Notes
+    /// The tangent point is where the ray is tangent to a scaled copy of the ellipsoid, which is exactly the point nearest to
+    /// the surface for spheres, and a close approximation of it for the planetary ellipsoids.
+    ///
+    /// # Returns
+    /// The tangent point and the nearest surface point, in the frame of the ellipsoid.
+    pub fn tangent_point(
+        &self,
+        view_point: Vector3,
+        view_direction: Vector3,
+    ) -> (Vector3, Vector3) {
+        if let Some(intercept) = self.intersect(view_point, view_direction) {
+            return (intercept, intercept);
+        }
+
+        let scale = self.radii_km();
+        let origin = view_point.component_div(&scale);
+        let direction = view_direction.component_div(&scale);
+
+        // Minimize |O' + t*D'|^2, ahead of the view point.
+        let t = (-origin.dot(&direction) / direction.dot(&direction)).max(0.0);
+        let tangent = view_point + view_direction * t;
+
+        (tangent, self.nearest_point(tangent))
+    }
+
+    /// Computes the limb of the ellipsoid as seen from the observer, i.e. the ellipse of the points of the surface where the
+    /// line of sight from the observer is tangent to the ellipsoid.
+    ///
+    /// This is functionally equivalent to the SPICE routine `edlimb_c`.
+    ///
+    /// # Returns
+    /// * `Some((center, semi_major_axis, semi_minor_axis))` - The center and the semi axis vectors of the limb ellipse, in the frame of the ellipsoid.
+    /// * `None` - If the observer is on or inside the ellipsoid.
+    pub fn limb(&self, observer_pos_body: Vector3) -> Option<(Vector3, Vector3, Vector3)> {
+        let scale = self.radii_km();
+        // In the unit sphere space, the limb is the circle of the points X such that X . V' = 1.
+        let observer = observer_pos_body.component_div(&scale);
+        let dist_sq = observer.norm_squared();
+        if dist_sq <= 1.0 {
+            return None;
+        }
+
+        let radius = (1.0 - 1.0 / dist_sq).sqrt();
+        let normal = observer.normalize();
+        let seed = if normal.x.abs() < 0.9 {
+            Vector3::x()
+        } else {
+            Vector3::y()
+        };
+        let u = normal.cross(&seed).normalize();
+        let w = normal.cross(&u);
+
+        // Unscaling maps the circle to an ellipse with these conjugate semi diameters.
+        let center = (observer / dist_sq).component_mul(&scale);
+        let g1 = (u * radius).component_mul(&scale);
+        let g2 = (w * radius).component_mul(&scale);
+
+        // Rotate the conjugate semi diameters into the principal semi axes.
+        let theta = 0.5 * (2.0 * g1.dot(&g2)).atan2(g1.norm_squared() - g2.norm_squared());
+        let (sin_t, cos_t) = theta.sin_cos();
+        let major = g1 * cos_t + g2 * sin_t;
+        let minor = g2 * cos_t - g1 * sin_t;
+
+        if major.norm() >= minor.norm() {
+            Some((center, major, minor))
+        } else {
+            Some((center, minor, major))
+        }
+    }
 }
 
 #[cfg_attr(feature = "python", pymethods)]
@@ -303,6 +362,14 @@ impl Ellipsoid {
     pub fn flattening(&self) -> f64 {
         (self.mean_equatorial_radius_km() - self.polar_radius_km) / self.mean_equatorial_radius_km()
     }
+
+    /// Returns the first eccentricity of the meridian ellipse, computed from the flattening ratio as `sqrt(2f - f^2)`
+    ///
+    /// :rtype: float
+    pub fn eccentricity(&self) -> f64 {
+        let flattening = self.flattening();
+        (2.0 * flattening - flattening.powi(2)).sqrt()
+    }
 }
 
 impl fmt::Display for Ellipsoid {
@@ -353,3 +420,42 @@ impl<'a> Decode<'a> for Ellipsoid {
         })
     }
 }
+
+#[cfg(test)]
+mod ut_ellipsoid {
+    use super::Ellipsoid;
+
+    #[test]
+    fn wgs84() {
+        let wgs84 = Ellipsoid::WGS84;
+        assert!(wgs84.is_spheroid());
+        assert!((1.0 / wgs84.flattening() - 298.257223563).abs() < 1e-8);
+        assert!((wgs84.eccentricity() - 0.0818191908426215).abs() < 1e-12);
+
+        // IAU Earth radii differ from WGS84
+        let iau_earth = Ellipsoid::from_spheroid(6378.1366, 6356.7519);
+        assert_ne!(iau_earth, wgs84);
+
+        assert_eq!(Ellipsoid::from_sphere(1737.4).eccentricity(), 0.0);
+    }
+
+    #[test]
+    fn wgs84_geodesy() {
+        use crate::constants::frames::EARTH_ITRF93;
+        use crate::prelude::{Epoch, Orbit};
+
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+        let itrf93_wgs84 = EARTH_ITRF93.with_ellipsoid(Ellipsoid::WGS84);
+
+        let equator = Orbit::try_latlongalt(0.0, 0.0, 0.0, epoch, itrf93_wgs84).unwrap();
+        assert!((equator.radius_km.x - 6378.137).abs() < 1e-9);
+        let pole = Orbit::try_latlongalt(90.0, 0.0, 0.0, epoch, itrf93_wgs84).unwrap();
+        assert!((pole.radius_km.z - 6356.752314245179).abs() < 1e-9);
+
+        let point = Orbit::try_latlongalt(48.8566, 2.3522, 0.035, epoch, itrf93_wgs84).unwrap();
+        let (lat_deg, long_deg, alt_km) = point.latlongalt().unwrap();
+        assert!((lat_deg - 48.8566).abs() < 1e-9);
+        assert!((long_deg - 2.3522).abs() < 1e-9);
+        assert!((alt_km - 0.035).abs() < 1e-9);
+    }
+}
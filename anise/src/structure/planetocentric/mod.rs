@@ -13,24 +13,30 @@ use crate::{
     constants::orientations::orientation_name_from_id,
     math::{
         rotation::{r1, r3, DCM},
+        uncertain::Uncertain,
         Matrix3,
     },
     prelude::{Frame, FrameUid},
     NaifId,
 };
-use core::f64::consts::FRAC_PI_2;
+use core::f64::consts::{FRAC_PI_2, TAU};
 use core::fmt;
 pub mod ellipsoid;
 pub mod phaseangle;
+pub mod radiometry;
 use der::{Decode, Encode, Reader, Writer};
 use ellipsoid::Ellipsoid;
-use hifitime::{Epoch, TimeUnits, Unit};
+use hifitime::{Duration, Epoch, TimeUnits, Unit};
 use phaseangle::PhaseAngle;
+use radiometry::Radiometry;
 
 use super::dataset::DataSetT;
 
 pub const MAX_NUT_PREC_ANGLES: usize = 32;
 
+/// Pole right ascension, pole declination, and prime meridian angles in degrees, each `None` if not defined.
+pub type PoleAnglesDeg = (Option<f64>, Option<f64>, Option<f64>);
+
 /// ANISE supports two different kinds of orientation data. High precision, with spline based interpolations, and constants right ascension, declination, and prime meridian, typically used for planetary constant data.
 ///
 /// # Documentation of rotation angles
@@ -82,6 +88,10 @@ pub struct PlanetaryData {
     /// E.g. For `E1 = 125.045 -  0.052992 d`, this would be stored as a single entry `(125.045, -0.052992)`.
     pub num_nut_prec_angles: u8,
     pub nut_prec_angles: [PhaseAngle<0>; MAX_NUT_PREC_ANGLES],
+    /// Albedos and infrared emission of this object
+    pub radiometry: Option<Radiometry>,
+    /// 1σ uncertainty of the gravitational parameter (μ) of this planetary object
+    pub mu_sigma_km3_s2: Option<f64>,
 }
 
 impl DataSetT for PlanetaryData {
@@ -98,6 +108,95 @@ impl PlanetaryData {
             shape: self.shape,
         }
     }
+    /// Returns the largest equatorial radius of the shape of this object in km, if defined
+    pub fn equatorial_radius_km(&self) -> Option<f64> {
+        self.shape
+            .map(|shape| shape.semi_major_equatorial_radius_km)
+    }
+
+    /// Returns the flattening ratio of the shape of this object, if defined
+    pub fn flattening(&self) -> Option<f64> {
+        self.shape.map(|shape| shape.flattening())
+    }
+
+    /// Returns the first eccentricity of the shape of this object, if defined
+    pub fn eccentricity(&self) -> Option<f64> {
+        self.shape.map(|shape| shape.eccentricity())
+    }
+
+    /// Returns the Bond albedo of this object, if defined
+    pub fn bond_albedo(&self) -> Option<f64> {
+        self.radiometry.map(|radiometry| radiometry.bond_albedo)
+    }
+
+    /// Returns the geometric albedo of this object, if defined
+    pub fn geometric_albedo(&self) -> Option<f64> {
+        self.radiometry
+            .map(|radiometry| radiometry.geometric_albedo)
+    }
+
+    /// Returns the mean infrared flux emitted by the surface of this object in W/m^2, if defined
+    pub fn ir_emission_w_m2(&self) -> Option<f64> {
+        self.radiometry
+            .map(|radiometry| radiometry.ir_emission_w_m2)
+    }
+
+    /// Returns the radius of the Laplace sphere of influence of this object in km, given its distance to the central body
+    /// (typically the semi-major axis of its orbit) and the gravitational parameter of that central body.
+    ///
+    /// `r_soi = d * (mu / mu_central)^(2/5)`
+    pub fn soi_radius_km(&self, distance_km: f64, central_mu_km3_s2: f64) -> f64 {
+        distance_km * (self.mu_km3_s2 / central_mu_km3_s2).powf(0.4)
+    }
+
+    /// Returns the radius of the Hill sphere of this object in km, given its distance to the central body and the
+    /// gravitational parameter of that central body.
+    ///
+    /// `r_hill = d * (mu / (3 mu_central))^(1/3)`
+    ///
+    /// # Note
+    /// For an eccentric orbit, the Hill radius at periapsis is obtained by providing the periapsis radius as the distance.
+    pub fn hill_radius_km(&self, distance_km: f64, central_mu_km3_s2: f64) -> f64 {
+        distance_km * (self.mu_km3_s2 / (3.0 * central_mu_km3_s2)).cbrt()
+    }
+
+    /// Returns the gravitational parameter of this object with its 1σ uncertainty, which is zero if not defined.
+    pub fn mu_km3_s2_1sigma(&self) -> Uncertain {
+        Uncertain::new(self.mu_km3_s2, self.mu_sigma_km3_s2.unwrap_or(0.0))
+    }
+
+    /// Returns the orbital period in seconds of an orbit of the provided semi-major axis around this object, with the 1σ
+    /// uncertainty stemming from that of the gravitational parameter.
+    ///
+    /// `T = 2π sqrt(a^3 / mu)`
+    pub fn period_s_1sigma(&self, sma_km: f64) -> Uncertain {
+        let inv_sqrt_mu = self.mu_km3_s2_1sigma().powf(-0.5);
+        Uncertain::new(
+            TAU * sma_km.powf(1.5) * inv_sqrt_mu.nominal,
+            TAU * sma_km.powf(1.5) * inv_sqrt_mu.sigma,
+        )
+    }
+
+    /// Same as `soi_radius_km`, with the 1σ uncertainty stemming from the gravitational parameters of both objects.
+    pub fn soi_radius_km_1sigma(&self, distance_km: f64, central: &Self) -> Uncertain {
+        self.radius_1sigma(distance_km, central, 1.0, 0.4)
+    }
+
+    /// Same as `hill_radius_km`, with the 1σ uncertainty stemming from the gravitational parameters of both objects.
+    pub fn hill_radius_km_1sigma(&self, distance_km: f64, central: &Self) -> Uncertain {
+        self.radius_1sigma(distance_km, central, 3.0, 1.0 / 3.0)
+    }
+
+    /// Returns `d * (mu / (k mu_central))^exponent` with its uncertainty, where the relative uncertainties of both
+    /// gravitational parameters are independent.
+    fn radius_1sigma(&self, distance_km: f64, central: &Self, k: f64, exponent: f64) -> Uncertain {
+        let mu = self.mu_km3_s2_1sigma();
+        let central_mu = central.mu_km3_s2_1sigma();
+        let nominal = distance_km * (mu.nominal / (k * central_mu.nominal)).powf(exponent);
+        let relative = exponent * mu.relative().hypot(central_mu.relative());
+        Uncertain::new(nominal, relative * nominal)
+    }
+
     /// Specifies what data is available in this structure.
     ///
     /// Returns:
@@ -106,6 +205,8 @@ impl PlanetaryData {
     /// + Bit 2 is set if `pole_declination` is available
     /// + Bit 3 is set if `prime_meridian` is available
     /// + Bit 4 is set if `long_axis` is available
+    /// + Bit 5 is set if `radiometry` is available
+    /// + Bit 6 is set if `mu_sigma_km3_s2` is available
     fn available_data(&self) -> u8 {
         let mut bits: u8 = 0;
 
@@ -124,6 +225,12 @@ impl PlanetaryData {
         if self.long_axis.is_some() {
             bits |= 1 << 4;
         }
+        if self.radiometry.is_some() {
+            bits |= 1 << 5;
+        }
+        if self.mu_sigma_km3_s2.is_some() {
+            bits |= 1 << 6;
+        }
 
         bits
     }
@@ -150,79 +257,111 @@ impl PlanetaryData {
         false
     }
 
+    /// Evaluates the nutation and precession angles of this planetary system at the provided epoch.
+    ///
+    /// Returns a vector of tuples of each angle in degrees and its rate in degrees per Julian century, in the order in which they are defined in the PCK.
+    pub fn nut_prec_angles_deg(&self, epoch: Epoch) -> Vec<(f64, f64)> {
+        self.nut_prec_angles
+            .iter()
+            .take(self.num_nut_prec_angles.into())
+            .map(|angle| {
+                (
+                    angle.evaluate_deg(epoch, Unit::Century),
+                    angle.evaluate_rate_deg(epoch, Unit::Century),
+                )
+            })
+            .collect()
+    }
+
+    /// Evaluates the pole right ascension, the pole declination, and the prime meridian angles in degrees at the provided epoch,
+    /// including the trigonometric terms of the nutation and precession angles of the provided system (which may be this object itself).
+    ///
+    /// Each angle is `None` if this object does not define it.
+    pub fn pole_angles_deg(&self, epoch: Epoch, system: &Self) -> PoleAnglesDeg {
+        let mut variable_angles_rad = [0.0_f64; MAX_NUT_PREC_ANGLES];
+        // Skip the computation of the nutation and precession angles of the system if we won't be using them.
+        if self.uses_trig_polynomial() {
+            for (ii, nut_prec_angle) in system
+                .nut_prec_angles
+                .iter()
+                .enumerate()
+                .take(system.num_nut_prec_angles.into())
+            {
+                variable_angles_rad[ii] = nut_prec_angle
+                    .evaluate_deg(epoch, Unit::Century)
+                    .to_radians();
+            }
+        }
+
+        let right_asc_deg = self.pole_right_ascension.map(|right_asc_deg| {
+            let mut angle_deg = right_asc_deg.evaluate_deg(epoch, Unit::Century);
+            // Add the nutation and precession angles for this phase angle
+            for (ii, coeff) in right_asc_deg
+                .coeffs
+                .iter()
+                .enumerate()
+                .take(right_asc_deg.coeffs_count as usize)
+            {
+                angle_deg += coeff * variable_angles_rad[ii].sin();
+            }
+            angle_deg
+        });
+
+        let dec_deg = self.pole_declination.map(|decl_deg| {
+            let mut angle_deg = decl_deg.evaluate_deg(epoch, Unit::Century);
+            // Add the nutation and precession angles for this phase angle
+            for (ii, coeff) in decl_deg
+                .coeffs
+                .iter()
+                .enumerate()
+                .take(decl_deg.coeffs_count as usize)
+            {
+                angle_deg += coeff * variable_angles_rad[ii].cos();
+            }
+            angle_deg
+        });
+
+        let twist_deg = self.prime_meridian.map(|twist_deg| {
+            let mut angle_deg = twist_deg.evaluate_deg(epoch, Unit::Day);
+            // Add the nutation and precession angles for this phase angle
+            for (ii, coeff) in twist_deg
+                .coeffs
+                .iter()
+                .enumerate()
+                .take(twist_deg.coeffs_count as usize)
+            {
+                angle_deg += coeff * variable_angles_rad[ii].sin();
+            }
+            angle_deg
+        });
+
+        (right_asc_deg, dec_deg, twist_deg)
+    }
+
     /// Computes the rotation to the parent frame, returning only the rotation matrix
-    fn dcm_to_parent(&self, epoch: Epoch, system: &Self) -> PhysicsResult<Matrix3> {
+    ///
+    /// The prime meridian is evaluated `meridian_delay` before the epoch, cf. [Self::rotation_to_parent_ut1].
+    fn dcm_to_parent(
+        &self,
+        epoch: Epoch,
+        system: &Self,
+        meridian_delay: Duration,
+    ) -> PhysicsResult<Matrix3> {
         if self.pole_declination.is_none()
             && self.prime_meridian.is_none()
             && self.pole_right_ascension.is_none()
         {
             Ok(Matrix3::identity())
         } else {
-            let mut variable_angles_rad = [0.0_f64; MAX_NUT_PREC_ANGLES];
-            // Skip the computation of the nutation and precession angles of the system if we won't be using them.
-            if self.uses_trig_polynomial() {
-                for (ii, nut_prec_angle) in system
-                    .nut_prec_angles
-                    .iter()
-                    .enumerate()
-                    .take(system.num_nut_prec_angles.into())
-                {
-                    variable_angles_rad[ii] = nut_prec_angle
-                        .evaluate_deg(epoch, Unit::Century)
-                        .to_radians();
-                }
+            let (right_asc_deg, dec_deg, mut twist_deg) = self.pole_angles_deg(epoch, system);
+            if meridian_delay != Duration::ZERO {
+                twist_deg = self.pole_angles_deg(epoch - meridian_delay, system).2;
             }
 
-            let right_asc_rad = match self.pole_right_ascension {
-                Some(right_asc_deg) => {
-                    let mut angle_deg = right_asc_deg.evaluate_deg(epoch, Unit::Century);
-                    // Add the nutation and precession angles for this phase angle
-                    for (ii, coeff) in right_asc_deg
-                        .coeffs
-                        .iter()
-                        .enumerate()
-                        .take(right_asc_deg.coeffs_count as usize)
-                    {
-                        angle_deg += coeff * variable_angles_rad[ii].sin();
-                    }
-                    angle_deg.to_radians() + FRAC_PI_2
-                }
-                None => 0.0,
-            };
-
-            let dec_rad = match self.pole_declination {
-                Some(decl_deg) => {
-                    let mut angle_deg = decl_deg.evaluate_deg(epoch, Unit::Century);
-                    // Add the nutation and precession angles for this phase angle
-                    for (ii, coeff) in decl_deg
-                        .coeffs
-                        .iter()
-                        .enumerate()
-                        .take(decl_deg.coeffs_count as usize)
-                    {
-                        angle_deg += coeff * variable_angles_rad[ii].cos();
-                    }
-                    FRAC_PI_2 - angle_deg.to_radians()
-                }
-                None => 0.0,
-            };
-
-            let twist_rad = match self.prime_meridian {
-                Some(twist_deg) => {
-                    let mut angle_deg = twist_deg.evaluate_deg(epoch, Unit::Day);
-                    // Add the nutation and precession angles for this phase angle
-                    for (ii, coeff) in twist_deg
-                        .coeffs
-                        .iter()
-                        .enumerate()
-                        .take(twist_deg.coeffs_count as usize)
-                    {
-                        angle_deg += coeff * variable_angles_rad[ii].sin();
-                    }
-                    angle_deg.to_radians()
-                }
-                None => 0.0,
-            };
+            let right_asc_rad =
+                right_asc_deg.map_or(0.0, |angle_deg| angle_deg.to_radians() + FRAC_PI_2);
+            let dec_rad = dec_deg.map_or(0.0, |angle_deg| FRAC_PI_2 - angle_deg.to_radians());
+            let twist_rad = twist_deg.map_or(0.0, |angle_deg| angle_deg.to_radians());
 
             let ra_dcm = r3(right_asc_rad);
             let dec_dcm = r1(dec_rad);
@@ -236,6 +375,20 @@ impl PlanetaryData {
     ///
     /// Source: <https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/rotation.html#Working%20with%20RA,%20Dec%20and%20Twist>
     pub fn rotation_to_parent(&self, epoch: Epoch, system: &Self) -> PhysicsResult<DCM> {
+        self.rotation_to_parent_ut1(epoch, system, Duration::ZERO)
+    }
+
+    /// Computes the rotation to the parent frame, including its time derivative, where the prime meridian is driven by UT1 instead of TDB.
+    ///
+    /// The prime meridian polynomials of the IAU models are functions of the days past J2000 TDB, so they drift from the actual rotation
+    /// of the Earth by the TDB - UT1 difference (about one minute, i.e. a quarter of a degree). Providing that difference evaluates the
+    /// prime meridian at the epoch whose TDB reading is the UT1 reading of the requested epoch, while the pole is evaluated in TDB.
+    pub fn rotation_to_parent_ut1(
+        &self,
+        epoch: Epoch,
+        system: &Self,
+        tdb_minus_ut1: Duration,
+    ) -> PhysicsResult<DCM> {
         if self.pole_declination.is_none()
             && self.prime_meridian.is_none()
             && self.pole_right_ascension.is_none()
@@ -244,14 +397,14 @@ impl PlanetaryData {
         } else {
             // For planetary constants data, we perform a finite differencing to compute the time derivative.
             let mut dcm = DCM {
-                rot_mat: self.dcm_to_parent(epoch, system)?,
+                rot_mat: self.dcm_to_parent(epoch, system, tdb_minus_ut1)?,
                 from: self.parent_id,
                 to: self.object_id,
                 rot_mat_dt: None,
             };
             // Compute rotation matrix one second before
-            let pre_rot_dcm = self.dcm_to_parent(epoch - 1.seconds(), system)?;
-            let post_rot_dcm = self.dcm_to_parent(epoch + 1.seconds(), system)?;
+            let pre_rot_dcm = self.dcm_to_parent(epoch - 1.seconds(), system, tdb_minus_ut1)?;
+            let post_rot_dcm = self.dcm_to_parent(epoch + 1.seconds(), system, tdb_minus_ut1)?;
 
             dcm.rot_mat_dt = Some((post_rot_dcm - pre_rot_dcm) / 2.0);
 
@@ -272,6 +425,8 @@ impl Encode for PlanetaryData {
             + self.pole_declination.encoded_len()?
             + self.prime_meridian.encoded_len()?
             + self.long_axis.encoded_len()?
+            + self.radiometry.encoded_len()?
+            + self.mu_sigma_km3_s2.encoded_len()?
             + self.num_nut_prec_angles.encoded_len()?
             + self.nut_prec_angles.encoded_len()?
     }
@@ -286,6 +441,8 @@ impl Encode for PlanetaryData {
         self.pole_declination.encode(encoder)?;
         self.prime_meridian.encode(encoder)?;
         self.long_axis.encode(encoder)?;
+        self.radiometry.encode(encoder)?;
+        self.mu_sigma_km3_s2.encode(encoder)?;
         self.num_nut_prec_angles.encode(encoder)?;
         self.nut_prec_angles.encode(encoder)
     }
@@ -329,6 +486,18 @@ impl<'a> Decode<'a> for PlanetaryData {
             None
         };
 
+        let radiometry = if data_flags & (1 << 5) != 0 {
+            Some(decoder.decode()?)
+        } else {
+            None
+        };
+
+        let mu_sigma_km3_s2 = if data_flags & (1 << 6) != 0 {
+            Some(decoder.decode()?)
+        } else {
+            None
+        };
+
         Ok(Self {
             object_id,
             parent_id,
@@ -340,6 +509,8 @@ impl<'a> Decode<'a> for PlanetaryData {
             long_axis,
             num_nut_prec_angles: decoder.decode()?,
             nut_prec_angles: decoder.decode()?,
+            radiometry,
+            mu_sigma_km3_s2,
         })
     }
 }
@@ -352,13 +523,16 @@ impl fmt::Display for PlanetaryData {
             None => format!("planetary data {}", self.object_id),
         };
 
-        write!(f, "{orientation_name}")?;
+        write!(f, "{orientation_name} (μ = {}", self.mu_km3_s2)?;
+        if let Some(sigma) = self.mu_sigma_km3_s2 {
+            write!(f, " ± {sigma}")?;
+        }
         match self.shape {
             Some(shape) => {
-                write!(f, " (μ = {} km^3/s^2, {shape})", self.mu_km3_s2)?;
+                write!(f, " km^3/s^2, {shape})")?;
             }
             None => {
-                write!(f, " (μ = {} km^3/s^2)", self.mu_km3_s2)?;
+                write!(f, " km^3/s^2)")?;
             }
         }
 
@@ -374,6 +548,9 @@ impl fmt::Display for PlanetaryData {
         if self.num_nut_prec_angles > 0 {
             write!(f, " + {} nut/prec angles", self.num_nut_prec_angles)?;
         }
+        if let Some(radiometry) = self.radiometry {
+            write!(f, " ({radiometry})")?;
+        }
 
         Ok(())
     }
@@ -381,8 +558,69 @@ impl fmt::Display for PlanetaryData {
 
 #[cfg(test)]
 mod planetary_constants_ut {
-    use super::{Ellipsoid, PhaseAngle, PlanetaryData};
+    use super::{Ellipsoid, PhaseAngle, PlanetaryData, Radiometry, MAX_NUT_PREC_ANGLES};
     use der::{Decode, Encode};
+    use hifitime::{Epoch, TimeUnits};
+
+    #[test]
+    fn nut_prec_and_pole_angles() {
+        // Mars constants from the pck00011 (nutation precession angles truncated to the first two)
+        let mut nut_prec_angles = [PhaseAngle::<0>::default(); MAX_NUT_PREC_ANGLES];
+        nut_prec_angles[0] = PhaseAngle {
+            offset_deg: 190.72646643,
+            rate_deg: 15917.10818695,
+            ..Default::default()
+        };
+        nut_prec_angles[1] = PhaseAngle {
+            offset_deg: 21.46892470,
+            rate_deg: 31834.27934054,
+            ..Default::default()
+        };
+        let mars = PlanetaryData {
+            object_id: 499,
+            parent_id: 4,
+            mu_km3_s2: 42_828.37,
+            pole_right_ascension: PhaseAngle::maybe_new(&[
+                317.269202,
+                -0.10927547,
+                0.0,
+                0.0,
+                0.000068,
+            ]),
+            pole_declination: PhaseAngle::maybe_new(&[54.432516, -0.05827105, 0.0, 0.0, 0.000051]),
+            prime_meridian: PhaseAngle::maybe_new(&[176.049863, 350.891982443297, 0.0]),
+            num_nut_prec_angles: 2,
+            nut_prec_angles,
+            ..Default::default()
+        };
+
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let angles = mars.nut_prec_angles_deg(epoch);
+        assert_eq!(angles.len(), 2);
+        // Check the rates by finite differencing
+        for (ii, (angle_deg, rate_deg_cy)) in angles.iter().enumerate() {
+            let next_deg = mars.nut_prec_angles_deg(epoch + 36525.days())[ii].0;
+            assert!((next_deg - angle_deg - rate_deg_cy).abs() < 1e-6);
+        }
+
+        let (ra_deg, dec_deg, pm_deg) = mars.pole_angles_deg(epoch, &mars);
+        let t_cy = epoch.to_tdb_duration().to_unit(hifitime::Unit::Century);
+        let expected_ra_deg =
+            317.269202 - 0.10927547 * t_cy + 0.000068 * angles[1].0.to_radians().sin();
+        assert!((ra_deg.unwrap() - expected_ra_deg).abs() < 1e-12);
+        let expected_dec_deg =
+            54.432516 - 0.05827105 * t_cy + 0.000051 * angles[1].0.to_radians().cos();
+        assert!((dec_deg.unwrap() - expected_dec_deg).abs() < 1e-12);
+        assert!(pm_deg.is_some());
+
+        // Angles which aren't defined are reported as such
+        let minimal = PlanetaryData {
+            object_id: 1234,
+            ..Default::default()
+        };
+        assert_eq!(minimal.pole_angles_deg(epoch, &minimal), (None, None, None));
+        assert!(minimal.nut_prec_angles_deg(epoch).is_empty());
+    }
 
     #[test]
     fn pc_encdec_min_repr() {
@@ -405,6 +643,85 @@ mod planetary_constants_ut {
         );
     }
 
+    #[test]
+    fn pc_encdec_with_radiometry() {
+        let repr = PlanetaryData {
+            object_id: 399,
+            mu_km3_s2: 398_600.435_436,
+            radiometry: Radiometry::from_naif_id(399),
+            ..Default::default()
+        };
+        assert_eq!(repr.bond_albedo(), Some(0.306));
+        assert_eq!(repr.geometric_albedo(), Some(0.434));
+        assert_eq!(repr.ir_emission_w_m2(), Some(236.019));
+
+        let mut buf = vec![];
+        repr.encode_to_vec(&mut buf).unwrap();
+
+        let repr_dec = PlanetaryData::from_der(&buf).unwrap();
+        assert_eq!(repr, repr_dec);
+        assert!(format!("{repr}")
+            .ends_with("(Bond albedo = 0.306, geometric albedo = 0.434, IR = 236.019 W/m^2)"));
+
+        // Data without radiometry decodes as such
+        let min_repr = PlanetaryData {
+            radiometry: None,
+            ..repr
+        };
+        let mut buf = vec![];
+        min_repr.encode_to_vec(&mut buf).unwrap();
+        assert!(PlanetaryData::from_der(&buf)
+            .unwrap()
+            .bond_albedo()
+            .is_none());
+    }
+
+    #[test]
+    fn mu_uncertainty() {
+        let sun = PlanetaryData {
+            object_id: 10,
+            mu_km3_s2: 132_712_440_041.279_42,
+            mu_sigma_km3_s2: Some(0.01),
+            ..Default::default()
+        };
+        let earth = PlanetaryData {
+            object_id: 399,
+            mu_km3_s2: 398_600.435_436,
+            mu_sigma_km3_s2: Some(0.008),
+            ..Default::default()
+        };
+
+        let mut buf = vec![];
+        earth.encode_to_vec(&mut buf).unwrap();
+        assert_eq!(PlanetaryData::from_der(&buf).unwrap(), earth);
+        assert_eq!(
+            format!("{earth}"),
+            "IAU_EARTH (μ = 398600.435436 ± 0.008 km^3/s^2)"
+        );
+
+        // GEO period of one sidereal day, with dT/T = -1/2 dmu/mu
+        let period = earth.period_s_1sigma(42_164.17);
+        assert!((period.nominal - 86_164.09).abs() < 0.1, "{period}");
+        assert!((period.relative() - 0.5 * 0.008 / 398_600.435_436).abs() < 1e-15);
+        assert!(period.lower_1sigma() < period.nominal && period.nominal < period.upper_1sigma());
+
+        let au_km = crate::constants::ASTRONOMICAL_UNIT_KM;
+        let soi = earth.soi_radius_km_1sigma(au_km, &sun);
+        assert_eq!(soi.nominal, earth.soi_radius_km(au_km, sun.mu_km3_s2));
+        let expected_rel = 0.4 * (0.008_f64 / 398_600.435_436).hypot(0.01 / 132_712_440_041.279_42);
+        assert!((soi.relative() - expected_rel).abs() < 1e-15);
+
+        let hill = earth.hill_radius_km_1sigma(au_km, &sun);
+        assert!((hill.nominal - earth.hill_radius_km(au_km, sun.mu_km3_s2)).abs() < 1e-6);
+
+        // Without uncertainty, the bounds collapse onto the nominal value
+        let exact = PlanetaryData {
+            mu_sigma_km3_s2: None,
+            ..earth
+        };
+        assert_eq!(exact.period_s_1sigma(42_164.17).sigma, 0.0);
+    }
+
     #[test]
     fn pc_encdec_with_shape_only() {
         let earth_data = Ellipsoid::from_spheroid(6378.1366, 6356.7519);
@@ -425,6 +742,10 @@ mod planetary_constants_ut {
             format!("{repr}"),
             "planetary data 1234 (μ = 12345.6789 km^3/s^2, eq. radius = 6378.1366 km, polar radius = 6356.7519 km, f = 0.0033528131084554717)"
         );
+        assert_eq!(repr.equatorial_radius_km(), Some(6378.1366));
+        assert_eq!(repr.flattening(), Some(0.0033528131084554717));
+        assert!((repr.eccentricity().unwrap() - 0.0818191).abs() < 1e-6);
+        assert_eq!(PlanetaryData::default().eccentricity(), None);
     }
 
     #[test]
@@ -533,7 +854,7 @@ mod planetary_constants_ut {
 
         assert_eq!(repr, min_repr_dec);
 
-        assert_eq!(core::mem::size_of::<PlanetaryData>(), 1984);
+        assert_eq!(core::mem::size_of::<PlanetaryData>(), 2032);
 
         assert_eq!(format!("{repr}"), "planetary data 1234 (μ = 12345.6789 km^3/s^2) Dec = 66.541 + 0.013 t PM = 38.317 + 13.1763582 t");
     }
@@ -602,6 +923,8 @@ mod planetary_constants_ut {
             long_axis: None,
             num_nut_prec_angles: 0,
             nut_prec_angles: Default::default(),
+            radiometry: None,
+            mu_sigma_km3_s2: None,
         };
 
         // Encode
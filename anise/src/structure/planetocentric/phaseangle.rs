@@ -53,6 +53,13 @@ impl<const N: usize> PhaseAngle<N> {
 
         self.offset_deg + self.rate_deg * factor + self.accel_deg * factor.powi(2)
     }
+
+    /// Evaluates the time derivative of this phase angle in degrees per `rate_unit` provided the epoch
+    pub fn evaluate_rate_deg(&self, epoch: Epoch, rate_unit: Unit) -> f64 {
+        let factor = epoch.to_tdb_duration().to_unit(rate_unit);
+
+        self.rate_deg + 2.0 * self.accel_deg * factor
+    }
 }
 
 impl<const N: usize> Encode for PhaseAngle<N> {
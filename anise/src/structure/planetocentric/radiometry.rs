@@ -0,0 +1,122 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+use der::{Decode, Encode, Reader, Writer};
+
+use crate::constants::{ASTRONOMICAL_UNIT_KM, SOLAR_FLUX_AT_1_AU_W_M2};
+use crate::NaifId;
+
+/// Radiometric constants of a celestial object, used by the solar radiation pressure, albedo, and thermal models.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Radiometry {
+    /// Bond albedo, i.e. the fraction of the incident solar power which is reflected, over all wavelengths and directions
+    pub bond_albedo: f64,
+    /// Geometric albedo, i.e. the ratio of the brightness at zero phase angle to that of a flat Lambertian disk of the same cross-section
+    pub geometric_albedo: f64,
+    /// Mean infrared flux emitted by the surface of the object, in W/m^2
+    pub ir_emission_w_m2: f64,
+}
+
+impl Radiometry {
+    /// Returns the standard radiometric constants of the planets and the Moon from the NASA planetary fact sheets, where the IR
+    /// emission is that of a black body at the equilibrium temperature of the object. Returns None for any other object.
+    pub const fn from_naif_id(id: NaifId) -> Option<Self> {
+        let (bond_albedo, geometric_albedo, ir_emission_w_m2) = match id {
+            199 | 1 => (0.068, 0.142, 2117.593),
+            299 | 2 => (0.77, 0.689, 65.279),
+            399 => (0.306, 0.434, 236.019),
+            301 => (0.11, 0.12, 303.137),
+            499 | 4 => (0.25, 0.17, 109.859),
+            599 | 5 => (0.343, 0.538, 8.302),
+            699 | 6 => (0.342, 0.499, 2.453),
+            799 | 7 => (0.3, 0.488, 0.651),
+            899 | 8 => (0.29, 0.442, 0.267),
+            _ => return None,
+        };
+        Some(Self {
+            bond_albedo,
+            geometric_albedo,
+            ir_emission_w_m2,
+        })
+    }
+
+    /// Returns the mean solar flux reflected by the surface of this object in W/m^2, given its distance to the Sun in km.
+    pub fn albedo_flux_w_m2(&self, sun_distance_km: f64) -> f64 {
+        self.bond_albedo * solar_flux_w_m2(sun_distance_km)
+    }
+}
+
+/// Returns the solar flux in W/m^2 at the provided distance from the Sun in km, scaling the solar constant by the inverse square law.
+pub fn solar_flux_w_m2(sun_distance_km: f64) -> f64 {
+    SOLAR_FLUX_AT_1_AU_W_M2 * (ASTRONOMICAL_UNIT_KM / sun_distance_km).powi(2)
+}
+
+impl fmt::Display for Radiometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Bond albedo = {}, geometric albedo = {}, IR = {} W/m^2",
+            self.bond_albedo, self.geometric_albedo, self.ir_emission_w_m2
+        )
+    }
+}
+
+impl Encode for Radiometry {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.bond_albedo.encoded_len()?
+            + self.geometric_albedo.encoded_len()?
+            + self.ir_emission_w_m2.encoded_len()?
+    }
+
+    fn encode(&self, encoder: &mut impl Writer) -> der::Result<()> {
+        self.bond_albedo.encode(encoder)?;
+        self.geometric_albedo.encode(encoder)?;
+        self.ir_emission_w_m2.encode(encoder)
+    }
+}
+
+impl<'a> Decode<'a> for Radiometry {
+    fn decode<R: Reader<'a>>(decoder: &mut R) -> der::Result<Self> {
+        Ok(Self {
+            bond_albedo: decoder.decode()?,
+            geometric_albedo: decoder.decode()?,
+            ir_emission_w_m2: decoder.decode()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_radiometry {
+    use super::{solar_flux_w_m2, Radiometry};
+    use crate::constants::{ASTRONOMICAL_UNIT_KM, SOLAR_FLUX_AT_1_AU_W_M2};
+
+    #[test]
+    fn solar_flux() {
+        assert_eq!(
+            solar_flux_w_m2(ASTRONOMICAL_UNIT_KM),
+            SOLAR_FLUX_AT_1_AU_W_M2
+        );
+        assert!((solar_flux_w_m2(2.0 * ASTRONOMICAL_UNIT_KM) - 340.25).abs() < 1e-12);
+
+        let earth = Radiometry::from_naif_id(399).unwrap();
+        // Earth reflects about 30% of the incident flux
+        assert!((earth.albedo_flux_w_m2(ASTRONOMICAL_UNIT_KM) - 416.466).abs() < 1e-9);
+        // The emitted and absorbed powers balance out: the sphere emits over four times its cross-section
+        assert!(
+            (earth.ir_emission_w_m2 - (1.0 - earth.bond_albedo) * SOLAR_FLUX_AT_1_AU_W_M2 / 4.0)
+                .abs()
+                < 5.0
+        );
+
+        assert_eq!(Radiometry::from_naif_id(4), Radiometry::from_naif_id(499));
+        assert!(Radiometry::from_naif_id(-85).is_none());
+    }
+}
@@ -0,0 +1,256 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+use der::{Decode, Encode, Reader, Writer};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::math::Vector3;
+use crate::NaifId;
+
+/// Simple geometric primitive used to coarsely model the shape of a spacecraft component, in meters in the frame of the component.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Primitive {
+    Sphere {
+        center_m: Vector3,
+        radius_m: f64,
+    },
+    /// Box whose edges are aligned with the axes of the frame of the component (e.g. a bus, or a solar array panel)
+    Cuboid {
+        center_m: Vector3,
+        half_extents_m: Vector3,
+    },
+}
+
+impl Default for Primitive {
+    fn default() -> Self {
+        Self::Sphere {
+            center_m: Vector3::zeros(),
+            radius_m: 0.0,
+        }
+    }
+}
+
+impl Primitive {
+    fn variant(&self) -> u8 {
+        match self {
+            Self::Sphere { .. } => 0,
+            Self::Cuboid { .. } => 1,
+        }
+    }
+
+    /// Returns the center and the size of this primitive for its encoding, where spheres are padded to the size of the cuboids.
+    fn center_and_size(&self) -> (Vector3, Vector3) {
+        match self {
+            Self::Sphere { center_m, radius_m } => (*center_m, Vector3::new(*radius_m, 0.0, 0.0)),
+            Self::Cuboid {
+                center_m,
+                half_extents_m,
+            } => (*center_m, *half_extents_m),
+        }
+    }
+
+    /// Returns true if the ray from the origin along the direction intersects this primitive, excluding the origin itself.
+    pub fn ray_intersects(&self, origin_m: Vector3, direction: Vector3) -> bool {
+        // Ignore grazing intersections at the origin of the ray, e.g. when it lies on the surface of this primitive.
+        const EPSILON_M: f64 = 1e-9;
+        let direction = direction.normalize();
+        match self {
+            Self::Sphere { center_m, radius_m } => {
+                let to_center = center_m - origin_m;
+                let t_closest = to_center.dot(&direction);
+                let miss_sq = to_center.norm_squared() - t_closest.powi(2);
+                if miss_sq > radius_m.powi(2) {
+                    return false;
+                }
+                // The far intersection must be ahead of the origin.
+                t_closest + (radius_m.powi(2) - miss_sq).sqrt() > EPSILON_M
+            }
+            Self::Cuboid {
+                center_m,
+                half_extents_m,
+            } => {
+                // Slab method
+                let (mut t_min, mut t_max) = (f64::NEG_INFINITY, f64::INFINITY);
+                for i in 0..3 {
+                    let low = center_m[i] - half_extents_m[i];
+                    let high = center_m[i] + half_extents_m[i];
+                    if direction[i].abs() < f64::EPSILON {
+                        if origin_m[i] < low || origin_m[i] > high {
+                            return false;
+                        }
+                    } else {
+                        let t1 = (low - origin_m[i]) / direction[i];
+                        let t2 = (high - origin_m[i]) / direction[i];
+                        t_min = t_min.max(t1.min(t2));
+                        t_max = t_max.min(t1.max(t2));
+                    }
+                }
+                t_max >= t_min && t_max > EPSILON_M
+            }
+        }
+    }
+
+    /// Returns the points sampling this primitive for coarse shadowing checks: its center and, for cuboids, its corners.
+    pub fn sample_points_m(&self) -> Vec<Vector3> {
+        match self {
+            Self::Sphere { center_m, .. } => vec![*center_m],
+            Self::Cuboid {
+                center_m,
+                half_extents_m,
+            } => {
+                let mut points = vec![*center_m];
+                for sx in [-1.0, 1.0] {
+                    for sy in [-1.0, 1.0] {
+                        for sz in [-1.0, 1.0] {
+                            points.push(
+                                center_m
+                                    + Vector3::new(
+                                        sx * half_extents_m.x,
+                                        sy * half_extents_m.y,
+                                        sz * half_extents_m.z,
+                                    ),
+                            );
+                        }
+                    }
+                }
+                points
+            }
+        }
+    }
+}
+
+/// Spacecraft component (e.g. the bus, a solar array, an antenna dish) modeled by a simple primitive, for self-shadowing checks.
+///
+/// The component is defined in its own frame, which is either the spacecraft body frame or one of its appendage frames (e.g. an
+/// articulation), such that the component moves with its appendage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Component {
+    /// Orientation ID of the frame of this component
+    pub frame_id: NaifId,
+    /// Position of the origin of the frame of this component (e.g. the hinge of an appendage) in the body frame, in meters
+    pub origin_m: Vector3,
+    /// Shape of this component, in the frame of this component
+    pub primitive: Primitive,
+}
+
+impl Encode for Primitive {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        let (center_m, size) = self.center_and_size();
+        self.variant().encoded_len()?
+            + center_m.x.encoded_len()?
+            + center_m.y.encoded_len()?
+            + center_m.z.encoded_len()?
+            + size.x.encoded_len()?
+            + size.y.encoded_len()?
+            + size.z.encoded_len()?
+    }
+
+    fn encode(&self, encoder: &mut impl Writer) -> der::Result<()> {
+        self.variant().encode(encoder)?;
+        let (center_m, size) = self.center_and_size();
+        center_m.x.encode(encoder)?;
+        center_m.y.encode(encoder)?;
+        center_m.z.encode(encoder)?;
+        size.x.encode(encoder)?;
+        size.y.encode(encoder)?;
+        size.z.encode(encoder)
+    }
+}
+
+impl<'a> Decode<'a> for Primitive {
+    fn decode<R: Reader<'a>>(decoder: &mut R) -> der::Result<Self> {
+        let variant: u8 = decoder.decode()?;
+        let center_m = Vector3::new(decoder.decode()?, decoder.decode()?, decoder.decode()?);
+        let size = Vector3::new(decoder.decode()?, decoder.decode()?, decoder.decode()?);
+
+        Ok(match variant {
+            1 => Self::Cuboid {
+                center_m,
+                half_extents_m: size,
+            },
+            _ => Self::Sphere {
+                center_m,
+                radius_m: size.x,
+            },
+        })
+    }
+}
+
+impl Encode for Component {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.frame_id.encoded_len()?
+            + self.origin_m.x.encoded_len()?
+            + self.origin_m.y.encoded_len()?
+            + self.origin_m.z.encoded_len()?
+            + self.primitive.encoded_len()?
+    }
+
+    fn encode(&self, encoder: &mut impl Writer) -> der::Result<()> {
+        self.frame_id.encode(encoder)?;
+        self.origin_m.x.encode(encoder)?;
+        self.origin_m.y.encode(encoder)?;
+        self.origin_m.z.encode(encoder)?;
+        self.primitive.encode(encoder)
+    }
+}
+
+impl<'a> Decode<'a> for Component {
+    fn decode<R: Reader<'a>>(decoder: &mut R) -> der::Result<Self> {
+        let frame_id = decoder.decode()?;
+        let origin_m = Vector3::new(decoder.decode()?, decoder.decode()?, decoder.decode()?);
+
+        Ok(Self {
+            frame_id,
+            origin_m,
+            primitive: decoder.decode()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod ut_geometry {
+    use super::{Component, Decode, Encode, Primitive};
+    use crate::math::Vector3;
+
+    #[test]
+    fn primitive_rays() {
+        let sphere = Primitive::Sphere {
+            center_m: Vector3::new(5.0, 0.0, 0.0),
+            radius_m: 1.0,
+        };
+        assert!(sphere.ray_intersects(Vector3::zeros(), Vector3::x()));
+        assert!(!sphere.ray_intersects(Vector3::zeros(), -Vector3::x()));
+        assert!(!sphere.ray_intersects(Vector3::zeros(), Vector3::new(1.0, 0.5, 0.0)));
+        // From inside, and from the surface outwards
+        assert!(sphere.ray_intersects(Vector3::new(5.0, 0.0, 0.0), Vector3::y()));
+        assert!(!sphere.ray_intersects(Vector3::new(4.0, 0.0, 0.0), -Vector3::x()));
+
+        let panel = Primitive::Cuboid {
+            center_m: Vector3::new(0.0, 3.0, 0.0),
+            half_extents_m: Vector3::new(1.0, 2.0, 0.01),
+        };
+        assert!(panel.ray_intersects(Vector3::new(0.5, 2.0, -5.0), Vector3::z()));
+        assert!(!panel.ray_intersects(Vector3::new(0.5, 2.0, -5.0), -Vector3::z()));
+        assert!(!panel.ray_intersects(Vector3::new(1.5, 2.0, -5.0), Vector3::z()));
+        assert!(!panel.ray_intersects(Vector3::new(0.5, 2.0, 0.01), Vector3::z()));
+        assert_eq!(panel.sample_points_m().len(), 9);
+
+        let component = Component {
+            frame_id: -85010,
+            origin_m: Vector3::new(0.0, 1.0, 0.0),
+            primitive: panel,
+        };
+        let mut buf = vec![];
+        component.encode_to_vec(&mut buf).unwrap();
+        assert_eq!(Component::from_der(&buf).unwrap(), component);
+        let mut buf = vec![];
+        sphere.encode_to_vec(&mut buf).unwrap();
+        assert_eq!(Primitive::from_der(&buf).unwrap(), sphere);
+    }
+}
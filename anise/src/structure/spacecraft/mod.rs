@@ -11,6 +11,7 @@ use super::dataset::DataSetT;
 use super::SpacecraftDataSet;
 use der::{Decode, Encode, Reader, Writer};
 pub use drag::DragData;
+pub use geometry::{Component, Primitive};
 pub use inertia::Inertia;
 pub use mass::Mass;
 use serde::{Deserialize, Serialize};
@@ -18,12 +19,13 @@ pub use srp::SRPData;
 use tabled::{settings::Style, Table, Tabled};
 
 mod drag;
+mod geometry;
 mod inertia;
 mod mass;
 mod srp;
 
 /// Spacecraft constants can store the some of the spacecraft constant data as the CCSDS Orbit Parameter Message (OPM) and CCSDS Attitude Parameter Messages (APM)
-#[derive(Copy, Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SpacecraftData {
     /// Mass of the spacecraft in kg
     pub mass: Option<Mass>,
@@ -33,6 +35,9 @@ pub struct SpacecraftData {
     pub drag_data: Option<DragData>,
     // Inertia tensor
     pub inertia: Option<Inertia>,
+    /// Components modeled by simple primitives, used for self-shadowing checks
+    #[serde(default)]
+    pub components: Vec<Component>,
 }
 
 impl DataSetT for SpacecraftData {
@@ -47,6 +52,7 @@ impl SpacecraftData {
     /// + Bit 1 is set if `srp_data` is available
     /// + Bit 2 is set if `drag_data` is available
     /// + Bit 3 is set if `inertia` is available
+    /// + Bit 4 is set if `components` is not empty
     fn available_data(&self) -> u8 {
         let mut bits: u8 = 0;
 
@@ -62,6 +68,9 @@ impl SpacecraftData {
         if self.inertia.is_some() {
             bits |= 1 << 3;
         }
+        if !self.components.is_empty() {
+            bits |= 1 << 4;
+        }
 
         bits
     }
@@ -75,6 +84,11 @@ impl Encode for SpacecraftData {
             + self.srp_data.encoded_len()?
             + self.drag_data.encoded_len()?
             + self.inertia.encoded_len()?
+            + if self.components.is_empty() {
+                der::Length::ZERO
+            } else {
+                self.components.encoded_len()?
+            }
     }
 
     fn encode(&self, encoder: &mut impl Writer) -> der::Result<()> {
@@ -82,7 +96,11 @@ impl Encode for SpacecraftData {
         self.mass.encode(encoder)?;
         self.srp_data.encode(encoder)?;
         self.drag_data.encode(encoder)?;
-        self.inertia.encode(encoder)
+        self.inertia.encode(encoder)?;
+        if !self.components.is_empty() {
+            self.components.encode(encoder)?;
+        }
+        Ok(())
     }
 }
 
@@ -114,11 +132,18 @@ impl<'a> Decode<'a> for SpacecraftData {
             None
         };
 
+        let components = if data_flags & (1 << 4) != 0 {
+            decoder.decode()?
+        } else {
+            Vec::new()
+        };
+
         Ok(Self {
             mass: mass_kg,
             srp_data,
             drag_data,
             inertia,
+            components,
         })
     }
 }
@@ -137,6 +162,8 @@ struct SpacecraftRow {
     drag: String,
     #[tabled(rename = "Inertia")]
     inertia: String,
+    #[tabled(rename = "Components")]
+    components: usize,
 }
 
 impl SpacecraftDataSet {
@@ -171,6 +198,7 @@ impl SpacecraftDataSet {
                 srp: format!("{:?}", data.srp_data),
                 drag: format!("{:?}", data.drag_data),
                 inertia: format!("{:?}", data.inertia),
+                components: data.components.len(),
             };
 
             rows.push(row);
@@ -184,7 +212,10 @@ impl SpacecraftDataSet {
 
 #[cfg(test)]
 mod spacecraft_constants_ut {
-    use super::{Decode, DragData, Encode, Inertia, Mass, SRPData, SpacecraftData};
+    use super::{
+        Component, Decode, DragData, Encode, Inertia, Mass, Primitive, SRPData, SpacecraftData,
+    };
+    use crate::math::Vector3;
 
     #[test]
     fn sc_min_repr() {
@@ -304,6 +335,14 @@ mod spacecraft_constants_ut {
             }),
             mass: Some(Mass::from_dry_and_prop_masses(150.0, 50.6)),
             drag_data: Some(DragData::default()),
+            components: vec![Component {
+                frame_id: -20,
+                origin_m: Vector3::new(0.0, 1.0, 0.0),
+                primitive: Primitive::Cuboid {
+                    center_m: Vector3::new(0.0, 2.0, 0.0),
+                    half_extents_m: Vector3::new(0.5, 1.0, 0.01),
+                },
+            }],
         };
 
         let mut buf = vec![];
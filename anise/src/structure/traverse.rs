@@ -0,0 +1,422 @@
+/*
+ * ANISE Toolkit
+ * Copyright (C) 2021-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+use core::fmt;
+use der::{Decode, Encode, Reader, Writer};
+use hifitime::Epoch;
+use snafu::{ensure, OptionExt};
+
+use super::dataset::DataSetT;
+use crate::astro::PhysicsResult;
+use crate::errors::{InvalidTraverseSnafu, TraverseEpochSnafu};
+use crate::math::cartesian::CartesianState;
+use crate::math::interpolation::linear_segment;
+use crate::math::Vector3;
+use crate::prelude::{Frame, Orbit};
+use crate::NaifId;
+
+/// Traverse of a surface asset (e.g. a rover) along a path of time tagged waypoints fixed in the body fixed frame of its body.
+///
+/// The position of the asset is linearly interpolated between its waypoints, so its velocity is constant between two waypoints.
+/// A traverse with a single waypoint defines a fixed asset at all epochs. Once loaded in an Almanac, its ID may be used as the
+/// ephemeris ID of a target or an observer frame, like an ephemeris object.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Traverse {
+    /// Ephemeris ID of the body on which the asset rests
+    pub center_id: NaifId,
+    /// Orientation ID of the body fixed frame of the waypoints
+    pub body_frame_id: NaifId,
+    /// Epochs of the waypoints, in seconds past J2000 TDB, in increasing order
+    pub epochs_tdb_s: Vec<f64>,
+    /// X coordinates of the waypoints in the body fixed frame, in km
+    pub x_km: Vec<f64>,
+    /// Y coordinates of the waypoints in the body fixed frame, in km
+    pub y_km: Vec<f64>,
+    /// Z coordinates of the waypoints in the body fixed frame, in km
+    pub z_km: Vec<f64>,
+}
+
+impl Traverse {
+    /// Builds a new traverse from its waypoints, as positions in the provided body fixed frame.
+    pub fn new(body_frame: Frame, waypoints: &[(Epoch, Vector3)]) -> PhysicsResult<Self> {
+        ensure!(
+            !waypoints.is_empty(),
+            InvalidTraverseSnafu {
+                detail: "the path has no waypoints"
+            }
+        );
+        ensure!(
+            waypoints.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            InvalidTraverseSnafu {
+                detail: "the epochs of the waypoints are not strictly increasing"
+            }
+        );
+
+        Ok(Self {
+            center_id: body_frame.ephemeris_id,
+            body_frame_id: body_frame.orientation_id,
+            epochs_tdb_s: waypoints
+                .iter()
+                .map(|(epoch, _)| epoch.to_tdb_seconds())
+                .collect(),
+            x_km: waypoints.iter().map(|(_, pos)| pos.x).collect(),
+            y_km: waypoints.iter().map(|(_, pos)| pos.y).collect(),
+            z_km: waypoints.iter().map(|(_, pos)| pos.z).collect(),
+        })
+    }
+
+    /// Builds a new traverse from its waypoints as geodetic latitude and longitude in degrees, and height in km, on the ellipsoid
+    /// of the provided body fixed frame, which must define the shape of its body.
+    pub fn from_geodetic(
+        body_frame: Frame,
+        waypoints: &[(Epoch, f64, f64, f64)],
+    ) -> PhysicsResult<Self> {
+        let positions = waypoints
+            .iter()
+            .map(|&(epoch, latitude_deg, longitude_deg, height_km)| {
+                Orbit::try_latlongalt(latitude_deg, longitude_deg, height_km, epoch, body_frame)
+                    .map(|site| (epoch, site.radius_km))
+            })
+            .collect::<PhysicsResult<Vec<_>>>()?;
+
+        Self::new(body_frame, &positions)
+    }
+
+    /// Returns the body fixed frame of the waypoints, without the shape of its body.
+    pub fn body_frame(&self) -> Frame {
+        Frame::new(self.center_id, self.body_frame_id)
+    }
+
+    /// Returns the number of waypoints of this traverse.
+    pub fn len(&self) -> usize {
+        self.epochs_tdb_s.len()
+    }
+
+    /// Returns whether this traverse has no waypoints.
+    pub fn is_empty(&self) -> bool {
+        self.epochs_tdb_s.is_empty()
+    }
+
+    fn waypoint(&self, idx: usize) -> Vector3 {
+        Vector3::new(self.x_km[idx], self.y_km[idx], self.z_km[idx])
+    }
+
+    fn waypoints(&self) -> impl Iterator<Item = Vector3> + '_ {
+        self.x_km
+            .iter()
+            .zip(&self.y_km)
+            .zip(&self.z_km)
+            .map(|((x, y), z)| Vector3::new(*x, *y, *z))
+    }
+
+    fn ensure_consistent(&self) -> PhysicsResult<()> {
+        let len = self.len();
+        ensure!(
+            len > 0 && [self.x_km.len(), self.y_km.len(), self.z_km.len()] == [len; 3],
+            InvalidTraverseSnafu {
+                detail: "the path is empty or inconsistent"
+            }
+        );
+        Ok(())
+    }
+
+    /// Returns the index of the first waypoint of the segment which contains the epoch, and the elapsed fraction of that segment.
+    fn locate(&self, epoch: Epoch) -> PhysicsResult<(usize, f64)> {
+        self.ensure_consistent()?;
+
+        linear_segment(&self.epochs_tdb_s, epoch.to_tdb_seconds()).context(TraverseEpochSnafu {
+            epoch,
+            start: Epoch::from_tdb_seconds(self.epochs_tdb_s[0]),
+            end: Epoch::from_tdb_seconds(self.epochs_tdb_s[self.len() - 1]),
+        })
+    }
+
+    /// Returns the state of the asset in the body fixed frame at the provided epoch.
+    pub fn state(&self, epoch: Epoch) -> PhysicsResult<CartesianState> {
+        let (idx, fraction) = self.locate(epoch)?;
+
+        let (radius_km, velocity_km_s) = if self.len() == 1 {
+            (self.waypoint(0), Vector3::zeros())
+        } else {
+            let (p0, p1) = (self.waypoint(idx), self.waypoint(idx + 1));
+            let dt_s = self.epochs_tdb_s[idx + 1] - self.epochs_tdb_s[idx];
+            (p0 + (p1 - p0) * fraction, (p1 - p0) / dt_s)
+        };
+
+        Ok(CartesianState {
+            radius_km,
+            velocity_km_s,
+            epoch,
+            frame: self.body_frame(),
+        })
+    }
+
+    /// Returns the length of the path between successive waypoints, in km.
+    pub fn total_distance_km(&self) -> f64 {
+        self.waypoints()
+            .zip(self.waypoints().skip(1))
+            .map(|(prev, next)| (next - prev).norm())
+            .sum()
+    }
+
+    /// Returns the distance travelled along the path from its first waypoint up to the provided epoch, in km.
+    pub fn distance_along_km(&self, epoch: Epoch) -> PhysicsResult<f64> {
+        let (idx, fraction) = self.locate(epoch)?;
+        if self.len() == 1 {
+            return Ok(0.0);
+        }
+
+        let travelled_km: f64 = (1..=idx)
+            .map(|k| (self.waypoint(k) - self.waypoint(k - 1)).norm())
+            .sum();
+
+        Ok(travelled_km + fraction * (self.waypoint(idx + 1) - self.waypoint(idx)).norm())
+    }
+
+    /// Returns the first epoch at which the asset has travelled the provided distance along the path, in km.
+    pub fn epoch_at_distance(&self, distance_km: f64) -> PhysicsResult<Epoch> {
+        self.ensure_consistent()?;
+        ensure!(
+            (0.0..=self.total_distance_km()).contains(&distance_km),
+            InvalidTraverseSnafu {
+                detail: "the distance is not between zero and the length of the path"
+            }
+        );
+
+        let mut travelled_km = 0.0;
+        for idx in 1..self.len() {
+            let segment_km = (self.waypoint(idx) - self.waypoint(idx - 1)).norm();
+            if travelled_km + segment_km >= distance_km && segment_km > 0.0 {
+                let (t0, t1) = (self.epochs_tdb_s[idx - 1], self.epochs_tdb_s[idx]);
+                let fraction = (distance_km - travelled_km) / segment_km;
+                return Ok(Epoch::from_tdb_seconds(t0 + fraction * (t1 - t0)));
+            }
+            travelled_km += segment_km;
+        }
+
+        // Zero distance on a path which does not move
+        Ok(Epoch::from_tdb_seconds(self.epochs_tdb_s[0]))
+    }
+}
+
+impl DataSetT for Traverse {
+    const NAME: &'static str = "traverse data";
+}
+
+impl Encode for Traverse {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.center_id.encoded_len()?
+            + self.body_frame_id.encoded_len()?
+            + self.epochs_tdb_s.encoded_len()?
+            + self.x_km.encoded_len()?
+            + self.y_km.encoded_len()?
+            + self.z_km.encoded_len()?
+    }
+
+    fn encode(&self, encoder: &mut impl Writer) -> der::Result<()> {
+        self.center_id.encode(encoder)?;
+        self.body_frame_id.encode(encoder)?;
+        self.epochs_tdb_s.encode(encoder)?;
+        self.x_km.encode(encoder)?;
+        self.y_km.encode(encoder)?;
+        self.z_km.encode(encoder)
+    }
+}
+
+impl<'a> Decode<'a> for Traverse {
+    fn decode<R: Reader<'a>>(decoder: &mut R) -> der::Result<Self> {
+        Ok(Self {
+            center_id: decoder.decode()?,
+            body_frame_id: decoder.decode()?,
+            epochs_tdb_s: decoder.decode()?,
+            x_km: decoder.decode()?,
+            y_km: decoder.decode()?,
+            z_km: decoder.decode()?,
+        })
+    }
+}
+
+impl fmt::Display for Traverse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "traverse on {} with {} waypoints over {:.3} km",
+            self.body_frame(),
+            self.len(),
+            self.total_distance_km()
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_traverse {
+    use super::{Decode, Encode, Traverse};
+    use crate::constants::frames::IAU_MARS_FRAME;
+    use crate::math::Vector3;
+    use crate::structure::planetocentric::ellipsoid::Ellipsoid;
+    use hifitime::{Epoch, Unit};
+
+    #[test]
+    fn traverse_path() {
+        let start = Epoch::from_tdb_seconds(0.0);
+        // Drives 3 km along X in 10 minutes, stops for 10 minutes, then drives 4 km along Y in 20 minutes.
+        let rover = Traverse::new(
+            IAU_MARS_FRAME,
+            &[
+                (start, Vector3::new(3390.0, 0.0, 0.0)),
+                (start + Unit::Minute * 10, Vector3::new(3393.0, 0.0, 0.0)),
+                (start + Unit::Minute * 20, Vector3::new(3393.0, 0.0, 0.0)),
+                (start + Unit::Minute * 40, Vector3::new(3393.0, 4.0, 0.0)),
+            ],
+        )
+        .unwrap();
+        println!("{rover}");
+        assert_eq!(rover.body_frame(), IAU_MARS_FRAME);
+        assert_eq!(rover.total_distance_km(), 7.0);
+
+        let state = rover.state(start + Unit::Minute * 5).unwrap();
+        assert!((state.radius_km - Vector3::new(3391.5, 0.0, 0.0)).norm() < 1e-9);
+        assert!((state.velocity_km_s - Vector3::new(0.005, 0.0, 0.0)).norm() < 1e-12);
+        assert_eq!(
+            rover
+                .state(start + Unit::Minute * 15)
+                .unwrap()
+                .velocity_km_s,
+            Vector3::zeros()
+        );
+        assert!(rover.state(start - Unit::Second * 1).is_err());
+        assert!(rover.state(start + Unit::Minute * 41).is_err());
+
+        // Along-path queries
+        let epoch = start + Unit::Minute * 30;
+        assert!((rover.distance_along_km(epoch).unwrap() - 5.0).abs() < 1e-9);
+        assert_eq!(
+            rover.distance_along_km(start + Unit::Minute * 40).unwrap(),
+            7.0
+        );
+        assert!((rover.epoch_at_distance(5.0).unwrap() - epoch).abs() < Unit::Microsecond * 1);
+        // The rover reaches 3 km when it stops.
+        assert!(
+            (rover.epoch_at_distance(3.0).unwrap() - (start + Unit::Minute * 10)).abs()
+                < Unit::Microsecond * 1
+        );
+        assert_eq!(rover.epoch_at_distance(0.0).unwrap(), start);
+        assert!(rover.epoch_at_distance(7.1).is_err());
+
+        // A single waypoint is a fixed asset.
+        let lander = Traverse::from_geodetic(
+            IAU_MARS_FRAME.with_ellipsoid(Ellipsoid::from_sphere(3390.0)),
+            &[(start, 0.0, 90.0, 0.0)],
+        )
+        .unwrap();
+        let state = lander.state(start + Unit::Day * 10).unwrap();
+        assert!((state.radius_km - Vector3::new(0.0, 3390.0, 0.0)).norm() < 1e-9);
+        assert_eq!(state.velocity_km_s, Vector3::zeros());
+        assert_eq!(lander.epoch_at_distance(0.0).unwrap(), start);
+
+        // Invalid paths
+        assert!(Traverse::new(IAU_MARS_FRAME, &[]).is_err());
+        assert!(Traverse::new(
+            IAU_MARS_FRAME,
+            &[(start, Vector3::zeros()), (start, Vector3::x())]
+        )
+        .is_err());
+        assert!(Traverse::from_geodetic(IAU_MARS_FRAME, &[(start, 0.0, 0.0, 0.0)]).is_err());
+
+        // Encoding round trip
+        let mut buf = vec![];
+        rover.encode_to_vec(&mut buf).unwrap();
+        assert_eq!(Traverse::from_der(&buf).unwrap(), rover);
+    }
+
+    #[test]
+    fn traverse_in_almanac() {
+        use crate::constants::frames::MARS_J2000;
+        use crate::math::cartesian::CartesianState;
+        use crate::naif::daf::test_utils::{fixed_segment, test_spk};
+        use crate::prelude::{Almanac, Frame};
+        use crate::structure::dataset::DataSetType;
+        use crate::structure::TraverseDataSet;
+        use bytes::BytesMut;
+
+        // Mars at rest with respect to the solar system barycenter over the first day past J2000 TDB
+        let mars = fixed_segment(499, 0, [2.0e8, 0.0, 0.0]);
+
+        let start = Epoch::from_et_seconds(1_000.0);
+        let (rover_id, lander_id) = (-1001, -1002);
+        let rover = Traverse::new(
+            IAU_MARS_FRAME,
+            &[
+                (start, Vector3::new(3390.0, 0.0, 0.0)),
+                (start + Unit::Hour * 1, Vector3::new(3390.0, 1.8, 0.0)),
+            ],
+        )
+        .unwrap();
+        let lander =
+            Traverse::new(IAU_MARS_FRAME, &[(start, Vector3::new(3390.0, 0.5, 0.2))]).unwrap();
+        let mut traverses = TraverseDataSet::default();
+        traverses
+            .push(rover, Some(rover_id), Some("ROVER"))
+            .unwrap();
+        traverses
+            .push(lander, Some(lander_id), Some("LANDER"))
+            .unwrap();
+        traverses.metadata.dataset_type = DataSetType::TraverseData;
+        traverses.set_crc32();
+
+        // Traverse data is loaded like any other dataset.
+        let mut buf = vec![];
+        traverses.encode_to_vec(&mut buf).unwrap();
+        let almanac = Almanac::default()
+            .load("../data/pck08.pca")
+            .unwrap()
+            .with_spk(test_spk(&[mars]))
+            .load_from_bytes(BytesMut::from(&buf[..]))
+            .unwrap();
+        assert_eq!(almanac.traverse_data.len(), 1);
+
+        let epoch = start + Unit::Minute * 30;
+        let rover_bf = Frame::new(rover_id, IAU_MARS_FRAME.orientation_id);
+        let lander_bf = Frame::new(lander_id, IAU_MARS_FRAME.orientation_id);
+
+        // The rover as seen from the lander, in the body fixed frame
+        let state = almanac.transform(rover_bf, lander_bf, epoch, None).unwrap();
+        assert_eq!(state.frame, lander_bf);
+        assert!((state.radius_km - Vector3::new(0.0, 0.4, -0.2)).norm() < 1e-9);
+        assert!((state.velocity_km_s - Vector3::new(0.0, 0.0005, 0.0)).norm() < 1e-12);
+
+        // The rover as seen from Mars in an inertial frame moves with the rotation of Mars.
+        let inertial = almanac
+            .transform(Frame::from_ephem_j2000(rover_id), MARS_J2000, epoch, None)
+            .unwrap();
+        assert!((inertial.rmag_km() - (3390.0_f64.powi(2) + 0.81).sqrt()).abs() < 1e-9);
+        assert!(inertial.vmag_km_s() > 0.2);
+        let back = almanac
+            .transform(MARS_J2000, Frame::from_ephem_j2000(rover_id), epoch, None)
+            .unwrap();
+        assert!((back.radius_km + inertial.radius_km).norm() < 1e-9);
+        assert!((back.velocity_km_s + inertial.velocity_km_s).norm() < 1e-12);
+
+        // States relative to the rover, e.g. a mast camera 2 meters above the rover
+        let mast = CartesianState::new(0.002, 0.0, 0.0, 0.0, 0.0, 0.0, epoch, rover_bf);
+        let mast_from_lander = almanac.transform_to(mast, lander_bf, None).unwrap();
+        assert!((mast_from_lander.radius_km - Vector3::new(0.002, 0.4, -0.2)).norm() < 1e-9);
+        let many = almanac.transform_many(&[(mast, lander_bf), (mast, MARS_J2000)]);
+        assert_eq!(
+            many[0].as_ref().unwrap().radius_km,
+            mast_from_lander.radius_km
+        );
+        assert!((many[1].as_ref().unwrap().rmag_km() - inertial.rmag_km() - 0.002).abs() < 1e-6);
+
+        // Outside of the path
+        assert!(almanac
+            .transform(rover_bf, lander_bf, start + Unit::Hour * 2, None)
+            .is_err());
+    }
+}
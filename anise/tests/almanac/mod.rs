@@ -1,6 +1,8 @@
 // Start by creating the ANISE planetary data
 use anise::{
-    constants::frames::{EARTH_ITRF93, EARTH_J2000, SUN_J2000},
+    constants::frames::{
+        EARTH_ITRF93, EARTH_J2000, EARTH_MOON_BARYCENTER_J2000, MOON_J2000, SUN_J2000,
+    },
     naif::kpl::parser::convert_tpc,
     prelude::{Aberration, Almanac, Orbit, BPC, SPK},
 };
@@ -138,3 +140,39 @@ fn test_type3_state_transformation() {
 
     println!("{state:x}");
 }
+
+#[test]
+fn test_barycenter() {
+    let almanac = Almanac::default()
+        .load("../data/de440s.bsp")
+        .unwrap()
+        .load("../data/pck08.pca")
+        .unwrap();
+
+    let epoch = Epoch::from_str("2021-10-29 12:34:56 TDB").unwrap();
+
+    // The Earth-Moon barycenter computed from the GMs should match the one from the ephemeris.
+    let emb = almanac
+        .barycenter(
+            &[EARTH_J2000, MOON_J2000],
+            EARTH_MOON_BARYCENTER_J2000,
+            epoch,
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(emb.frame, EARTH_MOON_BARYCENTER_J2000);
+    assert!(emb.rmag_km() < 1e-2, "{emb}");
+    assert!(emb.vmag_km_s() < 1e-7, "{emb}");
+
+    // Barycenter of a single body is that body
+    let moon = almanac
+        .barycenter(&[MOON_J2000], EARTH_J2000, epoch, None)
+        .unwrap();
+    let expected = almanac
+        .transform(MOON_J2000, EARTH_J2000, epoch, None)
+        .unwrap();
+    assert!((moon.radius_km - expected.radius_km).norm() < 1e-9);
+
+    assert!(almanac.barycenter(&[], EARTH_J2000, epoch, None).is_err());
+}
@@ -2,6 +2,7 @@ extern crate pretty_env_logger as pel;
 
 use anise::astro::orbit::ECC_EPSILON;
 use anise::constants::frames::{EARTH_J2000, MOON_J2000};
+use anise::ephemerides::ephemeris::LocalFrame;
 use anise::errors::PhysicsError;
 use anise::math::angles::{between_0_360, between_pm_180};
 use anise::math::Vector3;
@@ -1056,3 +1057,197 @@ fn test_duration_to_radius_error_conditions(epoch: Epoch, frame: Frame) {
     // If sma_km() returns Inf, then sma_km.abs().powi(3) is Inf, then n_rad_s is 0. Caught.
     // If sma_km() returns some error that leads to NaN sma, then n_rad_s is NaN. Caught.
 }
+
+#[test]
+fn lvlh_frame() {
+    let eme2k = EARTH_J2000.with_mu_km3_s2(398_600.441_5);
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2000, 1, 1);
+    // Near circular orbit
+    let orbit = Orbit::try_keplerian(7000.0, 1e-3, 51.6, 30.0, 10.0, 45.0, epoch, eme2k).unwrap();
+
+    let dcm = orbit.dcm_from_lvlh_to_inertial().unwrap();
+    // Z is nadir, Y is the negative orbit normal, and X is along the velocity for (near) circular orbits.
+    let x = dcm.rot_mat.column(0);
+    let y = dcm.rot_mat.column(1);
+    let z = dcm.rot_mat.column(2);
+    assert!((z + orbit.r_hat()).norm() < 1e-12);
+    assert!((y + orbit.h_hat().unwrap()).norm() < 1e-12);
+    assert!((x - orbit.velocity_km_s / orbit.vmag_km_s()).norm() < 1e-3);
+    assert!(dcm.is_valid(1e-12, 1e-12));
+
+    // The LVLH frame rotates at the true anomaly rate about the orbit normal.
+    let omega_rad_s = dcm.angular_velocity_rad_s().unwrap();
+    let ta_rate_rad_s = orbit.hmag().unwrap() / orbit.rmag_km().powi(2);
+    assert!((omega_rad_s.norm() - ta_rate_rad_s).abs() < 1e-9);
+
+    // Dispatch through the local frame
+    let local = orbit.dcm_to_inertial(LocalFrame::LVLH).unwrap();
+    assert_eq!(local.rot_mat, dcm.rot_mat);
+}
+
+#[test]
+fn ric_difference_rotating_frame() {
+    let eme2k = EARTH_J2000.with_mu_km3_s2(398_600.441_5);
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2000, 1, 1);
+
+    for ecc in [0.0, 1e-3, 0.2] {
+        let target =
+            Orbit::try_keplerian(7000.0, ecc, 51.6, 30.0, 45.0, 60.0, epoch, eme2k).unwrap();
+        let chaser = target.add_ta_deg(0.01).unwrap();
+
+        // The time derivative of the RIC frame is set, including for circular orbits.
+        let dcm = target.dcm_from_ric_to_inertial().unwrap();
+        assert!(dcm.rot_mat_dt.is_some(), "ecc = {ecc}");
+
+        // The RIC velocity is the rate of change of the RIC position difference.
+        let ric = target.ric_difference(&chaser).unwrap();
+        let ric_at = |epoch: Epoch| {
+            target
+                .propagate_to_epoch(epoch)
+                .unwrap()
+                .ric_difference(&chaser.propagate_to_epoch(epoch).unwrap())
+                .unwrap()
+        };
+        // Central difference over one second
+        let rate_km_s = ric_at(epoch + Unit::Millisecond * 500).radius_km
+            - ric_at(epoch - Unit::Millisecond * 500).radius_km;
+        assert!(
+            (rate_km_s - ric.velocity_km_s).norm() < 1e-9,
+            "ecc = {ecc}: {rate_km_s} vs {}",
+            ric.velocity_km_s
+        );
+        if ecc == 0.0 {
+            // Two objects on the same circular orbit do not move with respect to one another.
+            assert!(ric.velocity_km_s.norm() < 1e-12);
+        }
+    }
+}
+
+/// Returns the state of SPICE's `conics` at the provided epoch for the elements (rp, ecc, inc, lnode, argp, M0, t0, mu).
+fn spice_conics(mut elts: [f64; 8], epoch: Epoch) -> [f64; 6] {
+    let mut state = [0.0; 6];
+    unsafe {
+        spice::c::conics_c(elts.as_mut_ptr(), epoch.to_et_seconds(), state.as_mut_ptr());
+    }
+    state
+}
+
+#[rstest]
+fn verif_conics_hyperbolic_parabolic(epoch: Epoch, frame: Frame) {
+    let mu_km3_s2 = frame.mu_km3_s2().unwrap();
+    let (inc_deg, raan_deg, aop_deg) = (28.5_f64, 40.0_f64, 60.0_f64);
+
+    for (rp_km, ecc) in [(7000.0, 0.3), (7000.0, 1.0), (7000.0, 1.2), (6600.0, 3.5)] {
+        let elts = |ma0_deg: f64| {
+            [
+                rp_km,
+                ecc,
+                inc_deg.to_radians(),
+                raan_deg.to_radians(),
+                aop_deg.to_radians(),
+                ma0_deg.to_radians(),
+                epoch.to_et_seconds(),
+                mu_km3_s2,
+            ]
+        };
+
+        // At periapsis, then propagated with two body dynamics, including parabolic orbits
+        let at_periapsis = Orbit::try_keplerian_periapsis(
+            rp_km, ecc, inc_deg, raan_deg, aop_deg, 0.0, epoch, frame,
+        )
+        .unwrap();
+        for hours in [-3.0, 0.0, 1.0, 10.0] {
+            let at = epoch + Unit::Hour * hours;
+            let state = spice_conics(elts(0.0), at);
+            let orbit = at_periapsis.propagate_to_epoch(at).unwrap();
+            let spice_radius_km = Vector3::new(state[0], state[1], state[2]);
+            let spice_velocity_km_s = Vector3::new(state[3], state[4], state[5]);
+            assert!(
+                (orbit.radius_km - spice_radius_km).norm() < 1e-6 * spice_radius_km.norm(),
+                "e = {ecc}, {hours} h: {orbit} vs {spice_radius_km}"
+            );
+            assert!((orbit.velocity_km_s - spice_velocity_km_s).norm() < 1e-9 * 1e3);
+        }
+
+        if ecc == 1.0 {
+            // Parabolic orbits have no semi major axis, period, or mean anomaly.
+            assert!(at_periapsis.period().is_err());
+            assert!(at_periapsis.vinf_km_s().is_err());
+            assert!(Orbit::try_keplerian_periapsis(
+                rp_km, ecc, inc_deg, raan_deg, aop_deg, 180.0, epoch, frame
+            )
+            .is_err());
+            continue;
+        }
+
+        // From the mean anomaly, on the inbound and outbound legs of hyperbolic orbits
+        for ma0_deg in [-100.0, -10.0, 30.0, 250.0] {
+            let state = spice_conics(elts(ma0_deg), epoch);
+            let orbit = at_periapsis.with_ma_deg(ma0_deg).unwrap();
+            let spice_radius_km = Vector3::new(state[0], state[1], state[2]);
+            assert!(
+                (orbit.radius_km - spice_radius_km).norm() < 1e-6 * spice_radius_km.norm(),
+                "e = {ecc}, M0 = {ma0_deg} deg: {orbit} vs {spice_radius_km}"
+            );
+            f64_eq_tol!(orbit.periapsis_km().unwrap(), rp_km, 1e-6, "rp");
+            f64_eq_tol!(orbit.ecc().unwrap(), ecc, 1e-12, "ecc");
+            let ma_deg = if ecc < 1.0 {
+                between_0_360(ma0_deg)
+            } else {
+                ma0_deg
+            };
+            f64_eq_tol!(orbit.ma_deg().unwrap(), ma_deg, 1e-8, "mean anomaly");
+            // Constructing from the true anomaly returns the same state
+            let from_ta = Orbit::try_keplerian(
+                orbit.sma_km().unwrap(),
+                ecc,
+                inc_deg,
+                raan_deg,
+                aop_deg,
+                orbit.ta_deg().unwrap(),
+                epoch,
+                frame,
+            )
+            .unwrap();
+            assert!((from_ta.radius_km - orbit.radius_km).norm() < 1e-6);
+        }
+
+        if ecc > 1.0 {
+            let c3_km2_s2 = at_periapsis.c3_km2_s2().unwrap();
+            f64_eq_tol!(c3_km2_s2, mu_km3_s2 * (ecc - 1.0) / rp_km, 1e-9, "C3");
+            f64_eq_tol!(
+                at_periapsis.vinf_km_s().unwrap(),
+                c3_km2_s2.sqrt(),
+                1e-12,
+                "vinf"
+            );
+            assert!(at_periapsis.period().is_err());
+
+            // The velocity tends to the asymptotes far from periapsis
+            let far = Unit::Day * 300;
+            let outbound = at_periapsis.propagate_to_epoch(epoch + far).unwrap();
+            let inbound = at_periapsis.propagate_to_epoch(epoch - far).unwrap();
+            let out_hat = at_periapsis.asymptote_out_unit_vector().unwrap();
+            let in_hat = at_periapsis.asymptote_in_unit_vector().unwrap();
+            assert!(out_hat.angle(&outbound.velocity_km_s).to_degrees() < 0.1);
+            assert!(in_hat.angle(&inbound.velocity_km_s).to_degrees() < 0.1);
+            f64_eq_tol!(
+                out_hat.angle(&in_hat).to_degrees(),
+                2.0 * (1.0 / ecc).asin().to_degrees(),
+                1e-9,
+                "turn angle"
+            );
+            let (ra_deg, dec_deg) = at_periapsis.asymptote_out_ra_dec_deg().unwrap();
+            f64_eq_tol!(dec_deg, out_hat.z.asin().to_degrees(), 1e-12, "DLA");
+            f64_eq_tol!(
+                ra_deg,
+                between_0_360(out_hat.y.atan2(out_hat.x).to_degrees()),
+                1e-12,
+                "RLA"
+            );
+        } else {
+            assert!(at_periapsis.vinf_km_s().is_err());
+            assert!(at_periapsis.asymptote_out_unit_vector().is_err());
+        }
+    }
+}